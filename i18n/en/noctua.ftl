@@ -44,19 +44,87 @@ tooltip-rotate-ccw = Rotate counter-clockwise
 tooltip-rotate-cw = Rotate clockwise
 tooltip-flip-horizontal = Flip horizontally
 tooltip-flip-vertical = Flip vertically
+tooltip-orientation-lock = Keep rotation and flip for the next documents
 tooltip-info-panel = Toggle info panel
+tooltip-settings-panel = Toggle settings panel
 
 
 ## Footer / Status bar
 status-zoom-fit = Fit
+status-zoom-fit-width = Fit Width
+status-zoom-cover = Cover
+status-zoom-fill = Fill
+status-rotate-view = Rotate view (Ctrl+R, does not change the file)
+status-view-rotated = View rotated { $degrees }° (Ctrl+R, does not change the file)
+status-pixel-art-on = Pixel art: sharp pixels, integer zoom (click to turn off)
+status-pixel-art-off = Pixel art mode (click to turn on)
+status-alpha-mask = Show alpha channel as mask (A)
+status-tile-off = Tile preview for seamless textures (T)
+status-tile-grid = Tile preview: 3 × 3 grid (T for half offset)
+status-tile-offset = Tile preview: shifted by half, seams in the middle (T to turn off)
+status-magnifier = Magnifier: zoomed view follows the mouse (M)
+status-right-to-left-on = Reading right to left: ← goes forward (click for left to right)
+status-half-page = Sheet-music mode: page turns move half a page, click left or right to turn
+status-folder-unavailable = Folder not reachable, showing the images found last
+status-read-only = Read-only: this file cannot be changed in place (click for Save As)
+status-export-pages = Exporting page { $current } of { $total }
+status-export-pages-cancel = Stop page export
+status-catalog = Reading file { $current } of { $total } for the catalog
+status-catalog-cancel = Stop catalog export
+status-batch = Converting image { $current } of { $total }
+status-share = Sharing to { $name }…
+status-upload = Uploading: { $percent }%
+status-animation-frame = Frame { $current }/{ $total } · { $time } s
+status-fullscreen = Fullscreen (F11)
+status-slideshow = Slideshow (F5)
+
+## Presentation
+presentation-next-in = next in { $time }
+presentation-previous = Previous (←)
+presentation-next = Next (→)
+presentation-play = Advance automatically (P)
+presentation-pause = Pause (P)
+presentation-exit = Leave fullscreen (Esc)
+status-xmp-applied = Showing the crop and rotation from { $file } (click to show the original)
+status-xmp-ignored = Ignoring the edits in { $file } (click to apply them)
+status-strip-mode = Webtoon mode: all images of the folder in one vertical strip (Shift+W)
+status-compare = Compare with another image or the saved version (Shift+C)
+status-right-to-left-off = Read right to left, e.g. manga (swaps ← and →)
 status-zoom-percent = { $percent }%
 status-doc-dimensions = { $width } × { $height }
 status-nav-position = { $current } / { $total }
+status-find-none = No matches
+status-find-position = Match { $current } of { $total }
 status-separator =  |
 
 
+## Canvas controls
+canvas-previous = Previous (←)
+canvas-next = Next (→)
+canvas-rotate-ccw = Rotate counter-clockwise (Shift+R)
+canvas-rotate-cw = Rotate clockwise (R)
+canvas-zoom-out = Zoom out (-)
+canvas-zoom-in = Zoom in (+)
+canvas-fullscreen = Fullscreen (F11)
+
+
+## Compare mode
+compare-swap = Swap
+compare-side-by-side = Side by side
+compare-swipe = Swipe: drag the divider to reveal B
+compare-close = Leave compare mode (Shift+C)
+compare-slot-a = A: { $file }
+compare-slot-b = B: { $file }
+compare-loading = Loading…
+compare-unsupported = Only images can be compared
+compare-load-failed = The image to compare with could not be opened
+
+
 ## Placeholders / Empty states
 no-document = No document loaded
+recent-title = Recent Files
+recent-clear = Clear Recent Files
+loading-full-resolution = Loading full resolution…
 
 
 ## Labels
@@ -77,6 +145,9 @@ loading-thumbnails = Loading { $current } / { $total }…
 error-failed-to-open = Failed to open "{ $path }"
 error-unsupported-format = Unsupported file format
 error-no-image-loaded = No image loaded
+strip-no-images = No images in this folder for webtoon mode
+slideshow-no-matches = No images in this folder have the rating or tags chosen for slideshows
+text-select-unavailable = Text can only be copied from pages that are not rotated or flipped
 
 
 ## Properties panel
@@ -93,6 +164,14 @@ meta-format = Format
 meta-dimensions = Dimensions
 meta-filesize = Size
 meta-colortype = Color Type
+meta-resolution = Resolution
+meta-print-size = Print Size
+meta-orientation = EXIF Orientation
+meta-orientation-rotated = Turn { $degrees }° clockwise
+meta-orientation-mirrored = Mirror
+meta-orientation-mirrored-rotated = Mirror, turn { $degrees }° clockwise
+meta-orientation-applied = { $correction } (applied)
+meta-orientation-ignored = { $correction } (not applied)
 meta-path = Path
 meta-pages = Pages
 meta-current-page = Current Page
@@ -110,19 +189,637 @@ meta-aperture = Aperture
 meta-iso = ISO { $iso }
 meta-focal = Focal Length
 meta-gps = GPS Location
+meta-altitude = Altitude
+
+## Color profile metadata
+meta-section-icc = Color Profile
+meta-icc-name = Profile
+meta-icc-colorspace = Color Space
+meta-icc-intent = Rendering Intent
+meta-icc-intent-perceptual = Perceptual
+meta-icc-intent-relative = Relative colorimetric
+meta-icc-intent-saturation = Saturation
+meta-icc-intent-absolute = Absolute colorimetric
+meta-icc-intent-unknown = Unknown
+meta-icc-whitepoint = White Point (XYZ)
+
+## Crop selection
+meta-section-selection = Selection
+meta-selection-size = Size
+meta-aspect-ratio = Aspect Ratio
+meta-megapixels = Megapixels
+meta-mean-color = Mean Color (RGB)
+meta-luminance = Luminance
+meta-luminance-value = { $mean } ± { $deviation } (range { $min }–{ $max })
+meta-histogram = Histogram
+action-analyze-selection = Analyze Selection
+selection-analyze-failed = The selection lies outside the image.
 
 ## Action buttons
 action-set-wallpaper = Set as Wallpaper
 action-open-with = Open With…
 action-show-in-folder = Show in Folder
+action-export-profile = Export Profile…
+dialog-export-profile = Export Color Profile
+action-export-alpha = Export Alpha Mask…
+dialog-export-alpha = Export Alpha Mask
+action-shift-dates = Shift Capture Dates…
+action-edit-location = Edit Location…
+action-privacy-export = Privacy Export…
+action-batch-convert = Convert Folder Images…
+action-crop-dimensions = Enter Crop Dimensions… (G)
+action-select-text = Select Text (X)
+action-copy-text = Copy Selected Text (Ctrl+C)
+action-copy-svg = Copy as SVG (Ctrl+Shift+C)
+action-copy-info = Copy Info
+action-copy-info-json = Copy as JSON
+action-copy-coordinates = Copy Coordinates
+action-open-in-map = Open in Map
+action-browse-metadata = All Metadata…
+action-edit-metadata = Edit Metadata…
+action-export-catalog = Export Folder Catalog…
+dialog-export-catalog = Export Folder Catalog (CSV or JSON)
+catalog-done = { $count ->
+    [one] Catalog of 1 file written.
+   *[other] Catalog of { $count } files written.
+}
+catalog-cancelled = Catalog export stopped; nothing was written.
+share-to = Share to { $name }
+share-done = Shared to { $name }; the link is in the clipboard.
+action-upload = Upload…
+upload-title = Upload
+upload-endpoint = Upload address
+upload-endpoint-placeholder = https://0x0.st
+upload-endpoint-save = Save
+upload-endpoint-hint = A paste service the image is posted to (0x0.st and similar), or "put" followed by a WebDAV folder or S3 bucket address. User and password can go into the address.
+upload-endpoint-invalid = Enter an http(s) address, optionally preceded by "put".
+upload-no-endpoint = Set an upload address in the upload panel first.
+upload-start = Upload Image
+upload-progress = { $percent }%
+upload-cancel = Stop upload
+upload-done = Uploaded; the link is in the clipboard.
+upload-cancelled = Upload stopped.
+upload-history = Uploaded Images
+upload-history-clear = Clear
+upload-copy-link = Copy link
+upload-open-link = Open in browser
+action-zoom-bookmarks = Zoom Bookmarks…
+zoom-bookmarks-title = Zoom Bookmarks
+zoom-bookmarks-hint = Bookmark the region in view to come back to it later. Ctrl and the number of a bookmark show it again; each document keeps up to { $count }.
+zoom-bookmark-name-placeholder = Name, e.g. North gate
+zoom-bookmark-add = Bookmark View
+zoom-bookmark-default = Region { $number }
+zoom-bookmark-details = Ctrl+{ $number } · { $percent }%
+zoom-bookmark-details-page = Ctrl+{ $number } · { $percent }% · page { $page }
+zoom-bookmark-delete = Delete bookmark
+zoom-bookmarks-none = No zoom bookmarks for this document yet.
+zoom-bookmarks-full = This document has { $count } zoom bookmarks already; delete one first.
+dialog-privacy-export = Export Blurred Copies To
+dialog-batch-convert = Convert Images To
+action-edit-macros = Edit Macros…
+action-save-as = Save As… (Ctrl+S)
+dialog-save-as = Save Image As
+dialog-open-file = Open Image or Document
+dialog-filter-supported = Supported Files
+dialog-save-session = Save Session
+dialog-open-session = Open Session
+dialog-filter-session = Noctua Sessions
+dialog-macro-output = Save Macro Results To
+action-sprite-sheet = Slice Sprite Sheet…
+action-adjust-colors = Adjust Colors…
+action-resize = Resize…
+action-clean-scan = Clean Up Scan…
+dialog-sprite-cells = Export Sprite Cells To
+dialog-sprite-atlas = Save Sprite Atlas
+action-export-animation = Animation…
+dialog-export-animation = Save Animation
+dialog-save-frame = Save Frame
+dialog-extract-frames = Extract Frames To
+dialog-export-pages = Export Pages To
 
 
 ## Navigation panel (thumbnails)
 nav-panel-title = Pages
 nav-panel-loading = Loading { $current } / { $total }…
+left-tab-thumbnails = Thumbnails
+left-tab-outline = Outline
+left-tab-bookmarks = Bookmarks
+left-tab-search = Search
+left-page = Page { $page }
+left-outline-empty = This document has no outline
+left-headings-find = Find Headings
+left-headings-progress = Reading page { $done } of { $total }…
+left-headings-stop = Stop
+left-headings-hint = Found in large type on the pages; headings may be missing or misread.
+left-headings-none = No headings found.
+left-headings-stopped = Stopped; the headings of the pages read so far are listed.
+left-headings-failed = Could not read the pages: { $reason }. Text recognition needs tesseract and its language data.
+left-bookmarks-empty = No bookmarked pages
+left-bookmark-add = Bookmark This Page
+left-bookmark-remove = Remove Bookmark
+left-search-placeholder = Search text…
+left-search-count = { $count ->
+    [one] 1 match
+   *[other] { $count } matches
+}
+
+## Find bar
+find-placeholder = Find in document…
+find-previous = Previous match (Shift+Enter)
+find-next = Next match (Enter)
+find-close = Close (Escape)
 
 
 ## Format panel
 format-section-title = Paper Format
 format-section-subtitle = Select paper size for export
 orientation-section-title = Orientation
+format-resize = Resize to Paper Format…
+resolution-section-title = Print Resolution (DPI)
+resolution-section-subtitle = Stored in the exported file, pixels are not resampled
+pages-section-title = Pages
+straighten-section-title = Straighten
+straighten-hint = Turn the image by a fine angle, or draw a line along something that should be level or upright.
+straighten-line-active = Draw a line along a horizon or an edge.
+straighten-angle = Angle: { $degrees }°
+straighten-reset = Reset angle
+straighten-line = Straighten by Line
+straighten-filter = Resampling
+straighten-filter-nearest = Nearest neighbor (fastest)
+straighten-filter-bilinear = Bilinear (smooth)
+straighten-filter-bicubic = Bicubic (sharpest)
+straighten-edges = Corners
+straighten-edges-transparent = Transparent, keep the whole image
+straighten-edges-clamp = Repeat the edges, keep the whole image
+straighten-edges-crop = Crop to the largest rectangle
+straighten-apply = Apply Rotation
+
+## Page range input
+page-range-placeholder = All pages (e.g. 1-5, 8, 11-13, odd)
+page-range-count = { $count } of { $total } pages selected
+page-range-invalid = Cannot read “{ $part }”
+page-range-out-of-bounds = Page { $page } does not exist (1–{ $total })
+page-range-empty = No pages selected
+
+
+## Settings panel
+settings-section-navigation = Folder Navigation
+settings-exclude-patterns = Ignored files
+settings-exclude-placeholder = *.bak, Thumbs.db, .*/
+settings-exclude-hint = Comma-separated patterns. Use * and ? as wildcards, end with / to skip folders.
+settings-apply = Apply
+settings-sort-order = Sort files by
+settings-sort-hint = Order of the files when going to the next or previous one. Files without a capture time come after the others.
+sort-order-name = Name
+sort-order-modified = Date modified
+sort-order-size = File size
+sort-order-capture-date = Date taken
+settings-arrows-turn-pages = Arrow keys turn pages
+settings-rescan = Check folder for new images
+settings-rescan-off = Never
+settings-rescan-seconds = Every { $seconds } seconds
+settings-rescan-minutes = { $minutes ->
+    [one] Every minute
+   *[other] Every { $minutes } minutes
+}
+settings-rescan-hint = Picks up added and removed images without restarting, e.g. for digital signage. If the folder is on a network share that goes away, the last images keep showing.
+settings-low-power = Low-power mode
+low-power-auto = On battery or in power saver mode
+low-power-always = Always
+low-power-never = Never
+settings-low-power-hint = Renders thumbnails and zoomed documents more slowly, checks the folder at most every 5 minutes, stops animation previews and touchpad gliding.
+settings-low-power-active = Low-power mode is on: thumbnails and zoomed documents render more slowly, the folder is checked at most every 5 minutes, animation previews stop and touchpad gliding is off.
+settings-arrows-turn-pages-hint = For foot pedals and page turners that send ← and →: go through the pages of a document, then on to the next file. Page Up/Down, browser back/forward and media track keys always do this.
+settings-section-view = Default Zoom
+settings-view-raster = Images
+settings-view-vector = Vector graphics (SVG)
+settings-view-portable = Documents (PDF)
+settings-integer-zoom = Snap zoom to whole steps
+settings-integer-zoom-hint = Mouse wheel zooms to 100%, 200%, 300%, … or 50%, 33%, 25%, … to avoid shimmer on screenshots.
+settings-fit-sharpening = Sharpen scaled-down images
+settings-fit-sharpening-hint = Large images shown to fit the window are mildly sharpened to make up for the softness of scaling them down. Only the display changes; saved and exported files are not sharpened.
+settings-slide-interval = Show each slide for
+settings-slide-seconds = { $seconds } seconds
+settings-slideshow-loop = Start the slideshow over after the last image
+settings-slideshow-shuffle = Shuffle the slideshow
+settings-slideshow-filter = Show in the slideshow
+settings-slideshow-tags = Tags, separated by commas
+settings-slideshow-filter-hint = Only images with at least this many stars and one of the tags are shown. Ratings and tags are read from XMP sidecars or the XMP metadata in the image, as written by digiKam, darktable or Lightroom.
+settings-cover-screens = Black out other screens in fullscreen
+settings-cover-screens-hint = For presentations: projectors and second monitors show black instead of the desktop while the document is shown fullscreen.
+settings-hide-cursor = Hide the mouse pointer in fullscreen
+settings-canvas-controls = Show touch controls on the image
+settings-canvas-controls-hint = For tablets and convertibles: touching or moving the pointer over the image shows large buttons for previous, next, rotation, zoom and fullscreen for a few seconds.
+settings-canvas-background = Canvas background
+canvas-background-theme = Theme
+canvas-background-checkerboard = Checkerboard
+canvas-background-color = Solid color
+canvas-background-default = Same as other documents
+settings-document-background = Background of { $file }
+settings-document-background-hint = Kept for this file and used again whenever it is opened, e.g. a dark canvas for a diagram drawn in white lines.
+settings-auto-orient = Turn photos upright
+settings-auto-orient-hint = Uses the orientation the camera stored in the photo (EXIF). Edits and saved copies start from the upright image.
+settings-import-xmp = Apply edits from other photo tools
+settings-import-xmp-hint = Shows photos with the orientation and crop stored in XMP sidecars by digiKam, Lightroom and similar tools. The files are not changed.
+settings-confirm-delete = Ask before moving files to the trash
+view-mode-fit = Fit to window
+view-mode-fit-width = Fit width
+view-mode-actual-size = Actual size (100%)
+view-mode-cover = Cover window (crop overflow)
+view-mode-fill = Stretch to window
+view-mode-custom = Custom
+settings-section-magnifier = Magnifier
+settings-magnifier-zoom = Magnification
+settings-magnifier-factor = { $factor }×
+settings-magnifier-crosshair = Show crosshair
+settings-magnifier-hint = Press M to turn the magnifier on or off. The view follows the mouse: moving to an edge of the window shows that edge of the image.
+settings-section-svg-text = SVG Text
+settings-svg-fonts = Fallback fonts
+settings-svg-fonts-placeholder = Noto Sans, DejaVu Sans
+settings-svg-fonts-hint = Comma-separated font families for SVG text whose own fonts are not installed, tried in order.
+settings-svg-font-size = Default font size
+settings-svg-font-size-value = { $size } px
+settings-svg-text-to-path = Copy and save text as outlines
+settings-svg-text-to-path-hint = Copied and saved SVGs then look the same on computers without the fonts, but their text can no longer be edited.
+settings-section-export = Export Profiles
+settings-export-name = Profile name
+settings-export-placeholder = format webp; fit 1920x1080; quality 85
+settings-export-hint = Settings: format png|jpg|webp|tiff|bmp, fit WxH, quality 1-100, dpi N, metadata keep|strip, name template with {"{"}name{"}"}, {"{"}index{"}"}, {"{"}width{"}"}, {"{"}height{"}"}.
+settings-export-invalid = Cannot read "{ $setting }".
+settings-export-add = Add Profile
+settings-section-share = Share Targets
+settings-share-name = Target name
+settings-share-placeholder = upload-image --public {"{"}file{"}"}
+settings-share-hint = A command, with {"{"}file{"}"} for the exported image, or an address the image is uploaded to. The first link it answers with is copied to the clipboard.
+settings-share-add = Add Target
+settings-section-color = Color Management
+settings-color-management = Use monitor color profile
+settings-monitor-profile = Monitor profile
+settings-monitor-profile-auto = Automatic (system)
+settings-monitor-profile-active = Active profile: { $name }
+
+
+## Date shift tool
+date-shift-title = Shift Capture Dates
+date-shift-offset = Time offset
+date-shift-placeholder = -1d 02:30
+date-shift-hint = Added to the EXIF dates of all images in this folder. Examples: +1h, -30m, -1d 02:00:00.
+date-shift-sidecar = Write XMP sidecar files instead of modifying images
+date-shift-apply = Apply to Folder
+date-shift-preview = Preview ({ $count } images)
+date-shift-empty = No images with EXIF dates in this folder.
+date-shift-done = Updated { $count } images.
+date-shift-progress = { $done } of { $total }
+date-shift-confirm-title = Shift Capture Dates?
+date-shift-confirm-file = The capture dates of { $count } images are rewritten in place. This cannot be undone.
+date-shift-confirm-sidecar = XMP sidecar files with the shifted dates are written for { $count } images.
+date-shift-confirm = Shift Dates
+date-shift-cancel = Cancel
+
+
+## Batch tools
+selection-select-all = Select all images
+selection-select-matching = Select matching
+selection-tags = Tags, separated by commas
+rating-any = Any rating
+rating-at-least = { $stars ->
+    [one] 1 star or more
+   *[other] { $stars } stars or more
+}
+
+
+## Geotag editor
+geotag-title = Location
+geotag-location = Coordinates or map link
+geotag-placeholder = 48.8584, 2.2945
+geotag-hint = Paste coordinates, a geo: URI or an OpenStreetMap / Google Maps link.
+geotag-invalid = Not a valid location
+geotag-apply = Set Location
+geotag-clear = Remove Location
+geotag-show-map = Show on Map
+geotag-batch = Apply to Folder Images
+geotag-apply-selected = Set Location on { $count } Selected
+geotag-done = Updated { $count } images.
+geotag-progress = { $done } of { $total }
+geotag-failures = Not updated ({ $count })
+
+
+## Metadata browser
+metadata-browser-title = All Metadata
+metadata-browser-search = Search tags and values
+metadata-browser-reading = Reading metadata…
+metadata-browser-none = This file has no EXIF, XMP or IPTC metadata.
+metadata-browser-no-match = No tags match the search.
+metadata-browser-group = { $group } ({ $count })
+metadata-browser-copy = Copy value
+metadata-group-image = EXIF: Image
+metadata-group-photo = EXIF: Photo
+metadata-group-gps = EXIF: GPS
+metadata-group-interoperability = EXIF: Interoperability
+metadata-group-thumbnail = EXIF: Thumbnail
+metadata-group-xmp = XMP
+metadata-group-xmp-sidecar = XMP Sidecar
+metadata-group-iptc = IPTC
+
+## Metadata editor
+metadata-edit-title = Edit Metadata
+metadata-edit-hint = Stored in the EXIF data of JPEG and PNG files; the image itself is not encoded again. Empty fields are removed.
+metadata-edit-author = Author
+metadata-edit-copyright = Copyright
+metadata-edit-description = Description
+metadata-edit-rating = Rating
+metadata-edit-stars = { $stars ->
+    [one] 1 star
+   *[other] { $stars } stars
+}
+metadata-edit-date = Capture date
+metadata-edit-date-invalid = Enter the date as YYYY:MM:DD HH:MM:SS, or leave it empty.
+metadata-edit-read-only = This file is read-only; its metadata cannot be changed.
+metadata-edit-save = Save to File
+metadata-edit-saved = Metadata saved.
+
+## Privacy export
+privacy-title = Privacy Export
+privacy-hint = Blur faces, license plates or names in copies of the selected images. Draw a rectangle in crop mode and add it as a region; regions are relative to the image size and apply to every selected image.
+privacy-draw-region = Draw Region
+privacy-add-region = Add Region
+privacy-no-regions = No regions yet.
+privacy-clear-regions = Clear Regions
+privacy-images = Images
+privacy-export = Export { $count } Blurred Copies…
+privacy-done = Exported { $count } images.
+privacy-progress = { $done } of { $total }
+
+## Batch conversion
+batch-title = Convert Images
+batch-hint = Writes copies of the selected images, or of the whole folder if none are selected, into a folder you choose. Originals are never modified. Jobs started while one runs wait their turn.
+batch-format = Format
+batch-fit = Largest size
+batch-fit-hint = Width x height the images are scaled down to (never enlarged). Leave empty to keep the size.
+batch-fit-invalid = Enter the size as width x height, e.g. 1920x1080.
+batch-quality = Quality: { $quality }
+batch-strip-metadata = Strip all metadata (camera, location, author, …)
+batch-convert = { $count ->
+    [one] Convert 1 Selected Image…
+   *[other] Convert { $count } Selected Images…
+}
+batch-convert-all = { $count ->
+    [one] Convert 1 Image in the Folder…
+   *[other] Convert All { $count } Images in the Folder…
+}
+batch-progress = { $done } of { $total }
+batch-cancel = Stop conversion
+batch-queued = { $jobs ->
+    [one] 1 more job waiting ({ $images } images)
+   *[other] { $jobs } more jobs waiting ({ $images } images)
+}
+batch-done = { $failed ->
+    [0] Converted { $count } images.
+   *[other] Converted { $count } images; { $failed } failed.
+}
+batch-cancelled = Conversion stopped after { $count } images; waiting jobs were dropped.
+batch-failures = Not converted ({ $count })
+
+
+## Crop dimensions
+crop-geometry-title = Crop Dimensions
+crop-geometry-placeholder = 1920x1080+0+0
+crop-geometry-hint = Enter x,y,width,height (e.g. 10,20,640,480) or ImageMagick geometry WxH+X+Y.
+crop-geometry-preview = { $width } × { $height } px at { $x }, { $y }
+crop-geometry-invalid = Cannot read these dimensions.
+crop-geometry-outside = The region does not fit the image ({ $width } × { $height } px).
+crop-geometry-apply = Set Selection
+crop-geometry-confirm = Press Enter on the canvas to crop, Escape to cancel.
+
+
+## Wallpaper assistant
+wallpaper-title = Set as Wallpaper
+wallpaper-hint = Drag the frame to the part of the image the screen should show, or drag its corners to show less of it.
+wallpaper-ratio = Screen shape
+wallpaper-apply = Set Framed Part
+wallpaper-whole-image = Use Whole Image
+wallpaper-no-frame = The frame lies outside the image.
+wallpaper-unsupported = No desktop accepted the wallpaper.
+wallpaper-failed = Failed to set the wallpaper: { $error }
+
+
+## Edit history
+history-title = History
+history-undo = Undo
+history-redo = Redo
+history-original = Original
+history-rotate-cw = Rotate clockwise
+history-rotate-ccw = Rotate counter-clockwise
+history-flip-horizontal = Flip horizontally
+history-flip-vertical = Flip vertically
+history-rotate-to = Rotate to { $degrees }°
+history-crop = Crop to { $width } × { $height } px at { $x }, { $y }
+history-straighten = Straighten by { $degrees }°
+history-resize = Resize to { $width } × { $height }
+history-clean-scan = Clean up scan (specks up to { $size } px, paper { $percent } % whiter)
+history-hint = Click an entry to return to that state. Edits stay unsaved until you export.
+
+
+## Edit macros
+macro-title = Edit Macros
+macro-hint = Record a sequence of edits once and replay it on other images. Results are written as new files; originals are never modified.
+macro-record = Record Macro
+macro-recording = Recording
+macro-no-steps = Rotate, flip or crop the image, or add steps below.
+macro-step-placeholder = resize 1920w
+macro-add-step = Add Step
+macro-step-hint = Steps: rotate-cw, rotate-ccw, flip-h, flip-v, crop 16:9, crop x%,y%,w%,h%, resize 1920w, resize 1080h, export png|jpg|webp
+macro-name-placeholder = Macro name
+macro-save = Save Macro
+macro-cancel = Discard
+macro-saved = Saved Macros
+macro-none-saved = No saved macros yet.
+macro-run-current = Run on Image…
+macro-run-folder = Run on Folder…
+macro-done = { $name }: wrote { $count } images.
+macro-progress = { $done } of { $total }
+macro-stop = Stop macro
+macro-cancelled = { $name }: stopped after { $count } images.
+
+
+## Sprite sheet slicing
+sprite-title = Slice Sprite Sheet
+sprite-hint = Divide the image into a grid of cells and export them as numbered PNG files, or save a JSON atlas describing the cells of this sheet.
+sprite-by-count = Columns × rows instead of cell size
+sprite-size-placeholder = Cell size, e.g. 32x32
+sprite-count-placeholder = Columns × rows, e.g. 8x4
+sprite-cells = { $count } cells of { $width } × { $height } px
+sprite-invalid = No cell fits this grid.
+sprite-show-grid = Show grid on the image
+sprite-export-cells = Export Cells…
+sprite-export-atlas = Save JSON Atlas…
+sprite-done = Exported { $count } cells.
+sprite-atlas-saved = Atlas saved to { $file }.
+
+
+## Color adjustments
+adjust-title = Adjust Colors
+adjust-hint = The adjustments are shown on top of the image and written into it when saving.
+adjust-unsupported = Only raster images can be adjusted.
+adjust-exposure = Exposure
+adjust-brightness = Brightness
+adjust-contrast = Contrast
+adjust-gamma = Gamma
+adjust-saturation = Saturation
+adjust-reset = Reset
+adjust-reset-all = Reset All
+adjust-bilevel = Black and White
+adjust-threshold = Threshold: { $level }
+adjust-threshold-adaptive = Adaptive to uneven lighting
+adjust-bilevel-hint = For cleaning up scans. Saved as PNG, the image takes one bit per pixel.
+
+
+## Resize
+resize-title = Resize
+resize-unsupported = Only raster images can be resized.
+resize-current = Now { $width } × { $height } pixels
+resize-unit-pixels = Pixels
+resize-unit-percent = Percent
+resize-unit-paper = Paper format
+resize-width = Width
+resize-height = Height
+resize-lock-aspect = Keep aspect ratio
+resize-landscape = Landscape
+resize-portrait = Portrait
+resize-dpi = Resolution (DPI)
+resize-fit-paper = Fit inside the paper (keep aspect ratio)
+resize-filter = Resampling
+resize-filter-nearest = Nearest neighbor (pixel art)
+resize-filter-bilinear = Bilinear (fast)
+resize-filter-lanczos3 = Lanczos3 (sharpest)
+resize-preview = New size: { $width } × { $height } pixels
+resize-too-large = Larger than the limit of { $megapixels } megapixels
+resize-invalid = Enter a size
+resize-apply = Resize
+resize-hint = Resizing can be undone; the file changes only when saved. For a paper format, the resolution is stored in the saved file.
+
+## Scan cleanup
+scan-cleanup-title = Clean Up Scan
+scan-cleanup-unsupported = Only raster images can be cleaned up.
+scan-cleanup-despeckle = Remove specks up to { $size } px
+scan-cleanup-despeckle-hint = Dark dots of at most this many pixels take the paper color. Keep it below the size of periods and i-dots.
+scan-cleanup-whiten = Whiten paper: { $percent } %
+scan-cleanup-whiten-hint = Evens out shadows and yellowed paper toward white while text keeps its contrast.
+scan-cleanup-apply = Clean Up
+scan-cleanup-hint = The cleanup can be undone and recorded in macros; the file changes only when saved.
+
+## Export pages
+export-pages-title = Export Pages
+export-pages-open = Export Pages as Images…
+export-pages-unsupported = Only the pages of PDF documents can be exported as images.
+export-pages-current = Current page ({ $page })
+export-pages-range = Page range
+export-pages-all = All { $count } pages
+export-pages-format = Format
+export-pages-dpi = Resolution (DPI)
+export-pages-invalid-dpi = Enter a resolution from 1 to 1200 DPI.
+export-pages-summary = { $count ->
+    [one] 1 page
+   *[other] { $count } pages
+} will be written as separate images, named after the document and page number.
+export-pages-button = Export…
+export-pages-cancel = Stop Export
+export-pages-done = Exported { $count } pages.
+export-pages-cancelled = Stopped after { $count } pages.
+
+
+## Animation
+animation-title = Animation
+animation-frames = Frames
+animation-playback = Playback
+animation-play = Play
+animation-pause = Pause
+animation-speed = Speed
+animation-step = Step by frames
+animation-step-back = Step back
+animation-step-forward = Step forward
+animation-playback-hint = The frames play on the canvas while this panel is open. Moving the slider or stepping pauses playback.
+animation-playback-too-large = The frames are too large to keep for playback.
+animation-frame-count = { $count } frames
+animation-not-animated = The current image is not animated.
+animation-save-frame = Save Frame…
+animation-extract-frames = Extract All Frames…
+animation-frames-hint = Frames are saved as lossless PNG, each as shown during playback (transparency, disposal and blending applied).
+animation-frame-saved = Frame { $frame } saved.
+animation-frames-done = Extracted { $count } frames.
+animation-gif-title = Animated GIF or WebP
+animation-hint = Assembles numbered frames from the current folder (e.g. walk_01.png, walk_02.png, …) into an animated GIF or WebP, or writes the current GIF again with a new speed and loop count. Frames are scaled to the size of the first one. WebP keeps all colors and transparency, GIF up to 256 colors per frame.
+animation-none = The current image is neither a numbered frame nor a GIF.
+animation-gif = Frames of the current GIF
+animation-sequence = { $count } numbered frames found
+animation-fps = Frames per second
+animation-loops = Repeat count (0 = forever)
+animation-format = Format
+animation-export = Export Animation…
+animation-done = Wrote { $count } frames.
+
+
+## Export profiles
+export-profile = Export Profile
+export-profile-none = Original
+export-profile-none-hint = Keep the original format and size.
+save-as-title = Save As
+save-as-hint = Writes the image with all rotations, flips and crops applied. A profile sets format, size and quality; without one, pick the format here.
+save-as-button = Save As…
+save-as-format = Format
+save-as-quality = Quality: { $quality }
+save-as-overwrite = A file with this name and the new extension already exists. Replace it?
+save-as-replace = Replace
+save-as-cancel = Cancel
+save-as-strip-metadata = Strip all metadata (camera, location, author, …)
+save-as-premultiplied = Premultiplied alpha (for compositing tools that expect it)
+save-as-sidecar-too = Also save the edits as a sidecar next to the original
+save-as-sidecar-hint = A sidecar keeps the original untouched: the edits are stored next to it as JSON and applied again whenever it is opened.
+save-as-sidecar-button = Save Edits as Sidecar
+save-as-advice-working = Analyzing the image…
+save-as-advice-photo = Looks like a photo
+save-as-advice-graphic = Looks like a graphic: flat colors and sharp edges
+save-as-advice-transparent = Has transparent areas
+save-as-advice-animated = Animated image
+save-as-advice-lossy = Suggested: { $format } at quality { $quality }
+save-as-advice-lossless = Suggested: { $format }, lossless
+save-as-advice-keep = Keep the original format: Save As writes a single frame. Export all frames from the Animation panel.
+save-as-advice-size = about { $size }
+save-as-advice-use = Use
+save-as-advice-hint = Sizes are estimated from parts of the image. WebP is saved lossless.
+save-as-read-only = This file or its folder is read-only. Save the edited image under a new name in a writable folder.
+read-only-save-as = The original is read-only; choose another name or folder.
+read-only-sidecar = The folder of this file is read-only, so no sidecar can be saved next to it.
+geotag-read-only = This file is read-only; its location cannot be changed.
+read-only-delete = This file or its folder is read-only, so it cannot be moved to the trash.
+
+## Delete
+delete-title = Move to Trash?
+delete-body = “{ $name }” is moved to the trash and the next file is shown. It can be restored from the trash in the file manager.
+delete-confirm = Move to Trash
+delete-cancel = Cancel
+
+## Sessions
+session-no-files = None of the files of this session could be found.
+
+## Log viewer
+log-title = Log
+log-empty = No warnings or errors so far.
+log-refresh = Refresh
+log-copy = Copy to clipboard
+log-section-levels = Log Levels
+log-levels-hint = Messages of the chosen level and above are written to stderr. The list above always shows warnings and errors.
+log-level = { $level ->
+    [error] Errors
+    [warn] Warnings
+    [info] Information
+    [debug] Debug
+   *[trace] Trace
+}
+log-subsystem-interface = Interface
+log-subsystem-documents = Documents
+log-subsystem-loaders = Loaders
+log-subsystem-cache = Thumbnail cache
+log-subsystem-color = Color management
+log-subsystem-system = Desktop integration
+log-subsystem-rendering = Rendering
@@ -1,11 +1,11 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/collection.rs
+// noctua-core/src/document/collection.rs
 //
 // Document collection for managing multiple documents.
 
 use std::path::PathBuf;
 
-use crate::domain::document::core::content::DocumentContent;
+use crate::document::core::content::DocumentContent;
 
 /// A collection of documents with navigation support.
 ///
@@ -93,6 +93,11 @@ impl DocumentCollection {
         self.current_document = Some(document);
     }
 
+    /// Take the currently loaded document out of the collection.
+    pub fn take_current_document(&mut self) -> Option<DocumentContent> {
+        self.current_document.take()
+    }
+
     /// Clear the currently loaded document.
     pub fn clear_current_document(&mut self) {
         self.current_document = None;
@@ -176,6 +181,34 @@ impl DocumentCollection {
         }
     }
 
+    /// Replace the paths after a folder rescan, keeping the current document.
+    ///
+    /// If the current path is no longer in the list, the path that followed it
+    /// in sort order (or the first one) becomes current and its document needs
+    /// loading. Returns true in that case.
+    pub fn replace_paths(&mut self, paths: Vec<PathBuf>) -> bool {
+        let current = self.current_path().cloned();
+        self.paths = paths;
+
+        let Some(current) = current else {
+            self.current_index = (!self.paths.is_empty()).then_some(0);
+            return false;
+        };
+        if let Some(index) = self.paths.iter().position(|path| *path == current) {
+            self.current_index = Some(index);
+            return false;
+        }
+
+        self.current_document = None;
+        self.current_index = (!self.paths.is_empty()).then(|| {
+            self.paths
+                .iter()
+                .position(|path| *path > current)
+                .unwrap_or(0)
+        });
+        true
+    }
+
     /// Clear the entire collection.
     pub fn clear(&mut self) {
         self.paths.clear();
@@ -274,4 +307,29 @@ mod tests {
         assert_eq!(collection.len(), 2);
         assert_eq!(collection.current_index(), Some(1)); // Now points to c.png
     }
+
+    #[test]
+    fn test_replace_paths() {
+        let paths = vec![PathBuf::from("b.png"), PathBuf::from("d.png")];
+        let mut collection = DocumentCollection::from_paths(paths);
+        collection.goto(1);
+
+        // New file before the current one: same document, new index
+        let paths = vec![
+            PathBuf::from("a.png"),
+            PathBuf::from("b.png"),
+            PathBuf::from("d.png"),
+        ];
+        assert!(!collection.replace_paths(paths));
+        assert_eq!(collection.current_path(), Some(&PathBuf::from("d.png")));
+
+        // Current file removed: the next one in order takes its place
+        let paths = vec![PathBuf::from("a.png"), PathBuf::from("e.png")];
+        assert!(collection.replace_paths(paths));
+        assert_eq!(collection.current_path(), Some(&PathBuf::from("e.png")));
+
+        // ... or the first one if it was the last
+        assert!(collection.replace_paths(vec![PathBuf::from("a.png")]));
+        assert_eq!(collection.current_index(), Some(0));
+    }
 }
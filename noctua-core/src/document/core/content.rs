@@ -0,0 +1,731 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/content.rs
+//
+// Type-erased document content enum.
+
+use std::fmt;
+use std::path::Path;
+
+use super::document::{
+    DocResult, DocumentInfo, FlipDirection, ImageHandle, InterpolationQuality, MultiPage,
+    MultiPageThumbnails, RenderOutput, Renderable, Rotation, RotationMode, Transformable,
+    TransformState,
+};
+use super::cancel::CancelToken;
+use super::page::{OutlineEntry, PageRenderJob, TextHit, TextMatch, ThumbnailJob};
+use crate::document::operations::CropRegion;
+use crate::document::operations::adjust::Adjustments;
+use crate::document::operations::resize::Resize;
+use crate::document::operations::scan_cleanup::ScanCleanup;
+use crate::document::operations::straighten::FineRotation;
+
+use crate::document::types::raster::RasterDocument;
+#[cfg(feature = "vector")]
+use crate::document::types::vector::{VectorDocument, VectorRenderJob};
+#[cfg(feature = "portable")]
+use crate::document::types::portable::{PortableDocument, PortableRenderJob};
+
+// ============================================================================
+// Document Kind
+// ============================================================================
+
+/// Supported document kinds.
+///
+/// Which files belong to which kind is declared by the backends in
+/// `infrastructure::loaders::registry`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentKind {
+    Raster,
+    Vector,
+    Portable,
+}
+
+impl DocumentKind {
+    /// All kinds in display order.
+    pub const ALL: [Self; 3] = [Self::Raster, Self::Vector, Self::Portable];
+
+    /// Stable lowercase name (used as a config key).
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Raster => "raster",
+            Self::Vector => "vector",
+            Self::Portable => "portable",
+        }
+    }
+}
+
+impl fmt::Display for DocumentKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Raster => write!(f, "Raster"),
+            Self::Vector => write!(f, "Vector"),
+            Self::Portable => write!(f, "Portable"),
+        }
+    }
+}
+
+// ============================================================================
+// Document Content Enum
+// ============================================================================
+
+/// Type-erased document content.
+///
+/// The application only holds one document at a time, so the size difference
+/// between variants is acceptable. Boxing would add unnecessary indirection.
+#[allow(clippy::large_enum_variant)]
+pub enum DocumentContent {
+    Raster(RasterDocument),
+    #[cfg(feature = "vector")]
+    Vector(VectorDocument),
+    #[cfg(feature = "portable")]
+    Portable(PortableDocument),
+}
+
+/// Re-render of a document at a zoom, detached from it for a worker thread.
+#[derive(Debug, Clone)]
+pub enum ZoomRenderJob {
+    #[cfg(feature = "vector")]
+    Vector(VectorRenderJob),
+    #[cfg(feature = "portable")]
+    Portable(PortableRenderJob),
+}
+
+impl ZoomRenderJob {
+    /// Raster pixels per document pixel the job renders at.
+    #[must_use]
+    pub fn scale(&self) -> f64 {
+        match *self {
+            #[cfg(feature = "vector")]
+            Self::Vector(ref job) => job.scale,
+            #[cfg(feature = "portable")]
+            Self::Portable(ref job) => job.scale(),
+        }
+    }
+
+    /// Render the document at the zoom.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the raster exceeds the size limits.
+    pub fn run(&self) -> DocResult<image::DynamicImage> {
+        match *self {
+            #[cfg(feature = "vector")]
+            Self::Vector(ref job) => job.run(),
+            #[cfg(feature = "portable")]
+            Self::Portable(ref job) => job.run(),
+        }
+    }
+
+    /// Render the document like [`run`](Self::run), stopping once `token`
+    /// is cancelled (between bands of a large PDF page).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Cancelled`](crate::document::core::cancel::Cancelled) if the
+    /// token was cancelled, or the errors of [`run`](Self::run).
+    pub fn run_cancellable(&self, token: &CancelToken) -> DocResult<image::DynamicImage> {
+        match *self {
+            #[cfg(feature = "vector")]
+            Self::Vector(ref job) => {
+                token.check()?;
+                job.run()
+            }
+            #[cfg(feature = "portable")]
+            Self::Portable(ref job) => job.run_cancellable(token),
+        }
+    }
+}
+
+impl fmt::Debug for DocumentContent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Raster(_) => write!(f, "DocumentContent::Raster(...)"),
+            #[cfg(feature = "vector")]
+            Self::Vector(_) => write!(f, "DocumentContent::Vector(...)"),
+            #[cfg(feature = "portable")]
+            Self::Portable(_) => write!(f, "DocumentContent::Portable(...)"),
+        }
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+impl Renderable for DocumentContent {
+    fn render(&mut self, scale: f64) -> DocResult<RenderOutput> {
+        match self {
+            Self::Raster(doc) => doc.render(scale),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.render(scale),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.render(scale),
+        }
+    }
+
+    fn info(&self) -> DocumentInfo {
+        match self {
+            Self::Raster(doc) => doc.info(),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.info(),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.info(),
+        }
+    }
+}
+
+impl Transformable for DocumentContent {
+    fn rotate(&mut self, rotation: Rotation) {
+        match self {
+            Self::Raster(doc) => doc.rotate(rotation),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.rotate(rotation),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.rotate(rotation),
+        }
+    }
+
+    fn flip(&mut self, direction: FlipDirection) {
+        match self {
+            Self::Raster(doc) => doc.flip(direction),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.flip(direction),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.flip(direction),
+        }
+    }
+
+    fn transform_state(&self) -> TransformState {
+        match self {
+            Self::Raster(doc) => doc.transform_state(),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.transform_state(),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.transform_state(),
+        }
+    }
+
+    fn rotate_fine(&mut self, angle_degrees: f32) {
+        match self {
+            Self::Raster(doc) => doc.rotate_fine(angle_degrees),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.rotate_fine(angle_degrees),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.rotate_fine(angle_degrees),
+        }
+    }
+
+    fn reset_fine_rotation(&mut self) {
+        match self {
+            Self::Raster(doc) => doc.reset_fine_rotation(),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.reset_fine_rotation(),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.reset_fine_rotation(),
+        }
+    }
+
+    fn set_interpolation_quality(&mut self, quality: InterpolationQuality) {
+        match self {
+            Self::Raster(doc) => doc.set_interpolation_quality(quality),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.set_interpolation_quality(quality),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.set_interpolation_quality(quality),
+        }
+    }
+}
+
+// ============================================================================
+// Convenience Methods
+// ============================================================================
+
+impl DocumentContent {
+    /// Rotate document 90 degrees clockwise.
+    pub fn rotate_cw(&mut self) {
+        let new_rotation_mode = self.transform_state().rotation.rotate_cw();
+        match new_rotation_mode {
+            RotationMode::Standard(rot) => self.rotate(rot),
+            RotationMode::Fine(deg) => {
+                let normalized = ((deg / 90.0).round() as i16 * 90) % 360;
+                let rot = match normalized {
+                    0 => Rotation::None,
+                    90 => Rotation::Cw90,
+                    180 => Rotation::Cw180,
+                    270 => Rotation::Cw270,
+                    _ => Rotation::None,
+                };
+                self.rotate(rot);
+            }
+        }
+    }
+
+    /// Rotate document 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&mut self) {
+        let new_rotation_mode = self.transform_state().rotation.rotate_ccw();
+        match new_rotation_mode {
+            RotationMode::Standard(rot) => self.rotate(rot),
+            RotationMode::Fine(deg) => {
+                let normalized = ((deg / 90.0).round() as i16 * 90 + 360) % 360;
+                let rot = match normalized {
+                    0 => Rotation::None,
+                    90 => Rotation::Cw90,
+                    180 => Rotation::Cw180,
+                    270 => Rotation::Cw270,
+                    _ => Rotation::None,
+                };
+                self.rotate(rot);
+            }
+        }
+    }
+
+    /// Flip document horizontally.
+    pub fn flip_horizontal(&mut self) {
+        self.flip(FlipDirection::Horizontal);
+    }
+
+    /// Flip document vertically.
+    pub fn flip_vertical(&mut self) {
+        self.flip(FlipDirection::Vertical);
+    }
+
+    /// Get the document kind.
+    #[must_use]
+    pub fn kind(&self) -> DocumentKind {
+        match self {
+            Self::Raster(_) => DocumentKind::Raster,
+            #[cfg(feature = "vector")]
+            Self::Vector(_) => DocumentKind::Vector,
+            #[cfg(feature = "portable")]
+            Self::Portable(_) => DocumentKind::Portable,
+        }
+    }
+
+    /// Check if document supports multiple pages.
+    #[must_use]
+    pub fn is_multi_page(&self) -> bool {
+        matches!(self, Self::Portable(_))
+    }
+
+    /// Get total page count (returns 1 for single-page documents).
+    #[must_use]
+    pub fn page_count(&self) -> usize {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.page_count(),
+            _ => 1,
+        }
+    }
+
+    /// Get current page index (0 for single-page documents).
+    #[must_use]
+    pub fn current_page(&self) -> usize {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.current_page(),
+            _ => 0,
+        }
+    }
+
+    /// Navigate to a specific page (no-op for single-page documents).
+    pub fn go_to_page(&mut self, page: usize) -> DocResult<()> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.go_to_page(page),
+            _ => Ok(()),
+        }
+    }
+
+    /// Get thumbnail for a specific page (mutable access for trait compatibility).
+    pub fn get_thumbnail(&mut self, page: usize) -> DocResult<Option<ImageHandle>> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.get_thumbnail(page),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get thumbnail handle for a specific page (read-only access).
+    /// Returns None if the thumbnail hasn't been generated yet.
+    #[must_use]
+    pub fn get_thumbnail_handle(&self, page: usize) -> Option<ImageHandle> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.get_thumbnail_handle(page),
+            _ => None,
+        }
+    }
+
+    /// Thumbnail of a page turned and flipped like the page (None if not
+    /// generated yet or single-page).
+    #[must_use]
+    pub fn transformed_thumbnail(&self, page: usize) -> Option<ImageHandle> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.transformed_thumbnail(page),
+            _ => None,
+        }
+    }
+
+    /// Check if thumbnails are ready to be generated.
+    #[must_use]
+    pub fn thumbnails_ready(&self) -> bool {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.thumbnails_ready(),
+            _ => false,
+        }
+    }
+
+    /// Get count of thumbnails currently loaded.
+    #[must_use]
+    pub fn thumbnails_loaded(&self) -> usize {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => PortableDocument::thumbnails_loaded(doc),
+            _ => 0,
+        }
+    }
+
+    /// Check if all thumbnails have been loaded (trait-compliant).
+    #[must_use]
+    pub fn all_thumbnails_loaded(&self) -> bool {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => MultiPageThumbnails::thumbnails_loaded(doc),
+            _ => false,
+        }
+    }
+
+    /// Generate thumbnail for a specific page.
+    pub fn generate_thumbnail_page(&mut self, page: usize) -> DocResult<()> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => MultiPageThumbnails::generate_thumbnail_page(doc, page),
+            _ => Ok(()),
+        }
+    }
+
+    /// Generate all thumbnails.
+    pub fn generate_thumbnails(&mut self) -> DocResult<()> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => MultiPageThumbnails::generate_all_thumbnails(doc),
+            _ => Ok(()),
+        }
+    }
+
+    /// Job rendering `pages` at `dpi` on a worker, for the document kinds
+    /// whose pages can be exported (PDF).
+    #[must_use]
+    pub fn page_render_job(&self, pages: Vec<usize>, dpi: f64) -> Option<PageRenderJob> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => Some(doc.page_render_job(pages, dpi)),
+            _ => None,
+        }
+    }
+
+    /// Job re-rendering the document sharper for a view at `scale` screen
+    /// pixels per pixel, for the kinds that can (SVG, PDF). None if the
+    /// current raster is as sharp as it gets.
+    #[must_use]
+    pub fn zoom_render_job(&self, scale: f64) -> Option<ZoomRenderJob> {
+        match self {
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.zoom_render_job(scale).map(ZoomRenderJob::Vector),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.zoom_render_job(scale).map(ZoomRenderJob::Portable),
+            _ => None,
+        }
+    }
+
+    /// Check if the current page is a quick preview of a large PDF page,
+    /// waiting for its [`refine_job`](Self::refine_job).
+    #[must_use]
+    pub fn is_refining(&self) -> bool {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.is_refining(),
+            _ => false,
+        }
+    }
+
+    /// Job rendering the current page in full while a quick preview of it
+    /// is shown (large PDF pages). None otherwise.
+    #[must_use]
+    pub fn refine_job(&self) -> Option<ZoomRenderJob> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.refine_job().map(ZoomRenderJob::Portable),
+            _ => None,
+        }
+    }
+
+    /// Show the page rendered by a [`refine_job`](Self::refine_job); false
+    /// if the page changed meanwhile and the render was dropped.
+    pub fn finish_refine(&mut self, job: &ZoomRenderJob, rendered: image::DynamicImage) -> bool {
+        match (self, job) {
+            #[cfg(feature = "portable")]
+            (Self::Portable(doc), ZoomRenderJob::Portable(job)) => doc.finish_refine(job, rendered),
+            _ => false,
+        }
+    }
+
+    /// SVG markup of the document as shown, for the kinds that have it (SVG).
+    #[must_use]
+    pub fn svg_markup(&self) -> Option<String> {
+        match self {
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => Some(doc.svg_markup()),
+            _ => None,
+        }
+    }
+
+    /// Render the text of an SVG again after the text options changed;
+    /// nothing for the other kinds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SVG no longer parses.
+    pub fn refresh_svg_text(&mut self) -> DocResult<()> {
+        match self {
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.refresh_text(),
+            _ => Ok(()),
+        }
+    }
+
+    /// Thumbnails still missing, to render on a worker (None if there are none).
+    #[must_use]
+    pub fn thumbnail_job(&self) -> Option<ThumbnailJob> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.thumbnail_job(),
+            _ => None,
+        }
+    }
+
+    /// Add a thumbnail rendered by a [`ThumbnailJob`]; false if the page already had one.
+    pub fn add_thumbnail(&mut self, page: usize, handle: ImageHandle) -> bool {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.add_thumbnail(page, handle),
+            _ => false,
+        }
+    }
+
+    /// Get the current rendered image handle.
+    #[must_use]
+    pub fn handle(&self) -> Option<ImageHandle> {
+        match self {
+            Self::Raster(doc) => Some(doc.handle()),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => Some(doc.handle()),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => Some(doc.handle()),
+        }
+    }
+
+    /// Get the current rendered pixels (after transformations and color
+    /// adjustments).
+    #[must_use]
+    pub fn rendered_image(&self) -> &image::DynamicImage {
+        match self {
+            Self::Raster(doc) => doc.adjusted_image(),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => &doc.rendered,
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => &doc.rendered,
+        }
+    }
+
+    /// Get the embedded ICC color profile, if any (raster images only).
+    #[must_use]
+    pub fn icc_profile(&self) -> Option<&[u8]> {
+        match self {
+            Self::Raster(doc) => doc.icc_profile(),
+            _ => None,
+        }
+    }
+
+    /// Turn raster images upright as their EXIF orientation says.
+    ///
+    /// Returns whether anything changed.
+    pub fn apply_stored_orientation(&mut self) -> bool {
+        match self {
+            Self::Raster(doc) => doc.apply_stored_orientation(),
+            _ => false,
+        }
+    }
+
+    /// EXIF orientation applied on load (1 if none).
+    #[must_use]
+    pub fn applied_orientation(&self) -> u8 {
+        match self {
+            Self::Raster(doc) => doc.applied_orientation(),
+            _ => 1,
+        }
+    }
+
+    /// Check if color adjustments can be shown (raster images only).
+    #[must_use]
+    pub fn supports_adjustments(&self) -> bool {
+        matches!(self, Self::Raster(_))
+    }
+
+    /// Color adjustments shown on top of the image (neutral if unsupported).
+    #[must_use]
+    pub fn adjustments(&self) -> Adjustments {
+        match self {
+            Self::Raster(doc) => doc.adjustments(),
+            _ => Adjustments::default(),
+        }
+    }
+
+    /// Show the image with `adjustments`; ignored for other than raster images.
+    pub fn set_adjustments(&mut self, adjustments: Adjustments) {
+        if let Self::Raster(doc) = self {
+            doc.set_adjustments(adjustments);
+        }
+    }
+
+    /// Get the document outline (table of contents), empty if there is none.
+    #[must_use]
+    pub fn outline(&self) -> &[OutlineEntry] {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.outline(),
+            _ => &[],
+        }
+    }
+
+    /// Search the document text (multi-page documents with a text layer).
+    #[must_use]
+    pub fn search_text(&self, query: &str) -> Vec<TextMatch> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.search_text(query),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Positions of every occurrence of `query` (documents with a text layer).
+    #[must_use]
+    pub fn find_text(&self, query: &str) -> Vec<TextHit> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.find_text(query),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Text inside a region of the rendered page (documents with a text layer).
+    ///
+    /// None if the document has no text layer or the page is rotated or flipped.
+    pub fn selected_text(&mut self, region: CropRegion) -> Option<String> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.selected_text(region),
+            _ => None,
+        }
+    }
+
+    /// Boxes of the selected characters in a region of the rendered page, in pixels.
+    pub fn selected_text_boxes(&mut self, region: CropRegion) -> Vec<CropRegion> {
+        match self {
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.selected_text_boxes(region),
+            _ => Vec::new(),
+        }
+    }
+
+    /// Get current dimensions after transformations.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Self::Raster(doc) => doc.dimensions(),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.dimensions(),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.dimensions(),
+        }
+    }
+
+    /// Crop the document (supported for all types - works on rendered output).
+    pub fn crop(&mut self, x: u32, y: u32, width: u32, height: u32) -> DocResult<()> {
+        match self {
+            Self::Raster(doc) => doc.crop(x, y, width, height).map_err(|e| anyhow::anyhow!(e)),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.crop(x, y, width, height).map_err(|e| anyhow::anyhow!(e)),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.crop(x, y, width, height).map_err(|e| anyhow::anyhow!(e)),
+        }
+    }
+
+    /// Check if the pixels can be rotated by fine angles (raster images only).
+    #[must_use]
+    pub fn supports_fine_rotation(&self) -> bool {
+        matches!(self, Self::Raster(_))
+    }
+
+    /// Rotate by a fine angle, optionally cropping off the empty corners.
+    pub fn straighten(&mut self, rotation: FineRotation) -> DocResult<()> {
+        match self {
+            Self::Raster(doc) => {
+                doc.straighten(rotation);
+                Ok(())
+            }
+            _ => Err(anyhow::anyhow!(
+                "Fine rotation is only supported for raster images"
+            )),
+        }
+    }
+
+    /// Check if the pixels can be resampled to another size (raster images only).
+    #[must_use]
+    pub fn supports_resize(&self) -> bool {
+        matches!(self, Self::Raster(_))
+    }
+
+    /// Resample to a new pixel size.
+    pub fn resize(&mut self, resize: Resize) -> DocResult<()> {
+        match self {
+            Self::Raster(doc) => {
+                doc.resize(resize);
+                Ok(())
+            }
+            _ => Err(anyhow::anyhow!("Resizing is only supported for raster images")),
+        }
+    }
+
+    /// Check if scans can be cleaned up (raster images only).
+    #[must_use]
+    pub fn supports_scan_cleanup(&self) -> bool {
+        matches!(self, Self::Raster(_))
+    }
+
+    /// Remove specks and whiten the paper background.
+    pub fn clean_scan(&mut self, cleanup: ScanCleanup) -> DocResult<()> {
+        match self {
+            Self::Raster(doc) => {
+                doc.clean_scan(cleanup);
+                Ok(())
+            }
+            _ => Err(anyhow::anyhow!("Scan cleanup is only supported for raster images")),
+        }
+    }
+
+    /// Extract document metadata (basic info and EXIF if available).
+    #[must_use]
+    pub fn extract_meta(&self, path: &Path) -> crate::document::core::metadata::DocumentMeta {
+        match self {
+            Self::Raster(doc) => doc.extract_meta(path),
+            #[cfg(feature = "vector")]
+            Self::Vector(doc) => doc.extract_meta(path),
+            #[cfg(feature = "portable")]
+            Self::Portable(doc) => doc.extract_meta(path),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/core/document.rs
+// noctua-core/src/document/core/document.rs
 //
 // Core document traits and abstractions.
 
-use cosmic::widget::image::Handle as ImageHandle;
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 
 // ============================================================================
 // Type Definitions
@@ -12,6 +14,64 @@ use cosmic::widget::image::Handle as ImageHandle;
 /// Result type alias for document operations.
 pub type DocResult<T> = anyhow::Result<T>;
 
+/// Rendered RGBA pixels, ready to be displayed.
+///
+/// Cheap to clone. Every new handle gets a new id, so a viewer can keep
+/// whatever it made from the pixels (e.g. a texture) until the id changes.
+#[derive(Clone)]
+pub struct ImageHandle {
+    id: u64,
+    width: u32,
+    height: u32,
+    pixels: Arc<[u8]>,
+}
+
+impl ImageHandle {
+    /// Wrap RGBA8 pixels, row by row (`width * height * 4` bytes).
+    #[must_use]
+    pub fn from_rgba(width: u32, height: u32, pixels: Vec<u8>) -> Self {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+        Self {
+            id: NEXT_ID.fetch_add(1, Ordering::Relaxed),
+            width,
+            height,
+            pixels: pixels.into(),
+        }
+    }
+
+    /// Identity of the pixels (kept by clones).
+    #[must_use]
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    #[must_use]
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    #[must_use]
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// RGBA8 pixel data.
+    #[must_use]
+    pub fn pixels(&self) -> &[u8] {
+        &self.pixels
+    }
+}
+
+impl fmt::Debug for ImageHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "ImageHandle({}: {}x{})",
+            self.id, self.width, self.height
+        )
+    }
+}
+
 /// Rotation state for documents.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum Rotation {
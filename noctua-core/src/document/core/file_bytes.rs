@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/file_bytes.rs
+//
+// File contents for decoding, memory-mapped for large files.
+
+use std::fs::File;
+use std::io::{self, Read};
+use std::ops::Deref;
+use std::path::Path;
+
+use memmap2::Mmap;
+
+/// Files from this size on are memory-mapped instead of read into a buffer.
+pub const MMAP_THRESHOLD: u64 = 16 * 1024 * 1024;
+
+/// Read-only contents of a file.
+///
+/// Large files (multi-hundred-MB TIFF scans) are memory-mapped: the kernel
+/// pages in only what the decoder touches, and the pages do not count
+/// against the process heap. Small files are read into a buffer.
+pub enum FileBytes {
+    Mapped(Mmap),
+    Buffered(Vec<u8>),
+}
+
+impl FileBytes {
+    /// Open `path`, mapping it if it is at least `MMAP_THRESHOLD` bytes.
+    ///
+    /// Falls back to reading the file if mapping fails, which happens on
+    /// some network and FUSE filesystems.
+    pub fn open(path: &Path) -> io::Result<Self> {
+        Self::open_with_threshold(path, MMAP_THRESHOLD)
+    }
+
+    fn open_with_threshold(path: &Path, threshold: u64) -> io::Result<Self> {
+        let mut file = File::open(path)?;
+        let len = file.metadata()?.len();
+
+        if len >= threshold && len > 0 {
+            // SAFETY: the map is read-only and dropped with the bytes. If
+            // another program truncates the file meanwhile, reads past the new
+            // end fault, like reads of any other viewer mapping the file.
+            match unsafe { Mmap::map(&file) } {
+                Ok(map) => return Ok(Self::Mapped(map)),
+                Err(e) => tracing::debug!("Mapping {} failed, reading it: {e}", path.display()),
+            }
+        }
+
+        let mut bytes = Vec::with_capacity(usize::try_from(len).unwrap_or_default());
+        file.read_to_end(&mut bytes)?;
+        Ok(Self::Buffered(bytes))
+    }
+
+    /// Check if the file is memory-mapped.
+    #[must_use]
+    pub fn is_mapped(&self) -> bool {
+        matches!(self, Self::Mapped(_))
+    }
+}
+
+impl Deref for FileBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            Self::Mapped(map) => map,
+            Self::Buffered(bytes) => bytes,
+        }
+    }
+}
+
+impl AsRef<[u8]> for FileBytes {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open() {
+        let dir = std::env::temp_dir().join("noctua-file-bytes-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("owl.bin");
+        std::fs::write(&path, b"hoot hoot").unwrap();
+
+        let small = FileBytes::open(&path).unwrap();
+        assert!(!small.is_mapped());
+        assert_eq!(&*small, b"hoot hoot");
+
+        let large = FileBytes::open_with_threshold(&path, 4).unwrap();
+        assert!(large.is_mapped());
+        assert_eq!(&*large, b"hoot hoot");
+
+        assert!(FileBytes::open(&dir.join("missing.bin")).is_err());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
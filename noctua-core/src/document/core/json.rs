@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/json.rs
+//
+// Helpers for the JSON written by hand (atlases, catalogs, metadata
+// summaries and benchmark results).
+
+use std::fmt::Write;
+
+/// Escape a string for use inside JSON quotes.
+///
+/// Quotes and backslashes are escaped, other control characters written as
+/// `\u00XX`; everything else is kept as it is.
+#[must_use]
+pub fn escape_json(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if u32::from(c) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", u32::from(c));
+            }
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape_json() {
+        assert_eq!(escape_json("plain é"), "plain é");
+        assert_eq!(escape_json("a \"b\" c:\\d"), "a \\\"b\\\" c:\\\\d");
+        assert_eq!(escape_json("tab\there\nline"), "tab\\u0009here\\u000aline");
+    }
+}
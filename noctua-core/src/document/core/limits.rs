@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/limits.rs
+//
+// Safety limits checked when opening documents.
+
+use super::document::DocResult;
+
+/// Pixels per megapixel.
+const MEGAPIXEL: u64 = 1_000_000;
+
+/// Bytes per pixel a decoder may need at most (RGBA with 32-bit float channels).
+const MAX_BYTES_PER_PIXEL: u64 = 16;
+
+/// Largest raster (in pixels) a document is rendered at for a zoomed-in
+/// view; zooming in further scales that raster up.
+pub const MAX_ZOOM_PIXELS: u64 = 32_000_000;
+
+/// Size limits for opening untrusted files.
+///
+/// Dimensions are checked from the file header before any pixels are
+/// decoded, so a decompression bomb (a tiny PNG claiming 100000×100000
+/// pixels) is rejected with an error instead of exhausting memory.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OpenLimits {
+    /// Largest decoded or rendered image, in pixels (width × height).
+    pub max_pixels: u64,
+    /// Largest side of a PDF page, in points (1/72 inch).
+    pub max_page_size: f64,
+}
+
+impl Default for OpenLimits {
+    fn default() -> Self {
+        Self {
+            // 1 GiB as 8-bit RGBA
+            max_pixels: 268 * MEGAPIXEL,
+            // 200 inches, the largest page size PDF viewers commonly accept
+            max_page_size: 14_400.0,
+        }
+    }
+}
+
+impl OpenLimits {
+    /// Limits from the user-facing units of the settings.
+    #[must_use]
+    pub fn new(max_megapixels: u32, max_page_size: u32) -> Self {
+        Self {
+            max_pixels: u64::from(max_megapixels) * MEGAPIXEL,
+            max_page_size: f64::from(max_page_size),
+        }
+    }
+
+    /// Reject an image larger than `max_pixels`.
+    pub fn check_pixels(&self, width: u32, height: u32) -> DocResult<()> {
+        let pixels = u64::from(width) * u64::from(height);
+        if pixels > self.max_pixels {
+            anyhow::bail!(
+                "Image is too large: {width} × {height} pixels ({} megapixels, limit {})",
+                pixels.div_ceil(MEGAPIXEL),
+                self.max_pixels / MEGAPIXEL
+            );
+        }
+        Ok(())
+    }
+
+    /// Largest raster rendered for a zoomed-in view, in pixels.
+    #[must_use]
+    pub fn max_zoom_pixels(&self) -> u64 {
+        MAX_ZOOM_PIXELS.min(self.max_pixels)
+    }
+
+    /// Reject a PDF page with a side longer than `max_page_size`.
+    pub fn check_page(&self, width: f64, height: f64) -> DocResult<()> {
+        if !(width.is_finite() && height.is_finite()) || width.max(height) > self.max_page_size {
+            anyhow::bail!(
+                "Page is too large: {width:.0} × {height:.0} points (limit {:.0})",
+                self.max_page_size
+            );
+        }
+        Ok(())
+    }
+
+    /// Decoder limits for the `image` crate, which also cap the memory a
+    /// decoder allocates for metadata and intermediate buffers.
+    #[must_use]
+    pub fn decoder_limits(&self) -> image::Limits {
+        let side = u32::try_from(self.max_pixels).unwrap_or(u32::MAX);
+        let mut limits = image::Limits::default();
+        limits.max_image_width = Some(side);
+        limits.max_image_height = Some(side);
+        limits.max_alloc = Some(self.max_pixels.saturating_mul(MAX_BYTES_PER_PIXEL));
+        limits
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_pixels() {
+        let limits = OpenLimits::new(100, 14_400);
+        assert!(limits.check_pixels(10_000, 10_000).is_ok());
+        assert!(limits.check_pixels(10_000, 10_001).is_err());
+        // Decompression bomb: a few kilobytes of PNG claiming ten gigapixels
+        let error = limits.check_pixels(100_000, 100_000).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Image is too large: 100000 × 100000 pixels (10000 megapixels, limit 100)"
+        );
+        assert!(limits.check_pixels(u32::MAX, u32::MAX).is_err());
+    }
+
+    #[test]
+    fn test_check_page() {
+        let limits = OpenLimits::default();
+        assert!(limits.check_page(595.0, 842.0).is_ok());
+        assert!(limits.check_page(14_400.0, 200.0).is_ok());
+        assert!(limits.check_page(200.0, 14_401.0).is_err());
+        assert!(limits.check_page(f64::NAN, 842.0).is_err());
+    }
+}
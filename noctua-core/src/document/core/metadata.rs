@@ -0,0 +1,874 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/metadata.rs
+//
+// Document metadata structures and EXIF parsing.
+
+use std::fmt::Write;
+use std::io::Cursor;
+
+use super::json::escape_json;
+
+/// Minutes per degree for GPS coordinate conversion (DMS to decimal degrees).
+const MINUTES_PER_DEGREE: f64 = 60.0;
+
+/// Seconds per degree for GPS coordinate conversion (DMS to decimal degrees).
+const SECONDS_PER_DEGREE: f64 = 3600.0;
+
+/// Basic document metadata (always available).
+#[derive(Debug, Clone)]
+pub struct BasicMeta {
+    /// File name (without path).
+    pub file_name: String,
+    /// Full file path.
+    pub file_path: String,
+    /// Image format as string (e.g., "PNG", "JPEG", "PDF").
+    pub format: String,
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// File size in bytes.
+    pub file_size: u64,
+    /// Color type description (e.g., "RGBA8", "RGB8", "Grayscale").
+    pub color_type: String,
+}
+
+impl BasicMeta {
+    /// Format file size as human-readable string.
+    pub fn file_size_display(&self) -> String {
+        format_size(self.file_size)
+    }
+
+    /// Format resolution as "W × H".
+    pub fn resolution_display(&self) -> String {
+        format!("{} × {}", self.width, self.height)
+    }
+}
+
+/// Human-readable size of `bytes` (B, KB, MB or GB).
+#[must_use]
+pub fn format_size(bytes: u64) -> String {
+    const KB: u64 = 1024;
+    const MB: u64 = KB * 1024;
+    const GB: u64 = MB * 1024;
+
+    #[allow(clippy::cast_precision_loss)]
+    if bytes >= GB {
+        let size_gb = bytes as f64 / GB as f64;
+        format!("{size_gb:.2} GB")
+    } else if bytes >= MB {
+        let size_mb = bytes as f64 / MB as f64;
+        format!("{size_mb:.2} MB")
+    } else if bytes >= KB {
+        let size_kb = bytes as f64 / KB as f64;
+        format!("{size_kb:.1} KB")
+    } else {
+        format!("{bytes} B")
+    }
+}
+
+/// EXIF metadata (optional, mainly for JPEG/TIFF).
+#[derive(Debug, Clone, Default)]
+pub struct ExifMeta {
+    pub camera_make: Option<String>,
+    pub camera_model: Option<String>,
+    pub date_time: Option<String>,
+    pub exposure_time: Option<String>,
+    pub f_number: Option<String>,
+    pub iso: Option<u32>,
+    pub focal_length: Option<String>,
+    pub gps_latitude: Option<f64>,
+    pub gps_longitude: Option<f64>,
+    /// Altitude in meters, negative below sea level.
+    pub gps_altitude: Option<f64>,
+}
+
+impl ExifMeta {
+    /// Parse EXIF data from raw image bytes.
+    ///
+    /// Extracts camera information, exposure settings, and GPS coordinates
+    /// from JPEG/TIFF EXIF metadata using the kamadak-exif crate.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        use exif::{In, Reader, Tag};
+
+        let cursor = Cursor::new(bytes);
+        let exif_reader = Reader::new();
+        let exif = exif_reader.read_from_container(&mut cursor.clone()).ok()?;
+
+        let mut meta = Self::default();
+
+        // Camera make and model
+        if let Some(field) = exif.get_field(Tag::Make, In::PRIMARY) {
+            meta.camera_make = Some(field.display_value().to_string().trim().to_string());
+        }
+        if let Some(field) = exif.get_field(Tag::Model, In::PRIMARY) {
+            meta.camera_model = Some(field.display_value().to_string().trim().to_string());
+        }
+
+        // Date and time
+        if let Some(field) = exif.get_field(Tag::DateTime, In::PRIMARY) {
+            meta.date_time = Some(field.display_value().to_string());
+        }
+
+        // Exposure time
+        if let Some(field) = exif.get_field(Tag::ExposureTime, In::PRIMARY) {
+            meta.exposure_time = Some(field.display_value().to_string());
+        }
+
+        // F-number (aperture)
+        if let Some(field) = exif.get_field(Tag::FNumber, In::PRIMARY) {
+            meta.f_number = Some(field.display_value().to_string());
+        }
+
+        // ISO speed
+        if let Some(field) = exif.get_field(Tag::PhotographicSensitivity, In::PRIMARY) {
+            if let exif::Value::Short(ref vec) = field.value {
+                if let Some(&iso) = vec.first() {
+                    meta.iso = Some(u32::from(iso));
+                }
+            }
+        }
+
+        // Focal length
+        if let Some(field) = exif.get_field(Tag::FocalLength, In::PRIMARY) {
+            meta.focal_length = Some(field.display_value().to_string());
+        }
+
+        // GPS coordinates
+        meta.gps_latitude = Self::parse_gps_coord(&exif, Tag::GPSLatitude, Tag::GPSLatitudeRef);
+        meta.gps_longitude = Self::parse_gps_coord(&exif, Tag::GPSLongitude, Tag::GPSLongitudeRef);
+        meta.gps_altitude = Self::parse_gps_altitude(&exif);
+
+        Some(meta)
+    }
+
+    /// Parse the GPS altitude (reference 1 means below sea level).
+    fn parse_gps_altitude(exif: &exif::Exif) -> Option<f64> {
+        use exif::{In, Tag, Value};
+
+        let Value::Rational(ref rationals) = exif.get_field(Tag::GPSAltitude, In::PRIMARY)?.value
+        else {
+            return None;
+        };
+        let altitude = rationals.first()?.to_f64();
+        let below_sea_level = exif
+            .get_field(Tag::GPSAltitudeRef, In::PRIMARY)
+            .and_then(|f| f.value.get_uint(0))
+            == Some(1);
+        altitude
+            .is_finite()
+            .then_some(if below_sea_level { -altitude } else { altitude })
+    }
+
+    /// Parse GPS coordinate from EXIF data (converts DMS to decimal degrees).
+    fn parse_gps_coord(exif: &exif::Exif, coord_tag: exif::Tag, ref_tag: exif::Tag) -> Option<f64> {
+        use exif::{In, Value};
+
+        let coord_field = exif.get_field(coord_tag, In::PRIMARY)?;
+        let ref_field = exif.get_field(ref_tag, In::PRIMARY)?;
+
+        // Get reference (N/S for latitude, E/W for longitude)
+        let reference = ref_field.display_value().to_string();
+
+        // Parse DMS (Degrees, Minutes, Seconds) values
+        if let Value::Rational(ref rationals) = coord_field.value {
+            if rationals.len() >= 3 {
+                let degrees = rationals[0].to_f64();
+                let minutes = rationals[1].to_f64();
+                let seconds = rationals[2].to_f64();
+
+                // Convert to decimal degrees
+                let mut decimal =
+                    degrees + (minutes / MINUTES_PER_DEGREE) + (seconds / SECONDS_PER_DEGREE);
+
+                // Apply sign based on hemisphere
+                if reference == "S" || reference == "W" {
+                    decimal = -decimal;
+                }
+
+                return Some(decimal);
+            }
+        }
+
+        None
+    }
+
+    /// Combined camera make and model for display.
+    pub fn camera_display(&self) -> Option<String> {
+        match (&self.camera_make, &self.camera_model) {
+            (Some(make), Some(model)) => {
+                if model.starts_with(make) {
+                    Some(model.clone())
+                } else {
+                    Some(format!("{make} {model}"))
+                }
+            }
+            (Some(make), None) => Some(make.clone()),
+            (None, Some(model)) => Some(model.clone()),
+            (None, None) => None,
+        }
+    }
+
+    /// Format GPS coordinates for display.
+    pub fn gps_display(&self) -> Option<String> {
+        match (self.gps_latitude, self.gps_longitude) {
+            (Some(lat), Some(lon)) => Some(format!("{lat:.5}, {lon:.5}")),
+            _ => None,
+        }
+    }
+
+    /// Format the GPS altitude for display (e.g. "1250 m").
+    pub fn altitude_display(&self) -> Option<String> {
+        self.gps_altitude.map(|altitude| format!("{altitude:.0} m"))
+    }
+
+    /// `geo:` URI of the location (RFC 5870), with the altitude if known,
+    /// for handing to a map application.
+    pub fn geo_uri(&self) -> Option<String> {
+        let (lat, lon) = self.gps_latitude.zip(self.gps_longitude)?;
+        Some(match self.gps_altitude {
+            Some(altitude) => format!("geo:{lat:.6},{lon:.6},{altitude:.1}"),
+            None => format!("geo:{lat:.6},{lon:.6}"),
+        })
+    }
+}
+
+/// Summary of an embedded ICC color profile.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IccProfileInfo {
+    /// Profile description (e.g. "Adobe RGB (1998)").
+    pub description: Option<String>,
+    /// Color space signature (e.g. "RGB", "GRAY", "CMYK").
+    pub color_space: String,
+    /// Profile version (e.g. "4.3").
+    pub version: String,
+    /// Media white point (XYZ), from the `wtpt` tag or the header illuminant.
+    pub white_point: Option<[f64; 3]>,
+    /// Rendering intent declared in the header.
+    pub rendering_intent: RenderingIntent,
+    /// Size of the profile in bytes.
+    pub size: usize,
+}
+
+/// ICC rendering intent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RenderingIntent {
+    Perceptual,
+    RelativeColorimetric,
+    Saturation,
+    AbsoluteColorimetric,
+    Unknown(u32),
+}
+
+impl RenderingIntent {
+    /// Stable name of the intent (e.g. `relative-colorimetric`), for JSON
+    /// and to look up its label; not meant to be shown as it is.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Perceptual => "perceptual",
+            Self::RelativeColorimetric => "relative-colorimetric",
+            Self::Saturation => "saturation",
+            Self::AbsoluteColorimetric => "absolute-colorimetric",
+            Self::Unknown(_) => "unknown",
+        }
+    }
+}
+
+/// Size of the fixed ICC profile header.
+const ICC_HEADER_SIZE: usize = 128;
+
+impl IccProfileInfo {
+    /// Parse the header and descriptive tags of a raw ICC profile.
+    ///
+    /// Returns None if the data is too short or lacks the `acsp` signature.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < ICC_HEADER_SIZE + 4 || data.get(36..40)? != b"acsp" {
+            return None;
+        }
+
+        let color_space = String::from_utf8_lossy(data.get(16..20)?)
+            .trim()
+            .to_string();
+        let version = format!("{}.{}", data[8], data[9] >> 4);
+        let rendering_intent = match read_u32(data, 64)? {
+            0 => RenderingIntent::Perceptual,
+            1 => RenderingIntent::RelativeColorimetric,
+            2 => RenderingIntent::Saturation,
+            3 => RenderingIntent::AbsoluteColorimetric,
+            other => RenderingIntent::Unknown(other),
+        };
+
+        let description = find_tag(data, b"desc").and_then(parse_text_tag);
+        let white_point = find_tag(data, b"wtpt")
+            .and_then(|tag| parse_xyz(tag, 8))
+            .or_else(|| parse_xyz(data, 68));
+
+        Some(Self {
+            description,
+            color_space,
+            version,
+            white_point,
+            rendering_intent,
+            size: data.len(),
+        })
+    }
+
+    /// Format the white point as "X, Y, Z".
+    pub fn white_point_display(&self) -> Option<String> {
+        self.white_point
+            .map(|[x, y, z]| format!("{x:.4}, {y:.4}, {z:.4}"))
+    }
+}
+
+/// Read a big-endian u32 at `offset`.
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    let bytes = data.get(offset..offset + 4)?;
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
+/// Find a tag in the ICC tag table and return its data.
+fn find_tag<'a>(data: &'a [u8], signature: &[u8; 4]) -> Option<&'a [u8]> {
+    let count = read_u32(data, ICC_HEADER_SIZE)? as usize;
+    let max_count = (data.len() - ICC_HEADER_SIZE - 4) / 12;
+    (0..count.min(max_count)).find_map(|i| {
+        let entry = ICC_HEADER_SIZE + 4 + i * 12;
+        if data.get(entry..entry + 4)? != signature {
+            return None;
+        }
+        let offset = read_u32(data, entry + 4)? as usize;
+        let size = read_u32(data, entry + 8)? as usize;
+        data.get(offset..offset.checked_add(size)?)
+    })
+}
+
+/// Parse a `desc` (ICC v2) or `mluc` (ICC v4) text tag.
+fn parse_text_tag(tag: &[u8]) -> Option<String> {
+    let text = match tag.get(0..4)? {
+        b"desc" => {
+            let len = read_u32(tag, 8)? as usize;
+            let ascii = tag.get(12..12 + len)?;
+            String::from_utf8_lossy(ascii).to_string()
+        }
+        b"mluc" => {
+            // First record is used (usually en-US).
+            let len = read_u32(tag, 20)? as usize;
+            let offset = read_u32(tag, 24)? as usize;
+            let utf16: Vec<u16> = tag
+                .get(offset..offset + len)?
+                .chunks_exact(2)
+                .map(|c| u16::from_be_bytes([c[0], c[1]]))
+                .collect();
+            String::from_utf16_lossy(&utf16)
+        }
+        _ => return None,
+    };
+
+    let text = text.trim_end_matches('\0').trim().to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+/// Parse three s15Fixed16 numbers (XYZ) at `offset`.
+fn parse_xyz(data: &[u8], offset: usize) -> Option<[f64; 3]> {
+    let fixed = |o: usize| read_u32(data, o).map(|v| f64::from(v as i32) / 65536.0);
+    Some([fixed(offset)?, fixed(offset + 4)?, fixed(offset + 8)?])
+}
+
+/// Centimeters per inch (for physical size display).
+const CM_PER_INCH: f64 = 2.54;
+
+/// Print resolution declared by the file (JFIF density, EXIF, PNG pHYs).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Resolution {
+    /// Horizontal resolution in dots per inch.
+    pub x_dpi: f64,
+    /// Vertical resolution in dots per inch.
+    pub y_dpi: f64,
+}
+
+impl Resolution {
+    /// Create a resolution with the same DPI on both axes.
+    #[must_use]
+    pub fn uniform(dpi: f64) -> Self {
+        Self {
+            x_dpi: dpi,
+            y_dpi: dpi,
+        }
+    }
+
+    /// Read the declared resolution from raw file bytes.
+    ///
+    /// Checks the PNG `pHYs` chunk, the JPEG JFIF header and finally EXIF
+    /// `XResolution`/`YResolution`.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        Self::from_png(bytes)
+            .or_else(|| Self::from_jfif(bytes))
+            .or_else(|| Self::from_exif(bytes))
+            .filter(|r| r.x_dpi > 0.0 && r.y_dpi > 0.0)
+    }
+
+    /// Physical size in inches for the given pixel dimensions.
+    #[must_use]
+    pub fn physical_size_inches(&self, width: u32, height: u32) -> (f64, f64) {
+        (
+            f64::from(width) / self.x_dpi,
+            f64::from(height) / self.y_dpi,
+        )
+    }
+
+    /// Format the physical size as "W × H cm (W × H in)".
+    #[must_use]
+    pub fn physical_size_display(&self, width: u32, height: u32) -> String {
+        let (w_in, h_in) = self.physical_size_inches(width, height);
+        format!(
+            "{:.1} × {:.1} cm ({w_in:.2} × {h_in:.2} in)",
+            w_in * CM_PER_INCH,
+            h_in * CM_PER_INCH
+        )
+    }
+
+    /// Format the resolution as "300 DPI" (or "300 × 150 DPI").
+    #[must_use]
+    pub fn dpi_display(&self) -> String {
+        if (self.x_dpi - self.y_dpi).abs() < 0.5 {
+            format!("{:.0} DPI", self.x_dpi)
+        } else {
+            format!("{:.0} × {:.0} DPI", self.x_dpi, self.y_dpi)
+        }
+    }
+
+    /// Parse the PNG `pHYs` chunk (pixels per metre).
+    fn from_png(bytes: &[u8]) -> Option<Self> {
+        const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+        if !bytes.starts_with(PNG_SIGNATURE) {
+            return None;
+        }
+
+        let mut pos = PNG_SIGNATURE.len();
+        while let Some(len) = read_u32(bytes, pos) {
+            let len = len as usize;
+            let kind = bytes.get(pos + 4..pos + 8)?;
+            let data = bytes.get(pos + 8..pos + 8 + len)?;
+            match kind {
+                b"pHYs" if len >= 9 && data[8] == 1 => {
+                    let x = f64::from(read_u32(data, 0)?);
+                    let y = f64::from(read_u32(data, 4)?);
+                    return Some(Self {
+                        x_dpi: x * CM_PER_INCH / 100.0,
+                        y_dpi: y * CM_PER_INCH / 100.0,
+                    });
+                }
+                b"IDAT" | b"IEND" => return None,
+                _ => pos += 12 + len,
+            }
+        }
+        None
+    }
+
+    /// Parse the density fields of a JPEG JFIF APP0 segment.
+    fn from_jfif(bytes: &[u8]) -> Option<Self> {
+        if !bytes.starts_with(&[0xFF, 0xD8, 0xFF, 0xE0]) || bytes.get(6..11)? != b"JFIF\0" {
+            return None;
+        }
+
+        let density = |o: usize| {
+            bytes
+                .get(o..o + 2)
+                .map(|b| f64::from(u16::from_be_bytes([b[0], b[1]])))
+        };
+        let (x, y) = (density(14)?, density(16)?);
+        match bytes.get(13)? {
+            1 => Some(Self { x_dpi: x, y_dpi: y }),
+            2 => Some(Self {
+                x_dpi: x * CM_PER_INCH,
+                y_dpi: y * CM_PER_INCH,
+            }),
+            // Aspect ratio only
+            _ => None,
+        }
+    }
+
+    /// Parse EXIF `XResolution`/`YResolution`/`ResolutionUnit`.
+    fn from_exif(bytes: &[u8]) -> Option<Self> {
+        use exif::{In, Reader, Tag, Value};
+
+        let exif = Reader::new()
+            .read_from_container(&mut Cursor::new(bytes))
+            .ok()?;
+        let rational = |tag| match exif.get_field(tag, In::PRIMARY)?.value {
+            Value::Rational(ref v) => v.first().map(exif::Rational::to_f64),
+            _ => None,
+        };
+        let (x, y) = (rational(Tag::XResolution)?, rational(Tag::YResolution)?);
+
+        // 2 = inch (default), 3 = centimeter
+        let per_cm = exif
+            .get_field(Tag::ResolutionUnit, In::PRIMARY)
+            .and_then(|f| f.value.get_uint(0))
+            == Some(3);
+        let factor = if per_cm { CM_PER_INCH } else { 1.0 };
+
+        Some(Self {
+            x_dpi: x * factor,
+            y_dpi: y * factor,
+        })
+    }
+}
+
+/// EXIF orientation of a file that is not stored upright.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StoredOrientation {
+    /// EXIF value (2 to 8).
+    pub exif: u8,
+    /// Whether the image was turned upright on load.
+    pub applied: bool,
+}
+
+impl StoredOrientation {
+    /// What turns the stored pixels upright: whether to mirror them
+    /// horizontally first, then the clockwise rotation in degrees.
+    #[must_use]
+    pub fn correction(&self) -> (bool, u16) {
+        match self.exif {
+            2 => (true, 0),
+            3 => (false, 180),
+            4 => (true, 180),
+            5 => (true, 270),
+            6 => (false, 90),
+            7 => (true, 90),
+            8 => (false, 270),
+            _ => (false, 0),
+        }
+    }
+}
+
+/// Complete document metadata container.
+#[derive(Debug, Clone)]
+pub struct DocumentMeta {
+    pub basic: BasicMeta,
+    pub exif: Option<ExifMeta>,
+    /// Embedded ICC color profile (raster images only).
+    pub icc: Option<IccProfileInfo>,
+    /// Declared print resolution (raster images only).
+    pub resolution: Option<Resolution>,
+    /// Orientation the file was stored in, if not upright (raster images only).
+    pub orientation: Option<StoredOrientation>,
+}
+
+impl DocumentMeta {
+    /// Metadata as `(key, value)` pairs in the order the properties panel
+    /// shows them, for copying; missing values are left out.
+    ///
+    /// `size` is the current pixel size, which differs from the file's after
+    /// rotations, crops or resizing.
+    #[must_use]
+    pub fn summary(&self, (width, height): (u32, u32)) -> Vec<(&'static str, String)> {
+        let basic = &self.basic;
+        let mut fields = vec![
+            ("file_name", basic.file_name.clone()),
+            ("format", basic.format.clone()),
+            ("dimensions", format!("{width} × {height}")),
+        ];
+        if let Some(resolution) = self.resolution {
+            fields.push(("resolution", resolution.dpi_display()));
+            fields.push((
+                "print_size",
+                resolution.physical_size_display(width, height),
+            ));
+        }
+        fields.push(("file_size", basic.file_size_display()));
+        fields.push(("color_type", basic.color_type.clone()));
+        if let Some(orientation) = self.orientation {
+            fields.push(("orientation", orientation.exif.to_string()));
+        }
+        if let Some(exif) = &self.exif {
+            let values = [
+                ("camera", exif.camera_display()),
+                ("date_time", exif.date_time.clone()),
+                ("exposure", exif.exposure_time.clone()),
+                ("aperture", exif.f_number.clone()),
+                ("iso", exif.iso.map(|iso| iso.to_string())),
+                ("focal_length", exif.focal_length.clone()),
+                ("gps", exif.gps_display()),
+                ("altitude", exif.altitude_display()),
+            ];
+            fields.extend(
+                values
+                    .into_iter()
+                    .filter_map(|(key, value)| Some((key, value?))),
+            );
+        }
+        if let Some(icc) = &self.icc {
+            if let Some(description) = &icc.description {
+                fields.push(("color_profile", description.clone()));
+            }
+            fields.push((
+                "color_space",
+                format!("{} (ICC v{})", icc.color_space, icc.version),
+            ));
+            fields.push((
+                "rendering_intent",
+                icc.rendering_intent.as_str().to_string(),
+            ));
+            if let Some(white_point) = icc.white_point_display() {
+                fields.push(("white_point", white_point));
+            }
+        }
+        fields.push(("path", basic.file_path.clone()));
+        fields
+    }
+}
+
+/// JSON object of the `summary` fields, one per line.
+#[must_use]
+pub fn summary_json(fields: &[(&str, String)]) -> String {
+    let mut json = String::from("{");
+    for (i, (key, value)) in fields.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        let _ = write!(json, "{separator}\n  \"{key}\": \"{}\"", escape_json(value));
+    }
+    json.push_str("\n}\n");
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a minimal ICC profile with the given tags.
+    fn icc_profile(tags: &[(&[u8; 4], Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0u8; ICC_HEADER_SIZE];
+        data[8] = 4;
+        data[9] = 0x30;
+        data[16..20].copy_from_slice(b"RGB ");
+        data[36..40].copy_from_slice(b"acsp");
+        data[64..68].copy_from_slice(&1u32.to_be_bytes());
+
+        data.extend_from_slice(&(tags.len() as u32).to_be_bytes());
+        let mut offset = ICC_HEADER_SIZE + 4 + tags.len() * 12;
+        let mut payload = Vec::new();
+        for (sig, tag) in tags {
+            data.extend_from_slice(*sig);
+            data.extend_from_slice(&(offset as u32).to_be_bytes());
+            data.extend_from_slice(&(tag.len() as u32).to_be_bytes());
+            offset += tag.len();
+            payload.extend_from_slice(tag);
+        }
+        data.extend_from_slice(&payload);
+        data
+    }
+
+    #[test]
+    fn test_icc_header() {
+        let info = IccProfileInfo::from_bytes(&icc_profile(&[])).unwrap();
+        assert_eq!(info.color_space, "RGB");
+        assert_eq!(info.version, "4.3");
+        assert_eq!(info.rendering_intent, RenderingIntent::RelativeColorimetric);
+        assert_eq!(info.description, None);
+
+        assert!(IccProfileInfo::from_bytes(&[0u8; 64]).is_none());
+    }
+
+    #[test]
+    fn test_icc_desc_and_wtpt() {
+        let mut desc = b"desc\0\0\0\0".to_vec();
+        desc.extend_from_slice(&5u32.to_be_bytes());
+        desc.extend_from_slice(b"sRGB\0");
+
+        let mut wtpt = b"XYZ \0\0\0\0".to_vec();
+        for v in [0.9642_f64, 1.0, 0.8249] {
+            wtpt.extend_from_slice(&((v * 65536.0).round() as i32).to_be_bytes());
+        }
+
+        let info =
+            IccProfileInfo::from_bytes(&icc_profile(&[(b"desc", desc), (b"wtpt", wtpt)])).unwrap();
+        assert_eq!(info.description.as_deref(), Some("sRGB"));
+        assert_eq!(
+            info.white_point_display().as_deref(),
+            Some("0.9642, 1.0000, 0.8249")
+        );
+    }
+
+    #[test]
+    fn test_icc_mluc() {
+        let text: Vec<u8> = "Display P3"
+            .encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect();
+        let mut mluc = b"mluc\0\0\0\0".to_vec();
+        mluc.extend_from_slice(&1u32.to_be_bytes());
+        mluc.extend_from_slice(&12u32.to_be_bytes());
+        mluc.extend_from_slice(b"enUS");
+        mluc.extend_from_slice(&(text.len() as u32).to_be_bytes());
+        mluc.extend_from_slice(&28u32.to_be_bytes());
+        mluc.extend_from_slice(&text);
+
+        let info = IccProfileInfo::from_bytes(&icc_profile(&[(b"desc", mluc)])).unwrap();
+        assert_eq!(info.description.as_deref(), Some("Display P3"));
+    }
+
+    #[test]
+    fn test_resolution_png_phys() {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        // IHDR (contents irrelevant here)
+        png.extend_from_slice(&13u32.to_be_bytes());
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&[0; 13 + 4]);
+        // pHYs: 11811 px/m ≈ 300 DPI
+        png.extend_from_slice(&9u32.to_be_bytes());
+        png.extend_from_slice(b"pHYs");
+        png.extend_from_slice(&11811u32.to_be_bytes());
+        png.extend_from_slice(&11811u32.to_be_bytes());
+        png.extend_from_slice(&[1, 0, 0, 0, 0]);
+
+        let res = Resolution::from_bytes(&png).unwrap();
+        assert!((res.x_dpi - 300.0).abs() < 0.1);
+        assert_eq!(res.dpi_display(), "300 DPI");
+    }
+
+    #[test]
+    fn test_resolution_jfif() {
+        let mut jpeg = vec![0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+        jpeg.extend_from_slice(b"JFIF\0");
+        jpeg.extend_from_slice(&[1, 1, 1]); // version 1.1, unit = DPI
+        jpeg.extend_from_slice(&300u16.to_be_bytes());
+        jpeg.extend_from_slice(&150u16.to_be_bytes());
+
+        let res = Resolution::from_bytes(&jpeg).unwrap();
+        assert_eq!(
+            res,
+            Resolution {
+                x_dpi: 300.0,
+                y_dpi: 150.0
+            }
+        );
+
+        // Aspect-ratio-only density carries no physical size
+        jpeg[13] = 0;
+        assert_eq!(Resolution::from_jfif(&jpeg), None);
+    }
+
+    #[test]
+    fn test_physical_size_display() {
+        let res = Resolution::uniform(300.0);
+        assert_eq!(
+            res.physical_size_display(2480, 3508),
+            "21.0 × 29.7 cm (8.27 × 11.69 in)"
+        );
+    }
+
+    #[test]
+    fn test_summary() {
+        let meta = DocumentMeta {
+            basic: BasicMeta {
+                file_name: "cat \"1\".jpg".to_string(),
+                file_path: "/photos/cat \"1\".jpg".to_string(),
+                format: "JPEG".to_string(),
+                width: 4000,
+                height: 3000,
+                file_size: 2048,
+                color_type: "RGB8".to_string(),
+            },
+            exif: Some(ExifMeta {
+                camera_make: Some("Canon".to_string()),
+                camera_model: Some("Canon EOS R6".to_string()),
+                iso: Some(400),
+                ..ExifMeta::default()
+            }),
+            icc: None,
+            resolution: None,
+            orientation: None,
+        };
+
+        // Turned since opening
+        let fields = meta.summary((3000, 4000));
+        let keys: Vec<&str> = fields.iter().map(|(key, _)| *key).collect();
+        assert_eq!(
+            keys,
+            [
+                "file_name",
+                "format",
+                "dimensions",
+                "file_size",
+                "color_type",
+                "camera",
+                "iso",
+                "path"
+            ]
+        );
+        assert_eq!(fields[2].1, "3000 × 4000");
+        assert_eq!(fields[5].1, "Canon EOS R6");
+
+        let json = summary_json(&fields);
+        assert!(json.starts_with("{\n  \"file_name\": \"cat \\\"1\\\".jpg\",\n"));
+        assert!(json.contains("\n  \"iso\": \"400\",\n"));
+        assert!(json.ends_with("\"\n}\n"));
+        assert_eq!(summary_json(&[]), "{\n}\n");
+    }
+
+    #[test]
+    fn test_gps_from_exif() {
+        use crate::document::operations::geotag::GpsCoordinate;
+        use exif::experimental::Writer;
+        use exif::{Field, In, Rational, Tag, Value};
+
+        let tiff = |altitude: Option<(u32, u8)>| {
+            let mut fields = GpsCoordinate::new(-33.856_785, 151.215_3)
+                .unwrap()
+                .to_exif_fields();
+            if let Some((meters, reference)) = altitude {
+                let field = |tag, value| Field {
+                    tag,
+                    ifd_num: In::PRIMARY,
+                    value,
+                };
+                fields.push(field(Tag::GPSAltitudeRef, Value::Byte(vec![reference])));
+                fields.push(field(
+                    Tag::GPSAltitude,
+                    Value::Rational(vec![Rational::from((meters * 10, 10))]),
+                ));
+            }
+            let mut writer = Writer::new();
+            for field in &fields {
+                writer.push_field(field);
+            }
+            let mut out = Cursor::new(Vec::new());
+            writer.write(&mut out, false).unwrap();
+            out.into_inner()
+        };
+
+        let exif = ExifMeta::from_bytes(&tiff(Some((412, 0)))).unwrap();
+        assert_eq!(exif.gps_display().as_deref(), Some("-33.85679, 151.21530"));
+        assert_eq!(exif.altitude_display().as_deref(), Some("412 m"));
+        assert_eq!(
+            exif.geo_uri().as_deref(),
+            Some("geo:-33.856785,151.215300,412.0")
+        );
+
+        // Dead Sea shore
+        let exif = ExifMeta::from_bytes(&tiff(Some((430, 1)))).unwrap();
+        assert_eq!(exif.gps_altitude, Some(-430.0));
+
+        let exif = ExifMeta::from_bytes(&tiff(None)).unwrap();
+        assert_eq!(exif.gps_altitude, None);
+        assert_eq!(exif.geo_uri().as_deref(), Some("geo:-33.856785,151.215300"));
+        assert_eq!(ExifMeta::default().geo_uri(), None);
+    }
+
+    #[test]
+    fn test_orientation_correction() {
+        let correction = |exif| {
+            StoredOrientation {
+                exif,
+                applied: true,
+            }
+            .correction()
+        };
+        // Portrait photo from a camera held clockwise / counter-clockwise
+        assert_eq!(correction(6), (false, 90));
+        assert_eq!(correction(8), (false, 270));
+        // Flipped vertically = mirrored and turned upside down
+        assert_eq!(correction(4), (true, 180));
+        assert_eq!(correction(5), (true, 270));
+    }
+}
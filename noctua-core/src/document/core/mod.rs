@@ -1,12 +1,19 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/core/mod.rs
+// noctua-core/src/document/core/mod.rs
 //
 // Core document abstractions: traits, types, and metadata.
 
+pub mod cancel;
 pub mod content;
 pub mod document;
+pub mod file_bytes;
+pub mod json;
+pub mod limits;
 pub mod metadata;
 pub mod page;
+pub mod svg_text;
+pub mod temp_file;
+pub mod text_layer;
 
 // Re-export commonly used types
 pub use content::DocumentContent;
@@ -0,0 +1,530 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/page.rs
+//
+// Page abstraction for multi-page documents.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::ops::Range;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+
+use image::DynamicImage;
+
+use super::document::{DocResult, ImageHandle, TransformState};
+use super::limits::OpenLimits;
+use crate::document::operations::CropRegion;
+#[cfg(feature = "portable")]
+use crate::document::types::portable::PortableDocument;
+
+/// Represents a single page in a multi-page document.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct Page {
+    /// Page index (0-based).
+    pub index: usize,
+    /// Page width in pixels.
+    pub width: u32,
+    /// Page height in pixels.
+    pub height: u32,
+    /// Optional thumbnail handle.
+    pub thumbnail: Option<ImageHandle>,
+}
+
+impl Page {
+    /// Create a new page.
+    #[must_use]
+    pub fn new(index: usize, width: u32, height: u32) -> Self {
+        Self {
+            index,
+            width,
+            height,
+            thumbnail: None,
+        }
+    }
+
+    /// Create a page with a thumbnail.
+    #[must_use]
+    pub fn with_thumbnail(index: usize, width: u32, height: u32, thumbnail: ImageHandle) -> Self {
+        Self {
+            index,
+            width,
+            height,
+            thumbnail: Some(thumbnail),
+        }
+    }
+
+    /// Set the thumbnail for this page.
+    pub fn set_thumbnail(&mut self, thumbnail: ImageHandle) {
+        self.thumbnail = Some(thumbnail);
+    }
+
+    /// Check if this page has a thumbnail.
+    #[must_use]
+    pub fn has_thumbnail(&self) -> bool {
+        self.thumbnail.is_some()
+    }
+
+    /// Get the aspect ratio of the page.
+    #[must_use]
+    pub fn aspect_ratio(&self) -> f32 {
+        if self.height == 0 {
+            1.0
+        } else {
+            #[allow(clippy::cast_precision_loss)]
+            {
+                self.width as f32 / self.height as f32
+            }
+        }
+    }
+
+    /// Get page dimensions as a tuple.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+}
+
+/// Thumbnails of a document still to be rendered.
+///
+/// The job holds no document, so it can be run on a worker thread: it
+/// opens the file again there and renders the pages its queue hands out.
+#[derive(Debug, Clone)]
+pub struct ThumbnailJob {
+    /// File of the document.
+    pub path: PathBuf,
+    /// Page indices to render (0-based), the ones in view first.
+    pub queue: ThumbnailQueue,
+}
+
+impl ThumbnailJob {
+    /// Render the thumbnails, handing each to `deliver` with its page index.
+    ///
+    /// Stops early when `deliver` returns false (the receiver is gone).
+    pub fn run(&self, deliver: impl FnMut(usize, ImageHandle) -> bool) -> DocResult<()> {
+        self.run_cached(|_| None, deliver)
+    }
+
+    /// Like [`run`](Self::run), taking the thumbnails `cached` returns
+    /// instead of rendering them. The document is only opened if a page
+    /// is not cached.
+    pub fn run_cached(
+        &self,
+        cached: impl FnMut(usize) -> Option<ImageHandle>,
+        deliver: impl FnMut(usize, ImageHandle) -> bool,
+    ) -> DocResult<()> {
+        #[cfg(feature = "portable")]
+        return PortableDocument::render_thumbnails(self, cached, deliver);
+
+        #[cfg(not(feature = "portable"))]
+        {
+            let _ = (cached, deliver);
+            Ok(())
+        }
+    }
+}
+
+/// Pages waiting for their thumbnail, handed out nearest to the visible
+/// ones first.
+///
+/// Clones share the queue: the worker takes pages from one while the UI
+/// moves the visible range of another as the thumbnails are scrolled.
+#[derive(Debug, Clone, Default)]
+pub struct ThumbnailQueue(Arc<Mutex<QueueState>>);
+
+#[derive(Debug, Default)]
+struct QueueState {
+    pending: BTreeSet<usize>,
+    visible: Range<usize>,
+}
+
+impl ThumbnailQueue {
+    /// Queue of `pages`; until a visible range is set they are handed out
+    /// in order.
+    #[must_use]
+    pub fn new(pages: impl IntoIterator<Item = usize>) -> Self {
+        Self(Arc::new(Mutex::new(QueueState {
+            pending: pages.into_iter().collect(),
+            visible: 0..0,
+        })))
+    }
+
+    /// Move the visible range, so its pages come next.
+    pub fn set_visible(&self, pages: Range<usize>) {
+        self.lock().visible = pages;
+    }
+
+    /// Take the most urgent page: the first visible one, else the nearest
+    /// to the visible range (below it on a tie, as thumbnails are mostly
+    /// scrolled down). None once the queue is empty.
+    pub fn pop(&self) -> Option<usize> {
+        let mut state = self.lock();
+        let Range { start, end } = state.visible;
+        let below = state.pending.range(start..).next().copied();
+        let above = state.pending.range(..start).next_back().copied();
+        let page = match (below, above) {
+            (Some(below), Some(above)) => {
+                if (below + 1).saturating_sub(end) <= start - above {
+                    below
+                } else {
+                    above
+                }
+            }
+            (page, None) | (None, page) => page?,
+        };
+        state.pending.remove(&page);
+        Some(page)
+    }
+
+    /// Number of pages still waiting.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().pending.len()
+    }
+
+    /// Check if no page is waiting.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, QueueState> {
+        self.0.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Pages of a document to render as images at a print resolution, e.g. to
+/// export them.
+///
+/// Like a [`ThumbnailJob`] it holds no document and opens the file again
+/// on the thread running it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PageRenderJob {
+    /// File of the document.
+    pub path: PathBuf,
+    /// Page indices to render (0-based), in this order.
+    pub pages: Vec<usize>,
+    /// Rotation and flips of each page, applied to its image.
+    pub transforms: Vec<TransformState>,
+    /// Resolution in dots per inch (72 renders one pixel per PDF point).
+    pub dpi: f64,
+}
+
+impl PageRenderJob {
+    /// Render the pages, handing each image to `deliver` with its page index.
+    ///
+    /// Pages rendering to more pixels than `limits` allow fail the job.
+    /// Stops early when `deliver` returns false (e.g. the export was cancelled).
+    pub fn run(
+        &self,
+        limits: &OpenLimits,
+        deliver: impl FnMut(usize, DynamicImage) -> bool,
+    ) -> DocResult<()> {
+        #[cfg(feature = "portable")]
+        return PortableDocument::render_pages(self, limits, deliver);
+
+        #[cfg(not(feature = "portable"))]
+        {
+            let _ = (limits, deliver);
+            anyhow::bail!("Rendering pages needs the portable feature")
+        }
+    }
+}
+
+/// Entry of a document outline (table of contents).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutlineEntry {
+    /// Entry title.
+    pub title: String,
+    /// Target page index (0-based).
+    pub page: usize,
+    /// Nesting level (0 = top level).
+    pub level: usize,
+}
+
+/// A text search hit within a document.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextMatch {
+    /// Page index (0-based).
+    pub page: usize,
+    /// Surrounding text for the result list.
+    pub snippet: String,
+}
+
+/// Position of a text search hit, for highlighting it on the page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextHit {
+    /// Page index (0-based).
+    pub page: usize,
+    /// Boxes in pixels of the upright rendered page, one per line the hit spans.
+    pub boxes: Vec<CropRegion>,
+}
+
+/// Characters of context shown on each side of a search hit.
+const SNIPPET_CONTEXT: usize = 30;
+
+/// Find all case-insensitive occurrences of `query` in the given page texts.
+#[must_use]
+pub fn find_in_pages<'a, I>(pages: I, query: &str) -> Vec<TextMatch>
+where
+    I: IntoIterator<Item = (usize, &'a str)>,
+{
+    let query: Vec<char> = query.trim().to_lowercase().chars().collect();
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let mut matches = Vec::new();
+    for (page, text) in pages {
+        // Compare per char so snippet positions stay valid for any script.
+        let chars: Vec<char> = text.chars().collect();
+        let lower: Vec<char> = chars
+            .iter()
+            .map(|c| c.to_lowercase().next().unwrap_or(*c))
+            .collect();
+
+        let mut pos = 0;
+        while pos + query.len() <= lower.len() {
+            if lower[pos..pos + query.len()] == query[..] {
+                let start = pos.saturating_sub(SNIPPET_CONTEXT);
+                let end = (pos + query.len() + SNIPPET_CONTEXT).min(chars.len());
+                let snippet: String = chars[start..end].iter().collect();
+                let snippet = snippet.split_whitespace().collect::<Vec<_>>().join(" ");
+                matches.push(TextMatch {
+                    page,
+                    snippet: format!(
+                        "{}{snippet}{}",
+                        if start > 0 { "…" } else { "" },
+                        if end < chars.len() { "…" } else { "" }
+                    ),
+                });
+                pos += query.len();
+            } else {
+                pos += 1;
+            }
+        }
+    }
+    matches
+}
+
+/// Invalid page range expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageRangeError {
+    /// A part could not be parsed (e.g. `3-x` or `5-2`).
+    Invalid(String),
+    /// A page number is outside `1..=page_count` (1-based).
+    OutOfBounds(usize),
+    /// The expression selects no pages.
+    NoPages,
+}
+
+impl fmt::Display for PageRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Invalid(part) => write!(f, "Invalid page range: {part}"),
+            Self::OutOfBounds(page) => write!(f, "Page {page} does not exist"),
+            Self::NoPages => write!(f, "No pages selected"),
+        }
+    }
+}
+
+impl std::error::Error for PageRangeError {}
+
+/// A set of pages selected for printing, export or extraction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRange {
+    /// Selected page indices (0-based, sorted, unique).
+    pages: Vec<usize>,
+}
+
+impl PageRange {
+    /// Select every page of a document.
+    #[must_use]
+    pub fn all(page_count: usize) -> Self {
+        Self {
+            pages: (0..page_count).collect(),
+        }
+    }
+
+    /// Parse a range expression with 1-based page numbers.
+    ///
+    /// Parts are separated by commas: single pages (`8`), ranges (`1-5`),
+    /// open ranges (`11-`, `-3`) and the keywords `odd`, `even` and `all`.
+    /// An empty expression selects all pages.
+    pub fn parse(input: &str, page_count: usize) -> Result<Self, PageRangeError> {
+        if input.trim().is_empty() {
+            return Self::all(page_count).non_empty();
+        }
+
+        let page_number = |value: &str, part: &str| -> Result<usize, PageRangeError> {
+            let page: usize = value
+                .trim()
+                .parse()
+                .map_err(|_| PageRangeError::Invalid(part.to_string()))?;
+            if page == 0 || page > page_count {
+                return Err(PageRangeError::OutOfBounds(page));
+            }
+            Ok(page)
+        };
+
+        let mut pages = Vec::new();
+        for part in input.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+            match part.to_lowercase().as_str() {
+                "all" => pages.extend(0..page_count),
+                // 1-based odd pages are the even indices.
+                "odd" => pages.extend((0..page_count).step_by(2)),
+                "even" => pages.extend((1..page_count).step_by(2)),
+                _ => {
+                    let bound = |value: &str, default: usize| {
+                        if value.trim().is_empty() {
+                            Ok(default)
+                        } else {
+                            page_number(value, part)
+                        }
+                    };
+                    let (first, last) = match part.split_once('-') {
+                        Some((first, last)) => (bound(first, 1)?, bound(last, page_count)?),
+                        None => {
+                            let page = page_number(part, part)?;
+                            (page, page)
+                        }
+                    };
+                    if first > last {
+                        return Err(PageRangeError::Invalid(part.to_string()));
+                    }
+                    pages.extend(first - 1..last);
+                }
+            }
+        }
+
+        pages.sort_unstable();
+        pages.dedup();
+        Self { pages }.non_empty()
+    }
+
+    /// Selected page indices (0-based, ascending).
+    #[must_use]
+    pub fn pages(&self) -> &[usize] {
+        &self.pages
+    }
+
+    /// Number of selected pages.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pages.len()
+    }
+
+    /// Check if no page is selected.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pages.is_empty()
+    }
+
+    /// Check if a page index (0-based) is selected.
+    #[must_use]
+    pub fn contains(&self, page: usize) -> bool {
+        self.pages.binary_search(&page).is_ok()
+    }
+
+    fn non_empty(self) -> Result<Self, PageRangeError> {
+        if self.pages.is_empty() {
+            Err(PageRangeError::NoPages)
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thumbnail_queue_follows_visible_range() {
+        let queue = ThumbnailQueue::new(0..10);
+        assert_eq!(queue.pop(), Some(0));
+        assert_eq!(queue.pop(), Some(1));
+
+        // Scrolled to pages 5 and 6: those first, then outwards
+        queue.set_visible(5..7);
+        let order: Vec<_> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(order, [5, 6, 7, 4, 8, 3, 9, 2]);
+        assert!(queue.is_empty());
+
+        let clone = ThumbnailQueue::new([3, 8]);
+        let shared = clone.clone();
+        shared.set_visible(8..9);
+        assert_eq!(clone.pop(), Some(8));
+        assert_eq!(shared.len(), 1);
+    }
+
+    #[test]
+    fn test_find_in_pages() {
+        let pages = [
+            (0, "Introduction to Owls"),
+            (1, "The owl\nhunts at night. OWL!"),
+        ];
+        let matches = find_in_pages(pages, "owl");
+
+        assert_eq!(matches.len(), 3);
+        assert_eq!(matches[0].page, 0);
+        assert_eq!(matches[1].snippet, "The owl hunts at night. OWL!");
+        assert_eq!(matches[2].page, 1);
+        assert!(find_in_pages(pages, "  ").is_empty());
+    }
+
+    #[test]
+    fn test_find_snippet_is_trimmed() {
+        let text = format!("{}needle{}", "a".repeat(100), "b".repeat(100));
+        let matches = find_in_pages([(3, text.as_str())], "NEEDLE");
+
+        assert_eq!(matches.len(), 1);
+        assert!(matches[0].snippet.starts_with('…'));
+        assert!(matches[0].snippet.ends_with('…'));
+        assert_eq!(
+            matches[0].snippet.chars().count(),
+            2 + 6 + 2 * SNIPPET_CONTEXT
+        );
+    }
+
+    #[test]
+    fn test_page_range_parse() {
+        let range = PageRange::parse("1-5, 8, 11-13", 20).unwrap();
+        assert_eq!(range.pages(), &[0, 1, 2, 3, 4, 7, 10, 11, 12]);
+        assert_eq!(range.len(), 9);
+        assert!(range.contains(7));
+        assert!(!range.contains(5));
+
+        // Overlaps are merged, open ranges run to the document bounds
+        assert_eq!(PageRange::parse("3-, 4", 5).unwrap().pages(), &[2, 3, 4]);
+        assert_eq!(PageRange::parse("-2", 5).unwrap().pages(), &[0, 1]);
+        assert_eq!(PageRange::parse("", 3).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_page_range_odd_even() {
+        assert_eq!(PageRange::parse("odd", 5).unwrap().pages(), &[0, 2, 4]);
+        assert_eq!(PageRange::parse("Even", 5).unwrap().pages(), &[1, 3]);
+        assert_eq!(PageRange::parse("even", 1), Err(PageRangeError::NoPages));
+    }
+
+    #[test]
+    fn test_page_range_errors() {
+        assert_eq!(
+            PageRange::parse("1-x", 10),
+            Err(PageRangeError::Invalid("1-x".to_string()))
+        );
+        assert_eq!(
+            PageRange::parse("5-2", 10),
+            Err(PageRangeError::Invalid("5-2".to_string()))
+        );
+        assert_eq!(
+            PageRange::parse("0", 10),
+            Err(PageRangeError::OutOfBounds(0))
+        );
+        assert_eq!(
+            PageRange::parse("4, 12", 10),
+            Err(PageRangeError::OutOfBounds(12))
+        );
+    }
+}
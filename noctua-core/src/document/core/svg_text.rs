@@ -0,0 +1,79 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/svg_text.rs
+//
+// How text in SVG documents is rendered and written.
+
+use std::sync::RwLock;
+
+/// Font size of SVG text that sets none, in user units (as in browsers).
+pub const DEFAULT_FONT_SIZE: f32 = 12.0;
+
+/// Text options for SVG documents.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SvgTextOptions {
+    /// Families tried in order when none of those a text asks for is
+    /// installed, before the default serif.
+    pub fallback_families: Vec<String>,
+    /// Write text as outlines in copied and saved SVG markup, so it looks
+    /// the same without the fonts.
+    pub text_to_path: bool,
+    /// Font size of text that sets none, in user units.
+    pub font_size: f32,
+}
+
+impl Default for SvgTextOptions {
+    fn default() -> Self {
+        Self {
+            fallback_families: Vec::new(),
+            text_to_path: false,
+            font_size: DEFAULT_FONT_SIZE,
+        }
+    }
+}
+
+impl SvgTextOptions {
+    /// Parse a comma-separated list of font families, dropping empty entries.
+    #[must_use]
+    pub fn parse_families(input: &str) -> Vec<String> {
+        input
+            .split(',')
+            .map(str::trim)
+            .filter(|family| !family.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+}
+
+/// Options SVG documents are opened with (None = the defaults).
+static TEXT_OPTIONS: RwLock<Option<SvgTextOptions>> = RwLock::new(None);
+
+/// Open SVG documents with `options` from now on.
+pub fn set_svg_text_options(options: SvgTextOptions) {
+    if let Ok(mut text_options) = TEXT_OPTIONS.write() {
+        *text_options = Some(options);
+    }
+}
+
+/// Options SVG documents are opened with.
+#[must_use]
+pub fn svg_text_options() -> SvgTextOptions {
+    TEXT_OPTIONS
+        .read()
+        .ok()
+        .and_then(|options| options.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_families() {
+        assert_eq!(
+            SvgTextOptions::parse_families(" Noto Sans, ,DejaVu Sans ,"),
+            vec!["Noto Sans".to_string(), "DejaVu Sans".to_string()]
+        );
+        assert!(SvgTextOptions::parse_families("  ").is_empty());
+    }
+}
@@ -0,0 +1,340 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/temp_file.rs
+//
+// Atomic file writes: write a temporary file, then rename it over the target.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::RwLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::document::DocResult;
+
+/// Extension of files that are still being written.
+pub const TEMP_EXT: &str = "noctua-tmp";
+
+/// Extension of the records in the temp directory that name a temporary
+/// file being written next to its target.
+const PENDING_EXT: &str = "noctua-pending";
+
+/// Directory files are written in before they are moved to their target
+/// (None = next to the target).
+static TEMP_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Number of the next temporary file of this process.
+static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+/// Write temporary files to `dir` (None = next to their target).
+pub fn set_temp_dir(dir: Option<PathBuf>) {
+    if let Ok(mut temp_dir) = TEMP_DIR.write() {
+        *temp_dir = dir;
+    }
+}
+
+/// Configured directory for temporary files, if any.
+fn configured_dir() -> Option<PathBuf> {
+    TEMP_DIR.read().ok().and_then(|dir| dir.clone())
+}
+
+/// Directory for temporary files that belong to no target (shared exports,
+/// records of running writes), created if missing.
+///
+/// This is the configured temp directory, or else a `noctua` folder only the
+/// user can access: in the user runtime directory, the user cache, or as a
+/// last resort the system temp directory.
+pub fn temp_dir() -> io::Result<PathBuf> {
+    if let Some(dir) = configured_dir() {
+        fs::create_dir_all(&dir)?;
+        return Ok(dir);
+    }
+    let from_env = |name| {
+        std::env::var_os(name)
+            .map(PathBuf::from)
+            .filter(|dir| dir.is_absolute())
+    };
+    let dir = from_env("XDG_RUNTIME_DIR")
+        .map(|dir| dir.join("noctua"))
+        .or_else(|| from_env("XDG_CACHE_HOME").map(|dir| dir.join("noctua").join("tmp")))
+        .or_else(|| from_env("HOME").map(|dir| dir.join(".cache/noctua/tmp")))
+        .unwrap_or_else(|| std::env::temp_dir().join(format!("noctua-{}", user_name())));
+    create_private_dir(&dir)?;
+    Ok(dir)
+}
+
+/// Create `dir` accessible only by the user, and refuse an existing one that
+/// others can access (another user may have planted it).
+fn create_private_dir(dir: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    builder.mode(0o700);
+    builder.create(dir)?;
+
+    let metadata = fs::symlink_metadata(dir)?;
+    #[cfg(unix)]
+    let shared = metadata.permissions().mode() & 0o077 != 0;
+    #[cfg(not(unix))]
+    let shared = false;
+    if !metadata.is_dir() || shared {
+        return Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!("{} is not a private directory", dir.display()),
+        ));
+    }
+    Ok(())
+}
+
+/// Name of the user, to keep the last-resort temp directories apart.
+fn user_name() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_default()
+}
+
+/// Write `target` with `write`, which gets the path of a temporary file.
+///
+/// The temporary file is a hidden file next to `target` (or in the
+/// configured temp directory) and replaces `target` only if `write`
+/// succeeds, so neither a failed export nor a crash leaves half a file
+/// behind. A replaced file keeps its permissions.
+pub fn write_atomically<T>(
+    target: &Path,
+    write: impl FnOnce(&Path) -> DocResult<T>,
+) -> DocResult<T> {
+    let (temp, record) = match configured_dir() {
+        Some(dir) => {
+            fs::create_dir_all(&dir)?;
+            (create_temp(|| dir.join(temp_name()))?, None)
+        }
+        None => {
+            let temp = create_temp(|| sibling_of(target))?;
+            let record = record_pending(&temp);
+            (temp, record)
+        }
+    };
+
+    let result = write(&temp).and_then(|value| {
+        persist(&temp, target)?;
+        Ok(value)
+    });
+    if result.is_err() {
+        let _ = fs::remove_file(&temp);
+    }
+    if let Some(record) = record {
+        let _ = fs::remove_file(record);
+    }
+    result
+}
+
+/// Write `bytes` to `target` atomically (see [`write_atomically`]).
+pub fn write_bytes(target: &Path, bytes: impl AsRef<[u8]>) -> DocResult<()> {
+    write_atomically(target, |temp| Ok(fs::write(temp, bytes)?))
+}
+
+/// Remove the temporary files that crashed sessions left in `dir`, and those
+/// left next to their targets that the records in `dir` name.
+///
+/// Files of running processes (other windows writing right now) are kept.
+/// Returns the number of removed files.
+pub fn remove_stale(dir: &Path) -> io::Result<usize> {
+    let read = match fs::read_dir(dir) {
+        Ok(read) => read,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(0),
+        Err(e) => return Err(e),
+    };
+
+    let mut removed = 0;
+    for path in read.filter_map(Result::ok).map(|entry| entry.path()) {
+        if let Some(pid) = writer_of(&path, PENDING_EXT)
+            && !is_running(pid)
+        {
+            // Only a temporary file of the same process is removed
+            if let Ok(temp) = fs::read_to_string(&path).map(PathBuf::from)
+                && writer_of(&temp, TEMP_EXT) == Some(pid)
+                && fs::remove_file(&temp).is_ok()
+            {
+                removed += 1;
+            }
+            let _ = fs::remove_file(&path);
+        } else if let Some(pid) = writer_of(&path, TEMP_EXT)
+            && !is_running(pid)
+            && fs::remove_file(&path).is_ok()
+        {
+            removed += 1;
+        }
+    }
+    if removed > 0 {
+        tracing::info!(
+            "Removed {removed} stale temporary files from {}",
+            dir.display()
+        );
+    }
+    Ok(removed)
+}
+
+/// Create a new, empty temporary file at a path of `name`, which is asked
+/// again while the path is taken. Never opens an existing file.
+fn create_temp(name: impl Fn() -> PathBuf) -> io::Result<PathBuf> {
+    loop {
+        let path = name();
+        match fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&path)
+        {
+            Ok(_) => return Ok(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Record in the temp directory that `temp` is being written, so that
+/// [`remove_stale`] removes it after a crash. Returns the record.
+fn record_pending(temp: &Path) -> Option<PathBuf> {
+    let dir = temp_dir()
+        .inspect_err(|e| tracing::debug!("No record of {}: {e}", temp.display()))
+        .ok()?;
+    let content = temp.to_str()?;
+    let record = create_temp(|| dir.join(format!("{}.{PENDING_EXT}", unique_stem()))).ok()?;
+    match fs::write(&record, content) {
+        Ok(()) => Some(record),
+        Err(_) => {
+            let _ = fs::remove_file(&record);
+            None
+        }
+    }
+}
+
+/// Move the written `temp` file to `target`.
+fn persist(temp: &Path, target: &Path) -> io::Result<()> {
+    if let Ok(metadata) = fs::metadata(target) {
+        fs::set_permissions(temp, metadata.permissions())?;
+    }
+    match fs::rename(temp, target) {
+        // The configured temp directory is on another filesystem: copy the
+        // file next to the target, where renaming it is atomic again
+        Err(e) if e.kind() == io::ErrorKind::CrossesDevices => {
+            let moved = create_temp(|| sibling_of(target)).and_then(|sibling| {
+                let moved = fs::copy(temp, &sibling).and_then(|_| fs::rename(&sibling, target));
+                if moved.is_err() {
+                    let _ = fs::remove_file(&sibling);
+                }
+                moved
+            });
+            let _ = fs::remove_file(temp);
+            moved
+        }
+        result => result,
+    }
+}
+
+/// Unique name part of a temporary file of this process: `<pid>-<number>`.
+fn unique_stem() -> String {
+    let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+    format!("{}-{id}", process::id())
+}
+
+/// Unique name of a temporary file: `<pid>-<number>.noctua-tmp`.
+fn temp_name() -> String {
+    format!("{}.{TEMP_EXT}", unique_stem())
+}
+
+/// Hidden temporary file next to `target`.
+fn sibling_of(target: &Path) -> PathBuf {
+    let name = target.file_name().unwrap_or_default().to_string_lossy();
+    target.with_file_name(format!(".{name}.{}", temp_name()))
+}
+
+/// Process writing the file at `path` with the extension `ext`, None for
+/// other files.
+fn writer_of(path: &Path, ext: &str) -> Option<u32> {
+    let name = path.file_name()?.to_str()?;
+    let stem = name.strip_suffix(ext)?.strip_suffix('.')?;
+    let (pid, id) = stem.rsplit('.').next()?.split_once('-')?;
+    id.parse::<u64>().ok()?;
+    pid.parse().ok()
+}
+
+/// Check if the process `pid` is running (via `/proc`, so Linux only).
+fn is_running(pid: u32) -> bool {
+    pid == process::id() || Path::new("/proc").join(pid.to_string()).exists()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_atomically() {
+        let dir = std::env::temp_dir().join("noctua-temp-file-test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let target = dir.join("owl.txt");
+        fs::write(&target, b"hoot").unwrap();
+
+        // A failed write leaves the target alone
+        let failed = write_atomically(&target, |temp| {
+            fs::write(temp, b"half")?;
+            Err::<(), _>(anyhow::anyhow!("encoder failed"))
+        });
+        assert!(failed.is_err());
+        assert_eq!(fs::read(&target).unwrap(), b"hoot");
+
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        // The temporary file is written next to the target
+        let written = write_atomically(&target, |temp| {
+            assert_eq!(temp.parent(), Some(dir.as_path()));
+            assert_eq!(fs::read(temp).unwrap(), b"");
+            Ok(fs::write(temp, b"hoot hoot")?)
+        });
+        assert!(written.is_ok());
+        assert_eq!(fs::read(&target).unwrap(), b"hoot hoot");
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_remove_stale() {
+        let dir = std::env::temp_dir().join("noctua-temp-stale-test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        // No process gets a pid above the kernel limit of 2^22
+        let crashed = format!("{}-0.{TEMP_EXT}", u32::MAX);
+        let running = format!(".owl.png.{}-3.{TEMP_EXT}", process::id());
+        for name in [
+            crashed.as_str(),
+            running.as_str(),
+            "owl.png",
+            "7.noctua-tmp",
+        ] {
+            fs::write(dir.join(name), b"").unwrap();
+        }
+
+        // A crashed write next to its target, recorded in the temp directory
+        let target_dir = dir.join("photos");
+        fs::create_dir_all(&target_dir).unwrap();
+        let left = target_dir.join(format!(".owl.png.{}-5.{TEMP_EXT}", u32::MAX));
+        fs::write(&left, b"").unwrap();
+        let record = dir.join(format!("{}-6.{PENDING_EXT}", u32::MAX));
+        fs::write(&record, left.to_str().unwrap()).unwrap();
+
+        assert_eq!(remove_stale(&dir).unwrap(), 2);
+        assert!(!left.exists());
+        assert!(!record.exists());
+        assert!(!dir.join(&crashed).exists());
+        assert!(dir.join(&running).exists());
+        assert!(dir.join("owl.png").exists());
+        assert!(dir.join("7.noctua-tmp").exists());
+        assert_eq!(remove_stale(&dir.join("missing")).unwrap(), 0);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,219 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/core/text_layer.rs
+//
+// Positioned page text for selecting and copying.
+
+/// Rectangle in page points as (x, y, width, height), origin at the top left.
+pub type PageRect = (f64, f64, f64, f64);
+
+/// A character of the page text with its box in page points.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Glyph {
+    pub ch: char,
+    pub x1: f64,
+    pub y1: f64,
+    pub x2: f64,
+    pub y2: f64,
+}
+
+impl Glyph {
+    /// Whether the centre of the glyph box lies inside `rect`.
+    fn is_inside(&self, (x, y, width, height): PageRect) -> bool {
+        let cx = (self.x1 + self.x2) / 2.0;
+        let cy = (self.y1 + self.y2) / 2.0;
+        cx >= x && cx <= x + width && cy >= y && cy <= y + height
+    }
+
+    /// Whether `other` continues this glyph on the same text line.
+    fn same_line(&self, other: &Glyph) -> bool {
+        (self.y1 - other.y1).abs() < 0.5 && (self.y2 - other.y2).abs() < 0.5
+    }
+}
+
+/// Text of one page in reading order, one box per character.
+///
+/// Line breaks are kept as glyphs so copied text keeps the lines of the page.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TextLayer {
+    glyphs: Vec<Glyph>,
+}
+
+impl TextLayer {
+    /// Pair the page text with the character boxes reported for it,
+    /// given as (x1, y1, x2, y2). Characters without a box are dropped.
+    pub fn new<I>(text: &str, boxes: I) -> Self
+    where
+        I: IntoIterator<Item = (f64, f64, f64, f64)>,
+    {
+        let glyphs = text
+            .chars()
+            .zip(boxes)
+            .map(|(ch, (x1, y1, x2, y2))| Glyph {
+                ch,
+                x1: x1.min(x2),
+                y1: y1.min(y2),
+                x2: x1.max(x2),
+                y2: y1.max(y2),
+            })
+            .collect();
+        Self { glyphs }
+    }
+
+    /// Check if the page has no text (e.g. a scanned page).
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.glyphs.is_empty()
+    }
+
+    /// Glyphs inside `rect`, without line breaks.
+    fn selected(&self, rect: PageRect) -> impl Iterator<Item = &Glyph> {
+        self.glyphs
+            .iter()
+            .filter(move |glyph| glyph.ch != '\n' && glyph.is_inside(rect))
+    }
+
+    /// Text of the characters inside `rect`, with a line break wherever the
+    /// page text has one between selected characters.
+    #[must_use]
+    pub fn selected_text(&self, rect: PageRect) -> String {
+        let mut text = String::new();
+        let mut line_break = false;
+        for glyph in &self.glyphs {
+            if glyph.ch == '\n' {
+                line_break = !text.is_empty();
+            } else if glyph.is_inside(rect) {
+                if line_break {
+                    text.truncate(text.trim_end().len());
+                    text.push('\n');
+                    line_break = false;
+                }
+                text.push(glyph.ch);
+            }
+        }
+        text.trim_end().to_string()
+    }
+
+    /// Boxes to highlight for the selection, one per run of selected
+    /// characters on a line.
+    #[must_use]
+    pub fn selected_boxes(&self, rect: PageRect) -> Vec<PageRect> {
+        line_runs(self.selected(rect))
+    }
+
+    /// Every case-insensitive occurrence of `query`, as the boxes of each hit
+    /// (one per line it spans). Whitespace in the query also matches a line
+    /// break, so phrases wrapped onto the next line are found.
+    #[must_use]
+    pub fn find(&self, query: &str) -> Vec<Vec<PageRect>> {
+        let query: Vec<char> = query
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ")
+            .chars()
+            .map(fold)
+            .collect();
+        if query.is_empty() {
+            return Vec::new();
+        }
+
+        let chars: Vec<char> = self.glyphs.iter().map(|glyph| fold(glyph.ch)).collect();
+        let mut hits = Vec::new();
+        let mut pos = 0;
+        while pos + query.len() <= chars.len() {
+            if chars[pos..pos + query.len()] == query[..] {
+                let glyphs = &self.glyphs[pos..pos + query.len()];
+                hits.push(line_runs(glyphs.iter().filter(|glyph| glyph.ch != '\n')));
+                pos += query.len();
+            } else {
+                pos += 1;
+            }
+        }
+        hits
+    }
+}
+
+/// Character compared by `TextLayer::find`: lowercase, whitespace as a space.
+fn fold(ch: char) -> char {
+    if ch.is_whitespace() {
+        ' '
+    } else {
+        ch.to_lowercase().next().unwrap_or(ch)
+    }
+}
+
+/// Boxes around runs of glyphs on the same line.
+fn line_runs<'a>(glyphs: impl IntoIterator<Item = &'a Glyph>) -> Vec<PageRect> {
+    let mut runs: Vec<Glyph> = Vec::new();
+    for glyph in glyphs {
+        match runs.last_mut() {
+            Some(run) if run.same_line(glyph) => {
+                run.x1 = run.x1.min(glyph.x1);
+                run.x2 = run.x2.max(glyph.x2);
+            }
+            _ => runs.push(*glyph),
+        }
+    }
+    runs.iter()
+        .map(|run| (run.x1, run.y1, run.x2 - run.x1, run.y2 - run.y1))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Two lines of monospaced text, 10×20 points per character.
+    fn layer() -> TextLayer {
+        let text = "Hello owl\nat night";
+        let mut boxes = Vec::new();
+        for (line, content) in (0u32..).zip(text.split('\n')) {
+            let y = f64::from(line) * 20.0;
+            // One box more than characters: the line break
+            for column in 0..=u32::try_from(content.len()).unwrap() {
+                let x = f64::from(column) * 10.0;
+                boxes.push((x, y, x + 10.0, y + 20.0));
+            }
+        }
+        TextLayer::new(text, boxes)
+    }
+
+    #[test]
+    fn test_selected_text() {
+        let layer = layer();
+        // Centres of "owl" only
+        assert_eq!(layer.selected_text((60.0, 0.0, 30.0, 20.0)), "owl");
+        // Both lines keep their break
+        assert_eq!(
+            layer.selected_text((0.0, 0.0, 100.0, 40.0)),
+            "Hello owl\nat night"
+        );
+        // Trailing spaces are dropped
+        assert_eq!(layer.selected_text((0.0, 0.0, 30.0, 40.0)), "Hel\nat");
+        assert_eq!(layer.selected_text((200.0, 200.0, 10.0, 10.0)), "");
+        assert!(TextLayer::default().is_empty());
+    }
+
+    #[test]
+    fn test_selected_boxes() {
+        let layer = layer();
+        assert_eq!(
+            layer.selected_boxes((0.0, 0.0, 50.0, 40.0)),
+            vec![(0.0, 0.0, 50.0, 20.0), (0.0, 20.0, 50.0, 20.0)]
+        );
+        assert!(layer.selected_boxes((200.0, 200.0, 10.0, 10.0)).is_empty());
+    }
+
+    #[test]
+    fn test_find() {
+        let layer = layer();
+        assert_eq!(layer.find("OWL"), vec![vec![(60.0, 0.0, 30.0, 20.0)]]);
+        // A phrase wrapped onto the next line gets a box on each line
+        assert_eq!(
+            layer.find("owl  at"),
+            vec![vec![(60.0, 0.0, 30.0, 20.0), (0.0, 20.0, 20.0, 20.0)]]
+        );
+        assert_eq!(layer.find("t").len(), 2);
+        assert!(layer.find("hoot").is_empty());
+        assert!(layer.find(" ").is_empty());
+    }
+}
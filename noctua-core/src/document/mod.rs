@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/mod.rs
+// noctua-core/src/document/mod.rs
 //
 // Document domain: core abstractions, types, and operations.
 
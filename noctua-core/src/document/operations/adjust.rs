@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/adjust.rs
+//
+// Color adjustments (brightness, contrast, saturation, gamma, exposure).
+
+use std::ops::RangeInclusive;
+
+use image::DynamicImage;
+
+use super::threshold::Threshold;
+
+/// One of the color adjustments, as shown by a slider.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Adjustment {
+    Exposure,
+    Brightness,
+    Contrast,
+    Gamma,
+    Saturation,
+}
+
+impl Adjustment {
+    /// All adjustments, in the order they are applied.
+    pub const ALL: [Self; 5] = [
+        Self::Exposure,
+        Self::Brightness,
+        Self::Contrast,
+        Self::Gamma,
+        Self::Saturation,
+    ];
+
+    /// Values the adjustment can take.
+    #[must_use]
+    pub fn range(self) -> RangeInclusive<f32> {
+        match self {
+            Self::Exposure => -3.0..=3.0,
+            Self::Brightness | Self::Contrast | Self::Saturation => -100.0..=100.0,
+            Self::Gamma => 0.2..=5.0,
+        }
+    }
+
+    /// Smallest change of the value.
+    #[must_use]
+    pub fn step(self) -> f32 {
+        match self {
+            Self::Exposure | Self::Gamma => 0.05,
+            Self::Brightness | Self::Contrast | Self::Saturation => 1.0,
+        }
+    }
+
+    /// Value that leaves the image unchanged.
+    #[must_use]
+    pub fn neutral(self) -> f32 {
+        match self {
+            Self::Gamma => 1.0,
+            _ => 0.0,
+        }
+    }
+}
+
+/// Color adjustments applied on top of an image.
+///
+/// The image itself stays untouched; `apply` computes the adjusted copy.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Adjustments {
+    /// Exposure in stops: each one doubles or halves the light.
+    pub exposure: f32,
+    /// Percent of full white added (negative: removed).
+    pub brightness: f32,
+    /// Percent the tones are spread from (or pulled to) middle gray.
+    pub contrast: f32,
+    /// Gamma of the midtones; above 1 brightens them.
+    pub gamma: f32,
+    /// Percent the colors move away from gray; -100 is grayscale.
+    pub saturation: f32,
+    /// Black and white conversion after the other adjustments.
+    pub threshold: Option<Threshold>,
+}
+
+impl Default for Adjustments {
+    fn default() -> Self {
+        Self {
+            exposure: 0.0,
+            brightness: 0.0,
+            contrast: 0.0,
+            gamma: 1.0,
+            saturation: 0.0,
+            threshold: None,
+        }
+    }
+}
+
+impl Adjustments {
+    /// Value of `adjustment`.
+    #[must_use]
+    pub fn get(&self, adjustment: Adjustment) -> f32 {
+        match adjustment {
+            Adjustment::Exposure => self.exposure,
+            Adjustment::Brightness => self.brightness,
+            Adjustment::Contrast => self.contrast,
+            Adjustment::Gamma => self.gamma,
+            Adjustment::Saturation => self.saturation,
+        }
+    }
+
+    /// Set `adjustment` to `value`, clamped to its range.
+    pub fn set(&mut self, adjustment: Adjustment, value: f32) {
+        let range = adjustment.range();
+        let value = value.clamp(*range.start(), *range.end());
+        match adjustment {
+            Adjustment::Exposure => self.exposure = value,
+            Adjustment::Brightness => self.brightness = value,
+            Adjustment::Contrast => self.contrast = value,
+            Adjustment::Gamma => self.gamma = value,
+            Adjustment::Saturation => self.saturation = value,
+        }
+    }
+
+    /// Check if the adjustments leave the image unchanged.
+    #[must_use]
+    pub fn is_neutral(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Adjusted copy of `image`.
+    ///
+    /// 8-bit images are adjusted through a lookup table; deeper ones keep
+    /// 16 bits per channel. The alpha channel is left as it is, unless the
+    /// threshold turns the result into black and white 8-bit gray.
+    #[must_use]
+    pub fn apply(&self, image: &DynamicImage) -> DynamicImage {
+        let tones = Self {
+            threshold: None,
+            ..*self
+        };
+        match self.threshold {
+            Some(threshold) if tones.is_neutral() => threshold.apply(image),
+            Some(threshold) => threshold.apply(&tones.apply_tones(image)),
+            None => self.apply_tones(image),
+        }
+    }
+
+    /// Copy of `image` with the adjustments other than the threshold.
+    fn apply_tones(&self, image: &DynamicImage) -> DynamicImage {
+        if self.is_neutral() {
+            return image.clone();
+        }
+
+        let deep = matches!(
+            image,
+            DynamicImage::ImageLuma16(_)
+                | DynamicImage::ImageLumaA16(_)
+                | DynamicImage::ImageRgb16(_)
+                | DynamicImage::ImageRgba16(_)
+                | DynamicImage::ImageRgb32F(_)
+                | DynamicImage::ImageRgba32F(_)
+        );
+        let adjusted = if deep {
+            let mut pixels = image.to_rgba32f();
+            for pixel in pixels.pixels_mut() {
+                let [r, g, b, a] = pixel.0;
+                let [r, g, b] = self.saturate([r, g, b].map(|value| self.tone(value)));
+                pixel.0 = [r, g, b, a];
+            }
+            DynamicImage::ImageRgba16(DynamicImage::ImageRgba32F(pixels).to_rgba16())
+        } else {
+            let table: Vec<f32> = (0..=u8::MAX)
+                .map(|value| self.tone(f32::from(value) / 255.0))
+                .collect();
+            let mut pixels = image.to_rgba8();
+            for pixel in pixels.pixels_mut() {
+                let [r, g, b, a] = pixel.0;
+                let rgb = self.saturate([r, g, b].map(|value| table[usize::from(value)]));
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let [r, g, b] = rgb.map(|value| (value * 255.0).round() as u8);
+                pixel.0 = [r, g, b, a];
+            }
+            DynamicImage::ImageRgba8(pixels)
+        };
+
+        match (image.color().has_alpha(), deep) {
+            (true, _) => adjusted,
+            (false, true) => DynamicImage::ImageRgb16(adjusted.to_rgb16()),
+            (false, false) => DynamicImage::ImageRgb8(adjusted.to_rgb8()),
+        }
+    }
+
+    /// Exposure, brightness, contrast and gamma of a channel value (0-1).
+    fn tone(&self, value: f32) -> f32 {
+        let value = value * self.exposure.exp2() + self.brightness / 100.0;
+        let value = (value - 0.5) * (1.0 + self.contrast / 100.0) + 0.5;
+        value.clamp(0.0, 1.0).powf(1.0 / self.gamma)
+    }
+
+    /// Saturation of a color (channels 0-1), keeping its luminance.
+    fn saturate(&self, rgb: [f32; 3]) -> [f32; 3] {
+        if self.saturation == 0.0 {
+            return rgb;
+        }
+        let [r, g, b] = rgb;
+        let luma = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+        let factor = 1.0 + self.saturation / 100.0;
+        rgb.map(|value| (luma + (value - luma) * factor).clamp(0.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, Rgb, RgbImage, Rgba};
+
+    fn pixel(adjustments: Adjustments, color: [u8; 3]) -> Rgba<u8> {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(1, 1, Rgb(color)));
+        adjustments.apply(&image).get_pixel(0, 0)
+    }
+
+    #[test]
+    fn test_neutral() {
+        let adjustments = Adjustments::default();
+        assert!(adjustments.is_neutral());
+        assert_eq!(pixel(adjustments, [10, 128, 240]).0, [10, 128, 240, 255]);
+        for adjustment in Adjustment::ALL {
+            assert_eq!(adjustments.get(adjustment), adjustment.neutral());
+        }
+    }
+
+    #[test]
+    fn test_adjustments() {
+        let mut adjustments = Adjustments::default();
+        adjustments.set(Adjustment::Exposure, 1.0);
+        assert_eq!(pixel(adjustments, [50, 100, 200]).0, [100, 200, 255, 255]);
+
+        let mut adjustments = Adjustments::default();
+        adjustments.set(Adjustment::Brightness, -100.0);
+        assert_eq!(pixel(adjustments, [50, 100, 200]).0, [0, 0, 0, 255]);
+
+        let mut adjustments = Adjustments::default();
+        adjustments.set(Adjustment::Contrast, -100.0);
+        assert_eq!(pixel(adjustments, [0, 100, 255]).0, [128, 128, 128, 255]);
+
+        let mut adjustments = Adjustments::default();
+        adjustments.set(Adjustment::Gamma, 2.0);
+        assert_eq!(pixel(adjustments, [0, 64, 255]).0, [0, 128, 255, 255]);
+
+        let mut adjustments = Adjustments::default();
+        adjustments.set(Adjustment::Saturation, -100.0);
+        let [r, g, b, _] = pixel(adjustments, [255, 0, 0]).0;
+        assert_eq!((r, g, b), (54, 54, 54));
+
+        // Values are clamped to the range
+        adjustments.set(Adjustment::Gamma, 0.0);
+        assert_eq!(adjustments.gamma, 0.2);
+    }
+
+    #[test]
+    fn test_keeps_format() {
+        let mut adjustments = Adjustments::default();
+        adjustments.set(Adjustment::Brightness, 10.0);
+        let rgb = DynamicImage::new_rgb8(2, 2);
+        let rgba16 = DynamicImage::new_rgba16(2, 2);
+        let rgb32 = DynamicImage::new_rgb32f(2, 2);
+        assert_eq!(adjustments.apply(&rgb).color(), image::ColorType::Rgb8);
+        assert_eq!(adjustments.apply(&rgba16).color(), image::ColorType::Rgba16);
+        assert_eq!(adjustments.apply(&rgb32).color(), image::ColorType::Rgb16);
+        assert_eq!(adjustments.apply(&rgb).dimensions(), (2, 2));
+    }
+
+    #[test]
+    fn test_threshold_after_tones() {
+        let mut adjustments = Adjustments {
+            threshold: Some(Threshold::default()),
+            ..Adjustments::default()
+        };
+        assert!(!adjustments.is_neutral());
+        assert_eq!(pixel(adjustments, [100, 100, 100]).0, [0, 0, 0, 255]);
+
+        // Brightened above the level first
+        adjustments.set(Adjustment::Brightness, 20.0);
+        assert_eq!(pixel(adjustments, [100, 100, 100]).0, [255, 255, 255, 255]);
+        let rgba = DynamicImage::new_rgba8(2, 2);
+        assert_eq!(adjustments.apply(&rgba).color(), image::ColorType::L8);
+    }
+}
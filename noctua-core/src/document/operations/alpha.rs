@@ -0,0 +1,199 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/alpha.rs
+//
+// Alpha channel handling: mask view and export, premultiplied blending.
+
+use std::path::Path;
+
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, GrayImage, Luma, Rgba32FImage, RgbaImage};
+
+use super::export::{self, ExportFormat, ImageExportOptions};
+use crate::document::core::document::DocResult;
+
+/// How color values relate to alpha in written pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlphaMode {
+    /// Color independent of alpha, as PNG, WebP and TIFF define it.
+    #[default]
+    Straight,
+    /// Color already multiplied by alpha, for tools that composite without converting.
+    Premultiplied,
+}
+
+impl AlphaMode {
+    /// Name used in export profile settings.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Straight => "straight",
+            Self::Premultiplied => "premultiplied",
+        }
+    }
+
+    /// Parse a name from `name()`.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "straight" => Some(Self::Straight),
+            "premultiplied" => Some(Self::Premultiplied),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the image has an alpha channel (even if fully opaque).
+#[must_use]
+pub fn has_alpha(img: &DynamicImage) -> bool {
+    img.color().has_alpha()
+}
+
+/// Alpha channel as a grayscale image: white is opaque, black is transparent.
+///
+/// None if the image has no alpha channel.
+#[must_use]
+pub fn alpha_mask(img: &DynamicImage) -> Option<GrayImage> {
+    if !has_alpha(img) {
+        return None;
+    }
+    let rgba = img.to_rgba8();
+    Some(GrayImage::from_fn(rgba.width(), rgba.height(), |x, y| {
+        Luma([rgba.get_pixel(x, y)[3]])
+    }))
+}
+
+/// Write the alpha mask of `img` as an 8-bit grayscale PNG.
+pub fn export_alpha_mask(img: &DynamicImage, path: &Path) -> DocResult<()> {
+    let mask = alpha_mask(img).ok_or_else(|| anyhow::anyhow!("The image has no alpha channel"))?;
+    let options = ImageExportOptions {
+        preserve_metadata: false,
+        ..ImageExportOptions::default()
+    };
+    export::export_image(
+        &DynamicImage::ImageLuma8(mask),
+        path,
+        ExportFormat::Png,
+        &options,
+    )
+}
+
+/// Multiply the color channels by alpha (8 bits per channel, rounded).
+#[must_use]
+pub fn premultiply(img: &DynamicImage) -> RgbaImage {
+    let mut rgba = img.to_rgba8();
+    for pixel in rgba.pixels_mut() {
+        let alpha = u16::from(pixel[3]);
+        for channel in &mut pixel.0[..3] {
+            *channel = ((u16::from(*channel) * alpha + 127) / 255) as u8;
+        }
+    }
+    rgba
+}
+
+/// Straight color value of a premultiplied channel, rounded to nearest.
+///
+/// Truncating here darkens anti-aliased edges, the usual cause of dark fringes.
+#[must_use]
+pub fn unpremultiply_channel(value: u8, alpha: u8) -> u8 {
+    if alpha == 0 {
+        return 0;
+    }
+    let alpha = u16::from(alpha);
+    ((u16::from(value) * 255 + alpha / 2) / alpha).min(255) as u8
+}
+
+/// Resize with premultiplied blending.
+///
+/// Plain filtering mixes the (often black) color of transparent pixels into
+/// their neighbours; weighting by alpha keeps edges clean. Images without
+/// alpha are resized directly.
+#[must_use]
+pub fn resize_exact(
+    img: &DynamicImage,
+    width: u32,
+    height: u32,
+    filter: FilterType,
+) -> DynamicImage {
+    if !has_alpha(img) {
+        return img.resize_exact(width, height, filter);
+    }
+
+    let mut buffer: Rgba32FImage = img.to_rgba32f();
+    for pixel in buffer.pixels_mut() {
+        let alpha = pixel[3];
+        for channel in &mut pixel.0[..3] {
+            *channel *= alpha;
+        }
+    }
+    let mut resized = imageops::resize(&buffer, width, height, filter);
+    for pixel in resized.pixels_mut() {
+        let alpha = pixel[3];
+        for channel in &mut pixel.0[..3] {
+            *channel = if alpha > 0.0 {
+                (*channel / alpha).min(1.0)
+            } else {
+                0.0
+            };
+        }
+    }
+
+    let resized = DynamicImage::ImageRgba32F(resized);
+    match img {
+        DynamicImage::ImageRgba8(_) | DynamicImage::ImageLumaA8(_) => {
+            DynamicImage::ImageRgba8(resized.to_rgba8())
+        }
+        DynamicImage::ImageRgba16(_) | DynamicImage::ImageLumaA16(_) => {
+            DynamicImage::ImageRgba16(resized.to_rgba16())
+        }
+        _ => resized,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    fn test_alpha_mask_values() {
+        let img = RgbaImage::from_fn(2, 1, |x, _| Rgba([255, 0, 0, if x == 0 { 0 } else { 200 }]));
+        let mask = alpha_mask(&DynamicImage::ImageRgba8(img)).unwrap();
+        assert_eq!(mask.get_pixel(0, 0)[0], 0);
+        assert_eq!(mask.get_pixel(1, 0)[0], 200);
+    }
+
+    #[test]
+    fn test_resize_keeps_edge_color() {
+        // Opaque red next to fully transparent black: the blended edge stays red
+        let img = RgbaImage::from_fn(4, 1, |x, _| {
+            if x < 2 {
+                Rgba([255, 0, 0, 255])
+            } else {
+                Rgba([0, 0, 0, 0])
+            }
+        });
+        let out = resize_exact(&DynamicImage::ImageRgba8(img), 3, 1, FilterType::Triangle);
+        let edge = out.to_rgba8().get_pixel(1, 0).0;
+        assert!(edge[3] > 0 && edge[3] < 255);
+        assert_eq!(edge[0], 255);
+    }
+
+    #[test]
+    fn test_premultiply_roundtrip() {
+        let img = RgbaImage::from_pixel(1, 1, Rgba([200, 100, 50, 128]));
+        let pixel = premultiply(&DynamicImage::ImageRgba8(img))
+            .get_pixel(0, 0)
+            .0;
+        assert_eq!(pixel, [100, 50, 25, 128]);
+        assert_eq!(unpremultiply_channel(pixel[0], 128), 199);
+        assert_eq!(unpremultiply_channel(64, 128), 128);
+        assert_eq!(unpremultiply_channel(10, 0), 0);
+    }
+
+    #[test]
+    fn test_no_alpha_channel() {
+        let img = DynamicImage::new_rgb8(4, 4);
+        assert!(!has_alpha(&img));
+        assert!(alpha_mask(&img).is_none());
+    }
+}
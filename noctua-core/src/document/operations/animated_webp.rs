@@ -0,0 +1,168 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/animated_webp.rs
+//
+// Animated WebP encoding through libwebp's WebPAnimEncoder.
+
+use std::ffi::CStr;
+use std::ptr::{self, NonNull};
+
+use image::RgbaImage;
+use libwebp_sys as sys;
+
+use crate::document::core::document::DocResult;
+
+/// Encoder of an animated WebP, fed one full-canvas frame at a time.
+///
+/// Frames are stored losslessly, like the frames of a GIF; libwebp keeps only
+/// the changed area of each frame.
+pub(crate) struct AnimatedWebPEncoder {
+    encoder: NonNull<sys::WebPAnimEncoder>,
+    config: sys::WebPConfig,
+    width: u32,
+    height: u32,
+    /// Start of the next frame, in milliseconds.
+    timestamp: i32,
+}
+
+impl AnimatedWebPEncoder {
+    /// Create an encoder for a `width` x `height` canvas that plays `loops`
+    /// times (0 = forever).
+    pub fn new(width: u32, height: u32, loops: u16) -> DocResult<Self> {
+        let (Ok(canvas_width), Ok(canvas_height)) = (i32::try_from(width), i32::try_from(height))
+        else {
+            return Err(anyhow::anyhow!("Animation too large: {width}x{height}"));
+        };
+
+        // SAFETY: the options and config are plain C structs that the init
+        // functions fill in completely before they are read.
+        let (options, config) = unsafe {
+            let mut options = std::mem::zeroed::<sys::WebPAnimEncoderOptions>();
+            let mut config = std::mem::zeroed::<sys::WebPConfig>();
+            let ok = sys::WebPAnimEncoderOptionsInitInternal(
+                &mut options,
+                sys::WEBP_MUX_ABI_VERSION as i32,
+            ) != 0
+                && sys::WebPConfigInitInternal(
+                    &mut config,
+                    sys::WebPPreset::WEBP_PRESET_DEFAULT,
+                    75.0,
+                    sys::WEBP_ENCODER_ABI_VERSION as i32,
+                ) != 0;
+            if !ok {
+                return Err(anyhow::anyhow!("libwebp version mismatch"));
+            }
+            (options, config)
+        };
+        let options = sys::WebPAnimEncoderOptions {
+            anim_params: sys::WebPMuxAnimParams {
+                loop_count: i32::from(loops),
+                ..options.anim_params
+            },
+            ..options
+        };
+        let config = sys::WebPConfig {
+            lossless: 1,
+            ..config
+        };
+
+        // SAFETY: the options are initialised; a null result is an error.
+        let encoder = unsafe {
+            sys::WebPAnimEncoderNewInternal(
+                canvas_width,
+                canvas_height,
+                &options,
+                sys::WEBP_MUX_ABI_VERSION as i32,
+            )
+        };
+        let encoder =
+            NonNull::new(encoder).ok_or_else(|| anyhow::anyhow!("Cannot create WebP encoder"))?;
+        Ok(Self {
+            encoder,
+            config,
+            width,
+            height,
+            timestamp: 0,
+        })
+    }
+
+    /// Add `frame`, shown for `delay_ms` milliseconds.
+    ///
+    /// The frame must have the size of the canvas.
+    pub fn add_frame(&mut self, frame: &RgbaImage, delay_ms: u32) -> DocResult<()> {
+        if frame.dimensions() != (self.width, self.height) {
+            return Err(anyhow::anyhow!("Frame size differs from the animation"));
+        }
+
+        // SAFETY: the picture is initialised before use, imports from a
+        // buffer of `height` rows of `4 * width` bytes, and is freed on every
+        // path after the encoder has copied it.
+        unsafe {
+            let mut picture = std::mem::zeroed::<sys::WebPPicture>();
+            if !sys::WebPPictureInit(&mut picture) {
+                return Err(anyhow::anyhow!("libwebp version mismatch"));
+            }
+            picture.use_argb = 1;
+            picture.width = self.width as i32;
+            picture.height = self.height as i32;
+            let imported =
+                sys::WebPPictureImportRGBA(&mut picture, frame.as_ptr(), self.width as i32 * 4);
+            let added = imported != 0
+                && sys::WebPAnimEncoderAdd(
+                    self.encoder.as_ptr(),
+                    &mut picture,
+                    self.timestamp,
+                    &self.config,
+                ) != 0;
+            sys::WebPPictureFree(&mut picture);
+            if !added {
+                return Err(self.error("Cannot add WebP frame"));
+            }
+        }
+
+        self.timestamp = self
+            .timestamp
+            .saturating_add(i32::try_from(delay_ms).unwrap_or(i32::MAX));
+        Ok(())
+    }
+
+    /// Assemble the file.
+    pub fn finish(self) -> DocResult<Vec<u8>> {
+        // SAFETY: the null frame marks the end of the last frame; the
+        // assembled data is copied before libwebp frees it.
+        unsafe {
+            let ended = sys::WebPAnimEncoderAdd(
+                self.encoder.as_ptr(),
+                ptr::null_mut(),
+                self.timestamp,
+                ptr::null(),
+            ) != 0;
+            let mut data = sys::WebPData::default();
+            if !ended || sys::WebPAnimEncoderAssemble(self.encoder.as_ptr(), &mut data) == 0 {
+                return Err(self.error("Cannot assemble WebP animation"));
+            }
+            let bytes = std::slice::from_raw_parts(data.bytes, data.size).to_vec();
+            sys::WebPDataClear(&mut data);
+            Ok(bytes)
+        }
+    }
+
+    /// Error with libwebp's reason for the last failure.
+    fn error(&self, context: &str) -> anyhow::Error {
+        // SAFETY: the encoder owns the returned string; it is copied at once.
+        let reason = unsafe { sys::WebPAnimEncoderGetError(self.encoder.as_ptr()) };
+        if reason.is_null() {
+            return anyhow::anyhow!("{context}");
+        }
+        // SAFETY: non-null results are NUL-terminated strings.
+        let reason = unsafe { CStr::from_ptr(reason) }.to_string_lossy();
+        anyhow::anyhow!("{context}: {reason}")
+    }
+}
+
+impl Drop for AnimatedWebPEncoder {
+    fn drop(&mut self) {
+        // SAFETY: the encoder was created by WebPAnimEncoderNewInternal and
+        // is deleted only here.
+        unsafe { sys::WebPAnimEncoderDelete(self.encoder.as_ptr()) };
+    }
+}
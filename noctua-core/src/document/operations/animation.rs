@@ -0,0 +1,560 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/animation.rs
+//
+// Animations: frame decoding and extraction, playback timing, numbered
+// frame sequence detection and animated GIF and WebP encoding.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use image::codecs::gif::{GifDecoder, GifEncoder, Repeat};
+use image::codecs::png::PngDecoder;
+use image::codecs::webp::WebPDecoder;
+use image::imageops::FilterType;
+use image::{AnimationDecoder, Delay, DynamicImage, Frame, Frames, RgbaImage};
+
+use super::alpha;
+use super::animated_webp::AnimatedWebPEncoder;
+use super::export::{self, ExportFormat, ImageExportOptions};
+use crate::document::core::document::DocResult;
+use crate::document::core::temp_file;
+
+/// Encoder speed (1 = best quality, 30 = fastest).
+const GIF_SPEED: i32 = 10;
+
+/// Playback speeds offered in the preview, as factors of the file's timing.
+pub const PLAYBACK_SPEEDS: [f32; 5] = [0.25, 0.5, 1.0, 2.0, 4.0];
+
+/// Frame delays at or below this are shown for [`DEFAULT_FRAME_DELAY`],
+/// as browsers do (many GIFs store 0 and expect 100 ms).
+const MIN_FRAME_DELAY: Duration = Duration::from_millis(10);
+
+/// Display time of frames without a usable delay.
+const DEFAULT_FRAME_DELAY: Duration = Duration::from_millis(100);
+
+/// Playback settings of a written animation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AnimationSettings {
+    /// Frames per second.
+    pub fps: f32,
+    /// Number of times the animation plays (0 = forever).
+    pub loops: u16,
+}
+
+impl Default for AnimationSettings {
+    fn default() -> Self {
+        Self {
+            fps: 12.0,
+            loops: 0,
+        }
+    }
+}
+
+impl AnimationSettings {
+    /// Display time of one frame.
+    #[must_use]
+    pub fn frame_delay(&self) -> Delay {
+        Delay::from_numer_denom_ms(self.frame_millis(), 1)
+    }
+
+    /// Display time of one frame in milliseconds (at least 1).
+    fn frame_millis(&self) -> u32 {
+        ((1000.0 / self.fps.max(0.1)).round() as u32).max(1)
+    }
+}
+
+/// File format of a written animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AnimationFormat {
+    /// Animated GIF (256 colors per frame).
+    #[default]
+    Gif,
+    /// Animated WebP (lossless, full color and alpha).
+    WebP,
+}
+
+impl AnimationFormat {
+    /// All formats, in the order they are offered.
+    pub const ALL: [Self; 2] = [Self::Gif, Self::WebP];
+
+    /// File extension (without dot).
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Gif => "gif",
+            Self::WebP => "webp",
+        }
+    }
+
+    /// Format written for `path`, by its extension.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?;
+        Self::ALL
+            .into_iter()
+            .find(|format| extension.eq_ignore_ascii_case(format.extension()))
+    }
+}
+
+/// Display times of the frames of an animation, for playback.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Timeline {
+    delays: Vec<Duration>,
+}
+
+impl Timeline {
+    /// Timeline of frames shown for `delays`; too short delays are replaced
+    /// by the usual default.
+    #[must_use]
+    pub fn new(delays: impl IntoIterator<Item = Duration>) -> Self {
+        let delays = delays
+            .into_iter()
+            .map(|delay| {
+                if delay <= MIN_FRAME_DELAY {
+                    DEFAULT_FRAME_DELAY
+                } else {
+                    delay
+                }
+            })
+            .collect();
+        Self { delays }
+    }
+
+    /// Number of frames.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.delays.len()
+    }
+
+    /// Whether there are no frames.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.delays.is_empty()
+    }
+
+    /// Display time of frame `index` at `speed` (1.0 = as stored).
+    #[must_use]
+    pub fn delay(&self, index: usize, speed: f32) -> Duration {
+        self.delays.get(index).map_or(Duration::ZERO, |delay| {
+            let micros = delay.as_micros() as f64 / f64::from(speed.max(0.01));
+            Duration::from_micros(micros.round() as u64)
+        })
+    }
+
+    /// Time from the start of the animation at which frame `index` shows,
+    /// at the stored speed.
+    #[must_use]
+    pub fn start_of(&self, index: usize) -> Duration {
+        self.delays.iter().take(index).sum()
+    }
+
+    /// Length of one pass through the animation, at the stored speed.
+    #[must_use]
+    pub fn duration(&self) -> Duration {
+        self.delays.iter().sum()
+    }
+
+    /// Frame `frames` away from `index`, wrapping around at either end.
+    #[must_use]
+    pub fn step(&self, index: usize, frames: isize) -> usize {
+        let Ok(len) = isize::try_from(self.len()) else {
+            return index;
+        };
+        if len == 0 {
+            return 0;
+        }
+        let index = isize::try_from(index).unwrap_or(0);
+        (index + frames).rem_euclid(len) as usize
+    }
+}
+
+/// Numbered frames belonging to the same sequence as `current`, in frame order.
+///
+/// Files form a sequence when their names differ only in a trailing number
+/// (`walk_01.png`, `walk_02.png`, … or `frame9.png`, `frame10.png`). Returns
+/// an empty list if `current` has no number or is the only frame.
+#[must_use]
+pub fn numbered_sequence(entries: &[PathBuf], current: &Path) -> Vec<PathBuf> {
+    let Some(key) = sequence_key(current) else {
+        return Vec::new();
+    };
+
+    let mut frames: Vec<(u64, &PathBuf)> = entries
+        .iter()
+        .filter(|path| path.parent() == current.parent())
+        .filter_map(|path| {
+            let (prefix, number, extension) = sequence_key(path)?;
+            (prefix == key.0 && extension == key.2).then_some((number, path))
+        })
+        .collect();
+    if frames.len() < 2 {
+        return Vec::new();
+    }
+    frames.sort_by_key(|(number, _)| *number);
+    frames.into_iter().map(|(_, path)| path.clone()).collect()
+}
+
+/// (name before the trailing number, number, lowercase extension).
+fn sequence_key(path: &Path) -> Option<(String, u64, String)> {
+    let stem = path.file_stem()?.to_str()?;
+    let extension = path.extension()?.to_str()?.to_lowercase();
+    let prefix = stem.trim_end_matches(|c: char| c.is_ascii_digit());
+    let number = stem[prefix.len()..].parse().ok()?;
+    Some((prefix.to_string(), number, extension))
+}
+
+/// Whether the file is a GIF (by extension).
+#[must_use]
+pub fn is_gif(path: &Path) -> bool {
+    path.extension()
+        .and_then(|ext| ext.to_str())
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("gif"))
+}
+
+/// Whether the file is an animated GIF, WebP or PNG (APNG).
+///
+/// Reads the headers, or for GIF at most two frames; unreadable files
+/// count as still.
+#[must_use]
+pub fn is_animated(path: &Path) -> bool {
+    let Ok(file) = File::open(path) else {
+        return false;
+    };
+    let reader = BufReader::new(file);
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase);
+
+    match extension.as_deref() {
+        Some("gif") => {
+            GifDecoder::new(reader).is_ok_and(|decoder| decoder.into_frames().take(2).count() > 1)
+        }
+        Some("webp") => WebPDecoder::new(reader).is_ok_and(|decoder| decoder.has_animation()),
+        Some("png") => {
+            PngDecoder::new(reader).is_ok_and(|decoder| decoder.is_apng().unwrap_or(false))
+        }
+        _ => false,
+    }
+}
+
+/// Decode all frames of an animated GIF, WebP or PNG (APNG).
+///
+/// Each frame is the full composited canvas as it is shown during playback
+/// (disposal and blending applied), not the partial area stored in the file.
+/// Still images yield a single frame.
+pub fn decode_frames(path: &Path) -> DocResult<Vec<DynamicImage>> {
+    Ok(decode_timed_frames(path)?
+        .into_iter()
+        .map(|(frame, _)| frame)
+        .collect())
+}
+
+/// Decode all frames like [`decode_frames`], each with the delay stored
+/// for it (zero for still images).
+pub fn decode_timed_frames(path: &Path) -> DocResult<Vec<(DynamicImage, Duration)>> {
+    let reader = || -> DocResult<BufReader<File>> { Ok(BufReader::new(File::open(path)?)) };
+    let still = || -> DocResult<Vec<(DynamicImage, Duration)>> {
+        Ok(vec![(image::open(path)?, Duration::ZERO)])
+    };
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_lowercase);
+
+    let frames = match extension.as_deref() {
+        Some("gif") => GifDecoder::new(reader()?)?.into_frames(),
+        Some("webp") => {
+            let decoder = WebPDecoder::new(reader()?)?;
+            if !decoder.has_animation() {
+                return still();
+            }
+            decoder.into_frames()
+        }
+        Some("png") => {
+            let decoder = PngDecoder::new(reader()?)?;
+            if !decoder.is_apng()? {
+                return still();
+            }
+            decoder.apng()?.into_frames()
+        }
+        _ => return still(),
+    };
+    collect_canvases(frames)
+}
+
+/// Frames as full-canvas RGBA images with their delays.
+fn collect_canvases(frames: Frames<'_>) -> DocResult<Vec<(DynamicImage, Duration)>> {
+    Ok(frames
+        .collect_frames()?
+        .into_iter()
+        .map(|frame| {
+            let delay = Duration::from(frame.delay());
+            (DynamicImage::ImageRgba8(frame.into_buffer()), delay)
+        })
+        .collect())
+}
+
+/// File name of an extracted frame: `<name>_frame_<number>.png` (numbered from 1).
+#[must_use]
+pub fn frame_file_name(source: &Path, index: usize) -> String {
+    let stem = source
+        .file_stem()
+        .map_or_else(|| "animation".into(), |s| s.to_string_lossy());
+    format!("{stem}_frame_{:03}.png", index + 1)
+}
+
+/// Write one frame as a lossless PNG.
+pub fn save_frame(frame: &DynamicImage, target: &Path) -> DocResult<()> {
+    let options = ImageExportOptions {
+        preserve_metadata: false,
+        ..ImageExportOptions::default()
+    };
+    export::export_image(frame, target, ExportFormat::Png, &options)
+}
+
+/// Write every frame of `source` as a numbered PNG into `output_dir`.
+///
+/// Returns the number of written frames.
+pub fn extract_frames(source: &Path, output_dir: &Path) -> DocResult<usize> {
+    let frames = decode_frames(source)?;
+    for (index, frame) in frames.iter().enumerate() {
+        save_frame(frame, &output_dir.join(frame_file_name(source, index)))?;
+    }
+    Ok(frames.len())
+}
+
+/// Write the frames as an animation in `format`.
+///
+/// Frames are scaled to the size of the first one. Returns the frame count.
+pub fn write_animation(
+    frames: impl IntoIterator<Item = DocResult<DynamicImage>>,
+    settings: AnimationSettings,
+    format: AnimationFormat,
+    target: &Path,
+) -> DocResult<usize> {
+    match format {
+        AnimationFormat::Gif => write_gif(frames, settings, target),
+        AnimationFormat::WebP => write_webp(frames, settings, target),
+    }
+}
+
+/// Write the frames as an animated GIF.
+///
+/// Frames are scaled to the size of the first one. Returns the frame count.
+pub fn write_gif(
+    frames: impl IntoIterator<Item = DocResult<DynamicImage>>,
+    settings: AnimationSettings,
+    target: &Path,
+) -> DocResult<usize> {
+    temp_file::write_atomically(target, |temp| {
+        let mut encoder = GifEncoder::new_with_speed(File::create(temp)?, GIF_SPEED);
+        encoder.set_repeat(match settings.loops {
+            0 => Repeat::Infinite,
+            loops => Repeat::Finite(loops),
+        })?;
+
+        let delay = settings.frame_delay();
+        for_each_frame(frames, |frame| {
+            Ok(encoder.encode_frame(Frame::from_parts(frame, 0, 0, delay))?)
+        })
+    })
+}
+
+/// Write the frames as a lossless animated WebP.
+///
+/// Frames are scaled to the size of the first one. Returns the frame count.
+pub fn write_webp(
+    frames: impl IntoIterator<Item = DocResult<DynamicImage>>,
+    settings: AnimationSettings,
+    target: &Path,
+) -> DocResult<usize> {
+    let delay = settings.frame_millis();
+    let mut encoder = None;
+    let count = for_each_frame(frames, |frame| {
+        if encoder.is_none() {
+            encoder = Some(AnimatedWebPEncoder::new(
+                frame.width(),
+                frame.height(),
+                settings.loops,
+            )?);
+        }
+        encoder
+            .as_mut()
+            .map_or(Ok(()), |encoder| encoder.add_frame(&frame, delay))
+    })?;
+
+    let bytes = encoder
+        .ok_or_else(|| anyhow::anyhow!("No frames to write"))?
+        .finish()?;
+    temp_file::write_bytes(target, bytes)?;
+    Ok(count)
+}
+
+/// Pass the frames to `write` as RGBA images of the size of the first one.
+///
+/// Returns the frame count; no frames is an error.
+fn for_each_frame(
+    frames: impl IntoIterator<Item = DocResult<DynamicImage>>,
+    mut write: impl FnMut(RgbaImage) -> DocResult<()>,
+) -> DocResult<usize> {
+    let mut size = None;
+    let mut count = 0;
+    for frame in frames {
+        let mut img = frame?;
+        let (width, height) = *size.get_or_insert((img.width(), img.height()));
+        if (img.width(), img.height()) != (width, height) {
+            img = alpha::resize_exact(&img, width, height, FilterType::Triangle);
+        }
+        write(img.to_rgba8())?;
+        count += 1;
+    }
+
+    if count == 0 {
+        return Err(anyhow::anyhow!("No frames to write"));
+    }
+    Ok(count)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbered_sequence() {
+        let entries: Vec<PathBuf> = [
+            "/anim/walk_10.png",
+            "/anim/walk_2.png",
+            "/anim/walk_1.png",
+            "/anim/walk_3.jpg",
+            "/anim/run_1.png",
+            "/anim/cover.png",
+        ]
+        .into_iter()
+        .map(PathBuf::from)
+        .collect();
+
+        let frames = numbered_sequence(&entries, Path::new("/anim/walk_2.png"));
+        assert_eq!(
+            frames,
+            vec![
+                PathBuf::from("/anim/walk_1.png"),
+                PathBuf::from("/anim/walk_2.png"),
+                PathBuf::from("/anim/walk_10.png"),
+            ]
+        );
+        assert!(numbered_sequence(&entries, Path::new("/anim/run_1.png")).is_empty());
+        assert!(numbered_sequence(&entries, Path::new("/anim/cover.png")).is_empty());
+    }
+
+    #[test]
+    fn test_frames_are_composited() {
+        // Second frame only covers the left pixel; the right one must be kept
+        let dir = std::env::temp_dir().join("noctua-animation-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("two.gif");
+        {
+            // Written with the gif crate directly: image's encoder always
+            // disposes frames to the background, which would clear the
+            // pixel under test.
+            let mut red = [255, 0, 0, 255].repeat(2);
+            let mut blue = [0, 0, 255, 255];
+            let file = File::create(&path).unwrap();
+            let mut encoder = gif::Encoder::new(file, 2, 1, &[]).unwrap();
+            for (width, pixels) in [(2, red.as_mut_slice()), (1, blue.as_mut_slice())] {
+                let mut frame = gif::Frame::from_rgba(width, 1, pixels);
+                frame.delay = 10;
+                frame.dispose = gif::DisposalMethod::Keep;
+                encoder.write_frame(&frame).unwrap();
+            }
+        }
+
+        assert!(is_animated(&path));
+        let still = dir.join("still.gif");
+        image::RgbaImage::new(2, 1).save(&still).unwrap();
+        assert!(!is_animated(&still));
+        assert!(!is_animated(&dir.join("missing.gif")));
+
+        let timed = decode_timed_frames(&path).unwrap();
+        assert_eq!(timed[1].1, Duration::from_millis(100));
+
+        let frames = decode_frames(&path).unwrap();
+        assert_eq!(frames.len(), 2);
+        let second = frames[1].to_rgba8();
+        assert_eq!(second.dimensions(), (2, 1));
+        assert_eq!(second.get_pixel(0, 0).0, [0, 0, 255, 255]);
+        assert_eq!(second.get_pixel(1, 0).0, [255, 0, 0, 255]);
+        assert_eq!(frame_file_name(&path, 1), "two_frame_002.png");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_timeline() {
+        let timeline = Timeline::new([0, 40, 60, 5].map(Duration::from_millis));
+        assert_eq!(timeline.len(), 4);
+        assert_eq!(timeline.delay(0, 1.0), Duration::from_millis(100));
+        assert_eq!(timeline.delay(1, 2.0), Duration::from_millis(20));
+        assert_eq!(timeline.delay(2, 0.25), Duration::from_millis(240));
+        assert_eq!(timeline.start_of(2), Duration::from_millis(140));
+        assert_eq!(timeline.duration(), Duration::from_millis(300));
+
+        assert_eq!(timeline.step(1, 2), 3);
+        assert_eq!(timeline.step(3, 3), 2);
+        assert_eq!(timeline.step(0, -1), 3);
+        assert_eq!(timeline.step(1, -10), 3);
+        assert_eq!(Timeline::default().step(5, 1), 0);
+    }
+
+    #[test]
+    fn test_write_webp() {
+        let dir = std::env::temp_dir().join("noctua-animation-webp-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("colors.webp");
+        let colors = [[255, 0, 0, 255], [0, 255, 0, 255], [0, 0, 255, 255]];
+        let frames = colors.map(|color| {
+            Ok(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+                4,
+                3,
+                image::Rgba(color),
+            )))
+        });
+        let settings = AnimationSettings {
+            fps: 25.0,
+            loops: 2,
+        };
+
+        assert_eq!(
+            AnimationFormat::from_path(&path),
+            Some(AnimationFormat::WebP)
+        );
+        let count = write_animation(frames, settings, AnimationFormat::WebP, &path).unwrap();
+        assert_eq!(count, 3);
+        assert!(is_animated(&path));
+
+        let timed = decode_timed_frames(&path).unwrap();
+        assert_eq!(timed.len(), 3);
+        for ((frame, delay), color) in timed.iter().zip(colors) {
+            // The frames are lossless; image's WebP blending rounds by one
+            let pixel = frame.to_rgba8().get_pixel(3, 2).0;
+            assert!(pixel.iter().zip(color).all(|(&a, b)| a.abs_diff(b) <= 1));
+            // The last frame keeps its delay too
+            assert_eq!(*delay, Duration::from_millis(40));
+        }
+
+        let none = write_webp(std::iter::empty(), settings, &dir.join("none.webp"));
+        assert!(none.is_err());
+        assert!(!dir.join("none.webp").exists());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_frame_delay() {
+        let settings = AnimationSettings {
+            fps: 25.0,
+            loops: 0,
+        };
+        assert_eq!(settings.frame_delay(), Delay::from_numer_denom_ms(40, 1));
+    }
+}
@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/catalog.rs
+//
+// Folder catalogs: key metadata of many images as CSV or JSON.
+
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::document::core::file_bytes::FileBytes;
+use crate::document::core::json::escape_json;
+use crate::document::core::metadata::ExifMeta;
+
+/// Columns of a catalog, also the keys of its JSON objects.
+pub const COLUMNS: [&str; 8] = [
+    "file_name",
+    "file_size",
+    "width",
+    "height",
+    "date_time",
+    "camera",
+    "latitude",
+    "longitude",
+];
+
+/// Key metadata of one file.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CatalogEntry {
+    pub file_name: String,
+    /// Size in bytes.
+    pub file_size: u64,
+    /// Pixel size (width, height), if the file header gives one.
+    pub dimensions: Option<(u32, u32)>,
+    /// EXIF capture date.
+    pub date_time: Option<String>,
+    /// Camera make and model.
+    pub camera: Option<String>,
+    /// GPS location (latitude, longitude) in degrees.
+    pub location: Option<(f64, f64)>,
+}
+
+/// Value of a catalog cell.
+enum Cell {
+    Text(String),
+    Number(String),
+}
+
+impl CatalogEntry {
+    /// Entry of the file at `path`.
+    ///
+    /// Only the header and the EXIF data are read, the pixels are not
+    /// decoded. Documents and vector graphics get no pixel size.
+    #[must_use]
+    pub fn read(path: &Path) -> Self {
+        let file_name = path
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        let file_size = std::fs::metadata(path).map_or(0, |meta| meta.len());
+        let dimensions = image::image_dimensions(path).ok();
+        let exif = FileBytes::open(path)
+            .ok()
+            .and_then(|bytes| ExifMeta::from_bytes(&bytes));
+
+        Self {
+            file_name,
+            file_size,
+            dimensions,
+            date_time: exif.as_ref().and_then(|exif| exif.date_time.clone()),
+            camera: exif.as_ref().and_then(ExifMeta::camera_display),
+            location: exif.and_then(|exif| exif.gps_latitude.zip(exif.gps_longitude)),
+        }
+    }
+
+    /// Cells in the order of `COLUMNS`, None where the value is unknown.
+    fn cells(&self) -> [Option<Cell>; 8] {
+        let number = |value: String| Some(Cell::Number(value));
+        [
+            Some(Cell::Text(self.file_name.clone())),
+            number(self.file_size.to_string()),
+            self.dimensions
+                .and_then(|(width, _)| number(width.to_string())),
+            self.dimensions
+                .and_then(|(_, height)| number(height.to_string())),
+            self.date_time.clone().map(Cell::Text),
+            self.camera.clone().map(Cell::Text),
+            self.location
+                .and_then(|(latitude, _)| number(format!("{latitude:.6}"))),
+            self.location
+                .and_then(|(_, longitude)| number(format!("{longitude:.6}"))),
+        ]
+    }
+}
+
+/// File format of a catalog.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CatalogFormat {
+    /// Comma-separated values with a header row, for spreadsheets.
+    #[default]
+    Csv,
+    /// Array of objects, one per file.
+    Json,
+}
+
+impl CatalogFormat {
+    /// Format for the extension of `path` (CSV unless it is `.json`).
+    #[must_use]
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Csv,
+        }
+    }
+
+    /// File extension without the dot.
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    /// Catalog of `entries` in this format.
+    #[must_use]
+    pub fn write(self, entries: &[CatalogEntry]) -> String {
+        match self {
+            Self::Csv => to_csv(entries),
+            Self::Json => to_json(entries),
+        }
+    }
+}
+
+/// CSV with a header row of `COLUMNS`; unknown values are left empty.
+#[must_use]
+pub fn to_csv(entries: &[CatalogEntry]) -> String {
+    let mut csv = COLUMNS.join(",");
+    csv.push_str("\r\n");
+    for entry in entries {
+        let row: Vec<String> = entry
+            .cells()
+            .into_iter()
+            .map(|cell| match cell {
+                Some(Cell::Text(text)) => escape_csv(&text),
+                Some(Cell::Number(number)) => number,
+                None => String::new(),
+            })
+            .collect();
+        csv.push_str(&row.join(","));
+        csv.push_str("\r\n");
+    }
+    csv
+}
+
+/// JSON array with an object per entry; unknown values are `null`.
+#[must_use]
+pub fn to_json(entries: &[CatalogEntry]) -> String {
+    let mut json = String::from("[");
+    for (i, entry) in entries.iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        let fields: Vec<String> = COLUMNS
+            .iter()
+            .zip(entry.cells())
+            .map(|(key, cell)| match cell {
+                Some(Cell::Text(text)) => format!("\"{key}\": \"{}\"", escape_json(&text)),
+                Some(Cell::Number(number)) => format!("\"{key}\": {number}"),
+                None => format!("\"{key}\": null"),
+            })
+            .collect();
+        let _ = write!(json, "{separator}\n  {{{}}}", fields.join(", "));
+    }
+    if !entries.is_empty() {
+        json.push('\n');
+    }
+    json.push_str("]\n");
+    json
+}
+
+/// Quote a CSV value if it holds a separator, quote or line break.
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries() -> Vec<CatalogEntry> {
+        vec![
+            CatalogEntry {
+                file_name: "beach, day 1.jpg".to_string(),
+                file_size: 2_048_000,
+                dimensions: Some((4000, 3000)),
+                date_time: Some("2024-07-01 10:15:00".to_string()),
+                camera: Some("Canon EOS R6".to_string()),
+                location: Some((43.5, -1.25)),
+            },
+            CatalogEntry {
+                file_name: "scan.pdf".to_string(),
+                file_size: 512,
+                ..CatalogEntry::default()
+            },
+        ]
+    }
+
+    #[test]
+    fn test_csv() {
+        let csv = to_csv(&entries());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "file_name,file_size,width,height,date_time,camera,latitude,longitude"
+        );
+        assert_eq!(
+            lines[1],
+            "\"beach, day 1.jpg\",2048000,4000,3000,2024-07-01 10:15:00,Canon EOS R6,43.500000,-1.250000"
+        );
+        assert_eq!(lines[2], "scan.pdf,512,,,,,,");
+        assert_eq!(escape_csv("say \"cheese\""), "\"say \"\"cheese\"\"\"");
+    }
+
+    #[test]
+    fn test_json() {
+        let json = to_json(&entries());
+        assert!(json.starts_with(
+            "[\n  {\"file_name\": \"beach, day 1.jpg\", \"file_size\": 2048000, \"width\": 4000,"
+        ));
+        assert!(json.contains("\"latitude\": 43.500000, \"longitude\": -1.250000},\n"));
+        assert!(
+            json.contains("{\"file_name\": \"scan.pdf\", \"file_size\": 512, \"width\": null,")
+        );
+        assert!(json.ends_with("\"longitude\": null}\n]\n"));
+        assert_eq!(to_json(&[]), "[]\n");
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            CatalogFormat::from_path(Path::new("photos.JSON")),
+            CatalogFormat::Json
+        );
+        assert_eq!(
+            CatalogFormat::from_path(Path::new("photos.csv")),
+            CatalogFormat::Csv
+        );
+        assert_eq!(
+            CatalogFormat::from_path(Path::new("photos")),
+            CatalogFormat::Csv
+        );
+    }
+}
@@ -0,0 +1,200 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/crop.rs
+//
+// Crop operation domain model.
+
+/// Crop region in pixel coordinates.
+/// 
+/// Pure domain model - represents a rectangular region to crop.
+/// No UI concerns, just data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CropRegion {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl CropRegion {
+    pub fn new(x: u32, y: u32, width: u32, height: u32) -> Self {
+        Self { x, y, width, height }
+    }
+
+    pub fn as_tuple(&self) -> (u32, u32, u32, u32) {
+        (self.x, self.y, self.width, self.height)
+    }
+
+    /// Check if region has valid dimensions.
+    pub fn is_valid(&self) -> bool {
+        self.width > 0 && self.height > 0
+    }
+
+    /// Parse a region typed by the user.
+    ///
+    /// Accepts `x,y,w,h` (commas or spaces) and ImageMagick geometry
+    /// `WxH+X+Y` (`×` also works, the offset is optional).
+    pub fn parse_geometry(input: &str) -> Option<Self> {
+        let input = input.trim();
+        let number = |s: &str| s.trim().parse::<u32>().ok();
+
+        if let Some((width, rest)) = input.split_once(['x', 'X', '×']) {
+            let (height, offset) = match rest.find('+') {
+                Some(idx) => rest.split_at(idx),
+                None => (rest, ""),
+            };
+            let mut offsets = offset.split('+').skip(1);
+            let x = offsets.next().map_or(Some(0), number)?;
+            let y = offsets.next().map_or(Some(0), number)?;
+            if offsets.next().is_some() {
+                return None;
+            }
+            let region = Self::new(x, y, number(width)?, number(height)?);
+            return region.is_valid().then_some(region);
+        }
+
+        let parts: Vec<u32> = input
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|p| !p.is_empty())
+            .map(number)
+            .collect::<Option<_>>()?;
+        let [x, y, width, height] = parts.as_slice() else {
+            return None;
+        };
+        let region = Self::new(*x, *y, *width, *height);
+        region.is_valid().then_some(region)
+    }
+
+    /// Check if the region lies completely inside an image of the given size.
+    pub fn fits_within(&self, width: u32, height: u32) -> bool {
+        self.x.checked_add(self.width).is_some_and(|right| right <= width)
+            && self.y.checked_add(self.height).is_some_and(|bottom| bottom <= height)
+    }
+
+    /// Largest region with the aspect ratio `aw:ah`, centered in an image
+    /// of the given size.
+    pub fn centered_with_aspect(width: u32, height: u32, (aw, ah): (u32, u32)) -> Self {
+        let (width64, height64) = (u64::from(width), u64::from(height));
+        let (aw, ah) = (u64::from(aw.max(1)), u64::from(ah.max(1)));
+        let (region_width, region_height) = if width64 * ah > height64 * aw {
+            // Wider than the ratio: full height, the sides are cut off
+            ((height64 * aw + ah / 2) / ah, height64)
+        } else {
+            (width64, (width64 * ah + aw / 2) / aw)
+        };
+        let region_width = u32::try_from(region_width)
+            .unwrap_or(width)
+            .clamp(1, width.max(1));
+        let region_height = u32::try_from(region_height)
+            .unwrap_or(height)
+            .clamp(1, height.max(1));
+        Self::new(
+            width.saturating_sub(region_width) / 2,
+            height.saturating_sub(region_height) / 2,
+            region_width,
+            region_height,
+        )
+    }
+
+    /// Size of the region in megapixels.
+    pub fn megapixels(&self) -> f64 {
+        f64::from(self.width) * f64::from(self.height) / 1_000_000.0
+    }
+
+    /// Format the aspect ratio as "3:2", or "2.39:1" if it has no small
+    /// whole-number form.
+    pub fn aspect_ratio_display(&self) -> String {
+        if !self.is_valid() {
+            return String::new();
+        }
+        let divisor = gcd(self.width, self.height);
+        let (width, height) = (self.width / divisor, self.height / divisor);
+        if width <= 32 && height <= 32 {
+            format!("{width}:{height}")
+        } else if self.width >= self.height {
+            format!("{:.2}:1", f64::from(self.width) / f64::from(self.height))
+        } else {
+            format!("1:{:.2}", f64::from(self.height) / f64::from(self.width))
+        }
+    }
+}
+
+/// Greatest common divisor.
+fn gcd(a: u32, b: u32) -> u32 {
+    if b == 0 { a } else { gcd(b, a % b) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_comma_list() {
+        assert_eq!(
+            CropRegion::parse_geometry("10, 20, 300, 200"),
+            Some(CropRegion::new(10, 20, 300, 200))
+        );
+        assert_eq!(
+            CropRegion::parse_geometry("0 0 64 64"),
+            Some(CropRegion::new(0, 0, 64, 64))
+        );
+        assert!(CropRegion::parse_geometry("10, 20, 0, 5").is_none());
+        assert!(CropRegion::parse_geometry("10, 20, 30").is_none());
+    }
+
+    #[test]
+    fn test_parse_imagemagick_geometry() {
+        assert_eq!(
+            CropRegion::parse_geometry("1920x1080+40+12"),
+            Some(CropRegion::new(40, 12, 1920, 1080))
+        );
+        assert_eq!(
+            CropRegion::parse_geometry("800×600"),
+            Some(CropRegion::new(0, 0, 800, 600))
+        );
+        assert!(CropRegion::parse_geometry("800x600-10-10").is_none());
+        assert!(CropRegion::parse_geometry("800x600+1+2+3").is_none());
+    }
+
+    #[test]
+    fn test_fits_within() {
+        let region = CropRegion::new(10, 10, 90, 40);
+        assert!(region.fits_within(100, 50));
+        assert!(!region.fits_within(99, 50));
+        assert!(!CropRegion::new(u32::MAX, 0, 2, 2).fits_within(100, 100));
+    }
+
+    #[test]
+    fn test_aspect_ratio_and_megapixels() {
+        let ratio = |width, height| CropRegion::new(0, 0, width, height).aspect_ratio_display();
+        assert_eq!(ratio(6000, 4000), "3:2");
+        assert_eq!(ratio(1080, 1920), "9:16");
+        assert_eq!(ratio(2390, 1000), "2.39:1");
+        assert_eq!(ratio(1000, 1234), "1:1.23");
+        assert_eq!(ratio(0, 10), "");
+        assert!((CropRegion::new(5, 5, 2000, 1500).megapixels() - 3.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_centered_with_aspect() {
+        // Landscape photo for a 16:9 screen: top and bottom are cut off
+        assert_eq!(
+            CropRegion::centered_with_aspect(4000, 3000, (16, 9)),
+            CropRegion::new(0, 375, 4000, 2250)
+        );
+        // Panorama for a 16:10 screen: the sides are cut off
+        assert_eq!(
+            CropRegion::centered_with_aspect(6000, 2000, (16, 10)),
+            CropRegion::new(1400, 0, 3200, 2000)
+        );
+        // Portrait screen
+        assert_eq!(
+            CropRegion::centered_with_aspect(1920, 1080, (9, 16)),
+            CropRegion::new(656, 0, 608, 1080)
+        );
+        // Already the right shape
+        assert_eq!(
+            CropRegion::centered_with_aspect(2560, 1440, (16, 9)),
+            CropRegion::new(0, 0, 2560, 1440)
+        );
+    }
+}
@@ -0,0 +1,514 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/date_shift.rs
+//
+// Shift EXIF capture timestamps by a fixed offset (wrong camera clock).
+
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Seek};
+use std::path::{Path, PathBuf};
+
+use super::exif_write::update_exif_fields;
+use crate::document::core::document::DocResult;
+use crate::document::core::temp_file;
+
+/// Seconds per minute / hour / day.
+const MINUTE: i64 = 60;
+const HOUR: i64 = 60 * MINUTE;
+const DAY: i64 = 24 * HOUR;
+
+/// EXIF fields holding a date, shifted together.
+const DATE_TAGS: [exif::Tag; 3] = [
+    exif::Tag::DateTimeOriginal,
+    exif::Tag::DateTimeDigitized,
+    exif::Tag::DateTime,
+];
+
+/// XMP properties of the capture time, with their namespaces.
+const XMP_DATE_PROPERTIES: [(&str, &str); 2] = [
+    ("exif:DateTimeOriginal", "http://ns.adobe.com/exif/1.0/"),
+    ("xmp:CreateDate", "http://ns.adobe.com/xap/1.0/"),
+];
+
+/// EXIF date/time value (`YYYY:MM:DD HH:MM:SS`, no time zone).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ExifDateTime {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl ExifDateTime {
+    /// Parse an EXIF date/time string (`YYYY:MM:DD HH:MM:SS`).
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().trim_matches('"');
+        let (date, time) = value.split_once(' ')?;
+
+        let mut date = date.split([':', '-']).map(str::parse::<u32>);
+        let mut time = time.split(':').map(str::parse::<u32>);
+
+        let parsed = Self {
+            year: i32::try_from(date.next()?.ok()?).ok()?,
+            month: date.next()?.ok()?,
+            day: date.next()?.ok()?,
+            hour: time.next()?.ok()?,
+            minute: time.next()?.ok()?,
+            second: time.next()?.ok()?,
+        };
+
+        let valid = (1..=12).contains(&parsed.month)
+            && (1..=days_in_month(parsed.year, parsed.month)).contains(&parsed.day)
+            && parsed.hour < 24
+            && parsed.minute < 60
+            && parsed.second < 60;
+        valid.then_some(parsed)
+    }
+
+    /// Return this timestamp shifted by `offset` seconds.
+    #[must_use]
+    pub fn shifted(self, offset: i64) -> Self {
+        let days = days_from_civil(self.year, self.month, self.day);
+        let secs = i64::from(self.hour) * HOUR
+            + i64::from(self.minute) * MINUTE
+            + i64::from(self.second)
+            + offset;
+
+        let (year, month, day) = civil_from_days(days + secs.div_euclid(DAY));
+        let secs = secs.rem_euclid(DAY);
+
+        Self {
+            year,
+            month,
+            day,
+            hour: (secs / HOUR) as u32,
+            minute: (secs % HOUR / MINUTE) as u32,
+            second: (secs % MINUTE) as u32,
+        }
+    }
+
+    /// ISO 8601 form (`YYYY-MM-DDTHH:MM:SS`) used in XMP.
+    #[must_use]
+    pub fn to_iso8601(self) -> String {
+        format!(
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl fmt::Display for ExifDateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:04}:{:02}:{:02} {:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+/// Parse a time offset like `+1d 02:30`, `-01:00:00`, `2h 15m` or `-90s`.
+///
+/// Returns the offset in seconds.
+pub fn parse_offset(input: &str) -> Option<i64> {
+    let input = input.trim();
+    let (sign, rest) = match input.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, input.strip_prefix('+').unwrap_or(input)),
+    };
+
+    let mut total = 0i64;
+    let mut any = false;
+    for part in rest.split_whitespace() {
+        if part.contains(':') {
+            // HH:MM[:SS]
+            let fields: Vec<i64> = part
+                .split(':')
+                .map(|f| f.parse::<i64>().ok())
+                .collect::<Option<_>>()?;
+            total += match fields.as_slice() {
+                [h, m] => h * HOUR + m * MINUTE,
+                [h, m, s] => h * HOUR + m * MINUTE + s,
+                _ => return None,
+            };
+        } else {
+            let split = part.find(|c: char| !c.is_ascii_digit())?;
+            let (value, unit) = part.split_at(split);
+            let value: i64 = value.parse().ok()?;
+            total += value
+                * match unit {
+                    "d" => DAY,
+                    "h" => HOUR,
+                    "m" => MINUTE,
+                    "s" => 1,
+                    _ => return None,
+                };
+        }
+        any = true;
+    }
+
+    any.then_some(sign * total)
+}
+
+/// Planned date change for a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateShiftEntry {
+    pub path: PathBuf,
+    /// Capture time currently stored in the file.
+    pub original: ExifDateTime,
+}
+
+impl DateShiftEntry {
+    /// Read the capture time of a file (`DateTimeOriginal`, falling back to `DateTime`).
+    pub fn read(path: &Path) -> Option<Self> {
+        Some(Self {
+            path: path.to_path_buf(),
+            original: capture_date(path)?,
+        })
+    }
+}
+
+/// Capture time of a file (`DateTimeOriginal`, falling back to `DateTime`).
+///
+/// Reads the file only up to its EXIF data.
+#[must_use]
+pub fn capture_date(path: &Path) -> Option<ExifDateTime> {
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    read_exif_dates(&mut reader)
+        .into_iter()
+        .find_map(|(tag, value)| {
+            matches!(tag, exif::Tag::DateTimeOriginal | exif::Tag::DateTime).then_some(value)
+        })
+}
+
+/// Collect the capture times of all files that have one.
+#[must_use]
+pub fn plan_date_shift(paths: &[PathBuf]) -> Vec<DateShiftEntry> {
+    paths
+        .iter()
+        .filter_map(|p| DateShiftEntry::read(p))
+        .collect()
+}
+
+/// Shift all EXIF date fields of a JPEG or PNG file in place.
+///
+/// The date fields are rewritten through the EXIF writer in one pass, so
+/// each is shifted exactly once and no other bytes of the file change.
+/// Returns the number of fields changed.
+pub fn shift_file_dates(path: &Path, offset: i64) -> DocResult<usize> {
+    let dates = read_exif_dates(&mut BufReader::new(File::open(path)?));
+    if dates.is_empty() {
+        return Err(anyhow::anyhow!("No EXIF dates in {}", path.display()));
+    }
+
+    let mut changed = 0;
+    update_exif_fields(path, |fields| {
+        for field in fields.iter_mut().filter(|f| DATE_TAGS.contains(&f.tag)) {
+            let exif::Value::Ascii(values) = &mut field.value else {
+                continue;
+            };
+            let Some(value) = values.first_mut() else {
+                continue;
+            };
+            if let Some(date) = std::str::from_utf8(value)
+                .ok()
+                .and_then(ExifDateTime::parse)
+            {
+                *value = date.shifted(offset).to_string().into_bytes();
+                changed += 1;
+            }
+        }
+    })?;
+    Ok(changed)
+}
+
+/// Write the shifted capture time to an XMP sidecar (`<file>.xmp`).
+///
+/// The image file itself is left untouched. An existing sidecar (with the
+/// edit history of darktable, digiKam or RawTherapee) keeps everything but
+/// its capture time properties; one without an `rdf:Description` to hold
+/// them is refused. Returns the sidecar path.
+pub fn write_xmp_sidecar(path: &Path, date: ExifDateTime) -> DocResult<PathBuf> {
+    let mut sidecar = path.as_os_str().to_owned();
+    sidecar.push(".xmp");
+    let sidecar = PathBuf::from(sidecar);
+
+    let iso = date.to_iso8601();
+    let xmp = match std::fs::read_to_string(&sidecar) {
+        Ok(existing) => with_xmp_dates(&existing, &iso).ok_or_else(|| {
+            anyhow::anyhow!("No rdf:Description for the date in {}", sidecar.display())
+        })?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => format!(
+            r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+<x:xmpmeta xmlns:x="adobe:ns:meta/">
+ <rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+  <rdf:Description rdf:about=""
+    xmlns:exif="http://ns.adobe.com/exif/1.0/"
+    xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+    xmlns:photoshop="http://ns.adobe.com/photoshop/1.0/"
+   exif:DateTimeOriginal="{iso}"
+   xmp:CreateDate="{iso}"
+   photoshop:DateCreated="{iso}"/>
+ </rdf:RDF>
+</x:xmpmeta>
+<?xpacket end="w"?>
+"#
+        ),
+        Err(e) => return Err(e.into()),
+    };
+
+    temp_file::write_bytes(&sidecar, xmp)?;
+    Ok(sidecar)
+}
+
+/// `xmp` with the capture time properties set to `iso`.
+///
+/// Properties written as attribute or element are updated in place; missing
+/// ones (and their namespaces) are added to the first `rdf:Description`.
+/// None if they are missing and there is no `rdf:Description`.
+fn with_xmp_dates(xmp: &str, iso: &str) -> Option<String> {
+    let mut xmp = xmp.to_string();
+    let mut missing = Vec::new();
+    for (name, namespace) in XMP_DATE_PROPERTIES {
+        match xmp_value_range(&xmp, name) {
+            Some(range) => xmp.replace_range(range, iso),
+            None => missing.push((name, namespace)),
+        }
+    }
+    if missing.is_empty() {
+        return Some(xmp);
+    }
+
+    let tag = "<rdf:Description";
+    let at = xmp.find(tag)? + tag.len();
+    let mut added = String::new();
+    for (name, namespace) in missing {
+        let prefix = name.split(':').next().unwrap_or_default();
+        if !xmp.contains(&format!("xmlns:{prefix}=")) && !added.contains(namespace) {
+            added.push_str(&format!("\n    xmlns:{prefix}=\"{namespace}\""));
+        }
+        added.push_str(&format!("\n   {name}=\"{iso}\""));
+    }
+    xmp.insert_str(at, &added);
+    Some(xmp)
+}
+
+/// Byte range of the value of the property `name`, written as attribute
+/// (`name="value"`) or as element (`<name>value</name>`).
+fn xmp_value_range(xmp: &str, name: &str) -> Option<std::ops::Range<usize>> {
+    let attribute = format!("{name}=");
+    let mut from = 0;
+    while let Some(found) = xmp[from..].find(&attribute) {
+        let start = from + found;
+        from = start + attribute.len();
+        // Not the tail of a longer name
+        if !xmp[..start].ends_with(char::is_whitespace) {
+            continue;
+        }
+        let quote = xmp[from..]
+            .chars()
+            .next()
+            .filter(|&c| c == '"' || c == '\'')?;
+        let value = from + 1;
+        return Some(value..value + xmp[value..].find(quote)?);
+    }
+    let start = xmp.find(&format!("<{name}>"))? + name.len() + 2;
+    let end = xmp[start..].find(&format!("</{name}>"))?;
+    Some(start..start + end)
+}
+
+/// Read all EXIF date fields (DateTimeOriginal, DateTimeDigitized, DateTime).
+fn read_exif_dates(reader: &mut (impl BufRead + Seek)) -> Vec<(exif::Tag, ExifDateTime)> {
+    use exif::{In, Reader};
+
+    let Ok(exif) = Reader::new().read_from_container(reader) else {
+        return Vec::new();
+    };
+
+    DATE_TAGS
+        .into_iter()
+        .filter_map(|tag| {
+            let field = exif.get_field(tag, In::PRIMARY)?;
+            let exif::Value::Ascii(ref values) = field.value else {
+                return None;
+            };
+            let value = std::str::from_utf8(values.first()?).ok()?;
+            Some((tag, ExifDateTime::parse(value)?))
+        })
+        .collect()
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date.
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Proleptic Gregorian date for days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let doe = days - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year as i32, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dt(value: &str) -> ExifDateTime {
+        ExifDateTime::parse(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_and_format() {
+        let value = dt("2024:02:29 23:59:30");
+        assert_eq!(value.to_string(), "2024:02:29 23:59:30");
+        assert_eq!(value.to_iso8601(), "2024-02-29T23:59:30");
+
+        assert!(ExifDateTime::parse("2023:02:29 10:00:00").is_none());
+        assert!(ExifDateTime::parse("0000:00:00 00:00:00").is_none());
+        assert!(ExifDateTime::parse("garbage").is_none());
+    }
+
+    #[test]
+    fn test_shift_across_boundaries() {
+        assert_eq!(
+            dt("2024:02:28 23:30:00").shifted(HOUR),
+            dt("2024:02:29 00:30:00")
+        );
+        assert_eq!(
+            dt("2023:12:31 23:00:00").shifted(2 * HOUR),
+            dt("2024:01:01 01:00:00")
+        );
+        assert_eq!(
+            dt("2024:03:01 00:00:10").shifted(-20),
+            dt("2024:02:29 23:59:50")
+        );
+        assert_eq!(
+            dt("2024:01:15 12:00:00").shifted(-365 * DAY),
+            dt("2023:01:15 12:00:00")
+        );
+    }
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(
+            parse_offset("+1d 02:30"),
+            Some(DAY + 2 * HOUR + 30 * MINUTE)
+        );
+        assert_eq!(parse_offset("-01:00:05"), Some(-(HOUR + 5)));
+        assert_eq!(parse_offset("2h 15m"), Some(2 * HOUR + 15 * MINUTE));
+        assert_eq!(parse_offset("-90s"), Some(-90));
+        assert_eq!(parse_offset(""), None);
+        assert_eq!(parse_offset("3x"), None);
+    }
+
+    #[test]
+    fn test_shift_dates_one_offset_apart() {
+        let dir = std::env::temp_dir().join("noctua-date-shift-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("dates.jpg");
+        image::RgbImage::new(4, 2).save(&path).unwrap();
+
+        let ascii = |text: &str| exif::Value::Ascii(vec![text.as_bytes().to_vec()]);
+        let field = |tag, text: &str| exif::Field {
+            tag,
+            ifd_num: exif::In::PRIMARY,
+            value: ascii(text),
+        };
+        update_exif_fields(&path, |fields| {
+            fields.extend([
+                field(exif::Tag::DateTimeOriginal, "2024:05:01 10:00:00"),
+                field(exif::Tag::DateTime, "2024:05:01 11:00:00"),
+            ]);
+        })
+        .unwrap();
+
+        // Shifting the earlier date must not shift the later one twice
+        assert_eq!(shift_file_dates(&path, HOUR).unwrap(), 2);
+        let mut reader = BufReader::new(File::open(&path).unwrap());
+        let dates = read_exif_dates(&mut reader);
+        assert_eq!(
+            dates,
+            vec![
+                (exif::Tag::DateTimeOriginal, dt("2024:05:01 11:00:00")),
+                (exif::Tag::DateTime, dt("2024:05:01 12:00:00")),
+            ]
+        );
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_xmp_sidecar_keeps_edits() {
+        let dir = std::env::temp_dir().join("noctua-date-shift-xmp-test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("owl.cr2");
+        let sidecar = dir.join("owl.cr2.xmp");
+        let date = dt("2024:05:01 11:00:00");
+
+        // A new sidecar holds just the date
+        assert_eq!(write_xmp_sidecar(&path, date).unwrap(), sidecar);
+        let written = std::fs::read_to_string(&sidecar).unwrap();
+        assert!(written.contains(r#"exif:DateTimeOriginal="2024-05-01T11:00:00""#));
+
+        // darktable style: the history stays, the old date is replaced
+        let darktable = r#"<x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF>
+  <rdf:Description rdf:about="" xmlns:darktable="http://darktable.sf.net/"
+    xmlns:exif="http://ns.adobe.com/exif/1.0/"
+    exif:DateTimeOriginal="2024-05-01T10:00:00" darktable:history_end="3">
+   <darktable:history><rdf:Seq><rdf:li darktable:operation="exposure"/></rdf:Seq></darktable:history>
+  </rdf:Description></rdf:RDF></x:xmpmeta>"#;
+        std::fs::write(&sidecar, darktable).unwrap();
+        write_xmp_sidecar(&path, date).unwrap();
+        let written = std::fs::read_to_string(&sidecar).unwrap();
+        assert!(written.contains(r#"exif:DateTimeOriginal="2024-05-01T11:00:00""#));
+        assert!(!written.contains("10:00:00"));
+        assert!(written.contains(r#"xmlns:xmp="http://ns.adobe.com/xap/1.0/""#));
+        assert!(written.contains(r#"xmp:CreateDate="2024-05-01T11:00:00""#));
+        assert!(written.contains(r#"darktable:operation="exposure""#));
+        assert!(written.contains(r#"darktable:history_end="3""#));
+
+        // Element form is updated in place
+        let element = "<rdf:Description><xmp:CreateDate>2020-01-01T00:00:00</xmp:CreateDate>\
+                       <exif:DateTimeOriginal>2020-01-01T00:00:00</exif:DateTimeOriginal>\
+                       </rdf:Description>";
+        assert_eq!(
+            with_xmp_dates(element, "2024-05-01T11:00:00").unwrap(),
+            element.replace("2020-01-01T00:00:00", "2024-05-01T11:00:00")
+        );
+
+        // Nothing to put the date into: the sidecar is left alone
+        std::fs::write(&sidecar, "<x:xmpmeta/>").unwrap();
+        assert!(write_xmp_sidecar(&path, date).is_err());
+        assert_eq!(std::fs::read_to_string(&sidecar).unwrap(), "<x:xmpmeta/>");
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
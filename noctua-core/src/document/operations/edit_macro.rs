@@ -0,0 +1,367 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/edit_macro.rs
+//
+// Edit macros: recorded operation sequences replayed on image files.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use image::DynamicImage;
+use image::imageops::FilterType;
+
+use super::alpha;
+use super::export::{self, ExportFormat, ImageExportOptions};
+use super::scan_cleanup::ScanCleanup;
+use crate::document::core::document::DocResult;
+
+/// One step of an edit macro.
+///
+/// Steps are stored as text (see `Display` and `parse`), e.g.
+/// `rotate-cw; crop 16:9; resize 1920w; export webp`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MacroStep {
+    RotateCw,
+    RotateCcw,
+    FlipHorizontal,
+    FlipVertical,
+    /// Largest centered crop with this aspect ratio (width, height).
+    CropAspect(u32, u32),
+    /// Crop to a rectangle relative to the image size (0.0 to 1.0).
+    CropRelative {
+        x: f32,
+        y: f32,
+        width: f32,
+        height: f32,
+    },
+    /// Scale to this width, keeping the aspect ratio.
+    ResizeWidth(u32),
+    /// Scale to this height, keeping the aspect ratio.
+    ResizeHeight(u32),
+    /// Remove specks and whiten the paper of a scan.
+    CleanScan(ScanCleanup),
+    /// Format of the written copy.
+    Export(ExportFormat),
+}
+
+impl MacroStep {
+    /// Relative crop step for a pixel rectangle in an image of the given size.
+    #[must_use]
+    pub fn crop_from_pixels(
+        (x, y, width, height): (u32, u32, u32, u32),
+        image_width: u32,
+        image_height: u32,
+    ) -> Option<Self> {
+        if width == 0 || height == 0 || image_width == 0 || image_height == 0 {
+            return None;
+        }
+        let (iw, ih) = (image_width as f32, image_height as f32);
+        Some(Self::CropRelative {
+            x: x as f32 / iw,
+            y: y as f32 / ih,
+            width: width as f32 / iw,
+            height: height as f32 / ih,
+        })
+    }
+
+    /// Parse a single step (the inverse of `Display`).
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim().to_lowercase();
+        let (name, arg) = input
+            .split_once(char::is_whitespace)
+            .map_or((input.as_str(), ""), |(name, arg)| (name, arg.trim()));
+
+        match (name, arg) {
+            ("rotate-cw", "") => Some(Self::RotateCw),
+            ("rotate-ccw", "") => Some(Self::RotateCcw),
+            ("flip-h", "") => Some(Self::FlipHorizontal),
+            ("flip-v", "") => Some(Self::FlipVertical),
+            ("crop", arg) => parse_crop(arg),
+            ("resize", arg) => {
+                if let Some(width) = arg.strip_suffix('w') {
+                    width
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|&w| w > 0)
+                        .map(Self::ResizeWidth)
+                } else {
+                    let height = arg.strip_suffix('h')?;
+                    height
+                        .trim()
+                        .parse()
+                        .ok()
+                        .filter(|&h| h > 0)
+                        .map(Self::ResizeHeight)
+                }
+            }
+            ("clean-scan", arg) => parse_clean_scan(arg),
+            ("export", arg) => ExportFormat::from_path(Path::new(&format!("x.{arg}")))
+                .filter(|format| is_image_format(*format))
+                .map(Self::Export),
+            _ => None,
+        }
+    }
+
+    /// Apply the step to an image (`Export` leaves it unchanged).
+    #[must_use]
+    pub fn apply(&self, img: DynamicImage) -> DynamicImage {
+        let (width, height) = (img.width(), img.height());
+        match *self {
+            Self::RotateCw => img.rotate90(),
+            Self::RotateCcw => img.rotate270(),
+            Self::FlipHorizontal => img.fliph(),
+            Self::FlipVertical => img.flipv(),
+            Self::CropAspect(aw, ah) => {
+                let (x, y, w, h) = centered_aspect_crop((width, height), (aw, ah));
+                img.crop_imm(x, y, w, h)
+            }
+            Self::CropRelative {
+                x,
+                y,
+                width: w,
+                height: h,
+            } => {
+                let scale = |v: f32, size: u32| ((v * size as f32).round() as u32).min(size);
+                let (left, top) = (scale(x, width), scale(y, height));
+                let right = scale(x + w, width).max(left + 1).min(width);
+                let bottom = scale(y + h, height).max(top + 1).min(height);
+                if left >= right || top >= bottom {
+                    return img;
+                }
+                img.crop_imm(left, top, right - left, bottom - top)
+            }
+            Self::ResizeWidth(target) => {
+                let target_height = scaled(height, target, width);
+                alpha::resize_exact(&img, target, target_height, FilterType::Lanczos3)
+            }
+            Self::ResizeHeight(target) => {
+                let target_width = scaled(width, target, height);
+                alpha::resize_exact(&img, target_width, target, FilterType::Lanczos3)
+            }
+            Self::CleanScan(cleanup) => cleanup.apply(&img),
+            Self::Export(_) => img,
+        }
+    }
+}
+
+impl fmt::Display for MacroStep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::RotateCw => write!(f, "rotate-cw"),
+            Self::RotateCcw => write!(f, "rotate-ccw"),
+            Self::FlipHorizontal => write!(f, "flip-h"),
+            Self::FlipVertical => write!(f, "flip-v"),
+            Self::CropAspect(w, h) => write!(f, "crop {w}:{h}"),
+            Self::CropRelative {
+                x,
+                y,
+                width,
+                height,
+            } => write!(
+                f,
+                "crop {}%,{}%,{}%,{}%",
+                percent(*x),
+                percent(*y),
+                percent(*width),
+                percent(*height)
+            ),
+            Self::ResizeWidth(w) => write!(f, "resize {w}w"),
+            Self::ResizeHeight(h) => write!(f, "resize {h}h"),
+            Self::CleanScan(cleanup) => {
+                write!(f, "clean-scan {}px {}%", cleanup.despeckle, cleanup.whiten)
+            }
+            Self::Export(format) => write!(f, "export {}", format.extension()),
+        }
+    }
+}
+
+/// Parse a macro (steps separated by `;` or new lines).
+///
+/// Returns the first invalid step on error.
+pub fn parse_steps(input: &str) -> Result<Vec<MacroStep>, String> {
+    input
+        .split([';', '\n'])
+        .map(str::trim)
+        .filter(|step| !step.is_empty())
+        .map(|step| MacroStep::parse(step).ok_or_else(|| step.to_string()))
+        .collect()
+}
+
+/// Text form of a macro, as stored in the configuration.
+#[must_use]
+pub fn format_steps(steps: &[MacroStep]) -> String {
+    steps
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Apply all steps to an image.
+#[must_use]
+pub fn apply_steps(img: DynamicImage, steps: &[MacroStep]) -> DynamicImage {
+    steps.iter().fold(img, |img, step| step.apply(img))
+}
+
+/// Run the steps on `source` and write the result into `output_dir`.
+///
+/// The copy keeps the file name, with the extension of the last `export`
+/// step (or of the source, PNG if it cannot be written). Returns the written path.
+pub fn run_on_file(source: &Path, steps: &[MacroStep], output_dir: &Path) -> DocResult<PathBuf> {
+    let format = steps
+        .iter()
+        .rev()
+        .find_map(|step| match step {
+            MacroStep::Export(format) => Some(*format),
+            _ => None,
+        })
+        .or_else(|| ExportFormat::from_path(source).filter(|f| is_image_format(*f)))
+        .unwrap_or(ExportFormat::Png);
+
+    let file_name = source
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Invalid file name: {}", source.display()))?;
+    let mut target = output_dir.join(file_name);
+    target.set_extension(format.extension());
+    if target == source {
+        return Err(anyhow::anyhow!("Refusing to overwrite the original image"));
+    }
+
+    let img = apply_steps(image::open(source)?, steps);
+    export::export_image(&img, &target, format, &ImageExportOptions::default())?;
+    Ok(target)
+}
+
+/// Formats a macro can write.
+fn is_image_format(format: ExportFormat) -> bool {
+    matches!(
+        format,
+        ExportFormat::Png | ExportFormat::Jpeg | ExportFormat::WebP
+    )
+}
+
+/// Parse `16:9` or `10%,5%,80%,90%`.
+fn parse_crop(arg: &str) -> Option<MacroStep> {
+    if let Some((w, h)) = arg.split_once(':') {
+        let (w, h) = (w.trim().parse().ok()?, h.trim().parse().ok()?);
+        return (w > 0 && h > 0).then_some(MacroStep::CropAspect(w, h));
+    }
+
+    let values: Vec<f32> = arg
+        .split(',')
+        .map(|v| {
+            v.trim()
+                .trim_end_matches('%')
+                .parse::<f32>()
+                .map(|v| v / 100.0)
+        })
+        .collect::<Result<_, _>>()
+        .ok()?;
+    let [x, y, width, height] = values.as_slice() else {
+        return None;
+    };
+    let in_range = |v: f32| (0.0..=1.0).contains(&v);
+    (in_range(*x) && in_range(*y) && *width > 0.0 && *height > 0.0).then_some(
+        MacroStep::CropRelative {
+            x: *x,
+            y: *y,
+            width: width.min(1.0),
+            height: height.min(1.0),
+        },
+    )
+}
+
+/// Parse `6px 80%` (largest speck removed, paper whitening).
+fn parse_clean_scan(arg: &str) -> Option<MacroStep> {
+    let (despeckle, whiten) = arg.split_once(char::is_whitespace)?;
+    let despeckle = despeckle.strip_suffix("px")?.parse().ok()?;
+    let whiten = whiten.trim().strip_suffix('%')?.parse().ok()?;
+    (ScanCleanup::DESPECKLE_RANGE.contains(&despeckle)
+        && ScanCleanup::WHITEN_RANGE.contains(&whiten))
+    .then_some(MacroStep::CleanScan(ScanCleanup { despeckle, whiten }))
+}
+
+/// Largest rectangle with the given aspect ratio, centered in the image.
+fn centered_aspect_crop((width, height): (u32, u32), (aw, ah): (u32, u32)) -> (u32, u32, u32, u32) {
+    let target_width = scaled(height, aw, ah).min(width);
+    let target_height = scaled(target_width, ah, aw).min(height);
+    (
+        (width - target_width) / 2,
+        (height - target_height) / 2,
+        target_width.max(1),
+        target_height.max(1),
+    )
+}
+
+/// `value * numerator / denominator`, rounded, at least 1.
+fn scaled(value: u32, numerator: u32, denominator: u32) -> u32 {
+    let result = (u64::from(value) * u64::from(numerator) + u64::from(denominator) / 2)
+        / u64::from(denominator.max(1));
+    u32::try_from(result).unwrap_or(u32::MAX).max(1)
+}
+
+/// Percent value without needless decimals (`12.5`, `50`).
+fn percent(value: f32) -> String {
+    let rounded = (value * 10_000.0).round() / 100.0;
+    format!("{rounded}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_roundtrip() {
+        let text = "rotate-cw; crop 16:9; crop 12.5%,0%,50%,100%; resize 1920w; export webp";
+        let steps = parse_steps(text).unwrap();
+        assert_eq!(steps.len(), 5);
+        assert_eq!(steps[1], MacroStep::CropAspect(16, 9));
+        assert_eq!(steps[3], MacroStep::ResizeWidth(1920));
+        assert_eq!(steps[4], MacroStep::Export(ExportFormat::WebP));
+        assert_eq!(format_steps(&steps), text);
+
+        assert_eq!(
+            parse_steps("flip-h\nresize 0w"),
+            Err("resize 0w".to_string())
+        );
+        assert!(MacroStep::parse("export pdf").is_none());
+
+        let steps = parse_steps("clean-scan 6px 80%").unwrap();
+        let cleanup = ScanCleanup {
+            despeckle: 6,
+            whiten: 80,
+        };
+        assert_eq!(steps, vec![MacroStep::CleanScan(cleanup)]);
+        assert_eq!(format_steps(&steps), "clean-scan 6px 80%");
+        assert!(MacroStep::parse("clean-scan 6px 120%").is_none());
+    }
+
+    #[test]
+    fn test_centered_aspect_crop() {
+        assert_eq!(
+            centered_aspect_crop((4000, 3000), (16, 9)),
+            (0, 375, 4000, 2250)
+        );
+        assert_eq!(
+            centered_aspect_crop((1000, 1000), (16, 9)),
+            (0, 218, 1000, 563)
+        );
+        assert_eq!(
+            centered_aspect_crop((3000, 4000), (1, 1)),
+            (0, 500, 3000, 3000)
+        );
+    }
+
+    #[test]
+    fn test_apply_steps() {
+        let img = DynamicImage::new_rgb8(400, 300);
+        let steps = parse_steps("rotate-cw; resize 150w; crop 0%,0%,50%,50%").unwrap();
+        let out = steps.iter().fold(img, |img, step| step.apply(img));
+        // 300x400 -> 150x200 -> 75x100
+        assert_eq!((out.width(), out.height()), (75, 100));
+
+        let step = MacroStep::crop_from_pixels((100, 0, 200, 150), 400, 300).unwrap();
+        assert_eq!(step.to_string(), "crop 25%,0%,50%,50%");
+    }
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/embedded_preview.rs
+//
+// Thumbnails embedded in the EXIF data of photos, shown while they decode.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use exif::{In, Reader, Tag};
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageFormat};
+
+/// Thumbnail embedded in a JPEG file, turned upright if `auto_orient`.
+///
+/// Cameras store a small JPEG in the EXIF data, which decodes in a fraction
+/// of the time of the full image. Reads the file only up to its EXIF data.
+/// Returns None for other files and photos without a thumbnail.
+#[must_use]
+pub fn embedded_thumbnail(path: &Path, auto_orient: bool) -> Option<DynamicImage> {
+    if ImageFormat::from_path(path).ok()? != ImageFormat::Jpeg {
+        return None;
+    }
+    let mut reader = BufReader::new(File::open(path).ok()?);
+    let exif = Reader::new().read_from_container(&mut reader).ok()?;
+    let mut thumbnail =
+        image::load_from_memory_with_format(thumbnail_data(&exif)?, ImageFormat::Jpeg).ok()?;
+
+    // The thumbnail is stored the same way around as the image
+    let orientation = exif
+        .get_field(Tag::Orientation, In::PRIMARY)
+        .and_then(|field| field.value.get_uint(0))
+        .and_then(|value| Orientation::from_exif(u8::try_from(value).ok()?));
+    if auto_orient && let Some(orientation) = orientation {
+        thumbnail.apply_orientation(orientation);
+    }
+    Some(thumbnail)
+}
+
+/// Embedded JPEG thumbnail referenced by IFD1.
+pub(crate) fn thumbnail_data(exif: &exif::Exif) -> Option<&[u8]> {
+    let offset = exif
+        .get_field(Tag::JPEGInterchangeFormat, In::THUMBNAIL)?
+        .value
+        .get_uint(0)? as usize;
+    let len = exif
+        .get_field(Tag::JPEGInterchangeFormatLength, In::THUMBNAIL)?
+        .value
+        .get_uint(0)? as usize;
+    exif.buf().get(offset..offset.checked_add(len)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use exif::experimental::Writer;
+    use exif::{Field, Value};
+    use image::{GenericImageView, RgbImage};
+    use std::io::Cursor;
+
+    /// JPEG of `width` x `height` pixels.
+    fn jpeg(width: u32, height: u32) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::new());
+        DynamicImage::ImageRgb8(RgbImage::new(width, height))
+            .write_to(&mut bytes, ImageFormat::Jpeg)
+            .unwrap();
+        bytes.into_inner()
+    }
+
+    /// 64 x 32 JPEG with an 8 x 4 thumbnail and the EXIF `orientation`.
+    fn photo_with_thumbnail(orientation: u16) -> Vec<u8> {
+        let thumbnail = jpeg(8, 4);
+        let field = Field {
+            tag: Tag::Orientation,
+            ifd_num: In::PRIMARY,
+            value: Value::Short(vec![orientation]),
+        };
+        let mut writer = Writer::new();
+        writer.push_field(&field);
+        writer.set_jpeg(&thumbnail, In::THUMBNAIL);
+        let mut tiff = Cursor::new(Vec::new());
+        writer.write(&mut tiff, false).unwrap();
+        let tiff = tiff.into_inner();
+
+        // APP1 segment right after SOI
+        let image = jpeg(64, 32);
+        let mut bytes = image[..2].to_vec();
+        bytes.extend_from_slice(&[0xFF, 0xE1]);
+        bytes.extend_from_slice(&(tiff.len() as u16 + 8).to_be_bytes());
+        bytes.extend_from_slice(b"Exif\0\0");
+        bytes.extend_from_slice(&tiff);
+        bytes.extend_from_slice(&image[2..]);
+        bytes
+    }
+
+    #[test]
+    fn test_embedded_thumbnail() {
+        let dir = std::env::temp_dir().join("noctua-embedded-preview-test");
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        let upright = dir.join("upright.jpg");
+        let turned = dir.join("turned.jpg");
+        let plain = dir.join("plain.jpg");
+        std::fs::write(&upright, photo_with_thumbnail(1)).unwrap();
+        std::fs::write(&turned, photo_with_thumbnail(6)).unwrap();
+        std::fs::write(&plain, jpeg(64, 32)).unwrap();
+
+        let dimensions = |path: &Path, auto_orient| {
+            embedded_thumbnail(path, auto_orient).map(|t| t.dimensions())
+        };
+        assert_eq!(dimensions(&upright, true), Some((8, 4)));
+        assert_eq!(dimensions(&turned, true), Some((4, 8)));
+        assert_eq!(dimensions(&turned, false), Some((8, 4)));
+        assert_eq!(dimensions(&plain, true), None);
+
+        // Only JPEG files are looked into
+        let png = dir.join("turned.png");
+        std::fs::copy(&turned, &png).unwrap();
+        assert_eq!(dimensions(&png, true), None);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,390 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/exif_write.rs
+//
+// Rewrite EXIF blocks of JPEG and PNG files (metadata write-back).
+
+use std::fs::File;
+use std::io::{BufReader, Cursor};
+use std::path::Path;
+
+use exif::experimental::Writer;
+use exif::{Field, In, Reader, Tag};
+
+use super::embedded_preview::thumbnail_data;
+use super::export::crc32;
+use crate::document::core::document::DocResult;
+use crate::document::core::temp_file;
+
+/// JPEG APP1 identifier for EXIF data.
+const JPEG_EXIF_ID: &[u8] = b"Exif\0\0";
+
+/// Largest EXIF payload that fits a single JPEG APP1 segment.
+const JPEG_SEGMENT_MAX: usize = u16::MAX as usize - 2 - JPEG_EXIF_ID.len();
+
+/// Image containers that support EXIF write-back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Container {
+    Jpeg,
+    Png,
+}
+
+impl Container {
+    fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(&[0xFF, 0xD8]) {
+            Some(Self::Jpeg)
+        } else if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+            Some(Self::Png)
+        } else {
+            None
+        }
+    }
+}
+
+/// Edit the EXIF fields of a JPEG or PNG file and write it back in place.
+///
+/// `edit` receives all fields of the primary image (IFD0, EXIF and GPS
+/// sub-IFDs) and may add, change or remove entries. The thumbnail IFD and
+/// the pixel data are preserved as-is.
+pub fn update_exif_fields<F>(path: &Path, edit: F) -> DocResult<()>
+where
+    F: FnOnce(&mut Vec<Field>),
+{
+    let bytes = std::fs::read(path)?;
+    let container = Container::detect(&bytes).ok_or_else(|| {
+        anyhow::anyhow!("Writing metadata is only supported for JPEG and PNG files")
+    })?;
+
+    let existing = match Reader::new().read_from_container(&mut Cursor::new(&bytes)) {
+        Ok(exif) => Some(exif),
+        Err(exif::Error::NotFound(_)) => None,
+        Err(e) => return Err(anyhow::anyhow!("Failed to read EXIF data: {e}")),
+    };
+
+    let mut fields: Vec<Field> = existing
+        .iter()
+        .flat_map(|exif| exif.fields())
+        .filter(|f| f.ifd_num == In::PRIMARY)
+        .cloned()
+        .collect();
+    edit(&mut fields);
+    // Values of unknown type (e.g. vendor tags) cannot be encoded; they are
+    // dropped rather than failing the whole write
+    fields.retain(is_writable);
+
+    let tiff = if fields.is_empty() {
+        None
+    } else {
+        let little_endian = existing.as_ref().is_some_and(exif::Exif::little_endian);
+        let thumbnail: Vec<&Field> = existing
+            .iter()
+            .flat_map(|exif| exif.fields())
+            .filter(|f| f.ifd_num == In::THUMBNAIL && is_writable(f))
+            .collect();
+        let thumbnail_jpeg = existing.as_ref().and_then(thumbnail_data);
+
+        let mut writer = Writer::new();
+        for field in fields.iter().chain(thumbnail.iter().copied()) {
+            writer.push_field(field);
+        }
+        if let Some(jpeg) = thumbnail_jpeg {
+            writer.set_jpeg(jpeg, In::THUMBNAIL);
+        }
+
+        let mut out = Cursor::new(Vec::new());
+        writer
+            .write(&mut out, little_endian)
+            .map_err(|e| anyhow::anyhow!("Failed to encode EXIF data: {e}"))?;
+        Some(out.into_inner())
+    };
+
+    let updated = match container {
+        Container::Jpeg => replace_jpeg_exif(&bytes, tiff.as_deref())?,
+        Container::Png => replace_png_exif(&bytes, tiff.as_deref())?,
+    };
+
+    temp_file::write_bytes(path, updated)
+}
+
+/// Check if the EXIF writer can encode the value of `field`.
+fn is_writable(field: &Field) -> bool {
+    !matches!(field.value, exif::Value::Unknown(..))
+}
+
+/// EXIF fields of an original, kept to be written into an edited copy.
+#[derive(Debug, Clone, Default)]
+pub struct ExifCopy {
+    fields: Vec<Field>,
+}
+
+impl ExifCopy {
+    /// Fields of `source` to carry over; none if it has no EXIF data.
+    ///
+    /// The orientation and pixel dimensions are left out, since the copy is
+    /// already turned upright and may be cropped, and so is the thumbnail.
+    pub fn read(source: &Path) -> DocResult<Self> {
+        let mut reader = BufReader::new(File::open(source)?);
+        let exif = match Reader::new().read_from_container(&mut reader) {
+            Ok(exif) => exif,
+            // No EXIF data, or a format that cannot carry any
+            Err(exif::Error::NotFound(_) | exif::Error::InvalidFormat(_)) => {
+                return Ok(Self::default());
+            }
+            Err(e) => return Err(anyhow::anyhow!("Failed to read EXIF data: {e}")),
+        };
+
+        let fields = exif
+            .fields()
+            .filter(|f| f.ifd_num == In::PRIMARY)
+            .filter(|f| {
+                !matches!(
+                    f.tag,
+                    Tag::Orientation | Tag::PixelXDimension | Tag::PixelYDimension
+                )
+            })
+            .cloned()
+            .collect();
+        Ok(Self { fields })
+    }
+
+    /// Whether there is nothing to copy.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Add the fields to the EXIF data of the JPEG or PNG file `target`,
+    /// replacing fields with the same tags.
+    pub fn write_to(self, target: &Path) -> DocResult<()> {
+        if self.is_empty() {
+            return Ok(());
+        }
+        update_exif_fields(target, |fields| {
+            fields.retain(|f| !self.fields.iter().any(|c| c.tag == f.tag));
+            fields.extend(self.fields);
+        })
+    }
+}
+
+/// Replace (or remove, if `tiff` is None) the EXIF APP1 segment of a JPEG.
+///
+/// A new segment is placed after SOI and a leading JFIF APP0 segment.
+fn replace_jpeg_exif(jpeg: &[u8], tiff: Option<&[u8]>) -> DocResult<Vec<u8>> {
+    if let Some(tiff) = tiff
+        && tiff.len() > JPEG_SEGMENT_MAX
+    {
+        return Err(anyhow::anyhow!("EXIF data too large for JPEG"));
+    }
+
+    let mut out = Vec::with_capacity(jpeg.len() + tiff.map_or(0, <[u8]>::len) + 10);
+    out.extend_from_slice(&jpeg[..2]);
+
+    let mut pos = 2;
+    let mut inserted = false;
+    loop {
+        if pos + 4 > jpeg.len() || jpeg[pos] != 0xFF {
+            return Err(anyhow::anyhow!("Invalid JPEG data"));
+        }
+        let marker = jpeg[pos + 1];
+        // Start of scan: the rest is entropy-coded data.
+        if marker == 0xDA {
+            break;
+        }
+        let len = usize::from(u16::from_be_bytes([jpeg[pos + 2], jpeg[pos + 3]]));
+        let end = pos + 2 + len;
+        if len < 2 || end > jpeg.len() {
+            return Err(anyhow::anyhow!("Invalid JPEG segment"));
+        }
+
+        let segment = &jpeg[pos..end];
+        let is_exif = marker == 0xE1 && segment[4..].starts_with(JPEG_EXIF_ID);
+        if !inserted && marker != 0xE0 {
+            push_jpeg_exif(&mut out, tiff);
+            inserted = true;
+        }
+        if !is_exif {
+            out.extend_from_slice(segment);
+        }
+        pos = end;
+    }
+
+    if !inserted {
+        push_jpeg_exif(&mut out, tiff);
+    }
+    out.extend_from_slice(&jpeg[pos..]);
+    Ok(out)
+}
+
+fn push_jpeg_exif(out: &mut Vec<u8>, tiff: Option<&[u8]>) {
+    let Some(tiff) = tiff else {
+        return;
+    };
+    let len = (2 + JPEG_EXIF_ID.len() + tiff.len()) as u16;
+    out.extend_from_slice(&[0xFF, 0xE1]);
+    out.extend_from_slice(&len.to_be_bytes());
+    out.extend_from_slice(JPEG_EXIF_ID);
+    out.extend_from_slice(tiff);
+}
+
+/// Replace (or remove, if `tiff` is None) the `eXIf` chunk of a PNG.
+///
+/// A new chunk is placed right before the first `IDAT` chunk.
+fn replace_png_exif(png: &[u8], tiff: Option<&[u8]>) -> DocResult<Vec<u8>> {
+    const SIGNATURE_LEN: usize = 8;
+
+    let mut out = Vec::with_capacity(png.len() + tiff.map_or(0, <[u8]>::len) + 12);
+    out.extend_from_slice(&png[..SIGNATURE_LEN]);
+
+    let mut pos = SIGNATURE_LEN;
+    let mut inserted = false;
+    while pos + 8 <= png.len() {
+        let len = u32::from_be_bytes([png[pos], png[pos + 1], png[pos + 2], png[pos + 3]]) as usize;
+        let end = pos + 12 + len;
+        if end > png.len() {
+            return Err(anyhow::anyhow!("Invalid PNG chunk"));
+        }
+        let chunk_type = &png[pos + 4..pos + 8];
+
+        if chunk_type == b"IDAT" && !inserted {
+            if let Some(tiff) = tiff {
+                let mut chunk = Vec::with_capacity(4 + tiff.len());
+                chunk.extend_from_slice(b"eXIf");
+                chunk.extend_from_slice(tiff);
+                out.extend_from_slice(&(tiff.len() as u32).to_be_bytes());
+                out.extend_from_slice(&chunk);
+                out.extend_from_slice(&crc32(&chunk).to_be_bytes());
+            }
+            inserted = true;
+        }
+        if chunk_type != b"eXIf" {
+            out.extend_from_slice(&png[pos..end]);
+        }
+        pos = end;
+    }
+
+    if !inserted {
+        return Err(anyhow::anyhow!("Invalid PNG data"));
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Minimal TIFF header with an empty IFD (content is irrelevant for splicing).
+    const TIFF: &[u8] = b"II*\0\x08\0\0\0\0\0\0\0\0\0";
+
+    #[test]
+    fn test_jpeg_exif_replace_and_remove() {
+        // SOI, APP0 (JFIF), APP1 (old EXIF), SOS + data, EOI
+        let mut jpeg = vec![0xFF, 0xD8];
+        jpeg.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x07, b'J', b'F', b'I', b'F', 0]);
+        jpeg.extend_from_slice(&[0xFF, 0xE1, 0x00, 0x0A]);
+        jpeg.extend_from_slice(b"Exif\0\0ab");
+        jpeg.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]);
+
+        let replaced = replace_jpeg_exif(&jpeg, Some(TIFF)).unwrap();
+        // APP1 follows APP0 and contains the new data
+        assert_eq!(&replaced[11..13], &[0xFF, 0xE1]);
+        assert_eq!(&replaced[15..21], JPEG_EXIF_ID);
+        assert_eq!(&replaced[21..21 + TIFF.len()], TIFF);
+        assert!(replaced.ends_with(&[0xFF, 0xDA, 0x00, 0x02, 0x12, 0x34, 0xFF, 0xD9]));
+
+        let removed = replace_jpeg_exif(&jpeg, None).unwrap();
+        assert_eq!(removed.len(), jpeg.len() - 12);
+        assert!(!removed.windows(4).any(|w| w == b"Exif"));
+    }
+
+    #[test]
+    fn test_png_exif_insert() {
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png.extend_from_slice(&[0, 0, 0, 0]);
+        png.extend_from_slice(b"IHDR");
+        png.extend_from_slice(&crc32(b"IHDR").to_be_bytes());
+        png.extend_from_slice(&[0, 0, 0, 0]);
+        png.extend_from_slice(b"IDAT");
+        png.extend_from_slice(&crc32(b"IDAT").to_be_bytes());
+
+        let out = replace_png_exif(&png, Some(TIFF)).unwrap();
+        assert_eq!(&out[20..24], &(TIFF.len() as u32).to_be_bytes());
+        assert_eq!(&out[24..28], b"eXIf");
+        assert_eq!(&out[28..28 + TIFF.len()], TIFF);
+
+        // Writing again replaces the chunk instead of adding a second one
+        let again = replace_png_exif(&out, Some(TIFF)).unwrap();
+        assert_eq!(again, out);
+    }
+
+    #[test]
+    fn test_unknown_field_types_are_skipped() {
+        let dir = std::env::temp_dir().join("noctua-exif-unknown-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("vendor.jpg");
+        image::RgbImage::new(4, 2).save(&path).unwrap();
+
+        update_exif_fields(&path, |fields| {
+            fields.extend([
+                Field {
+                    tag: Tag::MakerNote,
+                    ifd_num: In::PRIMARY,
+                    value: exif::Value::Unknown(13, 1, 0),
+                },
+                Field {
+                    tag: Tag::Artist,
+                    ifd_num: In::PRIMARY,
+                    value: exif::Value::Ascii(vec![b"Ann".to_vec()]),
+                },
+            ]);
+        })
+        .unwrap();
+
+        let exif = Reader::new()
+            .read_from_container(&mut BufReader::new(File::open(&path).unwrap()))
+            .unwrap();
+        assert!(exif.get_field(Tag::Artist, In::PRIMARY).is_some());
+        assert!(exif.get_field(Tag::MakerNote, In::PRIMARY).is_none());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_copy_fields_to_edited_copy() {
+        let dir = std::env::temp_dir().join("noctua-exif-copy-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let (source, target) = (dir.join("source.jpg"), dir.join("target.png"));
+        let img = image::RgbImage::new(4, 2);
+        img.save(&source).unwrap();
+        img.save(&target).unwrap();
+
+        let ascii = |text: &str| exif::Value::Ascii(vec![text.as_bytes().to_vec()]);
+        update_exif_fields(&source, |fields| {
+            fields.extend([
+                Field {
+                    tag: Tag::Artist,
+                    ifd_num: In::PRIMARY,
+                    value: ascii("Ann"),
+                },
+                Field {
+                    tag: Tag::Orientation,
+                    ifd_num: In::PRIMARY,
+                    value: exif::Value::Short(vec![6]),
+                },
+            ]);
+        })
+        .unwrap();
+        ExifCopy::read(&source).unwrap().write_to(&target).unwrap();
+        // Formats without EXIF have nothing to copy
+        let bmp = dir.join("plain.bmp");
+        img.save(&bmp).unwrap();
+        assert!(ExifCopy::read(&bmp).unwrap().is_empty());
+
+        let file = File::open(&target).unwrap();
+        let exif = Reader::new()
+            .read_from_container(&mut BufReader::new(file))
+            .unwrap();
+        let artist = exif.get_field(Tag::Artist, In::PRIMARY).unwrap();
+        assert_eq!(artist.display_value().to_string(), "\"Ann\"");
+        // The saved pixels are already upright
+        assert!(exif.get_field(Tag::Orientation, In::PRIMARY).is_none());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
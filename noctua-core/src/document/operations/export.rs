@@ -0,0 +1,324 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/export.rs
+//
+// Document export operations to various formats.
+
+use std::io::Cursor;
+use std::path::Path;
+
+use image::{DynamicImage, GrayImage};
+
+use super::alpha::{self, AlphaMode};
+use super::threshold;
+use crate::document::core::document::DocResult;
+use crate::document::core::metadata::Resolution;
+use crate::document::core::temp_file;
+
+/// Supported export formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// PNG format (lossless).
+    Png,
+    /// JPEG format (lossy).
+    Jpeg,
+    /// WebP format.
+    WebP,
+    /// TIFF format (lossless, common for print).
+    Tiff,
+    /// BMP format (uncompressed).
+    Bmp,
+    /// PDF format.
+    Pdf,
+    /// SVG format (for vector documents).
+    Svg,
+}
+
+impl ExportFormat {
+    /// Formats offered when saving a raster image.
+    pub const SAVE_FORMATS: [Self; 5] = [Self::Png, Self::Jpeg, Self::WebP, Self::Tiff, Self::Bmp];
+
+    /// Get file extension for this format.
+    #[must_use]
+    pub fn extension(&self) -> &str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::WebP => "webp",
+            Self::Tiff => "tiff",
+            Self::Bmp => "bmp",
+            Self::Pdf => "pdf",
+            Self::Svg => "svg",
+        }
+    }
+
+    /// Get MIME type for this format.
+    #[must_use]
+    pub fn mime_type(&self) -> &str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::WebP => "image/webp",
+            Self::Tiff => "image/tiff",
+            Self::Bmp => "image/bmp",
+            Self::Pdf => "application/pdf",
+            Self::Svg => "image/svg+xml",
+        }
+    }
+
+    /// Detect format from file extension.
+    #[must_use]
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "png" => Some(Self::Png),
+            "jpg" | "jpeg" => Some(Self::Jpeg),
+            "webp" => Some(Self::WebP),
+            "tif" | "tiff" => Some(Self::Tiff),
+            "bmp" => Some(Self::Bmp),
+            "pdf" => Some(Self::Pdf),
+            "svg" => Some(Self::Svg),
+            _ => None,
+        }
+    }
+}
+
+/// Export options for image formats.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct ImageExportOptions {
+    /// Quality setting (0-100) for lossy formats.
+    pub quality: u8,
+    /// Whether to preserve metadata (EXIF, etc.).
+    pub preserve_metadata: bool,
+    /// Print resolution to declare in the file (pixels are not resampled).
+    pub resolution: Option<Resolution>,
+    /// Straight or premultiplied color for formats with an alpha channel.
+    pub alpha: AlphaMode,
+}
+
+impl Default for ImageExportOptions {
+    fn default() -> Self {
+        Self {
+            quality: 90,
+            preserve_metadata: true,
+            resolution: None,
+            alpha: AlphaMode::Straight,
+        }
+    }
+}
+
+/// Export a raster image to a file.
+///
+/// This function handles format-specific encoding and options.
+/// Premultiplied alpha is written with 8 bits per channel.
+pub fn export_image(
+    img: &DynamicImage,
+    path: &Path,
+    format: ExportFormat,
+    options: &ImageExportOptions,
+) -> DocResult<()> {
+    let premultiplied;
+    let img = if options.alpha == AlphaMode::Premultiplied
+        && format != ExportFormat::Jpeg
+        && alpha::has_alpha(img)
+    {
+        premultiplied = DynamicImage::ImageRgba8(alpha::premultiply(img));
+        &premultiplied
+    } else {
+        img
+    };
+
+    // Written under a temporary name, so a failed export keeps the old file
+    temp_file::write_atomically(path, |temp| encode(img, temp, format, options))
+}
+
+/// Encode `img` to a new file at `path`.
+fn encode(
+    img: &DynamicImage,
+    path: &Path,
+    format: ExportFormat,
+    options: &ImageExportOptions,
+) -> DocResult<()> {
+    match format {
+        ExportFormat::Png => {
+            let mut png = Vec::new();
+            match img {
+                // Black and white scans take one bit per pixel
+                DynamicImage::ImageLuma8(gray) if threshold::is_bilevel(img) => {
+                    write_bilevel_png(gray, &mut png)?;
+                }
+                _ => img.write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)?,
+            }
+            if let Some(resolution) = options.resolution {
+                png = insert_png_phys(&png, resolution)?;
+            }
+            std::fs::write(path, png)?;
+        }
+        ExportFormat::Jpeg => {
+            use image::codecs::jpeg::{JpegEncoder, PixelDensity, PixelDensityUnit};
+
+            let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+            let mut encoder = JpegEncoder::new_with_quality(file, options.quality);
+            if let Some(resolution) = options.resolution {
+                encoder.set_pixel_density(PixelDensity {
+                    density: (
+                        resolution.x_dpi.round() as u16,
+                        resolution.y_dpi.round() as u16,
+                    ),
+                    unit: PixelDensityUnit::Inches,
+                });
+            }
+            encoder.encode_image(&img.to_rgb8())?;
+        }
+        ExportFormat::WebP => {
+            img.save_with_format(path, image::ImageFormat::WebP)?;
+        }
+        ExportFormat::Tiff => {
+            img.save_with_format(path, image::ImageFormat::Tiff)?;
+        }
+        ExportFormat::Bmp => {
+            img.save_with_format(path, image::ImageFormat::Bmp)?;
+        }
+        ExportFormat::Pdf | ExportFormat::Svg => {
+            return Err(anyhow::anyhow!(
+                "Export to {} not yet implemented",
+                format.extension()
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Encode a black and white image as 1-bit grayscale PNG.
+fn write_bilevel_png(gray: &GrayImage, out: &mut Vec<u8>) -> DocResult<()> {
+    let mut encoder = png::Encoder::new(out, gray.width(), gray.height());
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::One);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&threshold::pack_bits(gray))?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Insert a `pHYs` chunk (pixels per metre) right after the PNG `IHDR` chunk.
+fn insert_png_phys(png: &[u8], resolution: Resolution) -> DocResult<Vec<u8>> {
+    // Signature (8 bytes) + IHDR chunk (4 length + 4 type + 13 data + 4 CRC)
+    const IHDR_END: usize = 33;
+    if png.len() < IHDR_END || &png[12..16] != b"IHDR" {
+        return Err(anyhow::anyhow!("Invalid PNG data"));
+    }
+
+    let per_metre = |dpi: f64| (dpi * 100.0 / 2.54).round() as u32;
+    let mut chunk = Vec::with_capacity(21);
+    chunk.extend_from_slice(b"pHYs");
+    chunk.extend_from_slice(&per_metre(resolution.x_dpi).to_be_bytes());
+    chunk.extend_from_slice(&per_metre(resolution.y_dpi).to_be_bytes());
+    chunk.push(1); // unit: metre
+
+    let mut out = Vec::with_capacity(png.len() + 21);
+    out.extend_from_slice(&png[..IHDR_END]);
+    out.extend_from_slice(&9u32.to_be_bytes());
+    out.extend_from_slice(&chunk);
+    out.extend_from_slice(&crc32(&chunk).to_be_bytes());
+    out.extend_from_slice(&png[IHDR_END..]);
+    Ok(out)
+}
+
+/// CRC-32 (ISO 3309) as used by PNG chunks.
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Export a document to a standard paper format (A4, Letter, etc.).
+///
+/// This function resizes the document to fit the target format while maintaining
+/// aspect ratio, then exports it.
+pub fn export_to_paper_format(
+    img: &DynamicImage,
+    path: &Path,
+    target_width: u32,
+    target_height: u32,
+    format: ExportFormat,
+) -> DocResult<()> {
+    use image::imageops::FilterType;
+
+    // Resize to fit target dimensions
+    let resized = img.resize(target_width, target_height, FilterType::Lanczos3);
+
+    // Export with default options
+    let options = ImageExportOptions::default();
+    export_image(&resized, path, format, &options)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_extension() {
+        assert_eq!(ExportFormat::Png.extension(), "png");
+        assert_eq!(ExportFormat::Jpeg.extension(), "jpg");
+        assert_eq!(ExportFormat::Pdf.extension(), "pdf");
+    }
+
+    #[test]
+    fn test_format_from_path() {
+        assert_eq!(
+            ExportFormat::from_path(Path::new("test.png")),
+            Some(ExportFormat::Png)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("test.JPG")),
+            Some(ExportFormat::Jpeg)
+        );
+        assert_eq!(
+            ExportFormat::from_path(Path::new("scan.BMP")),
+            Some(ExportFormat::Bmp)
+        );
+        assert_eq!(ExportFormat::from_path(Path::new("test.txt")), None);
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn test_png_phys_roundtrip() {
+        let img = DynamicImage::new_rgb8(4, 4);
+        let mut png = Vec::new();
+        img.write_to(&mut Cursor::new(&mut png), image::ImageFormat::Png)
+            .unwrap();
+
+        let with_phys = insert_png_phys(&png, Resolution::uniform(300.0)).unwrap();
+        let res = Resolution::from_bytes(&with_phys).unwrap();
+        assert!((res.x_dpi - 300.0).abs() < 0.1);
+
+        // Still decodes as a valid PNG
+        assert!(image::load_from_memory(&with_phys).is_ok());
+    }
+
+    #[test]
+    fn test_bilevel_png() {
+        let gray = GrayImage::from_fn(9, 3, |x, y| image::Luma([if x == y { 0 } else { 255 }]));
+        let mut png = Vec::new();
+        write_bilevel_png(&gray, &mut png).unwrap();
+        // Bit depth in IHDR
+        assert_eq!(png[24], 1);
+
+        let decoded = image::load_from_memory(&png).unwrap();
+        assert_eq!(decoded.to_luma8(), gray);
+    }
+}
@@ -0,0 +1,283 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/export_profile.rs
+//
+// Export profiles: named bundles of format, size, quality and naming settings.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use image::DynamicImage;
+use image::imageops::FilterType;
+
+use super::alpha::{self, AlphaMode};
+use super::export::{self, ExportFormat, ImageExportOptions};
+use crate::document::core::document::DocResult;
+use crate::document::core::metadata::Resolution;
+
+/// Settings applied when an image is written with a profile.
+///
+/// Stored as text in the configuration (see `Display` and `parse`), e.g.
+/// `format webp; fit 1920x1080; quality 85; metadata strip; name {name}-web`.
+/// `alpha premultiplied` writes premultiplied color (straight is the default).
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportProfile {
+    pub format: ExportFormat,
+    /// Bounding box the image is scaled down to (never enlarged).
+    pub fit: Option<(u32, u32)>,
+    /// Quality (0-100) for lossy formats.
+    pub quality: u8,
+    /// Print resolution to declare, in DPI.
+    pub dpi: Option<f64>,
+    /// Keep metadata (EXIF, etc.) where the format allows it.
+    pub keep_metadata: bool,
+    /// Straight or premultiplied color in the alpha channel.
+    pub alpha: AlphaMode,
+    /// File name without extension: `{name}` (original name), `{index}`,
+    /// `{width}` and `{height}` are replaced.
+    pub name_template: String,
+}
+
+impl Default for ExportProfile {
+    fn default() -> Self {
+        let options = ImageExportOptions::default();
+        Self {
+            format: ExportFormat::Png,
+            fit: None,
+            quality: options.quality,
+            dpi: None,
+            keep_metadata: options.preserve_metadata,
+            alpha: options.alpha,
+            name_template: "{name}".to_string(),
+        }
+    }
+}
+
+impl ExportProfile {
+    /// Parse the text form; settings that are not given keep their defaults.
+    ///
+    /// Returns the first invalid setting on error.
+    pub fn parse(input: &str) -> Result<Self, String> {
+        let mut profile = Self::default();
+
+        for setting in input.split(';').map(str::trim).filter(|s| !s.is_empty()) {
+            let (key, value) = setting
+                .split_once(char::is_whitespace)
+                .unwrap_or((setting, ""));
+            let value = value.trim();
+            let valid = match key.to_lowercase().as_str() {
+                "format" => ExportFormat::from_path(Path::new(&format!("x.{value}")))
+                    .filter(|f| !matches!(f, ExportFormat::Pdf | ExportFormat::Svg))
+                    .map(|format| profile.format = format)
+                    .is_some(),
+                "fit" => parse_size(value)
+                    .map(|size| profile.fit = Some(size))
+                    .is_some(),
+                "quality" => value
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|q| (1..=100).contains(q))
+                    .map(|quality| profile.quality = quality)
+                    .is_some(),
+                "dpi" => value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|dpi| *dpi > 0.0)
+                    .map(|dpi| profile.dpi = Some(dpi))
+                    .is_some(),
+                "metadata" => match value {
+                    "keep" => {
+                        profile.keep_metadata = true;
+                        true
+                    }
+                    "strip" => {
+                        profile.keep_metadata = false;
+                        true
+                    }
+                    _ => false,
+                },
+                "alpha" => AlphaMode::parse(value)
+                    .map(|mode| profile.alpha = mode)
+                    .is_some(),
+                "name" if !value.is_empty() => {
+                    profile.name_template = value.to_string();
+                    true
+                }
+                _ => false,
+            };
+            if !valid {
+                return Err(setting.to_string());
+            }
+        }
+
+        Ok(profile)
+    }
+
+    /// Size of an image of `size` after scaling down to the bounding box.
+    #[must_use]
+    pub fn output_size(&self, (width, height): (u32, u32)) -> (u32, u32) {
+        match self.fit {
+            Some((max_width, max_height)) if width > max_width || height > max_height => {
+                let ratio = (f64::from(max_width) / f64::from(width))
+                    .min(f64::from(max_height) / f64::from(height));
+                let scale = |v: u32| ((f64::from(v) * ratio).round() as u32).max(1);
+                (scale(width), scale(height))
+            }
+            _ => (width, height),
+        }
+    }
+
+    /// Scale the image down to the profile's bounding box.
+    #[must_use]
+    pub fn resize(&self, img: DynamicImage) -> DynamicImage {
+        let size = (img.width(), img.height());
+        let (width, height) = self.output_size(size);
+        if (width, height) == size {
+            img
+        } else {
+            alpha::resize_exact(&img, width, height, FilterType::Lanczos3)
+        }
+    }
+
+    /// Encoder options for this profile.
+    #[must_use]
+    pub fn options(&self) -> ImageExportOptions {
+        ImageExportOptions {
+            quality: self.quality,
+            preserve_metadata: self.keep_metadata,
+            resolution: self.dpi.map(Resolution::uniform),
+            alpha: self.alpha,
+        }
+    }
+
+    /// File name (with extension) for an image written from `source`.
+    ///
+    /// `index` numbers the images of a batch (starting at 1), `size` is the
+    /// size of the written image.
+    #[must_use]
+    pub fn file_name(&self, source: &Path, index: usize, (width, height): (u32, u32)) -> String {
+        let stem = source
+            .file_stem()
+            .map_or_else(|| "image".into(), |s| s.to_string_lossy());
+        let name = self
+            .name_template
+            .replace("{name}", &stem)
+            .replace("{index}", &format!("{index:03}"))
+            .replace("{width}", &width.to_string())
+            .replace("{height}", &height.to_string())
+            .replace(['/', '\\'], "_");
+        format!("{name}.{}", self.format.extension())
+    }
+
+    /// Resize `img` and write it to `target` with the profile's format and options.
+    pub fn write(&self, img: DynamicImage, target: &Path) -> DocResult<()> {
+        let img = self.resize(img);
+        export::export_image(&img, target, self.format, &self.options())
+    }
+
+    /// Resize `img` and write it into `output_dir`, named by the template.
+    ///
+    /// Returns the written path. Refuses to overwrite `source`.
+    pub fn write_into(
+        &self,
+        img: DynamicImage,
+        source: &Path,
+        index: usize,
+        output_dir: &Path,
+    ) -> DocResult<PathBuf> {
+        let img = self.resize(img);
+        let target = output_dir.join(self.file_name(source, index, (img.width(), img.height())));
+        if target == source {
+            return Err(anyhow::anyhow!("Refusing to overwrite the original image"));
+        }
+        export::export_image(&img, &target, self.format, &self.options())?;
+        Ok(target)
+    }
+}
+
+impl fmt::Display for ExportProfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "format {}", self.format.extension())?;
+        if let Some((width, height)) = self.fit {
+            write!(f, "; fit {width}x{height}")?;
+        }
+        write!(f, "; quality {}", self.quality)?;
+        if let Some(dpi) = self.dpi {
+            write!(f, "; dpi {dpi}")?;
+        }
+        let metadata = if self.keep_metadata { "keep" } else { "strip" };
+        write!(f, "; metadata {metadata}")?;
+        if self.alpha != AlphaMode::Straight {
+            write!(f, "; alpha {}", self.alpha.name())?;
+        }
+        write!(f, "; name {}", self.name_template)
+    }
+}
+
+/// Profiles added to a fresh configuration: (name, settings).
+pub const DEFAULT_PROFILES: [(&str, &str); 2] = [
+    (
+        "Web 1080p WebP",
+        "format webp; fit 1920x1080; quality 85; metadata strip; name {name}-web",
+    ),
+    (
+        "Print 300 DPI TIFF",
+        "format tiff; dpi 300; metadata keep; name {name}-print",
+    ),
+];
+
+/// Parse `1920x1080` (`×` also works).
+pub fn parse_size(value: &str) -> Option<(u32, u32)> {
+    let (width, height) = value.split_once(['x', 'X', '×'])?;
+    let (width, height) = (width.trim().parse().ok()?, height.trim().parse().ok()?);
+    (width > 0 && height > 0).then_some((width, height))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format_roundtrip() {
+        let text = "format webp; fit 1920x1080; quality 85; metadata strip; name {name}-web";
+        let profile = ExportProfile::parse(text).unwrap();
+        assert_eq!(profile.format, ExportFormat::WebP);
+        assert_eq!(profile.fit, Some((1920, 1080)));
+        assert!(!profile.keep_metadata);
+        assert_eq!(profile.to_string(), text);
+
+        for (_, settings) in DEFAULT_PROFILES {
+            assert!(ExportProfile::parse(settings).is_ok(), "{settings}");
+        }
+        assert_eq!(
+            ExportProfile::parse("format pdf"),
+            Err("format pdf".to_string())
+        );
+        assert_eq!(
+            ExportProfile::parse("quality 0"),
+            Err("quality 0".to_string())
+        );
+
+        let text = "format png; quality 90; metadata keep; alpha premultiplied; name {name}";
+        let profile = ExportProfile::parse(text).unwrap();
+        assert_eq!(profile.alpha, AlphaMode::Premultiplied);
+        assert_eq!(profile.to_string(), text);
+    }
+
+    #[test]
+    fn test_file_name_template() {
+        let profile = ExportProfile::parse("format jpg; name {index}_{name}_{width}w").unwrap();
+        assert_eq!(
+            profile.file_name(Path::new("/photos/beach.png"), 7, (1920, 1280)),
+            "007_beach_1920w.jpg"
+        );
+    }
+
+    #[test]
+    fn test_resize_only_shrinks() {
+        let profile = ExportProfile::parse("fit 100x100").unwrap();
+        let out = profile.resize(DynamicImage::new_rgb8(400, 200));
+        assert_eq!((out.width(), out.height()), (100, 50));
+        let out = profile.resize(DynamicImage::new_rgb8(40, 20));
+        assert_eq!((out.width(), out.height()), (40, 20));
+    }
+}
@@ -0,0 +1,337 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/format_advice.rs
+//
+// Export format advice: what kind of picture an image is, the format and
+// quality that suit it, and the file sizes the formats would give.
+
+use std::collections::HashSet;
+use std::io::Cursor;
+
+use image::{DynamicImage, GenericImageView, ImageFormat, imageops};
+
+use super::alpha;
+use super::export::ExportFormat;
+
+/// JPEG quality suggested for photos: no visible loss, far smaller than 95+.
+pub const PHOTO_QUALITY: u8 = 85;
+
+/// Side of the square tiles a sample is made of.
+const TILE: u32 = 128;
+
+/// Tiles per row and column of a sample.
+const TILES: u32 = 4;
+
+/// Samples with at most this many colors are graphics (logos, diagrams).
+const GRAPHIC_COLORS: usize = 256;
+
+/// Share of pixels like their left neighbor above which an image is a
+/// graphic (screenshots, charts) even with anti-aliased edges.
+const FLAT_SHARE: f64 = 0.6;
+
+/// What kind of picture an image is, as far as choosing a format goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageKind {
+    /// Camera picture or painting: many colors, noise, soft gradients.
+    Photo,
+    /// Screenshot, diagram, logo: few colors, flat areas, sharp edges.
+    Graphic,
+    /// Image with see-through parts.
+    Transparent,
+    /// Image with several frames.
+    Animated,
+}
+
+/// Estimated file size of the image in a format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SizeEstimate {
+    pub format: ExportFormat,
+    /// Bytes, rounded to what the sample suggests.
+    pub bytes: u64,
+}
+
+/// Format suggested for an image, with the sizes of the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatAdvice {
+    pub kind: ImageKind,
+    /// Suggested format; None keeps the original (Save As writes a single
+    /// frame of an animation).
+    pub format: Option<ExportFormat>,
+    /// JPEG quality of the suggestion.
+    pub quality: Option<u8>,
+    /// Estimated sizes, smallest first.
+    pub estimates: Vec<SizeEstimate>,
+}
+
+/// Tiles spread over an image, put together to stand in for it when
+/// encoding: encoders see the same detail, edges and noise, without the
+/// time a full-size image takes.
+#[derive(Debug, Clone)]
+pub struct Sample {
+    image: DynamicImage,
+    /// Pixels of the image per pixel of the sample.
+    scale: f64,
+    /// Whether any pixel of the whole image is not fully opaque.
+    transparent: bool,
+}
+
+impl Sample {
+    /// Sample of `img`; small images are taken whole.
+    ///
+    /// Copies at most `TILES` × `TILES` tiles, and reads the alpha channel
+    /// of the whole image; cheap enough for the UI thread.
+    #[must_use]
+    pub fn new(img: &DynamicImage) -> Self {
+        let (width, height) = img.dimensions();
+        let transparent =
+            alpha::has_alpha(img) && img.pixels().any(|(_, _, pixel)| pixel[3] < u8::MAX);
+        if width <= TILE * TILES && height <= TILE * TILES {
+            return Self {
+                image: img.clone(),
+                scale: 1.0,
+                transparent,
+            };
+        }
+
+        let columns = TILES.min(width.div_ceil(TILE));
+        let rows = TILES.min(height.div_ceil(TILE));
+        let (tile_width, tile_height) = (TILE.min(width), TILE.min(height));
+        let mut image = DynamicImage::new(columns * tile_width, rows * tile_height, img.color());
+        // Spread evenly, the first at the left or top edge, the last at the other
+        let offset = |index: u32, count: u32, size: u32, tile: u32| {
+            if count > 1 {
+                (size - tile) * index / (count - 1)
+            } else {
+                (size - tile) / 2
+            }
+        };
+        for row in 0..rows {
+            for column in 0..columns {
+                let x = offset(column, columns, width, tile_width);
+                let y = offset(row, rows, height, tile_height);
+                let tile = img.crop_imm(x, y, tile_width, tile_height);
+                imageops::replace(
+                    &mut image,
+                    &tile,
+                    i64::from(column * tile_width),
+                    i64::from(row * tile_height),
+                );
+            }
+        }
+        let scale =
+            f64::from(width) * f64::from(height) / f64::from(image.width() * image.height());
+        Self {
+            image,
+            scale,
+            transparent,
+        }
+    }
+
+    /// Kind of the sampled image, `animated` if it has several frames.
+    #[must_use]
+    pub fn kind(&self, animated: bool) -> ImageKind {
+        if animated {
+            ImageKind::Animated
+        } else if self.transparent {
+            ImageKind::Transparent
+        } else if self.is_flat() {
+            ImageKind::Graphic
+        } else {
+            ImageKind::Photo
+        }
+    }
+
+    /// Whether the sample has few colors or mostly flat areas.
+    fn is_flat(&self) -> bool {
+        let rgba = self.image.to_rgba8();
+        let mut colors = HashSet::new();
+        let mut repeated = 0usize;
+        for row in rgba.rows() {
+            let mut previous = None;
+            for pixel in row {
+                if previous == Some(pixel) {
+                    repeated += 1;
+                }
+                previous = Some(pixel);
+                if colors.len() <= GRAPHIC_COLORS {
+                    colors.insert(pixel.0);
+                }
+            }
+        }
+        let pixels = (rgba.width() * rgba.height()).max(1);
+        colors.len() <= GRAPHIC_COLORS || repeated as f64 / f64::from(pixels) >= FLAT_SHARE
+    }
+
+    /// Estimated sizes of the image as JPEG (at `quality`), WebP and PNG,
+    /// smallest first; formats that failed to encode are left out.
+    ///
+    /// Encodes the sample three times; meant for a background thread.
+    #[must_use]
+    pub fn estimate_sizes(&self, quality: u8) -> Vec<SizeEstimate> {
+        let mut estimates: Vec<SizeEstimate> =
+            [ExportFormat::Jpeg, ExportFormat::WebP, ExportFormat::Png]
+                .into_iter()
+                .filter_map(|format| {
+                    let bytes = encoded_size(&self.image, format, quality)?;
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let bytes = (bytes as f64 * self.scale).round() as u64;
+                    Some(SizeEstimate { format, bytes })
+                })
+                .collect();
+        estimates.sort_by_key(|estimate| estimate.bytes);
+        estimates
+    }
+
+    /// Format and quality suggested for the image, with the size estimates
+    /// of the formats (JPEG at the suggested quality, or `quality` if the
+    /// suggestion is lossless).
+    ///
+    /// Photos become JPEG; graphics and transparent images whichever of
+    /// the lossless PNG and WebP is smaller; animations keep their format.
+    #[must_use]
+    pub fn advise(&self, animated: bool, quality: u8) -> FormatAdvice {
+        let kind = self.kind(animated);
+        let quality = if kind == ImageKind::Photo {
+            PHOTO_QUALITY
+        } else {
+            quality
+        };
+        let estimates = self.estimate_sizes(quality);
+        let (format, quality) = match kind {
+            ImageKind::Photo => (Some(ExportFormat::Jpeg), Some(PHOTO_QUALITY)),
+            ImageKind::Graphic | ImageKind::Transparent => (
+                estimates
+                    .iter()
+                    .map(|estimate| estimate.format)
+                    .find(|format| *format != ExportFormat::Jpeg)
+                    .or(Some(ExportFormat::Png)),
+                None,
+            ),
+            ImageKind::Animated => (None, None),
+        };
+        FormatAdvice {
+            kind,
+            format,
+            quality,
+            estimates,
+        }
+    }
+}
+
+/// Bytes `img` takes encoded in `format` (None if encoding failed).
+fn encoded_size(img: &DynamicImage, format: ExportFormat, quality: u8) -> Option<usize> {
+    let mut out = Cursor::new(Vec::new());
+    let written = match format {
+        ExportFormat::Jpeg => {
+            let encoder = image::codecs::jpeg::JpegEncoder::new_with_quality(&mut out, quality);
+            img.to_rgb8().write_with_encoder(encoder)
+        }
+        ExportFormat::WebP => img.to_rgba8().write_to(&mut out, ImageFormat::WebP),
+        _ => img.write_to(&mut out, ImageFormat::Png),
+    };
+    written
+        .inspect_err(|e| tracing::debug!("Size estimate as {} failed: {e}", format.extension()))
+        .ok()
+        .map(|()| out.into_inner().len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgb, RgbImage, Rgba, RgbaImage};
+
+    /// Smooth gradients with sensor-like noise.
+    fn photo(width: u32, height: u32) -> DynamicImage {
+        let mut seed = 0x2545_F491_u32;
+        DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |x, y| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            let noise = (seed % 24) as u8;
+            Rgb([
+                (x * 200 / width) as u8 + noise,
+                (y * 200 / height) as u8 + noise,
+                120 + noise,
+            ])
+        }))
+    }
+
+    /// Boxes in a few flat colors.
+    fn diagram(width: u32, height: u32) -> DynamicImage {
+        DynamicImage::ImageRgb8(RgbImage::from_fn(width, height, |x, y| {
+            match ((x / 40) % 3, (y / 30) % 2) {
+                (0, _) => Rgb([255, 255, 255]),
+                (1, 0) => Rgb([20, 60, 160]),
+                _ => Rgb([0, 0, 0]),
+            }
+        }))
+    }
+
+    #[test]
+    fn test_kinds_and_suggestions() {
+        let advice = Sample::new(&photo(300, 200)).advise(false, 95);
+        assert_eq!(advice.kind, ImageKind::Photo);
+        assert_eq!(advice.format, Some(ExportFormat::Jpeg));
+        assert_eq!(advice.quality, Some(PHOTO_QUALITY));
+        assert_eq!(advice.estimates[0].format, ExportFormat::Jpeg);
+
+        let advice = Sample::new(&diagram(300, 200)).advise(false, 90);
+        assert_eq!(advice.kind, ImageKind::Graphic);
+        assert!(matches!(
+            advice.format,
+            Some(ExportFormat::Png | ExportFormat::WebP)
+        ));
+        assert_eq!(advice.quality, None);
+
+        // An alpha channel alone, all opaque, is no transparency
+        let opaque = DynamicImage::ImageRgba8(diagram(64, 64).to_rgba8());
+        assert_eq!(Sample::new(&opaque).kind(false), ImageKind::Graphic);
+        let mut logo = RgbaImage::from_pixel(64, 64, Rgba([200, 30, 30, 255]));
+        logo.put_pixel(63, 63, Rgba([0, 0, 0, 0]));
+        let advice = Sample::new(&DynamicImage::ImageRgba8(logo)).advise(false, 90);
+        assert_eq!(advice.kind, ImageKind::Transparent);
+        assert_ne!(advice.format, Some(ExportFormat::Jpeg));
+
+        let advice = Sample::new(&diagram(64, 64)).advise(true, 90);
+        assert_eq!((advice.kind, advice.format), (ImageKind::Animated, None));
+        assert_eq!(advice.estimates.len(), 3);
+    }
+
+    #[test]
+    fn test_sample_of_large_image() {
+        let sample = Sample::new(&photo(2000, 900));
+        assert_eq!(sample.image.dimensions(), (TILE * TILES, TILE * TILES));
+        assert!((sample.scale - 2000.0 * 900.0 / 262_144.0).abs() < 1e-9);
+
+        // A strip narrower than a row of tiles
+        let sample = Sample::new(&diagram(3000, 100));
+        assert_eq!(sample.image.dimensions(), (TILE * TILES, 100));
+        assert_eq!(sample.kind(false), ImageKind::Graphic);
+    }
+
+    #[test]
+    fn test_estimates_follow_real_sizes() {
+        // Small images are encoded whole: the estimate is the size
+        let small = photo(200, 150);
+        let estimates = Sample::new(&small).estimate_sizes(80);
+        let jpeg = estimates
+            .iter()
+            .find(|estimate| estimate.format == ExportFormat::Jpeg)
+            .unwrap();
+        assert_eq!(
+            jpeg.bytes,
+            encoded_size(&small, ExportFormat::Jpeg, 80).unwrap() as u64
+        );
+        assert!(
+            estimates
+                .windows(2)
+                .all(|pair| pair[0].bytes <= pair[1].bytes)
+        );
+
+        // Tiles of a uniform large image tell its size within reason
+        let large = photo(1600, 1200);
+        let estimated = Sample::new(&large).estimate_sizes(80)[0];
+        let real = encoded_size(&large, estimated.format, 80).unwrap() as f64;
+        let ratio = estimated.bytes as f64 / real;
+        assert!((0.7..1.3).contains(&ratio), "estimate off by {ratio}");
+    }
+}
@@ -0,0 +1,315 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/geotag.rs
+//
+// GPS location editing: parse coordinates and write EXIF GPS tags.
+
+use std::path::Path;
+
+use exif::{Context, Field, In, Rational, Tag, Value};
+
+use super::exif_write;
+use crate::document::core::document::DocResult;
+
+/// Denominator for the seconds part of GPS rationals (1/10000 arc second).
+const SECONDS_PRECISION: u32 = 10_000;
+
+/// A GPS location in decimal degrees (WGS 84).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GpsCoordinate {
+    /// Latitude, positive north.
+    pub latitude: f64,
+    /// Longitude, positive east.
+    pub longitude: f64,
+}
+
+impl GpsCoordinate {
+    /// Create a coordinate, validating the value ranges.
+    #[must_use]
+    pub fn new(latitude: f64, longitude: f64) -> Option<Self> {
+        ((-90.0..=90.0).contains(&latitude) && (-180.0..=180.0).contains(&longitude)).then_some(
+            Self {
+                latitude,
+                longitude,
+            },
+        )
+    }
+
+    /// Parse a pasted location.
+    ///
+    /// Accepts decimal pairs (`48.8584, 2.2945`), hemisphere letters
+    /// (`48.8584 N 2.2945 E`), degrees/minutes/seconds
+    /// (`48°51'30.2"N 2°17'40.2"E`), `geo:` URIs and map links from
+    /// OpenStreetMap or Google Maps.
+    #[must_use]
+    pub fn parse(input: &str) -> Option<Self> {
+        let input = input.trim();
+        if let Some(coord) = Self::parse_url(input) {
+            return Some(coord);
+        }
+
+        let parts: Vec<String> = if input.contains(',') {
+            input.split(',').map(|p| p.trim().to_string()).collect()
+        } else {
+            split_hemisphere_tokens(input)
+        };
+        let [lat, lon] = parts.as_slice() else {
+            return None;
+        };
+
+        Self::new(
+            parse_component(lat, 'N', 'S')?,
+            parse_component(lon, 'E', 'W')?,
+        )
+    }
+
+    /// Extract coordinates from a `geo:` URI or a map website link.
+    fn parse_url(input: &str) -> Option<Self> {
+        let pair = |value: &str, sep: char| -> Option<Self> {
+            let mut it = value.split(sep);
+            let lat = it.next()?.trim().parse().ok()?;
+            let lon = it.next()?.trim().parse().ok()?;
+            Self::new(lat, lon)
+        };
+
+        if let Some(rest) = input.strip_prefix("geo:") {
+            return pair(rest.split([';', '?']).next()?, ',');
+        }
+        if !input.starts_with("http") {
+            return None;
+        }
+
+        // OpenStreetMap marker: ?mlat=..&mlon=..
+        let query_value = |key: &str| -> Option<f64> {
+            input
+                .split(['?', '&', '#'])
+                .find_map(|kv| kv.strip_prefix(key)?.strip_prefix('='))?
+                .parse()
+                .ok()
+        };
+        if let (Some(lat), Some(lon)) = (query_value("mlat"), query_value("mlon")) {
+            return Self::new(lat, lon);
+        }
+        // OpenStreetMap view: #map=zoom/lat/lon
+        if let Some((_, view)) = input.split_once("#map=") {
+            let mut it = view.splitn(2, '/');
+            it.next()?;
+            return pair(it.next()?, '/');
+        }
+        // Google Maps: /@lat,lon,zoom or ?q=lat,lon
+        if let Some((_, at)) = input.split_once("/@") {
+            return pair(at, ',');
+        }
+        input
+            .split(['?', '&'])
+            .find_map(|kv| kv.strip_prefix("q=").or_else(|| kv.strip_prefix("query=")))
+            .and_then(|value| pair(&value.replace("%2C", ","), ','))
+    }
+
+    /// Format for display (matches the properties panel).
+    #[must_use]
+    pub fn display(&self) -> String {
+        format!("{:.5}, {:.5}", self.latitude, self.longitude)
+    }
+
+    /// OpenStreetMap link showing this location.
+    #[must_use]
+    pub fn map_url(&self) -> String {
+        format!(
+            "https://www.openstreetmap.org/?mlat={lat:.6}&mlon={lon:.6}#map=16/{lat:.6}/{lon:.6}",
+            lat = self.latitude,
+            lon = self.longitude
+        )
+    }
+
+    /// EXIF GPS fields for this location.
+    #[must_use]
+    pub fn to_exif_fields(&self) -> Vec<Field> {
+        let field = |tag, value| Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value,
+        };
+        let reference = |positive: bool, pos: &str, neg: &str| {
+            Value::Ascii(vec![if positive { pos } else { neg }.as_bytes().to_vec()])
+        };
+
+        vec![
+            field(Tag::GPSVersionID, Value::Byte(vec![2, 3, 0, 0])),
+            field(
+                Tag::GPSLatitudeRef,
+                reference(self.latitude >= 0.0, "N", "S"),
+            ),
+            field(Tag::GPSLatitude, Value::Rational(to_dms(self.latitude))),
+            field(
+                Tag::GPSLongitudeRef,
+                reference(self.longitude >= 0.0, "E", "W"),
+            ),
+            field(Tag::GPSLongitude, Value::Rational(to_dms(self.longitude))),
+        ]
+    }
+}
+
+/// Set or clear (`None`) the GPS location stored in a JPEG or PNG file.
+pub fn write_location(path: &Path, location: Option<GpsCoordinate>) -> DocResult<()> {
+    exif_write::update_exif_fields(path, |fields| {
+        fields.retain(|f| f.tag.context() != Context::Gps);
+        if let Some(location) = location {
+            fields.extend(location.to_exif_fields());
+        }
+    })
+}
+
+/// Split `48.8 N 2.3 E` style input into two coordinate parts.
+fn split_hemisphere_tokens(input: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    for token in input.split_whitespace() {
+        let is_hemisphere = token.len() == 1 && "NSEWnsew".contains(token);
+        match parts.last_mut() {
+            Some(last) if is_hemisphere => {
+                last.push(' ');
+                last.push_str(token);
+            }
+            _ => parts.push(token.to_string()),
+        }
+    }
+    parts
+}
+
+/// Parse one coordinate: decimal or degrees/minutes/seconds with optional hemisphere.
+fn parse_component(value: &str, positive: char, negative: char) -> Option<f64> {
+    let value = value.trim();
+    let upper = value.to_ascii_uppercase();
+    let (sign, body) = if let Some(body) = upper
+        .strip_suffix(negative)
+        .or_else(|| upper.strip_prefix(negative))
+    {
+        (-1.0, body.trim().to_string())
+    } else if let Some(body) = upper
+        .strip_suffix(positive)
+        .or_else(|| upper.strip_prefix(positive))
+    {
+        (1.0, body.trim().to_string())
+    } else {
+        (1.0, upper)
+    };
+
+    let numbers: Vec<f64> = body
+        .split(|c: char| !(c.is_ascii_digit() || c == '.' || c == '-'))
+        .filter(|s| !s.is_empty())
+        .map(str::parse)
+        .collect::<Result<_, _>>()
+        .ok()?;
+
+    let degrees = match numbers.as_slice() {
+        [d] => *d,
+        [d, m] => d.abs() + m / 60.0,
+        [d, m, s] => d.abs() + m / 60.0 + s / 3600.0,
+        _ => return None,
+    };
+    let negative_degrees = numbers.len() > 1 && numbers[0] < 0.0;
+
+    Some(if negative_degrees {
+        -degrees
+    } else {
+        sign * degrees
+    })
+}
+
+/// Convert decimal degrees to EXIF degrees/minutes/seconds rationals.
+///
+/// The value is rounded to whole seconds units first and then split, so
+/// rounding carries into the minutes and degrees instead of giving 60″.
+fn to_dms(value: f64) -> Vec<Rational> {
+    let precision = u64::from(SECONDS_PRECISION);
+    let units = (value.abs() * 3600.0 * precision as f64).round() as u64;
+    let seconds = units % (60 * precision);
+    let minutes = units / (60 * precision) % 60;
+    let degrees = units / (3600 * precision);
+
+    vec![
+        Rational {
+            num: degrees as u32,
+            denom: 1,
+        },
+        Rational {
+            num: minutes as u32,
+            denom: 1,
+        },
+        Rational {
+            num: seconds as u32,
+            denom: SECONDS_PRECISION,
+        },
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(coord: Option<GpsCoordinate>, lat: f64, lon: f64) {
+        let coord = coord.expect("coordinate should parse");
+        assert!((coord.latitude - lat).abs() < 1e-4, "{coord:?}");
+        assert!((coord.longitude - lon).abs() < 1e-4, "{coord:?}");
+    }
+
+    #[test]
+    fn test_parse_decimal_and_dms() {
+        assert_close(GpsCoordinate::parse("48.8584, 2.2945"), 48.8584, 2.2945);
+        assert_close(
+            GpsCoordinate::parse("-33.8568 151.2153"),
+            -33.8568,
+            151.2153,
+        );
+        assert_close(
+            GpsCoordinate::parse("33.8568 S 151.2153 E"),
+            -33.8568,
+            151.2153,
+        );
+        assert_close(
+            GpsCoordinate::parse("48°51'30.2\"N 2°17'40.2\"E"),
+            48.858_389,
+            2.294_5,
+        );
+        assert!(GpsCoordinate::parse("91.0, 0.0").is_none());
+        assert!(GpsCoordinate::parse("hello").is_none());
+    }
+
+    #[test]
+    fn test_parse_map_links() {
+        assert_close(
+            GpsCoordinate::parse("geo:47.0707,15.4395;u=10"),
+            47.0707,
+            15.4395,
+        );
+        assert_close(
+            GpsCoordinate::parse(
+                "https://www.openstreetmap.org/?mlat=47.0707&mlon=15.4395#map=16/47.07/15.43",
+            ),
+            47.0707,
+            15.4395,
+        );
+        assert_close(
+            GpsCoordinate::parse("https://www.openstreetmap.org/#map=17/47.07070/15.43950"),
+            47.0707,
+            15.4395,
+        );
+        assert_close(
+            GpsCoordinate::parse("https://www.google.com/maps/@47.0707,15.4395,15z"),
+            47.0707,
+            15.4395,
+        );
+    }
+
+    #[test]
+    fn test_to_dms() {
+        let dms = to_dms(-33.8568);
+        assert_eq!((dms[0].num, dms[1].num), (33, 51));
+        assert!((dms[2].to_f64() - 24.48).abs() < 1e-3);
+
+        // 59.99996″ rounds up into the next minute, and from there the degree
+        let dms = to_dms(12.0 + 59.0 / 60.0 + 59.999_96 / 3600.0);
+        assert_eq!((dms[0].num, dms[1].num, dms[2].num), (13, 0, 0));
+        let dms = to_dms(12.0 + 30.0 / 60.0 + 59.999_96 / 3600.0);
+        assert_eq!((dms[0].num, dms[1].num, dms[2].num), (12, 31, 0));
+    }
+}
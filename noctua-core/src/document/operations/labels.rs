@@ -0,0 +1,238 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/labels.rs
+//
+// Star ratings and tags written by photo managers, and filters on them.
+
+use std::path::{Path, PathBuf};
+
+use crate::document::core::file_bytes::FileBytes;
+
+use super::metadata_edit::MetadataFields;
+use super::xmp_edits::sidecar_candidates;
+
+/// Highest star rating.
+pub const MAX_RATING: u8 = 5;
+
+/// Star rating and tags of an image.
+///
+/// Read from `xmp:Rating` and the `dc:subject` keywords, which digiKam,
+/// darktable, Lightroom and most other photo managers write.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Labels {
+    /// Stars from 0 to 5 (rejected images count as 0).
+    pub rating: u8,
+    /// Keywords, as written.
+    pub tags: Vec<String>,
+}
+
+/// Labels of an XMP packet.
+#[must_use]
+pub fn parse(xmp: &str) -> Labels {
+    let rating = rating(xmp).unwrap_or(0);
+    let tags = xmp
+        .find("<dc:subject")
+        .and_then(|start| {
+            let rest = &xmp[start..];
+            rest.find("</dc:subject>").map(|end| &rest[..end])
+        })
+        .map(list_items)
+        .unwrap_or_default();
+    Labels { rating, tags }
+}
+
+/// Labels of `path`: from its XMP sidecar if it has one, else from the XMP
+/// packet embedded in the file. Without an XMP rating, the stars come from
+/// the EXIF rating (as Windows writes it). Images without any have no stars
+/// or tags.
+#[must_use]
+pub fn read(path: &Path) -> Labels {
+    let sidecar = sidecar_candidates(path)
+        .into_iter()
+        .filter(|sidecar| sidecar != path)
+        .find_map(|sidecar| std::fs::read_to_string(sidecar).ok());
+    let xmp = sidecar.or_else(|| embedded_xmp(path));
+    let mut labels = xmp.as_deref().map(parse).unwrap_or_default();
+    if xmp.as_deref().and_then(rating).is_none() {
+        labels.rating = MetadataFields::read(path).rating;
+    }
+    labels
+}
+
+/// Filter on the labels of images: a minimum rating and tags of which one
+/// must be present.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LabelFilter {
+    /// Fewest stars an image needs (0 = any).
+    pub min_rating: u8,
+    /// Tags compared without case; empty = any.
+    pub tags: Vec<String>,
+}
+
+impl LabelFilter {
+    /// Filter from a rating and comma-separated tags.
+    #[must_use]
+    pub fn new(min_rating: u8, tags: &str) -> Self {
+        Self {
+            min_rating: min_rating.min(MAX_RATING),
+            tags: tags
+                .split(',')
+                .map(str::trim)
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string)
+                .collect(),
+        }
+    }
+
+    /// Check if the filter lets every image through.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.min_rating == 0 && self.tags.is_empty()
+    }
+
+    /// Check if an image with `labels` passes.
+    #[must_use]
+    pub fn matches(&self, labels: &Labels) -> bool {
+        labels.rating >= self.min_rating
+            && (self.tags.is_empty()
+                || self.tags.iter().any(|wanted| {
+                    labels
+                        .tags
+                        .iter()
+                        .any(|tag| tag.eq_ignore_ascii_case(wanted))
+                }))
+    }
+
+    /// The images among `paths` that pass, in their order.
+    ///
+    /// Reads the labels of every image; meant for a background thread.
+    #[must_use]
+    pub fn select(&self, paths: &[PathBuf]) -> Vec<PathBuf> {
+        paths
+            .iter()
+            .filter(|path| self.is_empty() || self.matches(&read(path)))
+            .cloned()
+            .collect()
+    }
+}
+
+/// `xmp:Rating`, as attribute or element; -1 (rejected) reads as 0.
+fn rating(xmp: &str) -> Option<u8> {
+    let value = if let Some(start) = xmp.find("xmp:Rating=") {
+        let rest = &xmp[start + "xmp:Rating=".len()..];
+        let quote = rest.chars().next().filter(|&c| c == '"' || c == '\'')?;
+        rest[1..].split(quote).next()?
+    } else {
+        let start = xmp.find("<xmp:Rating>")? + "<xmp:Rating>".len();
+        let end = xmp[start..].find('<')?;
+        &xmp[start..start + end]
+    };
+    let stars = value.trim().parse::<f32>().ok()?;
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    Some(stars.round().clamp(0.0, f32::from(MAX_RATING)) as u8)
+}
+
+/// Contents of the `rdf:li` items in `list`.
+fn list_items(list: &str) -> Vec<String> {
+    list.split("<rdf:li")
+        .skip(1)
+        .filter_map(|item| {
+            let (_, content) = item.split_once('>')?;
+            let (text, _) = content.split_once("</rdf:li>")?;
+            let text = unescape(text.trim());
+            (!text.is_empty()).then_some(text)
+        })
+        .collect()
+}
+
+/// Text with the predefined XML entities replaced.
+pub(crate) fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+/// XMP packet stored inside the image file (JPEG, PNG, WebP, TIFF, ...).
+pub(crate) fn embedded_xmp(path: &Path) -> Option<String> {
+    const START: &[u8] = b"<x:xmpmeta";
+    const END: &[u8] = b"</x:xmpmeta>";
+
+    let bytes = FileBytes::open(path).ok()?;
+    let start = bytes
+        .windows(START.len())
+        .position(|window| window == START)?;
+    let length = bytes[start..]
+        .windows(END.len())
+        .position(|window| window == END)?;
+    let packet = &bytes[start..start + length + END.len()];
+    Some(String::from_utf8_lossy(packet).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGIKAM: &str = r#"<x:xmpmeta><rdf:RDF><rdf:Description xmp:Rating="4">
+        <dc:subject><rdf:Bag><rdf:li>Holiday</rdf:li><rdf:li>Tom &amp; Ann</rdf:li></rdf:Bag>
+        </dc:subject></rdf:Description></rdf:RDF></x:xmpmeta>"#;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse(DIGIKAM),
+            Labels {
+                rating: 4,
+                tags: vec!["Holiday".to_string(), "Tom & Ann".to_string()],
+            }
+        );
+        assert_eq!(parse("<xmp:Rating>-1</xmp:Rating>").rating, 0);
+        assert_eq!(parse("<x/>"), Labels::default());
+    }
+
+    #[test]
+    fn test_filter() {
+        let labels = parse(DIGIKAM);
+        assert!(LabelFilter::default().is_empty());
+        assert!(LabelFilter::new(4, "").matches(&labels));
+        assert!(!LabelFilter::new(5, "").matches(&labels));
+        assert!(LabelFilter::new(0, "beach, holiday").matches(&labels));
+        assert!(!LabelFilter::new(3, "beach").matches(&labels));
+        assert_eq!(LabelFilter::new(9, " a ,, b ").tags, vec!["a", "b"]);
+        assert_eq!(LabelFilter::new(9, "").min_rating, MAX_RATING);
+    }
+
+    #[test]
+    fn test_read_sidecar_and_embedded() {
+        let dir = std::env::temp_dir().join(format!("noctua-labels-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let embedded = dir.join("embedded.jpg");
+        let mut bytes = vec![0xFF, 0xD8, 0xFF, 0xE1];
+        bytes.extend_from_slice(DIGIKAM.as_bytes());
+        std::fs::write(&embedded, bytes).unwrap();
+        let with_sidecar = dir.join("sidecar.jpg");
+        std::fs::write(&with_sidecar, b"\xFF\xD8").unwrap();
+        std::fs::write(dir.join("sidecar.jpg.xmp"), r#"<x xmp:Rating="2"/>"#).unwrap();
+        let plain = dir.join("plain.jpg");
+        std::fs::write(&plain, b"\xFF\xD8").unwrap();
+
+        assert_eq!(read(&embedded).rating, 4);
+        assert_eq!(read(&with_sidecar).rating, 2);
+        assert_eq!(read(&plain), Labels::default());
+        let paths = [embedded.clone(), with_sidecar, plain];
+        assert_eq!(LabelFilter::new(3, "").select(&paths), vec![embedded]);
+        assert_eq!(LabelFilter::default().select(&paths).len(), 3);
+
+        // Stars written to EXIF only, as by Windows
+        let exif_only = dir.join("exif.jpg");
+        image::RgbImage::new(2, 2).save(&exif_only).unwrap();
+        let stars = MetadataFields {
+            rating: 3,
+            ..MetadataFields::default()
+        };
+        crate::document::operations::metadata_edit::write(&exif_only, &stars).unwrap();
+        assert_eq!(read(&exif_only).rating, 3);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,207 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/metadata_edit.rs
+//
+// Descriptive EXIF fields (author, copyright, description, rating, capture
+// date), read for editing and written back without touching the pixels.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+use exif::{Context, Field, In, Reader, Tag, Value};
+
+use super::date_shift::ExifDateTime;
+use super::exif_write;
+use super::labels::MAX_RATING;
+use crate::document::core::document::DocResult;
+
+/// Star rating, as Windows and many cameras write it (not in the EXIF
+/// standard, so the exif crate has no name for it).
+pub const RATING: Tag = Tag(Context::Tiff, 0x4746);
+
+/// Star rating as a percentage, written next to [`RATING`] by Windows.
+pub const RATING_PERCENT: Tag = Tag(Context::Tiff, 0x4749);
+
+/// Percentages Windows stores for 0 to 5 stars.
+const RATING_PERCENTS: [u16; 6] = [0, 1, 25, 50, 75, 99];
+
+/// Editable fields of an image; empty fields are not stored.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MetadataFields {
+    /// Photographer or creator (`Artist`).
+    pub author: String,
+    /// Copyright notice (`Copyright`).
+    pub copyright: String,
+    /// Title or caption (`ImageDescription`).
+    pub description: String,
+    /// Stars from 0 (none) to 5.
+    pub rating: u8,
+    /// When the picture was taken (`DateTimeOriginal`).
+    pub capture_date: Option<ExifDateTime>,
+}
+
+impl MetadataFields {
+    /// Fields stored in `path`; files without EXIF data have none.
+    #[must_use]
+    pub fn read(path: &Path) -> Self {
+        let Ok(file) = File::open(path) else {
+            return Self::default();
+        };
+        Reader::new()
+            .read_from_container(&mut BufReader::new(file))
+            .map(|exif| Self::from_fields(exif.fields().filter(|f| f.ifd_num == In::PRIMARY)))
+            .unwrap_or_default()
+    }
+
+    /// Fields among the EXIF fields of the primary image.
+    #[must_use]
+    pub fn from_fields<'a>(fields: impl IntoIterator<Item = &'a Field>) -> Self {
+        let mut read = Self::default();
+        for field in fields {
+            match field.tag {
+                Tag::Artist => read.author = ascii(&field.value),
+                Tag::Copyright => read.copyright = ascii(&field.value),
+                Tag::ImageDescription => read.description = ascii(&field.value),
+                Tag::DateTimeOriginal => {
+                    read.capture_date = ExifDateTime::parse(&ascii(&field.value));
+                }
+                RATING => {
+                    let stars = field.value.get_uint(0).unwrap_or(0);
+                    read.rating = u8::try_from(stars).unwrap_or(0).min(MAX_RATING);
+                }
+                _ => {}
+            }
+        }
+        read
+    }
+
+    /// Replace the edited fields in `fields`; empty ones are removed.
+    pub fn apply(&self, fields: &mut Vec<Field>) {
+        const EDITED: [Tag; 6] = [
+            Tag::Artist,
+            Tag::Copyright,
+            Tag::ImageDescription,
+            Tag::DateTimeOriginal,
+            RATING,
+            RATING_PERCENT,
+        ];
+        fields.retain(|f| !EDITED.contains(&f.tag));
+
+        let field = |tag, value| Field {
+            tag,
+            ifd_num: In::PRIMARY,
+            value,
+        };
+        for (tag, text) in [
+            (Tag::Artist, &self.author),
+            (Tag::Copyright, &self.copyright),
+            (Tag::ImageDescription, &self.description),
+        ] {
+            let text = text.trim();
+            if !text.is_empty() {
+                fields.push(field(tag, Value::Ascii(vec![text.as_bytes().to_vec()])));
+            }
+        }
+        if let Some(date) = self.capture_date {
+            let value = Value::Ascii(vec![date.to_string().into_bytes()]);
+            fields.push(field(Tag::DateTimeOriginal, value));
+        }
+        let rating = self.rating.min(MAX_RATING);
+        if rating > 0 {
+            let percent = RATING_PERCENTS[usize::from(rating)];
+            fields.push(field(RATING, Value::Short(vec![u16::from(rating)])));
+            fields.push(field(RATING_PERCENT, Value::Short(vec![percent])));
+        }
+    }
+}
+
+/// Store `edited` in the JPEG or PNG file `path`, in place.
+///
+/// Only the EXIF block is rewritten; the image data is copied byte for byte.
+pub fn write(path: &Path, edited: &MetadataFields) -> DocResult<()> {
+    exif_write::update_exif_fields(path, |fields| edited.apply(fields))
+}
+
+/// Text of an ASCII field: its first non-empty part (the photographer of a
+/// copyright with an editor part), trimmed.
+fn ascii(value: &Value) -> String {
+    match value {
+        Value::Ascii(parts) => parts
+            .iter()
+            .map(|part| String::from_utf8_lossy(part).trim().to_string())
+            .find(|part| !part.is_empty())
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn edited() -> MetadataFields {
+        MetadataFields {
+            author: "Ann Example".to_string(),
+            copyright: "© 2026 Ann Example".to_string(),
+            description: "Harbor at dusk".to_string(),
+            rating: 4,
+            capture_date: ExifDateTime::parse("2026:05:17 19:42:05"),
+        }
+    }
+
+    #[test]
+    fn test_apply_replaces_and_removes() {
+        let camera = Field {
+            tag: Tag::Make,
+            ifd_num: In::PRIMARY,
+            value: Value::Ascii(vec![b"Canon".to_vec()]),
+        };
+        let mut fields = vec![camera];
+        edited().apply(&mut fields);
+        assert_eq!(MetadataFields::from_fields(&fields), edited());
+        let percent = fields.iter().find(|f| f.tag == RATING_PERCENT).unwrap();
+        assert_eq!(percent.value.get_uint(0), Some(75));
+
+        // Emptied fields go, others stay
+        let cleared = MetadataFields {
+            description: "  ".to_string(),
+            rating: 0,
+            capture_date: None,
+            ..edited()
+        };
+        cleared.apply(&mut fields);
+        assert_eq!(fields.len(), 3);
+        assert!(fields.iter().any(|f| f.tag == Tag::Make));
+        assert_eq!(MetadataFields::from_fields(&fields).description, "");
+    }
+
+    #[test]
+    fn test_copyright_with_editor_part() {
+        let field = Field {
+            tag: Tag::Copyright,
+            ifd_num: In::PRIMARY,
+            value: Value::Ascii(vec![b"".to_vec(), b"Studio B".to_vec()]),
+        };
+        let read = MetadataFields::from_fields([&field]);
+        assert_eq!(read.copyright, "Studio B");
+    }
+
+    #[test]
+    fn test_write_keeps_pixels() {
+        let dir = std::env::temp_dir().join("noctua-metadata-edit-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("photo.jpg");
+        image::RgbImage::from_fn(16, 8, |x, y| image::Rgb([x as u8 * 16, y as u8 * 32, 90]))
+            .save(&path)
+            .unwrap();
+        let pixels = image::open(&path).unwrap().to_rgb8();
+
+        write(&path, &edited()).unwrap();
+        assert_eq!(MetadataFields::read(&path), edited());
+        assert_eq!(image::open(&path).unwrap().to_rgb8(), pixels);
+
+        write(&path, &MetadataFields::default()).unwrap();
+        assert_eq!(MetadataFields::read(&path), MetadataFields::default());
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,549 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/metadata_tags.rs
+//
+// Every metadata tag of an image: EXIF by directory, XMP properties
+// (embedded and sidecar) and IPTC datasets, for the metadata browser.
+
+use std::io::Cursor;
+use std::path::Path;
+
+use crate::document::core::file_bytes::FileBytes;
+
+use super::labels;
+use super::xmp_edits::sidecar_candidates;
+
+/// Values are cut to this many characters (maker notes and other binary
+/// blobs run to kilobytes of hex).
+pub const MAX_VALUE_CHARS: usize = 300;
+
+/// Where a group of tags is stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagGroup {
+    /// EXIF tags of the image itself (TIFF directory).
+    Image,
+    /// EXIF tags of the capture: exposure, lens, dates.
+    Photo,
+    /// EXIF GPS tags.
+    Gps,
+    /// EXIF interoperability tags.
+    Interoperability,
+    /// EXIF tags of the embedded thumbnail.
+    Thumbnail,
+    /// XMP packet embedded in the file.
+    Xmp,
+    /// XMP sidecar file next to the image.
+    XmpSidecar,
+    /// IPTC-IIM datasets (Photoshop image resources).
+    Iptc,
+}
+
+/// Name and value of a tag, as text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataTag {
+    pub name: String,
+    pub value: String,
+}
+
+impl MetadataTag {
+    fn new(name: impl Into<String>, value: &str) -> Self {
+        let value = value.trim();
+        let value = match value.char_indices().nth(MAX_VALUE_CHARS) {
+            Some((cut, _)) => format!("{}…", &value[..cut]),
+            None => value.to_string(),
+        };
+        Self {
+            name: name.into(),
+            value,
+        }
+    }
+
+    /// Check if `query` (lowercase) is in the name or value.
+    fn matches(&self, query: &str) -> bool {
+        self.name.to_lowercase().contains(query) || self.value.to_lowercase().contains(query)
+    }
+}
+
+/// Tags of one group, in the order they are stored.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataGroup {
+    pub group: TagGroup,
+    pub tags: Vec<MetadataTag>,
+}
+
+/// All metadata of `path`, grouped; groups without tags are left out.
+///
+/// Reads the whole file; meant for a background thread.
+#[must_use]
+pub fn read(path: &Path) -> Vec<MetadataGroup> {
+    let mut groups = Vec::new();
+    if let Ok(bytes) = FileBytes::open(path) {
+        groups.extend(exif_groups(&bytes));
+        if let Some(xmp) = labels::embedded_xmp(path) {
+            push_group(&mut groups, TagGroup::Xmp, parse_xmp(&xmp));
+        }
+        push_group(&mut groups, TagGroup::Iptc, parse_iptc(&bytes));
+    }
+    let sidecar = sidecar_candidates(path)
+        .into_iter()
+        .filter(|sidecar| sidecar != path)
+        .find_map(|sidecar| std::fs::read_to_string(sidecar).ok());
+    if let Some(xmp) = sidecar {
+        push_group(&mut groups, TagGroup::XmpSidecar, parse_xmp(&xmp));
+    }
+    groups
+}
+
+/// The tags of `groups` with `query` in their name or value, compared
+/// without case; groups left without tags are dropped.
+#[must_use]
+pub fn filter(groups: &[MetadataGroup], query: &str) -> Vec<MetadataGroup> {
+    let query = query.trim().to_lowercase();
+    groups
+        .iter()
+        .filter_map(|group| {
+            let tags: Vec<MetadataTag> = group
+                .tags
+                .iter()
+                .filter(|tag| tag.matches(&query))
+                .cloned()
+                .collect();
+            (!tags.is_empty()).then_some(MetadataGroup {
+                group: group.group,
+                tags,
+            })
+        })
+        .collect()
+}
+
+fn push_group(groups: &mut Vec<MetadataGroup>, group: TagGroup, tags: Vec<MetadataTag>) {
+    if !tags.is_empty() {
+        groups.push(MetadataGroup { group, tags });
+    }
+}
+
+/// EXIF tags of an image file (or bare TIFF data), by directory.
+fn exif_groups(bytes: &[u8]) -> Vec<MetadataGroup> {
+    use exif::{Context, In, Reader, Tag, Value};
+
+    let Ok(exif) = Reader::new().read_from_container(&mut Cursor::new(bytes)) else {
+        return Vec::new();
+    };
+    let mut groups: Vec<MetadataGroup> = Vec::new();
+    for field in exif.fields() {
+        // Offsets of other directories, not tags of their own
+        if matches!(
+            field.tag,
+            Tag::ExifIFDPointer | Tag::GPSInfoIFDPointer | Tag::InteropIFDPointer
+        ) {
+            continue;
+        }
+        let group = if field.ifd_num == In::THUMBNAIL {
+            TagGroup::Thumbnail
+        } else {
+            match field.tag.context() {
+                Context::Exif => TagGroup::Photo,
+                Context::Gps => TagGroup::Gps,
+                Context::Interop => TagGroup::Interoperability,
+                _ => TagGroup::Image,
+            }
+        };
+        // Text without the quotes the crate puts around it
+        let value = match &field.value {
+            Value::Ascii(lines) => lines
+                .iter()
+                .map(|line| String::from_utf8_lossy(line).into_owned())
+                .collect::<Vec<_>>()
+                .join(", "),
+            _ => field.display_value().with_unit(&exif).to_string(),
+        };
+        let tag = MetadataTag::new(field.tag.to_string(), &value);
+        match groups.iter_mut().find(|existing| existing.group == group) {
+            Some(existing) => existing.tags.push(tag),
+            None => groups.push(MetadataGroup {
+                group,
+                tags: vec![tag],
+            }),
+        }
+    }
+    groups
+}
+
+/// Properties of an XMP packet: attributes of the `rdf:Description`
+/// elements, simple elements and lists (`rdf:Bag`, `rdf:Seq`, `rdf:Alt`),
+/// whose items are joined with commas.
+///
+/// Structures nested in a property (e.g. the edit history) are skipped.
+#[must_use]
+pub fn parse_xmp(xmp: &str) -> Vec<MetadataTag> {
+    let mut tags = Vec::new();
+    // Property element being read, with its text or list items
+    let mut property: Option<(String, Vec<String>)> = None;
+    let mut depth = 0usize;
+
+    let mut rest = xmp;
+    while let Some(start) = rest.find('<') {
+        let Some(end) = rest[start..].find('>') else {
+            break;
+        };
+        let element = &rest[start + 1..start + end];
+        let after = &rest[start + end + 1..];
+        let text = &after[..after.find('<').unwrap_or(after.len())];
+        rest = after;
+
+        if element.starts_with('?') || element.starts_with('!') {
+            continue;
+        }
+        let self_closing = element.ends_with('/');
+        let element = element.trim_end_matches('/');
+        let (name, attributes) = element
+            .split_once(char::is_whitespace)
+            .unwrap_or((element, ""));
+
+        if let Some(closed) = name.strip_prefix('/') {
+            let Some((open, values)) = &property else {
+                continue;
+            };
+            if depth == 0 && open == closed {
+                if !values.is_empty() {
+                    tags.push(MetadataTag::new(open.as_str(), &values.join(", ")));
+                }
+                property = None;
+            } else if !is_syntax(closed) {
+                depth = depth.saturating_sub(1);
+            }
+            continue;
+        }
+
+        match (&mut property, name) {
+            (None, "rdf:Description") => tags.extend(properties(attributes)),
+            (None, _) if !is_syntax(name) => {
+                if self_closing {
+                    tags.extend(properties(attributes));
+                } else {
+                    let text = labels::unescape(text.trim());
+                    let values = if text.is_empty() {
+                        Vec::new()
+                    } else {
+                        vec![text]
+                    };
+                    property = Some((name.to_string(), values));
+                }
+            }
+            (Some((_, values)), "rdf:li") if depth == 0 && !self_closing => {
+                let text = labels::unescape(text.trim());
+                if !text.is_empty() {
+                    values.push(text);
+                }
+            }
+            (Some(_), _) if !is_syntax(name) && !self_closing => depth += 1,
+            _ => {}
+        }
+    }
+    tags
+}
+
+/// Whether an element or attribute belongs to the RDF and XMP syntax
+/// rather than being a property.
+fn is_syntax(name: &str) -> bool {
+    name.starts_with("rdf:") || name.starts_with("x:") || name.starts_with("xml")
+}
+
+/// Properties written as `prefix:Name="value"` attributes.
+fn properties(attributes: &str) -> Vec<MetadataTag> {
+    let mut tags = Vec::new();
+    let mut rest = attributes;
+    while let Some(equals) = rest.find('=') {
+        let name = rest[..equals].trim();
+        let value_start = rest[equals + 1..].trim_start();
+        let Some(quote) = value_start
+            .chars()
+            .next()
+            .filter(|&c| c == '"' || c == '\'')
+        else {
+            break;
+        };
+        let Some(length) = value_start[1..].find(quote) else {
+            break;
+        };
+        let value = &value_start[1..=length];
+        if name.contains(':') && !is_syntax(name) {
+            tags.push(MetadataTag::new(name, &labels::unescape(value)));
+        }
+        rest = &value_start[length + 2..];
+    }
+    tags
+}
+
+/// IPTC-IIM datasets of the application record (2:xx), from the first
+/// Photoshop image resource block in `bytes` (a JPEG APP13 segment).
+///
+/// Repeated datasets such as keywords give one tag with the values
+/// joined by commas.
+#[must_use]
+pub fn parse_iptc(bytes: &[u8]) -> Vec<MetadataTag> {
+    const PHOTOSHOP: &[u8] = b"Photoshop 3.0\0";
+    const IPTC_RESOURCE: u16 = 0x0404;
+
+    let Some(start) = bytes
+        .windows(PHOTOSHOP.len())
+        .position(|window| window == PHOTOSHOP)
+    else {
+        return Vec::new();
+    };
+    let mut resources = &bytes[start + PHOTOSHOP.len()..];
+    while resources.len() >= 12 && resources.starts_with(b"8BIM") {
+        let id = u16::from_be_bytes([resources[4], resources[5]]);
+        // Pascal name, padded with its length byte to an even size
+        let name_size = (usize::from(resources[6]) + 2) & !1;
+        let Some(header) = resources.get(6 + name_size..10 + name_size) else {
+            break;
+        };
+        let size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as usize;
+        let data_start = 10 + name_size;
+        if id == IPTC_RESOURCE {
+            // A block cut short still gives the datasets before the cut
+            let end = (data_start + size).min(resources.len());
+            return parse_iim(&resources[data_start..end]);
+        }
+        let next = data_start + ((size + 1) & !1);
+        resources = resources.get(next..).unwrap_or_default();
+    }
+    Vec::new()
+}
+
+/// Datasets of the application record in IIM `data`.
+fn parse_iim(mut data: &[u8]) -> Vec<MetadataTag> {
+    let mut datasets: Vec<(u8, Vec<String>)> = Vec::new();
+    while data.len() >= 5 && data[0] == 0x1C {
+        let (record, dataset) = (data[1], data[2]);
+        let length = usize::from(u16::from_be_bytes([data[3], data[4]]));
+        // Extended datasets (over 32 KB) are not text
+        if length & 0x8000 != 0 {
+            break;
+        }
+        let Some(value) = data.get(5..5 + length) else {
+            break;
+        };
+        data = &data[5 + length..];
+        // 2:00 is the record version, a binary number
+        if record != 2 || dataset == 0 {
+            continue;
+        }
+        let value = String::from_utf8_lossy(value).trim().to_string();
+        if value.is_empty() {
+            continue;
+        }
+        match datasets.iter_mut().find(|(number, _)| *number == dataset) {
+            Some((_, values)) => values.push(value),
+            None => datasets.push((dataset, vec![value])),
+        }
+    }
+    datasets
+        .into_iter()
+        .map(|(dataset, values)| {
+            let name = iim_name(dataset).map_or_else(|| format!("2:{dataset}"), str::to_string);
+            MetadataTag::new(name, &values.join(", "))
+        })
+        .collect()
+}
+
+/// Name of an application record dataset, as in the IIM specification.
+fn iim_name(dataset: u8) -> Option<&'static str> {
+    Some(match dataset {
+        5 => "Object Name",
+        7 => "Edit Status",
+        10 => "Urgency",
+        15 => "Category",
+        20 => "Supplemental Category",
+        25 => "Keywords",
+        40 => "Special Instructions",
+        55 => "Date Created",
+        60 => "Time Created",
+        62 => "Digital Creation Date",
+        63 => "Digital Creation Time",
+        65 => "Originating Program",
+        80 => "By-line",
+        85 => "By-line Title",
+        90 => "City",
+        92 => "Sub-location",
+        95 => "Province/State",
+        100 => "Country Code",
+        101 => "Country",
+        103 => "Original Transmission Reference",
+        105 => "Headline",
+        110 => "Credit",
+        115 => "Source",
+        116 => "Copyright Notice",
+        118 => "Contact",
+        120 => "Caption/Abstract",
+        122 => "Writer/Editor",
+        _ => return None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(name: &str, value: &str) -> MetadataTag {
+        MetadataTag::new(name, value)
+    }
+
+    /// Photoshop image resources holding `datasets` as IIM.
+    fn iptc_block(datasets: &[(u8, u8, &str)]) -> Vec<u8> {
+        let mut iim = Vec::new();
+        for &(record, dataset, value) in datasets {
+            iim.extend_from_slice(&[0x1C, record, dataset]);
+            iim.extend_from_slice(&(value.len() as u16).to_be_bytes());
+            iim.extend_from_slice(value.as_bytes());
+        }
+        let mut block = b"\xFF\xD8\xFF\xED\0\0Photoshop 3.0\0".to_vec();
+        // A resolution resource with an odd size comes first
+        block.extend_from_slice(b"8BIM\x03\xED\0\0\0\0\0\x03abc\0");
+        block.extend_from_slice(b"8BIM\x04\x04\0\0");
+        block.extend_from_slice(&(iim.len() as u32).to_be_bytes());
+        block.extend_from_slice(&iim);
+        block
+    }
+
+    #[test]
+    fn test_parse_iptc() {
+        let bytes = iptc_block(&[
+            (1, 90, "\x1B%G"),
+            (2, 0, "\0\x04"),
+            (2, 25, "owl"),
+            (2, 116, "© 2024 Ann Smith"),
+            (2, 25, "night"),
+            (2, 120, "Tawny owl at dusk "),
+            (2, 199, "custom"),
+        ]);
+        assert_eq!(
+            parse_iptc(&bytes),
+            vec![
+                tag("Keywords", "owl, night"),
+                tag("Copyright Notice", "© 2024 Ann Smith"),
+                tag("Caption/Abstract", "Tawny owl at dusk"),
+                tag("2:199", "custom"),
+            ]
+        );
+        assert!(parse_iptc(b"\xFF\xD8\xFF\xD9").is_empty());
+        // Cut off in the middle of a dataset
+        assert_eq!(parse_iptc(&bytes[..bytes.len() - 8]).len(), 3);
+    }
+
+    #[test]
+    fn test_parse_xmp() {
+        let xmp = r#"<?xpacket begin="" id="W5M0MpCehiHzreSzNTczkc9d"?>
+            <x:xmpmeta xmlns:x="adobe:ns:meta/"><rdf:RDF xmlns:rdf="http://www.w3.org/1999/02/22-rdf-syntax-ns#">
+            <rdf:Description rdf:about="" xmlns:xmp="http://ns.adobe.com/xap/1.0/"
+                xmp:Rating="4" xmp:CreatorTool='darktable 4.6'>
+              <dc:subject><rdf:Bag><rdf:li>Holiday</rdf:li><rdf:li>Tom &amp; Ann</rdf:li></rdf:Bag></dc:subject>
+              <dc:rights><rdf:Alt><rdf:li xml:lang="x-default">CC BY 4.0</rdf:li></rdf:Alt></dc:rights>
+              <photoshop:City>Oslo</photoshop:City>
+              <xmpMM:History><rdf:Seq><rdf:li><rdf:Description stEvt:action="saved"/></rdf:li></rdf:Seq></xmpMM:History>
+              <exif:Flash exif:Fired="False"/>
+              <dc:title/>
+            </rdf:Description></rdf:RDF></x:xmpmeta><?xpacket end="w"?>"#;
+        assert_eq!(
+            parse_xmp(xmp),
+            vec![
+                tag("xmp:Rating", "4"),
+                tag("xmp:CreatorTool", "darktable 4.6"),
+                tag("dc:subject", "Holiday, Tom & Ann"),
+                tag("dc:rights", "CC BY 4.0"),
+                tag("photoshop:City", "Oslo"),
+                tag("exif:Fired", "False"),
+            ]
+        );
+        assert!(parse_xmp("").is_empty());
+        assert!(parse_xmp("<x:xmpmeta><rdf:RDF></rdf:RDF></x:xmpmeta>").is_empty());
+    }
+
+    #[test]
+    fn test_exif_groups() {
+        use exif::experimental::Writer;
+        use exif::{Field, In, Rational, Tag, Value};
+
+        let fields = [
+            Field {
+                tag: Tag::Make,
+                ifd_num: In::PRIMARY,
+                value: Value::Ascii(vec![b"Owlcam".to_vec()]),
+            },
+            Field {
+                tag: Tag::FocalLength,
+                ifd_num: In::PRIMARY,
+                value: Value::Rational(vec![Rational::from((50, 1))]),
+            },
+            Field {
+                tag: Tag::MakerNote,
+                ifd_num: In::PRIMARY,
+                value: Value::Undefined(vec![0xAB; 2000], 0),
+            },
+            Field {
+                tag: Tag::GPSAltitudeRef,
+                ifd_num: In::PRIMARY,
+                value: Value::Byte(vec![0]),
+            },
+        ];
+        let mut writer = Writer::new();
+        for field in &fields {
+            writer.push_field(field);
+        }
+        let mut tiff = Cursor::new(Vec::new());
+        writer.write(&mut tiff, false).unwrap();
+
+        let groups = exif_groups(&tiff.into_inner());
+        let order: Vec<TagGroup> = groups.iter().map(|group| group.group).collect();
+        assert_eq!(order, [TagGroup::Image, TagGroup::Photo, TagGroup::Gps]);
+        assert_eq!(groups[0].tags, vec![tag("Make", "Owlcam")]);
+        assert_eq!(groups[1].tags[0], tag("FocalLength", "50 mm"));
+        assert_eq!(groups[1].tags[1].value.chars().count(), MAX_VALUE_CHARS + 1);
+        assert_eq!(groups[2].tags[0].name, "GPSAltitudeRef");
+        assert!(exif_groups(b"not an image").is_empty());
+    }
+
+    #[test]
+    fn test_filter() {
+        let groups = vec![
+            MetadataGroup {
+                group: TagGroup::Image,
+                tags: vec![tag("Make", "Owlcam"), tag("Model", "X100")],
+            },
+            MetadataGroup {
+                group: TagGroup::Xmp,
+                tags: vec![tag("xmp:Rating", "4")],
+            },
+        ];
+        assert_eq!(filter(&groups, ""), groups);
+        assert_eq!(
+            filter(&groups, " OWL "),
+            vec![MetadataGroup {
+                group: TagGroup::Image,
+                tags: vec![tag("Make", "Owlcam")],
+            }]
+        );
+        assert_eq!(filter(&groups, "rating")[0].group, TagGroup::Xmp);
+        assert!(filter(&groups, "lens").is_empty());
+    }
+
+    #[test]
+    fn test_read_file_and_sidecar() {
+        let dir = std::env::temp_dir().join(format!("noctua-tags-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("owl.jpg");
+        let mut bytes = iptc_block(&[(2, 25, "owl")]);
+        bytes.extend_from_slice(br#"<x:xmpmeta><rdf:Description xmp:Rating="3"/></x:xmpmeta>"#);
+        std::fs::write(&path, bytes).unwrap();
+        std::fs::write(dir.join("owl.xmp"), r#"<x xmp:Rating="5"/>"#).unwrap();
+
+        let groups = read(&path);
+        let order: Vec<TagGroup> = groups.iter().map(|group| group.group).collect();
+        assert_eq!(order, [TagGroup::Xmp, TagGroup::Iptc, TagGroup::XmpSidecar]);
+        assert_eq!(groups[0].tags, vec![tag("xmp:Rating", "3")]);
+        assert_eq!(groups[2].tags, vec![tag("xmp:Rating", "5")]);
+        assert!(read(&dir.join("missing.jpg")).is_empty());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/mod.rs
+//
+// Document operations: transformations, rendering, and export.
+
+pub mod adjust;
+pub mod alpha;
+mod animated_webp;
+pub mod animation;
+pub mod catalog;
+pub mod crop;
+pub mod date_shift;
+pub mod edit_macro;
+pub mod embedded_preview;
+pub mod exif_write;
+pub mod export;
+pub mod export_profile;
+pub mod format_advice;
+pub mod geotag;
+pub mod labels;
+pub mod metadata_edit;
+pub mod metadata_tags;
+pub mod pixel_art;
+pub mod privacy;
+pub mod region_stats;
+pub mod render;
+pub mod resize;
+pub mod scan_cleanup;
+pub mod sharpen;
+pub mod slideshow;
+pub mod sprite_sheet;
+pub mod straighten;
+pub mod strip;
+pub mod svg_markup;
+pub mod threshold;
+pub mod tile;
+pub mod transform;
+pub mod xmp_edits;
+
+// Re-export CropRegion for convenience
+pub use crop::CropRegion;
+
+// Note: Low-level pixel operations (apply_rotation, apply_flip, crop_image)
+// are internal helpers (pub(crate)) used only by document type implementations.
+// Use high-level operations above for application and UI code.
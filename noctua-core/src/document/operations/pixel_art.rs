@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/pixel_art.rs
+//
+// Pixel-art detection and integer zoom steps for crisp scaling.
+
+use std::collections::HashSet;
+
+use image::{DynamicImage, GenericImageView};
+
+/// Largest side (in pixels) an image may have to count as pixel art.
+const MAX_SIZE: u32 = 256;
+
+/// Maximum number of distinct colors in a pixel-art image.
+const MAX_COLORS: usize = 256;
+
+/// Largest integer zoom factor offered for pixel art.
+const MAX_ZOOM: f32 = 32.0;
+
+/// Tolerance when comparing a scale with a zoom step.
+const STEP_EPSILON: f32 = 0.001;
+
+/// Check if an image looks like pixel art (small, with few distinct colors).
+#[must_use]
+pub fn is_pixel_art(img: &DynamicImage) -> bool {
+    let (width, height) = img.dimensions();
+    if width == 0 || height == 0 || width.max(height) > MAX_SIZE {
+        return false;
+    }
+
+    let mut colors = HashSet::with_capacity(MAX_COLORS + 1);
+    for (_, _, pixel) in img.pixels() {
+        colors.insert(pixel.0);
+        if colors.len() > MAX_COLORS {
+            return false;
+        }
+    }
+    true
+}
+
+/// Next power-of-two zoom step (25%, 50%, 100%, 200%, 400%, ...) from `scale`.
+#[must_use]
+pub fn integer_zoom_step(scale: f32, zoom_in: bool) -> f32 {
+    let exponent = scale.max(f32::MIN_POSITIVE).log2();
+    let next = if zoom_in {
+        (exponent + STEP_EPSILON).floor() + 1.0
+    } else {
+        (exponent - STEP_EPSILON).ceil() - 1.0
+    };
+    2f32.powf(next).min(MAX_ZOOM)
+}
+
+/// Next snapped zoom level from `scale`: whole multiples above 100%
+/// (200%, 300%, ...) and simple fractions below (50%, 33%, 25%, ...).
+#[must_use]
+pub fn snap_zoom_step(scale: f32, zoom_in: bool) -> f32 {
+    let scale = scale.max(f32::MIN_POSITIVE);
+    match (zoom_in, scale >= 1.0 - STEP_EPSILON) {
+        (true, true) => (scale + STEP_EPSILON).floor() + 1.0,
+        (true, false) => 1.0 / ((1.0 / scale - STEP_EPSILON).ceil() - 1.0).max(1.0),
+        (false, _) if scale > 1.0 + STEP_EPSILON => (scale - STEP_EPSILON).ceil() - 1.0,
+        (false, _) => 1.0 / ((1.0 / scale + STEP_EPSILON).floor() + 1.0),
+    }
+}
+
+/// Largest integer zoom (at least 100%) that shows the whole image in the canvas.
+///
+/// Returns 1.0 if the canvas size is not known yet.
+#[must_use]
+pub fn integer_fit_scale(
+    (width, height): (u32, u32),
+    (canvas_width, canvas_height): (f32, f32),
+) -> f32 {
+    if width == 0 || height == 0 || canvas_width <= 0.0 || canvas_height <= 0.0 {
+        return 1.0;
+    }
+    let fit = (canvas_width / width as f32).min(canvas_height / height as f32);
+    fit.floor().clamp(1.0, MAX_ZOOM)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    fn test_detect_pixel_art() {
+        let sprite = RgbaImage::from_fn(32, 32, |x, y| {
+            if (x / 4 + y / 4) % 2 == 0 {
+                Rgba([255, 0, 0, 255])
+            } else {
+                Rgba([0, 0, 255, 255])
+            }
+        });
+        assert!(is_pixel_art(&DynamicImage::ImageRgba8(sprite)));
+
+        // Gradient: too many colors
+        let gradient =
+            RgbaImage::from_fn(64, 64, |x, y| Rgba([(x * 4) as u8, (y * 4) as u8, 0, 255]));
+        assert!(!is_pixel_art(&DynamicImage::ImageRgba8(gradient)));
+
+        // Too large even with a single color
+        assert!(!is_pixel_art(&DynamicImage::new_rgb8(1024, 16)));
+    }
+
+    #[test]
+    fn test_integer_zoom_steps() {
+        assert_eq!(integer_zoom_step(1.0, true), 2.0);
+        assert_eq!(integer_zoom_step(2.0, true), 4.0);
+        assert_eq!(integer_zoom_step(3.0, true), 4.0);
+        assert_eq!(integer_zoom_step(4.0, false), 2.0);
+        assert_eq!(integer_zoom_step(1.0, false), 0.5);
+        assert_eq!(integer_zoom_step(32.0, true), MAX_ZOOM);
+    }
+
+    #[test]
+    fn test_snap_zoom_steps() {
+        assert_eq!(snap_zoom_step(1.0, true), 2.0);
+        assert_eq!(snap_zoom_step(1.5, true), 2.0);
+        assert_eq!(snap_zoom_step(2.5, false), 2.0);
+        assert_eq!(snap_zoom_step(2.0, false), 1.0);
+        assert_eq!(snap_zoom_step(1.0, false), 0.5);
+        assert!((snap_zoom_step(0.5, false) - 1.0 / 3.0).abs() < 1e-6);
+        assert!((snap_zoom_step(0.3, true) - 1.0 / 3.0).abs() < 1e-6);
+        assert_eq!(snap_zoom_step(0.5, true), 1.0);
+        assert_eq!(snap_zoom_step(0.8, true), 1.0);
+    }
+
+    #[test]
+    fn test_integer_fit_scale() {
+        assert_eq!(integer_fit_scale((32, 16), (800.0, 600.0)), 25.0);
+        assert_eq!(integer_fit_scale((200, 100), (300.0, 300.0)), 1.0);
+        assert_eq!(integer_fit_scale((16, 16), (0.0, 0.0)), 1.0);
+    }
+}
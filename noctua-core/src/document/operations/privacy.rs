@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/privacy.rs
+//
+// Privacy export: blur fixed regions (faces, plates, names) in image copies.
+
+use std::path::{Path, PathBuf};
+
+use image::imageops::FilterType;
+use image::DynamicImage;
+
+use super::alpha;
+use super::export::{self, ExportFormat, ImageExportOptions};
+use crate::document::core::document::DocResult;
+
+/// Downscale factor used to blur a region (larger = stronger blur).
+const BLUR_FACTOR: u32 = 16;
+
+/// Rectangle to blur, relative to the image size (0.0 to 1.0).
+///
+/// Relative coordinates let one set of regions apply to images of
+/// different resolutions (e.g. a fixed webcam or document scan layout).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BlurRegion {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+}
+
+impl BlurRegion {
+    /// Build a region from pixel coordinates within an image of the given size.
+    #[must_use]
+    pub fn from_pixels(
+        (x, y, width, height): (u32, u32, u32, u32),
+        image_width: u32,
+        image_height: u32,
+    ) -> Option<Self> {
+        if width == 0 || height == 0 || image_width == 0 || image_height == 0 {
+            return None;
+        }
+        let (iw, ih) = (image_width as f32, image_height as f32);
+        Some(Self {
+            x: (x as f32 / iw).clamp(0.0, 1.0),
+            y: (y as f32 / ih).clamp(0.0, 1.0),
+            width: (width as f32 / iw).clamp(0.0, 1.0),
+            height: (height as f32 / ih).clamp(0.0, 1.0),
+        })
+    }
+
+    /// Pixel rectangle (x, y, width, height) in an image of the given size.
+    ///
+    /// Clamped to the image bounds; None if nothing remains.
+    #[must_use]
+    pub fn to_pixels(&self, image_width: u32, image_height: u32) -> Option<(u32, u32, u32, u32)> {
+        let scale = |v: f32, size: u32| ((v * size as f32).round() as u32).min(size);
+        let x = scale(self.x, image_width);
+        let y = scale(self.y, image_height);
+        let right = scale(self.x + self.width, image_width);
+        let bottom = scale(self.y + self.height, image_height);
+
+        (right > x && bottom > y).then(|| (x, y, right - x, bottom - y))
+    }
+
+    /// Short description for the region list (percent of the image).
+    #[must_use]
+    pub fn display(&self) -> String {
+        format!(
+            "{:.0}%, {:.0}% · {:.0} × {:.0}%",
+            self.x * 100.0,
+            self.y * 100.0,
+            self.width * 100.0,
+            self.height * 100.0
+        )
+    }
+}
+
+/// Blur the given regions of an image in place.
+///
+/// Each region is downscaled and scaled back up, which removes detail
+/// irreversibly and is much faster than a large gaussian kernel. Blending is
+/// premultiplied, so transparent areas do not darken the blurred edges.
+pub fn blur_regions(img: &mut DynamicImage, regions: &[BlurRegion]) {
+    let (width, height) = (img.width(), img.height());
+
+    for region in regions {
+        let Some((x, y, w, h)) = region.to_pixels(width, height) else {
+            continue;
+        };
+
+        let small = alpha::resize_exact(
+            &img.crop_imm(x, y, w, h),
+            (w / BLUR_FACTOR).max(1),
+            (h / BLUR_FACTOR).max(1),
+            FilterType::Triangle,
+        );
+        let blurred = alpha::resize_exact(&small, w, h, FilterType::Triangle);
+        image::imageops::replace(img, &blurred, i64::from(x), i64::from(y));
+    }
+}
+
+/// Export a copy of `source` with the regions blurred into `output_dir`.
+///
+/// The copy keeps the file name (PNG is used for formats that cannot be
+/// written) and carries no EXIF metadata. Returns the written path.
+pub fn export_redacted(
+    source: &Path,
+    regions: &[BlurRegion],
+    output_dir: &Path,
+) -> DocResult<PathBuf> {
+    let format = ExportFormat::from_path(source)
+        .filter(|f| {
+            matches!(
+                f,
+                ExportFormat::Png | ExportFormat::Jpeg | ExportFormat::WebP
+            )
+        })
+        .unwrap_or(ExportFormat::Png);
+
+    let file_name = source
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("Invalid file name: {}", source.display()))?;
+    let mut target = output_dir.join(file_name);
+    if ExportFormat::from_path(&target) != Some(format) {
+        target.set_extension(format.extension());
+    }
+    if target == source {
+        return Err(anyhow::anyhow!("Refusing to overwrite the original image"));
+    }
+
+    let mut img = image::open(source)?;
+    blur_regions(&mut img, regions);
+
+    let options = ImageExportOptions {
+        preserve_metadata: false,
+        ..ImageExportOptions::default()
+    };
+    export::export_image(&img, &target, format, &options)?;
+    Ok(target)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    fn test_region_pixel_roundtrip() {
+        let region = BlurRegion::from_pixels((100, 50, 200, 100), 1000, 500).unwrap();
+        assert_eq!(region.to_pixels(1000, 500), Some((100, 50, 200, 100)));
+        // Same relative area on a smaller image
+        assert_eq!(region.to_pixels(500, 250), Some((50, 25, 100, 50)));
+        assert!(BlurRegion::from_pixels((0, 0, 0, 10), 100, 100).is_none());
+    }
+
+    #[test]
+    fn test_region_clamped_to_image() {
+        let region = BlurRegion {
+            x: 0.9,
+            y: 0.9,
+            width: 0.5,
+            height: 0.5,
+        };
+        assert_eq!(region.to_pixels(100, 100), Some((90, 90, 10, 10)));
+    }
+
+    #[test]
+    fn test_blur_only_touches_region() {
+        // Checkerboard so blurring is visible
+        let checker = RgbaImage::from_fn(64, 64, |x, y| {
+            if (x + y) % 2 == 0 {
+                Rgba([255, 255, 255, 255])
+            } else {
+                Rgba([0, 0, 0, 255])
+            }
+        });
+        let mut img = DynamicImage::ImageRgba8(checker.clone());
+        let region = BlurRegion::from_pixels((0, 0, 32, 32), 64, 64).unwrap();
+        blur_regions(&mut img, &[region]);
+
+        let out = img.to_rgba8();
+        // Outside the region: unchanged
+        assert_eq!(out.get_pixel(40, 40), checker.get_pixel(40, 40));
+        // Inside: the pattern is averaged out
+        let inside = out.get_pixel(10, 10)[0];
+        assert!((64..=192).contains(&inside), "pixel value {inside}");
+    }
+}
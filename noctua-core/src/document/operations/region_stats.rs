@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/region_stats.rs
+//
+// Pixel statistics and luminance histogram of an image region.
+
+use image::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+
+use super::CropRegion;
+
+/// Statistics of the pixels in a region of an image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RegionStats {
+    /// Number of pixels counted.
+    pub pixels: u64,
+    /// Mean of the red, green and blue channels (0-255).
+    pub mean: [f64; 3],
+    /// Mean luminance (Rec. 709 weights, 0-255).
+    pub luma_mean: f64,
+    /// Standard deviation of the luminance.
+    pub luma_std_dev: f64,
+    /// Darkest and brightest luminance.
+    pub luma_range: (u8, u8),
+    /// Number of pixels of each luminance value.
+    pub histogram: Vec<u64>,
+}
+
+impl RegionStats {
+    /// Histogram as gray bars on a transparent `width` x `height` image,
+    /// scaled to the most frequent value.
+    #[must_use]
+    pub fn histogram_image(&self, width: u32, height: u32) -> RgbaImage {
+        let mut image = RgbaImage::new(width, height);
+        let peak = self.histogram.iter().copied().max().unwrap_or(0);
+        if peak == 0 || width == 0 {
+            return image;
+        }
+        let bins = self.histogram.len() as u64;
+        for x in 0..width {
+            // Each column shows the busiest of the values it covers
+            let first = u64::from(x) * bins / u64::from(width);
+            let last = (u64::from(x + 1) * bins / u64::from(width)).max(first + 1);
+            let count = (first..last)
+                .map(|bin| self.histogram[bin as usize])
+                .max()
+                .unwrap_or(0);
+            #[allow(clippy::cast_possible_truncation)]
+            let bar = (count * u64::from(height)).div_ceil(peak) as u32;
+            for y in height - bar..height {
+                image.put_pixel(x, y, Rgba([160, 160, 160, 255]));
+            }
+        }
+        image
+    }
+}
+
+/// Compute the statistics of `region` of `image`.
+///
+/// The region is clipped to the image; transparent pixels count like opaque
+/// ones. Returns None if nothing of it lies inside the image.
+#[must_use]
+pub fn region_stats(image: &DynamicImage, region: CropRegion) -> Option<RegionStats> {
+    let (width, height) = image.dimensions();
+    let right = region.x.saturating_add(region.width).min(width);
+    let bottom = region.y.saturating_add(region.height).min(height);
+    if region.x >= right || region.y >= bottom {
+        return None;
+    }
+
+    let view = image.view(region.x, region.y, right - region.x, bottom - region.y);
+    let mut sums = [0.0; 3];
+    let mut luma_sum = 0.0;
+    let mut luma_squares = 0.0;
+    let mut histogram = vec![0; 256];
+    let mut range = (u8::MAX, u8::MIN);
+    for (_, _, Rgba([r, g, b, _])) in view.pixels() {
+        let luma = 0.2126 * f64::from(r) + 0.7152 * f64::from(g) + 0.0722 * f64::from(b);
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let level = luma.round() as u8;
+        histogram[usize::from(level)] += 1;
+        range = (range.0.min(level), range.1.max(level));
+        for (sum, value) in sums.iter_mut().zip([r, g, b]) {
+            *sum += f64::from(value);
+        }
+        luma_sum += luma;
+        luma_squares += luma * luma;
+    }
+
+    let pixels = u64::from(right - region.x) * u64::from(bottom - region.y);
+    #[allow(clippy::cast_precision_loss)]
+    let count = pixels as f64;
+    let luma_mean = luma_sum / count;
+    Some(RegionStats {
+        pixels,
+        mean: sums.map(|sum| sum / count),
+        luma_mean,
+        luma_std_dev: (luma_squares / count - luma_mean * luma_mean)
+            .max(0.0)
+            .sqrt(),
+        luma_range: range,
+        histogram,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 4 x 2 image: a black and a white column on the left, red on the right.
+    fn image() -> DynamicImage {
+        DynamicImage::ImageRgba8(RgbaImage::from_fn(4, 2, |x, _| match x {
+            0 => Rgba([0, 0, 0, 255]),
+            1 => Rgba([255, 255, 255, 255]),
+            _ => Rgba([255, 0, 0, 255]),
+        }))
+    }
+
+    #[test]
+    fn test_region_stats() {
+        let stats = region_stats(&image(), CropRegion::new(0, 0, 2, 2)).unwrap();
+        assert_eq!(stats.pixels, 4);
+        assert_eq!(stats.mean, [127.5, 127.5, 127.5]);
+        assert!((stats.luma_mean - 127.5).abs() < 1e-9);
+        assert!((stats.luma_std_dev - 127.5).abs() < 1e-9);
+        assert_eq!(stats.luma_range, (0, 255));
+        assert_eq!(stats.histogram[0], 2);
+        assert_eq!(stats.histogram[255], 2);
+
+        // Clipped to the image
+        let red = region_stats(&image(), CropRegion::new(2, 1, 10, 10)).unwrap();
+        assert_eq!(red.pixels, 2);
+        assert_eq!(red.mean, [255.0, 0.0, 0.0]);
+        assert_eq!(red.luma_range, (54, 54));
+        assert!(region_stats(&image(), CropRegion::new(4, 0, 2, 2)).is_none());
+    }
+
+    #[test]
+    fn test_histogram_image() {
+        let stats = region_stats(&image(), CropRegion::new(0, 0, 4, 2)).unwrap();
+        let histogram = stats.histogram_image(256, 10);
+        let bar = |x| {
+            (0..10)
+                .filter(|&y| histogram.get_pixel(x, y)[3] > 0)
+                .count()
+        };
+        // Red (luma 54) covers half the pixels, black and white a quarter
+        assert_eq!(bar(54), 10);
+        assert_eq!(bar(0), 5);
+        assert_eq!(bar(255), 5);
+        assert_eq!(bar(128), 0);
+    }
+}
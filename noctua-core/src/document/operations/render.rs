@@ -1,11 +1,12 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/operations/render.rs
+// noctua-core/src/document/operations/render.rs
 //
 // Rendering operations for documents.
 
-use cosmic::widget::image::Handle as ImageHandle;
 use image::{DynamicImage, GenericImageView};
 
+use crate::document::core::document::ImageHandle;
+
 /// Create an image handle from RGBA pixel data.
 ///
 /// This is the primary way to create image handles for display in the UI.
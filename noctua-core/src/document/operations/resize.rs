@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/resize.rs
+//
+// Resampling images to a new pixel size.
+
+use image::DynamicImage;
+use image::imageops::FilterType;
+
+/// Largest width or height a resize may produce (the JPEG limit).
+pub const MAX_SIDE: u32 = 65_535;
+
+/// Millimeters per inch, for paper sizes at a print resolution.
+const MM_PER_INCH: f64 = 25.4;
+
+/// How new pixels are computed from the old ones.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ResampleFilter {
+    /// Nearest pixel: hard edges, for pixel art and screenshots.
+    Nearest,
+    /// Linear blend of the neighbouring pixels: fast and smooth.
+    Bilinear,
+    /// Lanczos with three lobes: sharpest, for photos.
+    #[default]
+    Lanczos3,
+}
+
+impl ResampleFilter {
+    /// All filters, in the order they are offered.
+    pub const ALL: [Self; 3] = [Self::Nearest, Self::Bilinear, Self::Lanczos3];
+
+    /// Name used in edit sidecars.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Nearest => "nearest",
+            Self::Bilinear => "bilinear",
+            Self::Lanczos3 => "lanczos3",
+        }
+    }
+
+    /// Filter from its sidecar name.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|filter| filter.as_str() == name)
+    }
+
+    fn filter_type(self) -> FilterType {
+        match self {
+            Self::Nearest => FilterType::Nearest,
+            Self::Bilinear => FilterType::Triangle,
+            Self::Lanczos3 => FilterType::Lanczos3,
+        }
+    }
+}
+
+/// Resampling to a new pixel size.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Resize {
+    pub width: u32,
+    pub height: u32,
+    pub filter: ResampleFilter,
+}
+
+impl Resize {
+    /// Resize to `width` x `height`, `None` if a side is 0 or above `MAX_SIDE`.
+    #[must_use]
+    pub fn new(width: u32, height: u32, filter: ResampleFilter) -> Option<Self> {
+        let valid = |side: u32| (1..=MAX_SIDE).contains(&side);
+        (valid(width) && valid(height)).then_some(Self {
+            width,
+            height,
+            filter,
+        })
+    }
+
+    /// Resampled copy of `image`, the same image if the size does not change.
+    #[must_use]
+    pub fn apply(self, image: &DynamicImage) -> DynamicImage {
+        if (image.width(), image.height()) == (self.width, self.height) {
+            return image.clone();
+        }
+        image.resize_exact(self.width, self.height, self.filter.filter_type())
+    }
+}
+
+/// Side matching `side` in the proportions of `from` to `to`, at least 1.
+///
+/// E.g. the height for a new width: `proportional(height, width, new_width)`.
+#[must_use]
+pub fn proportional(side: u32, from: u32, to: u32) -> u32 {
+    if from == 0 {
+        return side;
+    }
+    let scaled = (u64::from(side) * u64::from(to) + u64::from(from) / 2) / u64::from(from);
+    u32::try_from(scaled).unwrap_or(u32::MAX).max(1)
+}
+
+/// Size of a `width` x `height` image scaled by `percent`.
+#[must_use]
+pub fn percent_size(width: u32, height: u32, percent: f64) -> (u32, u32) {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let scale = |side: u32| {
+        (f64::from(side) * percent / 100.0)
+            .round()
+            .clamp(1.0, f64::from(u32::MAX)) as u32
+    };
+    (scale(width), scale(height))
+}
+
+/// Pixels covering `width_mm` x `height_mm` at `dpi` dots per inch.
+#[must_use]
+pub fn paper_size(width_mm: u32, height_mm: u32, dpi: f64) -> (u32, u32) {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let pixels = |mm: u32| {
+        (f64::from(mm) / MM_PER_INCH * dpi)
+            .round()
+            .clamp(1.0, f64::from(u32::MAX)) as u32
+    };
+    (pixels(width_mm), pixels(height_mm))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resize() {
+        assert_eq!(Resize::new(0, 10, ResampleFilter::Nearest), None);
+        assert_eq!(Resize::new(10, MAX_SIDE + 1, ResampleFilter::Nearest), None);
+
+        let image = DynamicImage::new_rgb8(40, 20);
+        for filter in ResampleFilter::ALL {
+            let resized = Resize::new(10, 30, filter).unwrap().apply(&image);
+            assert_eq!((resized.width(), resized.height()), (10, 30));
+            assert_eq!(ResampleFilter::parse(filter.as_str()), Some(filter));
+        }
+        assert_eq!(ResampleFilter::parse("cubic"), None);
+    }
+
+    #[test]
+    fn test_sizes() {
+        assert_eq!(proportional(1080, 1920, 1280), 720);
+        assert_eq!(proportional(1, 1000, 10), 1);
+        assert_eq!(percent_size(1920, 1080, 50.0), (960, 540));
+        assert_eq!(percent_size(3, 3, 10.0), (1, 1));
+        // A4 at 300 DPI
+        assert_eq!(paper_size(210, 297, 300.0), (2480, 3508));
+    }
+}
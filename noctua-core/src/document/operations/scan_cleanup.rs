@@ -0,0 +1,291 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/scan_cleanup.rs
+//
+// Scan cleanup: removing dark specks and whitening the paper background.
+
+use std::ops::RangeInclusive;
+
+use image::{DynamicImage, Rgb, RgbImage, RgbaImage};
+
+/// Number of blocks the paper color is estimated in along the larger side.
+const PAPER_BLOCKS: u32 = 24;
+
+/// Smallest side of a paper block, so a text stroke never fills one.
+const MIN_BLOCK: u32 = 16;
+
+/// Part of a block's pixels darker than its paper color, in percent: text,
+/// lines and specks cover less than this.
+const PAPER_PERCENTILE: u32 = 90;
+
+/// Brightness of the paper color (in percent) that becomes pure white, so
+/// the grain of the paper fades as well.
+const WHITE_POINT: u32 = 92;
+
+/// Darkest level still taken for paper; darker areas (photos, black
+/// borders) are brightened no further than this allows.
+const MIN_PAPER: u32 = 96;
+
+/// Cleanup of scanned documents.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanCleanup {
+    /// Largest dark speck removed, in pixels (0 keeps them all).
+    pub despeckle: u8,
+    /// How far the paper is brought to white, in percent (0 leaves it).
+    pub whiten: u8,
+}
+
+impl Default for ScanCleanup {
+    fn default() -> Self {
+        Self {
+            despeckle: 6,
+            whiten: 100,
+        }
+    }
+}
+
+impl ScanCleanup {
+    /// Values the speck size can take.
+    pub const DESPECKLE_RANGE: RangeInclusive<u8> = 0..=50;
+    /// Values the whitening strength can take.
+    pub const WHITEN_RANGE: RangeInclusive<u8> = 0..=100;
+
+    /// Check if the cleanup leaves images as they are.
+    #[must_use]
+    pub fn is_none(&self) -> bool {
+        self.despeckle == 0 && self.whiten == 0
+    }
+
+    /// Cleaned copy of `image`, with 8 bits per channel.
+    ///
+    /// The paper color is estimated around each pixel, so shadows and
+    /// yellowed paper are evened out while text strokes keep their contrast
+    /// to it. Specks are groups of connected pixels darker than half the
+    /// paper; they take the paper color. Transparency is kept.
+    #[must_use]
+    pub fn apply(&self, image: &DynamicImage) -> DynamicImage {
+        if self.is_none() {
+            return image.clone();
+        }
+        let source = image.to_rgba8();
+        let paper = paper_colors(&source);
+        let mut cleaned = source.clone();
+        if self.whiten > 0 {
+            for (pixel, paper) in cleaned.pixels_mut().zip(paper.pixels()) {
+                for channel in 0..3 {
+                    pixel[channel] = self.whitened(pixel[channel], paper[channel]);
+                }
+            }
+        }
+        if self.despeckle > 0 {
+            self.remove_specks(&source, &paper, &mut cleaned);
+        }
+
+        let color = image.color();
+        let cleaned = DynamicImage::ImageRgba8(cleaned);
+        match (color.has_alpha(), color.has_color()) {
+            (true, _) => cleaned,
+            (false, true) => DynamicImage::ImageRgb8(cleaned.to_rgb8()),
+            (false, false) => DynamicImage::ImageLuma8(cleaned.to_luma8()),
+        }
+    }
+
+    /// Channel `value` divided by the paper color around it, blended in by
+    /// the whitening strength.
+    fn whitened(&self, value: u8, paper: u8) -> u8 {
+        let value = u32::from(value);
+        let white = (u32::from(paper) * WHITE_POINT / 100).max(MIN_PAPER);
+        let normalized = (value * 255 / white).min(255);
+        let strength = u32::from(self.whiten);
+        ((value * (100 - strength) + normalized * strength + 50) / 100) as u8
+    }
+
+    /// Give the specks of `source` the (whitened) paper color in `cleaned`.
+    ///
+    /// Dark pixels are grouped with their eight neighbours; groups of up to
+    /// `despeckle` pixels are specks, larger ones are text or lines.
+    fn remove_specks(&self, source: &RgbaImage, paper: &RgbImage, cleaned: &mut RgbaImage) {
+        let (w, h) = (source.width() as usize, source.height() as usize);
+        let dark: Vec<bool> = source
+            .pixels()
+            .zip(paper.pixels())
+            .map(|(pixel, paper)| {
+                luma(pixel[0], pixel[1], pixel[2]) * 2 < luma(paper[0], paper[1], paper[2])
+            })
+            .collect();
+
+        let max_size = usize::from(self.despeckle);
+        let mut seen = vec![false; w * h];
+        let mut stack = Vec::new();
+        let mut speck = Vec::new();
+        for start in 0..w * h {
+            if !dark[start] || seen[start] {
+                continue;
+            }
+            speck.clear();
+            seen[start] = true;
+            stack.push(start);
+            while let Some(index) = stack.pop() {
+                speck.push(index);
+                let (x, y) = (index % w, index / w);
+                for ny in y.saturating_sub(1)..=(y + 1).min(h - 1) {
+                    for nx in x.saturating_sub(1)..=(x + 1).min(w - 1) {
+                        let next = ny * w + nx;
+                        if dark[next] && !seen[next] {
+                            seen[next] = true;
+                            stack.push(next);
+                        }
+                    }
+                }
+            }
+            if speck.len() > max_size {
+                continue;
+            }
+            for &index in &speck {
+                let (x, y) = ((index % w) as u32, (index / w) as u32);
+                let paper = paper.get_pixel(x, y);
+                let pixel = cleaned.get_pixel_mut(x, y);
+                for channel in 0..3 {
+                    pixel[channel] = self.whitened(paper[channel], paper[channel]);
+                }
+            }
+        }
+    }
+}
+
+/// Paper color around each pixel: a bright percentile of each channel in a
+/// grid of blocks, blended between the block centers.
+fn paper_colors(image: &RgbaImage) -> RgbImage {
+    let (width, height) = image.dimensions();
+    let block = (width.max(height) / PAPER_BLOCKS).max(MIN_BLOCK);
+    let (columns, rows) = (width.div_ceil(block), height.div_ceil(block));
+
+    let mut grid = Vec::with_capacity((columns * rows) as usize);
+    for row in 0..rows {
+        for column in 0..columns {
+            let (left, top) = (column * block, row * block);
+            let (right, bottom) = ((left + block).min(width), (top + block).min(height));
+            let mut histograms = [[0u32; 256]; 3];
+            for y in top..bottom {
+                for x in left..right {
+                    let pixel = image.get_pixel(x, y);
+                    for (histogram, &value) in histograms.iter_mut().zip(&pixel.0) {
+                        histogram[usize::from(value)] += 1;
+                    }
+                }
+            }
+            let rank = (right - left) * (bottom - top) * PAPER_PERCENTILE / 100;
+            grid.push(histograms.map(|histogram| percentile(&histogram, rank)));
+        }
+    }
+
+    RgbImage::from_fn(width, height, |x, y| {
+        // Position in the grid, between the centers of the blocks
+        let position = |coordinate: u32, blocks: u32| {
+            ((coordinate as f32 + 0.5) / block as f32 - 0.5).clamp(0.0, (blocks - 1) as f32)
+        };
+        let (gx, gy) = (position(x, columns), position(y, rows));
+        let (x0, y0) = (gx as u32, gy as u32);
+        let (x1, y1) = ((x0 + 1).min(columns - 1), (y0 + 1).min(rows - 1));
+        let (fx, fy) = (gx - x0 as f32, gy - y0 as f32);
+        let at = |column: u32, row: u32, channel: usize| {
+            f32::from(grid[(row * columns + column) as usize][channel])
+        };
+        Rgb(std::array::from_fn(|channel| {
+            let top = at(x0, y0, channel) * (1.0 - fx) + at(x1, y0, channel) * fx;
+            let bottom = at(x0, y1, channel) * (1.0 - fx) + at(x1, y1, channel) * fx;
+            (top * (1.0 - fy) + bottom * fy).round() as u8
+        }))
+    })
+}
+
+/// Value with `rank` counted values below it.
+fn percentile(histogram: &[u32; 256], rank: u32) -> u8 {
+    let mut below = 0;
+    for (value, &count) in histogram.iter().enumerate() {
+        below += count;
+        if below > rank {
+            return value as u8;
+        }
+    }
+    u8::MAX
+}
+
+/// Perceived brightness (0-255) of a color.
+fn luma(red: u8, green: u8, blue: u8) -> u32 {
+    (u32::from(red) * 77 + u32::from(green) * 150 + u32::from(blue) * 29) >> 8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GrayImage, Luma};
+
+    /// Gray paper getting darker from left to right, with a text line every
+    /// 16 rows.
+    fn shaded_page() -> GrayImage {
+        GrayImage::from_fn(128, 128, |x, y| {
+            let paper = 220 - x as u8 / 2;
+            Luma([if y % 16 == 8 { paper / 4 } else { paper }])
+        })
+    }
+
+    #[test]
+    fn test_whitening_evens_out_the_paper() {
+        let cleanup = ScanCleanup {
+            despeckle: 0,
+            whiten: 100,
+        };
+        let cleaned = cleanup.apply(&DynamicImage::ImageLuma8(shaded_page()));
+        assert_eq!(cleaned.color(), image::ColorType::L8);
+        let cleaned = cleaned.to_luma8();
+        assert!(cleaned.get_pixel(4, 3).0[0] >= 250);
+        assert!(cleaned.get_pixel(124, 3).0[0] >= 250);
+        // Text keeps its contrast to the paper
+        assert!(cleaned.get_pixel(4, 8).0[0] < 80);
+        assert!(cleaned.get_pixel(124, 8).0[0] < 80);
+
+        let half = ScanCleanup {
+            despeckle: 0,
+            whiten: 50,
+        };
+        let value = half
+            .apply(&DynamicImage::ImageLuma8(shaded_page()))
+            .to_luma8();
+        assert!((180..230).contains(&value.get_pixel(124, 3).0[0]));
+    }
+
+    #[test]
+    fn test_despeckle_keeps_strokes() {
+        let mut page = GrayImage::from_pixel(64, 64, Luma([240]));
+        for (x, y) in [(10, 10), (11, 10), (10, 11)] {
+            page.put_pixel(x, y, Luma([20]));
+        }
+        for x in 20..40 {
+            page.put_pixel(x, 30, Luma([20]));
+        }
+        let cleanup = ScanCleanup {
+            despeckle: 4,
+            whiten: 0,
+        };
+        let cleaned = cleanup.apply(&DynamicImage::ImageLuma8(page)).to_luma8();
+        assert_eq!(cleaned.get_pixel(10, 10).0, [240]);
+        assert_eq!(cleaned.get_pixel(11, 10).0, [240]);
+        assert_eq!(cleaned.get_pixel(20, 30).0, [20]);
+        assert_eq!(cleaned.get_pixel(39, 30).0, [20]);
+    }
+
+    #[test]
+    fn test_no_cleanup_keeps_image() {
+        let image = DynamicImage::ImageRgba8(RgbaImage::new(3, 2));
+        let cleanup = ScanCleanup {
+            despeckle: 0,
+            whiten: 0,
+        };
+        assert!(cleanup.is_none());
+        assert_eq!(cleanup.apply(&image), image);
+        assert_eq!(
+            ScanCleanup::default().apply(&image).color(),
+            image::ColorType::Rgba8
+        );
+    }
+}
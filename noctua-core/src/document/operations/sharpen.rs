@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/sharpen.rs
+//
+// Mildly sharpened display copies of images shown smaller than their pixels.
+
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, RgbaImage};
+
+/// Copies are made at this multiple of the fitted size: enough for HiDPI
+/// screens, and on others the GPU halves them without losing detail.
+pub const OVERSAMPLE: f32 = 2.0;
+
+/// Radius (standard deviation) of the blur the sharpening takes away.
+const SIGMA: f32 = 0.8;
+
+/// Strength of the sharpening; kept low so edges get no halos.
+const AMOUNT: f32 = 0.5;
+
+/// Size of the display copy of a `width` x `height` image fitted into
+/// `bounds`. None if the image is not shown smaller than its pixels.
+#[must_use]
+pub fn display_size((width, height): (u32, u32), bounds: (f32, f32)) -> Option<(u32, u32)> {
+    let (bounds_width, bounds_height) = bounds;
+    if width == 0 || height == 0 || bounds_width <= 0.0 || bounds_height <= 0.0 {
+        return None;
+    }
+    let scale = (bounds_width / width as f32).min(bounds_height / height as f32) * OVERSAMPLE;
+    if scale >= 1.0 {
+        return None;
+    }
+    let side = |length: u32| ((length as f32 * scale).round() as u32).max(1);
+    Some((side(width), side(height)))
+}
+
+/// `image` scaled down to `width` x `height` and mildly sharpened (unsharp
+/// mask of the color channels), which makes up for the softness of the
+/// minification. For display only; the alpha channel is left as it is.
+#[must_use]
+pub fn sharpen_for_display(image: &DynamicImage, width: u32, height: u32) -> RgbaImage {
+    let mut pixels = image
+        .resize_exact(width, height, FilterType::Triangle)
+        .to_rgba8();
+    let blurred = imageops::blur(&pixels, SIGMA);
+    for (pixel, soft) in pixels.pixels_mut().zip(blurred.pixels()) {
+        for channel in 0..3 {
+            let value = f32::from(pixel[channel]);
+            let detail = value - f32::from(soft[channel]);
+            pixel[channel] = (value + detail * AMOUNT).round().clamp(0.0, 255.0) as u8;
+        }
+    }
+    pixels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{Rgba, RgbaImage};
+
+    #[test]
+    fn test_display_size() {
+        // 4000 x 2000 fitted into 1000 x 1000: 1000 x 500, oversampled
+        assert_eq!(
+            display_size((4000, 2000), (1000.0, 1000.0)),
+            Some((2000, 1000))
+        );
+        // Shown at its own size or larger: nothing to sharpen
+        assert_eq!(display_size((1500, 1000), (1000.0, 1000.0)), None);
+        assert_eq!(display_size((400, 300), (1000.0, 1000.0)), None);
+        assert_eq!(display_size((4000, 2000), (0.0, 0.0)), None);
+    }
+
+    #[test]
+    fn test_sharpen_for_display() {
+        // Dark left half, light right half
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_fn(40, 8, |x, _| {
+            if x < 20 {
+                Rgba([64, 64, 64, 128])
+            } else {
+                Rgba([192, 192, 192, 128])
+            }
+        }));
+        let plain = image.resize_exact(10, 2, FilterType::Triangle).to_rgba8();
+        let sharpened = sharpen_for_display(&image, 10, 2);
+        assert_eq!(sharpened.dimensions(), (10, 2));
+
+        // The edge gains contrast, flat areas and alpha stay
+        assert!(sharpened.get_pixel(4, 0)[0] < plain.get_pixel(4, 0)[0]);
+        assert!(sharpened.get_pixel(5, 0)[0] > plain.get_pixel(5, 0)[0]);
+        assert_eq!(sharpened.get_pixel(0, 0)[0], plain.get_pixel(0, 0)[0]);
+        assert_eq!(sharpened.get_pixel(5, 0)[3], 128);
+    }
+}
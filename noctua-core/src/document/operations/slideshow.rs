@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/slideshow.rs
+//
+// Order in which a slideshow visits the documents of a folder.
+
+/// Folder positions in slideshow order.
+///
+/// The slideshow starts at the current document; the others follow in folder
+/// order or shuffled. Each document is shown once per round.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlideOrder {
+    order: Vec<usize>,
+    position: usize,
+}
+
+impl SlideOrder {
+    /// Folder order from `start`, continuing at the beginning after the last document.
+    #[must_use]
+    pub fn sequential(len: usize, start: usize) -> Self {
+        let start = start.min(len.saturating_sub(1));
+        Self {
+            order: (start..len).chain(0..start).collect(),
+            position: 0,
+        }
+    }
+
+    /// `start` first, then the other documents in an order derived from `seed`.
+    #[must_use]
+    pub fn shuffled(len: usize, start: usize, seed: u64) -> Self {
+        let mut slides = Self::sequential(len, start);
+        // Fisher-Yates on everything after the first slide, with xorshift64
+        let mut state = seed | 1;
+        for i in (2..slides.order.len()).rev() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let j = 1 + (state % i as u64) as usize;
+            slides.order.swap(i, j);
+        }
+        slides
+    }
+
+    /// Keep only the documents for which `keep` holds, in the same order.
+    ///
+    /// If the first slide is dropped, the next kept one starts the slideshow.
+    pub fn retain(&mut self, keep: impl Fn(usize) -> bool) {
+        self.order.retain(|&index| keep(index));
+        self.position = 0;
+    }
+
+    /// Folder position of the slide shown now, `None` if there are no slides.
+    #[must_use]
+    pub fn current(&self) -> Option<usize> {
+        self.order.get(self.position).copied()
+    }
+
+    /// Folder position of the next slide.
+    ///
+    /// After the last slide the round starts over if `looping`, otherwise
+    /// `None` ends the slideshow.
+    pub fn advance(&mut self, looping: bool) -> Option<usize> {
+        if self.order.len() < 2 {
+            return None;
+        }
+        self.position += 1;
+        if self.position == self.order.len() {
+            if !looping {
+                self.position -= 1;
+                return None;
+            }
+            self.position = 0;
+        }
+        Some(self.order[self.position])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sequential_wraps_or_ends() {
+        let mut slides = SlideOrder::sequential(3, 1);
+        assert_eq!(slides.advance(true), Some(2));
+        assert_eq!(slides.advance(true), Some(0));
+        assert_eq!(slides.advance(true), Some(1));
+
+        let mut slides = SlideOrder::sequential(3, 1);
+        assert_eq!(slides.advance(false), Some(2));
+        assert_eq!(slides.advance(false), Some(0));
+        assert_eq!(slides.advance(false), None);
+        assert_eq!(SlideOrder::sequential(1, 0).advance(true), None);
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut slides = SlideOrder::sequential(5, 1);
+        slides.retain(|index| index % 2 == 0);
+        assert_eq!(slides.current(), Some(2));
+        assert_eq!(slides.advance(true), Some(4));
+        assert_eq!(slides.advance(true), Some(0));
+        assert_eq!(slides.advance(true), Some(2));
+
+        slides.retain(|_| false);
+        assert_eq!(slides.current(), None);
+        assert_eq!(slides.advance(true), None);
+    }
+
+    #[test]
+    fn test_shuffled_shows_every_document_once() {
+        let mut slides = SlideOrder::shuffled(10, 4, 0x5eed);
+        let mut shown = vec![4];
+        while let Some(index) = slides.advance(false) {
+            shown.push(index);
+        }
+        assert_eq!(shown.len(), 10);
+        assert_eq!(shown[0], 4);
+        shown.sort_unstable();
+        assert_eq!(shown, (0..10).collect::<Vec<_>>());
+    }
+}
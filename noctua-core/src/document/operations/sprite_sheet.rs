@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/sprite_sheet.rs
+//
+// Sprite sheet slicing: grid overlay, cell export and JSON atlas.
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use image::{DynamicImage, Rgba, RgbaImage};
+
+use super::export::{self, ExportFormat, ImageExportOptions};
+use crate::document::core::document::DocResult;
+use crate::document::core::json::escape_json;
+
+/// Grid line color of the overlay (magenta stands out on most artwork).
+const GRID_COLOR: Rgba<u8> = Rgba([255, 0, 255, 255]);
+
+/// Image side per pixel of grid line width, so lines stay visible when zoomed out.
+const LINE_WIDTH_STEP: u32 = 512;
+
+/// How the sheet is divided into cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SliceGrid {
+    /// Fixed cell size in pixels; partial cells at the right and bottom are ignored.
+    CellSize { width: u32, height: u32 },
+    /// Number of columns and rows; the remainder of the division is ignored.
+    Count { columns: u32, rows: u32 },
+}
+
+/// One cell of the grid, numbered row by row from 0.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpriteCell {
+    pub index: usize,
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SliceGrid {
+    /// Parse `32x32` as a cell size, or as columns × rows if `by_count` is set.
+    #[must_use]
+    pub fn parse(input: &str, by_count: bool) -> Option<Self> {
+        let (a, b) = input.split_once(['x', 'X', '×'])?;
+        let (a, b) = (a.trim().parse().ok()?, b.trim().parse().ok()?);
+        if a == 0 || b == 0 {
+            return None;
+        }
+        Some(if by_count {
+            Self::Count {
+                columns: a,
+                rows: b,
+            }
+        } else {
+            Self::CellSize {
+                width: a,
+                height: b,
+            }
+        })
+    }
+
+    /// Cells of an image of the given size (empty if no cell fits).
+    #[must_use]
+    pub fn cells(&self, (width, height): (u32, u32)) -> Vec<SpriteCell> {
+        let (cell_width, cell_height, columns, rows) = match *self {
+            Self::CellSize {
+                width: w,
+                height: h,
+            } => (w, h, width / w, height / h),
+            Self::Count { columns, rows } => (width / columns, height / rows, columns, rows),
+        };
+        if cell_width == 0 || cell_height == 0 {
+            return Vec::new();
+        }
+
+        (0..rows)
+            .flat_map(|row| (0..columns).map(move |col| (row, col)))
+            .enumerate()
+            .map(|(index, (row, col))| SpriteCell {
+                index,
+                x: col * cell_width,
+                y: row * cell_height,
+                width: cell_width,
+                height: cell_height,
+            })
+            .collect()
+    }
+}
+
+impl SpriteCell {
+    /// Name of the cell for a sheet file: `<stem>_<index>` (three digits).
+    #[must_use]
+    pub fn name(&self, source: &Path) -> String {
+        let stem = source
+            .file_stem()
+            .map_or_else(|| "sprite".into(), |s| s.to_string_lossy());
+        format!("{stem}_{:03}", self.index)
+    }
+}
+
+/// Copy of the image with the cell borders drawn on top.
+#[must_use]
+pub fn draw_grid(img: &DynamicImage, grid: SliceGrid) -> RgbaImage {
+    let mut out = img.to_rgba8();
+    let (width, height) = out.dimensions();
+    let line = (width.max(height) / LINE_WIDTH_STEP).max(1);
+
+    for cell in grid.cells((width, height)) {
+        let (right, bottom) = (cell.x + cell.width, cell.y + cell.height);
+        for y in cell.y..bottom {
+            for x in cell.x..right {
+                let on_border = x < cell.x + line
+                    || y < cell.y + line
+                    || x + line >= right
+                    || y + line >= bottom;
+                if on_border {
+                    out.put_pixel(x, y, GRID_COLOR);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Write every cell as a numbered PNG into `output_dir`.
+///
+/// Returns the number of written files.
+pub fn export_cells(
+    img: &DynamicImage,
+    grid: SliceGrid,
+    source: &Path,
+    output_dir: &Path,
+) -> DocResult<usize> {
+    let cells = grid.cells((img.width(), img.height()));
+    let options = ImageExportOptions {
+        preserve_metadata: false,
+        ..ImageExportOptions::default()
+    };
+    for cell in &cells {
+        let target = output_dir.join(format!("{}.png", cell.name(source)));
+        let part = img.crop_imm(cell.x, cell.y, cell.width, cell.height);
+        export::export_image(&part, &target, ExportFormat::Png, &options)?;
+    }
+    Ok(cells.len())
+}
+
+/// Atlas description of the sheet in the common JSON array format
+/// (`frames` with `filename` and `frame` rectangles, plus `meta`).
+#[must_use]
+pub fn atlas_json(grid: SliceGrid, source: &Path, (width, height): (u32, u32)) -> String {
+    let image_name = source
+        .file_name()
+        .map(|name| name.to_string_lossy())
+        .unwrap_or_default();
+
+    let mut json = String::from("{\n  \"frames\": [");
+    for (i, cell) in grid.cells((width, height)).iter().enumerate() {
+        let separator = if i == 0 { "" } else { "," };
+        let _ = write!(
+            json,
+            "{separator}\n    {{\"filename\": \"{}\", \
+             \"frame\": {{\"x\": {}, \"y\": {}, \"w\": {}, \"h\": {}}}, \
+             \"rotated\": false, \"trimmed\": false, \
+             \"sourceSize\": {{\"w\": {}, \"h\": {}}}}}",
+            escape_json(&cell.name(source)),
+            cell.x,
+            cell.y,
+            cell.width,
+            cell.height,
+            cell.width,
+            cell.height,
+        );
+    }
+    let _ = write!(
+        json,
+        "\n  ],\n  \"meta\": {{\"image\": \"{}\", \"size\": {{\"w\": {width}, \"h\": {height}}}, \
+         \"scale\": \"1\"}}\n}}\n",
+        escape_json(&image_name),
+    );
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_by_size_and_count() {
+        let grid = SliceGrid::parse("32x16", false).unwrap();
+        let cells = grid.cells((100, 40));
+        // 3 columns (4 px left over) × 2 rows
+        assert_eq!(cells.len(), 6);
+        assert_eq!((cells[4].x, cells[4].y), (32, 16));
+
+        let grid = SliceGrid::parse("4 × 2", true).unwrap();
+        assert_eq!(
+            grid,
+            SliceGrid::Count {
+                columns: 4,
+                rows: 2
+            }
+        );
+        let cells = grid.cells((100, 40));
+        assert_eq!(cells.len(), 8);
+        assert_eq!((cells[7].x, cells[7].y, cells[7].width), (75, 20, 25));
+
+        assert!(SliceGrid::parse("0x8", false).is_none());
+        assert!(
+            SliceGrid::CellSize {
+                width: 64,
+                height: 64
+            }
+            .cells((32, 32))
+            .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_atlas_json() {
+        let grid = SliceGrid::Count {
+            columns: 2,
+            rows: 1,
+        };
+        let json = atlas_json(grid, Path::new("/games/hero \"run\".png"), (64, 32));
+        assert!(json.contains("\"filename\": \"hero \\\"run\\\"_001\""));
+        assert!(json.contains("\"frame\": {\"x\": 32, \"y\": 0, \"w\": 32, \"h\": 32}"));
+        assert!(json.contains("\"size\": {\"w\": 64, \"h\": 32}"));
+    }
+
+    #[test]
+    fn test_draw_grid_borders() {
+        let img = DynamicImage::new_rgb8(8, 8);
+        let out = draw_grid(
+            &img,
+            SliceGrid::CellSize {
+                width: 4,
+                height: 4,
+            },
+        );
+        assert_eq!(*out.get_pixel(0, 0), GRID_COLOR);
+        assert_eq!(*out.get_pixel(3, 1), GRID_COLOR);
+        assert_eq!(out.get_pixel(1, 1).0, [0, 0, 0, 255]);
+    }
+}
@@ -0,0 +1,463 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/straighten.rs
+//
+// Fine rotation by arbitrary angles with a choice of resampling filter, and
+// transparent, clamped or cropped corners.
+
+use image::{DynamicImage, Rgba, RgbaImage};
+
+/// Largest angle the straighten slider reaches either way, in degrees.
+pub const MAX_ANGLE: f32 = 45.0;
+
+/// How new pixels are computed when turning an image by a fine angle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RotateFilter {
+    /// Nearest pixel: fastest, with jagged edges.
+    Nearest,
+    /// Blend of the four nearest pixels: smooth and fast.
+    #[default]
+    Bilinear,
+    /// Cubic curve through the sixteen nearest pixels: sharpest.
+    Bicubic,
+}
+
+impl RotateFilter {
+    /// All filters, in the order they are offered.
+    pub const ALL: [Self; 3] = [Self::Nearest, Self::Bilinear, Self::Bicubic];
+
+    /// Name used in edit sidecars.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Nearest => "nearest",
+            Self::Bilinear => "bilinear",
+            Self::Bicubic => "bicubic",
+        }
+    }
+
+    /// Filter from its sidecar name.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|filter| filter.as_str() == name)
+    }
+
+    /// Source pixels along one axis and their weights for the sample at
+    /// `position` (in pixel coordinates, centers on whole numbers).
+    ///
+    /// Unused taps have a weight of 0.
+    fn taps(self, position: f64) -> [(i64, f64); 4] {
+        let base = position.floor();
+        let t = position - base;
+        #[allow(clippy::cast_possible_truncation)]
+        let base = base as i64;
+        match self {
+            Self::Nearest => {
+                let nearest = if t < 0.5 { base } else { base + 1 };
+                [(nearest, 1.0), (0, 0.0), (0, 0.0), (0, 0.0)]
+            }
+            Self::Bilinear => [(base, 1.0 - t), (base + 1, t), (0, 0.0), (0, 0.0)],
+            Self::Bicubic => [
+                (base - 1, cubic(1.0 + t)),
+                (base, cubic(t)),
+                (base + 1, cubic(1.0 - t)),
+                (base + 2, cubic(2.0 - t)),
+            ],
+        }
+    }
+}
+
+/// Catmull-Rom weight of a pixel `distance` away from the sample.
+fn cubic(distance: f64) -> f64 {
+    let x = distance.abs();
+    if x < 1.0 {
+        1.5 * x * x * x - 2.5 * x * x + 1.0
+    } else if x < 2.0 {
+        -0.5 * x * x * x + 2.5 * x * x - 4.0 * x + 2.0
+    } else {
+        0.0
+    }
+}
+
+/// What becomes of the corners a fine rotation leaves empty.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RotateEdges {
+    /// The canvas grows to hold the whole image; the corners are transparent.
+    Transparent,
+    /// The canvas grows; the corners repeat the nearest edge pixels.
+    Clamp,
+    /// Crop to the largest upright rectangle inside the rotated image.
+    #[default]
+    Crop,
+}
+
+impl RotateEdges {
+    /// All edge modes, in the order they are offered.
+    pub const ALL: [Self; 3] = [Self::Transparent, Self::Clamp, Self::Crop];
+
+    /// Name used in edit sidecars.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Transparent => "transparent",
+            Self::Clamp => "clamp",
+            Self::Crop => "crop",
+        }
+    }
+
+    /// Edge mode from its sidecar name.
+    #[must_use]
+    pub fn parse(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|edges| edges.as_str() == name)
+    }
+}
+
+/// A fine rotation: clockwise angle, resampling filter and what becomes of
+/// the empty corners.
+///
+/// The angle is kept in tenths of a degree, the slider step, so rotations
+/// compare exactly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FineRotation {
+    /// Clockwise angle in tenths of a degree.
+    pub decidegrees: i16,
+    /// Corners: transparent, clamped or cropped off.
+    pub edges: RotateEdges,
+    /// Resampling filter.
+    pub filter: RotateFilter,
+}
+
+impl FineRotation {
+    /// Rotation by `degrees` clockwise, rounded to a tenth and wrapped to
+    /// -180..=180.
+    #[must_use]
+    pub fn new(degrees: f32, edges: RotateEdges, filter: RotateFilter) -> Self {
+        let wrapped = (degrees + 180.0).rem_euclid(360.0) - 180.0;
+        #[allow(clippy::cast_possible_truncation)]
+        let decidegrees = (wrapped * 10.0).round() as i16;
+        Self {
+            decidegrees,
+            edges,
+            filter,
+        }
+    }
+
+    /// Clockwise angle in degrees.
+    #[must_use]
+    pub fn degrees(self) -> f32 {
+        f32::from(self.decidegrees) / 10.0
+    }
+
+    /// Check if the rotation leaves the image unchanged.
+    #[must_use]
+    pub fn is_none(self) -> bool {
+        self.decidegrees == 0
+    }
+
+    /// Rotated copy of `image`.
+    ///
+    /// Unless cropped, the canvas grows to hold the whole image.
+    #[must_use]
+    pub fn apply(self, image: &DynamicImage) -> DynamicImage {
+        if self.is_none() {
+            return image.clone();
+        }
+        let clamp = self.edges == RotateEdges::Clamp;
+        let rotated = rotate(image, self.degrees(), self.filter, clamp);
+        if self.edges != RotateEdges::Crop {
+            return DynamicImage::ImageRgba8(rotated);
+        }
+
+        let (width, height) = inscribed_size(image.width(), image.height(), self.degrees());
+        let x = (rotated.width() - width).div_ceil(2);
+        let y = (rotated.height() - height).div_ceil(2);
+        let cropped = DynamicImage::ImageRgba8(
+            image::imageops::crop_imm(&rotated, x, y, width, height).to_image(),
+        );
+        // Nothing transparent is left, so opaque images stay without alpha
+        if image.color().has_alpha() {
+            cropped
+        } else {
+            DynamicImage::ImageRgb8(cropped.to_rgb8())
+        }
+    }
+}
+
+/// `image` rotated clockwise by `degrees`, resampled with `filter`.
+///
+/// The canvas grows to hold the whole image. What the image does not cover
+/// stays transparent, or with `clamp` repeats the nearest edge pixels.
+#[must_use]
+pub fn rotate(image: &DynamicImage, degrees: f32, filter: RotateFilter, clamp: bool) -> RgbaImage {
+    let source = image.to_rgba8();
+    let (width, height) = source.dimensions();
+    let (sin, cos) = f64::from(degrees).to_radians().sin_cos();
+    let (w, h) = (f64::from(width), f64::from(height));
+    // Round away float noise so 90° turns do not gain a row
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let side = |length: f64| ((length - 1e-6).ceil().max(1.0)) as u32;
+    let out_width = side(w * cos.abs() + h * sin.abs());
+    let out_height = side(w * sin.abs() + h * cos.abs());
+
+    let sample = |x: i64, y: i64| -> [f64; 4] {
+        let (x, y) = if clamp {
+            (
+                x.clamp(0, i64::from(width) - 1),
+                y.clamp(0, i64::from(height) - 1),
+            )
+        } else if x < 0 || y < 0 || x >= i64::from(width) || y >= i64::from(height) {
+            return [0.0; 4];
+        } else {
+            (x, y)
+        };
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let Rgba([r, g, b, a]) = *source.get_pixel(x as u32, y as u32);
+        // Premultiplied, so transparent neighbours do not darken the edges
+        let alpha = f64::from(a) / 255.0;
+        [
+            f64::from(r) * alpha,
+            f64::from(g) * alpha,
+            f64::from(b) * alpha,
+            f64::from(a),
+        ]
+    };
+
+    RgbaImage::from_fn(out_width, out_height, |x, y| {
+        // Turn the pixel center back onto the source image
+        let dx = f64::from(x) + 0.5 - f64::from(out_width) / 2.0;
+        let dy = f64::from(y) + 0.5 - f64::from(out_height) / 2.0;
+        let sx = dx * cos + dy * sin + w / 2.0 - 0.5;
+        let sy = -dx * sin + dy * cos + h / 2.0 - 0.5;
+
+        let mut sum = [0.0; 4];
+        for (py, weight_y) in filter.taps(sy) {
+            if weight_y == 0.0 {
+                continue;
+            }
+            for (px, weight_x) in filter.taps(sx) {
+                if weight_x == 0.0 {
+                    continue;
+                }
+                for (total, value) in sum.iter_mut().zip(sample(px, py)) {
+                    *total += value * weight_x * weight_y;
+                }
+            }
+        }
+
+        // Bicubic overshoots around sharp edges
+        let [r, g, b, a] = sum.map(|value| value.clamp(0.0, 255.0));
+        if a <= 0.0 {
+            return Rgba([0, 0, 0, 0]);
+        }
+        let alpha = a / 255.0;
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let channel = |value: f64| value.round().clamp(0.0, 255.0) as u8;
+        Rgba([
+            channel(r / alpha),
+            channel(g / alpha),
+            channel(b / alpha),
+            channel(a),
+        ])
+    })
+}
+
+/// Size of the largest upright rectangle inside a `width` x `height` image
+/// rotated by `degrees`, centered on it.
+#[must_use]
+pub fn inscribed_size(width: u32, height: u32, degrees: f32) -> (u32, u32) {
+    let (w, h) = (f64::from(width), f64::from(height));
+    let (sin, cos) = f64::from(degrees).to_radians().sin_cos();
+    let (sin, cos) = (sin.abs(), cos.abs());
+    let (long, short) = if w >= h { (w, h) } else { (h, w) };
+
+    let (inner_w, inner_h) = if short <= 2.0 * sin * cos * long || (sin - cos).abs() < 1e-10 {
+        // Two corners of the rectangle touch the long sides only
+        let half = short / 2.0;
+        if w >= h {
+            (half / sin, half / cos)
+        } else {
+            (half / cos, half / sin)
+        }
+    } else {
+        // All four corners touch the sides
+        let cos_2a = cos * cos - sin * sin;
+        ((w * cos - h * sin) / cos_2a, (h * cos - w * sin) / cos_2a)
+    };
+
+    // A little off the edges, where the resampling blends in the transparent corners
+    let margin = if sin < 1e-6 { 0.0 } else { 1.0 };
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let side = |length: f64| (length - margin).floor().max(1.0) as u32;
+    (side(inner_w), side(inner_h))
+}
+
+/// Clockwise rotation that makes the line from `start` to `end` level or
+/// plumb, whichever is nearer (-45..=45 degrees).
+///
+/// Points are in image or canvas coordinates (y pointing down). None if the
+/// points coincide.
+#[must_use]
+pub fn line_angle(start: (f32, f32), end: (f32, f32)) -> Option<f32> {
+    let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+    if dx == 0.0 && dy == 0.0 {
+        return None;
+    }
+    let slope = dy.atan2(dx).to_degrees();
+    // The nearest multiple of 90° is where the line should point
+    let off = slope - (slope / 90.0).round() * 90.0;
+    Some(-off)
+}
+
+/// A rotate gesture split into quarter turns and a fine rotation.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GestureRotation {
+    /// Clockwise quarter turns (-2..=2).
+    pub quarter_turns: i32,
+    /// Clockwise fine rotation left over (-45..=45 degrees).
+    pub degrees: f32,
+    /// The gesture is within the tolerance of a quarter turn, so the fine
+    /// rotation snapped to 0.
+    pub snapped: bool,
+}
+
+impl GestureRotation {
+    /// Split a gesture that turned `degrees` clockwise.
+    ///
+    /// Within `tolerance` degrees of 0, 90, 180 or 270 the fine rotation
+    /// snaps to 0, so a gesture ends on an exact quarter turn.
+    #[must_use]
+    pub fn split(degrees: f32, tolerance: f32) -> Self {
+        let wrapped = (degrees + 180.0).rem_euclid(360.0) - 180.0;
+        let turns = (wrapped / 90.0).round();
+        let fine = wrapped - turns * 90.0;
+        let snapped = fine.abs() <= tolerance;
+        #[allow(clippy::cast_possible_truncation)]
+        Self {
+            quarter_turns: turns as i32,
+            degrees: if snapped { 0.0 } else { fine },
+            snapped,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, Rgb, RgbImage};
+
+    #[test]
+    fn test_fine_rotation() {
+        let rotation = FineRotation::new(2.34, RotateEdges::Crop, RotateFilter::Bilinear);
+        assert_eq!(rotation.decidegrees, 23);
+        assert!((rotation.degrees() - 2.3).abs() < 1e-6);
+        let turned = FineRotation::new(350.0, RotateEdges::Transparent, RotateFilter::Nearest);
+        assert_eq!(turned.decidegrees, -100);
+        assert!(FineRotation::new(0.01, RotateEdges::Crop, RotateFilter::Bicubic).is_none());
+        for filter in RotateFilter::ALL {
+            assert_eq!(RotateFilter::parse(filter.as_str()), Some(filter));
+        }
+        for edges in RotateEdges::ALL {
+            assert_eq!(RotateEdges::parse(edges.as_str()), Some(edges));
+        }
+        assert_eq!(RotateEdges::parse("wrap"), None);
+    }
+
+    #[test]
+    fn test_rotate() {
+        // A quarter turn moves the red top-left pixel to the top right
+        let image = DynamicImage::ImageRgb8(RgbImage::from_fn(4, 2, |x, y| {
+            if (x, y) == (0, 0) {
+                Rgb([255, 0, 0])
+            } else {
+                Rgb([0, 0, 255])
+            }
+        }));
+        for filter in RotateFilter::ALL {
+            let rotated = rotate(&image, 90.0, filter, false);
+            assert_eq!(rotated.dimensions(), (2, 4));
+            assert_eq!(rotated.get_pixel(1, 0).0, [255, 0, 0, 255], "{filter:?}");
+            assert_eq!(rotated.get_pixel(0, 0).0, [0, 0, 255, 255], "{filter:?}");
+        }
+
+        // A slight turn grows the canvas and leaves transparent corners
+        let plain = DynamicImage::ImageRgb8(RgbImage::from_pixel(100, 50, Rgb([10, 20, 30])));
+        let rotated = rotate(&plain, 10.0, RotateFilter::Bilinear, false);
+        assert_eq!(rotated.dimensions(), (108, 67));
+        assert_eq!(rotated.get_pixel(0, 0)[3], 0);
+        assert_eq!(rotated.get_pixel(54, 33)[3], 255);
+
+        // Clamped corners repeat the edge, and no filter overshoots a flat color
+        for filter in RotateFilter::ALL {
+            let rotated = rotate(&plain, 10.0, filter, true);
+            assert_eq!(rotated.get_pixel(0, 0).0, [10, 20, 30, 255], "{filter:?}");
+            assert_eq!(rotated.get_pixel(54, 33).0, [10, 20, 30, 255], "{filter:?}");
+        }
+    }
+
+    #[test]
+    fn test_inscribed_size() {
+        assert_eq!(inscribed_size(100, 50, 0.0), (100, 50));
+        let (width, height) = inscribed_size(100, 50, 10.0);
+        assert!(width < 100 && height < 50);
+        assert!(width > 80 && height > 30);
+        // Same shape either way round
+        assert_eq!(inscribed_size(100, 50, -10.0), (width, height));
+    }
+
+    #[test]
+    fn test_auto_crop_leaves_no_corners() {
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(120, 80, Rgb([200, 100, 50])));
+        for degrees in [1.5, -7.0, 30.0, 45.0] {
+            let rotation = FineRotation::new(degrees, RotateEdges::Crop, RotateFilter::Bicubic);
+            let cropped = rotation.apply(&image);
+            assert_eq!(cropped.color(), image::ColorType::Rgb8);
+            let (width, height) = cropped.dimensions();
+            assert!(width <= 120 && height <= 80);
+            let rotated = rotate(&image, rotation.degrees(), rotation.filter, false);
+            let x = (rotated.width() - width).div_ceil(2);
+            let y = (rotated.height() - height).div_ceil(2);
+            for (px, py) in [
+                (x, y),
+                (x + width - 1, y),
+                (x, y + height - 1),
+                (x + width - 1, y + height - 1),
+            ] {
+                assert_eq!(rotated.get_pixel(px, py)[3], 255, "{degrees}° at {px},{py}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_line_angle() {
+        // Sloping down to the right by 5°: turn 5° counter-clockwise
+        let (sin, cos) = 5f32.to_radians().sin_cos();
+        let end = (100.0 * cos, 100.0 * sin);
+        assert!((line_angle((0.0, 0.0), end).unwrap() + 5.0).abs() < 1e-3);
+        // Nearly plumb: turn to vertical
+        let angle = line_angle((0.0, 0.0), (-3.0, 100.0)).unwrap();
+        assert!((angle + 1.718).abs() < 1e-2, "{angle}");
+        assert_eq!(line_angle((1.0, 1.0), (1.0, 1.0)), None);
+    }
+
+    #[test]
+    fn test_gesture_rotation() {
+        let rotation = GestureRotation::split(12.5, 4.0);
+        assert_eq!(rotation.quarter_turns, 0);
+        assert!((rotation.degrees - 12.5).abs() < 1e-4);
+        assert!(!rotation.snapped);
+        // Close to a quarter turn either way snaps onto it
+        assert_eq!(
+            GestureRotation::split(87.0, 4.0),
+            GestureRotation {
+                quarter_turns: 1,
+                degrees: 0.0,
+                snapped: true,
+            }
+        );
+        assert_eq!(GestureRotation::split(-182.0, 4.0).quarter_turns, 2);
+        let rotation = GestureRotation::split(-100.0, 4.0);
+        assert_eq!(rotation.quarter_turns, -1);
+        assert!((rotation.degrees + 10.0).abs() < 1e-4);
+        // Full turns wrap around
+        assert_eq!(GestureRotation::split(361.0, 4.0).quarter_turns, 0);
+    }
+}
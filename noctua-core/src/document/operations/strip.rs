@@ -0,0 +1,162 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/strip.rs
+//
+// Continuous vertical strip (webtoon) layout of a folder of images.
+
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// One image of the strip with its pixel size.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StripPage {
+    pub path: PathBuf,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// Images of the folder that form the strip, in folder order.
+///
+/// Only the file headers are read; files without a readable raster size
+/// (vector graphics, documents, broken files) are left out.
+#[must_use]
+pub fn load_pages(entries: &[PathBuf]) -> Vec<StripPage> {
+    entries
+        .iter()
+        .filter_map(|path| {
+            let (width, height) = image::image_dimensions(path).ok()?;
+            (width > 0 && height > 0).then(|| StripPage {
+                path: path.clone(),
+                width,
+                height,
+            })
+        })
+        .collect()
+}
+
+/// Vertical positions of the pages for a given strip width.
+///
+/// Pages are scaled to the strip width but never enlarged, so narrow
+/// webtoon images keep their size on wide windows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StripLayout {
+    /// (top, displayed width, displayed height) per page.
+    pages: Vec<(f32, f32, f32)>,
+}
+
+impl StripLayout {
+    /// Lay out the pages one below the other.
+    #[must_use]
+    pub fn new(pages: &[StripPage], width: f32) -> Self {
+        let mut top = 0.0;
+        let pages = pages
+            .iter()
+            .map(|page| {
+                let shown_width = width.min(page.width as f32).max(1.0);
+                let shown_height = shown_width * page.height as f32 / page.width as f32;
+                let entry = (top, shown_width, shown_height);
+                top += shown_height;
+                entry
+            })
+            .collect();
+        Self { pages }
+    }
+
+    /// Displayed size of a page.
+    #[must_use]
+    pub fn size(&self, index: usize) -> (f32, f32) {
+        self.pages
+            .get(index)
+            .map_or((0.0, 0.0), |&(_, width, height)| (width, height))
+    }
+
+    /// Offset of the top of a page from the start of the strip.
+    #[must_use]
+    pub fn top(&self, index: usize) -> f32 {
+        self.pages.get(index).map_or(0.0, |&(top, _, _)| top)
+    }
+
+    /// Height of the whole strip.
+    #[must_use]
+    pub fn total_height(&self) -> f32 {
+        self.pages
+            .last()
+            .map_or(0.0, |&(top, _, height)| top + height)
+    }
+
+    /// Page shown at the given scroll offset.
+    #[must_use]
+    pub fn page_at(&self, offset: f32) -> usize {
+        self.pages
+            .iter()
+            .rposition(|&(top, _, _)| top <= offset)
+            .unwrap_or(0)
+    }
+
+    /// Pages to load for a scroll position: those in view plus the ones
+    /// within `preload` pixels above or below it.
+    #[must_use]
+    pub fn visible(&self, offset: f32, view_height: f32, preload: f32) -> Range<usize> {
+        let (start, end) = (offset - preload, offset + view_height + preload);
+        let first = self
+            .pages
+            .iter()
+            .position(|&(top, _, height)| top + height > start)
+            .unwrap_or(self.pages.len());
+        let last = self
+            .pages
+            .iter()
+            .rposition(|&(top, _, _)| top < end)
+            .map_or(first, |index| (index + 1).max(first));
+        first..last
+    }
+}
+
+/// Index of `current` among the strip pages (first page if not found).
+#[must_use]
+pub fn page_index(pages: &[StripPage], current: Option<&Path>) -> usize {
+    current
+        .and_then(|current| pages.iter().position(|page| page.path == current))
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(width: u32, height: u32) -> StripPage {
+        StripPage {
+            path: PathBuf::from(format!("/comic/{width}x{height}.png")),
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_layout_never_enlarges() {
+        let pages = [page(800, 2000), page(400, 1000), page(1600, 800)];
+        let layout = StripLayout::new(&pages, 800.0);
+        assert_eq!(layout.size(0), (800.0, 2000.0));
+        assert_eq!(layout.size(1), (400.0, 1000.0));
+        assert_eq!(layout.size(2), (800.0, 400.0));
+        assert_eq!(layout.top(2), 3000.0);
+        assert_eq!(layout.total_height(), 3400.0);
+        assert_eq!(layout.page_at(2999.0), 1);
+        assert_eq!(layout.page_at(3000.0), 2);
+    }
+
+    #[test]
+    fn test_visible_with_preload() {
+        let pages = [
+            page(100, 1000),
+            page(100, 1000),
+            page(100, 1000),
+            page(100, 1000),
+        ];
+        let layout = StripLayout::new(&pages, 100.0);
+        // Viewing 1200..1800 with one screen of preload: 600..2400
+        assert_eq!(layout.visible(1200.0, 600.0, 600.0), 0..3);
+        assert_eq!(layout.visible(1200.0, 600.0, 0.0), 1..2);
+        assert_eq!(layout.visible(3500.0, 600.0, 0.0), 3..4);
+        assert!(layout.visible(5000.0, 600.0, 0.0).is_empty());
+    }
+}
@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/svg_markup.rs
+//
+// SVG markup showing the source turned, flipped and cropped like the view.
+
+use crate::document::core::document::{Rotation, RotationMode, TransformState};
+use crate::document::operations::crop::CropRegion;
+
+/// SVG markup of `source` with `transform` and `crop` applied, keeping the
+/// vector data.
+///
+/// `size` is the native size the raster is rendered at, which `crop` is
+/// given in (after the transform, like the raster). The original `<svg>`
+/// element is nested in a wrapper that turns and flips it, and the crop
+/// becomes the wrapper's viewBox. Untouched documents come back as is.
+/// Fine rotation is left out, as in the raster.
+#[must_use]
+pub fn transformed_svg(
+    source: &str,
+    size: (u32, u32),
+    transform: TransformState,
+    crop: Option<CropRegion>,
+) -> String {
+    let (width, height) = size;
+    let rotation = match transform.rotation {
+        RotationMode::Standard(rotation) => rotation,
+        RotationMode::Fine(_) => Rotation::None,
+    };
+
+    // SVG applies the rightmost transform first: flips, then the turn
+    let mut transforms = Vec::new();
+    match rotation {
+        Rotation::None => {}
+        Rotation::Cw90 => transforms.push(format!("matrix(0 1 -1 0 {height} 0)")),
+        Rotation::Cw180 => transforms.push(format!("matrix(-1 0 0 -1 {width} {height})")),
+        Rotation::Cw270 => transforms.push(format!("matrix(0 -1 1 0 0 {width})")),
+    }
+    if transform.flip_v {
+        transforms.push(format!("matrix(1 0 0 -1 0 {height})"));
+    }
+    if transform.flip_h {
+        transforms.push(format!("matrix(-1 0 0 1 {width} 0)"));
+    }
+
+    if transforms.is_empty() && crop.is_none() {
+        return source.to_string();
+    }
+
+    let (out_width, out_height) = match rotation {
+        Rotation::None | Rotation::Cw180 => (width, height),
+        Rotation::Cw90 | Rotation::Cw270 => (height, width),
+    };
+    let view = crop.unwrap_or(CropRegion::new(0, 0, out_width, out_height));
+
+    // The DOCTYPE may declare entities the original element uses, so it
+    // stays in front of the wrapper
+    let (prolog, root) = split_prolog(source);
+    let mut markup = String::with_capacity(source.len() + 256);
+    markup.push_str(prolog);
+    markup.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"{} {} {} {}\">\n",
+        view.width, view.height, view.x, view.y, view.width, view.height
+    ));
+    if !transforms.is_empty() {
+        markup.push_str(&format!("<g transform=\"{}\">\n", transforms.join(" ")));
+    }
+    // Sized viewport, so relative sizes of the original resolve as before
+    markup.push_str(&format!("<svg width=\"{width}\" height=\"{height}\">\n"));
+    markup.push_str(root.trim_end());
+    markup.push_str("\n</svg>\n");
+    if !transforms.is_empty() {
+        markup.push_str("</g>\n");
+    }
+    markup.push_str("</svg>\n");
+    markup
+}
+
+/// Split the source before its root element, after the XML declaration,
+/// DOCTYPE and any comments.
+fn split_prolog(source: &str) -> (&str, &str) {
+    let mut rest = source;
+    loop {
+        let trimmed = rest.trim_start();
+        let end = if trimmed.starts_with("<?") {
+            trimmed.find("?>").map(|end| end + 2)
+        } else if trimmed.starts_with("<!--") {
+            trimmed.find("-->").map(|end| end + 3)
+        } else if trimmed.starts_with("<!") {
+            doctype_end(trimmed)
+        } else {
+            None
+        };
+        match end {
+            Some(end) => rest = &trimmed[end..],
+            None => break,
+        }
+    }
+    let root = rest.trim_start();
+    source.split_at(source.len() - root.len())
+}
+
+/// Length of the DOCTYPE at the start of `source`, past its internal subset.
+fn doctype_end(source: &str) -> Option<usize> {
+    let mut in_subset = false;
+    for (index, c) in source.char_indices() {
+        match c {
+            '[' => in_subset = true,
+            ']' => in_subset = false,
+            '>' if !in_subset => return Some(index + 1),
+            _ => {}
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "<?xml version=\"1.0\"?>\n<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"40\" height=\"20\"><rect width=\"10\" height=\"10\"/></svg>\n";
+
+    #[test]
+    fn test_untouched_svg_is_unchanged() {
+        let markup = transformed_svg(SOURCE, (40, 20), TransformState::default(), None);
+        assert_eq!(markup, SOURCE);
+    }
+
+    #[test]
+    fn test_crop_becomes_view_box() {
+        let crop = CropRegion::new(5, 2, 10, 8);
+        let markup = transformed_svg(SOURCE, (40, 20), TransformState::default(), Some(crop));
+        assert!(markup.starts_with("<?xml version=\"1.0\"?>\n<svg "));
+        assert!(markup.contains("width=\"10\" height=\"8\" viewBox=\"5 2 10 8\""));
+        assert!(!markup.contains("<g "));
+        assert!(markup.contains("<rect width=\"10\" height=\"10\"/>"));
+    }
+
+    #[test]
+    fn test_turned_svg_swaps_size() {
+        let transform = TransformState {
+            rotation: RotationMode::Standard(Rotation::Cw90),
+            flip_h: true,
+            flip_v: false,
+        };
+        let markup = transformed_svg(SOURCE, (40, 20), transform, None);
+        assert!(markup.contains("width=\"20\" height=\"40\" viewBox=\"0 0 20 40\""));
+        assert!(markup.contains("<g transform=\"matrix(0 1 -1 0 20 0) matrix(-1 0 0 1 40 0)\">"));
+        assert!(markup.contains("<svg width=\"40\" height=\"20\">"));
+    }
+
+    #[test]
+    fn test_doctype_stays_in_prolog() {
+        let source = "<!DOCTYPE svg [<!ENTITY ns \"http://www.w3.org/2000/svg\">]>\n<!-- logo -->\n<svg xmlns=\"&ns;\"/>";
+        let (prolog, root) = split_prolog(source);
+        assert!(prolog.ends_with("<!-- logo -->\n"));
+        assert_eq!(root, "<svg xmlns=\"&ns;\"/>");
+    }
+}
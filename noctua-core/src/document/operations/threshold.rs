@@ -0,0 +1,171 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/threshold.rs
+//
+// Black and white (bilevel) conversion for cleaning up scans.
+
+use std::ops::RangeInclusive;
+
+use image::{DynamicImage, GrayImage, Luma};
+
+/// Part of the mean a pixel has to stay below to turn black with the
+/// adaptive threshold, in percent of the level (Bradley's method).
+const ADAPTIVE_PERCENT: u64 = 85;
+
+/// Conversion of an image to pure black and white.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Threshold {
+    /// Gray level (0-255) below which pixels turn black.
+    pub level: u8,
+    /// Compare each pixel to the mean of its neighborhood instead, scaled by
+    /// `level / 128`, so shadows and uneven lighting don't turn black.
+    pub adaptive: bool,
+}
+
+impl Default for Threshold {
+    fn default() -> Self {
+        Self {
+            level: 128,
+            adaptive: false,
+        }
+    }
+}
+
+impl Threshold {
+    /// Values the level can take.
+    pub const RANGE: RangeInclusive<u8> = 1..=254;
+
+    /// Black and white copy of `image`, as 8-bit gray with only 0 and 255.
+    ///
+    /// Transparency is dropped.
+    #[must_use]
+    pub fn apply(&self, image: &DynamicImage) -> DynamicImage {
+        let gray = image.to_luma8();
+        let bilevel = if self.adaptive {
+            self.adaptive(&gray)
+        } else {
+            let level = self.level;
+            GrayImage::from_fn(gray.width(), gray.height(), |x, y| {
+                bilevel_pixel(gray.get_pixel(x, y).0[0] >= level)
+            })
+        };
+        DynamicImage::ImageLuma8(bilevel)
+    }
+
+    /// Threshold each pixel against the mean of the square around it, an
+    /// eighth of the larger side wide, summed up through an integral image.
+    fn adaptive(&self, gray: &GrayImage) -> GrayImage {
+        let (width, height) = gray.dimensions();
+        let (w, h) = (width as usize, height as usize);
+
+        // sums[y * (w + 1) + x]: sum of the pixels above and left of (x, y)
+        let mut sums = vec![0u64; (w + 1) * (h + 1)];
+        for y in 0..h {
+            let mut row = 0u64;
+            for x in 0..w {
+                row += u64::from(gray.get_pixel(x as u32, y as u32).0[0]);
+                sums[(y + 1) * (w + 1) + x + 1] = sums[y * (w + 1) + x + 1] + row;
+            }
+        }
+
+        let half = (w.max(h) / 16).max(1);
+        let level = u64::from(self.level);
+        GrayImage::from_fn(width, height, |x, y| {
+            let (x, y) = (x as usize, y as usize);
+            let (left, right) = (x.saturating_sub(half), (x + half + 1).min(w));
+            let (top, bottom) = (y.saturating_sub(half), (y + half + 1).min(h));
+            let count = ((right - left) * (bottom - top)) as u64;
+            let sum = sums[bottom * (w + 1) + right] + sums[top * (w + 1) + left]
+                - sums[top * (w + 1) + right]
+                - sums[bottom * (w + 1) + left];
+            let value = u64::from(gray.get_pixel(x as u32, y as u32).0[0]);
+            // value < mean * level / 128 * ADAPTIVE_PERCENT / 100, in integers
+            bilevel_pixel(value * count * 128 * 100 >= sum * level * ADAPTIVE_PERCENT)
+        })
+    }
+}
+
+/// Check if `image` holds nothing but black and white pixels, as written by
+/// `Threshold::apply`.
+#[must_use]
+pub fn is_bilevel(image: &DynamicImage) -> bool {
+    match image {
+        DynamicImage::ImageLuma8(gray) => gray.pixels().all(|pixel| matches!(pixel.0[0], 0 | 255)),
+        _ => false,
+    }
+}
+
+/// Rows of a bilevel image packed eight pixels to a byte, most significant
+/// bit first and set for white, each row padded to a whole byte (as in
+/// 1-bit PNG).
+#[must_use]
+pub fn pack_bits(gray: &GrayImage) -> Vec<u8> {
+    let row_bytes = (gray.width() as usize).div_ceil(8);
+    let mut packed = vec![0u8; row_bytes * gray.height() as usize];
+    for (x, y, pixel) in gray.enumerate_pixels() {
+        if pixel.0[0] >= 128 {
+            packed[y as usize * row_bytes + x as usize / 8] |= 0x80 >> (x % 8);
+        }
+    }
+    packed
+}
+
+fn bilevel_pixel(white: bool) -> Luma<u8> {
+    Luma([if white { 255 } else { 0 }])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_global_threshold() {
+        let gray = GrayImage::from_fn(4, 1, |x, _| Luma([[0, 127, 128, 250][x as usize]]));
+        let bilevel = Threshold::default().apply(&DynamicImage::ImageLuma8(gray));
+        assert!(is_bilevel(&bilevel));
+        assert_eq!(bilevel.to_luma8().into_raw(), vec![0, 0, 255, 255]);
+
+        let dark = Threshold {
+            level: 200,
+            adaptive: false,
+        };
+        let rgb = DynamicImage::new_rgb8(2, 2);
+        assert_eq!(dark.apply(&rgb).color(), image::ColorType::L8);
+    }
+
+    #[test]
+    fn test_adaptive_threshold_ignores_shading() {
+        // Text strokes on paper that darkens from left to right
+        let gray = GrayImage::from_fn(64, 64, |x, y| {
+            let paper = 240 - x as u8 * 2;
+            Luma([if y % 8 == 0 { paper / 3 } else { paper }])
+        });
+        let image = DynamicImage::ImageLuma8(gray);
+
+        // A global threshold turns the shaded paper black
+        let global = Threshold {
+            level: 150,
+            adaptive: false,
+        }
+        .apply(&image)
+        .to_luma8();
+        assert_eq!(global.get_pixel(60, 3).0, [0]);
+
+        let adaptive = Threshold {
+            adaptive: true,
+            ..Threshold::default()
+        }
+        .apply(&image)
+        .to_luma8();
+        assert_eq!(adaptive.get_pixel(60, 3).0, [255]);
+        assert_eq!(adaptive.get_pixel(60, 8).0, [0]);
+        assert_eq!(adaptive.get_pixel(2, 3).0, [255]);
+        assert_eq!(adaptive.get_pixel(2, 16).0, [0]);
+    }
+
+    #[test]
+    fn test_pack_bits() {
+        let gray = GrayImage::from_fn(10, 2, |x, y| bilevel_pixel((x + y) % 2 == 0));
+        assert_eq!(pack_bits(&gray), vec![0xAA, 0x80, 0x55, 0x40]);
+        assert!(!is_bilevel(&DynamicImage::new_rgb8(1, 1)));
+    }
+}
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/tile.rs
+//
+// Seamless texture preview: 3×3 tiling and half-offset wrap.
+
+use image::imageops::{self, FilterType};
+use image::{DynamicImage, RgbaImage};
+
+use super::alpha;
+
+/// Largest tile side in the 3×3 preview (larger images are scaled down).
+const MAX_TILE_SIZE: u32 = 1024;
+
+/// Brightness of the eight surrounding tiles (the center tile stays unchanged).
+const OUTER_BRIGHTNESS: u16 = 70;
+
+/// The image repeated 3×3, with the surrounding tiles dimmed so the center
+/// copy stands out.
+#[must_use]
+pub fn tile_grid(img: &DynamicImage) -> RgbaImage {
+    let (width, height) = (img.width().max(1), img.height().max(1));
+    let tile = if width.max(height) > MAX_TILE_SIZE {
+        let ratio = f64::from(MAX_TILE_SIZE) / f64::from(width.max(height));
+        let scale = |v: u32| ((f64::from(v) * ratio).round() as u32).max(1);
+        alpha::resize_exact(img, scale(width), scale(height), FilterType::Triangle).to_rgba8()
+    } else {
+        img.to_rgba8()
+    };
+
+    let mut outer = tile.clone();
+    for pixel in outer.pixels_mut() {
+        for channel in &mut pixel.0[..3] {
+            *channel = (u16::from(*channel) * OUTER_BRIGHTNESS / 100) as u8;
+        }
+    }
+
+    let (tw, th) = tile.dimensions();
+    let mut grid = RgbaImage::new(tw * 3, th * 3);
+    for row in 0..3 {
+        for col in 0..3 {
+            let source = if row == 1 && col == 1 { &tile } else { &outer };
+            imageops::replace(&mut grid, source, i64::from(col * tw), i64::from(row * th));
+        }
+    }
+    grid
+}
+
+/// The image shifted by half its size with wrap-around, so the tile edges
+/// meet in a cross through the middle.
+#[must_use]
+pub fn offset_half(img: &DynamicImage) -> RgbaImage {
+    let rgba = img.to_rgba8();
+    let (width, height) = rgba.dimensions();
+    let (dx, dy) = (width / 2, height / 2);
+    RgbaImage::from_fn(width, height, |x, y| {
+        *rgba.get_pixel((x + dx) % width, (y + dy) % height)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::Rgba;
+
+    #[test]
+    fn test_tile_grid_layout() {
+        let img = DynamicImage::ImageRgba8(RgbaImage::from_pixel(4, 2, Rgba([200, 100, 0, 255])));
+        let grid = tile_grid(&img);
+        assert_eq!(grid.dimensions(), (12, 6));
+        assert_eq!(grid.get_pixel(5, 3).0, [200, 100, 0, 255]);
+        assert_eq!(grid.get_pixel(0, 0).0, [140, 70, 0, 255]);
+        assert_eq!(grid.get_pixel(11, 5).0, [140, 70, 0, 255]);
+    }
+
+    #[test]
+    fn test_offset_half_wraps() {
+        let img = RgbaImage::from_fn(4, 2, |x, y| Rgba([x as u8, y as u8, 0, 255]));
+        let out = offset_half(&DynamicImage::ImageRgba8(img));
+        assert_eq!(out.get_pixel(0, 0).0, [2, 1, 0, 255]);
+        assert_eq!(out.get_pixel(2, 1).0, [0, 0, 0, 255]);
+        assert_eq!(out.get_pixel(3, 0).0, [1, 1, 0, 255]);
+    }
+}
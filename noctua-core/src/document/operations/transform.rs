@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/operations/transform.rs
+// noctua-core/src/document/operations/transform.rs
 //
 // Document transformation operations.
 //
@@ -22,7 +22,7 @@
 // ## Usage Example
 //
 // ```rust
-// use crate::domain::document::operations::transform;
+// use crate::document::operations::transform;
 //
 // // High-level: Works with any DocumentContent (RECOMMENDED)
 // let mut document = DocumentContent::Raster(raster_doc);
@@ -40,10 +40,8 @@
 
 use image::{DynamicImage, GenericImageView};
 
-use crate::domain::document::core::content::DocumentContent;
-use crate::domain::document::core::document::{
-    DocResult, FlipDirection, Rotation, RotationMode, Transformable,
-};
+use crate::document::core::content::DocumentContent;
+use crate::document::core::document::{DocResult, FlipDirection, Rotation, RotationMode, Transformable};
 
 /// Apply a 90-degree rotation to a raster image.
 ///
@@ -139,10 +137,14 @@ pub fn dimensions_after_rotation(width: u32, height: u32, rotation: Rotation) ->
 /// # Examples
 ///
 /// ```no_run
-/// use crate::domain::document::operations::transform::rotate_document_cw;
+/// # use noctua_core::DocumentContent;
+/// use noctua_core::document::operations::transform::rotate_document_cw;
 ///
+/// # fn example(document: &mut DocumentContent) -> noctua_core::DocResult<()> {
 /// // Works with any document type
-/// rotate_document_cw(&mut document)?;
+/// rotate_document_cw(document)?;
+/// # Ok(())
+/// # }
 /// ```
 ///
 /// # Implementation Details
@@ -182,9 +184,13 @@ pub fn rotate_document_cw(document: &mut DocumentContent) -> DocResult<()> {
 /// # Examples
 ///
 /// ```no_run
-/// use crate::domain::document::operations::transform::rotate_document_ccw;
+/// # use noctua_core::DocumentContent;
+/// use noctua_core::document::operations::transform::rotate_document_ccw;
 ///
-/// rotate_document_ccw(&mut document)?;
+/// # fn example(document: &mut DocumentContent) -> noctua_core::DocResult<()> {
+/// rotate_document_ccw(document)?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn rotate_document_ccw(document: &mut DocumentContent) -> DocResult<()> {
     let new_rotation_mode = document.transform_state().rotation.rotate_ccw();
@@ -218,9 +224,13 @@ pub fn rotate_document_ccw(document: &mut DocumentContent) -> DocResult<()> {
 /// # Examples
 ///
 /// ```no_run
-/// use crate::domain::document::operations::transform::flip_document_horizontal;
+/// # use noctua_core::DocumentContent;
+/// use noctua_core::document::operations::transform::flip_document_horizontal;
 ///
-/// flip_document_horizontal(&mut document)?;
+/// # fn example(document: &mut DocumentContent) -> noctua_core::DocResult<()> {
+/// flip_document_horizontal(document)?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn flip_document_horizontal(document: &mut DocumentContent) -> DocResult<()> {
     document.flip(FlipDirection::Horizontal);
@@ -235,9 +245,13 @@ pub fn flip_document_horizontal(document: &mut DocumentContent) -> DocResult<()>
 /// # Examples
 ///
 /// ```no_run
-/// use crate::domain::document::operations::transform::flip_document_vertical;
+/// # use noctua_core::DocumentContent;
+/// use noctua_core::document::operations::transform::flip_document_vertical;
 ///
-/// flip_document_vertical(&mut document)?;
+/// # fn example(document: &mut DocumentContent) -> noctua_core::DocResult<()> {
+/// flip_document_vertical(document)?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn flip_document_vertical(document: &mut DocumentContent) -> DocResult<()> {
     document.flip(FlipDirection::Vertical);
@@ -257,11 +271,15 @@ pub fn flip_document_vertical(document: &mut DocumentContent) -> DocResult<()> {
 /// # Examples
 ///
 /// ```no_run
-/// use crate::domain::document::core::document::Rotation;
-/// use crate::domain::document::operations::transform::rotate_document_to;
+/// # use noctua_core::DocumentContent;
+/// use noctua_core::document::core::document::Rotation;
+/// use noctua_core::document::operations::transform::rotate_document_to;
 ///
+/// # fn example(document: &mut DocumentContent) -> noctua_core::DocResult<()> {
 /// // Rotate to 180 degrees
-/// rotate_document_to(&mut document, Rotation::Cw180)?;
+/// rotate_document_to(document, Rotation::Cw180)?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn rotate_document_to(document: &mut DocumentContent, rotation: Rotation) -> DocResult<()> {
     document.rotate(rotation);
@@ -276,10 +294,14 @@ pub fn rotate_document_to(document: &mut DocumentContent, rotation: Rotation) ->
 /// # Examples
 ///
 /// ```no_run
-/// use crate::domain::document::operations::transform::reset_document_transforms;
+/// # use noctua_core::DocumentContent;
+/// use noctua_core::document::operations::transform::reset_document_transforms;
 ///
+/// # fn example(document: &mut DocumentContent) -> noctua_core::DocResult<()> {
 /// // Undo all rotations and flips
-/// reset_document_transforms(&mut document)?;
+/// reset_document_transforms(document)?;
+/// # Ok(())
+/// # }
 /// ```
 pub fn reset_document_transforms(document: &mut DocumentContent) -> DocResult<()> {
     // Reset to no rotation
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/operations/xmp_edits.rs
+//
+// Crop and orientation edits read from XMP sidecars of other photo tools.
+
+use std::path::{Path, PathBuf};
+
+/// Crop as fractions of the oriented image size (0.0 to 1.0).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RelativeCrop {
+    pub left: f32,
+    pub top: f32,
+    pub right: f32,
+    pub bottom: f32,
+}
+
+impl RelativeCrop {
+    /// Pixel rectangle (x, y, width, height) in an image of the given size.
+    ///
+    /// Returns `None` if nothing would remain or the whole image is kept.
+    #[must_use]
+    pub fn to_pixels(&self, width: u32, height: u32) -> Option<(u32, u32, u32, u32)> {
+        let scale = |v: f32, size: u32| (v.clamp(0.0, 1.0) * size as f32).round() as u32;
+        let (left, top) = (scale(self.left, width), scale(self.top, height));
+        let (right, bottom) = (scale(self.right, width), scale(self.bottom, height));
+        if left >= right || top >= bottom || (right - left, bottom - top) == (width, height) {
+            return None;
+        }
+        Some((left, top, right - left, bottom - top))
+    }
+}
+
+/// Edits found in an XMP sidecar.
+///
+/// Supported are the EXIF orientation (`tiff:Orientation`, written by
+/// digiKam and others) and the Camera Raw crop (`crs:CropLeft` … `crs:CropBottom`,
+/// written by Lightroom and compatible tools). Straightening angles and tool
+/// specific histories (e.g. darktable's) are not read.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct XmpEdits {
+    /// EXIF orientation (1 = as stored, 2 to 8 = flipped and/or rotated),
+    /// `None` if the sidecar leaves it to the image file.
+    pub orientation: Option<u16>,
+    /// Crop applied after the orientation.
+    pub crop: Option<RelativeCrop>,
+}
+
+/// Sidecar files other tools may have written for `path`, in the order they are tried:
+/// `photo.jpg.xmp` (darktable, digiKam) and `photo.xmp` (Lightroom).
+#[must_use]
+pub fn sidecar_candidates(path: &Path) -> [PathBuf; 2] {
+    let mut appended = path.as_os_str().to_owned();
+    appended.push(".xmp");
+    [PathBuf::from(appended), path.with_extension("xmp")]
+}
+
+/// Read the edits from the first existing sidecar of `path`.
+///
+/// Returns the sidecar and its edits, or `None` if no sidecar has any.
+#[must_use]
+pub fn read(path: &Path) -> Option<(PathBuf, XmpEdits)> {
+    sidecar_candidates(path)
+        .into_iter()
+        .filter(|sidecar| sidecar != path)
+        .find_map(|sidecar| {
+            let edits = parse(&std::fs::read_to_string(&sidecar).ok()?)?;
+            Some((sidecar, edits))
+        })
+}
+
+/// Edits of an XMP packet, `None` if it neither rotates, flips nor crops.
+#[must_use]
+pub fn parse(xmp: &str) -> Option<XmpEdits> {
+    let orientation = value(xmp, "tiff:Orientation")
+        .and_then(|v| v.parse().ok())
+        .filter(|o| (1..=8).contains(o));
+
+    let crop = crop(xmp);
+    (orientation.is_some_and(|o| o != 1) || crop.is_some())
+        .then_some(XmpEdits { orientation, crop })
+}
+
+/// Crop rectangle, `None` if switched off (`crs:HasCrop="False"`) or
+/// covering the whole image.
+fn crop(xmp: &str) -> Option<RelativeCrop> {
+    if value(xmp, "crs:HasCrop").is_some_and(|v| !v.eq_ignore_ascii_case("true")) {
+        return None;
+    }
+    let fraction = |name: &str| value(xmp, name)?.parse::<f32>().ok();
+    let crop = RelativeCrop {
+        left: fraction("crs:CropLeft")?,
+        top: fraction("crs:CropTop")?,
+        right: fraction("crs:CropRight")?,
+        bottom: fraction("crs:CropBottom")?,
+    };
+    crop.to_pixels(10_000, 10_000).map(|_| crop)
+}
+
+/// Value of a property, written either as attribute (`name="value"`) or
+/// as element (`<name>value</name>`).
+fn value<'a>(xmp: &'a str, name: &str) -> Option<&'a str> {
+    if let Some(start) = xmp.find(&format!("{name}=")) {
+        let rest = &xmp[start + name.len() + 1..];
+        let quote = rest.chars().next().filter(|&c| c == '"' || c == '\'')?;
+        return rest[1..].split(quote).next().map(str::trim);
+    }
+    let start = xmp.find(&format!("<{name}>"))? + name.len() + 2;
+    let end = xmp[start..].find('<')?;
+    Some(xmp[start..start + end].trim())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_attributes_and_elements() {
+        let lightroom = r#"<rdf:Description rdf:about=""
+            tiff:Orientation="6"
+            crs:HasCrop="True"
+            crs:CropTop="0.1" crs:CropLeft="0.2" crs:CropBottom="0.9" crs:CropRight="0.7"/>"#;
+        let edits = parse(lightroom).unwrap();
+        assert_eq!(edits.orientation, Some(6));
+        assert_eq!(
+            edits.crop.unwrap().to_pixels(100, 50),
+            Some((20, 5, 50, 40))
+        );
+
+        let digikam = "<rdf:Description><tiff:Orientation>3</tiff:Orientation></rdf:Description>";
+        assert_eq!(
+            parse(digikam),
+            Some(XmpEdits {
+                orientation: Some(3),
+                crop: None
+            })
+        );
+    }
+
+    #[test]
+    fn test_parse_ignores_unedited() {
+        assert_eq!(parse(r#"<x tiff:Orientation="1" xmp:Rating="3"/>"#), None);
+        // Crop values kept around after the crop was switched off
+        let disabled = r#"<x crs:HasCrop="False" crs:CropTop="0.1" crs:CropLeft="0.1"
+            crs:CropBottom="0.9" crs:CropRight="0.9"/>"#;
+        assert_eq!(parse(disabled), None);
+        let full = r#"<x crs:CropTop="0" crs:CropLeft="0" crs:CropBottom="1" crs:CropRight="1"/>"#;
+        assert_eq!(parse(full), None);
+    }
+
+    #[test]
+    fn test_sidecar_candidates() {
+        let [appended, replaced] = sidecar_candidates(Path::new("/photos/IMG_1.CR2"));
+        assert_eq!(appended, PathBuf::from("/photos/IMG_1.CR2.xmp"));
+        assert_eq!(replaced, PathBuf::from("/photos/IMG_1.xmp"));
+    }
+}
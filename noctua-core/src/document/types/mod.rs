@@ -1,5 +1,5 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/types/mod.rs
+// noctua-core/src/document/types/mod.rs
 //
 // Concrete document type implementations.
 
@@ -8,3 +8,5 @@ pub mod raster;
 pub mod vector;
 #[cfg(feature = "portable")]
 pub mod portable;
+#[cfg(feature = "portable")]
+mod pdf_outline;
@@ -0,0 +1,191 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/types/pdf_outline.rs
+//
+// PDF outline (bookmarks) through poppler-glib's index iterator, which the
+// poppler binding does not expose.
+
+use std::ffi::{CStr, CString, c_char, c_int};
+use std::path::Path;
+use std::ptr;
+
+use glib::ffi::{GError, g_error_free, gboolean};
+use glib::gobject_ffi::{GObject, g_object_unref};
+
+use crate::document::core::page::OutlineEntry;
+
+/// Nesting levels read; deeper entries are left out, guarding against
+/// outlines that loop back on themselves.
+const MAX_LEVEL: usize = 32;
+
+/// `POPPLER_ACTION_GOTO_DEST`: an action going to a page of the document.
+const ACTION_GOTO_DEST: c_int = 2;
+
+/// `POPPLER_DEST_NAMED`: a destination given by name, looked up separately.
+const DEST_NAMED: c_int = 9;
+
+#[repr(C)]
+struct PopplerDocument {
+    _private: [u8; 0],
+}
+
+#[repr(C)]
+struct PopplerIndexIter {
+    _private: [u8; 0],
+}
+
+/// `PopplerActionGotoDest`; every action starts with its type and title.
+#[repr(C)]
+struct PopplerAction {
+    kind: c_int,
+    title: *mut c_char,
+    dest: *mut PopplerDest,
+}
+
+/// Start of a `PopplerDest`, up to the fields read here.
+#[repr(C)]
+struct PopplerDest {
+    kind: c_int,
+    /// Target page, 1-based (0 = unknown).
+    page_num: c_int,
+    /// Left, bottom, right, top and zoom.
+    _view: [f64; 5],
+    named_dest: *mut c_char,
+}
+
+#[link(name = "poppler-glib")]
+unsafe extern "C" {
+    fn poppler_document_new_from_file(
+        uri: *const c_char,
+        password: *const c_char,
+        error: *mut *mut GError,
+    ) -> *mut PopplerDocument;
+    fn poppler_document_find_dest(
+        document: *mut PopplerDocument,
+        link_name: *const c_char,
+    ) -> *mut PopplerDest;
+    fn poppler_dest_free(dest: *mut PopplerDest);
+    fn poppler_index_iter_new(document: *mut PopplerDocument) -> *mut PopplerIndexIter;
+    fn poppler_index_iter_get_child(parent: *mut PopplerIndexIter) -> *mut PopplerIndexIter;
+    fn poppler_index_iter_next(iter: *mut PopplerIndexIter) -> gboolean;
+    fn poppler_index_iter_get_action(iter: *mut PopplerIndexIter) -> *mut PopplerAction;
+    fn poppler_index_iter_free(iter: *mut PopplerIndexIter);
+    fn poppler_action_free(action: *mut PopplerAction);
+}
+
+/// Outline of the PDF at `path`, empty if it has none or cannot be read.
+///
+/// Entries that go to no page of the document (web links, other files) are
+/// left out; their children are kept.
+pub(super) fn read_outline(path: &Path, page_count: usize) -> Vec<OutlineEntry> {
+    let Some(uri) = glib::filename_to_uri(path, None)
+        .ok()
+        .and_then(|uri| CString::new(uri.as_str()).ok())
+    else {
+        return Vec::new();
+    };
+
+    let mut error = ptr::null_mut();
+    // SAFETY: the URI is a NUL-terminated string; on failure the document is
+    // null and the error set.
+    let document = unsafe { poppler_document_new_from_file(uri.as_ptr(), ptr::null(), &mut error) };
+    if document.is_null() {
+        if !error.is_null() {
+            // SAFETY: the error was set for us and is freed once.
+            unsafe { g_error_free(error) };
+        }
+        return Vec::new();
+    }
+
+    let mut outline = Vec::new();
+    // SAFETY: the document is valid until it is unreferenced last; the
+    // iterator, null without an outline, is freed after the walk.
+    unsafe {
+        let iter = poppler_index_iter_new(document);
+        if !iter.is_null() {
+            collect(document, iter, 0, page_count, &mut outline);
+            poppler_index_iter_free(iter);
+        }
+        g_object_unref(document.cast::<GObject>());
+    }
+    outline
+}
+
+/// Add the entries from `iter` on, at `level`, and their children.
+///
+/// # Safety
+///
+/// `document` and `iter` must be valid; `iter` stays owned by the caller.
+unsafe fn collect(
+    document: *mut PopplerDocument,
+    iter: *mut PopplerIndexIter,
+    level: usize,
+    page_count: usize,
+    outline: &mut Vec<OutlineEntry>,
+) {
+    loop {
+        // SAFETY: the action is a copy owned here, freed after its title is
+        // copied; the child iterator is freed after its entries are added.
+        unsafe {
+            let action = poppler_index_iter_get_action(iter);
+            if !action.is_null() {
+                if let Some(page) =
+                    target_page(document, &*action).filter(|page| *page < page_count)
+                {
+                    let title = if (*action).title.is_null() {
+                        String::new()
+                    } else {
+                        CStr::from_ptr((*action).title)
+                            .to_string_lossy()
+                            .trim()
+                            .to_string()
+                    };
+                    outline.push(OutlineEntry { title, page, level });
+                }
+                poppler_action_free(action);
+            }
+
+            if level < MAX_LEVEL {
+                let child = poppler_index_iter_get_child(iter);
+                if !child.is_null() {
+                    collect(document, child, level + 1, page_count, outline);
+                    poppler_index_iter_free(child);
+                }
+            }
+
+            if poppler_index_iter_next(iter) == 0 {
+                break;
+            }
+        }
+    }
+}
+
+/// Page (0-based) an action goes to, None if it leaves the document.
+///
+/// # Safety
+///
+/// `document` must be valid and `action` read from one of its iterators.
+unsafe fn target_page(document: *mut PopplerDocument, action: &PopplerAction) -> Option<usize> {
+    if action.kind != ACTION_GOTO_DEST || action.dest.is_null() {
+        return None;
+    }
+    // SAFETY: a go-to action's destination is valid while the action is;
+    // a looked-up destination is a copy freed here.
+    let page_num = unsafe {
+        let dest = &*action.dest;
+        if dest.kind == DEST_NAMED {
+            if dest.named_dest.is_null() {
+                return None;
+            }
+            let named = poppler_document_find_dest(document, dest.named_dest);
+            if named.is_null() {
+                return None;
+            }
+            let page_num = (*named).page_num;
+            poppler_dest_free(named);
+            page_num
+        } else {
+            dest.page_num
+        }
+    };
+    usize::try_from(page_num).ok()?.checked_sub(1)
+}
@@ -0,0 +1,986 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/types/portable.rs
+//
+// Portable documents (PDF) with poppler backend.
+
+use std::cell::OnceCell;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+
+/// PDF page render quality multiplier (2.0 = double resolution for sharp display).
+const PDF_RENDER_QUALITY: f64 = 2.0;
+
+/// Render qualities (pixels per point) for zoomed-in views. Zooming renders
+/// at the next tier up rather than at every step, so a few renders cover the
+/// whole zoom range.
+const ZOOM_QUALITY_TIERS: [f64; 4] = [4.0, 8.0, 16.0, 32.0];
+
+/// PDF thumbnail size multiplier (0.25 = 25% for fast preview generation).
+const PDF_THUMBNAIL_SIZE: f64 = 0.25;
+
+/// PDF coordinates are in points, 72 to the inch.
+const POINTS_PER_INCH: f64 = 72.0;
+
+/// Rendered pages larger than this many pixels (about A1 at
+/// `PDF_RENDER_QUALITY`) are shown as a quick preview first and refined in
+/// the background; their renders can be cancelled between bands.
+const PROGRESSIVE_PIXELS: i64 = 12_000_000;
+
+/// Render quality (pixels per point) of the quick preview of a large page.
+const PREVIEW_QUALITY: f64 = 0.5;
+
+/// Number of horizontal bands a large page is rendered in, checking for
+/// cancellation before each.
+const RENDER_BANDS: u32 = 4;
+
+use cairo::{Context, Format, ImageSurface};
+use image::{DynamicImage, GenericImageView, ImageReader, RgbaImage};
+use poppler::PopplerDocument;
+
+use crate::document::core::cancel::CancelToken;
+use crate::document::core::page::{
+    self, OutlineEntry, PageRenderJob, TextHit, TextMatch, ThumbnailJob, ThumbnailQueue,
+};
+use crate::document::core::document::{
+    DocResult, DocumentInfo, FlipDirection, ImageHandle, MultiPage, MultiPageThumbnails,
+    Renderable, RenderOutput, Rotation, RotationMode, TransformState, Transformable,
+};
+use crate::document::core::limits::OpenLimits;
+use crate::document::core::text_layer::{PageRect, TextLayer};
+use crate::document::operations::{CropRegion, transform};
+
+use super::pdf_outline;
+
+/// Represents a portable document (PDF).
+pub struct PortableDocument {
+    /// The parsed PDF document.
+    document: PopplerDocument,
+    /// Path to the source file (for caching).
+    source_path: PathBuf,
+    /// Total number of pages.
+    num_pages: usize,
+    /// Current page index (0-based).
+    page_index: usize,
+    /// Transformation of each page, so pages of mixed-orientation scans can
+    /// be turned one by one.
+    transforms: Vec<TransformState>,
+    /// Current rendered page as image.
+    pub rendered: DynamicImage,
+    /// Image handle for display.
+    pub handle: ImageHandle,
+    /// Thumbnail handle of each page (None = not yet generated).
+    thumbnail_cache: Vec<Option<ImageHandle>>,
+    /// Document outline, empty if the PDF has none; read on first use.
+    outline: OnceCell<Vec<OutlineEntry>>,
+    /// Text of the current page, extracted on the first selection.
+    text_layer: Option<TextLayer>,
+    /// Size limits the document was opened with, also applied to zoom renders.
+    limits: OpenLimits,
+    /// The current page was cropped, so a fresh render would not match it.
+    cropped: bool,
+    /// The current page shows a quick preview scaled up to its size, until
+    /// the render of a [`refine_job`](Self::refine_job) replaces it.
+    refining: bool,
+}
+
+impl PortableDocument {
+    /// Open a PDF document and render the first page.
+    ///
+    /// Fails if any page is larger than `limits` allow, either by its size
+    /// in points or as rendered pixels.
+    pub fn open(path: &Path, limits: &OpenLimits) -> anyhow::Result<Self> {
+        // Poppler reads the objects of a page from the file when needed, so
+        // unlike images the file is neither mapped nor read into a buffer
+        let document = PopplerDocument::new_from_file(path, None)
+            .map_err(|e| anyhow::anyhow!("Failed to parse PDF: {e}"))?;
+
+        let num_pages = document.get_n_pages();
+        if num_pages == 0 {
+            return Err(anyhow::anyhow!("PDF has no pages"));
+        }
+        for page_index in 0..num_pages {
+            let Some(page) = document.get_page(page_index) else {
+                continue;
+            };
+            let (width, height) = page.get_size();
+            limits.check_page(width, height)?;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            limits.check_pixels(
+                (width * PDF_RENDER_QUALITY) as u32,
+                (height * PDF_RENDER_QUALITY) as u32,
+            )?;
+        }
+
+        let (rendered, refining) =
+            Self::render_progressive(&document, 0, TransformState::default())?;
+        let handle = Self::create_image_handle_from_image(&rendered);
+
+        Ok(Self {
+            document,
+            source_path: path.to_path_buf(),
+            num_pages,
+            page_index: 0,
+            transforms: vec![TransformState::default(); num_pages],
+            rendered,
+            handle,
+            thumbnail_cache: vec![None; num_pages],
+            outline: OnceCell::new(),
+            text_layer: None,
+            limits: *limits,
+            cropped: false,
+            refining,
+        })
+    }
+
+    /// Returns the current pixel dimensions (width, height).
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        self.rendered.dimensions()
+    }
+
+    /// Get the current image handle.
+    #[must_use]
+    pub fn handle(&self) -> ImageHandle {
+        self.handle.clone()
+    }
+
+    /// Get native dimensions of current page.
+    #[must_use]
+    pub fn native_dimensions(&self) -> (u32, u32) {
+        self.rendered.dimensions()
+    }
+
+    /// Transformation of a page (the default for pages out of range).
+    #[must_use]
+    pub fn page_transform(&self, page: usize) -> TransformState {
+        self.transforms.get(page).copied().unwrap_or_default()
+    }
+
+    /// Thumbnail of a page turned and flipped like the page.
+    ///
+    /// Thumbnails are rendered upright; this applies the page's transform to
+    /// the stored one. None if it hasn't been generated yet.
+    #[must_use]
+    pub fn transformed_thumbnail(&self, page: usize) -> Option<ImageHandle> {
+        let thumbnail = self.get_thumbnail_handle(page)?;
+        let state = self.page_transform(page);
+        if state == TransformState::default() {
+            return Some(thumbnail);
+        }
+        let pixels = RgbaImage::from_raw(
+            thumbnail.width(),
+            thumbnail.height(),
+            thumbnail.pixels().to_vec(),
+        )?;
+        let mut image = DynamicImage::ImageRgba8(pixels);
+        if let RotationMode::Standard(rotation) = state.rotation {
+            image = transform::apply_rotation(image, rotation);
+        }
+        if state.flip_h {
+            image = Self::apply_flip(image, FlipDirection::Horizontal);
+        }
+        if state.flip_v {
+            image = Self::apply_flip(image, FlipDirection::Vertical);
+        }
+        Some(Self::create_image_handle_from_image(&image))
+    }
+
+    /// Get the number of thumbnails currently loaded.
+    pub fn thumbnails_loaded(&self) -> usize {
+        self.thumbnail_cache.iter().flatten().count()
+    }
+
+    /// Get thumbnail handle for a specific page (read-only access).
+    /// Returns None if the thumbnail hasn't been generated yet.
+    #[must_use]
+    pub fn get_thumbnail_handle(&self, page: usize) -> Option<ImageHandle> {
+        self.thumbnail_cache.get(page).cloned().flatten()
+    }
+
+    /// Document outline entries (may be empty).
+    ///
+    /// The binding keeps its poppler handle to itself, so reading the outline
+    /// parses the file again; this is left until the outline is first asked
+    /// for instead of slowing down every open.
+    #[must_use]
+    pub fn outline(&self) -> &[OutlineEntry] {
+        self.outline
+            .get_or_init(|| pdf_outline::read_outline(&self.source_path, self.num_pages))
+    }
+
+    /// Search the text layer of all pages (case-insensitive).
+    #[must_use]
+    pub fn search_text(&self, query: &str) -> Vec<TextMatch> {
+        let texts: Vec<(usize, String)> = (0..self.num_pages)
+            .filter_map(|index| {
+                let page = self.document.get_page(index)?;
+                Some((index, page.get_text()?.to_string()))
+            })
+            .collect();
+        page::find_in_pages(texts.iter().map(|(i, t)| (*i, t.as_str())), query)
+    }
+
+    /// Text inside a region of the rendered page, for copying.
+    ///
+    /// None while the page is rotated or flipped, as the region no longer
+    /// maps onto the page text.
+    pub fn selected_text(&mut self, region: CropRegion) -> Option<String> {
+        let rect = self.page_rect(region)?;
+        Some(self.text_layer().selected_text(rect))
+    }
+
+    /// Boxes of the characters inside a region of the rendered page, in
+    /// rendered pixels, for highlighting the selection.
+    pub fn selected_text_boxes(&mut self, region: CropRegion) -> Vec<CropRegion> {
+        let Some(rect) = self.page_rect(region) else {
+            return Vec::new();
+        };
+        self.text_layer()
+            .selected_boxes(rect)
+            .into_iter()
+            .map(pixel_region)
+            .collect()
+    }
+
+    /// Every occurrence of `query` on all pages (case-insensitive), with its
+    /// boxes in pixels of the upright rendered page.
+    #[must_use]
+    pub fn find_text(&self, query: &str) -> Vec<TextHit> {
+        (0..self.num_pages)
+            .flat_map(|index| {
+                self.page_text_layer(index)
+                    .find(query)
+                    .into_iter()
+                    .map(move |boxes| TextHit {
+                        page: index,
+                        boxes: boxes.into_iter().map(pixel_region).collect(),
+                    })
+            })
+            .collect()
+    }
+
+    /// Page rectangle in points of a region of the upright rendered page.
+    fn page_rect(&self, region: CropRegion) -> Option<PageRect> {
+        if self.transform_state() != TransformState::default() {
+            return None;
+        }
+        let points = |pixels: u32| f64::from(pixels) / PDF_RENDER_QUALITY;
+        Some((
+            points(region.x),
+            points(region.y),
+            points(region.width),
+            points(region.height),
+        ))
+    }
+
+    /// Text layer of the current page, extracted on first use.
+    fn text_layer(&mut self) -> &TextLayer {
+        if self.text_layer.is_none() {
+            self.text_layer = Some(self.page_text_layer(self.page_index));
+        }
+        self.text_layer.get_or_insert_default()
+    }
+
+    /// Text of a page with the box of each character.
+    fn page_text_layer(&self, index: usize) -> TextLayer {
+        let Some(page) = self.document.get_page(index) else {
+            return TextLayer::default();
+        };
+        let text = page.get_text().map(|t| t.to_string()).unwrap_or_default();
+        let boxes = page.get_text_layout().unwrap_or_default();
+        TextLayer::new(&text, boxes.iter().map(|r| (r.x1, r.y1, r.x2, r.y2)))
+    }
+
+    // Helper functions
+
+    /// Extract metadata for this portable document.
+    pub fn extract_meta(&self, path: &Path) -> crate::document::core::metadata::DocumentMeta {
+        use crate::document::core::metadata::{BasicMeta, DocumentMeta};
+
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        let file_path = path.to_string_lossy().to_string();
+        let file_size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+
+        let (width, height) = self.dimensions();
+        let format = format!("PDF ({} pages)", self.num_pages);
+
+        let basic = BasicMeta {
+            file_name,
+            file_path,
+            format,
+            width,
+            height,
+            file_size,
+            color_type: "Rendered".to_string(),
+        };
+
+        DocumentMeta {
+            basic,
+            exif: None,
+            icc: None,
+            resolution: None,
+            orientation: None,
+        }
+    }
+
+    /// Crop the current page to the specified rectangle.
+    /// Works on rendered output (raster).
+    pub fn crop(&mut self, x: u32, y: u32, width: u32, height: u32) -> Result<(), String> {
+        let (img_width, img_height) = self.rendered.dimensions();
+
+        // Validate crop region
+        if x >= img_width || y >= img_height {
+            return Err(format!(
+                "Crop region ({}, {}) is outside rendered bounds ({}, {})",
+                x, y, img_width, img_height
+            ));
+        }
+
+        // Clamp dimensions
+        let crop_width = width.min(img_width - x);
+        let crop_height = height.min(img_height - y);
+
+        if crop_width == 0 || crop_height == 0 {
+            return Err("Crop region has zero width or height".to_string());
+        }
+
+        // Cutting the preview would keep its blur, so the page is rendered
+        // in full first
+        if self.refining {
+            let transform = self.transform_state();
+            let rendered = Self::render_page(&self.document, self.page_index, transform.rotation)
+                .map_err(|e| format!("Failed to render PDF page: {e}"))?;
+            self.rendered = Self::apply_flips(rendered, transform);
+            self.refining = false;
+        }
+
+        // Crop rendered image
+        self.rendered = self.rendered.crop_imm(x, y, crop_width, crop_height);
+
+        // Update handle
+        self.handle = Self::create_image_handle_from_image(&self.rendered);
+        self.cropped = true;
+
+        Ok(())
+    }
+
+    /// Job rendering the current page for a view showing `scale` screen
+    /// pixels per pixel of the rendered page, so zooming in stays sharp.
+    ///
+    /// The page is rendered at the quality tier covering the zoom, capped by
+    /// [`OpenLimits::max_zoom_pixels`]. None if that is no sharper than the
+    /// rendered page, once the page was cropped, or while it is a preview.
+    #[must_use]
+    pub fn zoom_render_job(&self, scale: f64) -> Option<PortableRenderJob> {
+        if self.cropped || self.refining {
+            return None;
+        }
+        let page = self.document.get_page(self.page_index)?;
+        let quality = zoom_quality(page.get_size(), scale, self.limits.max_zoom_pixels())?;
+        Some(self.render_job(quality))
+    }
+
+    /// Check if the current page is a quick preview waiting for its
+    /// [`refine_job`](Self::refine_job).
+    #[must_use]
+    pub fn is_refining(&self) -> bool {
+        self.refining
+    }
+
+    /// Job rendering the current page in full while it shows a preview.
+    ///
+    /// Pages over `PROGRESSIVE_PIXELS` are rendered at `PREVIEW_QUALITY` and
+    /// scaled up, so turning to them does not wait for poppler. None once the
+    /// page is rendered properly.
+    #[must_use]
+    pub fn refine_job(&self) -> Option<PortableRenderJob> {
+        self.refining.then(|| self.render_job(PDF_RENDER_QUALITY))
+    }
+
+    /// Show the page rendered by a [`refine_job`](Self::refine_job) in place
+    /// of its preview.
+    ///
+    /// Returns false, dropping the render, if the page or its transform
+    /// changed meanwhile.
+    pub fn finish_refine(&mut self, job: &PortableRenderJob, rendered: DynamicImage) -> bool {
+        if self.refine_job().as_ref() != Some(job)
+            || rendered.dimensions() != self.rendered.dimensions()
+        {
+            return false;
+        }
+        self.rendered = rendered;
+        self.handle = Self::create_image_handle_from_image(&self.rendered);
+        self.refining = false;
+        true
+    }
+
+    /// Job rendering the current page as it is turned and flipped at `quality`.
+    fn render_job(&self, quality: f64) -> PortableRenderJob {
+        PortableRenderJob {
+            path: self.source_path.clone(),
+            page: self.page_index,
+            transform: self.transform_state(),
+            limits: self.limits,
+            quality,
+        }
+    }
+
+    fn create_image_handle_from_image(img: &DynamicImage) -> ImageHandle {
+        let (width, height) = img.dimensions();
+        let pixels = img.to_rgba8().into_raw();
+        ImageHandle::from_rgba(width, height, pixels)
+    }
+
+    /// Generate a single thumbnail page. Returns the next page to generate, or None if done.
+    pub fn generate_thumbnail_page(&mut self, page: usize) -> Option<usize> {
+        // Only pages without a thumbnail are rendered.
+        if let Some(slot @ None) = self.thumbnail_cache.get_mut(page) {
+            *slot = Some(Self::render_thumbnail(&self.document, page));
+        }
+
+        // Return next page if not done.
+        let next = page + 1;
+        if next < self.num_pages {
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    /// Thumbnails still missing, to render off the UI thread.
+    ///
+    /// None once all pages have a thumbnail.
+    #[must_use]
+    pub fn thumbnail_job(&self) -> Option<ThumbnailJob> {
+        let missing = self
+            .thumbnail_cache
+            .iter()
+            .enumerate()
+            .filter(|(_, thumbnail)| thumbnail.is_none())
+            .map(|(page, _)| page);
+        let queue = ThumbnailQueue::new(missing);
+        (!queue.is_empty()).then(|| ThumbnailJob {
+            path: self.source_path.clone(),
+            queue,
+        })
+    }
+
+    /// Add a thumbnail rendered by a [`ThumbnailJob`].
+    ///
+    /// Pages arrive in any order; one that already has a thumbnail keeps it.
+    /// Returns whether the thumbnail was added.
+    pub fn add_thumbnail(&mut self, page: usize, handle: ImageHandle) -> bool {
+        match self.thumbnail_cache.get_mut(page) {
+            Some(slot @ None) => {
+                *slot = Some(handle);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Render the thumbnails of `job`, handing each to `deliver`.
+    ///
+    /// Pages are taken from the job's queue as they are rendered, so the
+    /// ones scrolled into view meanwhile come next. Pages for which `cached`
+    /// returns a thumbnail are not rendered. Poppler documents cannot be
+    /// moved between threads, so the file is opened again by the thread
+    /// running the job, once a page needs it.
+    pub fn render_thumbnails(
+        job: &ThumbnailJob,
+        mut cached: impl FnMut(usize) -> Option<ImageHandle>,
+        mut deliver: impl FnMut(usize, ImageHandle) -> bool,
+    ) -> DocResult<()> {
+        let mut document = None;
+
+        while let Some(page) = job.queue.pop() {
+            let thumbnail = match cached(page) {
+                Some(thumbnail) => thumbnail,
+                None => {
+                    let document = match &mut document {
+                        Some(document) => document,
+                        None => document.insert(
+                            PopplerDocument::new_from_file(&job.path, None)
+                                .map_err(|e| anyhow::anyhow!("Failed to parse PDF: {e}"))?,
+                        ),
+                    };
+                    if page >= document.get_n_pages() {
+                        continue;
+                    }
+                    Self::render_thumbnail(document, page)
+                }
+            };
+            if !deliver(page, thumbnail) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Job rendering `pages` at `dpi`, turned and flipped like the pages.
+    #[must_use]
+    pub fn page_render_job(&self, pages: Vec<usize>, dpi: f64) -> PageRenderJob {
+        PageRenderJob {
+            path: self.source_path.clone(),
+            pages,
+            transforms: self.transforms.clone(),
+            dpi,
+        }
+    }
+
+    /// Render the pages of `job`, handing each image to `deliver`.
+    ///
+    /// Like [`render_thumbnails`](Self::render_thumbnails), the file is opened
+    /// again by the thread running the job.
+    pub fn render_pages(
+        job: &PageRenderJob,
+        limits: &OpenLimits,
+        mut deliver: impl FnMut(usize, DynamicImage) -> bool,
+    ) -> DocResult<()> {
+        let document = PopplerDocument::new_from_file(&job.path, None)
+            .map_err(|e| anyhow::anyhow!("Failed to parse PDF: {e}"))?;
+        let scale = job.dpi / POINTS_PER_INCH;
+
+        for &page_index in &job.pages {
+            let page = document
+                .get_page(page_index)
+                .ok_or_else(|| anyhow::anyhow!("Failed to get page {}", page_index + 1))?;
+            let (width, height) = page.get_size();
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            limits.check_pixels((width * scale) as u32, (height * scale) as u32)?;
+
+            let transform = job.transforms.get(page_index).copied().unwrap_or_default();
+            let rendered =
+                Self::render_page_at_scale(&document, page_index, transform.rotation, scale)?;
+            if !deliver(page_index, Self::apply_flips(rendered, transform)) {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Render the thumbnail of a page (transparent pixel if rendering fails).
+    fn render_thumbnail(document: &PopplerDocument, page: usize) -> ImageHandle {
+        match Self::render_page_at_scale(
+            document,
+            page,
+            RotationMode::Standard(Rotation::None),
+            PDF_THUMBNAIL_SIZE,
+        ) {
+            Ok(img) => Self::create_image_handle_from_image(&img),
+            Err(e) => {
+                tracing::warn!("Failed to generate thumbnail for page {page}: {e}");
+                ImageHandle::from_rgba(1, 1, vec![0, 0, 0, 0])
+            }
+        }
+    }
+
+    /// Render a specific page from the document to an image.
+    fn render_page(
+        document: &PopplerDocument,
+        page_index: usize,
+        rotation: RotationMode,
+    ) -> anyhow::Result<DynamicImage> {
+        Self::render_page_at_scale(document, page_index, rotation, PDF_RENDER_QUALITY)
+    }
+
+    /// Render a page with the flips of `transform`, as a preview scaled up
+    /// to the full size if it is a large one.
+    ///
+    /// Returns the image and whether it is a preview.
+    fn render_progressive(
+        document: &PopplerDocument,
+        page_index: usize,
+        transform: TransformState,
+    ) -> anyhow::Result<(DynamicImage, bool)> {
+        let page = document
+            .get_page(page_index)
+            .ok_or_else(|| anyhow::anyhow!("Failed to get page {page_index}"))?;
+        let (width, height) = raster_size(page.get_size(), transform.rotation, PDF_RENDER_QUALITY);
+        if i64::from(width) * i64::from(height) <= PROGRESSIVE_PIXELS {
+            let rendered = Self::render_page(document, page_index, transform.rotation)?;
+            return Ok((Self::apply_flips(rendered, transform), false));
+        }
+
+        let preview =
+            Self::render_page_at_scale(document, page_index, transform.rotation, PREVIEW_QUALITY)?;
+        #[allow(clippy::cast_sign_loss)]
+        let preview = Self::apply_flips(preview, transform).resize_exact(
+            width as u32,
+            height as u32,
+            image::imageops::FilterType::Triangle,
+        );
+        Ok((preview, true))
+    }
+
+    /// Render a specific page at a given scale.
+    fn render_page_at_scale(
+        document: &PopplerDocument,
+        page_index: usize,
+        rotation: RotationMode,
+        scale: f64,
+    ) -> anyhow::Result<DynamicImage> {
+        let page = document
+            .get_page(page_index)
+            .ok_or_else(|| anyhow::anyhow!("Failed to get page {page_index}"))?;
+        let (_, height) = raster_size(page.get_size(), rotation, scale);
+        Self::render_band(document, page_index, rotation, scale, 0, height)
+    }
+
+    /// Render `rows` rows of a page from row `top` down, at a given scale.
+    fn render_band(
+        document: &PopplerDocument,
+        page_index: usize,
+        rotation: RotationMode,
+        scale: f64,
+        top: i32,
+        rows: i32,
+    ) -> anyhow::Result<DynamicImage> {
+        let page = document
+            .get_page(page_index)
+            .ok_or_else(|| anyhow::anyhow!("Failed to get page {page_index}"))?;
+
+        let (page_width, page_height) = page.get_size();
+        let rotation_degrees = rotation.to_degrees() as i16;
+
+        let (width, height) = if rotation_degrees == 90 || rotation_degrees == 270 {
+            (page_height, page_width)
+        } else {
+            (page_width, page_height)
+        };
+        let (scaled_width, _) = raster_size((page_width, page_height), rotation, scale);
+
+        let surface = ImageSurface::create(Format::ARgb32, scaled_width, rows)
+            .map_err(|e| anyhow::anyhow!("Failed to create Cairo surface: {e}"))?;
+
+        let context = Context::new(&surface)
+            .map_err(|e| anyhow::anyhow!("Failed to create Cairo context: {e}"))?;
+
+        // Fill with white background.
+        context.set_source_rgb(1.0, 1.0, 1.0);
+        let _ = context.paint();
+
+        context.translate(0.0, -f64::from(top));
+        context.scale(scale, scale);
+
+        if rotation != RotationMode::Standard(Rotation::None) {
+            let center_x = width / 2.0;
+            let center_y = height / 2.0;
+            context.translate(center_x, center_y);
+            context.rotate(f64::from(rotation_degrees) * std::f64::consts::PI / 180.0);
+            context.translate(-page_width / 2.0, -page_height / 2.0);
+        }
+
+        page.render(&context);
+
+        drop(context);
+        surface.flush();
+
+        let mut png_data: Vec<u8> = Vec::new();
+        surface
+            .write_to_png(&mut png_data)
+            .map_err(|e| anyhow::anyhow!("Failed to write PNG: {e}"))?;
+
+        let image = ImageReader::new(Cursor::new(png_data))
+            .with_guessed_format()
+            .map_err(|e| anyhow::anyhow!("Failed to read PNG format: {e}"))?
+            .decode()
+            .map_err(|e| anyhow::anyhow!("Failed to decode PNG: {e}"))?;
+
+        Ok(image)
+    }
+
+    /// Re-render the current page with its transform.
+    fn rerender(&mut self) {
+        let transform = self.transform_state();
+        match Self::render_progressive(&self.document, self.page_index, transform) {
+            Ok((rendered, refining)) => {
+                self.rendered = rendered;
+                self.handle = Self::create_image_handle_from_image(&self.rendered);
+                self.cropped = false;
+                self.refining = refining;
+            }
+            Err(e) => {
+                tracing::error!("Failed to render PDF page: {e}");
+            }
+        }
+    }
+
+    /// Apply the flips of `transform` to a page rendered with its rotation.
+    fn apply_flips(mut rendered: DynamicImage, transform: TransformState) -> DynamicImage {
+        if transform.flip_h {
+            rendered = Self::apply_flip(rendered, FlipDirection::Horizontal);
+        }
+        if transform.flip_v {
+            rendered = Self::apply_flip(rendered, FlipDirection::Vertical);
+        }
+        rendered
+    }
+
+    fn apply_flip(img: DynamicImage, direction: FlipDirection) -> DynamicImage {
+        use image::imageops::{flip_horizontal, flip_vertical};
+        match direction {
+            FlipDirection::Horizontal => DynamicImage::ImageRgba8(flip_horizontal(&img.to_rgba8())),
+            FlipDirection::Vertical => DynamicImage::ImageRgba8(flip_vertical(&img.to_rgba8())),
+        }
+    }
+
+    /// Navigate to the next page.
+    #[allow(dead_code)]
+    pub fn next_page(&mut self) -> bool {
+        if self.page_index + 1 < self.num_pages {
+            self.page_index += 1;
+            self.text_layer = None;
+            self.rerender();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Navigate to the previous page.
+    #[allow(dead_code)]
+    pub fn prev_page(&mut self) -> bool {
+        if self.page_index > 0 {
+            self.page_index -= 1;
+            self.text_layer = None;
+            self.rerender();
+            true
+        } else {
+            false
+        }
+    }
+}
+
+/// Render quality tier for a view showing `scale` screen pixels per pixel of
+/// a page rendered at `PDF_RENDER_QUALITY`.
+///
+/// The smallest tier at least as sharp as the view, or the largest one
+/// fitting `max_pixels` for a page of `(width, height)` points. None if no
+/// tier is sharper than the rendered page.
+fn zoom_quality((width, height): (f64, f64), scale: f64, max_pixels: u64) -> Option<f64> {
+    let wanted = scale * PDF_RENDER_QUALITY;
+    #[allow(clippy::cast_precision_loss)]
+    let fits = |quality: f64| width * height * quality * quality <= max_pixels as f64;
+    let tiers = ZOOM_QUALITY_TIERS
+        .into_iter()
+        .filter(|&quality| fits(quality));
+    let quality = tiers
+        .clone()
+        .find(|&quality| quality >= wanted)
+        .or_else(|| tiers.last())?;
+    (wanted > PDF_RENDER_QUALITY * 1.01 && quality > PDF_RENDER_QUALITY).then_some(quality)
+}
+
+/// Size in pixels of a page of `(width, height)` points rendered turned by
+/// `rotation` at `scale`.
+#[allow(clippy::cast_possible_truncation)]
+fn raster_size((width, height): (f64, f64), rotation: RotationMode, scale: f64) -> (i32, i32) {
+    let (width, height) = match rotation.to_degrees() as i16 {
+        90 | 270 => (height, width),
+        _ => (width, height),
+    };
+    ((width * scale) as i32, (height * scale) as i32)
+}
+
+/// Region of the rendered page covering a rectangle in page points.
+fn pixel_region((x, y, width, height): PageRect) -> CropRegion {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let pixels = |points: f64| (points * PDF_RENDER_QUALITY).round() as u32;
+    CropRegion::new(pixels(x), pixels(y), pixels(width), pixels(height))
+}
+
+/// Render of a page of a PDF for a zoomed-in view, run off the UI thread.
+///
+/// Like a [`PageRenderJob`] it holds no document and opens the file again
+/// on the thread running it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PortableRenderJob {
+    path: PathBuf,
+    page: usize,
+    transform: TransformState,
+    limits: OpenLimits,
+    /// Pixels per point the page is rendered at.
+    pub quality: f64,
+}
+
+impl PortableRenderJob {
+    /// Raster pixels per pixel of the rendered page.
+    #[must_use]
+    pub fn scale(&self) -> f64 {
+        self.quality / PDF_RENDER_QUALITY
+    }
+
+    /// Render the page, turned and flipped like it.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file can no longer be read or the raster
+    /// exceeds the size limits.
+    pub fn run(&self) -> DocResult<DynamicImage> {
+        self.run_cancellable(&CancelToken::new())
+    }
+
+    /// Render the page like [`run`](Self::run), stopping once `token` is
+    /// cancelled.
+    ///
+    /// Large pages are rendered in bands, checking the token before each,
+    /// so a page the user already left does not hold up the next render.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Cancelled`](crate::document::core::cancel::Cancelled) if the
+    /// token was cancelled, or the errors of [`run`](Self::run).
+    pub fn run_cancellable(&self, token: &CancelToken) -> DocResult<DynamicImage> {
+        token.check()?;
+        let document = PopplerDocument::new_from_file(&self.path, None)
+            .map_err(|e| anyhow::anyhow!("Failed to parse PDF: {e}"))?;
+        let page = document
+            .get_page(self.page)
+            .ok_or_else(|| anyhow::anyhow!("Failed to get page {}", self.page + 1))?;
+        let (width, height) = page.get_size();
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        self.limits.check_pixels(
+            (width * self.quality) as u32,
+            (height * self.quality) as u32,
+        )?;
+
+        let rotation = self.transform.rotation;
+        let (raster_width, raster_height) = raster_size((width, height), rotation, self.quality);
+        let pixels = i64::from(raster_width) * i64::from(raster_height);
+        let rendered = if pixels <= PROGRESSIVE_PIXELS {
+            PortableDocument::render_page_at_scale(&document, self.page, rotation, self.quality)?
+        } else {
+            #[allow(clippy::cast_sign_loss)]
+            let (raster_width, raster_height) = (raster_width as u32, raster_height as u32);
+            let band = raster_height.div_ceil(RENDER_BANDS);
+            let mut raster = RgbaImage::new(raster_width, raster_height);
+            for top in (0..raster_height).step_by(band as usize) {
+                token.check()?;
+                let rows = band.min(raster_height - top);
+                #[allow(clippy::cast_possible_wrap)]
+                let part = PortableDocument::render_band(
+                    &document,
+                    self.page,
+                    rotation,
+                    self.quality,
+                    top as i32,
+                    rows as i32,
+                )?;
+                image::imageops::replace(&mut raster, &part.to_rgba8(), 0, i64::from(top));
+            }
+            DynamicImage::ImageRgba8(raster)
+        };
+        token.check()?;
+        Ok(PortableDocument::apply_flips(rendered, self.transform))
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+impl Renderable for PortableDocument {
+    fn render(&mut self, _scale: f64) -> DocResult<RenderOutput> {
+        // The page stays at PDF_RENDER_QUALITY, so crop and text coordinates
+        // do not depend on the zoom; zoomed views use `zoom_render_job`.
+        let (width, height) = self.dimensions();
+        Ok(RenderOutput {
+            handle: self.handle.clone(),
+            width,
+            height,
+        })
+    }
+
+    fn info(&self) -> DocumentInfo {
+        let (width, height) = self.dimensions();
+        DocumentInfo {
+            width,
+            height,
+            format: "PDF".to_string(),
+        }
+    }
+}
+
+// Transforms apply to the current page only.
+impl Transformable for PortableDocument {
+    fn rotate(&mut self, rotation: Rotation) {
+        if let Some(transform) = self.transforms.get_mut(self.page_index) {
+            transform.rotation = RotationMode::Standard(rotation);
+        }
+        self.rerender();
+    }
+
+    fn flip(&mut self, direction: FlipDirection) {
+        if let Some(transform) = self.transforms.get_mut(self.page_index) {
+            match direction {
+                FlipDirection::Horizontal => transform.flip_h = !transform.flip_h,
+                FlipDirection::Vertical => transform.flip_v = !transform.flip_v,
+            }
+        }
+        self.rerender();
+    }
+
+    fn transform_state(&self) -> TransformState {
+        self.page_transform(self.page_index)
+    }
+}
+
+impl MultiPage for PortableDocument {
+    fn page_count(&self) -> usize {
+        self.num_pages
+    }
+
+    fn current_page(&self) -> usize {
+        self.page_index
+    }
+
+    fn go_to_page(&mut self, page: usize) -> DocResult<()> {
+        if page >= self.num_pages {
+            return Err(anyhow::anyhow!(
+                "Page {} out of range (0-{})",
+                page,
+                self.num_pages - 1
+            ));
+        }
+        self.page_index = page;
+        self.text_layer = None;
+        self.rerender();
+        Ok(())
+    }
+}
+
+impl MultiPageThumbnails for PortableDocument {
+    fn thumbnails_ready(&self) -> bool {
+        self.thumbnail_cache.iter().all(Option::is_some)
+    }
+
+    fn thumbnails_loaded(&self) -> bool {
+        let loaded = PortableDocument::thumbnails_loaded(self);
+        loaded >= self.num_pages
+    }
+
+    fn generate_thumbnail_page(&mut self, page: usize) -> DocResult<()> {
+        PortableDocument::generate_thumbnail_page(self, page);
+        Ok(())
+    }
+
+    fn generate_all_thumbnails(&mut self) -> DocResult<()> {
+        if self.thumbnails_ready() {
+            return Ok(());
+        }
+        for page in 0..self.num_pages {
+            PortableDocument::generate_thumbnail_page(self, page);
+        }
+        Ok(())
+    }
+
+    fn get_thumbnail(&mut self, page: usize) -> DocResult<Option<ImageHandle>> {
+        Ok(self.get_thumbnail_handle(page))
+    }
+}
@@ -1,18 +1,25 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/document/types/raster.rs
+// noctua-core/src/document/types/raster.rs
 //
 // Raster image document support (PNG, JPEG, WebP, etc.).
 
+use std::io::Cursor;
 use std::path::Path;
 
-use image::{DynamicImage, GenericImageView, ImageReader};
+use image::metadata::Orientation;
+use image::{DynamicImage, GenericImageView, ImageDecoder, ImageFormat, ImageReader};
 
-use cosmic::widget::image::Handle as ImageHandle;
-
-use crate::domain::document::core::document::{
-    DocResult, DocumentInfo, FlipDirection, InterpolationQuality, Renderable, RenderOutput,
-    Rotation, RotationMode, TransformState, Transformable,
+use crate::document::core::document::{
+    DocResult, DocumentInfo, FlipDirection, ImageHandle, InterpolationQuality, Renderable,
+    RenderOutput, Rotation, RotationMode, TransformState, Transformable,
 };
+use crate::document::core::cancel::CancelToken;
+use crate::document::core::file_bytes::FileBytes;
+use crate::document::core::limits::OpenLimits;
+use crate::document::operations::adjust::Adjustments;
+use crate::document::operations::resize::Resize;
+use crate::document::operations::scan_cleanup::ScanCleanup;
+use crate::document::operations::straighten::{FineRotation, RotateEdges, RotateFilter};
 
 /// Represents a raster image document (PNG, JPEG, WebP, ...).
 pub struct RasterDocument {
@@ -30,12 +37,51 @@ pub struct RasterDocument {
     fine_rotation_angle: f32,
     /// Interpolation quality for fine rotation and resize operations.
     interpolation_quality: InterpolationQuality,
+    /// Embedded ICC color profile (raw bytes), if the file carries one.
+    icc_profile: Option<Vec<u8>>,
+    /// EXIF orientation stored in the file (1 = upright).
+    stored_orientation: u8,
+    /// Whether the stored orientation was applied to the pixels.
+    orientation_applied: bool,
+    /// Color adjustments shown on top of the image.
+    adjustments: Adjustments,
+    /// The image with the adjustments (None while they are neutral).
+    adjusted: Option<DynamicImage>,
 }
 
 impl RasterDocument {
     /// Load a raster document from disk.
-    pub fn open(path: &Path) -> image::ImageResult<Self> {
-        let document = ImageReader::open(path)?.decode()?;
+    ///
+    /// Fails without decoding the pixels if the header announces an image
+    /// beyond `limits`. Large files are decoded from a memory map.
+    pub fn open(path: &Path, limits: &OpenLimits) -> DocResult<Self> {
+        Self::open_cancellable(path, limits, &CancelToken::new())
+    }
+
+    /// Load a raster document unless `token` is cancelled meanwhile.
+    ///
+    /// The token is checked before decoding and before the display handle is
+    /// built. A cancelled load fails with `Cancelled`.
+    pub fn open_cancellable(
+        path: &Path,
+        limits: &OpenLimits,
+        token: &CancelToken,
+    ) -> DocResult<Self> {
+        token.check()?;
+        let bytes = FileBytes::open(path)?;
+        let mut reader = ImageReader::new(Cursor::new(&*bytes));
+        if let Ok(format) = ImageFormat::from_path(path) {
+            reader.set_format(format);
+        }
+        reader.limits(limits.decoder_limits());
+        let mut decoder = reader.into_decoder()?;
+        let (width, height) = decoder.dimensions();
+        limits.check_pixels(width, height)?;
+        let icc_profile = decoder.icc_profile().ok().flatten();
+        let stored_orientation = decoder.orientation().map_or(1, Orientation::to_exif);
+        token.check()?;
+        let document = DynamicImage::from_decoder(decoder)?;
+        token.check()?;
         let (native_width, native_height) = document.dimensions();
         let handle = Self::create_image_handle_from_image(&document);
 
@@ -47,9 +93,44 @@ impl RasterDocument {
             handle,
             fine_rotation_angle: 0.0,
             interpolation_quality: InterpolationQuality::default(),
+            icc_profile,
+            stored_orientation,
+            orientation_applied: false,
+            adjustments: Adjustments::default(),
+            adjusted: None,
         })
     }
 
+    /// Turn the pixels upright as the EXIF orientation of the file says.
+    ///
+    /// The result becomes the native image, as if the file had been stored
+    /// upright. Returns whether anything changed.
+    pub fn apply_stored_orientation(&mut self) -> bool {
+        if self.orientation_applied {
+            return false;
+        }
+        let Some(orientation) = Orientation::from_exif(self.stored_orientation)
+            .filter(|&orientation| orientation != Orientation::NoTransforms)
+        else {
+            return false;
+        };
+        self.document.apply_orientation(orientation);
+        (self.native_width, self.native_height) = self.document.dimensions();
+        self.refresh_handle();
+        self.orientation_applied = true;
+        true
+    }
+
+    /// EXIF orientation applied on load (1 if none).
+    #[must_use]
+    pub fn applied_orientation(&self) -> u8 {
+        if self.orientation_applied {
+            self.stored_orientation
+        } else {
+            1
+        }
+    }
+
     /// Returns the current pixel dimensions (width, height) after transforms.
     #[must_use]
     pub fn dimensions(&self) -> (u32, u32) {
@@ -74,6 +155,35 @@ impl RasterDocument {
         &self.document
     }
 
+    /// The image as shown: with the color adjustments baked in.
+    #[must_use]
+    pub fn adjusted_image(&self) -> &DynamicImage {
+        self.adjusted.as_ref().unwrap_or(&self.document)
+    }
+
+    /// Color adjustments shown on top of the image.
+    #[must_use]
+    pub fn adjustments(&self) -> Adjustments {
+        self.adjustments
+    }
+
+    /// Show the image with `adjustments`.
+    ///
+    /// They stay on top of the pixels: later rotations, flips and crops
+    /// keep them, and neutral adjustments show the image as it is.
+    pub fn set_adjustments(&mut self, adjustments: Adjustments) {
+        if adjustments != self.adjustments {
+            self.adjustments = adjustments;
+            self.refresh_handle();
+        }
+    }
+
+    /// Get the embedded ICC color profile, if any.
+    #[must_use]
+    pub fn icc_profile(&self) -> Option<&[u8]> {
+        self.icc_profile.as_deref()
+    }
+
     /// Get native dimensions (before transformations).
     #[must_use]
     pub fn native_dimensions(&self) -> (u32, u32) {
@@ -124,10 +234,48 @@ impl RasterDocument {
         self.fine_rotation_angle = 0.0;
 
         // Regenerate handle
-        self.handle = Self::create_image_handle_from_image(&self.document);
+        self.refresh_handle();
 
         Ok(())
     }
+
+    /// Rotate the pixels by a fine angle (in-place).
+    ///
+    /// Like a crop, the result becomes the new native image and the
+    /// transformations are reset.
+    pub fn straighten(&mut self, rotation: FineRotation) {
+        if rotation.is_none() {
+            return;
+        }
+        self.document = rotation.apply(&self.document);
+        (self.native_width, self.native_height) = self.document.dimensions();
+        self.transform = TransformState::default();
+        self.fine_rotation_angle += rotation.degrees();
+        self.refresh_handle();
+    }
+
+    /// Resample the pixels to a new size (in-place).
+    ///
+    /// Like a crop, the result becomes the new native image and the
+    /// transformations are reset.
+    pub fn resize(&mut self, resize: Resize) {
+        self.document = resize.apply(&self.document);
+        (self.native_width, self.native_height) = self.document.dimensions();
+        self.transform = TransformState::default();
+        self.refresh_handle();
+    }
+
+    /// Remove specks and whiten the paper of a scan (in-place).
+    ///
+    /// The size stays the same, the transformations as well.
+    pub fn clean_scan(&mut self, cleanup: ScanCleanup) {
+        if cleanup.is_none() {
+            return;
+        }
+        self.document = cleanup.apply(&self.document);
+        self.refresh_handle();
+    }
+
     /// Crop the image to the specified rectangle and return as DynamicImage.
     ///
     /// This does NOT modify the document - it's used for exporting cropped images.
@@ -163,11 +311,10 @@ impl RasterDocument {
     /// Extract metadata for this raster document.
     ///
     /// Returns basic metadata (dimensions, format, file size) and EXIF data if available.
-    pub fn extract_meta(
-        &self,
-        path: &Path,
-    ) -> crate::domain::document::core::metadata::DocumentMeta {
-        use crate::domain::document::core::metadata::{BasicMeta, DocumentMeta, ExifMeta};
+    pub fn extract_meta(&self, path: &Path) -> crate::document::core::metadata::DocumentMeta {
+        use crate::document::core::metadata::{
+            BasicMeta, DocumentMeta, ExifMeta, IccProfileInfo, Resolution, StoredOrientation,
+        };
 
         let file_name = path
             .file_name()
@@ -198,12 +345,28 @@ impl RasterDocument {
             color_type,
         };
 
-        // Try to extract EXIF data
-        let exif = std::fs::read(path)
-            .ok()
-            .and_then(|bytes| ExifMeta::from_bytes(&bytes));
-
-        DocumentMeta { basic, exif }
+        // Try to extract EXIF data and the declared print resolution
+        let bytes = FileBytes::open(path).ok();
+        let exif = bytes.as_deref().and_then(ExifMeta::from_bytes);
+        let resolution = bytes.as_deref().and_then(Resolution::from_bytes);
+
+        let icc = self
+            .icc_profile
+            .as_deref()
+            .and_then(IccProfileInfo::from_bytes);
+
+        let orientation = (self.stored_orientation != 1).then_some(StoredOrientation {
+            exif: self.stored_orientation,
+            applied: self.orientation_applied,
+        });
+
+        DocumentMeta {
+            basic,
+            exif,
+            icc,
+            resolution,
+            orientation,
+        }
     }
 
     /// Resize the document to specific dimensions (for format conversion).
@@ -221,10 +384,18 @@ impl RasterDocument {
         self.document = self
             .document
             .resize_exact(target_width, target_height, filter);
-        self.handle = Self::create_image_handle_from_image(&self.document);
+        self.refresh_handle();
     }
 
     // Helper functions
+
+    /// Rebuild the adjusted image and the display handle after a change.
+    fn refresh_handle(&mut self) {
+        self.adjusted =
+            (!self.adjustments.is_neutral()).then(|| self.adjustments.apply(&self.document));
+        self.handle = Self::create_image_handle_from_image(self.adjusted_image());
+    }
+
     fn create_image_handle_from_image(img: &DynamicImage) -> ImageHandle {
         let (width, height) = img.dimensions();
         let pixels = img.to_rgba8().into_raw();
@@ -305,7 +476,7 @@ impl Transformable for RasterDocument {
 
         // Set to standard rotation mode
         self.transform.rotation = RotationMode::Standard(rotation);
-        self.handle = Self::create_image_handle_from_image(&self.document);
+        self.refresh_handle();
     }
 
     fn flip(&mut self, direction: FlipDirection) {
@@ -317,7 +488,7 @@ impl Transformable for RasterDocument {
             FlipDirection::Horizontal => self.transform.flip_h = !self.transform.flip_h,
             FlipDirection::Vertical => self.transform.flip_v = !self.transform.flip_v,
         }
-        self.handle = Self::create_image_handle_from_image(&self.document);
+        self.refresh_handle();
     }
 
     fn transform_state(&self) -> TransformState {
@@ -325,21 +496,11 @@ impl Transformable for RasterDocument {
     }
 
     fn rotate_fine(&mut self, angle_degrees: f32) {
-        // TODO: Re-enable when imageproc dependency is added to Cargo.toml
-        // For now, round to nearest 90-degree rotation
-        log::warn!("Fine rotation not yet implemented, rounding to nearest 90 degrees");
-
-        let rounded = ((angle_degrees / 90.0).round() as i16 * 90) % 360;
-        let rotation = match rounded {
-            0 => Rotation::None,
-            90 => Rotation::Cw90,
-            180 => Rotation::Cw180,
-            270 => Rotation::Cw270,
-            _ => Rotation::None,
-        };
-
-        self.rotate(rotation);
-        self.transform.rotation = RotationMode::Standard(rotation);
+        self.straighten(FineRotation::new(
+            angle_degrees,
+            RotateEdges::Transparent,
+            RotateFilter::Bilinear,
+        ));
     }
 
     fn reset_fine_rotation(&mut self) {
@@ -0,0 +1,485 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/src/document/types/vector.rs
+//
+// Vector documents (SVG, etc.).
+
+use std::path::Path;
+use std::borrow::Cow;
+use std::sync::{Arc, OnceLock};
+
+/// Minimum pixmap size for SVG rendering (prevents zero-size pixmaps).
+const MIN_PIXMAP_SIZE: u32 = 1;
+
+/// Zoom renders are skipped this close to the native size.
+const ZOOM_EPSILON: f64 = 0.01;
+
+use image::{DynamicImage, GenericImageView, RgbaImage};
+use resvg::tiny_skia::{self, Pixmap};
+use resvg::usvg::{
+    FontFamily, FontResolver, FontSelectionFn, FontStyle, Options, Tree, WriteOptions, fontdb,
+};
+
+use crate::document::core::document::{
+    DocResult, DocumentInfo, FlipDirection, ImageHandle, Renderable, RenderOutput, Rotation,
+    RotationMode, TransformState, Transformable,
+};
+pub use crate::document::core::limits::MAX_ZOOM_PIXELS;
+use crate::document::core::limits::OpenLimits;
+use crate::document::core::svg_text::{SvgTextOptions, svg_text_options};
+use crate::document::operations::alpha::unpremultiply_channel;
+use crate::document::operations::crop::CropRegion;
+use crate::document::operations::svg_markup::transformed_svg;
+
+/// Represents a vector document such as SVG.
+pub struct VectorDocument {
+    /// SVG source, for copying the markup.
+    source: Arc<str>,
+    /// Parsed SVG document, shared with zoom renders on worker threads.
+    document: Arc<Tree>,
+    /// Native width of the SVG (from viewBox or width attribute).
+    native_width: u32,
+    /// Native height of the SVG (from viewBox or height attribute).
+    native_height: u32,
+    /// Accumulated transformations.
+    transform: TransformState,
+    /// Rasterized image at the current scale.
+    pub rendered: DynamicImage,
+    /// Image handle for display.
+    pub handle: ImageHandle,
+    /// Current rendered width.
+    pub width: u32,
+    /// Current rendered height.
+    pub height: u32,
+    /// Size limits, also applied when rendering at a higher zoom.
+    limits: OpenLimits,
+    /// Region the raster was cropped to, so it no longer matches the SVG.
+    crop: Option<CropRegion>,
+}
+
+impl VectorDocument {
+    /// Load a vector document from disk.
+    ///
+    /// Fails if rendering it at native size would exceed `limits`.
+    pub fn open(path: &Path, limits: &OpenLimits) -> anyhow::Result<Self> {
+        let raw_data = std::fs::read_to_string(path)?;
+
+        // Parse SVG with the system fonts and the configured text options.
+        let options = parse_options(&svg_text_options());
+        let document = Arc::new(Tree::from_str(&raw_data, &options)?);
+
+        // Get native size from the parsed document.
+        let size = document.size();
+        let native_width = size.width().ceil() as u32;
+        let native_height = size.height().ceil() as u32;
+
+        let transform = TransformState::default();
+
+        // Render at native scale (1.0).
+        let (rendered, width, height) = render_document(
+            &document,
+            native_width,
+            native_height,
+            1.0,
+            transform,
+            limits,
+        )?;
+        let handle = Self::create_image_handle_from_image(&rendered);
+
+        Ok(Self {
+            source: raw_data.into(),
+            document,
+            native_width,
+            native_height,
+            transform,
+            rendered,
+            handle,
+            width,
+            height,
+            limits: *limits,
+            crop: None,
+        })
+    }
+
+    /// Returns the dimensions of the rasterized representation.
+    #[must_use]
+    pub fn dimensions(&self) -> (u32, u32) {
+        (self.width, self.height)
+    }
+
+    /// Get the current image handle.
+    #[must_use]
+    pub fn handle(&self) -> ImageHandle {
+        self.handle.clone()
+    }
+
+    /// Get native dimensions (before transformations).
+    #[must_use]
+    pub fn native_dimensions(&self) -> (u32, u32) {
+        (self.native_width, self.native_height)
+    }
+
+    /// Extract metadata for this vector document.
+    pub fn extract_meta(&self, path: &Path) -> crate::document::core::metadata::DocumentMeta {
+        use crate::document::core::metadata::{BasicMeta, DocumentMeta};
+
+        let file_name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string();
+
+        let file_path = path.to_string_lossy().to_string();
+        let file_size = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+
+        let basic = BasicMeta {
+            file_name,
+            file_path,
+            format: "SVG".to_string(),
+            width: self.native_width,
+            height: self.native_height,
+            file_size,
+            color_type: "Vector".to_string(),
+        };
+
+        DocumentMeta {
+            basic,
+            exif: None,
+            icc: None,
+            resolution: None,
+            orientation: None,
+        }
+    }
+
+    /// Crop the document to the specified rectangle.
+    /// Works on rendered output (raster).
+    pub fn crop(&mut self, x: u32, y: u32, width: u32, height: u32) -> Result<(), String> {
+        let (img_width, img_height) = self.rendered.dimensions();
+
+        // Validate crop region
+        if x >= img_width || y >= img_height {
+            return Err(format!(
+                "Crop region ({}, {}) is outside rendered bounds ({}, {})",
+                x, y, img_width, img_height
+            ));
+        }
+
+        // Clamp dimensions
+        let crop_width = width.min(img_width - x);
+        let crop_height = height.min(img_height - y);
+
+        if crop_width == 0 || crop_height == 0 {
+            return Err("Crop region has zero width or height".to_string());
+        }
+
+        // Crop rendered image
+        self.rendered = self.rendered.crop_imm(x, y, crop_width, crop_height);
+        self.width = crop_width;
+        self.height = crop_height;
+
+        // Update handle
+        self.handle = Self::create_image_handle_from_image(&self.rendered);
+        // Successive crops narrow the earlier region
+        let (offset_x, offset_y) = self.crop.map_or((0, 0), |crop| (crop.x, crop.y));
+        self.crop = Some(CropRegion::new(
+            offset_x + x,
+            offset_y + y,
+            crop_width,
+            crop_height,
+        ));
+
+        Ok(())
+    }
+
+    /// Job rendering the SVG for a view showing `scale` screen pixels per
+    /// pixel of the raster, so zooming in stays sharp.
+    ///
+    /// The scale is capped so the render stays within `MAX_ZOOM_PIXELS` and
+    /// the size limits. None if that is no sharper than the raster (zoomed
+    /// out or at 100%), or once the raster was cropped.
+    #[must_use]
+    pub fn zoom_render_job(&self, scale: f64) -> Option<VectorRenderJob> {
+        let pixels = f64::from(self.native_width) * f64::from(self.native_height);
+        #[allow(clippy::cast_precision_loss)]
+        let max_pixels = self.limits.max_zoom_pixels() as f64;
+        let scale = scale.min((max_pixels / pixels.max(1.0)).sqrt());
+        (self.crop.is_none() && scale > 1.0 + ZOOM_EPSILON).then(|| VectorRenderJob {
+            document: Arc::clone(&self.document),
+            native_width: self.native_width,
+            native_height: self.native_height,
+            transform: self.transform,
+            limits: self.limits,
+            scale,
+        })
+    }
+
+    /// SVG markup turned, flipped and cropped like the raster, keeping
+    /// the vector data.
+    ///
+    /// With `text_to_path` in the text options, text is written as outlines.
+    #[must_use]
+    pub fn svg_markup(&self) -> String {
+        let source = if svg_text_options().text_to_path {
+            Cow::Owned(self.document.to_string(&WriteOptions::default()))
+        } else {
+            Cow::Borrowed(&*self.source)
+        };
+        transformed_svg(
+            &source,
+            (self.native_width, self.native_height),
+            self.transform,
+            self.crop,
+        )
+    }
+
+    /// Parse the SVG again with the current text options, keeping the
+    /// transform and crop.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the SVG no longer parses; the document is kept.
+    pub fn refresh_text(&mut self) -> anyhow::Result<()> {
+        let options = parse_options(&svg_text_options());
+        self.document = Arc::new(Tree::from_str(&self.source, &options)?);
+        let crop = self.crop;
+        self.rerender();
+        if let Some(crop) = crop {
+            self.crop(crop.x, crop.y, crop.width, crop.height)
+                .map_err(anyhow::Error::msg)?;
+        }
+        Ok(())
+    }
+
+    /// Re-render at native size with the current transform.
+    fn rerender(&mut self) {
+        if let Ok((rendered, width, height)) = render_document(
+            &self.document,
+            self.native_width,
+            self.native_height,
+            1.0,
+            self.transform,
+            &self.limits,
+        ) {
+            self.rendered = rendered;
+            self.width = width;
+            self.height = height;
+            self.handle = Self::create_image_handle_from_image(&self.rendered);
+            self.crop = None;
+        }
+    }
+
+    // Helper function
+    fn create_image_handle_from_image(img: &image::DynamicImage) -> ImageHandle {
+        let (width, height) = img.dimensions();
+        let pixels = img.to_rgba8().into_raw();
+        ImageHandle::from_rgba(width, height, pixels)
+    }
+}
+
+// ============================================================================
+// Trait Implementations
+// ============================================================================
+
+impl Renderable for VectorDocument {
+    fn render(&mut self, _scale: f64) -> DocResult<RenderOutput> {
+        // The raster stays at native size, so crop and selection coordinates
+        // do not depend on the zoom; zoomed views use `zoom_render_job`.
+        Ok(RenderOutput {
+            handle: self.handle.clone(),
+            width: self.width,
+            height: self.height,
+        })
+    }
+
+    fn info(&self) -> DocumentInfo {
+        DocumentInfo {
+            width: self.native_width,
+            height: self.native_height,
+            format: "SVG".to_string(),
+        }
+    }
+}
+
+impl Transformable for VectorDocument {
+    fn rotate(&mut self, rotation: Rotation) {
+        self.transform.rotation = RotationMode::Standard(rotation);
+        self.rerender();
+    }
+
+    fn flip(&mut self, direction: FlipDirection) {
+        match direction {
+            FlipDirection::Horizontal => self.transform.flip_h = !self.transform.flip_h,
+            FlipDirection::Vertical => self.transform.flip_v = !self.transform.flip_v,
+        }
+        self.rerender();
+    }
+
+    fn transform_state(&self) -> TransformState {
+        self.transform
+    }
+}
+
+/// SVG rendered at a zoom, detached from the document for a worker thread.
+#[derive(Debug, Clone)]
+pub struct VectorRenderJob {
+    document: Arc<Tree>,
+    native_width: u32,
+    native_height: u32,
+    transform: TransformState,
+    limits: OpenLimits,
+    /// Raster pixels per SVG unit, after the size cap.
+    pub scale: f64,
+}
+
+impl VectorRenderJob {
+    /// Render the SVG, turned and flipped like the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the raster exceeds the size limits.
+    pub fn run(&self) -> anyhow::Result<DynamicImage> {
+        render_document(
+            &self.document,
+            self.native_width,
+            self.native_height,
+            self.scale,
+            self.transform,
+            &self.limits,
+        )
+        .map(|(image, _, _)| image)
+    }
+}
+
+/// System fonts, loaded once for all SVG documents.
+fn system_fonts() -> Arc<fontdb::Database> {
+    static FONTS: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+    Arc::clone(FONTS.get_or_init(|| {
+        let mut fonts = fontdb::Database::new();
+        fonts.load_system_fonts();
+        tracing::debug!("Loaded {} font faces for SVG text", fonts.len());
+        Arc::new(fonts)
+    }))
+}
+
+/// Parser options rendering text with the system fonts and `text`.
+fn parse_options(text: &SvgTextOptions) -> Options<'static> {
+    Options {
+        font_size: text.font_size,
+        font_resolver: FontResolver {
+            select_font: font_selector(text.fallback_families.clone()),
+            select_fallback: FontResolver::default_fallback_selector(),
+        },
+        fontdb: system_fonts(),
+        ..Options::default()
+    }
+}
+
+/// Font of the first installed family a text asks for, else of the first
+/// installed fallback family, else usvg's default serif.
+fn font_selector(fallback_families: Vec<String>) -> FontSelectionFn<'static> {
+    let default = FontResolver::default_font_selector();
+    Box::new(move |font, fonts| {
+        let mut families: Vec<fontdb::Family<'_>> = font
+            .families()
+            .iter()
+            .map(|family| match family {
+                FontFamily::Serif => fontdb::Family::Serif,
+                FontFamily::SansSerif => fontdb::Family::SansSerif,
+                FontFamily::Cursive => fontdb::Family::Cursive,
+                FontFamily::Fantasy => fontdb::Family::Fantasy,
+                FontFamily::Monospace => fontdb::Family::Monospace,
+                FontFamily::Named(name) => fontdb::Family::Name(name),
+            })
+            .collect();
+        for name in &fallback_families {
+            families.push(fontdb::Family::Name(name));
+        }
+        let style = match font.style() {
+            FontStyle::Normal => fontdb::Style::Normal,
+            FontStyle::Italic => fontdb::Style::Italic,
+            FontStyle::Oblique => fontdb::Style::Oblique,
+        };
+        let query = fontdb::Query {
+            families: &families,
+            weight: fontdb::Weight(font.weight()),
+            style,
+            ..fontdb::Query::default()
+        };
+        fonts.query(&query).or_else(|| default(font, fonts))
+    })
+}
+
+/// Render the SVG document at a given scale with transformations.
+fn render_document(
+    document: &Tree,
+    native_width: u32,
+    native_height: u32,
+    scale: f64,
+    transform: TransformState,
+    limits: &OpenLimits,
+) -> anyhow::Result<(DynamicImage, u32, u32)> {
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let width = ((f64::from(native_width) * scale).ceil() as u32).max(MIN_PIXMAP_SIZE);
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    let height = ((f64::from(native_height) * scale).ceil() as u32).max(MIN_PIXMAP_SIZE);
+    limits.check_pixels(width, height)?;
+
+    let mut pixmap =
+        Pixmap::new(width, height).ok_or_else(|| anyhow::anyhow!("Failed to create pixmap"))?;
+
+    #[allow(clippy::cast_possible_truncation)]
+    let scale_f32 = scale as f32;
+    let ts = tiny_skia::Transform::from_scale(scale_f32, scale_f32);
+    resvg::render(document, ts, &mut pixmap.as_mut());
+
+    let mut image = pixmap_to_dynamic_image(&pixmap);
+
+    // Apply flip transformations using shared utilities
+    if transform.flip_h {
+        image =
+            crate::document::operations::transform::apply_flip(image, FlipDirection::Horizontal);
+    }
+    if transform.flip_v {
+        image = crate::document::operations::transform::apply_flip(image, FlipDirection::Vertical);
+    }
+
+    // Apply rotation using shared utilities
+    image = match transform.rotation {
+        RotationMode::Standard(rotation) => {
+            crate::document::operations::transform::apply_rotation(image, rotation)
+        }
+        RotationMode::Fine(_) => {
+            // For vector documents, fine rotation is handled differently
+            // For now, we just render without rotation
+            // TODO: Implement fine rotation support for vector documents
+            image
+        }
+    };
+
+    let final_width = image.width();
+    let final_height = image.height();
+
+    Ok((image, final_width, final_height))
+}
+
+/// Convert a `tiny_skia` Pixmap to a `DynamicImage`.
+fn pixmap_to_dynamic_image(pixmap: &Pixmap) -> DynamicImage {
+    let width = pixmap.width();
+    let height = pixmap.height();
+
+    // tiny_skia uses premultiplied alpha, we need to unpremultiply for image crate
+    let mut pixels = Vec::with_capacity((width * height * 4) as usize);
+    for pixel in pixmap.pixels() {
+        let a = pixel.alpha();
+        pixels.extend_from_slice(&[
+            unpremultiply_channel(pixel.red(), a),
+            unpremultiply_channel(pixel.green(), a),
+            unpremultiply_channel(pixel.blue(), a),
+            a,
+        ]);
+    }
+
+    let rgba_image = RgbaImage::from_raw(width, height, pixels)
+        .expect("Failed to create RgbaImage from pixmap data");
+
+    DynamicImage::ImageRgba8(rgba_image)
+}
@@ -1,14 +1,18 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
-// src/domain/mod.rs
+// noctua-core/src/lib.rs
 //
-// Domain layer: business logic, document abstractions, and viewport management.
+// Noctua core: document loading, rendering, transforms and metadata
+// (raster, vector, portable), without any UI dependencies.
+//
+// The viewer binary uses this crate as its domain layer; other applications
+// can render documents with it directly. Rendered pixels are handed out as
+// `ImageHandle`s, which callers turn into whatever their toolkit displays.
 
 pub mod document;
 
 // Re-export core document types
-#[allow(unused_imports)]
-pub use document::core::content::DocumentContent;
-#[allow(unused_imports)]
+pub use document::core::content::{DocumentContent, DocumentKind};
+pub use document::core::document::{DocResult, ImageHandle};
 pub use document::core::metadata::DocumentMeta;
 
 // Note: Viewport and error handling were removed to reduce code bloat.
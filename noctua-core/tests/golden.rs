@@ -0,0 +1,202 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/tests/golden.rs
+//
+// Golden-image tests: render fixture documents through the real pipeline and
+// compare the pixels with reference PNGs in `tests/golden`.
+//
+// After an intended rendering change, run with `NOCTUA_BLESS=1` to write the
+// current output as the new references, and review the PNGs before committing.
+
+mod common;
+
+use std::path::Path;
+
+use common::fixture;
+use image::{Rgba, RgbaImage};
+use noctua_core::document::core::document::{Renderable, Rotation, Transformable};
+use noctua_core::document::core::limits::OpenLimits;
+use noctua_core::document::types::raster::RasterDocument;
+
+/// YIQ distance between black and white, the largest possible.
+const MAX_DELTA: f64 = 35215.0;
+
+/// Share of `MAX_DELTA` above which two pixels count as different.
+const PIXEL_THRESHOLD: f64 = 0.1;
+
+/// Largest share of pixels that may differ (inclusive), for anti-aliasing
+/// changes between renderer versions.
+const MAX_DIFFERENT: f64 = 0.005;
+
+/// Pixels of two images that differ noticeably.
+struct Diff {
+    different: usize,
+    total: usize,
+    /// Differing pixels in red over a faded copy of the expected image.
+    image: RgbaImage,
+}
+
+impl Diff {
+    fn passes(&self) -> bool {
+        #[allow(clippy::cast_precision_loss)]
+        let share = self.different as f64 / self.total as f64;
+        share <= MAX_DIFFERENT
+    }
+}
+
+/// Pixel blended over white, as RGB in 0..=255.
+fn over_white(pixel: Rgba<u8>) -> [f64; 3] {
+    let alpha = f64::from(pixel[3]) / 255.0;
+    [0, 1, 2].map(|c| 255.0 + (f64::from(pixel[c]) - 255.0) * alpha)
+}
+
+/// Perceptual color distance in YIQ space (luma weighted highest).
+fn color_delta(a: Rgba<u8>, b: Rgba<u8>) -> f64 {
+    let [r1, g1, b1] = over_white(a);
+    let [r2, g2, b2] = over_white(b);
+    let (dr, dg, db) = (r1 - r2, g1 - g2, b1 - b2);
+    let y = dr * 0.298_895_31 + dg * 0.586_622_47 + db * 0.114_482_23;
+    let i = dr * 0.595_977_99 - dg * 0.274_176_10 - db * 0.321_801_89;
+    let q = dr * 0.211_470_17 - dg * 0.522_617_24 + db * 0.311_147_07;
+    0.5053 * y * y + 0.299 * i * i + 0.1957 * q * q
+}
+
+/// Compare two images; None if their sizes differ.
+fn diff(expected: &RgbaImage, actual: &RgbaImage) -> Option<Diff> {
+    if expected.dimensions() != actual.dimensions() {
+        return None;
+    }
+    let mut image = RgbaImage::new(expected.width(), expected.height());
+    let mut different = 0;
+    for ((x, y, &want), &got) in expected.enumerate_pixels().zip(actual.pixels()) {
+        let pixel = if color_delta(want, got) > PIXEL_THRESHOLD * MAX_DELTA {
+            different += 1;
+            Rgba([255, 0, 0, 255])
+        } else {
+            let [r, g, b] = over_white(want).map(|c| (255.0 - (255.0 - c) * 0.2) as u8);
+            Rgba([r, g, b, 255])
+        };
+        image.put_pixel(x, y, pixel);
+    }
+    Some(Diff {
+        different,
+        total: expected.pixels().len(),
+        image,
+    })
+}
+
+/// Current render output of a document as an RGBA image.
+fn rendered(document: &mut impl Renderable, scale: f64) -> RgbaImage {
+    let output = document.render(scale).expect("document renders");
+    let handle = output.handle;
+    RgbaImage::from_raw(handle.width(), handle.height(), handle.pixels().to_vec())
+        .expect("render output is RGBA")
+}
+
+/// Compare `actual` with the golden image `name`, or replace the golden image
+/// when blessing. On a mismatch the output and a diff image are written to the
+/// cargo test directory.
+fn assert_golden(name: &str, actual: &RgbaImage) {
+    let golden = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/golden")
+        .join(format!("{name}.png"));
+    if std::env::var_os("NOCTUA_BLESS").is_some() {
+        actual.save(&golden).expect("golden image written");
+        return;
+    }
+
+    let expected = image::open(&golden)
+        .unwrap_or_else(|e| {
+            panic!(
+                "no golden image {} ({e}); run with NOCTUA_BLESS=1",
+                golden.display()
+            )
+        })
+        .to_rgba8();
+
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("golden");
+    let keep_output = || {
+        std::fs::create_dir_all(&out_dir).expect("output directory created");
+        let path = out_dir.join(format!("{name}.actual.png"));
+        actual.save(&path).expect("render output written");
+        path
+    };
+
+    let Some(diff) = diff(&expected, actual) else {
+        panic!(
+            "{name}: rendered {:?}, golden image is {:?} (output: {})",
+            actual.dimensions(),
+            expected.dimensions(),
+            keep_output().display()
+        );
+    };
+    if !diff.passes() {
+        let output = keep_output();
+        let diff_path = out_dir.join(format!("{name}.diff.png"));
+        diff.image.save(&diff_path).expect("diff image written");
+        panic!(
+            "{name}: {} of {} pixels differ (output: {}, diff: {})",
+            diff.different,
+            diff.total,
+            output.display(),
+            diff_path.display()
+        );
+    }
+}
+
+#[test]
+fn test_diff_tolerates_small_deviations() {
+    let expected = RgbaImage::from_pixel(20, 20, Rgba([200, 40, 40, 255]));
+    let mut actual = expected.clone();
+    // Slight color noise everywhere passes
+    for pixel in actual.pixels_mut() {
+        pixel[0] = 203;
+    }
+    assert!(diff(&expected, &actual).unwrap().passes());
+    // Two of 400 pixels (anti-aliasing) are exactly at the limit of 0.5%
+    actual.put_pixel(0, 0, Rgba([255, 255, 255, 255]));
+    actual.put_pixel(1, 0, Rgba([255, 255, 255, 255]));
+    let result = diff(&expected, &actual).unwrap();
+    assert_eq!(result.different, 2);
+    assert!(result.passes());
+    // A third one is over it
+    actual.put_pixel(2, 0, Rgba([255, 255, 255, 255]));
+    let result = diff(&expected, &actual).unwrap();
+    assert_eq!(result.different, 3);
+    assert!(!result.passes());
+    // Fully transparent pixels look white whatever their color channels hold
+    let white = RgbaImage::from_pixel(2, 2, Rgba([255, 255, 255, 255]));
+    let clear = RgbaImage::from_pixel(2, 2, Rgba([0, 0, 0, 0]));
+    assert_eq!(diff(&white, &clear).unwrap().different, 0);
+    assert!(diff(&white, &RgbaImage::new(2, 3)).is_none());
+}
+
+#[test]
+fn test_raster_rotated() {
+    let mut document = RasterDocument::open(&fixture("quadrants.png"), &OpenLimits::default())
+        .expect("fixture opens");
+    document.rotate(Rotation::Cw90);
+    assert_golden("quadrants-rotate-cw", &rendered(&mut document, 1.0));
+}
+
+#[cfg(feature = "vector")]
+#[test]
+fn test_vector_scaled() {
+    use noctua_core::document::types::vector::VectorDocument;
+
+    // The document raster stays at native size; zoomed views are re-rendered
+    let document = VectorDocument::open(&fixture("shapes.svg"), &OpenLimits::default())
+        .expect("fixture opens");
+    let job = document.zoom_render_job(2.0).expect("zoom renders sharper");
+    let image = job.run().expect("zoom renders").to_rgba8();
+    assert_golden("shapes-2x", &image);
+}
+
+#[cfg(feature = "portable")]
+#[test]
+fn test_portable_page() {
+    use noctua_core::document::types::portable::PortableDocument;
+
+    let mut document = PortableDocument::open(&fixture("page.pdf"), &OpenLimits::default())
+        .expect("fixture opens");
+    assert_golden("page-1", &rendered(&mut document, 1.0));
+}
@@ -0,0 +1,73 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/tests/open_limits.rs
+//
+// Files beyond the size limits are rejected before their pixels are decoded.
+
+mod common;
+
+use common::fixture;
+use noctua_core::document::core::limits::OpenLimits;
+use noctua_core::document::types::raster::RasterDocument;
+
+#[test]
+fn test_decompression_bomb_rejected() {
+    // 1.6 KB of PNG announcing 100000 × 100000 pixels (40 GB decoded)
+    let Err(error) = RasterDocument::open(&fixture("bomb.png"), &OpenLimits::default()) else {
+        panic!("decompression bomb was decoded");
+    };
+    assert!(
+        error
+            .to_string()
+            .starts_with("Image is too large: 100000 × 100000")
+    );
+
+    let small = OpenLimits::new(0, 14_400);
+    assert!(RasterDocument::open(&fixture("quadrants.png"), &small).is_err());
+}
+
+#[cfg(feature = "vector")]
+#[test]
+fn test_huge_svg_rejected() {
+    use noctua_core::document::types::vector::VectorDocument;
+
+    assert!(VectorDocument::open(&fixture("huge.svg"), &OpenLimits::default()).is_err());
+    assert!(VectorDocument::open(&fixture("shapes.svg"), &OpenLimits::default()).is_ok());
+}
+
+#[cfg(feature = "vector")]
+#[test]
+fn test_svg_zoom_render_capped() {
+    use noctua_core::document::types::vector::{MAX_ZOOM_PIXELS, VectorDocument};
+
+    // 16 × 16 units: zoomed to 400% the render is 64 × 64 pixels
+    let doc = VectorDocument::open(&fixture("shapes.svg"), &OpenLimits::default()).unwrap();
+    let job = doc.zoom_render_job(4.0).unwrap();
+    let image = job.run().unwrap();
+    assert_eq!((image.width(), image.height()), (64, 64));
+    // Zoomed out or at 100% the native raster is sharp enough
+    assert!(doc.zoom_render_job(1.0).is_none());
+    assert!(doc.zoom_render_job(0.5).is_none());
+
+    // Far in, the render stops growing at the cap
+    let job = doc.zoom_render_job(100_000.0).unwrap();
+    let side = (MAX_ZOOM_PIXELS as f64).sqrt() / 16.0;
+    assert!((job.scale - side).abs() < 1e-6);
+    let small = OpenLimits::new(1, 14_400);
+    let doc = VectorDocument::open(&fixture("shapes.svg"), &small).unwrap();
+    assert!(doc.zoom_render_job(100_000.0).unwrap().scale <= 64.0);
+}
+
+#[cfg(feature = "portable")]
+#[test]
+fn test_huge_pdf_page_rejected() {
+    use noctua_core::document::types::portable::PortableDocument;
+
+    let Err(error) = PortableDocument::open(&fixture("huge-page.pdf"), &OpenLimits::default())
+    else {
+        panic!("oversized page was rendered");
+    };
+    assert_eq!(
+        error.to_string(),
+        "Page is too large: 20000 × 20000 points (limit 14400)"
+    );
+}
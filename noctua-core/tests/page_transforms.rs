@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/tests/page_transforms.rs
+//
+// Pages of a PDF are rotated and flipped one by one.
+
+#![cfg(feature = "portable")]
+
+mod common;
+
+use common::fixture;
+use image::{GenericImageView, Rgba};
+use noctua_core::document::core::cancel::CancelToken;
+use noctua_core::document::core::document::{
+    FlipDirection, MultiPage, Rotation, RotationMode, TransformState, Transformable,
+};
+use noctua_core::document::core::limits::OpenLimits;
+use noctua_core::document::types::portable::PortableDocument;
+
+#[test]
+fn test_transforms_are_per_page() {
+    let mut document = PortableDocument::open(&fixture("pages.pdf"), &OpenLimits::default())
+        .expect("fixture opens");
+    let upright = document.dimensions();
+
+    document.rotate(Rotation::Cw90);
+    document.flip(FlipDirection::Horizontal);
+    let turned = TransformState {
+        rotation: RotationMode::Standard(Rotation::Cw90),
+        flip_h: true,
+        flip_v: false,
+    };
+    assert_eq!(document.transform_state(), turned);
+    assert_eq!(document.dimensions(), (upright.1, upright.0));
+
+    // The second page keeps its own (default) transform
+    document.go_to_page(1).unwrap();
+    assert_eq!(document.transform_state(), TransformState::default());
+    assert_eq!(document.dimensions(), upright);
+    assert_eq!(document.page_transform(0), turned);
+
+    document.go_to_page(0).unwrap();
+    assert_eq!(document.dimensions(), (upright.1, upright.0));
+
+    // Thumbnails follow their page
+    document.generate_thumbnail_page(0);
+    document.generate_thumbnail_page(1);
+    let size = |page| {
+        let thumbnail = document.transformed_thumbnail(page).unwrap();
+        (thumbnail.width(), thumbnail.height())
+    };
+    let (width, height) = size(1);
+    assert_eq!(size(0), (height, width));
+}
+
+#[test]
+fn test_thumbnails_follow_visible_pages() {
+    let mut document = PortableDocument::open(&fixture("pages.pdf"), &OpenLimits::default())
+        .expect("fixture opens");
+    let job = document.thumbnail_job().unwrap();
+
+    // The second page is in view, so it comes first
+    job.queue.set_visible(1..2);
+    let mut thumbnails = Vec::new();
+    job.run(|page, thumbnail| {
+        thumbnails.push((page, thumbnail));
+        true
+    })
+    .unwrap();
+    let pages: Vec<_> = thumbnails.iter().map(|(page, _)| *page).collect();
+    assert_eq!(pages, [1, 0]);
+
+    for (page, thumbnail) in thumbnails {
+        assert!(document.add_thumbnail(page, thumbnail.clone()));
+        assert!(!document.add_thumbnail(page, thumbnail));
+    }
+    assert_eq!(document.thumbnails_loaded(), 2);
+    assert!(document.thumbnail_job().is_none());
+}
+
+#[test]
+fn test_zoom_render_tiers() {
+    // 24 × 16 points, rendered at 48 × 32 pixels
+    let mut document = PortableDocument::open(&fixture("page.pdf"), &OpenLimits::default())
+        .expect("fixture opens");
+    assert!(document.zoom_render_job(1.0).is_none());
+    assert!(document.zoom_render_job(0.5).is_none());
+
+    // Zooming renders at the next tier up: 150% and 200% share one render
+    let job = document.zoom_render_job(1.5).unwrap();
+    assert_eq!(job.quality, 4.0);
+    assert_eq!(document.zoom_render_job(2.0).unwrap(), job);
+    assert_eq!(document.zoom_render_job(3.0).unwrap().scale(), 4.0);
+    assert_eq!(document.zoom_render_job(100.0).unwrap().quality, 32.0);
+
+    // The render is turned like the page
+    document.rotate(Rotation::Cw90);
+    let image = document.zoom_render_job(2.0).unwrap().run().unwrap();
+    assert_eq!((image.width(), image.height()), (64, 96));
+
+    // The tiers stay within the limits
+    let limits = OpenLimits {
+        max_pixels: 10_000,
+        ..OpenLimits::default()
+    };
+    let small = PortableDocument::open(&fixture("page.pdf"), &limits).expect("fixture opens");
+    assert_eq!(small.zoom_render_job(100.0).unwrap().quality, 4.0);
+
+    // A cropped page is only scaled up
+    document.crop(0, 0, 20, 20).unwrap();
+    assert!(document.zoom_render_job(4.0).is_none());
+}
+
+#[test]
+fn test_large_page_is_refined() {
+    // 1800 × 1800 points, a blue top half over a red one
+    let mut document = PortableDocument::open(&fixture("large-page.pdf"), &OpenLimits::default())
+        .expect("fixture opens");
+    assert!(document.is_refining());
+    assert_eq!(document.dimensions(), (3600, 3600));
+    assert!(document.zoom_render_job(2.0).is_none());
+
+    let job = document.refine_job().unwrap();
+    let token = CancelToken::new();
+    token.cancel();
+    assert!(job.run_cancellable(&token).is_err());
+
+    // The bands are stacked in order
+    let rendered = job.run().unwrap();
+    assert_eq!(rendered.dimensions(), (3600, 3600));
+    assert_eq!(rendered.get_pixel(1800, 10), Rgba([0, 0, 255, 255]));
+    assert_eq!(rendered.get_pixel(1800, 3590), Rgba([255, 0, 0, 255]));
+
+    // A render of the page before it was turned is dropped
+    document.rotate(Rotation::Cw180);
+    assert!(!document.finish_refine(&job, rendered.clone()));
+    document.rotate(Rotation::None);
+    assert!(document.finish_refine(&job, rendered));
+    assert!(!document.is_refining());
+    assert!(document.refine_job().is_none());
+}
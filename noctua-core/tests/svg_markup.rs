@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// noctua-core/tests/svg_markup.rs
+//
+// Turned, flipped and cropped SVGs stay vectors that render like the raster.
+
+#![cfg(feature = "vector")]
+
+mod common;
+
+use common::fixture;
+use noctua_core::document::core::document::{FlipDirection, Rotation, Transformable};
+use noctua_core::document::core::limits::OpenLimits;
+use noctua_core::document::types::vector::VectorDocument;
+use resvg::tiny_skia::{Pixmap, Transform};
+use resvg::usvg::{Options, Tree};
+
+/// Render markup at its own size, as straight RGBA like the raster.
+fn render(markup: &str) -> (u32, u32, Vec<u8>) {
+    let tree = Tree::from_str(markup, &Options::default()).expect("markup parses");
+    let size = tree.size().to_int_size();
+    let mut pixmap = Pixmap::new(size.width(), size.height()).unwrap();
+    resvg::render(&tree, Transform::identity(), &mut pixmap.as_mut());
+    let pixels = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+    (size.width(), size.height(), pixels)
+}
+
+#[test]
+fn test_markup_matches_raster() {
+    let mut doc = VectorDocument::open(&fixture("shapes.svg"), &OpenLimits::default()).unwrap();
+    let (width, height) = doc.dimensions();
+    let source = std::fs::read_to_string(fixture("shapes.svg")).unwrap();
+    assert_eq!(doc.svg_markup(), source);
+
+    doc.rotate(Rotation::Cw90);
+    doc.flip(FlipDirection::Horizontal);
+    doc.crop(2, 3, height - 4, width - 6).unwrap();
+
+    let (markup_width, markup_height, pixels) = render(&doc.svg_markup());
+    assert_eq!((markup_width, markup_height), doc.dimensions());
+    assert_eq!(pixels, doc.rendered.to_rgba8().into_raw());
+}
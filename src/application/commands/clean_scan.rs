@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/clean_scan.rs
+//
+// Clean scan command: remove specks and whiten the paper of the current image.
+
+use crate::application::DocumentManager;
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::scan_cleanup::ScanCleanup;
+
+/// Clean scan command.
+///
+/// Removes dark specks from the current raster image and brings its paper
+/// background to white; the size stays the same.
+pub struct CleanScanCommand {
+    cleanup: ScanCleanup,
+}
+
+impl CleanScanCommand {
+    /// Create a new clean scan command.
+    #[must_use]
+    pub fn new(cleanup: ScanCleanup) -> Self {
+        Self { cleanup }
+    }
+
+    /// Execute the cleanup on the document manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no document is open or it is not a raster image.
+    pub fn execute(&self, manager: &mut DocumentManager) -> DocResult<()> {
+        manager
+            .current_document_mut()
+            .ok_or_else(|| anyhow::anyhow!("No document open"))?
+            .clean_scan(self.cleanup)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clean_scan_without_document_fails() {
+        let mut manager = DocumentManager::new();
+        assert!(
+            CleanScanCommand::new(ScanCleanup::default())
+                .execute(&mut manager)
+                .is_err()
+        );
+    }
+}
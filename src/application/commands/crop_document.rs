@@ -8,7 +8,6 @@ use cosmic::iced::{ContentFit, Size, Vector};
 use crate::application::DocumentManager;
 use crate::domain::document::core::content::DocumentKind;
 use crate::domain::document::core::document::DocResult;
-use crate::domain::document::operations::CropRegion;
 
 /// Crop document command.
 ///
@@ -39,22 +38,20 @@ impl CropDocumentCommand {
 
     /// Create a crop command from canvas coordinates.
     ///
-    /// Converts canvas-space coordinates to image-space pixels based on
-    /// the current view state (scale, pan, content fit).
+    /// Converts canvas-space coordinates (logical pixels, possibly
+    /// fractional) to image-space pixels based on the current view state
+    /// (scale, pan, content fit).
     ///
     /// # Errors
     ///
     /// Returns an error if the crop region is invalid or outside image bounds.
     pub fn from_canvas_selection(
-        crop_region: &CropRegion,
+        canvas_rect: (f32, f32, f32, f32),
         canvas_size: Size,
         image_size: Size,
         scale: f32,
         pan_offset: Vector,
     ) -> Result<Self, String> {
-        let (x, y, w, h) = crop_region.as_tuple();
-        let canvas_rect = (x as f32, y as f32, w as f32, h as f32);
-
         // Convert canvas coordinates to image pixel coordinates
         let image_rect = Self::canvas_rect_to_image_rect(
             canvas_rect,
@@ -137,22 +134,7 @@ impl CropDocumentCommand {
         offset: Vector,
         content_fit: ContentFit,
     ) -> (f32, f32) {
-        // Calculate displayed image dimensions based on ContentFit
-        let (display_w, display_h) = match content_fit {
-            ContentFit::Contain => {
-                let aspect = image_size.width / image_size.height;
-                let canvas_aspect = canvas_size.width / canvas_size.height;
-
-                if aspect > canvas_aspect {
-                    // Limited by width
-                    (canvas_size.width, canvas_size.width / aspect)
-                } else {
-                    // Limited by height
-                    (canvas_size.height * aspect, canvas_size.height)
-                }
-            }
-            _ => (image_size.width, image_size.height),
-        };
+        let (display_w, display_h) = Self::display_size(canvas_size, image_size, content_fit);
 
         // Apply scale
         let scaled_w = display_w * scale;
@@ -173,6 +155,73 @@ impl CropDocumentCommand {
         (pixel_x, pixel_y)
     }
 
+    /// Convert this pixel region to canvas coordinates for the crop overlay.
+    ///
+    /// Inverse of [`Self::from_canvas_selection`] for the same view state.
+    #[must_use]
+    pub fn to_canvas_selection(
+        &self,
+        canvas_size: Size,
+        image_size: Size,
+        scale: f32,
+        pan_offset: Vector,
+    ) -> (f32, f32, f32, f32) {
+        let to_canvas = |px: u32, py: u32| {
+            Self::image_to_canvas_coords(
+                px as f32,
+                py as f32,
+                canvas_size,
+                image_size,
+                scale,
+                pan_offset,
+                ContentFit::Contain,
+            )
+        };
+        let (x1, y1) = to_canvas(self.x, self.y);
+        let (x2, y2) = to_canvas(self.x + self.width, self.y + self.height);
+        (x1, y1, x2 - x1, y2 - y1)
+    }
+
+    /// Convert a single point from image coordinates to canvas coordinates.
+    fn image_to_canvas_coords(
+        px: f32,
+        py: f32,
+        canvas_size: Size,
+        image_size: Size,
+        scale: f32,
+        offset: Vector,
+        content_fit: ContentFit,
+    ) -> (f32, f32) {
+        let (display_w, display_h) = Self::display_size(canvas_size, image_size, content_fit);
+
+        let center_x = (canvas_size.width - display_w * scale) / 2.0;
+        let center_y = (canvas_size.height - display_h * scale) / 2.0;
+
+        (
+            px / image_size.width * display_w * scale + center_x + offset.x,
+            py / image_size.height * display_h * scale + center_y + offset.y,
+        )
+    }
+
+    /// Displayed image dimensions (before zoom) based on `ContentFit`.
+    fn display_size(canvas_size: Size, image_size: Size, content_fit: ContentFit) -> (f32, f32) {
+        match content_fit {
+            ContentFit::Contain => {
+                let aspect = image_size.width / image_size.height;
+                let canvas_aspect = canvas_size.width / canvas_size.height;
+
+                if aspect > canvas_aspect {
+                    // Limited by width
+                    (canvas_size.width, canvas_size.width / aspect)
+                } else {
+                    // Limited by height
+                    (canvas_size.height * aspect, canvas_size.height)
+                }
+            }
+            _ => (image_size.width, image_size.height),
+        }
+    }
+
     /// Execute the crop command on the document manager.
     ///
     /// # Errors
@@ -225,4 +274,38 @@ mod tests {
         assert_eq!(cmd.width, 100);
         assert_eq!(cmd.height, 150);
     }
+
+    #[test]
+    fn test_canvas_selection_roundtrip() {
+        let canvas = Size::new(800.0, 600.0);
+        let image = Size::new(400.0, 300.0);
+        let pan = Vector::new(12.0, -8.0);
+        let cmd = CropDocumentCommand::new(10, 20, 100, 50);
+
+        let (x, y, w, h) = cmd.to_canvas_selection(canvas, image, 1.0, pan);
+        assert_eq!((x, y, w, h), (32.0, 32.0, 200.0, 100.0));
+
+        let back =
+            CropDocumentCommand::from_canvas_selection((x, y, w, h), canvas, image, 1.0, pan)
+                .unwrap();
+        assert_eq!((back.x, back.y, back.width, back.height), (10, 20, 100, 50));
+    }
+
+    #[test]
+    fn test_fractional_canvas_selection() {
+        // 1000 px wide image in a 400 px canvas: a logical pixel is 2.5
+        // image pixels, so dropping the fractions of the selection would
+        // shift the crop
+        let canvas = Size::new(400.0, 300.0);
+        let image = Size::new(1000.0, 750.0);
+        let cmd = CropDocumentCommand::from_canvas_selection(
+            (40.8, 20.4, 120.8, 80.4),
+            canvas,
+            image,
+            1.0,
+            Vector::ZERO,
+        )
+        .unwrap();
+        assert_eq!((cmd.x, cmd.y, cmd.width, cmd.height), (102, 51, 302, 201));
+    }
 }
@@ -0,0 +1,78 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/date_shift.rs
+//
+// Date shift command: correct EXIF capture times of all images in the folder.
+
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::date_shift::{self, DateShiftEntry};
+
+/// Where shifted dates are written to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DateShiftTarget {
+    /// Patch the EXIF fields inside the image files.
+    #[default]
+    File,
+    /// Write XMP sidecar files and leave the images untouched.
+    Sidecar,
+}
+
+/// Shift EXIF dates command.
+///
+/// Applies a fixed offset (in seconds) to the capture time of every image
+/// in the current folder that carries EXIF dates.
+#[derive(Debug, Clone, Copy)]
+pub struct ShiftDatesCommand {
+    /// Offset in seconds (negative moves dates into the past).
+    pub offset: i64,
+    /// Write-back target.
+    pub target: DateShiftTarget,
+}
+
+impl ShiftDatesCommand {
+    /// Create a new date shift command.
+    #[must_use]
+    pub fn new(offset: i64, target: DateShiftTarget) -> Self {
+        Self { offset, target }
+    }
+
+    /// Execute the date shift for the dated files of the folder.
+    ///
+    /// Blocks until all files are written, calling `progress` with the
+    /// number handled so far after each; returning false from it stops
+    /// before the next file. Returns the number of files updated. Files that
+    /// fail are logged and skipped.
+    pub fn execute(
+        &self,
+        entries: &[DateShiftEntry],
+        mut progress: impl FnMut(usize) -> bool,
+    ) -> DocResult<usize> {
+        if self.offset == 0 {
+            return Ok(0);
+        }
+        if entries.is_empty() {
+            return Err(anyhow::anyhow!("No images with EXIF dates in folder"));
+        }
+
+        let mut updated = 0;
+        for (index, entry) in entries.iter().enumerate() {
+            let result = match self.target {
+                DateShiftTarget::File => {
+                    date_shift::shift_file_dates(&entry.path, self.offset).map(|_| ())
+                }
+                DateShiftTarget::Sidecar => {
+                    date_shift::write_xmp_sidecar(&entry.path, entry.original.shifted(self.offset))
+                        .map(|_| ())
+                }
+            };
+
+            match result {
+                Ok(()) => updated += 1,
+                Err(e) => tracing::warn!("Date shift failed for {}: {e}", entry.path.display()),
+            }
+            if !progress(index + 1) {
+                break;
+            }
+        }
+        Ok(updated)
+    }
+}
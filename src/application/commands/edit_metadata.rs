@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/edit_metadata.rs
+//
+// Edit metadata command: write author, copyright, description, rating and
+// capture date into the current image.
+
+use std::path::PathBuf;
+
+use crate::application::DocumentManager;
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::metadata_edit::{self, MetadataFields};
+
+/// Edit metadata command.
+///
+/// Rewrites the EXIF block of a JPEG or PNG file in place; the pixel data
+/// is not decoded or re-encoded.
+pub struct EditMetadataCommand {
+    /// Fields to store (empty ones are removed).
+    pub fields: MetadataFields,
+    /// File to update.
+    pub path: PathBuf,
+}
+
+impl EditMetadataCommand {
+    /// Create a new edit metadata command.
+    #[must_use]
+    pub fn new(fields: MetadataFields, path: PathBuf) -> Self {
+        Self { fields, path }
+    }
+
+    /// Execute the edit metadata command.
+    pub fn execute(&self, manager: &mut DocumentManager) -> DocResult<()> {
+        metadata_edit::write(&self.path, &self.fields)?;
+        tracing::info!("Updated metadata of {}", self.path.display());
+
+        if manager.current_path() == Some(self.path.as_path()) {
+            manager.refresh_metadata();
+        }
+        Ok(())
+    }
+}
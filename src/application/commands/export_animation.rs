@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/export_animation.rs
+//
+// Export animation command: assemble frames into an animated GIF or WebP.
+
+use std::path::PathBuf;
+
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::animation::{self, AnimationFormat, AnimationSettings};
+
+/// Export animation command.
+///
+/// Writes either a numbered frame sequence or the frames of a GIF (to change
+/// its speed or loop count) as a new animated GIF or WebP.
+pub struct ExportAnimationCommand {
+    /// Frame images, or a single GIF whose frames are used.
+    pub sources: Vec<PathBuf>,
+    /// Playback settings.
+    pub settings: AnimationSettings,
+    /// Format to write.
+    pub format: AnimationFormat,
+    /// File to write.
+    pub target: PathBuf,
+}
+
+impl ExportAnimationCommand {
+    /// Create a new export animation command.
+    #[must_use]
+    pub fn new(
+        sources: Vec<PathBuf>,
+        settings: AnimationSettings,
+        format: AnimationFormat,
+        target: PathBuf,
+    ) -> Self {
+        Self {
+            sources,
+            settings,
+            format,
+            target,
+        }
+    }
+
+    /// Execute the export.
+    ///
+    /// Returns the number of frames written.
+    pub fn execute(&self) -> DocResult<usize> {
+        if self.sources.iter().any(|source| source == &self.target) {
+            return Err(anyhow::anyhow!("Refusing to overwrite a source image"));
+        }
+
+        let count = match self.sources.as_slice() {
+            [gif] if animation::is_gif(gif) => animation::write_animation(
+                animation::decode_frames(gif)?.into_iter().map(Ok),
+                self.settings,
+                self.format,
+                &self.target,
+            )?,
+            sources => animation::write_animation(
+                sources
+                    .iter()
+                    .map(|path| image::open(path).map_err(Into::into)),
+                self.settings,
+                self.format,
+                &self.target,
+            )?,
+        };
+
+        tracing::info!("Wrote {count} frames to {}", self.target.display());
+        Ok(count)
+    }
+}
@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/geotag.rs
+//
+// Geotag command: set or clear the GPS location of one or more images.
+
+use std::path::{Path, PathBuf};
+
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::geotag::{self, GpsCoordinate};
+
+/// Geotag command.
+///
+/// Writes the EXIF GPS tags of the given files (JPEG and PNG).
+/// A `None` location removes existing GPS tags.
+pub struct GeotagCommand {
+    /// Location to store, or `None` to clear it.
+    pub location: Option<GpsCoordinate>,
+    /// Files to update.
+    pub paths: Vec<PathBuf>,
+}
+
+impl GeotagCommand {
+    /// Create a new geotag command.
+    #[must_use]
+    pub fn new(location: Option<GpsCoordinate>, paths: Vec<PathBuf>) -> Self {
+        Self { location, paths }
+    }
+
+    /// Execute the geotag command.
+    ///
+    /// Blocks until all files are written, calling `handled` after each
+    /// with the number handled so far and, if the file could not be
+    /// updated, the reason; returning false from it stops before the next
+    /// file. Returns the number of files updated. Files that fail are
+    /// logged and skipped; an error is returned only if no file could be
+    /// updated.
+    pub fn execute(
+        &self,
+        mut handled: impl FnMut(usize, Option<(&Path, &anyhow::Error)>) -> bool,
+    ) -> DocResult<usize> {
+        let mut updated = 0;
+        let mut last_error = None;
+
+        for (index, path) in self.paths.iter().enumerate() {
+            let failed = match geotag::write_location(path, self.location) {
+                Ok(()) => {
+                    updated += 1;
+                    None
+                }
+                Err(e) => {
+                    tracing::warn!("Geotag failed for {}: {e}", path.display());
+                    Some(e)
+                }
+            };
+            let go_on = handled(index + 1, failed.as_ref().map(|e| (path.as_path(), e)));
+            if failed.is_some() {
+                last_error = failed;
+            }
+            if !go_on {
+                break;
+            }
+        }
+
+        match last_error {
+            Some(e) if updated == 0 => Err(e),
+            _ => Ok(updated),
+        }
+    }
+}
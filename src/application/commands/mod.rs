@@ -3,8 +3,17 @@
 //
 // Application commands: document operations and navigation.
 
+pub mod clean_scan;
 pub mod crop_document;
+pub mod date_shift;
+pub mod edit_metadata;
+pub mod export_animation;
+pub mod geotag;
 pub mod navigate;
 pub mod open_document;
+pub mod privacy_export;
+pub mod resize_document;
+pub mod run_macro;
 pub mod save_document;
+pub mod straighten_document;
 pub mod transform_document;
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/privacy_export.rs
+//
+// Privacy export command: write blurred copies of selected images.
+
+use std::path::PathBuf;
+
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::export_profile::ExportProfile;
+use crate::domain::document::operations::privacy::{self, BlurRegion};
+
+/// Privacy export command.
+///
+/// Blurs the same relative regions in every selected image and writes the
+/// results as new files into an output folder. Originals are never modified.
+pub struct PrivacyExportCommand {
+    /// Regions to blur.
+    pub regions: Vec<BlurRegion>,
+    /// Images to export.
+    pub paths: Vec<PathBuf>,
+    /// Folder receiving the copies.
+    pub output_dir: PathBuf,
+    /// Export profile for format, size and naming (None = keep the original format).
+    pub profile: Option<ExportProfile>,
+}
+
+impl PrivacyExportCommand {
+    /// Create a new privacy export command.
+    #[must_use]
+    pub fn new(regions: Vec<BlurRegion>, paths: Vec<PathBuf>, output_dir: PathBuf) -> Self {
+        Self {
+            regions,
+            paths,
+            output_dir,
+            profile: None,
+        }
+    }
+
+    /// Write the copies with an export profile (metadata is always stripped).
+    #[must_use]
+    pub fn with_profile(mut self, profile: Option<ExportProfile>) -> Self {
+        self.profile = profile.map(|profile| ExportProfile {
+            keep_metadata: false,
+            ..profile
+        });
+        self
+    }
+
+    /// Execute the export.
+    ///
+    /// Blocks until all copies are written, calling `progress` with the
+    /// number of images handled so far after each; returning false from it
+    /// stops before the next image. Returns the number of copies written.
+    /// Images that fail are logged and skipped.
+    pub fn execute(&self, mut progress: impl FnMut(usize) -> bool) -> DocResult<usize> {
+        if self.regions.is_empty() {
+            return Err(anyhow::anyhow!("No regions to blur"));
+        }
+        std::fs::create_dir_all(&self.output_dir)?;
+
+        let mut written = 0;
+        for (index, path) in self.paths.iter().enumerate() {
+            let result = match &self.profile {
+                Some(profile) => image::open(path).map_err(Into::into).and_then(|mut img| {
+                    privacy::blur_regions(&mut img, &self.regions);
+                    profile.write_into(img, path, index + 1, &self.output_dir)
+                }),
+                None => privacy::export_redacted(path, &self.regions, &self.output_dir),
+            };
+            match result {
+                Ok(target) => {
+                    tracing::info!("Privacy export: {}", target.display());
+                    written += 1;
+                }
+                Err(e) => tracing::warn!("Privacy export failed for {}: {e}", path.display()),
+            }
+            if !progress(index + 1) {
+                break;
+            }
+        }
+
+        Ok(written)
+    }
+}
@@ -0,0 +1,55 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/resize_document.rs
+//
+// Resize document command: resample the current image to a new pixel size.
+
+use crate::application::DocumentManager;
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::resize::Resize;
+
+/// Resize document command.
+///
+/// Resamples the pixels of the current raster image with the chosen filter
+/// and updates the shown metadata to the new size.
+pub struct ResizeDocumentCommand {
+    resize: Resize,
+}
+
+impl ResizeDocumentCommand {
+    /// Create a new resize document command.
+    #[must_use]
+    pub fn new(resize: Resize) -> Self {
+        Self { resize }
+    }
+
+    /// Execute the resize command on the document manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no document is open or it is not a raster image.
+    pub fn execute(&self, manager: &mut DocumentManager) -> DocResult<()> {
+        manager
+            .current_document_mut()
+            .ok_or_else(|| anyhow::anyhow!("No document open"))?
+            .resize(self.resize)?;
+        manager.refresh_metadata();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::operations::resize::ResampleFilter;
+
+    #[test]
+    fn test_resize_without_document_fails() {
+        let mut manager = DocumentManager::new();
+        let resize = Resize::new(640, 480, ResampleFilter::Lanczos3).unwrap();
+        assert!(
+            ResizeDocumentCommand::new(resize)
+                .execute(&mut manager)
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/run_macro.rs
+//
+// Run macro command: replay an edit macro on images and write the results.
+
+use std::path::PathBuf;
+
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::edit_macro::{self, MacroStep};
+use crate::domain::document::operations::export_profile::ExportProfile;
+
+/// Run macro command.
+///
+/// Applies the same steps to every image and writes the results as new
+/// files into an output folder. Originals are never modified.
+pub struct RunMacroCommand {
+    /// Steps to replay.
+    pub steps: Vec<MacroStep>,
+    /// Images to process.
+    pub paths: Vec<PathBuf>,
+    /// Folder receiving the results.
+    pub output_dir: PathBuf,
+    /// Export profile for format, size and naming (replaces `export` steps).
+    pub profile: Option<ExportProfile>,
+}
+
+impl RunMacroCommand {
+    /// Create a new run macro command.
+    #[must_use]
+    pub fn new(steps: Vec<MacroStep>, paths: Vec<PathBuf>, output_dir: PathBuf) -> Self {
+        Self {
+            steps,
+            paths,
+            output_dir,
+            profile: None,
+        }
+    }
+
+    /// Write the results with an export profile.
+    #[must_use]
+    pub fn with_profile(mut self, profile: Option<ExportProfile>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Execute the macro.
+    ///
+    /// Blocks until all images are written, calling `progress` with the
+    /// number handled so far after each; returning false from it stops
+    /// before the next image. Returns the number of images written. Images
+    /// that fail (e.g. documents that are not raster images) are logged and
+    /// skipped.
+    pub fn execute(&self, mut progress: impl FnMut(usize) -> bool) -> DocResult<usize> {
+        if self.steps.is_empty() {
+            return Err(anyhow::anyhow!("The macro has no steps"));
+        }
+        std::fs::create_dir_all(&self.output_dir)?;
+
+        let mut written = 0;
+        for (index, path) in self.paths.iter().enumerate() {
+            let result = match &self.profile {
+                Some(profile) => image::open(path).map_err(Into::into).and_then(|img| {
+                    let img = edit_macro::apply_steps(img, &self.steps);
+                    profile.write_into(img, path, index + 1, &self.output_dir)
+                }),
+                None => edit_macro::run_on_file(path, &self.steps, &self.output_dir),
+            };
+            match result {
+                Ok(target) => {
+                    tracing::info!("Macro output: {}", target.display());
+                    written += 1;
+                }
+                Err(e) => tracing::warn!("Macro failed for {}: {e}", path.display()),
+            }
+            if !progress(index + 1) {
+                break;
+            }
+        }
+
+        Ok(written)
+    }
+}
@@ -2,58 +2,210 @@
 // src/application/commands/save_document.rs
 //
 // Save document command: export document to a file.
-// Reserved for future implementation - not yet used.
 
-#![allow(dead_code)]
-
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use crate::application::document_manager::DocumentManager;
+use crate::domain::document::core::content::DocumentContent;
 use crate::domain::document::core::document::DocResult;
-use crate::domain::document::operations::export::ExportFormat;
+use crate::domain::document::core::metadata::Resolution;
+use crate::domain::document::operations::alpha::AlphaMode;
+use crate::domain::document::operations::exif_write::ExifCopy;
+use crate::domain::document::operations::export::{self, ExportFormat, ImageExportOptions};
+use crate::domain::document::operations::export_profile::ExportProfile;
 
 /// Save document command.
+///
+/// Writes the current raster image, including all applied edits and color
+/// adjustments. Vector documents saved as SVG keep their vector data, with
+/// the rotation, flips and crop wrapped around the original drawing; in
+/// other formats they are rasterized at their natural size. The EXIF data
+/// of the original is carried over to JPEG and PNG files unless stripped.
 pub struct SaveDocumentCommand {
-    /// Target format for export.
+    /// Target format for export (None = from the file extension).
     format: Option<ExportFormat>,
+    /// Quality for lossy formats (ignored when a profile is set).
+    quality: u8,
+    /// Print resolution to declare (None = the file's; ignored when a profile
+    /// is set).
+    resolution: Option<Resolution>,
+    /// Alpha mode (ignored when a profile is set).
+    alpha: AlphaMode,
+    /// Export profile for format, size and quality.
+    profile: Option<ExportProfile>,
+    /// Leave out all metadata, whatever the profile says.
+    strip_metadata: bool,
 }
 
 impl SaveDocumentCommand {
     /// Create a new save document command with automatic format detection.
     #[must_use]
     pub fn new() -> Self {
-        Self { format: None }
+        Self {
+            format: None,
+            quality: ImageExportOptions::default().quality,
+            resolution: None,
+            alpha: AlphaMode::Straight,
+            profile: None,
+            strip_metadata: false,
+        }
     }
 
     /// Create a save document command with a specific format.
     #[must_use]
+    #[allow(dead_code)]
     pub fn with_format(format: ExportFormat) -> Self {
         Self {
             format: Some(format),
+            ..Self::new()
+        }
+    }
+
+    /// Write this format; its extension replaces the one of the path.
+    #[must_use]
+    pub fn format(mut self, format: Option<ExportFormat>) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Quality (1-100) for JPEG.
+    #[must_use]
+    pub fn quality(mut self, quality: u8) -> Self {
+        self.quality = quality.clamp(1, 100);
+        self
+    }
+
+    /// Declare a print resolution in the saved file.
+    #[must_use]
+    pub fn resolution(mut self, resolution: Option<Resolution>) -> Self {
+        self.resolution = resolution;
+        self
+    }
+
+    /// Write straight or premultiplied alpha.
+    #[must_use]
+    pub fn alpha(mut self, alpha: AlphaMode) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Save with an export profile; its format replaces the extension of the path.
+    #[must_use]
+    pub fn profile(mut self, profile: Option<ExportProfile>) -> Self {
+        self.profile = profile;
+        self
+    }
+
+    /// Write no metadata at all.
+    #[must_use]
+    pub fn strip_metadata(mut self, strip: bool) -> Self {
+        self.strip_metadata = strip;
+        self
+    }
+
+    /// File that is written when saving to `path`: the extension is replaced
+    /// by the one of the profile or chosen format.
+    #[must_use]
+    pub fn target(&self, path: &Path) -> PathBuf {
+        match self
+            .profile
+            .as_ref()
+            .map(|profile| profile.format)
+            .or(self.format)
+        {
+            Some(format) => path.with_extension(format.extension()),
+            None => path.to_path_buf(),
         }
     }
 
     /// Execute the save document command.
-    pub fn execute(&self, manager: &DocumentManager, path: &Path) -> DocResult<()> {
-        let _document = manager
+    ///
+    /// Returns the path that was written (see `target`).
+    pub fn execute(&self, manager: &DocumentManager, path: &Path) -> DocResult<PathBuf> {
+        let document = manager
             .current_document()
             .ok_or_else(|| anyhow::anyhow!("No document loaded"))?;
+        // SVG stays SVG, rather than a raster in an unknown format
+        #[cfg(feature = "vector")]
+        if let DocumentContent::Vector(vector) = document
+            && self.profile.is_none()
+            && self.format.or_else(|| ExportFormat::from_path(path)) == Some(ExportFormat::Svg)
+        {
+            let target = self.target(path);
+            std::fs::write(&target, vector.svg_markup())?;
+            tracing::info!("Saved {} as SVG", target.display());
+            return Ok(target);
+        }
+
+        let img = match document {
+            DocumentContent::Raster(raster) => raster.adjusted_image().clone(),
+            #[cfg(feature = "vector")]
+            DocumentContent::Vector(vector) => vector.rendered.clone(),
+            #[allow(unreachable_patterns)]
+            _ => {
+                return Err(anyhow::anyhow!(
+                    "Saving is only supported for raster and vector images"
+                ));
+            }
+        };
+
+        let target = self.target(path);
+        if let Some(profile) = &self.profile {
+            let metadata = self.metadata_to_copy(manager, profile.format, profile.keep_metadata);
+            profile.write(img, &target)?;
+            write_metadata(metadata, &target);
+            return Ok(target);
+        }
 
         // Detect format from path or use specified format
         let format = self
             .format
             .or_else(|| ExportFormat::from_path(path))
             .ok_or_else(|| anyhow::anyhow!("Could not determine export format"))?;
+        let options = ImageExportOptions {
+            quality: self.quality,
+            preserve_metadata: !self.strip_metadata,
+            resolution: self
+                .resolution
+                .or_else(|| manager.current_metadata().and_then(|meta| meta.resolution)),
+            alpha: self.alpha,
+        };
+        // Read first: the original may be the file that is replaced
+        let metadata = self.metadata_to_copy(manager, format, options.preserve_metadata);
+        export::export_image(&img, &target, format, &options)?;
+        write_metadata(metadata, &target);
 
-        // TODO: Implement actual save logic
-        // This would involve:
-        // 1. Getting the rendered image from the document
-        // 2. Applying any necessary transformations
-        // 3. Exporting to the target format
+        tracing::info!("Saved {} as {:?}", target.display(), format);
+        Ok(target)
+    }
 
-        log::info!("Save to {} as {:?}", path.display(), format);
+    /// EXIF fields of the original to write into a `format` file, if
+    /// metadata is kept and the format can take it.
+    fn metadata_to_copy(
+        &self,
+        manager: &DocumentManager,
+        format: ExportFormat,
+        keep: bool,
+    ) -> ExifCopy {
+        let writable = matches!(format, ExportFormat::Jpeg | ExportFormat::Png);
+        let Some(source) = manager.current_path() else {
+            return ExifCopy::default();
+        };
+        if self.strip_metadata || !keep || !writable {
+            return ExifCopy::default();
+        }
+        ExifCopy::read(source).unwrap_or_else(|e| {
+            tracing::warn!("Metadata of {} not copied: {e}", source.display());
+            ExifCopy::default()
+        })
+    }
+}
 
-        Err(anyhow::anyhow!("Save operation not yet implemented"))
+/// Write the copied metadata into the saved file; the image is saved even
+/// if this fails.
+fn write_metadata(metadata: ExifCopy, target: &Path) {
+    if let Err(e) = metadata.write_to(target) {
+        tracing::warn!("Failed to copy metadata to {}: {e}", target.display());
     }
 }
 
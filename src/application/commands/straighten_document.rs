@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/commands/straighten_document.rs
+//
+// Straighten document command: rotate the current image by a fine angle.
+
+use crate::application::DocumentManager;
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::straighten::FineRotation;
+
+/// Straighten document command.
+///
+/// Rotates the pixels of the current raster image by a fine angle; the empty
+/// corners stay transparent, repeat the edges or are cropped off.
+pub struct StraightenDocumentCommand {
+    rotation: FineRotation,
+}
+
+impl StraightenDocumentCommand {
+    /// Create a new straighten document command.
+    #[must_use]
+    pub fn new(rotation: FineRotation) -> Self {
+        Self { rotation }
+    }
+
+    /// Execute the straighten command on the document manager.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no document is open or it is not a raster image.
+    pub fn execute(&self, manager: &mut DocumentManager) -> DocResult<()> {
+        manager
+            .current_document_mut()
+            .ok_or_else(|| anyhow::anyhow!("No document open"))?
+            .straighten(self.rotation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::operations::straighten::{RotateEdges, RotateFilter};
+
+    #[test]
+    fn test_straighten_without_document_fails() {
+        let mut manager = DocumentManager::new();
+        let cmd = StraightenDocumentCommand::new(FineRotation::new(
+            2.5,
+            RotateEdges::Crop,
+            RotateFilter::Bilinear,
+        ));
+        assert!(cmd.execute(&mut manager).is_err());
+    }
+}
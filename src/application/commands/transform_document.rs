@@ -4,7 +4,7 @@
 // Transform document command: rotate, flip, and other transformations.
 
 use crate::application::document_manager::DocumentManager;
-use crate::domain::document::core::document::{DocResult, Rotation};
+use crate::domain::document::core::document::{DocResult, Rotation, RotationMode, TransformState};
 use crate::domain::document::operations::transform;
 
 /// Transformation operation.
@@ -23,6 +23,79 @@ pub enum TransformOperation {
     RotateTo(Rotation),
 }
 
+impl TransformOperation {
+    /// State of a document in `state` after this operation.
+    ///
+    /// States read as flips first, then the rotation (the order
+    /// `sequence_for` replays them in), so a flip after a rotation mirrors
+    /// the rotation too. `RotateTo` sets the rotation.
+    #[must_use]
+    pub fn apply_to(self, state: TransformState) -> TransformState {
+        match self {
+            Self::RotateCw => TransformState {
+                rotation: state.rotation.rotate_cw(),
+                ..state
+            },
+            Self::RotateCcw => TransformState {
+                rotation: state.rotation.rotate_ccw(),
+                ..state
+            },
+            Self::FlipHorizontal => TransformState {
+                rotation: mirrored(state.rotation),
+                flip_h: !state.flip_h,
+                ..state
+            },
+            Self::FlipVertical => TransformState {
+                rotation: mirrored(state.rotation),
+                flip_v: !state.flip_v,
+                ..state
+            },
+            Self::RotateTo(rotation) => TransformState {
+                rotation: RotationMode::Standard(rotation),
+                ..state
+            },
+        }
+    }
+
+    /// Operations that reproduce `state` on an untransformed document:
+    /// the flips, then the rotation.
+    ///
+    /// Fine rotations are rounded to the nearest 90° step.
+    #[must_use]
+    pub fn sequence_for(state: TransformState) -> Vec<Self> {
+        let mut ops = Vec::with_capacity(3);
+
+        if state.flip_h {
+            ops.push(Self::FlipHorizontal);
+        }
+        if state.flip_v {
+            ops.push(Self::FlipVertical);
+        }
+        let degrees = ((state.rotation.to_degrees() / 90.0).round() as i16 * 90).rem_euclid(360);
+        let rotation = match degrees {
+            90 => Rotation::Cw90,
+            180 => Rotation::Cw180,
+            270 => Rotation::Cw270,
+            _ => Rotation::None,
+        };
+        if rotation != Rotation::None {
+            ops.push(Self::RotateTo(rotation));
+        }
+
+        ops
+    }
+}
+
+/// The same rotation seen in a mirror (turning the other way).
+fn mirrored(rotation: RotationMode) -> RotationMode {
+    match rotation {
+        RotationMode::Standard(Rotation::Cw90) => RotationMode::Standard(Rotation::Cw270),
+        RotationMode::Standard(Rotation::Cw270) => RotationMode::Standard(Rotation::Cw90),
+        RotationMode::Standard(rotation) => RotationMode::Standard(rotation),
+        RotationMode::Fine(degrees) => RotationMode::Fine((360.0 - degrees) % 360.0),
+    }
+}
+
 /// Transform document command.
 pub struct TransformDocumentCommand {
     operation: TransformOperation,
@@ -38,11 +111,13 @@ impl TransformDocumentCommand {
     /// Execute the transform command.
     ///
     /// Uses high-level transform operations that work across all document types
-    /// (Raster, Vector, Portable).
+    /// (Raster, Vector, Portable). Multi-page documents transform the current
+    /// page only.
     pub fn execute(&self, manager: &mut DocumentManager) -> DocResult<()> {
         let document = manager
             .current_document_mut()
             .ok_or_else(|| anyhow::anyhow!("No document loaded"))?;
+        let page = document.current_page();
 
         match self.operation {
             TransformOperation::RotateCw => {
@@ -62,6 +137,7 @@ impl TransformDocumentCommand {
             }
         }
 
+        manager.refresh_thumbnail(page);
         Ok(())
     }
 }
@@ -78,4 +154,98 @@ mod tests {
         let cmd = TransformDocumentCommand::new(TransformOperation::FlipHorizontal);
         assert_eq!(cmd.operation, TransformOperation::FlipHorizontal);
     }
+
+    #[test]
+    fn test_sequence_for_state() {
+        assert!(TransformOperation::sequence_for(TransformState::default()).is_empty());
+
+        let state = TransformState {
+            rotation: RotationMode::Standard(Rotation::Cw90),
+            flip_h: true,
+            flip_v: false,
+        };
+        assert_eq!(
+            TransformOperation::sequence_for(state),
+            vec![
+                TransformOperation::FlipHorizontal,
+                TransformOperation::RotateTo(Rotation::Cw90)
+            ]
+        );
+
+        let state = TransformState {
+            rotation: RotationMode::Fine(268.0),
+            ..TransformState::default()
+        };
+        assert_eq!(
+            TransformOperation::sequence_for(state),
+            vec![TransformOperation::RotateTo(Rotation::Cw270)]
+        );
+    }
+
+    #[test]
+    fn test_flip_after_rotation_mirrors_it() {
+        let state = [
+            TransformOperation::RotateCw,
+            TransformOperation::FlipHorizontal,
+        ]
+        .into_iter()
+        .fold(TransformState::default(), |state, op| op.apply_to(state));
+        assert_eq!(
+            state,
+            TransformState {
+                rotation: RotationMode::Standard(Rotation::Cw270),
+                flip_h: true,
+                flip_v: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_replayed_state_matches_applied_operations() {
+        use TransformOperation::{FlipHorizontal, FlipVertical, RotateCcw, RotateCw};
+
+        let dir = std::env::temp_dir().join(format!("noctua-transform-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("pixels.png");
+        // Every pixel differs, so any wrong turn or mirror shows
+        image::RgbaImage::from_fn(3, 2, |x, y| {
+            image::Rgba([x as u8 * 80, y as u8 * 200, 0, 255])
+        })
+        .save(&path)
+        .unwrap();
+        let pixels = |manager: &DocumentManager| {
+            manager
+                .current_document()
+                .unwrap()
+                .rendered_image()
+                .to_rgba8()
+        };
+
+        for ops in [
+            &[FlipHorizontal, RotateCw][..],
+            &[RotateCw, FlipHorizontal],
+            &[FlipVertical, RotateCcw, FlipHorizontal],
+            &[RotateCw, FlipVertical, RotateCw, FlipHorizontal, RotateCcw],
+        ] {
+            let mut applied = DocumentManager::new();
+            applied.open_document(&path).unwrap();
+            let mut state = TransformState::default();
+            for &op in ops {
+                TransformDocumentCommand::new(op)
+                    .execute(&mut applied)
+                    .unwrap();
+                state = op.apply_to(state);
+            }
+
+            let mut replayed = DocumentManager::new();
+            replayed.open_document(&path).unwrap();
+            for op in TransformOperation::sequence_for(state) {
+                TransformDocumentCommand::new(op)
+                    .execute(&mut replayed)
+                    .unwrap();
+            }
+            assert_eq!(pixels(&applied), pixels(&replayed), "{ops:?}");
+        }
+        let _ = std::fs::remove_dir_all(&dir);
+    }
 }
@@ -5,13 +5,32 @@
 
 use std::path::{Path, PathBuf};
 
+use crate::application::services::decode_service::{DecodeJob, DecodeResult};
+use crate::application::services::page_refine::{RefineJob, RefineResult};
 use crate::domain::document::collection::DocumentCollection;
-use crate::domain::document::core::content::DocumentContent;
-use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::content::{DocumentContent, DocumentKind};
+use crate::domain::document::core::document::{DocResult, ImageHandle};
+use crate::domain::document::core::limits::OpenLimits;
 use crate::domain::document::core::metadata::DocumentMeta;
-use crate::infrastructure::filesystem::file_ops;
+use crate::domain::document::core::page::ThumbnailJob;
+use crate::infrastructure::filesystem::{ExcludePatterns, SortOrder, file_ops};
 use crate::infrastructure::loaders::DocumentLoaderFactory;
 
+/// Outcome of applying a folder rescan.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FolderSync {
+    /// Same files as before.
+    Unchanged,
+    /// Files were added or removed; the current document stays.
+    Changed,
+    /// The current file was removed and the next one is shown instead.
+    CurrentRemoved,
+    /// The folder could not be read or came back empty (e.g. an unmounted
+    /// network share); the previous list and document are kept.
+    Unavailable,
+}
+
 /// Central document manager.
 ///
 /// Orchestrates document loading, metadata extraction, and folder navigation.
@@ -23,6 +42,20 @@ pub struct DocumentManager {
     current_metadata: Option<DocumentMeta>,
     /// Document loader factory.
     loader: DocumentLoaderFactory,
+    /// Ignore patterns applied when scanning folders.
+    exclude_patterns: ExcludePatterns,
+    /// Order of the files in folder navigation.
+    sort_order: SortOrder,
+    /// Turn raster images upright by their EXIF orientation when loading.
+    auto_orient: bool,
+    /// Page thumbnails of the current document, converted for display
+    /// (None = not generated yet).
+    thumbnails: Vec<Option<cosmic::widget::image::Handle>>,
+    /// Token of the image being decoded in the background, if any.
+    pending_decode: Option<CancelToken>,
+    /// Image id of the page preview being refined in the background, and
+    /// the token of its render.
+    pending_refine: Option<(u64, CancelToken)>,
 }
 
 impl DocumentManager {
@@ -33,18 +66,55 @@ impl DocumentManager {
             collection: DocumentCollection::new(),
             current_metadata: None,
             loader: DocumentLoaderFactory::new(),
+            exclude_patterns: ExcludePatterns::default(),
+            sort_order: SortOrder::default(),
+            auto_orient: true,
+            thumbnails: Vec::new(),
+            pending_decode: None,
+            pending_refine: None,
         }
     }
 
+    /// Set the ignore patterns used when scanning folders for navigation.
+    ///
+    /// Takes effect on the next folder scan (see `rescan_folder`).
+    pub fn set_exclude_patterns<S: AsRef<str>>(&mut self, patterns: &[S]) {
+        self.exclude_patterns = ExcludePatterns::new(patterns);
+    }
+
+    /// Set the order of the files in folder navigation.
+    ///
+    /// Takes effect on the next folder scan (see `rescan_folder`).
+    pub fn set_sort_order(&mut self, order: SortOrder) {
+        self.sort_order = order;
+    }
+
+    /// Set the size limits files are checked against before they are decoded.
+    ///
+    /// Takes effect when the next document is loaded.
+    pub fn set_open_limits(&mut self, limits: OpenLimits) {
+        self.loader.set_limits(limits);
+    }
+
+    /// Set whether raster images are turned upright by their EXIF orientation.
+    ///
+    /// Takes effect when the next document is loaded.
+    pub fn set_auto_orient(&mut self, enabled: bool) {
+        self.auto_orient = enabled;
+    }
+
     /// Open a document from a file path or directory.
     ///
     /// If a directory is provided, opens the first supported file found.
     /// Also scans the parent folder for navigation.
     pub fn open_document(&mut self, path: &Path) -> DocResult<()> {
+        self.cancel_decode();
+
         // Determine the actual file to open
         let file_path = if path.is_dir() {
             // Scan directory and find first supported file
-            let paths = file_ops::collect_supported_files(path);
+            let paths =
+                file_ops::collect_supported_files(path, &self.exclude_patterns, self.sort_order);
             self.collection = DocumentCollection::from_paths(paths);
 
             self.collection
@@ -56,39 +126,185 @@ impl DocumentManager {
         };
 
         // Load the document
-        let document = self.loader.load(&file_path)?;
+        let mut document = self.loader.load(&file_path)?;
+        if self.auto_orient {
+            document.apply_stored_orientation();
+        }
 
         // Extract metadata
         let metadata = self.extract_metadata(&file_path, &document);
 
         // Scan folder for navigation if not already done
         if !path.is_dir() {
-            if let Some(parent) = file_path.parent() {
-                let paths = file_ops::collect_supported_files(parent);
-                self.collection = DocumentCollection::from_paths(paths);
-                // Find and set current document index
-                if let Some(idx) = self.collection.paths().iter().position(|p| p == &file_path) {
-                    self.collection.goto(idx);
+            match self.collection.paths().iter().position(|p| *p == file_path) {
+                Some(index) => {
+                    self.collection.goto(index);
                 }
+                None => self.scan_folder_of(&file_path),
             }
         }
 
-        // Generate thumbnails for multi-page documents (PDF)
-        let mut document = document;
-        if document.is_multi_page() {
-            log::info!("Generating thumbnails for multi-page document...");
-            if let Err(e) = document.generate_thumbnails() {
-                log::warn!("Failed to generate thumbnails: {e}");
+        // Store document in collection; thumbnails of multi-page documents
+        // are rendered by a worker (see `thumbnail_job`)
+        self.thumbnails = display_thumbnails(&document);
+        self.collection.set_current_document(document);
+        self.current_metadata = Some(metadata);
+
+        Ok(())
+    }
+
+    /// Reload the current document from disk, discarding unsaved edits.
+    ///
+    /// Keeps the folder list and metadata.
+    pub fn reload_document(&mut self) -> DocResult<()> {
+        let path = self
+            .collection
+            .current_path()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No document open"))?;
+        self.cancel_decode();
+
+        let mut document = self.loader.load(&path)?;
+        if self.auto_orient {
+            document.apply_stored_orientation();
+        }
+        if let Some(previous) = self.collection.current_document() {
+            // Color adjustments are not edits, so undo keeps them
+            document.set_adjustments(previous.adjustments());
+            // Thumbnails show the pages as stored, so they survive the reload
+            for page in 0..previous.page_count() {
+                if let Some(handle) = previous.get_thumbnail_handle(page) {
+                    document.add_thumbnail(page, handle);
+                }
             }
         }
-
-        // Store document in collection
+        self.thumbnails = display_thumbnails(&document);
         self.collection.set_current_document(document);
-        self.current_metadata = Some(metadata);
 
         Ok(())
     }
 
+    /// Re-scan the folder of the current document (e.g. after changing ignore patterns).
+    ///
+    /// Keeps the current document loaded and its position in the new list.
+    pub fn rescan_folder(&mut self) {
+        let Some(current) = self.collection.current_path().cloned() else {
+            return;
+        };
+        let document = self.collection.take_current_document();
+        self.scan_folder_of(&current);
+        if let Some(document) = document {
+            self.collection.set_current_document(document);
+        }
+    }
+
+    /// Folder of the current document and a function listing its files, to
+    /// run off the UI thread (reading a network folder can block). The
+    /// function returns None if the folder cannot be read.
+    #[must_use]
+    pub fn folder_scanner(
+        &self,
+    ) -> Option<(
+        PathBuf,
+        impl FnOnce() -> Option<Vec<PathBuf>> + Send + 'static,
+    )> {
+        let folder = self.collection.current_path()?.parent()?.to_path_buf();
+        let excludes = self.exclude_patterns.clone();
+        let order = self.sort_order;
+        let dir = folder.clone();
+        Some((folder, move || {
+            file_ops::read_supported_files(&dir, &excludes, order).ok()
+        }))
+    }
+
+    /// Apply the files found by a background scan of the current folder.
+    ///
+    /// `None` or an empty list means the folder was unavailable; nothing
+    /// changes then, so a display keeps showing its last image.
+    pub fn apply_folder_scan(&mut self, paths: Option<Vec<PathBuf>>) -> FolderSync {
+        let Some(mut paths) = paths.filter(|paths| !paths.is_empty()) else {
+            return FolderSync::Unavailable;
+        };
+        let Some(current) = self.collection.current_path().cloned() else {
+            return FolderSync::Unchanged;
+        };
+
+        // Keep an explicitly opened file that matches an ignore pattern
+        if !paths.contains(&current) && current.exists() {
+            paths.push(current);
+        }
+        if paths == self.collection.paths() {
+            return FolderSync::Unchanged;
+        }
+        if !self.collection.replace_paths(paths) {
+            return FolderSync::Changed;
+        }
+
+        if let Some(next) = self.collection.current_path().cloned()
+            && let Err(e) = self.open_document(&next)
+        {
+            tracing::warn!("Failed to open {} after rescan: {e}", next.display());
+        }
+        FolderSync::CurrentRemoved
+    }
+
+    /// Move the current file to the trash and drop it from the folder list.
+    ///
+    /// Returns the file to show instead: the next one, or the last one when
+    /// the trashed file was at the end. Nothing changes if the move fails.
+    pub fn trash_current(&mut self) -> DocResult<Option<PathBuf>> {
+        let (Some(index), Some(path)) = (
+            self.collection.current_index(),
+            self.collection.current_path().cloned(),
+        ) else {
+            return Ok(None);
+        };
+        file_ops::move_to_trash(&path)?;
+
+        self.cancel_decode();
+        self.collection.remove_at(index);
+        self.current_metadata = None;
+        self.thumbnails.clear();
+        Ok(self.collection.current_path().cloned())
+    }
+
+    /// Navigate `paths` instead of the files of a folder (e.g. those of a
+    /// saved session), starting at `current`.
+    ///
+    /// Returns the file to load then (see `step`), or None without files.
+    pub fn set_file_list(&mut self, paths: Vec<PathBuf>, current: usize) -> Option<PathBuf> {
+        self.cancel_decode();
+        self.collection = DocumentCollection::from_paths(paths);
+        self.collection.goto(current);
+        self.current_metadata = None;
+        self.thumbnails.clear();
+        self.collection.current_path().cloned()
+    }
+
+    /// Build the navigation collection from the folder containing `file_path`.
+    ///
+    /// The file itself is always part of the collection, even if it matches an
+    /// ignore pattern (it was opened explicitly).
+    fn scan_folder_of(&mut self, file_path: &Path) {
+        let Some(parent) = file_path.parent() else {
+            return;
+        };
+
+        let paths =
+            file_ops::collect_supported_files(parent, &self.exclude_patterns, self.sort_order);
+        self.collection = DocumentCollection::from_paths(paths);
+
+        // Find and set current document index
+        let idx = match self.collection.paths().iter().position(|p| p == file_path) {
+            Some(idx) => idx,
+            None => {
+                self.collection.add_path(file_path.to_path_buf());
+                self.collection.len() - 1
+            }
+        };
+        self.collection.goto(idx);
+    }
+
     /// Get the current document.
     #[must_use]
     pub fn current_document(&self) -> Option<&DocumentContent> {
@@ -105,9 +321,58 @@ impl DocumentManager {
     /// Returns None if the thumbnail hasn't been generated yet.
     #[must_use]
     pub fn get_thumbnail_handle(&self, page: usize) -> Option<cosmic::widget::image::Handle> {
+        self.thumbnails.get(page).cloned().flatten()
+    }
+
+    /// Thumbnails of the current document still to be rendered, if any.
+    #[must_use]
+    pub fn thumbnail_job(&self) -> Option<ThumbnailJob> {
+        self.collection.current_document()?.thumbnail_job()
+    }
+
+    /// Add a thumbnail rendered in the background for the document at `path`.
+    ///
+    /// Ignored if another document is open by now or the page already has
+    /// a thumbnail. Returns whether the thumbnail was added.
+    pub fn add_thumbnail(&mut self, path: &Path, page: usize, handle: ImageHandle) -> bool {
+        if self.collection.current_path().map(PathBuf::as_path) != Some(path) {
+            return false;
+        }
+        let added = self
+            .collection
+            .current_document_mut()
+            .is_some_and(|document| document.add_thumbnail(page, handle.clone()));
+        if added {
+            // The page may have been turned before its thumbnail arrived
+            let shown = self
+                .collection
+                .current_document()
+                .and_then(|document| document.transformed_thumbnail(page))
+                .unwrap_or(handle);
+            if let Some(slot) = self.thumbnails.get_mut(page) {
+                *slot = Some(display_handle(&shown));
+            }
+        }
+        added
+    }
+
+    /// Convert the thumbnail of a page again, after its transform changed.
+    pub fn refresh_thumbnail(&mut self, page: usize) {
+        let thumbnail = self
+            .collection
+            .current_document()
+            .and_then(|document| document.transformed_thumbnail(page));
+        if let (Some(slot), Some(thumbnail)) = (self.thumbnails.get_mut(page), thumbnail) {
+            *slot = Some(display_handle(&thumbnail));
+        }
+    }
+
+    /// Page shown of the current document (0 for single pages).
+    #[must_use]
+    pub fn current_page(&self) -> usize {
         self.collection
-            .current_document()?
-            .get_thumbnail_handle(page)
+            .current_document()
+            .map_or(0, DocumentContent::current_page)
     }
 
     /// Get the current document path.
@@ -122,6 +387,16 @@ impl DocumentManager {
         self.current_metadata.as_ref()
     }
 
+    /// Re-read metadata of the current document from disk (e.g. after editing EXIF).
+    pub fn refresh_metadata(&mut self) {
+        let Some(path) = self.collection.current_path().cloned() else {
+            return;
+        };
+        if let Some(document) = self.collection.current_document() {
+            self.current_metadata = Some(self.extract_metadata(&path, document));
+        }
+    }
+
     /// Get all folder entries for navigation.
     #[must_use]
     pub fn folder_entries(&self) -> &[PathBuf] {
@@ -138,17 +413,7 @@ impl DocumentManager {
     ///
     /// Wraps around to the first document when at the end.
     pub fn next_document(&mut self) -> Option<PathBuf> {
-        // Use DocumentCollection navigation
-        if self.collection.has_next() {
-            self.collection.next();
-        } else if !self.collection.is_empty() {
-            // Wrap around to first
-            self.collection.goto(0);
-        } else {
-            return None;
-        }
-
-        let next_path = self.collection.current_path()?.clone();
+        let next_path = self.step(true)?;
         if self.open_document(&next_path).is_ok() {
             Some(next_path)
         } else {
@@ -160,22 +425,149 @@ impl DocumentManager {
     ///
     /// Wraps around to the last document when at the beginning.
     pub fn previous_document(&mut self) -> Option<PathBuf> {
+        let prev_path = self.step(false)?;
+        if self.open_document(&prev_path).is_ok() {
+            Some(prev_path)
+        } else {
+            None
+        }
+    }
+
+    /// Move to the next or previous file in the folder without loading it.
+    ///
+    /// Wraps around at both ends. Returns the path of the new current file.
+    pub fn step(&mut self, forward: bool) -> Option<PathBuf> {
         // Use DocumentCollection navigation
-        if self.collection.has_previous() {
+        if forward && self.collection.has_next() {
+            self.collection.next();
+        } else if !forward && self.collection.has_previous() {
             self.collection.previous();
         } else if !self.collection.is_empty() {
-            // Wrap around to last
-            let last_idx = self.collection.len() - 1;
-            self.collection.goto(last_idx);
+            // Wrap around to the other end
+            let last = self.collection.len() - 1;
+            self.collection.goto(if forward { 0 } else { last });
         } else {
             return None;
         }
+        self.collection.current_path().cloned()
+    }
 
-        let prev_path = self.collection.current_path()?.clone();
-        if self.open_document(&prev_path).is_ok() {
-            Some(prev_path)
-        } else {
-            None
+    /// Start loading the image at `path` (the current file) off the UI thread.
+    ///
+    /// Cancels the decode it supersedes. Until the result is passed to
+    /// `finish_decode`, no document is open; the view keeps showing the last
+    /// render. Returns None for other documents, which are opened with
+    /// `open_document`.
+    pub fn begin_decode(&mut self, path: &Path) -> Option<DecodeJob> {
+        self.cancel_decode();
+        if self.loader.detect_kind(path) != Some(DocumentKind::Raster) {
+            return None;
+        }
+
+        let token = CancelToken::new();
+        self.pending_decode = Some(token.clone());
+        self.collection.clear_current_document();
+        self.current_metadata = None;
+        self.thumbnails.clear();
+        Some(DecodeJob::new(
+            path.to_path_buf(),
+            self.loader.limits(),
+            self.auto_orient,
+            token,
+        ))
+    }
+
+    /// Open the image decoded by a `DecodeJob`.
+    ///
+    /// Returns `Ok(false)` if a newer load superseded it, or the current file
+    /// changed meanwhile; the image is dropped then.
+    pub fn finish_decode(&mut self, decoded: &DecodeResult) -> DocResult<bool> {
+        if decoded.is_cancelled()
+            || self.collection.current_path().map(PathBuf::as_path) != Some(decoded.path())
+        {
+            return Ok(false);
+        }
+        let Some(result) = decoded.take() else {
+            return Ok(false);
+        };
+        self.pending_decode = None;
+
+        let image = result?;
+        self.collection
+            .set_current_document(DocumentContent::Raster(image.document));
+        self.current_metadata = Some(image.metadata);
+        Ok(true)
+    }
+
+    /// Start loading the image at `path` to show beside the current document
+    /// (compare mode).
+    ///
+    /// Unlike `begin_decode`, the current document is left alone. Returns None
+    /// for files that are not raster images.
+    #[must_use]
+    pub fn begin_compare_decode(&self, path: &Path) -> Option<DecodeJob> {
+        (self.loader.detect_kind(path) == Some(DocumentKind::Raster)).then(|| {
+            DecodeJob::new(
+                path.to_path_buf(),
+                self.loader.limits(),
+                self.auto_orient,
+                CancelToken::new(),
+            )
+        })
+    }
+
+    /// Check if `path` is the current file and still being decoded.
+    #[must_use]
+    pub fn is_decoding(&self, path: &Path) -> bool {
+        self.pending_decode.is_some() && self.current_path() == Some(path)
+    }
+
+    /// Start rendering the current page in full if it shows a quick preview
+    /// (large PDF pages).
+    ///
+    /// Cancels the render of a preview that was replaced, e.g. by turning
+    /// the page. Returns None if the page needs no render, or one for this
+    /// preview was already started (also if it failed, so it is not retried
+    /// over and over).
+    pub fn begin_refine(&mut self) -> Option<RefineJob> {
+        let preview = self
+            .current_document()
+            .filter(|doc| doc.is_refining())
+            .and_then(DocumentContent::handle)
+            .map(|handle| handle.id());
+        if preview == self.pending_refine.as_ref().map(|(id, _)| *id) {
+            return None;
+        }
+        if let Some((_, token)) = self.pending_refine.take() {
+            token.cancel();
+        }
+
+        let job = self.current_document()?.refine_job()?;
+        let token = CancelToken::new();
+        self.pending_refine = Some((preview?, token.clone()));
+        Some(RefineJob::new(job, token))
+    }
+
+    /// Show the page rendered by a `RefineJob` in place of its preview.
+    ///
+    /// Returns false if the render failed, or the page changed meanwhile.
+    pub fn finish_refine(&mut self, refined: &RefineResult) -> bool {
+        if refined.is_cancelled() {
+            return false;
+        }
+        let Some(rendered) = refined.take() else {
+            return false;
+        };
+        let Some(doc) = self.current_document_mut() else {
+            return false;
+        };
+        doc.finish_refine(refined.job(), rendered)
+    }
+
+    /// Cancel the image being decoded in the background, if any.
+    fn cancel_decode(&mut self) {
+        if let Some(token) = self.pending_decode.take() {
+            token.cancel();
         }
     }
 
@@ -196,7 +588,6 @@ impl DocumentManager {
 
     /// Check if there is a next document available.
     #[must_use]
-    #[allow(dead_code)]
     pub fn has_next(&self) -> bool {
         self.collection.has_next()
     }
@@ -209,6 +600,27 @@ impl DocumentManager {
     }
 }
 
+/// Image handle the toolkit can display, holding a copy of the rendered pixels.
+///
+/// Each call creates a new texture; keep the result instead of converting the
+/// same image again.
+#[must_use]
+pub fn display_handle(image: &ImageHandle) -> cosmic::widget::image::Handle {
+    cosmic::widget::image::Handle::from_rgba(image.width(), image.height(), image.pixels().to_vec())
+}
+
+/// Display handles of the page thumbnails, turned like their pages (None
+/// for the pages not generated yet and for single pages).
+fn display_thumbnails(document: &DocumentContent) -> Vec<Option<cosmic::widget::image::Handle>> {
+    (0..document.page_count())
+        .map(|page| {
+            document
+                .transformed_thumbnail(page)
+                .map(|thumbnail| display_handle(&thumbnail))
+        })
+        .collect()
+}
+
 impl Default for DocumentManager {
     fn default() -> Self {
         Self::new()
@@ -0,0 +1,212 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/edit_history.rs
+//
+// Edit history: applied operations with undo, redo and revert.
+
+use crate::application::commands::clean_scan::CleanScanCommand;
+use crate::application::commands::crop_document::CropDocumentCommand;
+use crate::application::commands::resize_document::ResizeDocumentCommand;
+use crate::application::commands::straighten_document::StraightenDocumentCommand;
+use crate::application::commands::transform_document::{TransformDocumentCommand, TransformOperation};
+use crate::application::document_manager::DocumentManager;
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::operations::CropRegion;
+use crate::domain::document::operations::resize::Resize;
+use crate::domain::document::operations::scan_cleanup::ScanCleanup;
+use crate::domain::document::operations::straighten::FineRotation;
+
+/// A single edit applied to the current document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditOperation {
+    /// Rotate or flip.
+    Transform(TransformOperation),
+    /// Crop to a region in image pixels.
+    Crop(CropRegion),
+    /// Rotate by a fine angle.
+    Straighten(FineRotation),
+    /// Resample to a new pixel size.
+    Resize(Resize),
+    /// Remove specks and whiten the paper of a scan.
+    CleanScan(ScanCleanup),
+}
+
+impl EditOperation {
+    /// Apply the operation to the current document.
+    pub fn execute(&self, manager: &mut DocumentManager) -> DocResult<()> {
+        match *self {
+            Self::Transform(operation) => TransformDocumentCommand::new(operation).execute(manager),
+            Self::Crop(region) => {
+                CropDocumentCommand::new(region.x, region.y, region.width, region.height)
+                    .execute(manager)
+            }
+            Self::Straighten(rotation) => StraightenDocumentCommand::new(rotation).execute(manager),
+            Self::Resize(resize) => ResizeDocumentCommand::new(resize).execute(manager),
+            Self::CleanScan(cleanup) => CleanScanCommand::new(cleanup).execute(manager),
+        }
+    }
+}
+
+/// Operations applied to the current document since it was opened.
+///
+/// Undo does not invert operations (a crop cannot be inverted): the
+/// document is reloaded from disk and the remaining entries are replayed.
+#[derive(Debug, Clone, Default)]
+pub struct EditHistory {
+    entries: Vec<EditOperation>,
+    /// Page each entry was applied to (pages of a PDF are turned one by one).
+    pages: Vec<usize>,
+    /// Number of entries currently applied; the rest can be redone.
+    position: usize,
+}
+
+impl EditHistory {
+    /// Record an operation that was just applied to `page`.
+    ///
+    /// Discards the entries that were undone before.
+    pub fn record(&mut self, operation: EditOperation, page: usize) {
+        self.entries.truncate(self.position);
+        self.pages.truncate(self.position);
+        self.entries.push(operation);
+        self.pages.push(page);
+        self.position = self.entries.len();
+    }
+
+    /// All recorded operations, including undone ones.
+    #[must_use]
+    pub fn entries(&self) -> &[EditOperation] {
+        &self.entries
+    }
+
+    /// Number of operations currently applied.
+    #[must_use]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Operations currently applied to `page`, in the order they were applied.
+    pub fn applied_on(&self, page: usize) -> impl Iterator<Item = &EditOperation> {
+        self.entries[..self.position]
+            .iter()
+            .zip(&self.pages)
+            .filter(move |(_, applied)| **applied == page)
+            .map(|(operation, _)| operation)
+    }
+
+    #[must_use]
+    pub fn can_undo(&self) -> bool {
+        self.position > 0
+    }
+
+    #[must_use]
+    pub fn can_redo(&self) -> bool {
+        self.position < self.entries.len()
+    }
+
+    /// Forget all entries (e.g. when another document is opened).
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.pages.clear();
+        self.position = 0;
+    }
+
+    /// Restore the state after the first `count` operations.
+    ///
+    /// Reloads the document from disk and replays the kept entries, each on
+    /// its page; the page shown stays the same.
+    pub fn revert_to(&mut self, count: usize, manager: &mut DocumentManager) -> DocResult<()> {
+        let count = count.min(self.entries.len());
+        let shown = manager.current_page();
+        manager.reload_document()?;
+        go_to_page(manager, shown)?;
+        for (operation, &page) in self.entries[..count].iter().zip(&self.pages) {
+            execute_on_page(operation, page, manager)?;
+        }
+        self.position = count;
+        Ok(())
+    }
+
+    /// Revert the last applied operation.
+    pub fn undo(&mut self, manager: &mut DocumentManager) -> DocResult<()> {
+        if !self.can_undo() {
+            return Ok(());
+        }
+        self.revert_to(self.position - 1, manager)
+    }
+
+    /// Re-apply the next undone operation.
+    pub fn redo(&mut self, manager: &mut DocumentManager) -> DocResult<()> {
+        let (Some(operation), Some(&page)) = (
+            self.entries.get(self.position),
+            self.pages.get(self.position),
+        ) else {
+            return Ok(());
+        };
+        execute_on_page(operation, page, manager)?;
+        self.position += 1;
+        Ok(())
+    }
+}
+
+/// Show `page` of the current document, if it isn't already.
+fn go_to_page(manager: &mut DocumentManager, page: usize) -> DocResult<()> {
+    match manager.current_document_mut() {
+        Some(document) if document.current_page() != page => document.go_to_page(page),
+        _ => Ok(()),
+    }
+}
+
+/// Apply `operation` to `page`, then return to the page shown.
+fn execute_on_page(
+    operation: &EditOperation,
+    page: usize,
+    manager: &mut DocumentManager,
+) -> DocResult<()> {
+    let shown = manager.current_page();
+    go_to_page(manager, page)?;
+    operation.execute(manager)?;
+    go_to_page(manager, shown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_truncates_redo_tail() {
+        let mut history = EditHistory::default();
+        history.record(EditOperation::Transform(TransformOperation::RotateCw), 0);
+        history.record(
+            EditOperation::Transform(TransformOperation::FlipVertical),
+            0,
+        );
+        assert_eq!(history.position(), 2);
+        assert!(history.can_undo());
+        assert!(!history.can_redo());
+
+        // Pretend the last entry was undone, then record a new one
+        history.position = 1;
+        assert!(history.can_redo());
+        history.record(EditOperation::Crop(CropRegion::new(0, 0, 10, 10)), 0);
+        assert_eq!(
+            history.entries(),
+            &[
+                EditOperation::Transform(TransformOperation::RotateCw),
+                EditOperation::Crop(CropRegion::new(0, 0, 10, 10)),
+            ]
+        );
+        assert!(!history.can_redo());
+    }
+
+    #[test]
+    fn test_revert_without_document_keeps_position() {
+        let mut history = EditHistory::default();
+        history.record(EditOperation::Transform(TransformOperation::RotateCw), 0);
+
+        let mut manager = DocumentManager::new();
+        assert!(history.revert_to(0, &mut manager).is_err());
+        assert_eq!(history.position(), 1);
+        // Nothing left to redo, so redo is a no-op
+        assert!(history.redo(&mut manager).is_ok());
+        assert_eq!(history.position(), 1);
+    }
+}
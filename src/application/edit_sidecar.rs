@@ -0,0 +1,555 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/edit_sidecar.rs
+//
+// Edit sidecars: applied edits and color adjustments stored as JSON next to
+// the original file, and edits imported from the XMP sidecars of other tools.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::application::commands::transform_document::TransformOperation;
+use crate::application::edit_history::EditOperation;
+use crate::domain::document::core::document::{DocResult, Rotation};
+use crate::domain::document::core::temp_file;
+use crate::domain::document::operations::CropRegion;
+use crate::domain::document::operations::adjust::{Adjustment, Adjustments};
+use crate::domain::document::operations::resize::{ResampleFilter, Resize};
+use crate::domain::document::operations::scan_cleanup::ScanCleanup;
+use crate::domain::document::operations::straighten::{FineRotation, RotateEdges, RotateFilter};
+use crate::domain::document::operations::threshold::Threshold;
+use crate::domain::document::operations::xmp_edits::XmpEdits;
+
+/// Suffix appended to the original file name (`photo.jpg.noctua.json`).
+const SIDECAR_SUFFIX: &str = ".noctua.json";
+
+/// Format version written into the sidecar.
+const SIDECAR_VERSION: u32 = 1;
+
+/// Sidecar file belonging to `original`.
+#[must_use]
+pub fn sidecar_path(original: &Path) -> PathBuf {
+    let mut name = original.as_os_str().to_owned();
+    name.push(SIDECAR_SUFFIX);
+    PathBuf::from(name)
+}
+
+/// What a sidecar keeps: the applied edits and the color adjustments shown
+/// on top of them.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SidecarEdits {
+    pub edits: Vec<EditOperation>,
+    pub adjustments: Adjustments,
+}
+
+impl SidecarEdits {
+    /// Check if there is nothing to store.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty() && self.adjustments.is_neutral()
+    }
+}
+
+/// The sidecar file.
+#[derive(Serialize, Deserialize)]
+struct SidecarFile {
+    #[serde(default)]
+    version: u32,
+    edits: Vec<StoredEdit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    adjustments: Option<StoredAdjustments>,
+}
+
+/// One edit, in the order they are applied:
+/// `{"op": "rotate-cw"}`, `{"op": "rotate-to", "degrees": 90}`,
+/// `{"op": "crop", "x": 0, "y": 0, "width": 640, "height": 480}`,
+/// `{"op": "straighten", "degrees": -2.5, "edges": "crop", "filter": "bilinear"}`,
+/// `{"op": "resize", "width": 1920, "height": 1080, "filter": "lanczos3"}`,
+/// `{"op": "clean-scan", "despeckle": 6, "whiten": 100}`.
+#[derive(Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "kebab-case")]
+enum StoredEdit {
+    RotateCw,
+    RotateCcw,
+    FlipHorizontal,
+    FlipVertical,
+    RotateTo {
+        degrees: i16,
+    },
+    Crop {
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+    },
+    Straighten {
+        degrees: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        edges: Option<String>,
+        /// Sidecars from before the edge modes only say whether to crop.
+        #[serde(default, rename = "auto-crop", skip_serializing)]
+        auto_crop: Option<bool>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filter: Option<String>,
+    },
+    Resize {
+        width: u32,
+        height: u32,
+        filter: String,
+    },
+    CleanScan {
+        despeckle: u8,
+        whiten: u8,
+    },
+}
+
+impl From<&EditOperation> for StoredEdit {
+    fn from(edit: &EditOperation) -> Self {
+        match *edit {
+            EditOperation::Transform(TransformOperation::RotateCw) => Self::RotateCw,
+            EditOperation::Transform(TransformOperation::RotateCcw) => Self::RotateCcw,
+            EditOperation::Transform(TransformOperation::FlipHorizontal) => Self::FlipHorizontal,
+            EditOperation::Transform(TransformOperation::FlipVertical) => Self::FlipVertical,
+            EditOperation::Transform(TransformOperation::RotateTo(rotation)) => Self::RotateTo {
+                degrees: rotation.to_degrees(),
+            },
+            EditOperation::Crop(region) => Self::Crop {
+                x: region.x,
+                y: region.y,
+                width: region.width,
+                height: region.height,
+            },
+            EditOperation::Straighten(rotation) => Self::Straighten {
+                degrees: rotation.degrees(),
+                edges: Some(rotation.edges.as_str().to_string()),
+                auto_crop: None,
+                filter: Some(rotation.filter.as_str().to_string()),
+            },
+            EditOperation::Resize(resize) => Self::Resize {
+                width: resize.width,
+                height: resize.height,
+                filter: resize.filter.as_str().to_string(),
+            },
+            EditOperation::CleanScan(cleanup) => Self::CleanScan {
+                despeckle: cleanup.despeckle,
+                whiten: cleanup.whiten,
+            },
+        }
+    }
+}
+
+impl StoredEdit {
+    /// The edit, if its values are valid.
+    fn to_edit(&self) -> Option<EditOperation> {
+        let operation = match self {
+            Self::RotateCw => EditOperation::Transform(TransformOperation::RotateCw),
+            Self::RotateCcw => EditOperation::Transform(TransformOperation::RotateCcw),
+            Self::FlipHorizontal => EditOperation::Transform(TransformOperation::FlipHorizontal),
+            Self::FlipVertical => EditOperation::Transform(TransformOperation::FlipVertical),
+            Self::RotateTo { degrees } => {
+                let rotation = match degrees {
+                    0 => Rotation::None,
+                    90 => Rotation::Cw90,
+                    180 => Rotation::Cw180,
+                    270 => Rotation::Cw270,
+                    _ => return None,
+                };
+                EditOperation::Transform(TransformOperation::RotateTo(rotation))
+            }
+            &Self::Crop {
+                x,
+                y,
+                width,
+                height,
+            } => {
+                if width == 0 || height == 0 {
+                    return None;
+                }
+                EditOperation::Crop(CropRegion::new(x, y, width, height))
+            }
+            Self::Straighten {
+                degrees,
+                edges,
+                auto_crop,
+                filter,
+            } => {
+                let edges = match (edges, auto_crop) {
+                    (Some(edges), _) => RotateEdges::parse(edges)?,
+                    (None, Some(true)) => RotateEdges::Crop,
+                    (None, Some(false)) => RotateEdges::Transparent,
+                    (None, None) => return None,
+                };
+                let filter = filter
+                    .as_deref()
+                    .map_or(Some(RotateFilter::Bilinear), RotateFilter::parse)?;
+                EditOperation::Straighten(FineRotation::new(*degrees, edges, filter))
+            }
+            Self::Resize {
+                width,
+                height,
+                filter,
+            } => EditOperation::Resize(Resize::new(
+                *width,
+                *height,
+                ResampleFilter::parse(filter)?,
+            )?),
+            &Self::CleanScan { despeckle, whiten } => {
+                if !ScanCleanup::DESPECKLE_RANGE.contains(&despeckle)
+                    || !ScanCleanup::WHITEN_RANGE.contains(&whiten)
+                {
+                    return None;
+                }
+                EditOperation::CleanScan(ScanCleanup { despeckle, whiten })
+            }
+        };
+        Some(operation)
+    }
+}
+
+/// Color adjustments: `{"exposure": 0.5, "brightness": 0, "contrast": 10,
+/// "gamma": 1, "saturation": -100, "threshold": {"level": 128, "adaptive": false}}`.
+#[derive(Serialize, Deserialize)]
+struct StoredAdjustments {
+    exposure: f32,
+    brightness: f32,
+    contrast: f32,
+    gamma: f32,
+    saturation: f32,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    threshold: Option<StoredThreshold>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredThreshold {
+    level: u8,
+    adaptive: bool,
+}
+
+impl From<&Adjustments> for StoredAdjustments {
+    fn from(adjustments: &Adjustments) -> Self {
+        Self {
+            exposure: adjustments.exposure,
+            brightness: adjustments.brightness,
+            contrast: adjustments.contrast,
+            gamma: adjustments.gamma,
+            saturation: adjustments.saturation,
+            threshold: adjustments.threshold.map(|threshold| StoredThreshold {
+                level: threshold.level,
+                adaptive: threshold.adaptive,
+            }),
+        }
+    }
+}
+
+impl StoredAdjustments {
+    /// The adjustments, with values clamped to their ranges.
+    fn to_adjustments(&self) -> Adjustments {
+        let mut adjustments = Adjustments {
+            threshold: self.threshold.as_ref().map(|threshold| Threshold {
+                level: threshold
+                    .level
+                    .clamp(*Threshold::RANGE.start(), *Threshold::RANGE.end()),
+                adaptive: threshold.adaptive,
+            }),
+            ..Adjustments::default()
+        };
+        for (adjustment, value) in [
+            (Adjustment::Exposure, self.exposure),
+            (Adjustment::Brightness, self.brightness),
+            (Adjustment::Contrast, self.contrast),
+            (Adjustment::Gamma, self.gamma),
+            (Adjustment::Saturation, self.saturation),
+        ] {
+            adjustments.set(adjustment, value);
+        }
+        adjustments
+    }
+}
+
+/// JSON form of the sidecar; the adjustments are left out while neutral.
+pub fn to_json(stored: &SidecarEdits) -> DocResult<String> {
+    let file = SidecarFile {
+        version: SIDECAR_VERSION,
+        edits: stored.edits.iter().map(StoredEdit::from).collect(),
+        adjustments: (!stored.adjustments.is_neutral())
+            .then(|| StoredAdjustments::from(&stored.adjustments)),
+    };
+    let mut json = serde_json::to_string_pretty(&file)?;
+    json.push('\n');
+    Ok(json)
+}
+
+/// Read a sidecar written by `to_json`.
+///
+/// Unknown fields are skipped; returns `None` if it is no valid JSON, or
+/// any edit is unknown, incomplete or out of range.
+#[must_use]
+pub fn parse(json: &str) -> Option<SidecarEdits> {
+    let file: SidecarFile = serde_json::from_str(json).ok()?;
+    Some(SidecarEdits {
+        edits: file
+            .edits
+            .iter()
+            .map(StoredEdit::to_edit)
+            .collect::<Option<_>>()?,
+        adjustments: file
+            .adjustments
+            .as_ref()
+            .map(StoredAdjustments::to_adjustments)
+            .unwrap_or_default(),
+    })
+}
+
+/// Write the edits next to `original`; with nothing to store an existing
+/// sidecar is removed.
+///
+/// Returns the sidecar path.
+pub fn save(original: &Path, stored: &SidecarEdits) -> DocResult<PathBuf> {
+    let path = sidecar_path(original);
+    if stored.is_empty() {
+        if path.exists() {
+            fs::remove_file(&path)?;
+        }
+    } else {
+        temp_file::write_bytes(&path, to_json(stored)?)?;
+    }
+    Ok(path)
+}
+
+/// Edits stored next to `original`, if it has a readable sidecar.
+#[must_use]
+pub fn load(original: &Path) -> Option<SidecarEdits> {
+    let path = sidecar_path(original);
+    let json = fs::read_to_string(&path).ok()?;
+    let stored = parse(&json);
+    if stored.is_none() {
+        tracing::warn!("Ignoring invalid edit sidecar {}", path.display());
+    }
+    stored
+}
+
+/// Transforms that turn stored pixels as the EXIF orientation says.
+fn orientation_transforms(orientation: u16) -> &'static [TransformOperation] {
+    use TransformOperation::{FlipHorizontal, FlipVertical, RotateCcw, RotateCw};
+
+    match orientation {
+        2 => &[FlipHorizontal],
+        3 => &[RotateCw, RotateCw],
+        4 => &[FlipVertical],
+        5 => &[RotateCw, FlipHorizontal],
+        6 => &[RotateCw],
+        7 => &[RotateCcw, FlipHorizontal],
+        8 => &[RotateCcw],
+        _ => &[],
+    }
+}
+
+/// Transforms that take an image turned by `orientation_transforms` back to
+/// the stored pixels.
+fn undo_orientation(orientation: u16) -> &'static [TransformOperation] {
+    use TransformOperation::{FlipHorizontal, FlipVertical, RotateCcw, RotateCw};
+
+    match orientation {
+        2 => &[FlipHorizontal],
+        3 => &[RotateCw, RotateCw],
+        4 => &[FlipVertical],
+        5 => &[FlipHorizontal, RotateCcw],
+        6 => &[RotateCcw],
+        7 => &[FlipHorizontal, RotateCw],
+        8 => &[RotateCw],
+        _ => &[],
+    }
+}
+
+/// Edits that reproduce the orientation and crop of another tool's XMP
+/// sidecar on an image of the given size.
+///
+/// `applied` is the EXIF orientation the image was already turned by on load
+/// (1 if none); an orientation in the sidecar replaces it.
+#[must_use]
+pub fn from_xmp(xmp: XmpEdits, (width, height): (u32, u32), applied: u16) -> Vec<EditOperation> {
+    let target = xmp.orientation.unwrap_or(applied);
+    let transforms: Vec<TransformOperation> = if target == applied {
+        Vec::new()
+    } else {
+        [undo_orientation(applied), orientation_transforms(target)].concat()
+    };
+    let swaps = |orientation: u16| (5..=8).contains(&orientation);
+    let (width, height) = if swaps(applied) == swaps(target) {
+        (width, height)
+    } else {
+        (height, width)
+    };
+
+    let mut edits: Vec<EditOperation> = transforms
+        .into_iter()
+        .map(EditOperation::Transform)
+        .collect();
+    if let Some((x, y, crop_width, crop_height)) =
+        xmp.crop.and_then(|crop| crop.to_pixels(width, height))
+    {
+        edits.push(EditOperation::Crop(CropRegion::new(
+            x,
+            y,
+            crop_width,
+            crop_height,
+        )));
+    }
+    edits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sidecar_path() {
+        assert_eq!(
+            sidecar_path(Path::new("/photos/cat.jpg")),
+            PathBuf::from("/photos/cat.jpg.noctua.json")
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let stored = SidecarEdits {
+            edits: vec![
+                EditOperation::Transform(TransformOperation::RotateCw),
+                EditOperation::Transform(TransformOperation::FlipVertical),
+                EditOperation::Transform(TransformOperation::RotateTo(Rotation::Cw270)),
+                EditOperation::Crop(CropRegion::new(10, 20, 640, 480)),
+                EditOperation::Straighten(FineRotation::new(
+                    -2.5,
+                    RotateEdges::Clamp,
+                    RotateFilter::Bicubic,
+                )),
+                EditOperation::Resize(Resize::new(1920, 1080, ResampleFilter::Nearest).unwrap()),
+                EditOperation::CleanScan(ScanCleanup {
+                    despeckle: 4,
+                    whiten: 80,
+                }),
+            ],
+            adjustments: Adjustments {
+                exposure: 0.5,
+                saturation: -100.0,
+                threshold: Some(Threshold {
+                    level: 90,
+                    adaptive: true,
+                }),
+                ..Adjustments::default()
+            },
+        };
+        let json = to_json(&stored).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value["edits"][3],
+            serde_json::json!({"op": "crop", "x": 10, "y": 20, "width": 640, "height": 480})
+        );
+        assert_eq!(
+            value["edits"][4],
+            serde_json::json!({
+                "op": "straighten", "degrees": -2.5, "edges": "clamp", "filter": "bicubic"
+            })
+        );
+        assert_eq!(
+            value["edits"][5],
+            serde_json::json!({"op": "resize", "width": 1920, "height": 1080, "filter": "nearest"})
+        );
+        assert_eq!(
+            value["edits"][6],
+            serde_json::json!({"op": "clean-scan", "despeckle": 4, "whiten": 80})
+        );
+        assert_eq!(
+            value["adjustments"]["threshold"],
+            serde_json::json!({"level": 90, "adaptive": true})
+        );
+        assert_eq!(parse(&json), Some(stored));
+
+        // Neutral adjustments are left out
+        let empty = to_json(&SidecarEdits::default()).unwrap();
+        assert!(!empty.contains("adjustments"));
+        assert_eq!(parse(&empty), Some(SidecarEdits::default()));
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_edits() {
+        // Hand-edited whitespace and extra fields are fine, unknown operations are not
+        let json = "{\"edits\":[{ \"op\" : \"flip-horizontal\", \"note\": \"a}b\" },\
+                    {\"op\":\"crop\",\"x\":1,\"y\":2,\"width\":3,\"height\":4}]}";
+        assert_eq!(parse(json).map(|stored| stored.edits.len()), Some(2));
+        assert_eq!(parse("{\"edits\": [{\"op\": \"sharpen\"}]}"), None);
+        assert_eq!(parse("{\"edits\": [{\"op\": \"crop\", \"x\": 1}]}"), None);
+        assert_eq!(
+            parse("{\"edits\": [{\"op\": \"rotate-to\", \"degrees\": 45}]}"),
+            None
+        );
+        assert_eq!(parse("{\"version\": 1}"), None);
+        assert_eq!(parse("{\"edits\": [}"), None);
+    }
+
+    #[test]
+    fn test_parse_clamps_adjustments() {
+        let json = "{\"edits\": [], \"adjustments\": {\"exposure\": 9, \"brightness\": 0,\
+                    \"contrast\": -500, \"gamma\": 1, \"saturation\": 0,\
+                    \"threshold\": {\"level\": 255, \"adaptive\": false}}}";
+        let adjustments = parse(json).unwrap().adjustments;
+        assert_eq!(adjustments.exposure, 3.0);
+        assert_eq!(adjustments.contrast, -100.0);
+        assert_eq!(adjustments.threshold.map(|t| t.level), Some(254));
+    }
+
+    #[test]
+    fn test_parse_auto_crop_straighten() {
+        let json = "{\"edits\": [{\"op\": \"straighten\", \"degrees\": 3, \"auto-crop\": false}]}";
+        assert_eq!(
+            parse(json).map(|stored| stored.edits),
+            Some(vec![EditOperation::Straighten(FineRotation::new(
+                3.0,
+                RotateEdges::Transparent,
+                RotateFilter::Bilinear,
+            ))])
+        );
+        assert_eq!(
+            parse("{\"edits\": [{\"op\": \"straighten\", \"degrees\": 3}]}"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_from_xmp() {
+        use crate::domain::document::operations::xmp_edits::RelativeCrop;
+
+        // Portrait shot stored sideways, cropped in the upright orientation
+        let xmp = XmpEdits {
+            orientation: Some(6),
+            crop: Some(RelativeCrop {
+                left: 0.2,
+                top: 0.1,
+                right: 0.7,
+                bottom: 0.9,
+            }),
+        };
+        assert_eq!(
+            from_xmp(xmp, (100, 50), 1),
+            vec![
+                EditOperation::Transform(TransformOperation::RotateCw),
+                EditOperation::Crop(CropRegion::new(10, 10, 25, 80)),
+            ]
+        );
+        // Already turned upright on load: only the crop is left
+        assert_eq!(
+            from_xmp(xmp, (50, 100), 6),
+            vec![EditOperation::Crop(CropRegion::new(10, 10, 25, 80))]
+        );
+        // The sidecar's orientation replaces the one applied on load
+        let upright = XmpEdits {
+            orientation: Some(1),
+            crop: None,
+        };
+        assert_eq!(
+            from_xmp(upright, (50, 100), 8),
+            vec![EditOperation::Transform(TransformOperation::RotateCw)]
+        );
+    }
+}
@@ -5,7 +5,10 @@
 
 pub mod commands;
 pub mod document_manager;
+pub mod edit_history;
+pub mod edit_sidecar;
 pub mod services;
+pub mod session;
 
 // Re-export document manager
 pub use document_manager::DocumentManager;
@@ -0,0 +1,298 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/batch_convert.rs
+//
+// Batch conversion: images of a folder written in another format, size and
+// quality on a worker thread, one queued job after the other.
+
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use futures_util::Stream;
+use image::{DynamicImage, ImageDecoder, ImageReader};
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::domain::document::operations::exif_write::ExifCopy;
+use crate::domain::document::operations::export::ExportFormat;
+use crate::domain::document::operations::export_profile::ExportProfile;
+
+/// Images converted with the same settings into a folder.
+#[derive(Debug, Clone)]
+pub struct BatchJob {
+    /// Images to convert.
+    pub paths: Vec<PathBuf>,
+    /// Folder the converted images are written to.
+    pub output_dir: PathBuf,
+    /// Format, size, quality and naming of the written images.
+    pub profile: ExportProfile,
+    /// Write every image in the format of its original (PNG if that cannot
+    /// be written) instead of the profile's.
+    pub keep_format: bool,
+    /// Size limits each image is checked against.
+    pub limits: OpenLimits,
+}
+
+impl BatchJob {
+    /// Profile `path` is written with.
+    #[must_use]
+    pub fn profile_for(&self, path: &Path) -> ExportProfile {
+        let mut profile = self.profile.clone();
+        if self.keep_format {
+            profile.format = ExportFormat::from_path(path)
+                .filter(|format| ExportFormat::SAVE_FORMATS.contains(format))
+                .unwrap_or(ExportFormat::Png);
+        }
+        profile
+    }
+
+    /// Load `path` upright, convert it and write it into the output folder.
+    ///
+    /// `index` numbers the images of the job, starting at 1.
+    fn convert(&self, path: &Path, index: usize) -> DocResult<PathBuf> {
+        let profile = self.profile_for(path);
+        let metadata = if profile.keep_metadata
+            && matches!(profile.format, ExportFormat::Jpeg | ExportFormat::Png)
+        {
+            ExifCopy::read(path).unwrap_or_default()
+        } else {
+            ExifCopy::default()
+        };
+
+        let img = open_upright(path, &self.limits)?;
+        let target = profile.write_into(img, path, index, &self.output_dir)?;
+        if !metadata.is_empty()
+            && let Err(e) = metadata.write_to(&target)
+        {
+            tracing::warn!("Failed to copy metadata to {}: {e}", target.display());
+        }
+        Ok(target)
+    }
+}
+
+/// Progress of a running batch conversion.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchEvent {
+    /// Images handled so far, of the total.
+    Progress(usize, usize),
+    /// An image could not be converted, and why; the job goes on.
+    Failed(PathBuf, String),
+    /// The job ended: images written. Images written before it was
+    /// cancelled stay.
+    Finished(usize),
+}
+
+/// Jobs waiting for the running one to finish, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct JobQueue {
+    pending: VecDeque<BatchJob>,
+}
+
+impl JobQueue {
+    /// Add a job after the waiting ones.
+    pub fn push(&mut self, job: BatchJob) {
+        self.pending.push_back(job);
+    }
+
+    /// Take the job to run next.
+    pub fn take_next(&mut self) -> Option<BatchJob> {
+        self.pending.pop_front()
+    }
+
+    /// Number of waiting jobs.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Check if no job is waiting.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    /// Images of all waiting jobs.
+    #[must_use]
+    pub fn image_count(&self) -> usize {
+        self.pending.iter().map(|job| job.paths.len()).sum()
+    }
+
+    /// Drop the waiting jobs.
+    pub fn clear(&mut self) {
+        self.pending.clear();
+    }
+}
+
+/// Run `job` on a worker thread.
+///
+/// The stream yields a progress event for every image (after a failure
+/// event if it could not be converted) and always ends with the number of
+/// written images, also if the worker cannot start or panics. Cancelling
+/// `token` (or dropping the stream) stops the worker before the next image.
+pub fn convert_in_background(
+    job: BatchJob,
+    token: CancelToken,
+) -> impl Stream<Item = BatchEvent> + Send + 'static {
+    let written = Arc::new(AtomicUsize::new(0));
+    let output_dir = job.output_dir.clone();
+    worker::run_in_background(
+        "batch-convert",
+        EVENT_QUEUE_LENGTH,
+        {
+            let written = Arc::clone(&written);
+            move |events| {
+                let total = job.paths.len();
+                if let Err(e) = std::fs::create_dir_all(&job.output_dir) {
+                    let dir = job.output_dir.display();
+                    tracing::warn!("Batch conversion to {dir} failed: {e}");
+                    events.send(BatchEvent::Failed(job.output_dir.clone(), e.to_string()));
+                    events.send(BatchEvent::Finished(0));
+                    return;
+                }
+                for (index, path) in job.paths.iter().enumerate() {
+                    if token.is_cancelled() {
+                        break;
+                    }
+                    match job.convert(path, index + 1) {
+                        Ok(target) => {
+                            tracing::debug!("Converted {} to {}", path.display(), target.display());
+                            written.fetch_add(1, Ordering::Relaxed);
+                        }
+                        Err(e) => {
+                            tracing::warn!("Batch conversion failed for {}: {e}", path.display());
+                            events.send(BatchEvent::Failed(path.clone(), e.to_string()));
+                        }
+                    }
+                    if !events.send(BatchEvent::Progress(index + 1, total)) {
+                        return;
+                    }
+                }
+                let written = written.load(Ordering::Relaxed);
+                tracing::info!(
+                    "Converted {written} of {total} images into {}",
+                    job.output_dir.display()
+                );
+                events.send(BatchEvent::Finished(written));
+            }
+        },
+        // Images written before the failure stay
+        move |reason| {
+            vec![
+                BatchEvent::Failed(output_dir.clone(), reason),
+                BatchEvent::Finished(written.load(Ordering::Relaxed)),
+            ]
+        },
+    )
+}
+
+/// Decode `path` within `limits`, turned upright as its EXIF orientation says.
+fn open_upright(path: &Path, limits: &OpenLimits) -> DocResult<DynamicImage> {
+    let mut reader = ImageReader::open(path)?.with_guessed_format()?;
+    reader.limits(limits.decoder_limits());
+    let mut decoder = reader.into_decoder()?;
+    let (width, height) = decoder.dimensions();
+    limits.check_pixels(width, height)?;
+    let orientation = decoder.orientation()?;
+    let mut img = DynamicImage::from_decoder(decoder)?;
+    img.apply_orientation(orientation);
+    Ok(img)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    fn job(paths: Vec<PathBuf>, output_dir: PathBuf) -> BatchJob {
+        BatchJob {
+            paths,
+            output_dir,
+            profile: ExportProfile::parse("format jpg; fit 8x8; name {name}-small").unwrap(),
+            keep_format: false,
+            limits: OpenLimits::default(),
+        }
+    }
+
+    #[test]
+    fn test_queue_runs_jobs_in_order() {
+        let mut queue = JobQueue::default();
+        assert!(queue.take_next().is_none());
+        queue.push(job(vec![PathBuf::from("a.png")], PathBuf::from("/out/1")));
+        queue.push(job(
+            vec![PathBuf::from("b.png"), PathBuf::from("c.png")],
+            PathBuf::from("/out/2"),
+        ));
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.image_count(), 3);
+        assert_eq!(
+            queue.take_next().unwrap().output_dir,
+            PathBuf::from("/out/1")
+        );
+        assert_eq!(queue.len(), 1);
+        queue.clear();
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_keep_format_per_image() {
+        let mut job = job(Vec::new(), std::env::temp_dir());
+        let format = |job: &BatchJob, name: &str| job.profile_for(Path::new(name)).format;
+        assert_eq!(format(&job, "a.png"), ExportFormat::Jpeg);
+        job.keep_format = true;
+        assert_eq!(format(&job, "a.webp"), ExportFormat::WebP);
+        assert_eq!(format(&job, "a.gif"), ExportFormat::Png);
+    }
+
+    #[test]
+    fn test_converts_and_reports_failures() {
+        let dir = std::env::temp_dir().join("noctua-batch-convert-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let image = dir.join("square.png");
+        image::RgbImage::from_pixel(32, 16, image::Rgb([200, 40, 40]))
+            .save(&image)
+            .unwrap();
+        let missing = dir.join("missing.png");
+        let output_dir = dir.join("out");
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let events: Vec<_> = runtime.block_on(
+            convert_in_background(
+                job(vec![image, missing.clone()], output_dir.clone()),
+                CancelToken::new(),
+            )
+            .collect(),
+        );
+        assert!(matches!(
+            events.as_slice(),
+            [
+                BatchEvent::Progress(1, 2),
+                BatchEvent::Failed(path, _),
+                BatchEvent::Progress(2, 2),
+                BatchEvent::Finished(1),
+            ] if *path == missing
+        ));
+        let converted = image::open(output_dir.join("square-small.jpg")).unwrap();
+        assert_eq!((converted.width(), converted.height()), (8, 4));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_cancelled_job_writes_nothing() {
+        let token = CancelToken::new();
+        token.cancel();
+        let output_dir = std::env::temp_dir().join("noctua-batch-cancel-test");
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let events: Vec<_> = runtime.block_on(
+            convert_in_background(
+                job(vec![PathBuf::from("never-read.png")], output_dir.clone()),
+                token,
+            )
+            .collect(),
+        );
+        assert_eq!(events, vec![BatchEvent::Finished(0)]);
+        std::fs::remove_dir_all(&output_dir).ok();
+    }
+}
@@ -8,9 +8,7 @@
 
 use std::path::Path;
 
-use cosmic::widget::image::Handle as ImageHandle;
-use image::DynamicImage;
-
+use crate::domain::document::core::document::ImageHandle;
 use crate::infrastructure::cache::ThumbnailCache;
 
 /// Cache service for managing document caches.
@@ -30,19 +28,17 @@ impl CacheService {
     /// Returns None if the thumbnail is not cached or the cache is invalid.
     #[must_use]
     pub fn get_thumbnail(&self, path: &Path, page: usize) -> Option<ImageHandle> {
-        ThumbnailCache::load(path, page)
+        ThumbnailCache::for_file(path)?.load(page)
     }
 
     /// Save a thumbnail to cache.
     ///
     /// Returns true if the thumbnail was successfully cached.
-    pub fn put_thumbnail(&self, path: &Path, page: usize, image: &DynamicImage) -> bool {
-        ThumbnailCache::save(path, page, image).is_some()
+    pub fn put_thumbnail(&self, path: &Path, page: usize, thumbnail: &ImageHandle) -> bool {
+        ThumbnailCache::for_file(path).is_some_and(|cache| cache.save(page, thumbnail))
     }
 
     /// Clear all cached thumbnails.
-    ///
-    /// This operation is not yet implemented.
     pub fn clear_cache(&self) -> Result<(), String> {
         ThumbnailCache::clear_cache().map_err(|e| e.to_string())
     }
@@ -52,8 +48,7 @@ impl CacheService {
     /// Returns the total size in bytes, or None if it cannot be determined.
     #[must_use]
     pub fn cache_size(&self) -> Option<u64> {
-        // TODO: Implement cache size calculation
-        None
+        ThumbnailCache::size().ok()
     }
 }
 
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/date_shift.rs
+//
+// Date shift: capture times of the folder's images rewritten on a worker
+// thread, reporting progress as it goes.
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::application::commands::date_shift::ShiftDatesCommand;
+use crate::domain::document::operations::date_shift::DateShiftEntry;
+
+/// Progress of a running date shift.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DateShiftEvent {
+    /// Files handled so far, of the total.
+    Progress(usize, usize),
+    /// The shift ended: files updated, or why it failed.
+    Finished(Result<usize, String>),
+}
+
+/// Run `command` for `entries` on a worker thread.
+///
+/// The stream yields a progress event for every handled file and ends with
+/// the result. Dropping the stream stops the worker before the next file.
+pub fn shift_in_background(
+    command: ShiftDatesCommand,
+    entries: Vec<DateShiftEntry>,
+) -> impl Stream<Item = DateShiftEvent> + Send + 'static {
+    worker::run_in_background(
+        "date-shift",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let total = entries.len();
+            let finished = command
+                .execute(&entries, |done| {
+                    events.send(DateShiftEvent::Progress(done, total))
+                })
+                .map_err(|e| e.to_string());
+            match &finished {
+                Ok(count) => tracing::info!("Shifted the dates of {count} of {total} images"),
+                Err(e) => tracing::warn!("Date shift failed: {e}"),
+            }
+            events.send(DateShiftEvent::Finished(finished));
+        },
+        |reason| vec![DateShiftEvent::Finished(Err(reason))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::commands::date_shift::DateShiftTarget;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_failure_ends_the_stream() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let command = ShiftDatesCommand::new(3600, DateShiftTarget::Sidecar);
+        let events: Vec<_> = runtime.block_on(shift_in_background(command, Vec::new()).collect());
+        assert!(matches!(
+            events.as_slice(),
+            [DateShiftEvent::Finished(Err(_))]
+        ));
+    }
+}
@@ -0,0 +1,170 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/decode_service.rs
+//
+// Decode service: decodes images off the UI thread, skipping superseded ones.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::document::{DocResult, ImageHandle};
+use crate::domain::document::core::limits::OpenLimits;
+use crate::domain::document::core::metadata::DocumentMeta;
+use crate::domain::document::operations::embedded_preview::embedded_thumbnail;
+use crate::domain::document::operations::render::create_image_handle_from_image;
+use crate::domain::document::types::raster::RasterDocument;
+
+/// Decodes run one at a time: a load that waited here while the user moved
+/// on is cancelled by then and skips its decode.
+static DECODE_LANE: Mutex<()> = Mutex::new(());
+
+/// An image decoded off the UI thread, with its metadata.
+pub struct DecodedImage {
+    pub document: RasterDocument,
+    pub metadata: DocumentMeta,
+}
+
+/// Image to decode in the background, created by `DocumentManager::begin_decode`.
+pub struct DecodeJob {
+    path: PathBuf,
+    limits: OpenLimits,
+    auto_orient: bool,
+    token: CancelToken,
+}
+
+impl DecodeJob {
+    #[must_use]
+    pub fn new(path: PathBuf, limits: OpenLimits, auto_orient: bool, token: CancelToken) -> Self {
+        Self {
+            path,
+            limits,
+            auto_orient,
+            token,
+        }
+    }
+
+    /// Decode the image on a blocking thread.
+    pub async fn decode(self) -> DecodeResult {
+        let path = self.path.clone();
+        let token = self.token.clone();
+        let result = tokio::task::spawn_blocking(move || self.run())
+            .await
+            .unwrap_or_else(|e| Err(anyhow::anyhow!("Decoder crashed: {e}")));
+        DecodeResult {
+            path,
+            token,
+            result: Arc::new(Mutex::new(Some(result))),
+        }
+    }
+
+    /// Read the thumbnail embedded in the file on a blocking thread, to show
+    /// until the decode finishes. None if the file has none.
+    ///
+    /// Runs beside the decode lane, so it is not held up by earlier decodes.
+    pub fn preview(&self) -> impl Future<Output = Option<ImageHandle>> + use<> {
+        let path = self.path.clone();
+        let auto_orient = self.auto_orient;
+        async move {
+            tokio::task::spawn_blocking(move || {
+                embedded_thumbnail(&path, auto_orient)
+                    .map(|thumbnail| create_image_handle_from_image(&thumbnail))
+            })
+            .await
+            .ok()
+            .flatten()
+        }
+    }
+
+    fn run(&self) -> DocResult<DecodedImage> {
+        let _lane = DECODE_LANE
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        let mut document = RasterDocument::open_cancellable(&self.path, &self.limits, &self.token)?;
+        if self.auto_orient {
+            document.apply_stored_orientation();
+        }
+        self.token.check()?;
+        let metadata = document.extract_meta(&self.path);
+        Ok(DecodedImage { document, metadata })
+    }
+}
+
+/// Outcome of a [`DecodeJob`], carried to the UI in a message.
+///
+/// Messages are cloned, decoded images are not: the clones share the
+/// result and the first [`take`](Self::take) gets it.
+#[derive(Clone)]
+pub struct DecodeResult {
+    path: PathBuf,
+    token: CancelToken,
+    result: Arc<Mutex<Option<DocResult<DecodedImage>>>>,
+}
+
+impl DecodeResult {
+    /// File that was decoded.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Check if a newer load superseded this one.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Take the decoded image (None if already taken).
+    pub fn take(&self) -> Option<DocResult<DecodedImage>> {
+        self.result.lock().ok()?.take()
+    }
+}
+
+impl fmt::Debug for DecodeResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DecodeResult")
+            .field("path", &self.path)
+            .field("cancelled", &self.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::core::cancel::Cancelled;
+
+    fn job(path: &Path, token: &CancelToken) -> DecodeJob {
+        DecodeJob::new(
+            path.to_path_buf(),
+            OpenLimits::default(),
+            true,
+            token.clone(),
+        )
+    }
+
+    #[test]
+    fn test_decode() {
+        let dir = std::env::temp_dir().join("noctua-decode-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("owl.png");
+        image::RgbaImage::new(3, 2).save(&path).unwrap();
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+
+        let decoded = runtime.block_on(job(&path, &CancelToken::new()).decode());
+        assert_eq!(decoded.path(), path);
+        let image = decoded.clone().take().unwrap().unwrap();
+        assert_eq!(image.document.dimensions(), (3, 2));
+        // Clones share the result
+        assert!(decoded.take().is_none());
+
+        let token = CancelToken::new();
+        token.cancel();
+        let cancelled = runtime.block_on(job(&path, &token).decode());
+        assert!(cancelled.is_cancelled());
+        let error = cancelled.take().unwrap().err().unwrap();
+        assert!(error.is::<Cancelled>());
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
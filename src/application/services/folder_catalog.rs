@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/folder_catalog.rs
+//
+// Folder catalog: key metadata of the folder's files written as CSV or JSON
+// on a worker thread, reporting progress as it goes.
+
+use std::path::PathBuf;
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::temp_file;
+use crate::domain::document::operations::catalog::{CatalogEntry, CatalogFormat};
+
+/// Progress of a running catalog export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CatalogEvent {
+    /// Files read so far, of the total.
+    Progress(usize, usize),
+    /// The export ended: files in the written catalog, or why it failed.
+    /// Nothing is written if it was cancelled.
+    Finished(Result<usize, String>),
+}
+
+/// Read the metadata of `paths` on a worker thread and write the catalog
+/// to `target`, in the format its extension names.
+///
+/// The stream yields a progress event for every read file and ends with the
+/// result. Cancelling `token` (or dropping the stream) stops the worker
+/// before the next file, without writing the catalog.
+pub fn export_in_background(
+    paths: Vec<PathBuf>,
+    target: PathBuf,
+    token: CancelToken,
+) -> impl Stream<Item = CatalogEvent> + Send + 'static {
+    worker::run_in_background(
+        "folder-catalog",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let total = paths.len();
+            let mut entries = Vec::with_capacity(total);
+            for path in &paths {
+                if token.is_cancelled() {
+                    events.send(CatalogEvent::Finished(Ok(0)));
+                    return;
+                }
+                entries.push(CatalogEntry::read(path));
+                if !events.send(CatalogEvent::Progress(entries.len(), total)) {
+                    return;
+                }
+            }
+
+            let catalog = CatalogFormat::from_path(&target).write(&entries);
+            let finished = temp_file::write_bytes(&target, catalog)
+                .map(|()| entries.len())
+                .map_err(|e| format!("{}: {e}", target.display()));
+            match &finished {
+                Ok(count) => {
+                    tracing::info!("Wrote a catalog of {count} files to {}", target.display())
+                }
+                Err(e) => tracing::warn!("Folder catalog failed: {e}"),
+            }
+            events.send(CatalogEvent::Finished(finished));
+        },
+        |reason| vec![CatalogEvent::Finished(Err(reason))],
+    )
+}
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/geotag.rs
+//
+// Geotagging: GPS tags of the selected images written on a worker thread,
+// reporting progress and the files that failed as it goes.
+
+use std::path::PathBuf;
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::application::commands::geotag::GeotagCommand;
+
+/// Progress of a running geotag write.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GeotagEvent {
+    /// Files handled so far, of the total.
+    Progress(usize, usize),
+    /// A file could not be updated, and why; the others go on.
+    Failed(PathBuf, String),
+    /// The write ended: files updated, or why none was.
+    Finished(Result<usize, String>),
+}
+
+/// Run `command` on a worker thread.
+///
+/// The stream yields a progress event for every handled file (after a
+/// failure event if it could not be updated) and ends with the result.
+/// Dropping the stream stops the worker before the next file.
+pub fn write_in_background(
+    command: GeotagCommand,
+) -> impl Stream<Item = GeotagEvent> + Send + 'static {
+    worker::run_in_background(
+        "geotag",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let total = command.paths.len();
+            let finished = command
+                .execute(|done, failed| {
+                    if let Some((path, e)) = failed
+                        && !events.send(GeotagEvent::Failed(path.to_path_buf(), e.to_string()))
+                    {
+                        return false;
+                    }
+                    events.send(GeotagEvent::Progress(done, total))
+                })
+                .map_err(|e| e.to_string());
+            match &finished {
+                Ok(count) => tracing::info!("Geotagged {count} of {total} images"),
+                Err(e) => tracing::warn!("Geotag failed: {e}"),
+            }
+            events.send(GeotagEvent::Finished(finished));
+        },
+        |reason| vec![GeotagEvent::Finished(Err(reason))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_reports_each_failed_file() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let dir = std::env::temp_dir().join("noctua-geotag-service-test");
+        let paths = vec![dir.join("missing-a.jpg"), dir.join("missing-b.jpg")];
+        let command = GeotagCommand::new(None, paths.clone());
+        let events: Vec<_> = runtime.block_on(write_in_background(command).collect());
+
+        let failed: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                GeotagEvent::Failed(path, _) => Some(path.clone()),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(failed, paths);
+        assert!(events.contains(&GeotagEvent::Progress(2, 2)));
+        assert!(matches!(events.last(), Some(GeotagEvent::Finished(Err(_)))));
+    }
+}
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/heading_outline.rs
+//
+// Heading outline: a rough outline for scanned documents without one, from
+// the lines in large type that text recognition finds on each page.
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::domain::document::core::page::{OutlineEntry, PageRenderJob};
+use crate::infrastructure::system::ocr::{self, OcrLine};
+
+/// Resolution pages are rendered at for recognition; plenty for headings.
+pub const OCR_DPI: f64 = 150.0;
+
+/// Lines this many times the height of the body text are headings.
+const HEADING_RATIO: f32 = 1.4;
+
+/// Headings this many times the height of the body text are top level.
+const TITLE_RATIO: f32 = 1.9;
+
+/// Pages with fewer lines have no body text to compare with.
+const MIN_LINES: usize = 3;
+
+/// Longer lines are display text (e.g. a pull quote), not headings.
+const MAX_HEADING_WORDS: usize = 12;
+
+/// Lines recognized with less confidence are mostly noise or pictures.
+const MIN_CONFIDENCE: f32 = 60.0;
+
+/// Titles are cut to this many characters.
+const MAX_TITLE_CHARS: usize = 80;
+
+/// Progress of a running heading search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OutlineEvent {
+    /// Headings of a page (possibly none), and the pages read so far of the total.
+    Page {
+        entries: Vec<OutlineEntry>,
+        done: usize,
+        total: usize,
+    },
+    /// The search ended: headings found, or why it stopped.
+    Finished(Result<usize, String>),
+}
+
+/// Headings among the recognized `lines` of a page, with their level
+/// (0 for the largest type, 1 for the others).
+///
+/// The median line height is taken as the body text; lines clearly taller
+/// than it, short and read with confidence are headings. A heading set
+/// over two lines gives one title.
+#[must_use]
+pub fn headings(lines: &[OcrLine]) -> Vec<(String, usize)> {
+    if lines.len() < MIN_LINES {
+        return Vec::new();
+    }
+    let mut heights: Vec<u32> = lines.iter().map(|line| line.height).collect();
+    heights.sort_unstable();
+    let body = heights[heights.len() / 2].max(1) as f32;
+
+    let mut found: Vec<(String, usize)> = Vec::new();
+    let mut previous: Option<&OcrLine> = None;
+    for line in lines {
+        let ratio = line.height as f32 / body;
+        let letters = line.text.chars().filter(|c| c.is_alphabetic()).count();
+        if ratio < HEADING_RATIO
+            || letters < 3
+            || line.text.split_whitespace().count() > MAX_HEADING_WORDS
+            || line.confidence < MIN_CONFIDENCE
+        {
+            previous = None;
+            continue;
+        }
+        let level = usize::from(ratio < TITLE_RATIO);
+        match (previous, found.last_mut()) {
+            (Some(above), Some((title, above_level)))
+                if *above_level == level && line.top <= above.bottom() + above.height / 2 =>
+            {
+                title.push(' ');
+                title.push_str(&line.text);
+            }
+            _ => found.push((line.text.clone(), level)),
+        }
+        previous = Some(line);
+    }
+
+    for (title, _) in &mut found {
+        if let Some((cut, _)) = title.char_indices().nth(MAX_TITLE_CHARS) {
+            title.truncate(cut);
+            title.push('…');
+        }
+    }
+    found
+}
+
+/// Render the pages of `job` and look for headings on them on a worker
+/// thread, reading them in `language` (a tesseract language code).
+///
+/// The stream yields the headings of every page read and ends with the
+/// result. Cancelling `token` (or dropping the stream) stops the worker
+/// after the page it is reading.
+pub fn outline_in_background(
+    job: PageRenderJob,
+    language: String,
+    limits: OpenLimits,
+    token: CancelToken,
+) -> impl Stream<Item = OutlineEvent> + Send + 'static {
+    worker::run_in_background(
+        "heading-outline",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let total = job.pages.len();
+            let mut done = 0;
+            let mut found = 0;
+            let mut failed = None;
+            let result = job.run(&limits, |page, image| {
+                let lines = match ocr::recognize_lines(&image, &language) {
+                    Ok(lines) => lines,
+                    Err(e) => {
+                        failed = Some(format!("{e:#}"));
+                        return false;
+                    }
+                };
+                let entries: Vec<OutlineEntry> = headings(&lines)
+                    .into_iter()
+                    .map(|(title, level)| OutlineEntry { title, page, level })
+                    .collect();
+                found += entries.len();
+                done += 1;
+                events.send(OutlineEvent::Page {
+                    entries,
+                    done,
+                    total,
+                }) && !token.is_cancelled()
+            });
+
+            let finished = match (result, failed) {
+                (Err(e), _) => Err(e.to_string()),
+                (Ok(()), Some(e)) => Err(e),
+                (Ok(()), None) => Ok(found),
+            };
+            if let Err(e) = &finished {
+                tracing::warn!("Heading search in {} stopped: {e}", job.path.display());
+            }
+            events.send(OutlineEvent::Finished(finished));
+        },
+        |reason| vec![OutlineEvent::Finished(Err(reason))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str, top: u32, height: u32) -> OcrLine {
+        OcrLine {
+            text: text.to_string(),
+            top,
+            height,
+            confidence: 90.0,
+        }
+    }
+
+    #[test]
+    fn test_headings() {
+        let lines = [
+            line("12", 40, 20),
+            line("CHAPTER THREE", 120, 64),
+            line("The Owls of", 260, 40),
+            line("the Northern Forest", 304, 40),
+            line("Night had fallen over the valley when the first", 400, 22),
+            line("calls echoed between the old pines and firs.", 430, 22),
+            line("Most of the birds had left their nests by then.", 460, 22),
+            line("Only the young stayed, waiting for the adults", 490, 22),
+            line("to come back with mice, voles and now and then", 520, 22),
+            line("a frog from the stream below the clearing.", 550, 22),
+            OcrLine {
+                confidence: 30.0,
+                ..line("~~ :: ~~", 600, 60)
+            },
+            line("Hunting", 680, 34),
+            line("They hunted in pairs, one flying low above the", 730, 22),
+        ];
+        assert_eq!(
+            headings(&lines),
+            vec![
+                ("CHAPTER THREE".to_string(), 0),
+                ("The Owls of the Northern Forest".to_string(), 1),
+                ("Hunting".to_string(), 1),
+            ]
+        );
+
+        // A title page alone has no body text to tell headings by
+        assert!(headings(&lines[1..3]).is_empty());
+    }
+
+    #[test]
+    fn test_long_titles_are_cut() {
+        let long = "Owl ".repeat(10).trim_end().to_string() + &"s".repeat(60);
+        let lines = [
+            line(&long, 0, 60),
+            line("body", 100, 20),
+            line("body", 130, 20),
+        ];
+        let found = headings(&lines);
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].0.chars().count(), MAX_TITLE_CHARS + 1);
+        assert!(found[0].0.ends_with('…'));
+    }
+}
@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/macro_run.rs
+//
+// Macro runs: an edit macro replayed on images on a worker thread,
+// reporting progress as it goes.
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::application::commands::run_macro::RunMacroCommand;
+use crate::domain::document::core::cancel::CancelToken;
+
+/// Progress of a running macro.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MacroEvent {
+    /// Images handled so far, of the total.
+    Progress(usize, usize),
+    /// The run ended: images written, or why it failed.
+    Finished(Result<usize, String>),
+}
+
+/// Run `command` on a worker thread.
+///
+/// The stream yields a progress event for every handled image and ends
+/// with the result. Cancelling `token` (or dropping the stream) stops the
+/// worker before the next image; images written until then stay.
+pub fn run_in_background(
+    command: RunMacroCommand,
+    token: CancelToken,
+) -> impl Stream<Item = MacroEvent> + Send + 'static {
+    worker::run_in_background(
+        "macro-run",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let total = command.paths.len();
+            let finished = command
+                .execute(|done| {
+                    events.send(MacroEvent::Progress(done, total)) && !token.is_cancelled()
+                })
+                .map_err(|e| e.to_string());
+            match &finished {
+                Ok(count) => tracing::info!(
+                    "Macro wrote {count} of {total} images into {}",
+                    command.output_dir.display()
+                ),
+                Err(e) => tracing::warn!("Macro failed: {e}"),
+            }
+            events.send(MacroEvent::Finished(finished));
+        },
+        |reason| vec![MacroEvent::Finished(Err(reason))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_macro_without_steps_fails() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let command = RunMacroCommand::new(Vec::new(), Vec::new(), std::env::temp_dir());
+        let events: Vec<_> =
+            runtime.block_on(run_in_background(command, CancelToken::new()).collect());
+        assert!(matches!(events.as_slice(), [MacroEvent::Finished(Err(_))]));
+    }
+}
@@ -1,7 +1,25 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // src/application/services/mod.rs
 //
-// Application services: cache management and preview generation.
+// Application services: cache management, background decoding, preview
+// generation, the thumbnail worker, page export, folder catalogs, uploads,
+// refining large pages, thumbnails of the recent files, outlines of
+// scanned documents, batch conversion, date shifts, geotagging, macro runs
+// and privacy exports, and the worker they run on.
 
+pub mod batch_convert;
 pub mod cache_service;
+pub mod date_shift;
+pub mod decode_service;
+pub mod folder_catalog;
+pub mod geotag;
+pub mod heading_outline;
+pub mod macro_run;
+pub mod page_export;
+pub mod page_refine;
 pub mod preview_service;
+pub mod privacy_export;
+pub mod recent_thumbnails;
+pub mod thumbnail_worker;
+pub mod upload;
+pub mod worker;
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/page_export.rs
+//
+// Page export: renders pages of a PDF to image files on a worker thread,
+// reporting progress as it goes.
+
+use std::path::{Path, PathBuf};
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::domain::document::core::metadata::Resolution;
+use crate::domain::document::core::page::PageRenderJob;
+use crate::domain::document::operations::export::{self, ExportFormat, ImageExportOptions};
+
+/// Largest resolution pages are exported at.
+pub const MAX_DPI: f64 = 1200.0;
+
+/// Pages exported at a resolution into a folder.
+#[derive(Debug, Clone)]
+pub struct PageExport {
+    /// Pages to render, with their transforms and the resolution.
+    pub job: PageRenderJob,
+    /// Pages of the whole document, for the width of the page numbers.
+    pub page_count: usize,
+    /// Folder the images are written to.
+    pub output_dir: PathBuf,
+    /// PNG or JPEG.
+    pub format: ExportFormat,
+    /// JPEG quality (1-100).
+    pub quality: u8,
+    /// Size limits each rendered page is checked against.
+    pub limits: OpenLimits,
+}
+
+/// Progress of a running page export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageExportEvent {
+    /// Pages written so far, of the total.
+    Progress(usize, usize),
+    /// The export ended: pages written, or why it stopped.
+    Finished(Result<usize, String>),
+}
+
+/// Image file name of `page` (0-based), e.g. `report-p07.png`.
+///
+/// Page numbers are padded to the digits of `page_count`, so the files
+/// sort in page order.
+#[must_use]
+pub fn page_file_name(
+    source: &Path,
+    page: usize,
+    page_count: usize,
+    format: ExportFormat,
+) -> String {
+    let stem = source
+        .file_stem()
+        .map_or_else(|| "page".into(), |s| s.to_string_lossy());
+    let width = page_count.max(1).to_string().len();
+    format!("{stem}-p{:0width$}.{}", page + 1, format.extension())
+}
+
+/// Run `export` on a worker thread.
+///
+/// The stream yields a progress event for every written page and ends with
+/// the result. Cancelling `token` (or dropping the stream) stops the
+/// worker after the page it is rendering; pages written until then stay.
+pub fn export_in_background(
+    export: PageExport,
+    token: CancelToken,
+) -> impl Stream<Item = PageExportEvent> + Send + 'static {
+    worker::run_in_background(
+        "page-export",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let total = export.job.pages.len();
+            let options = ImageExportOptions {
+                quality: export.quality,
+                resolution: Some(Resolution::uniform(export.job.dpi)),
+                ..ImageExportOptions::default()
+            };
+            let mut written = 0;
+            let mut failed = None;
+            let result = export.job.run(&export.limits, |page, image| {
+                let name = page_file_name(&export.job.path, page, export.page_count, export.format);
+                let target = export.output_dir.join(name);
+                if let Err(e) = export::export_image(&image, &target, export.format, &options) {
+                    failed = Some(format!("{}: {e}", target.display()));
+                    return false;
+                }
+                written += 1;
+                events.send(PageExportEvent::Progress(written, total)) && !token.is_cancelled()
+            });
+
+            let finished = match (result, failed) {
+                (Err(e), _) => Err(e.to_string()),
+                (Ok(()), Some(e)) => Err(e),
+                (Ok(()), None) => Ok(written),
+            };
+            if let Err(e) = &finished {
+                tracing::warn!("Page export of {} stopped: {e}", export.job.path.display());
+            }
+            events.send(PageExportEvent::Finished(finished));
+        },
+        |reason| vec![PageExportEvent::Finished(Err(reason))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_page_file_name() {
+        let source = Path::new("/docs/report.pdf");
+        assert_eq!(
+            page_file_name(source, 6, 12, ExportFormat::Png),
+            "report-p07.png"
+        );
+        assert_eq!(
+            page_file_name(source, 0, 5, ExportFormat::Jpeg),
+            "report-p1.jpg"
+        );
+        assert_eq!(
+            page_file_name(source, 41, 120, ExportFormat::Png),
+            "report-p042.png"
+        );
+    }
+
+    #[test]
+    fn test_missing_file_finishes_with_error() {
+        let export = PageExport {
+            job: PageRenderJob {
+                path: std::env::temp_dir().join("noctua-missing-owl.pdf"),
+                pages: vec![0, 1],
+                transforms: Vec::new(),
+                dpi: 150.0,
+            },
+            page_count: 2,
+            output_dir: std::env::temp_dir(),
+            format: ExportFormat::Png,
+            quality: 90,
+            limits: OpenLimits::default(),
+        };
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let events: Vec<_> =
+            runtime.block_on(export_in_background(export, CancelToken::new()).collect());
+        assert!(matches!(
+            events.as_slice(),
+            [PageExportEvent::Finished(Err(_))]
+        ));
+    }
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/page_refine.rs
+//
+// Page refine service: renders large PDF pages in full off the UI thread,
+// while a quick preview of them is shown.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+use image::DynamicImage;
+
+use crate::domain::document::core::cancel::{CancelToken, Cancelled};
+use crate::domain::document::core::content::ZoomRenderJob;
+use crate::domain::document::core::document::DocResult;
+
+/// Full render of the current page, created by `DocumentManager::begin_refine`.
+pub struct RefineJob {
+    job: ZoomRenderJob,
+    token: CancelToken,
+}
+
+impl RefineJob {
+    #[must_use]
+    pub fn new(job: ZoomRenderJob, token: CancelToken) -> Self {
+        Self { job, token }
+    }
+
+    /// Render the page on a blocking thread.
+    ///
+    /// Stops early once the page is left, turned or flipped, which cancels
+    /// the token.
+    pub async fn render(self) -> RefineResult {
+        let job = self.job.clone();
+        let token = self.token.clone();
+        let result = tokio::task::spawn_blocking(move || self.job.run_cancellable(&self.token))
+            .await
+            .unwrap_or_else(|e| Err(anyhow::anyhow!("Renderer crashed: {e}")));
+        if let Err(e) = &result
+            && e.downcast_ref::<Cancelled>().is_none()
+        {
+            tracing::warn!("Failed to render PDF page: {e}");
+        }
+        RefineResult {
+            job,
+            token,
+            rendered: Arc::new(Mutex::new(result.ok())),
+        }
+    }
+}
+
+/// Outcome of a [`RefineJob`], carried to the UI in a message.
+///
+/// Like a decode result, the clones share the page and the first
+/// [`take`](Self::take) gets it.
+#[derive(Clone)]
+pub struct RefineResult {
+    job: ZoomRenderJob,
+    token: CancelToken,
+    rendered: Arc<Mutex<Option<DynamicImage>>>,
+}
+
+impl RefineResult {
+    /// Render that was run.
+    #[must_use]
+    pub fn job(&self) -> &ZoomRenderJob {
+        &self.job
+    }
+
+    /// Check if the page was left before the render finished.
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Take the rendered page (None if it failed or was already taken).
+    pub fn take(&self) -> Option<DynamicImage> {
+        self.rendered.lock().ok()?.take()
+    }
+}
+
+impl fmt::Debug for RefineResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RefineResult")
+            .field("job", &self.job)
+            .field("cancelled", &self.is_cancelled())
+            .finish_non_exhaustive()
+    }
+}
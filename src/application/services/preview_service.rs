@@ -6,10 +6,8 @@
 
 #![allow(dead_code)]
 
-use cosmic::widget::image::Handle as ImageHandle;
-
 use crate::domain::document::core::content::DocumentContent;
-use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::document::{DocResult, ImageHandle};
 
 /// Preview service for generating document thumbnails and previews.
 ///
@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/privacy_export.rs
+//
+// Privacy export: blurred copies of the selected images written on a
+// worker thread, reporting progress as it goes.
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::application::commands::privacy_export::PrivacyExportCommand;
+
+/// Progress of a running privacy export.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PrivacyExportEvent {
+    /// Images handled so far, of the total.
+    Progress(usize, usize),
+    /// The export ended: copies written, or why it failed.
+    Finished(Result<usize, String>),
+}
+
+/// Run `command` on a worker thread.
+///
+/// The stream yields a progress event for every handled image and ends with
+/// the result. Dropping the stream stops the worker before the next image.
+pub fn export_in_background(
+    command: PrivacyExportCommand,
+) -> impl Stream<Item = PrivacyExportEvent> + Send + 'static {
+    worker::run_in_background(
+        "privacy-export",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let total = command.paths.len();
+            let finished = command
+                .execute(|done| events.send(PrivacyExportEvent::Progress(done, total)))
+                .map_err(|e| e.to_string());
+            match &finished {
+                Ok(count) => tracing::info!(
+                    "Exported {count} of {total} blurred copies into {}",
+                    command.output_dir.display()
+                ),
+                Err(e) => tracing::warn!("Privacy export failed: {e}"),
+            }
+            events.send(PrivacyExportEvent::Finished(finished));
+        },
+        |reason| vec![PrivacyExportEvent::Finished(Err(reason))],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_export_without_regions_fails() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let command = PrivacyExportCommand::new(Vec::new(), Vec::new(), std::env::temp_dir());
+        let events: Vec<_> = runtime.block_on(export_in_background(command).collect());
+        assert!(matches!(
+            events.as_slice(),
+            [PrivacyExportEvent::Finished(Err(_))]
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/recent_thumbnails.rs
+//
+// Thumbnails of the recently opened documents for the start screen.
+
+use std::path::{Path, PathBuf};
+
+use futures_util::{Stream, StreamExt, stream};
+
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::core::document::ImageHandle;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::domain::document::operations::embedded_preview::embedded_thumbnail;
+use crate::domain::document::operations::render::create_image_handle_from_image;
+use crate::domain::document::types::raster::RasterDocument;
+use crate::infrastructure::cache::ThumbnailCache;
+use crate::infrastructure::loaders::DocumentLoaderFactory;
+
+/// Largest side of a start screen thumbnail, in pixels.
+pub const THUMBNAIL_SIZE: u32 = 192;
+
+/// Load the thumbnails of `paths` one after another off the UI thread.
+///
+/// The stream yields each file with its thumbnail as soon as it is ready;
+/// files without one (gone, or never shown as a document with pages) are
+/// left out.
+pub fn load_in_background(
+    paths: Vec<PathBuf>,
+    limits: OpenLimits,
+    auto_orient: bool,
+) -> impl Stream<Item = (PathBuf, ImageHandle)> + Send + 'static {
+    stream::iter(paths).filter_map(move |path| async move {
+        tokio::task::spawn_blocking(move || {
+            let thumbnail = thumbnail(&path, &limits, auto_orient)?;
+            Some((path, thumbnail))
+        })
+        .await
+        .ok()
+        .flatten()
+    })
+}
+
+/// Thumbnail of the document at `path`.
+///
+/// Photos give the thumbnail embedded in them, other images are decoded
+/// and scaled down. PDFs and SVGs are not rendered again: their first page
+/// thumbnail is taken from the disk cache if they were shown before.
+fn thumbnail(path: &Path, limits: &OpenLimits, auto_orient: bool) -> Option<ImageHandle> {
+    if DocumentLoaderFactory::new().detect_kind(path)? != DocumentKind::Raster {
+        return ThumbnailCache::for_file(path)?.load(0);
+    }
+    let image = match embedded_thumbnail(path, auto_orient) {
+        Some(image) => image,
+        None => {
+            let mut document = RasterDocument::open(path, limits).ok()?;
+            if auto_orient {
+                document.apply_stored_orientation();
+            }
+            document.image().thumbnail(THUMBNAIL_SIZE, THUMBNAIL_SIZE)
+        }
+    };
+    Some(create_image_handle_from_image(&image))
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/thumbnail_worker.rs
+//
+// Thumbnail worker: renders page thumbnails off the UI thread, reusing the
+// ones in the disk cache.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use futures_util::Stream;
+
+use super::worker;
+use crate::domain::document::core::document::ImageHandle;
+use crate::domain::document::core::page::ThumbnailJob;
+use crate::infrastructure::cache::ThumbnailCache;
+use crate::infrastructure::cache::thumbnail_cache::MAX_CACHE_SIZE;
+
+/// Thumbnails rendered ahead of the UI; the worker waits while this many are queued.
+const QUEUE_LENGTH: usize = 8;
+
+/// Pause after each rendered thumbnail in low-power mode.
+const LOW_POWER_PAUSE: Duration = Duration::from_millis(300);
+
+/// Whether workers pause between renders, to save battery.
+static LOW_POWER: AtomicBool = AtomicBool::new(false);
+
+/// Pause between renders (`true`) or render at full speed, in running
+/// workers too.
+pub fn set_low_power(low_power: bool) {
+    LOW_POWER.store(low_power, Ordering::Relaxed);
+}
+
+/// Render the thumbnails of `job` on a worker thread.
+///
+/// Pages are taken from the job's queue, so moving its visible range
+/// reorders the pages still to come. Thumbnails in the disk cache are
+/// loaded instead of rendered, rendered ones are added to it. The stream
+/// yields each page index with its thumbnail as soon as it is ready, and
+/// ends when all are done. Dropping
+/// the stream stops the worker after the page it is rendering. In
+/// low-power mode the worker rests after each rendered page.
+pub fn render_in_background(
+    job: ThumbnailJob,
+) -> impl Stream<Item = (usize, ImageHandle)> + Send + 'static {
+    worker::run_in_background(
+        "thumbnails",
+        QUEUE_LENGTH,
+        move |events| {
+            let cache = ThumbnailCache::for_file(&job.path);
+            let result = job.run_cached(
+                |page| cache.as_ref()?.load(page),
+                |page, handle| {
+                    let rendered = cache.as_ref().is_none_or(|cache| !cache.has(page));
+                    if rendered && let Some(cache) = &cache {
+                        cache.save(page, &handle);
+                    }
+                    let sent = events.send((page, handle));
+                    if rendered && LOW_POWER.load(Ordering::Relaxed) {
+                        thread::sleep(LOW_POWER_PAUSE);
+                    }
+                    sent
+                },
+            );
+            if let Err(e) = result {
+                tracing::warn!("Failed to render thumbnails of {}: {e}", job.path.display());
+            }
+            if cache.is_some()
+                && let Err(e) = ThumbnailCache::evict(MAX_CACHE_SIZE)
+            {
+                tracing::warn!("Failed to trim the thumbnail cache: {e}");
+            }
+        },
+        // The thumbnails rendered so far stay; the others are missing
+        |_| Vec::new(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::document::core::page::ThumbnailQueue;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_missing_file_ends_stream() {
+        let job = ThumbnailJob {
+            path: std::env::temp_dir().join("noctua-missing-owl.pdf"),
+            queue: ThumbnailQueue::new(0..3),
+        };
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let thumbnails: Vec<_> = runtime.block_on(render_in_background(job).collect());
+        assert!(thumbnails.is_empty());
+    }
+}
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/upload.rs
+//
+// Image upload: an exported image sent to the upload endpoint on a worker
+// thread, reporting progress as it goes.
+
+use std::path::PathBuf;
+
+use futures_util::Stream;
+
+use super::worker::{self, EVENT_QUEUE_LENGTH};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::infrastructure::system::upload::UploadEndpoint;
+
+/// Progress of a running upload.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UploadEvent {
+    /// Percentage of the file sent so far.
+    Progress(u8),
+    /// The upload ended: the link to the file, or why it failed.
+    Finished(Result<String, String>),
+}
+
+/// Upload `file` to `endpoint` on a worker thread, removing the file
+/// afterwards.
+///
+/// The stream yields a progress event whenever the sent percentage grows
+/// and ends with the result. Cancelling `token` stops the upload.
+pub fn upload_in_background(
+    endpoint: UploadEndpoint,
+    file: PathBuf,
+    token: CancelToken,
+) -> impl Stream<Item = UploadEvent> + Send + 'static {
+    worker::run_in_background(
+        "upload",
+        EVENT_QUEUE_LENGTH,
+        move |events| {
+            let mut sent = 0;
+            let finished = endpoint
+                .upload(&file, &token, |percent| {
+                    if percent > sent {
+                        sent = percent;
+                        events.send(UploadEvent::Progress(percent));
+                    }
+                })
+                .map_err(|e| format!("{e:#}"));
+            match &finished {
+                Ok(link) => tracing::info!("Uploaded {} to {link}", file.display()),
+                Err(e) => tracing::warn!("Upload of {} failed: {e}", file.display()),
+            }
+            if let Err(e) = std::fs::remove_file(&file) {
+                tracing::warn!("Failed to remove {}: {e}", file.display());
+            }
+            events.send(UploadEvent::Finished(finished));
+        },
+        |reason| vec![UploadEvent::Finished(Err(reason))],
+    )
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/services/worker.rs
+//
+// Background jobs: work run on a named thread, sending its events to a
+// stream the UI subscribes to.
+
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
+use std::thread;
+
+use futures_util::{Stream, stream};
+use tokio::sync::mpsc;
+
+/// Events a job sends before it waits for the UI to catch up.
+pub const EVENT_QUEUE_LENGTH: usize = 64;
+
+/// Sending end of a job's event stream.
+pub struct Events<T> {
+    sender: mpsc::Sender<T>,
+}
+
+impl<T> Events<T> {
+    /// Send `event`, waiting while the queue is full.
+    ///
+    /// Returns false once the stream is dropped; the job should stop then.
+    pub fn send(&self, event: T) -> bool {
+        self.sender.blocking_send(event).is_ok()
+    }
+}
+
+/// Run `work` on a thread called `name` and stream the events it sends.
+///
+/// At most `queue_length` events wait for the UI; then `work` waits too.
+/// If the thread cannot be started or `work` panics, the events of
+/// `failed` (given the reason, and no more than `queue_length`) end the
+/// stream instead, so a job always ends with its terminal event. Dropping
+/// the stream makes [`Events::send`] return false.
+pub fn run_in_background<T: Send + 'static>(
+    name: &str,
+    queue_length: usize,
+    work: impl FnOnce(&Events<T>) + Send + 'static,
+    failed: impl Fn(String) -> Vec<T> + Send + Sync + 'static,
+) -> impl Stream<Item = T> + Send + 'static {
+    let failed = Arc::new(failed);
+    let (sender, receiver) = mpsc::channel(queue_length.max(1));
+
+    let spawned = thread::Builder::new().name(name.to_string()).spawn({
+        let sender = sender.clone();
+        let failed = Arc::clone(&failed);
+        let name = name.to_string();
+        move || {
+            let events = Events { sender };
+            if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| work(&events))) {
+                let reason = panic_message(payload.as_ref());
+                tracing::error!("Job {name} panicked: {reason}");
+                for event in failed(reason) {
+                    if !events.send(event) {
+                        break;
+                    }
+                }
+            }
+        }
+    });
+    if let Err(e) = spawned {
+        tracing::warn!("Failed to start {name}: {e}");
+        // The queue is still empty, so these fit without waiting
+        for event in failed(e.to_string()) {
+            let _ = sender.try_send(event);
+        }
+    }
+    drop(sender);
+
+    stream::unfold(receiver, |mut receiver| async move {
+        let event = receiver.recv().await?;
+        Some((event, receiver))
+    })
+}
+
+/// Message of a panic, as passed to `panic!`.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| (*message).to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "Unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::StreamExt;
+
+    #[test]
+    fn test_panic_ends_with_failure_events() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let events: Vec<Result<u8, String>> = runtime.block_on(
+            run_in_background(
+                "panicking-test-job",
+                1,
+                |events| {
+                    events.send(Ok(1));
+                    panic!("decoder exploded");
+                },
+                |reason| vec![Err(reason)],
+            )
+            .collect(),
+        );
+        assert_eq!(events, vec![Ok(1), Err("decoder exploded".to_string())]);
+    }
+
+    #[test]
+    fn test_finished_job_sends_no_failure() {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let events: Vec<u8> = runtime.block_on(
+            run_in_background(
+                "test-job",
+                1,
+                |events| {
+                    for n in 0..3 {
+                        events.send(n);
+                    }
+                },
+                |_| vec![9],
+            )
+            .collect(),
+        );
+        assert_eq!(events, vec![0, 1, 2]);
+    }
+}
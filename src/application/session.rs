@@ -0,0 +1,307 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/application/session.rs
+//
+// Session files: the navigated files, their views and the compare pair,
+// stored as JSON to pick a review up again later or on another machine.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::temp_file;
+
+/// Extension of session files (`review.noctua-session`).
+pub const SESSION_EXTENSION: &str = "noctua-session";
+
+/// Format version written into the session.
+const SESSION_VERSION: u32 = 1;
+
+/// How a document was looked at: page, zoom and pan.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentView {
+    /// Page of a multi-page document (0-based).
+    pub page: usize,
+    /// Key of the fit mode (e.g. "fit-width"); None for a custom zoom.
+    pub fit: Option<String>,
+    /// Zoom factor of a custom zoom.
+    pub scale: f32,
+    /// Pan offset in pixels.
+    pub pan: (f32, f32),
+    /// Quarter turns of the view rotation.
+    pub rotation: u8,
+}
+
+/// A file of the session, with its view if it was looked at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionFile {
+    pub path: PathBuf,
+    pub view: Option<DocumentView>,
+}
+
+/// The file compared with the current one, and how.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionCompare {
+    pub path: PathBuf,
+    /// Swipe divider instead of side by side.
+    pub swipe: bool,
+    /// Swipe divider position as a fraction of the canvas width.
+    pub divider: f32,
+}
+
+/// Files navigated in a session, the one shown and the compare pair.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Session {
+    pub files: Vec<SessionFile>,
+    /// Index of the file shown, in `files`.
+    pub current: usize,
+    pub compare: Option<SessionCompare>,
+}
+
+/// The session file.
+#[derive(Serialize, Deserialize)]
+struct SessionJson {
+    #[serde(default)]
+    version: u32,
+    #[serde(default)]
+    current: usize,
+    files: Vec<FileJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    compare: Option<CompareJson>,
+}
+
+/// A file; the view fields are only there if it was looked at.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct FileJson {
+    path: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    page: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    fit: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    scale: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pan_x: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pan_y: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    rotation: Option<u8>,
+}
+
+/// The compare pair: `"mode"` is `"swipe"` or `"side-by-side"`.
+#[derive(Serialize, Deserialize)]
+struct CompareJson {
+    path: String,
+    #[serde(default)]
+    mode: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    divider: Option<f32>,
+}
+
+impl Session {
+    /// JSON form of the session.
+    ///
+    /// Paths inside `base` (the folder of the session file) are written
+    /// relative to it, so a session kept with its files can be moved along.
+    pub fn to_json(&self, base: &Path) -> DocResult<String> {
+        let files = self
+            .files
+            .iter()
+            .map(|file| {
+                let view = file.view.as_ref();
+                FileJson {
+                    path: path_json(&file.path, base),
+                    page: view.map(|view| view.page),
+                    fit: view.and_then(|view| view.fit.clone()),
+                    scale: view.map(|view| view.scale),
+                    pan_x: view.map(|view| view.pan.0),
+                    pan_y: view.map(|view| view.pan.1),
+                    rotation: view.map(|view| view.rotation),
+                }
+            })
+            .collect();
+        let compare = self.compare.as_ref().map(|compare| CompareJson {
+            path: path_json(&compare.path, base),
+            mode: if compare.swipe {
+                "swipe"
+            } else {
+                "side-by-side"
+            }
+            .to_string(),
+            divider: Some(compare.divider),
+        });
+        let session = SessionJson {
+            version: SESSION_VERSION,
+            current: self.current,
+            files,
+            compare,
+        };
+        let mut json = serde_json::to_string_pretty(&session)?;
+        json.push('\n');
+        Ok(json)
+    }
+
+    /// Read a session written by `to_json`, resolving relative paths
+    /// against `base`.
+    ///
+    /// Returns `None` if it is no session or has no files; unknown fields
+    /// are skipped.
+    #[must_use]
+    pub fn parse(json: &str, base: &Path) -> Option<Self> {
+        let session: SessionJson = serde_json::from_str(json).ok()?;
+        if session.files.is_empty() {
+            return None;
+        }
+
+        let current = session.current.min(session.files.len() - 1);
+        let files = session
+            .files
+            .into_iter()
+            .map(|file| SessionFile {
+                path: base.join(&file.path),
+                view: file.page.map(|page| DocumentView {
+                    page,
+                    fit: file.fit,
+                    scale: file.scale.unwrap_or(1.0),
+                    pan: (file.pan_x.unwrap_or(0.0), file.pan_y.unwrap_or(0.0)),
+                    rotation: file.rotation.unwrap_or(0) % 4,
+                }),
+            })
+            .collect();
+        let compare = session.compare.map(|compare| SessionCompare {
+            path: base.join(&compare.path),
+            swipe: compare.mode == "swipe",
+            divider: compare
+                .divider
+                .map_or(0.5, |divider| divider.clamp(0.0, 1.0)),
+        });
+        Some(Self {
+            files,
+            current,
+            compare,
+        })
+    }
+}
+
+/// Write the session to `path`.
+pub fn save(path: &Path, session: &Session) -> DocResult<()> {
+    let base = path.parent().unwrap_or(Path::new(""));
+    temp_file::write_bytes(path, session.to_json(base)?)
+}
+
+/// Read the session stored at `path`.
+pub fn load(path: &Path) -> DocResult<Session> {
+    let json = fs::read_to_string(path)?;
+    let base = path.parent().unwrap_or(Path::new(""));
+    Session::parse(&json, base)
+        .ok_or_else(|| anyhow::anyhow!("{} is no valid session file", path.display()))
+}
+
+/// Check if `path` names a session file.
+#[must_use]
+pub fn is_session_file(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == SESSION_EXTENSION)
+}
+
+/// `path` as stored, relative to `base` if inside it.
+fn path_json(path: &Path, base: &Path) -> String {
+    let path = if base.as_os_str().is_empty() {
+        path
+    } else {
+        path.strip_prefix(base).unwrap_or(path)
+    };
+    path.to_string_lossy().into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session() -> Session {
+        Session {
+            files: vec![
+                SessionFile {
+                    path: PathBuf::from("/review/a \"1\".jpg"),
+                    view: None,
+                },
+                SessionFile {
+                    path: PathBuf::from("/review/scans/b.pdf"),
+                    view: Some(DocumentView {
+                        page: 3,
+                        fit: None,
+                        scale: 2.5,
+                        pan: (-40.0, 12.5),
+                        rotation: 1,
+                    }),
+                },
+                SessionFile {
+                    path: PathBuf::from("/elsewhere/c.png"),
+                    view: Some(DocumentView {
+                        page: 0,
+                        fit: Some("fit-width".to_string()),
+                        scale: 1.0,
+                        pan: (0.0, 0.0),
+                        rotation: 0,
+                    }),
+                },
+            ],
+            current: 1,
+            compare: Some(SessionCompare {
+                path: PathBuf::from("/elsewhere/c.png"),
+                swipe: true,
+                divider: 0.25,
+            }),
+        }
+    }
+
+    #[test]
+    fn test_session_round_trip() {
+        let base = Path::new("/review");
+        let json = session().to_json(base).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            value["files"][0],
+            serde_json::json!({"path": "a \"1\".jpg"})
+        );
+        assert_eq!(value["files"][1]["path"], "scans/b.pdf");
+        assert_eq!(value["files"][1]["page"], 3);
+        assert_eq!(value["files"][2]["path"], "/elsewhere/c.png");
+        assert_eq!(Session::parse(&json, base), Some(session()));
+    }
+
+    #[test]
+    fn test_relative_paths_follow_the_session() {
+        let json = session().to_json(Path::new("/review")).unwrap();
+        let moved = Session::parse(&json, Path::new("/mnt/backup")).unwrap();
+        assert_eq!(
+            moved.files[0].path,
+            PathBuf::from("/mnt/backup/a \"1\".jpg")
+        );
+        assert_eq!(moved.files[2].path, PathBuf::from("/elsewhere/c.png"));
+    }
+
+    #[test]
+    fn test_parse_is_lenient_with_extras_and_strict_with_structure() {
+        let json = "{\"version\": 2, \"current\": 9, \"note\": [true, null],\
+                    \"files\": [{\"path\": \"x\\u00e9.jpg\", \"stars\": 5}]}";
+        let parsed = Session::parse(json, Path::new("/r")).unwrap();
+        assert_eq!(parsed.current, 0);
+        assert_eq!(parsed.files[0].path, PathBuf::from("/r/xé.jpg"));
+        assert_eq!(parsed.files[0].view, None);
+
+        assert_eq!(Session::parse("{\"files\": []}", Path::new("/r")), None);
+        assert_eq!(
+            Session::parse("{\"files\": [{\"path\": \"a\"}", Path::new("/r")),
+            None
+        );
+        assert_eq!(Session::parse("{\"edits\": []}", Path::new("/r")), None);
+    }
+
+    #[test]
+    fn test_is_session_file() {
+        assert!(is_session_file(Path::new("/r/review.noctua-session")));
+        assert!(!is_session_file(Path::new("/r/photo.jpg")));
+    }
+}
@@ -0,0 +1,231 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/cli/bench.rs
+//
+// `noctua bench`: time opening, rendering and thumbnailing of a document.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Args;
+
+use crate::domain::document::core::content::DocumentContent;
+use crate::domain::document::core::document::Renderable;
+use crate::domain::document::core::json::escape_json;
+use crate::infrastructure::loaders::DocumentLoaderFactory;
+
+/// Zoom the render phase draws at; vector documents re-rasterize for it.
+const RENDER_SCALE: f64 = 2.0;
+
+/// Arguments of `noctua bench`.
+#[derive(Args, Debug, Clone)]
+pub struct BenchArgs {
+    /// Document to measure
+    #[arg(value_name = "FILE")]
+    pub file: PathBuf,
+
+    /// Pages shown and thumbnailed per run (multi-page documents)
+    #[arg(long, default_value_t = 1)]
+    pub pages: usize,
+
+    /// Measured runs; each opens the document from scratch
+    #[arg(long, default_value_t = 5)]
+    pub iterations: usize,
+}
+
+/// Durations of one phase over all runs.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PhaseTimes {
+    /// Phase name in the report (`open`, `render`, `pages`, `thumbnails`).
+    pub name: &'static str,
+    /// One entry per run.
+    pub runs: Vec<Duration>,
+}
+
+/// Minimum, median, mean and maximum of a phase, in milliseconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Summary {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub max: f64,
+}
+
+impl PhaseTimes {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            runs: Vec::new(),
+        }
+    }
+
+    /// Statistics of the runs; None before the first run.
+    #[must_use]
+    pub fn summary(&self) -> Option<Summary> {
+        let mut millis: Vec<f64> = self.runs.iter().map(|d| d.as_secs_f64() * 1000.0).collect();
+        millis.sort_by(f64::total_cmp);
+        let (&min, &max) = (millis.first()?, millis.last()?);
+        let mid = millis.len() / 2;
+        let median = if millis.len() % 2 == 0 {
+            (millis[mid - 1] + millis[mid]) / 2.0
+        } else {
+            millis[mid]
+        };
+        #[allow(clippy::cast_precision_loss)]
+        let mean = millis.iter().sum::<f64>() / millis.len() as f64;
+        Some(Summary {
+            min,
+            median,
+            mean,
+            max,
+        })
+    }
+}
+
+/// Measured phases of a document.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub file: PathBuf,
+    /// Document kind (`raster`, `vector`, `portable`).
+    pub kind: &'static str,
+    pub page_count: usize,
+    /// Pages shown and thumbnailed per run.
+    pub pages: usize,
+    pub phases: Vec<PhaseTimes>,
+}
+
+impl Report {
+    /// Machine-readable form, one JSON object; times are milliseconds.
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut json = format!(
+            "{{\n  \"noctua\": \"{}\",\n  \"file\": \"{}\",\n  \"kind\": \"{}\",\n  \
+             \"page_count\": {},\n  \"pages\": {},\n  \"phases\": [",
+            env!("CARGO_PKG_VERSION"),
+            escape_json(&self.file.to_string_lossy()),
+            self.kind,
+            self.page_count,
+            self.pages,
+        );
+        let summaries = self
+            .phases
+            .iter()
+            .filter_map(|phase| Some((phase, phase.summary()?)));
+        for (i, (phase, summary)) in summaries.enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            let _ = write!(
+                json,
+                "{separator}\n    {{\"phase\": \"{}\", \"runs\": {}, \"min_ms\": {:.3}, \
+                 \"median_ms\": {:.3}, \"mean_ms\": {:.3}, \"max_ms\": {:.3}}}",
+                phase.name,
+                phase.runs.len(),
+                summary.min,
+                summary.median,
+                summary.mean,
+                summary.max,
+            );
+        }
+        json.push_str("\n  ]\n}\n");
+        json
+    }
+}
+
+/// Time `work`, passing its result on.
+fn timed<T>(times: &mut PhaseTimes, work: impl FnOnce() -> T) -> T {
+    let start = Instant::now();
+    let result = work();
+    times.runs.push(start.elapsed());
+    result
+}
+
+/// Measure the document with the real backends.
+///
+/// Phases per run: `open` (parse and decode or render the first page),
+/// `render` (draw at 200% zoom), and for multi-page documents `pages`
+/// (show the following pages) and `thumbnails`.
+pub fn measure(args: &BenchArgs) -> anyhow::Result<Report> {
+    anyhow::ensure!(args.iterations > 0, "--iterations must be at least 1");
+    let loader = DocumentLoaderFactory::new();
+
+    let mut open = PhaseTimes::new("open");
+    let mut render = PhaseTimes::new("render");
+    let mut pages = PhaseTimes::new("pages");
+    let mut thumbnails = PhaseTimes::new("thumbnails");
+    let mut info = None;
+
+    for _ in 0..args.iterations {
+        let mut document: DocumentContent = timed(&mut open, || loader.load(&args.file))?;
+        timed(&mut render, || document.render(RENDER_SCALE))?;
+
+        let page_count = document.page_count();
+        let shown = args.pages.clamp(1, page_count);
+        if document.is_multi_page() {
+            timed(&mut pages, || {
+                (1..shown).try_for_each(|page| document.go_to_page(page))
+            })?;
+            timed(&mut thumbnails, || {
+                (0..shown).try_for_each(|page| document.generate_thumbnail_page(page))
+            })?;
+        }
+        info = Some((document.kind(), page_count, shown));
+    }
+
+    let (kind, page_count, pages_shown) = info.expect("at least one run");
+    Ok(Report {
+        file: args.file.clone(),
+        kind: kind.name(),
+        page_count,
+        pages: pages_shown,
+        phases: vec![open, render, pages, thumbnails],
+    })
+}
+
+/// Run the benchmark and print the report to stdout.
+pub fn run(args: &BenchArgs) -> anyhow::Result<()> {
+    let report = measure(args)?;
+    print!("{}", report.to_json());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn phase(name: &'static str, millis: &[u64]) -> PhaseTimes {
+        PhaseTimes {
+            name,
+            runs: millis.iter().copied().map(Duration::from_millis).collect(),
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let summary = phase("open", &[30, 10, 20, 60]).summary().unwrap();
+        assert_eq!(summary.min, 10.0);
+        assert_eq!(summary.median, 25.0);
+        assert_eq!(summary.mean, 30.0);
+        assert_eq!(summary.max, 60.0);
+        assert_eq!(phase("open", &[7, 1, 3]).summary().unwrap().median, 3.0);
+        assert_eq!(phase("pages", &[]).summary(), None);
+    }
+
+    #[test]
+    fn test_report_json() {
+        let report = Report {
+            file: PathBuf::from("/tmp/a \"b\".pdf"),
+            kind: "portable",
+            page_count: 12,
+            pages: 2,
+            phases: vec![phase("open", &[2, 4]), phase("pages", &[])],
+        };
+        let json = report.to_json();
+        assert!(json.contains("\"file\": \"/tmp/a \\\"b\\\".pdf\""));
+        assert!(json.contains("\"page_count\": 12,\n  \"pages\": 2,"));
+        assert!(json.contains(
+            "{\"phase\": \"open\", \"runs\": 2, \"min_ms\": 2.000, \"median_ms\": 3.000, \
+             \"mean_ms\": 3.000, \"max_ms\": 4.000}\n  ]"
+        ));
+        // Phases that never ran are left out
+        assert!(!json.contains("\"pages\", \"runs\""));
+    }
+}
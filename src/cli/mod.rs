@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/cli/mod.rs
+//
+// Subcommands that run on the command line without opening a window.
+
+pub mod bench;
+
+use clap::Subcommand;
+
+/// Subcommands of `noctua`; without one the viewer starts.
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Measure open, render and thumbnail times of a document and print them as JSON
+    Bench(bench::BenchArgs),
+}
+
+/// Run a subcommand to completion.
+pub fn run(command: &Command) -> anyhow::Result<()> {
+    match command {
+        Command::Bench(args) => bench::run(args),
+    }
+}
@@ -4,7 +4,10 @@
 // Global configuration for the application with cosmic-config support.
 
 use cosmic::cosmic_config::{self, CosmicConfigEntry, cosmic_config_derive::CosmicConfigEntry};
-use std::path::PathBuf;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::domain::document::operations::export_profile::DEFAULT_PROFILES;
 
 /// Global configuration for the application.
 #[derive(Debug, Clone, CosmicConfigEntry, PartialEq)]
@@ -24,8 +27,116 @@ pub struct AppConfig {
     pub min_scale: f32,
     /// Maximum zoom level (8.0 = 800% of original size).
     pub max_scale: f32,
+    /// Snap wheel zoom to integer multiples and simple fractions of 100%.
+    pub integer_zoom: bool,
+    /// Show large images scaled down in the fit view mildly sharpened
+    /// (display only, saved and exported pixels are untouched).
+    pub fit_sharpening: bool,
+    /// Magnification of the magnifier mode, relative to the normal view.
+    pub magnifier_zoom: f32,
+    /// Draw a crosshair at the mouse pointer in magnifier mode.
+    pub magnifier_crosshair: bool,
+    /// Also write the edits as a sidecar next to the original when saving a copy.
+    pub edit_sidecar_on_save: bool,
+    /// Apply orientation and crop from XMP sidecars of other photo tools on open.
+    pub import_xmp_edits: bool,
+    /// Turn photos upright by their EXIF orientation on open.
+    pub auto_orient: bool,
+    /// Ask before the Delete key moves the current file to the trash.
+    pub confirm_delete: bool,
+    /// Largest image opened, in megapixels; larger files (decompression bombs) are rejected.
+    pub max_image_megapixels: u32,
+    /// Largest side of a PDF page opened, in points (1/72 inch).
+    pub max_page_size: u32,
+    /// Font families for SVG text whose own fonts are not installed, in order.
+    pub svg_fallback_fonts: Vec<String>,
+    /// Write SVG text as outlines when copying or saving SVG markup.
+    pub svg_text_to_path: bool,
+    /// Font size of SVG text that sets none, in user units.
+    pub svg_font_size: f32,
     /// Show 3x3 grid during crop selection.
     pub crop_show_grid: bool,
+    /// Aspect ratio of the screen the wallpaper assistant crops for (width, height).
+    pub wallpaper_ratio: (u32, u32),
+    /// Black out the other screens while in fullscreen (presentations).
+    pub cover_other_screens: bool,
+    /// Hide the mouse pointer in fullscreen along with the controls.
+    pub hide_cursor_in_fullscreen: bool,
+    /// Show large previous/next, rotate, zoom and fullscreen buttons on the
+    /// canvas while the pointer moves (tablets and convertibles).
+    pub canvas_controls: bool,
+    /// What shows behind transparent images: "theme", "checkerboard" or "color".
+    pub canvas_background: String,
+    /// Canvas color of the "color" background, as `#rrggbb`.
+    pub canvas_color: String,
+    /// Seconds each slide is shown when advancing automatically in fullscreen.
+    pub slide_interval: u32,
+    /// Start over after the last document of a slideshow instead of ending it.
+    pub slideshow_loop: bool,
+    /// Show the documents of a slideshow in random order.
+    pub slideshow_shuffle: bool,
+    /// Fewest stars a document needs to appear in a slideshow (0 = all).
+    pub slideshow_min_rating: u8,
+    /// Comma-separated tags of which a slideshow document needs one (empty = all).
+    pub slideshow_tags: String,
+    /// Left/right arrow keys turn pages instead of switching files (foot pedals).
+    pub arrows_turn_pages: bool,
+    /// Seconds between checks of the folder for added or removed files (0 = off).
+    pub folder_rescan_interval: u32,
+    /// When background work is cut back: "auto" (on battery or with the power
+    /// saver profile), "always" or "never".
+    pub low_power_mode: String,
+    /// Ignore patterns for folder navigation (`*`/`?` globs, trailing `/` for directories).
+    pub nav_exclude_patterns: Vec<String>,
+    /// Order of the files in folder navigation ("name", "modified", "size" or "capture-date").
+    pub nav_sort_order: String,
+    /// Convert displayed colors to the monitor profile.
+    pub color_management: bool,
+    /// Monitor ICC profile to use (None = detect via colord / `_ICC_PROFILE`).
+    pub monitor_profile: Option<PathBuf>,
+    /// Active left panel tab per document kind (e.g. "portable" -> "outline").
+    pub left_panel_tabs: BTreeMap<String, String>,
+    /// Initial view mode per document kind (e.g. "portable" -> "fit-width").
+    pub view_modes: BTreeMap<String, String>,
+    /// Saved edit macros: name -> steps (e.g. "rotate-cw; crop 16:9; export webp").
+    pub macros: BTreeMap<String, String>,
+    /// Named export profiles: name -> settings (e.g. "format webp; fit 1920x1080").
+    pub export_profiles: BTreeMap<String, String>,
+    /// Share targets: name -> upload address, or command with `{file}` for the
+    /// exported image (e.g. "upload-image --public {file}").
+    pub share_targets: BTreeMap<String, String>,
+    /// Upload endpoint: a paste service address (e.g. "https://0x0.st"), or
+    /// "put" and a WebDAV or S3 address (empty = no uploads).
+    pub upload_endpoint: String,
+    /// Uploaded images as file name and link, most recent first (at most
+    /// `MAX_UPLOADS`).
+    pub upload_history: Vec<(String, String)>,
+    /// Tesseract languages headings of scanned documents are read in, joined
+    /// by `+` (e.g. "deu+eng").
+    pub ocr_language: String,
+    /// Documents read right to left (manga): the file of multi-page documents,
+    /// the folder for single images. Most recently used first (at most
+    /// `MAX_DOCUMENT_SETTINGS`).
+    pub right_to_left: Vec<PathBuf>,
+    /// Zoom regions bookmarked per document, at most `MAX_ZOOM_BOOKMARKS`
+    /// each: name, page, zoom (screen pixels per image pixel) and pan offset.
+    /// Most recently used first (at most `MAX_DOCUMENT_SETTINGS` documents).
+    pub zoom_bookmarks: Vec<(PathBuf, Vec<ZoomBookmark>)>,
+    /// Canvas background of single documents, overriding `canvas_background`:
+    /// "theme", "checkerboard" or a `#rrggbb` color. Most recently used first
+    /// (at most `MAX_DOCUMENT_SETTINGS`).
+    pub document_backgrounds: Vec<(PathBuf, String)>,
+    /// Key combination that quits kiosk mode (e.g. "Ctrl+Alt+Q").
+    pub kiosk_exit_keys: String,
+    /// Log level per subsystem (e.g. "loaders" -> "debug"); the others log warnings and errors.
+    pub log_levels: BTreeMap<String, String>,
+    /// Documents opened by name, most recent first (at most `MAX_RECENT_FILES`).
+    pub recent_files: Vec<PathBuf>,
+    /// Directory of the caches, e.g. on a tmpfs (None = ~/.cache/noctua).
+    pub cache_dir: Option<PathBuf>,
+    /// Directory files are written in before they replace their target
+    /// (None = next to the target).
+    pub temp_dir: Option<PathBuf>,
 }
 
 impl Default for AppConfig {
@@ -38,7 +149,409 @@ impl Default for AppConfig {
             pan_step: 50.0,
             min_scale: 0.1,
             max_scale: 8.0,
+            integer_zoom: false,
+            fit_sharpening: false,
+            magnifier_zoom: 4.0,
+            magnifier_crosshair: true,
+            edit_sidecar_on_save: false,
+            import_xmp_edits: true,
+            auto_orient: true,
+            confirm_delete: true,
+            max_image_megapixels: 268,
+            max_page_size: 14_400,
+            svg_fallback_fonts: ["Noto Sans", "DejaVu Sans", "Liberation Sans"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            svg_text_to_path: false,
+            svg_font_size: 12.0,
             crop_show_grid: true,
+            wallpaper_ratio: (16, 9),
+            cover_other_screens: false,
+            hide_cursor_in_fullscreen: true,
+            canvas_controls: false,
+            canvas_background: "theme".to_string(),
+            canvas_color: "#808080".to_string(),
+            slide_interval: 5,
+            slideshow_loop: true,
+            slideshow_shuffle: false,
+            slideshow_min_rating: 0,
+            slideshow_tags: String::new(),
+            arrows_turn_pages: false,
+            folder_rescan_interval: 0,
+            low_power_mode: "auto".to_string(),
+            nav_exclude_patterns: ["*.bak", "*~", "Thumbs.db", "desktop.ini", ".*", ".*/"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            nav_sort_order: "name".to_string(),
+            color_management: false,
+            monitor_profile: None,
+            left_panel_tabs: BTreeMap::new(),
+            view_modes: [
+                ("raster", "fit"),
+                ("vector", "fit"),
+                ("portable", "fit-width"),
+            ]
+            .into_iter()
+            .map(|(kind, mode)| (kind.to_string(), mode.to_string()))
+            .collect(),
+            macros: BTreeMap::new(),
+            export_profiles: DEFAULT_PROFILES
+                .into_iter()
+                .map(|(name, settings)| (name.to_string(), settings.to_string()))
+                .collect(),
+            share_targets: BTreeMap::new(),
+            upload_endpoint: String::new(),
+            upload_history: Vec::new(),
+            ocr_language: "eng".to_string(),
+            right_to_left: Vec::new(),
+            zoom_bookmarks: Vec::new(),
+            document_backgrounds: Vec::new(),
+            kiosk_exit_keys: "Ctrl+Alt+Q".to_string(),
+            log_levels: BTreeMap::new(),
+            recent_files: Vec::new(),
+            cache_dir: None,
+            temp_dir: None,
+        }
+    }
+}
+
+impl AppConfig {
+    /// Length of the recent files list.
+    pub const MAX_RECENT_FILES: usize = 12;
+
+    /// Put `path` at the top of the recent files, moving it up if it was
+    /// listed already and dropping the oldest beyond the limit.
+    ///
+    /// Returns whether the list changed.
+    pub fn add_recent_file(&mut self, path: &Path) -> bool {
+        if self.recent_files.first().is_some_and(|first| first == path) {
+            return false;
+        }
+        self.recent_files.retain(|recent| recent != path);
+        self.recent_files.insert(0, path.to_path_buf());
+        self.recent_files.truncate(Self::MAX_RECENT_FILES);
+        true
+    }
+
+    /// Length of the upload history.
+    pub const MAX_UPLOADS: usize = 50;
+
+    /// Put an upload at the top of the history, dropping the oldest beyond
+    /// the limit.
+    pub fn add_upload(&mut self, file_name: String, link: String) {
+        self.upload_history.insert(0, (file_name, link));
+        self.upload_history.truncate(Self::MAX_UPLOADS);
+    }
+
+    /// Documents remembered per setting that is kept for single documents
+    /// (reading order, zoom bookmarks, background); the least recently used
+    /// are forgotten beyond it.
+    pub const MAX_DOCUMENT_SETTINGS: usize = 200;
+
+    /// Move the settings of `path` to the top, so they are forgotten last.
+    ///
+    /// Returns whether a list changed.
+    pub fn touch_document_settings(&mut self, path: &Path) -> bool {
+        let mut changed = false;
+        if let Some(index) = self.right_to_left.iter().position(|kept| kept == path) {
+            let kept = self.right_to_left.remove(index);
+            self.right_to_left.insert(0, kept);
+            changed |= index > 0;
+        }
+        changed |= move_to_top(&mut self.zoom_bookmarks, path);
+        changed |= move_to_top(&mut self.document_backgrounds, path);
+        changed
+    }
+
+    /// Whether the documents at `key` are read right to left.
+    #[must_use]
+    pub fn is_right_to_left(&self, key: &Path) -> bool {
+        self.right_to_left.iter().any(|kept| kept == key)
+    }
+
+    /// Read the documents at `key` right to left or not.
+    pub fn set_right_to_left(&mut self, key: &Path, right_to_left: bool) {
+        self.right_to_left.retain(|kept| kept != key);
+        if right_to_left {
+            self.right_to_left.insert(0, key.to_path_buf());
+            self.right_to_left.truncate(Self::MAX_DOCUMENT_SETTINGS);
+        }
+    }
+
+    /// Own canvas background of `path`, None if it uses the configured one.
+    #[must_use]
+    pub fn document_background(&self, path: &Path) -> Option<&str> {
+        find(&self.document_backgrounds, path).map(String::as_str)
+    }
+
+    /// Give `path` a canvas background of its own, or the configured one
+    /// again with None.
+    pub fn set_document_background(&mut self, path: &Path, background: Option<String>) {
+        self.document_backgrounds.retain(|(kept, _)| kept != path);
+        if let Some(background) = background {
+            self.document_backgrounds
+                .insert(0, (path.to_path_buf(), background));
+            self.document_backgrounds
+                .truncate(Self::MAX_DOCUMENT_SETTINGS);
+        }
+    }
+
+    /// Zoom bookmarks per document, one for each number key.
+    pub const MAX_ZOOM_BOOKMARKS: usize = 9;
+
+    /// Zoom bookmarks of `path`.
+    #[must_use]
+    pub fn zoom_bookmarks_of(&self, path: &Path) -> &[ZoomBookmark] {
+        find(&self.zoom_bookmarks, path).map_or(&[], Vec::as_slice)
+    }
+
+    /// Bookmark a zoom region of `path`, replacing the one of the same name.
+    ///
+    /// Returns false if the document has `MAX_ZOOM_BOOKMARKS` already.
+    pub fn add_zoom_bookmark(&mut self, path: &Path, bookmark: ZoomBookmark) -> bool {
+        if find(&self.zoom_bookmarks, path).is_some() {
+            move_to_top(&mut self.zoom_bookmarks, path);
+        } else {
+            self.zoom_bookmarks
+                .insert(0, (path.to_path_buf(), Vec::new()));
+            self.zoom_bookmarks.truncate(Self::MAX_DOCUMENT_SETTINGS);
+        }
+        let bookmarks = &mut self.zoom_bookmarks[0].1;
+        if let Some(same) = bookmarks.iter_mut().find(|kept| kept.0 == bookmark.0) {
+            *same = bookmark;
+        } else if bookmarks.len() < Self::MAX_ZOOM_BOOKMARKS {
+            bookmarks.push(bookmark);
+        } else {
+            return false;
+        }
+        true
+    }
+
+    /// Remove the zoom bookmark at `index` of `path`.
+    pub fn remove_zoom_bookmark(&mut self, path: &Path, index: usize) {
+        let Some(position) = self
+            .zoom_bookmarks
+            .iter()
+            .position(|(kept, _)| kept == path)
+        else {
+            return;
+        };
+        let bookmarks = &mut self.zoom_bookmarks[position].1;
+        if index < bookmarks.len() {
+            bookmarks.remove(index);
+        }
+        if bookmarks.is_empty() {
+            self.zoom_bookmarks.remove(position);
+        }
+    }
+
+    /// Canvas color as RGB, None if it is not a valid `#rrggbb` value.
+    #[must_use]
+    pub fn canvas_rgb(&self) -> Option<[u8; 3]> {
+        parse_hex_color(&self.canvas_color)
+    }
+
+    /// Canvas background key of `path` and the color of a "color"
+    /// background: the document's own background if it has one, else the
+    /// configured one.
+    #[must_use]
+    pub fn canvas_background_of(&self, path: Option<&Path>) -> (&str, Option<[u8; 3]>) {
+        match path.and_then(|path| self.document_background(path)) {
+            Some(own) => match parse_hex_color(own) {
+                Some(rgb) => ("color", Some(rgb)),
+                None => (own, None),
+            },
+            None => (self.canvas_background.as_str(), self.canvas_rgb()),
+        }
+    }
+}
+
+/// Bookmarked zoom region: name, page, zoom and pan offset.
+pub type ZoomBookmark = (String, usize, f32, (f32, f32));
+
+/// Value kept for `path` in a most recently used first list.
+fn find<'a, T>(list: &'a [(PathBuf, T)], path: &Path) -> Option<&'a T> {
+    list.iter()
+        .find(|(kept, _)| kept == path)
+        .map(|(_, value)| value)
+}
+
+/// Move the entry of `path` to the top of a most recently used first list.
+///
+/// Returns whether it moved; false if it is on top already or not listed.
+fn move_to_top<T>(list: &mut Vec<(PathBuf, T)>, path: &Path) -> bool {
+    match list.iter().position(|(kept, _)| kept == path) {
+        Some(index) if index > 0 => {
+            let entry = list.remove(index);
+            list.insert(0, entry);
+            true
+        }
+        _ => false,
+    }
+}
+
+/// RGB of a `#rrggbb` color (the `#` may be left out).
+#[must_use]
+pub fn parse_hex_color(value: &str) -> Option<[u8; 3]> {
+    let hex = value.trim();
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 || !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |start: usize| u8::from_str_radix(&hex[start..start + 2], 16).ok();
+    Some([channel(0)?, channel(2)?, channel(4)?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_recent_files_are_bounded_and_unique() {
+        let mut config = AppConfig::default();
+        for index in 0..20 {
+            assert!(config.add_recent_file(Path::new(&format!("/scans/{index}.png"))));
+        }
+        assert_eq!(config.recent_files.len(), AppConfig::MAX_RECENT_FILES);
+        assert_eq!(config.recent_files[0], Path::new("/scans/19.png"));
+
+        // Reopening moves a file to the top instead of listing it twice
+        assert!(config.add_recent_file(Path::new("/scans/15.png")));
+        assert!(!config.add_recent_file(Path::new("/scans/15.png")));
+        assert_eq!(config.recent_files.len(), AppConfig::MAX_RECENT_FILES);
+        assert_eq!(config.recent_files[0], Path::new("/scans/15.png"));
+        assert_eq!(config.recent_files[1], Path::new("/scans/19.png"));
+        let listed = config
+            .recent_files
+            .iter()
+            .filter(|path| path.ends_with("15.png"));
+        assert_eq!(listed.count(), 1);
+    }
+
+    #[test]
+    fn test_parse_hex_color() {
+        assert_eq!(parse_hex_color("#808080"), Some([128, 128, 128]));
+        assert_eq!(parse_hex_color(" 1e2A3f "), Some([30, 42, 63]));
+        assert_eq!(parse_hex_color("#fff"), None);
+        assert_eq!(parse_hex_color("#12345g"), None);
+        assert_eq!(parse_hex_color("#ééé"), None);
+        assert_eq!(AppConfig::default().canvas_rgb(), Some([128, 128, 128]));
+    }
+
+    #[test]
+    fn test_zoom_bookmarks() {
+        let mut config = AppConfig::default();
+        let map = Path::new("/maps/city.tif");
+        for number in 1..=AppConfig::MAX_ZOOM_BOOKMARKS {
+            let name = format!("Area {number}");
+            assert!(config.add_zoom_bookmark(map, (name, 0, 2.0, (0.0, 0.0))));
+        }
+        assert!(!config.add_zoom_bookmark(map, ("Harbor".to_string(), 0, 4.0, (10.0, -5.0))));
+
+        // Saving under a taken name moves that bookmark, keeping its number
+        let moved = ("Area 3".to_string(), 1, 4.0, (10.0, -5.0));
+        assert!(config.add_zoom_bookmark(map, moved.clone()));
+        assert_eq!(config.zoom_bookmarks_of(map)[2], moved);
+        assert_eq!(
+            config.zoom_bookmarks_of(map).len(),
+            AppConfig::MAX_ZOOM_BOOKMARKS
+        );
+
+        config.remove_zoom_bookmark(map, 2);
+        assert_eq!(config.zoom_bookmarks_of(map)[2].0, "Area 4");
+        config.remove_zoom_bookmark(map, 99);
+        for _ in 1..AppConfig::MAX_ZOOM_BOOKMARKS {
+            config.remove_zoom_bookmark(map, 0);
+        }
+        assert!(config.zoom_bookmarks.is_empty());
+    }
+
+    #[test]
+    fn test_document_backgrounds() {
+        let mut config = AppConfig::default();
+        let diagram = Path::new("/plans/wiring.svg");
+        assert_eq!(
+            config.canvas_background_of(Some(diagram)),
+            ("theme", Some([128, 128, 128]))
+        );
+        assert_eq!(config.canvas_background_of(None).0, "theme");
+
+        config.set_document_background(diagram, Some("#101820".to_string()));
+        assert_eq!(
+            config.canvas_background_of(Some(diagram)),
+            ("color", Some([16, 24, 32]))
+        );
+        config.set_document_background(diagram, Some("checkerboard".to_string()));
+        assert_eq!(
+            config.canvas_background_of(Some(diagram)),
+            ("checkerboard", None)
+        );
+
+        // Other documents keep the configured background
+        config.canvas_background = "color".to_string();
+        assert_eq!(
+            config.canvas_background_of(Some(Path::new("/plans/site.png"))),
+            ("color", Some([128, 128, 128]))
+        );
+    }
+
+    #[test]
+    fn test_document_settings_forget_least_recently_used() {
+        let mut config = AppConfig::default();
+        let path = |index: usize| PathBuf::from(format!("/scans/{index}.png"));
+        let remember = |config: &mut AppConfig, index: usize| {
+            let bookmark = ("Area 1".to_string(), 0, 2.0, (0.0, 0.0));
+            config.set_right_to_left(&path(index), true);
+            config.set_document_background(&path(index), Some("checkerboard".to_string()));
+            assert!(config.add_zoom_bookmark(&path(index), bookmark));
+        };
+        remember(&mut config, 0);
+        remember(&mut config, 1);
+
+        // Using the older settings again keeps them the longest
+        assert!(config.touch_document_settings(&path(0)));
+        assert!(!config.touch_document_settings(&path(0)));
+        for index in 2..=AppConfig::MAX_DOCUMENT_SETTINGS {
+            remember(&mut config, index);
+        }
+        assert_eq!(config.right_to_left.len(), AppConfig::MAX_DOCUMENT_SETTINGS);
+        assert_eq!(
+            config.zoom_bookmarks.len(),
+            AppConfig::MAX_DOCUMENT_SETTINGS
+        );
+        assert_eq!(
+            config.document_backgrounds.len(),
+            AppConfig::MAX_DOCUMENT_SETTINGS
+        );
+        assert!(config.is_right_to_left(&path(0)));
+        assert!(!config.is_right_to_left(&path(1)));
+        assert_eq!(config.document_background(&path(1)), None);
+        assert!(config.zoom_bookmarks_of(&path(1)).is_empty());
+
+        config.set_right_to_left(&path(0), false);
+        assert!(!config.is_right_to_left(&path(0)));
+        config.set_document_background(&path(0), None);
+        assert_eq!(config.document_background(&path(0)), None);
+    }
+
+    #[test]
+    fn test_upload_history_is_bounded() {
+        let mut config = AppConfig::default();
+        for index in 0..60 {
+            config.add_upload(
+                format!("shot-{index}.png"),
+                format!("https://0x0.st/{index}.png"),
+            );
         }
+        assert_eq!(config.upload_history.len(), AppConfig::MAX_UPLOADS);
+        assert_eq!(
+            config.upload_history[0],
+            (
+                "shot-59.png".to_string(),
+                "https://0x0.st/59.png".to_string()
+            )
+        );
     }
 }
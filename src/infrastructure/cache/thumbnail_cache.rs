@@ -1,97 +1,104 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // src/infrastructure/cache/thumbnail_cache.rs
 //
-// Disk cache for document thumbnails stored in ~/.cache/noctua/
+// Disk cache for document thumbnails stored in ~/.cache/noctua/thumbnails/
 
 use std::fs;
-use std::io::BufWriter;
+use std::io;
 use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
 
-use image::DynamicImage;
+use image::{DynamicImage, RgbaImage};
 use sha2::{Digest, Sha256};
 
-use cosmic::widget::image::Handle as ImageHandle;
-
+use crate::domain::document::core::document::ImageHandle;
+use crate::domain::document::core::file_bytes::FileBytes;
+use crate::domain::document::core::temp_file;
 use crate::domain::document::operations::render::create_image_handle_from_image;
+use crate::infrastructure::filesystem::locations;
 
-/// Cache directory name under ~/.cache/ for thumbnail storage.
-const CACHE_DIR: &str = "noctua";
+/// Subdirectory of the cache directory holding the thumbnails.
+const THUMBNAIL_DIR: &str = "thumbnails";
 
 /// File extension for cached thumbnails.
 const THUMBNAIL_EXT: &str = "png";
 
-/// Thumbnail cache manager for disk-based caching.
-pub struct ThumbnailCache;
+/// Size the cache is trimmed to after thumbnails were added.
+pub const MAX_CACHE_SIZE: u64 = 256 * 1024 * 1024;
+
+/// Cached thumbnails of one version of a document.
+///
+/// Thumbnails are keyed by a hash of the file contents and its modification
+/// time, so a renamed or moved file keeps its thumbnails and a changed one
+/// gets new ones.
+pub struct ThumbnailCache {
+    /// Directory of the cache.
+    dir: PathBuf,
+    /// Key of the file version, shared by its pages.
+    key: String,
+}
 
 impl ThumbnailCache {
-    /// Load a thumbnail from disk cache.
-    /// Returns None if not cached or cache is invalid.
-    pub fn load(file_path: &Path, page: usize) -> Option<ImageHandle> {
-        let cache_path = Self::thumbnail_path(file_path, page)?;
-
-        log::debug!("Cache lookup: file={}, page={}", file_path.display(), page);
-
-        if !cache_path.exists() {
-            log::debug!(
-                "Thumbnail not found in cache: file={} page={}",
-                file_path.display(),
-                page
-            );
-            return None;
-        }
+    /// Cache for the document at `file_path`.
+    ///
+    /// Reads the whole file to hash it, so call it off the UI thread.
+    /// Returns None if the file cannot be read or there is no cache directory.
+    pub fn for_file(file_path: &Path) -> Option<Self> {
+        Self::in_dir(Self::cache_dir()?, file_path)
+    }
 
+    fn in_dir(dir: PathBuf, file_path: &Path) -> Option<Self> {
+        let key = Self::cache_key(file_path)?;
+        Some(Self { dir, key })
+    }
+
+    /// Load the thumbnail of a page (None if not cached or unreadable).
+    pub fn load(&self, page: usize) -> Option<ImageHandle> {
+        let cache_path = self.thumbnail_path(page);
         let img = image::open(&cache_path).ok()?;
-        log::debug!(
-            "Thumbnail loaded from cache: file={} page={}",
-            file_path.display(),
-            page
-        );
+
+        // Mark the thumbnail as recently used, so eviction keeps it longer
+        if let Ok(file) = fs::File::options().append(true).open(&cache_path) {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        tracing::debug!("Thumbnail loaded from cache: page={page}");
         Some(create_image_handle_from_image(&img))
     }
 
-    /// Save a thumbnail to disk cache.
-    pub fn save(file_path: &Path, page: usize, image: &DynamicImage) -> Option<()> {
-        let dir = Self::ensure_cache_dir()?;
-        let key = Self::cache_key(file_path, page)?;
-        let cache_path = dir.join(format!("{key}.{THUMBNAIL_EXT}"));
-
-        log::debug!(
-            "Saving thumbnail to cache: file={}, page={}, path={}",
-            file_path.display(),
-            page,
-            cache_path.display()
-        );
-
-        let file = fs::File::create(&cache_path).ok()?;
-        let writer = BufWriter::new(file);
-
-        let res = image.write_to(
-            &mut std::io::BufWriter::new(writer),
-            image::ImageFormat::Png,
-        );
-        match res {
-            Ok(()) => {
-                log::debug!(
-                    "Thumbnail cached successfully: file={} page={}",
-                    file_path.display(),
-                    page
-                );
-                Some(())
-            }
+    /// Save the thumbnail of a page. Returns whether it was written.
+    pub fn save(&self, page: usize, thumbnail: &ImageHandle) -> bool {
+        let Some(image) = RgbaImage::from_raw(
+            thumbnail.width(),
+            thumbnail.height(),
+            thumbnail.pixels().to_vec(),
+        ) else {
+            return false;
+        };
+        if let Err(e) = fs::create_dir_all(&self.dir) {
+            tracing::warn!("Failed to create the thumbnail cache: {e}");
+            return false;
+        }
+
+        // Written under a temporary name, so readers never see half a file
+        let written = temp_file::write_atomically(&self.thumbnail_path(page), |temp| {
+            Ok(DynamicImage::ImageRgba8(image).save_with_format(temp, image::ImageFormat::Png)?)
+        });
+        match written {
+            Ok(()) => true,
             Err(e) => {
-                log::warn!(
-                    "Failed to cache thumbnail: file={} page={}: {}",
-                    file_path.display(),
-                    page,
-                    e
-                );
-                None
+                tracing::warn!("Failed to cache thumbnail: page={page}: {e}");
+                false
             }
         }
     }
 
+    /// Check if the thumbnail of a page is cached.
+    pub fn has(&self, page: usize) -> bool {
+        self.thumbnail_path(page).exists()
+    }
+
     /// Clear all cached thumbnails.
-    pub fn clear_cache() -> std::io::Result<()> {
+    pub fn clear_cache() -> io::Result<()> {
         if let Some(dir) = Self::cache_dir()
             && dir.exists()
         {
@@ -100,50 +107,162 @@ impl ThumbnailCache {
         Ok(())
     }
 
-    /// Check if a thumbnail exists in cache.
-    #[allow(dead_code)]
-    pub fn has(file_path: &Path, page: usize) -> bool {
-        Self::thumbnail_path(file_path, page).is_some_and(|p| p.exists())
+    /// Total size of the cached thumbnails in bytes.
+    pub fn size() -> io::Result<u64> {
+        let Some(dir) = Self::cache_dir() else {
+            return Ok(0);
+        };
+        Ok(Self::entries(&dir)?.iter().map(|entry| entry.1).sum())
+    }
+
+    /// Delete the least recently used thumbnails until the cache holds at
+    /// most `max_size` bytes. Returns the number of bytes freed.
+    pub fn evict(max_size: u64) -> io::Result<u64> {
+        match Self::cache_dir() {
+            Some(dir) => Self::evict_in(&dir, max_size),
+            None => Ok(0),
+        }
+    }
+
+    fn evict_in(dir: &Path, max_size: u64) -> io::Result<u64> {
+        let mut entries = Self::entries(dir)?;
+        let mut size: u64 = entries.iter().map(|entry| entry.1).sum();
+        if size <= max_size {
+            return Ok(0);
+        }
+
+        let mut freed = 0;
+        entries.sort_by_key(|entry| entry.2);
+        for (path, len, _) in entries {
+            if size <= max_size {
+                break;
+            }
+            match fs::remove_file(&path) {
+                Ok(()) => {
+                    size -= len;
+                    freed += len;
+                }
+                Err(e) => tracing::debug!("Failed to evict {}: {e}", path.display()),
+            }
+        }
+        tracing::debug!("Evicted {freed} bytes of thumbnails");
+        Ok(freed)
     }
 
     // Private helper methods
 
-    /// Get the cache directory path (~/.cache/noctua/).
+    /// Get the cache directory path (~/.cache/noctua/thumbnails/ by default).
     fn cache_dir() -> Option<PathBuf> {
-        dirs::cache_dir().map(|p| p.join(CACHE_DIR))
+        locations::cache_dir().map(|dir| dir.join(THUMBNAIL_DIR))
     }
 
-    /// Ensure the cache directory exists.
-    fn ensure_cache_dir() -> Option<PathBuf> {
-        let dir = Self::cache_dir()?;
-        fs::create_dir_all(&dir).ok()?;
-        Some(dir)
+    /// Cached thumbnails in `dir` with their size and last use.
+    fn entries(dir: &Path) -> io::Result<Vec<(PathBuf, u64, SystemTime)>> {
+        let read = match fs::read_dir(dir) {
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        Ok(read
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == THUMBNAIL_EXT))
+            .filter_map(|path| {
+                let metadata = fs::metadata(&path).ok()?;
+                let used = metadata.modified().unwrap_or(UNIX_EPOCH);
+                Some((path, metadata.len(), used))
+            })
+            .collect())
     }
 
-    /// Generate a cache key from file path, modification time, and page number.
-    /// Format: sha256(path + mtime + page)
-    fn cache_key(file_path: &Path, page: usize) -> Option<String> {
-        let metadata = fs::metadata(file_path).ok()?;
-        let mtime = metadata
+    /// Generate a cache key from the file contents and modification time.
+    /// Format: sha256(contents + mtime)
+    fn cache_key(file_path: &Path) -> Option<String> {
+        let mtime = fs::metadata(file_path)
+            .ok()?
             .modified()
             .ok()?
-            .duration_since(std::time::UNIX_EPOCH)
+            .duration_since(UNIX_EPOCH)
             .ok()?
-            .as_secs();
+            .as_nanos();
+        let contents = FileBytes::open(file_path).ok()?;
 
         let mut hasher = Sha256::new();
-        hasher.update(file_path.to_string_lossy().as_bytes());
+        hasher.update(&*contents);
         hasher.update(mtime.to_le_bytes());
-        hasher.update(page.to_le_bytes());
 
         let hash = hasher.finalize();
         Some(format!("{hash:x}"))
     }
 
     /// Get the full path for a cached thumbnail.
-    fn thumbnail_path(file_path: &Path, page: usize) -> Option<PathBuf> {
-        let dir = Self::cache_dir()?;
-        let key = Self::cache_key(file_path, page)?;
-        Some(dir.join(format!("{key}.{THUMBNAIL_EXT}")))
+    fn thumbnail_path(&self, page: usize) -> PathBuf {
+        self.dir
+            .join(format!("{}-{page}.{THUMBNAIL_EXT}", self.key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(name);
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = temp_dir("noctua-thumbnail-cache-test");
+        let document = dir.join("owl.pdf");
+        fs::write(&document, b"%PDF owl").unwrap();
+        let cache = ThumbnailCache::in_dir(dir.join("cache"), &document).unwrap();
+
+        assert!(!cache.has(0));
+        assert!(cache.load(0).is_none());
+        let thumbnail = ImageHandle::from_rgba(2, 1, vec![255, 0, 0, 255, 0, 0, 255, 255]);
+        assert!(cache.save(0, &thumbnail));
+        assert!(cache.has(0));
+        let loaded = cache.load(0).unwrap();
+        assert_eq!((loaded.width(), loaded.height()), (2, 1));
+        assert_eq!(loaded.pixels(), thumbnail.pixels());
+
+        // A copy has the same contents, a changed file does not
+        let copy = dir.join("copy.pdf");
+        fs::copy(&document, &copy).unwrap();
+        let mtime = fs::metadata(&document).unwrap().modified().unwrap();
+        let copied = fs::File::options().append(true).open(&copy).unwrap();
+        copied.set_modified(mtime).unwrap();
+        let cached = |path: &Path| ThumbnailCache::in_dir(dir.join("cache"), path).unwrap();
+        assert!(cached(&copy).has(0));
+        fs::write(&document, b"%PDF owls").unwrap();
+        assert!(!cached(&document).has(0));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_evict_oldest_first() {
+        let dir = temp_dir("noctua-thumbnail-evict-test");
+        let now = SystemTime::now();
+        for (index, name) in ["old.png", "mid.png", "new.png"].into_iter().enumerate() {
+            let path = dir.join(name);
+            fs::write(&path, [0; 100]).unwrap();
+            let age = std::time::Duration::from_secs(60 * (3 - index as u64));
+            let file = fs::File::options().append(true).open(&path).unwrap();
+            file.set_modified(now - age).unwrap();
+        }
+        fs::write(dir.join("notes.txt"), [0; 500]).unwrap();
+
+        assert_eq!(ThumbnailCache::evict_in(&dir, 300).unwrap(), 0);
+        assert_eq!(ThumbnailCache::evict_in(&dir, 150).unwrap(), 200);
+        assert!(!dir.join("old.png").exists());
+        assert!(!dir.join("mid.png").exists());
+        assert!(dir.join("new.png").exists());
+        assert!(dir.join("notes.txt").exists());
+
+        fs::remove_dir_all(&dir).ok();
     }
 }
@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/color/display_transform.rs
+//
+// Color transform from document color space to the monitor profile (lcms2).
+
+use std::fmt;
+use std::sync::Arc;
+
+use image::RgbaImage;
+use lcms2::{Intent, PixelFormat, Profile, Transform};
+
+use crate::domain::document::core::document::DocResult;
+
+/// Color transform applied to rendered pixels before display.
+pub struct DisplayTransform {
+    transform: Transform<u8, u8>,
+}
+
+impl DisplayTransform {
+    /// Build a transform from `source` to `monitor` ICC profiles.
+    ///
+    /// A missing source profile is treated as sRGB; a missing monitor profile
+    /// converts to sRGB (embedded profile → sRGB only).
+    pub fn new(source: Option<&[u8]>, monitor: Option<&[u8]>) -> DocResult<Self> {
+        let input = match source {
+            Some(data) => Profile::new_icc(data)?,
+            None => Profile::new_srgb(),
+        };
+        let output = match monitor {
+            Some(data) => Profile::new_icc(data)?,
+            None => Profile::new_srgb(),
+        };
+
+        let transform = Transform::new(
+            &input,
+            PixelFormat::RGBA_8,
+            &output,
+            PixelFormat::RGBA_8,
+            Intent::Perceptual,
+        )?;
+
+        Ok(Self { transform })
+    }
+
+    /// Convert the pixels of an RGBA image in place (alpha is preserved).
+    pub fn apply(&self, image: &mut RgbaImage) {
+        let pixels: &mut [u8] = image;
+        self.transform.transform_in_place(pixels);
+    }
+}
+
+/// Display transform kept for the profile pair it converts between, so
+/// renders of the same document reuse it instead of rebuilding it.
+#[derive(Clone, Default)]
+pub struct CachedTransform {
+    /// Source profile of the transform (None = sRGB).
+    source: Option<Vec<u8>>,
+    /// Monitor profile of the transform (None = sRGB).
+    monitor: Option<Vec<u8>>,
+    transform: Option<Arc<DisplayTransform>>,
+}
+
+impl CachedTransform {
+    /// Transform from `source` to `monitor`, built only when either changed.
+    pub fn get(
+        &mut self,
+        source: Option<&[u8]>,
+        monitor: Option<&[u8]>,
+    ) -> DocResult<Arc<DisplayTransform>> {
+        if let Some(transform) = &self.transform
+            && self.source.as_deref() == source
+            && self.monitor.as_deref() == monitor
+        {
+            return Ok(Arc::clone(transform));
+        }
+
+        let transform = Arc::new(DisplayTransform::new(source, monitor)?);
+        self.source = source.map(<[u8]>::to_vec);
+        self.monitor = monitor.map(<[u8]>::to_vec);
+        self.transform = Some(Arc::clone(&transform));
+        Ok(transform)
+    }
+}
+
+impl fmt::Debug for CachedTransform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CachedTransform")
+            .field("built", &self.transform.is_some())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transform_reused_for_same_profiles() {
+        let mut cache = CachedTransform::default();
+        let first = cache.get(None, None).unwrap();
+        let second = cache.get(None, None).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+
+        let srgb = Profile::new_srgb().icc().unwrap();
+        let other = cache.get(Some(&srgb), None).unwrap();
+        assert!(!Arc::ptr_eq(&first, &other));
+    }
+}
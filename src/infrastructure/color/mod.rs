@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/color/mod.rs
+//
+// Color management: monitor profile discovery and display transforms.
+
+#[cfg(feature = "color")]
+pub mod display_transform;
+pub mod monitor_profile;
+
+// Re-export commonly used types
+#[cfg(feature = "color")]
+pub use display_transform::{CachedTransform, DisplayTransform};
+pub use monitor_profile::MonitorProfile;
@@ -0,0 +1,193 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/color/monitor_profile.rs
+//
+// Discover the ICC profile of the current monitor.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Well-known locations of installed ICC profiles.
+const PROFILE_DIRS: &[&str] = &["/usr/share/color/icc", "/var/lib/colord/icc"];
+
+/// ICC profile used as output for color-managed display.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MonitorProfile {
+    /// Display name (file name or source).
+    pub name: String,
+    /// Raw ICC profile bytes.
+    pub data: Vec<u8>,
+}
+
+impl MonitorProfile {
+    /// Load a profile from an ICC file.
+    pub fn load(path: &Path) -> Option<Self> {
+        match std::fs::read(path) {
+            Ok(data) => Some(Self {
+                name: profile_label(path),
+                data,
+            }),
+            Err(e) => {
+                tracing::warn!("Failed to read ICC profile {}: {e}", path.display());
+                None
+            }
+        }
+    }
+
+    /// Detect the profile of the current monitor.
+    ///
+    /// Attempts the following methods in order:
+    /// 1. colord (default profile of the first display device)
+    /// 2. `_ICC_PROFILE` property of the X11 root window
+    pub fn detect() -> Option<Self> {
+        if let Some(path) = colord_display_profile()
+            && let Some(profile) = Self::load(&path)
+        {
+            tracing::info!("Using monitor profile from colord: {}", path.display());
+            return Some(profile);
+        }
+
+        if let Some(data) = x11_icc_profile() {
+            tracing::info!("Using monitor profile from _ICC_PROFILE");
+            return Some(Self {
+                name: "_ICC_PROFILE".to_string(),
+                data,
+            });
+        }
+
+        tracing::info!("No monitor profile found, falling back to sRGB");
+        None
+    }
+}
+
+/// List installed ICC profiles (user and system directories).
+#[must_use]
+pub fn available_profiles() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = Vec::new();
+    if let Some(data) = dirs::data_dir() {
+        dirs.push(data.join("icc"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".color").join("icc"));
+    }
+    dirs.extend(PROFILE_DIRS.iter().map(PathBuf::from));
+
+    let mut profiles: Vec<PathBuf> = dirs
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.extension()
+                .and_then(|e| e.to_str())
+                .is_some_and(|e| e.eq_ignore_ascii_case("icc") || e.eq_ignore_ascii_case("icm"))
+        })
+        .collect();
+
+    profiles.sort();
+    profiles.dedup();
+    profiles
+}
+
+/// Human-readable label for a profile file.
+#[must_use]
+pub fn profile_label(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| path.display().to_string())
+}
+
+/// Query colord for the default profile of the first display device.
+fn colord_display_profile() -> Option<PathBuf> {
+    let output = Command::new("colormgr")
+        .args(["get-devices-by-kind", "display"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let devices = String::from_utf8_lossy(&output.stdout);
+    for device in parse_field(&devices, "Object Path") {
+        let Ok(output) = Command::new("colormgr")
+            .args(["device-get-default-profile", device])
+            .output()
+        else {
+            continue;
+        };
+        if !output.status.success() {
+            continue;
+        }
+
+        let profile = String::from_utf8_lossy(&output.stdout);
+        if let Some(filename) = parse_field(&profile, "Filename").next() {
+            return Some(PathBuf::from(filename));
+        }
+    }
+
+    None
+}
+
+/// Read the `_ICC_PROFILE` atom from the X11 root window via `xprop`.
+fn x11_icc_profile() -> Option<Vec<u8>> {
+    let output = Command::new("xprop")
+        .args(["-root", "_ICC_PROFILE"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    parse_xprop_bytes(&String::from_utf8_lossy(&output.stdout))
+}
+
+/// Extract all values of a `Key: value` field from `colormgr` output.
+fn parse_field<'a>(output: &'a str, key: &'a str) -> impl Iterator<Item = &'a str> {
+    output.lines().filter_map(move |line| {
+        let (k, v) = line.split_once(':')?;
+        (k.trim() == key)
+            .then(|| v.trim())
+            .filter(|v| !v.is_empty())
+    })
+}
+
+/// Parse `xprop` CARDINAL output (`_ICC_PROFILE(CARDINAL) = 0, 0, 12, ...`).
+fn parse_xprop_bytes(output: &str) -> Option<Vec<u8>> {
+    let (_, values) = output.split_once('=')?;
+    let bytes: Option<Vec<u8>> = values
+        .split(',')
+        .map(|v| v.trim().parse::<u8>().ok())
+        .collect();
+    bytes.filter(|b| !b.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_field() {
+        let output = "Object Path:   /org/freedesktop/ColorManager/devices/xrandr_a\n\
+                      Kind:          display\n\
+                      Object Path:   /org/freedesktop/ColorManager/devices/xrandr_b\n";
+        let paths: Vec<&str> = parse_field(output, "Object Path").collect();
+        assert_eq!(
+            paths,
+            vec![
+                "/org/freedesktop/ColorManager/devices/xrandr_a",
+                "/org/freedesktop/ColorManager/devices/xrandr_b"
+            ]
+        );
+        assert_eq!(parse_field(output, "Filename").next(), None);
+    }
+
+    #[test]
+    fn test_parse_xprop_bytes() {
+        assert_eq!(
+            parse_xprop_bytes("_ICC_PROFILE(CARDINAL) = 0, 0, 12, 72"),
+            Some(vec![0, 0, 12, 72])
+        );
+        assert_eq!(parse_xprop_bytes("_ICC_PROFILE:  not found."), None);
+        assert_eq!(parse_xprop_bytes("_ICC_PROFILE(CARDINAL) = 0, 300"), None);
+    }
+}
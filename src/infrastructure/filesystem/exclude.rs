@@ -0,0 +1,176 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/filesystem/exclude.rs
+//
+// Ignore patterns applied when scanning folders for navigation.
+
+use std::path::Path;
+
+/// Set of glob-style ignore patterns for folder scanning.
+///
+/// Patterns are matched case-insensitively against the file name:
+/// - `*` matches any run of characters, `?` matches a single character
+/// - A trailing `/` (e.g. `.*/`) matches directory names between the scanned
+///   folder and the file; the scanned folder and its ancestors never match
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExcludePatterns {
+    /// Patterns matched against the file name.
+    file_patterns: Vec<String>,
+    /// Patterns matched against directory names below the scanned folder.
+    dir_patterns: Vec<String>,
+}
+
+impl ExcludePatterns {
+    /// Build an exclude set from raw pattern strings (empty entries are skipped).
+    #[must_use]
+    pub fn new<S: AsRef<str>>(patterns: &[S]) -> Self {
+        let mut file_patterns = Vec::new();
+        let mut dir_patterns = Vec::new();
+
+        for pattern in patterns {
+            let pattern = pattern.as_ref().trim().to_lowercase();
+            if pattern.is_empty() {
+                continue;
+            }
+            if let Some(dir) = pattern.strip_suffix('/') {
+                if !dir.is_empty() {
+                    dir_patterns.push(dir.to_string());
+                }
+            } else {
+                file_patterns.push(pattern);
+            }
+        }
+
+        Self {
+            file_patterns,
+            dir_patterns,
+        }
+    }
+
+    /// Parse a comma-separated pattern list (as entered in settings).
+    #[must_use]
+    pub fn parse_list(input: &str) -> Vec<String> {
+        input
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// Check if no patterns are configured.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.file_patterns.is_empty() && self.dir_patterns.is_empty()
+    }
+
+    /// Check if a path found while scanning `root` should be skipped.
+    #[must_use]
+    pub fn is_excluded(&self, path: &Path, root: &Path) -> bool {
+        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
+            let name = name.to_lowercase();
+            if self.file_patterns.iter().any(|p| glob_match(p, &name)) {
+                return true;
+            }
+        }
+
+        if self.dir_patterns.is_empty() {
+            return false;
+        }
+
+        // Opening a file under ~/.local must not hide its siblings
+        let Ok(relative) = path.strip_prefix(root) else {
+            return false;
+        };
+        relative.parent().is_some_and(|parent| {
+            parent.components().any(|component| {
+                let name = component.as_os_str().to_string_lossy().to_lowercase();
+                self.dir_patterns.iter().any(|p| glob_match(p, &name))
+            })
+        })
+    }
+}
+
+/// Match a name against a glob pattern supporting `*` and `?`.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+
+    let (mut p, mut n) = (0, 0);
+    // Position of the last `*` in the pattern and the name index it resumed from.
+    let mut star: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.bak", "image.xcf.bak"));
+        assert!(glob_match("thumbs.db", "thumbs.db"));
+        assert!(glob_match("img_??.png", "img_01.png"));
+        assert!(glob_match(".*", ".hidden.png"));
+        assert!(!glob_match("*.bak", "image.png"));
+        assert!(!glob_match("img_??.png", "img_001.png"));
+    }
+
+    #[test]
+    fn test_file_patterns() {
+        let excludes = ExcludePatterns::new(&["*.xcf.bak", "Thumbs.db", ".*"]);
+
+        let root = Path::new("/photos");
+
+        assert!(excludes.is_excluded(Path::new("/photos/Thumbs.db"), root));
+        assert!(excludes.is_excluded(Path::new("/photos/draft.xcf.bak"), root));
+        assert!(excludes.is_excluded(Path::new("/photos/.cover.jpg"), root));
+        assert!(!excludes.is_excluded(Path::new("/photos/cover.jpg"), root));
+    }
+
+    #[test]
+    fn test_dir_patterns() {
+        let excludes = ExcludePatterns::new(&[".*/"]);
+
+        let root = Path::new("/photos");
+
+        assert!(excludes.is_excluded(Path::new("/photos/.thumbnails/a.png"), root));
+        assert!(!excludes.is_excluded(Path::new("/photos/a.png"), root));
+        assert!(!excludes.is_excluded(Path::new("/photos/.a.png"), root));
+    }
+
+    #[test]
+    fn test_dir_patterns_ignore_scanned_folder() {
+        let excludes = ExcludePatterns::new(&[".*/"]);
+
+        let hidden = Path::new("/home/user/.local/share/wallpapers");
+        assert!(!excludes.is_excluded(&hidden.join("a.png"), hidden));
+        assert!(!excludes.is_excluded(Path::new("./photo.jpg"), Path::new(".")));
+        assert!(excludes.is_excluded(Path::new("./.cache/photo.jpg"), Path::new(".")));
+    }
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            ExcludePatterns::parse_list(" *.bak, Thumbs.db ,,.*"),
+            vec!["*.bak", "Thumbs.db", ".*"]
+        );
+        assert!(ExcludePatterns::new(&ExcludePatterns::parse_list("")).is_empty());
+    }
+}
@@ -8,64 +8,60 @@ use std::path::{Path, PathBuf};
 
 use anyhow::anyhow;
 
-use crate::domain::document::core::content::{DocumentContent, DocumentKind};
-
-use crate::domain::document::types::raster::RasterDocument;
-#[cfg(feature = "vector")]
-use crate::domain::document::types::vector::VectorDocument;
-#[cfg(feature = "portable")]
-use crate::domain::document::types::portable::PortableDocument;
-
-/// Open a document from a file path and dispatch to the correct type.
+use crate::domain::document::core::content::DocumentContent;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::infrastructure::filesystem::exclude::ExcludePatterns;
+use crate::infrastructure::filesystem::sort::SortOrder;
+use crate::infrastructure::loaders::registry;
+
+/// Open a document from a file path with the backend registered for it,
+/// within the default size limits.
 ///
 /// Raster formats are delegated to the `image` crate, which decides
 /// based on enabled codecs (e.g. default-formats).
 pub fn open_document(path: &Path) -> anyhow::Result<DocumentContent> {
-    let kind = DocumentKind::from_path(path)
+    let backend = registry::for_path(path)
         .ok_or_else(|| anyhow!("Unsupported document type: {}", path.display()))?;
 
-    let content = match kind {
-        DocumentKind::Raster => {
-            let raster = RasterDocument::open(path)?;
-            DocumentContent::Raster(raster)
-        }
-        #[cfg(feature = "vector")]
-        DocumentKind::Vector => {
-            let vector = VectorDocument::open(path)?;
-            DocumentContent::Vector(vector)
-        }
-        #[cfg(feature = "portable")]
-        DocumentKind::Portable => {
-            let portable = PortableDocument::open(path)?;
-            DocumentContent::Portable(portable)
-        }
-        #[cfg(not(any(feature = "vector", feature = "portable")))]
-        _ => return Err(anyhow!("No document features enabled")),
-    };
-
-    Ok(content)
+    (backend.open)(path, &OpenLimits::default())
 }
 
-/// Collect all supported document files from a directory, sorted alphabetically.
+/// Collect all supported document files from a directory, sorted in `order`.
 ///
 /// This scans the directory and returns a list of files that are recognized as
-/// supported document types (images, PDFs, SVGs, etc.).
-pub fn collect_supported_files(dir: &Path) -> Vec<PathBuf> {
+/// supported document types (images, PDFs, SVGs, etc.). Files matching one of
+/// the `excludes` patterns are skipped.
+pub fn collect_supported_files(
+    dir: &Path,
+    excludes: &ExcludePatterns,
+    order: SortOrder,
+) -> Vec<PathBuf> {
+    read_supported_files(dir, excludes, order).unwrap_or_default()
+}
+
+/// Like [`collect_supported_files`], but reports a directory that cannot be
+/// read (removed, or on a network mount that went away) as an error.
+pub fn read_supported_files(
+    dir: &Path,
+    excludes: &ExcludePatterns,
+    order: SortOrder,
+) -> std::io::Result<Vec<PathBuf>> {
     let mut entries: Vec<PathBuf> = Vec::new();
 
-    if let Ok(read_dir) = fs::read_dir(dir) {
-        for entry in read_dir.flatten() {
-            let path = entry.path();
+    for entry in fs::read_dir(dir)?.flatten() {
+        let path = entry.path();
 
-            // Only keep regular files that are recognized as supported documents.
-            if path.is_file() && DocumentKind::from_path(&path).is_some() {
-                entries.push(path);
-            }
+        // Only keep regular files that are recognized as supported documents.
+        if path.is_file()
+            && registry::for_path(&path).is_some()
+            && !excludes.is_excluded(&path, dir)
+        {
+            entries.push(path);
         }
     }
 
-    entries.sort();
-    entries
+    order.sort(&mut entries);
+    Ok(entries)
 }
 
 // ---------------------------------------------------------------------------
@@ -83,6 +79,48 @@ pub fn read_file_bytes(path: &Path) -> Option<Vec<u8>> {
     fs::read(path).ok()
 }
 
+/// Move `path` to the desktop trash (the XDG trash on Linux), so it can
+/// still be restored from the file manager.
+pub fn move_to_trash(path: &Path) -> anyhow::Result<()> {
+    trash::delete(path).map_err(|e| anyhow!("Failed to move {} to the trash: {e}", path.display()))
+}
+
+/// Check if `path` can be changed in place: the file is not write-protected,
+/// opens for writing (fails on read-only mounts), and its directory accepts
+/// new files (needed for atomic saves and sidecars).
+///
+/// Opening for writing leaves the file untouched; the directory is probed
+/// with an empty file that is removed right away.
+pub fn is_writable(path: &Path) -> bool {
+    let file_writable = fs::metadata(path).is_ok_and(|m| !m.permissions().readonly())
+        && fs::OpenOptions::new().write(true).open(path).is_ok();
+    file_writable && path.parent().is_some_and(is_writable_dir)
+}
+
+/// Check if files can be created in `dir`.
+fn is_writable_dir(dir: &Path) -> bool {
+    let dir = if dir.as_os_str().is_empty() {
+        Path::new(".")
+    } else {
+        dir
+    };
+    if fs::metadata(dir).is_ok_and(|m| m.permissions().readonly()) {
+        return false;
+    }
+    let probe = dir.join(format!(".noctua-write-test-{}", std::process::id()));
+    match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&probe)
+    {
+        Ok(_) => {
+            let _ = fs::remove_file(&probe);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
 // ---------------------------------------------------------------------------
 // DEPRECATED FUNCTIONS
 // ---------------------------------------------------------------------------
@@ -187,3 +225,31 @@ pub fn apply_crop(
     Err("Deprecated function - use CropDocumentCommand".to_string())
 }
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_writable() {
+        let dir = std::env::temp_dir().join(format!("noctua-writable-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let file = dir.join("image.png");
+        fs::write(&file, b"png").unwrap();
+
+        assert!(is_writable(&file));
+        assert!(!is_writable(&dir.join("missing.png")));
+
+        let mut permissions = fs::metadata(&file).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&file, permissions.clone()).unwrap();
+        assert!(!is_writable(&file));
+        // The probe file is gone again
+        assert_eq!(fs::read_dir(&dir).unwrap().count(), 1);
+
+        #[allow(clippy::permissions_set_readonly_false)]
+        permissions.set_readonly(false);
+        fs::set_permissions(&file, permissions).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/filesystem/locations.rs
+//
+// Cache and temp directories, overridable in the config.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+
+use crate::domain::document::core::temp_file;
+
+/// Folder of the caches under the user cache directory (~/.cache/).
+const CACHE_DIR: &str = "noctua";
+
+/// Configured cache directory (None = ~/.cache/noctua).
+static CACHE_ROOT: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Use the cache and temp directories of the config (None = the defaults).
+pub fn configure(cache_dir: Option<PathBuf>, temp_dir: Option<PathBuf>) {
+    if let Ok(mut root) = CACHE_ROOT.write() {
+        *root = cache_dir;
+    }
+    temp_file::set_temp_dir(temp_dir);
+}
+
+/// Directory of the caches, None if there is no user cache directory.
+#[must_use]
+pub fn cache_dir() -> Option<PathBuf> {
+    CACHE_ROOT
+        .read()
+        .ok()
+        .and_then(|root| root.clone())
+        .or_else(|| dirs::cache_dir().map(|dir| dir.join(CACHE_DIR)))
+}
+
+/// Remove the temporary files that crashed sessions left in the temp
+/// directory, next to the files they were writing, and in the caches.
+pub fn remove_stale_files() {
+    let temp = temp_file::temp_dir()
+        .inspect_err(|e| tracing::warn!("No temp directory: {e}"))
+        .ok();
+    let caches = cache_dir()
+        .and_then(|dir| fs::read_dir(dir).ok())
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir());
+    for dir in temp.into_iter().chain(caches) {
+        if let Err(e) = temp_file::remove_stale(&dir) {
+            tracing::warn!("Failed to clean up {}: {e}", dir.display());
+        }
+    }
+}
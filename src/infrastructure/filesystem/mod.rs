@@ -3,7 +3,14 @@
 //
 // Filesystem operations: file I/O, folder scanning, and file watching.
 
+pub mod exclude;
 pub mod file_ops;
+pub mod locations;
+pub mod sort;
+
+// Re-export ExcludePatterns
+pub use exclude::ExcludePatterns;
+pub use sort::SortOrder;
 
 // TODO: Re-implement these helpers without UI dependencies
 // pub use file_ops::{file_size, read_file_bytes};
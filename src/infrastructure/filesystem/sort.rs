@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/filesystem/sort.rs
+//
+// Sort orders of the files in folder navigation.
+
+use std::cmp::Ordering;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::domain::document::operations::date_shift;
+
+/// Order of the files when stepping through a folder.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    /// File name, with numbers compared by value ("img2" before "img10").
+    #[default]
+    Name,
+    /// Last modification, oldest first.
+    Modified,
+    /// File size, smallest first.
+    Size,
+    /// EXIF capture time, oldest first; files without one follow by name.
+    CaptureDate,
+}
+
+impl SortOrder {
+    pub const ALL: [Self; 4] = [Self::Name, Self::Modified, Self::Size, Self::CaptureDate];
+
+    /// Key of the order in the config.
+    #[must_use]
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Name => "name",
+            Self::Modified => "modified",
+            Self::Size => "size",
+            Self::CaptureDate => "capture-date",
+        }
+    }
+
+    /// Order with the config key `key`, None if unknown.
+    #[must_use]
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|order| order.key() == key)
+    }
+
+    /// Sort `paths` in this order.
+    ///
+    /// The orders other than by name read each file's metadata once (the
+    /// capture time from the start of the file), so sort off the UI thread
+    /// for large folders.
+    pub fn sort(self, paths: &mut Vec<PathBuf>) {
+        match self {
+            Self::Name => paths.sort_by(|a, b| compare_names(a, b)),
+            Self::Modified => {
+                sort_by_key(paths, |path| {
+                    fs::metadata(path).and_then(|m| m.modified()).ok()
+                });
+            }
+            Self::Size => sort_by_key(paths, |path| fs::metadata(path).map(|m| m.len()).ok()),
+            Self::CaptureDate => sort_by_key(paths, date_shift::capture_date),
+        }
+    }
+}
+
+/// Sort by a key read once per file; files without it follow, ties go by name.
+fn sort_by_key<K: Ord>(paths: &mut Vec<PathBuf>, key: impl Fn(&Path) -> Option<K>) {
+    let mut keyed: Vec<(Option<K>, PathBuf)> =
+        paths.drain(..).map(|path| (key(&path), path)).collect();
+    keyed.sort_by(|(a_key, a), (b_key, b)| {
+        let by_key = match (a_key, b_key) {
+            (Some(a_key), Some(b_key)) => a_key.cmp(b_key),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        };
+        by_key.then_with(|| compare_names(a, b))
+    });
+    paths.extend(keyed.into_iter().map(|(_, path)| path));
+}
+
+/// Compare paths by their file names in natural order, then as a whole.
+fn compare_names(a: &Path, b: &Path) -> Ordering {
+    let name = |path: &Path| {
+        path.file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .into_owned()
+    };
+    natural_cmp(&name(a), &name(b)).then_with(|| a.cmp(b))
+}
+
+/// Compare like people do: ignoring case, with runs of digits compared by
+/// value ("img2" before "img10", "IMG_3" next to "img_3").
+#[must_use]
+pub fn natural_cmp(a: &str, b: &str) -> Ordering {
+    let mut a = a.chars().peekable();
+    let mut b = b.chars().peekable();
+    loop {
+        let ordering = match (a.peek(), b.peek()) {
+            (None, None) => return Ordering::Equal,
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(x), Some(y)) if x.is_ascii_digit() && y.is_ascii_digit() => {
+                let x = take_number(&mut a);
+                let y = take_number(&mut b);
+                // Without leading zeros, a longer number is a larger one
+                x.len().cmp(&y.len()).then_with(|| x.cmp(&y))
+            }
+            (Some(x), Some(y)) => {
+                let ordering = x.to_lowercase().cmp(y.to_lowercase());
+                a.next();
+                b.next();
+                ordering
+            }
+        };
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+}
+
+/// Take a run of digits, without its leading zeros.
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        if !(digits.is_empty() && digit == '0') {
+            digits.push(digit);
+        }
+    }
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_natural_cmp() {
+        let mut names = vec!["img10.png", "IMG2.png", "img1.png", "img02b.png", "a.png"];
+        names.sort_by(|a, b| natural_cmp(a, b));
+        assert_eq!(
+            names,
+            ["a.png", "img1.png", "IMG2.png", "img02b.png", "img10.png"]
+        );
+        assert_eq!(natural_cmp("scan007", "scan7"), Ordering::Equal);
+        assert_eq!(natural_cmp("page", "page1"), Ordering::Less);
+    }
+
+    #[test]
+    fn test_sort_orders() {
+        let dir = std::env::temp_dir().join("noctua-sort-test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let now = std::time::SystemTime::now();
+        for (index, (name, len)) in [("owl10.png", 3), ("owl2.png", 1), ("owl1.png", 2)]
+            .into_iter()
+            .enumerate()
+        {
+            let path = dir.join(name);
+            fs::write(&path, vec![0; len]).unwrap();
+            let file = fs::File::options().append(true).open(&path).unwrap();
+            file.set_modified(now - std::time::Duration::from_secs(60 * (3 - index as u64)))
+                .unwrap();
+        }
+        let sorted = |order: SortOrder| {
+            let mut paths: Vec<PathBuf> = fs::read_dir(&dir)
+                .unwrap()
+                .map(|entry| entry.unwrap().path())
+                .collect();
+            order.sort(&mut paths);
+            paths
+                .iter()
+                .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sorted(SortOrder::Name),
+            ["owl1.png", "owl2.png", "owl10.png"]
+        );
+        assert_eq!(
+            sorted(SortOrder::Modified),
+            ["owl10.png", "owl2.png", "owl1.png"]
+        );
+        assert_eq!(
+            sorted(SortOrder::Size),
+            ["owl2.png", "owl1.png", "owl10.png"]
+        );
+        // No capture times: by name
+        assert_eq!(
+            sorted(SortOrder::CaptureDate),
+            ["owl1.png", "owl2.png", "owl10.png"]
+        );
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn test_keys() {
+        for order in SortOrder::ALL {
+            assert_eq!(SortOrder::from_key(order.key()), Some(order));
+        }
+        assert_eq!(SortOrder::from_key("random"), None);
+    }
+}
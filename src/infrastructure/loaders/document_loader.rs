@@ -7,19 +7,16 @@ use std::path::Path;
 
 use crate::domain::document::core::content::{DocumentContent, DocumentKind};
 use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::limits::OpenLimits;
 
-use super::raster_loader::RasterLoader;
-#[cfg(feature = "vector")]
-use super::svg_loader::SvgLoader;
-#[cfg(feature = "portable")]
-use super::pdf_loader::PdfLoader;
+use super::registry;
 
 /// Trait for loading documents from files.
 ///
 /// Implementations handle specific document formats (raster, vector, portable).
 pub trait DocumentLoader {
-    /// Load a document from a file path.
-    fn load(&self, path: &Path) -> DocResult<DocumentContent>;
+    /// Load a document from a file path, rejecting files beyond `limits`.
+    fn load(&self, path: &Path, limits: &OpenLimits) -> DocResult<DocumentContent>;
 
     /// Check if this loader supports the given file.
     fn supports(&self, path: &Path) -> bool;
@@ -27,14 +24,30 @@ pub trait DocumentLoader {
 
 /// Document loader factory.
 ///
-/// Detects the document format and delegates to the appropriate loader.
-pub struct DocumentLoaderFactory;
+/// Looks up the backend for the file in the registry and delegates to it.
+pub struct DocumentLoaderFactory {
+    /// Size limits every opened file is checked against.
+    limits: OpenLimits,
+}
 
 impl DocumentLoaderFactory {
-    /// Create a new document loader factory.
+    /// Create a new document loader factory with the default limits.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self {
+            limits: OpenLimits::default(),
+        }
+    }
+
+    /// Set the size limits for the files loaded from now on.
+    pub fn set_limits(&mut self, limits: OpenLimits) {
+        self.limits = limits;
+    }
+
+    /// Size limits files are checked against.
+    #[must_use]
+    pub fn limits(&self) -> OpenLimits {
+        self.limits
     }
 
     /// Load a document from a file, automatically detecting the format.
@@ -46,7 +59,7 @@ impl DocumentLoaderFactory {
     /// - The file cannot be read
     /// - The document is malformed
     pub fn load(&self, path: &Path) -> DocResult<DocumentContent> {
-        let kind = DocumentKind::from_path(path).ok_or_else(|| {
+        let backend = registry::for_path(path).ok_or_else(|| {
             anyhow::anyhow!(
                 "Unsupported file format: {}",
                 path.extension()
@@ -55,38 +68,19 @@ impl DocumentLoaderFactory {
             )
         })?;
 
-        match kind {
-            DocumentKind::Raster => {
-                let loader = RasterLoader;
-                loader.load(path)
-            }
-            #[cfg(feature = "vector")]
-            DocumentKind::Vector => {
-                let loader = SvgLoader;
-                loader.load(path)
-            }
-            #[cfg(feature = "portable")]
-            DocumentKind::Portable => {
-                let loader = PdfLoader;
-                loader.load(path)
-            }
-            #[cfg(not(any(feature = "vector", feature = "portable")))]
-            _ => Err(anyhow::anyhow!(
-                "No document loaders available (check feature flags)"
-            )),
-        }
+        (backend.open)(path, &self.limits)
     }
 
     /// Detect the document kind from a file path.
     #[must_use]
     pub fn detect_kind(&self, path: &Path) -> Option<DocumentKind> {
-        DocumentKind::from_path(path)
+        registry::for_path(path).map(|backend| backend.kind)
     }
 
     /// Check if a file is supported by any loader.
     #[must_use]
     pub fn is_supported(&self, path: &Path) -> bool {
-        DocumentKind::from_path(path).is_some()
+        registry::for_path(path).is_some()
     }
 }
 
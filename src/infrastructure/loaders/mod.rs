@@ -4,6 +4,7 @@
 // Document loaders for various formats.
 
 pub mod document_loader;
+pub mod registry;
 
 pub mod raster_loader;
 #[cfg(feature = "vector")]
@@ -7,6 +7,7 @@ use std::path::Path;
 
 use crate::domain::document::core::content::DocumentContent;
 use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::limits::OpenLimits;
 use crate::domain::document::types::portable::PortableDocument;
 use crate::infrastructure::loaders::document_loader::DocumentLoader;
 
@@ -14,8 +15,8 @@ use crate::infrastructure::loaders::document_loader::DocumentLoader;
 pub struct PdfLoader;
 
 impl DocumentLoader for PdfLoader {
-    fn load(&self, path: &Path) -> DocResult<DocumentContent> {
-        let document = PortableDocument::open(path)
+    fn load(&self, path: &Path, limits: &OpenLimits) -> DocResult<DocumentContent> {
+        let document = PortableDocument::open(path, limits)
             .map_err(|e| anyhow::anyhow!("Failed to load PDF document: {e}"))?;
 
         Ok(DocumentContent::Portable(document))
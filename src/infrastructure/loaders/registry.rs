@@ -0,0 +1,157 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/loaders/registry.rs
+//
+// Registry of document backends: which files each one handles and how to open them.
+
+use std::path::Path;
+
+use crate::domain::document::core::content::{DocumentContent, DocumentKind};
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::limits::OpenLimits;
+
+use super::document_loader::DocumentLoader;
+use super::raster_loader::RasterLoader;
+#[cfg(feature = "vector")]
+use super::svg_loader::SvgLoader;
+#[cfg(feature = "portable")]
+use super::pdf_loader::PdfLoader;
+
+/// A document backend.
+///
+/// Adding a format means writing its loader, adding the `DocumentKind` and
+/// `DocumentContent` variants and registering it in `BACKENDS` behind the
+/// cargo feature that pulls in its decoder. Format detection, folder scans
+/// and opening all go through the registry.
+#[derive(Debug)]
+pub struct Backend {
+    /// Kind of the documents this backend opens.
+    pub kind: DocumentKind,
+    /// File extensions, lowercase and without the dot.
+    pub extensions: &'static [&'static str],
+    /// MIME types of the supported files (as listed in the desktop file).
+    pub mime_types: &'static [&'static str],
+    /// Open a file of this backend within the given size limits.
+    pub open: fn(&Path, &OpenLimits) -> DocResult<DocumentContent>,
+}
+
+impl Backend {
+    /// Whether the file has one of the backend's extensions.
+    #[must_use]
+    pub fn handles(&self, path: &Path) -> bool {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| {
+                self.extensions
+                    .iter()
+                    .any(|known| known.eq_ignore_ascii_case(ext))
+            })
+    }
+}
+
+/// Registered backends of the enabled features, tried in order.
+pub const BACKENDS: &[Backend] = &[
+    #[cfg(feature = "vector")]
+    Backend {
+        kind: DocumentKind::Vector,
+        extensions: &["svg", "svgz"],
+        mime_types: &["image/svg+xml", "image/svg+xml-compressed"],
+        open: |path, limits| SvgLoader.load(path, limits),
+    },
+    #[cfg(feature = "portable")]
+    Backend {
+        kind: DocumentKind::Portable,
+        extensions: &["pdf"],
+        mime_types: &["application/pdf"],
+        open: |path, limits| PdfLoader.load(path, limits),
+    },
+    // Extensions known to image-rs; whether a file decodes depends on the enabled codecs
+    Backend {
+        kind: DocumentKind::Raster,
+        extensions: &[
+            "png", "apng", "jpg", "jpeg", "jfif", "gif", "webp", "bmp", "ico", "tif", "tiff",
+            "tga", "dds", "hdr", "exr", "pbm", "pgm", "ppm", "pam", "pnm", "ff", "qoi", "avif",
+        ],
+        mime_types: &[
+            "image/png",
+            "image/apng",
+            "image/jpeg",
+            "image/gif",
+            "image/webp",
+            "image/bmp",
+            "image/vnd.microsoft.icon",
+            "image/tiff",
+            "image/x-tga",
+            "image/vnd-ms.dds",
+            "image/vnd.radiance",
+            "image/x-exr",
+            "image/x-portable-bitmap",
+            "image/x-portable-graymap",
+            "image/x-portable-pixmap",
+            "image/x-portable-anymap",
+            "image/qoi",
+            "image/avif",
+        ],
+        open: |path, limits| RasterLoader.load(path, limits),
+    },
+];
+
+/// Backend handling a file, chosen by its extension.
+#[must_use]
+pub fn for_path(path: &Path) -> Option<&'static Backend> {
+    BACKENDS.iter().find(|backend| backend.handles(path))
+}
+
+/// Backend handling a MIME type.
+#[allow(dead_code)]
+#[must_use]
+pub fn for_mime(mime: &str) -> Option<&'static Backend> {
+    BACKENDS.iter().find(|backend| {
+        backend
+            .mime_types
+            .iter()
+            .any(|known| known.eq_ignore_ascii_case(mime))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_for_path() {
+        let kind = |name: &str| for_path(Path::new(name)).map(|backend| backend.kind);
+        assert_eq!(kind("photo.JPG"), Some(DocumentKind::Raster));
+        assert_eq!(kind("scan.tiff"), Some(DocumentKind::Raster));
+        #[cfg(feature = "vector")]
+        assert_eq!(kind("logo.svgz"), Some(DocumentKind::Vector));
+        #[cfg(feature = "portable")]
+        assert_eq!(kind("paper.pdf"), Some(DocumentKind::Portable));
+        assert_eq!(kind("notes.txt"), None);
+        assert_eq!(kind("README"), None);
+    }
+
+    #[test]
+    fn test_extensions_are_unique() {
+        let mut extensions: Vec<&str> = BACKENDS
+            .iter()
+            .flat_map(|backend| backend.extensions.iter().copied())
+            .collect();
+        let count = extensions.len();
+        extensions.sort_unstable();
+        extensions.dedup();
+        assert_eq!(extensions.len(), count);
+    }
+
+    #[cfg(all(feature = "vector", feature = "portable"))]
+    #[test]
+    fn test_desktop_file_types_are_handled() {
+        let desktop = include_str!("../../../resources/org.codeberg.wfx.Noctua.desktop");
+        let types = desktop
+            .lines()
+            .find_map(|line| line.strip_prefix("MimeType="))
+            .expect("desktop file lists MIME types");
+        for mime in types.split(';').filter(|mime| !mime.is_empty()) {
+            assert!(for_mime(mime).is_some(), "no backend for {mime}");
+        }
+    }
+}
@@ -0,0 +1,189 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/logging/mod.rs
+//
+// Logging setup: tracing to stderr with log levels per subsystem, changeable at runtime.
+
+pub mod recent;
+
+use std::collections::BTreeMap;
+use std::io::IsTerminal;
+use std::sync::OnceLock;
+
+use tracing::Level;
+use tracing::level_filters::LevelFilter;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::{fmt, reload, EnvFilter, Layer, Registry};
+
+pub use recent::LogEntry;
+
+/// Levels selectable per subsystem, from quiet to verbose.
+pub const LEVELS: [Level; 5] = [
+    Level::ERROR,
+    Level::WARN,
+    Level::INFO,
+    Level::DEBUG,
+    Level::TRACE,
+];
+
+/// Level of everything without a subsystem level, unless `RUST_LOG` is set.
+pub const DEFAULT_LEVEL: Level = Level::WARN;
+
+/// Parts of the viewer whose log level can be set separately.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Subsystem {
+    Interface,
+    Documents,
+    Loaders,
+    Cache,
+    Color,
+    System,
+    Rendering,
+}
+
+impl Subsystem {
+    pub const ALL: [Self; 7] = [
+        Self::Interface,
+        Self::Documents,
+        Self::Loaders,
+        Self::Cache,
+        Self::Color,
+        Self::System,
+        Self::Rendering,
+    ];
+
+    /// Key of the subsystem in the config.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Interface => "interface",
+            Self::Documents => "documents",
+            Self::Loaders => "loaders",
+            Self::Cache => "cache",
+            Self::Color => "color",
+            Self::System => "system",
+            Self::Rendering => "rendering",
+        }
+    }
+
+    /// Target prefix of the messages logged by the subsystem.
+    fn target(self) -> &'static str {
+        match self {
+            Self::Interface => "noctua::ui",
+            Self::Documents => "noctua::application",
+            Self::Loaders => "noctua::infrastructure::loaders",
+            Self::Cache => "noctua::infrastructure::cache",
+            Self::Color => "noctua::infrastructure::color",
+            Self::System => "noctua::infrastructure::system",
+            Self::Rendering => "noctua_core",
+        }
+    }
+}
+
+/// Level of `subsystem` in the config levels (subsystem key -> level name).
+#[must_use]
+pub fn level_of(levels: &BTreeMap<String, String>, subsystem: Subsystem) -> Option<Level> {
+    levels.get(subsystem.key())?.parse().ok()
+}
+
+/// Filter directives for `base` followed by the configured subsystem levels.
+///
+/// Unknown subsystems and levels in the config are skipped.
+#[must_use]
+pub fn filter_directives(base: &str, levels: &BTreeMap<String, String>) -> String {
+    let subsystems = Subsystem::ALL.into_iter().filter_map(|subsystem| {
+        level_of(levels, subsystem)
+            .map(|level| format!("{}={}", subsystem.target(), level.as_str().to_lowercase()))
+    });
+    std::iter::once(base.trim().to_string())
+        .filter(|base| !base.is_empty())
+        .chain(subsystems)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Handle to swap the stderr filter, with the directives it starts from.
+struct Reloader {
+    handle: reload::Handle<EnvFilter, Registry>,
+    base: String,
+}
+
+static RELOADER: OnceLock<Reloader> = OnceLock::new();
+
+/// Install the logger for `tracing` and `log` messages.
+///
+/// Messages go to stderr, filtered by `RUST_LOG` (warnings and errors
+/// without it). Warnings and errors are also kept for the log viewer.
+pub fn init() {
+    let base = std::env::var("RUST_LOG").unwrap_or_else(|_| DEFAULT_LEVEL.as_str().to_lowercase());
+    let (filter, handle) = reload::Layer::new(EnvFilter::new(&base));
+
+    let stderr = fmt::layer()
+        .with_writer(std::io::stderr)
+        .with_ansi(std::io::stderr().is_terminal())
+        .with_filter(filter);
+    let subscriber = Registry::default()
+        .with(stderr)
+        .with(recent::RecentLayer.with_filter(LevelFilter::WARN));
+    recent::start_clock();
+
+    // Dependencies that log through `log` (noctua-core uses `tracing`) reach
+    // the same filters; those decide the level, so every record is forwarded
+    if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
+        eprintln!("Failed to install the logger: {e}");
+        return;
+    }
+    if let Err(e) = tracing_log::LogTracer::init() {
+        tracing::warn!("Failed to forward log messages: {e}");
+    }
+    let _ = RELOADER.set(Reloader { handle, base });
+}
+
+/// Apply the subsystem levels from the config (subsystem key -> level name).
+pub fn set_levels(levels: &BTreeMap<String, String>) {
+    let Some(reloader) = RELOADER.get() else {
+        return;
+    };
+    let directives = filter_directives(&reloader.base, levels);
+    if let Err(e) = reloader.handle.reload(EnvFilter::new(&directives)) {
+        tracing::warn!("Failed to change log levels to {directives}: {e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(key, level)| ((*key).to_string(), (*level).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_filter_directives() {
+        assert_eq!(filter_directives("warn", &BTreeMap::new()), "warn");
+        assert_eq!(
+            filter_directives(
+                "warn",
+                &levels(&[("loaders", "debug"), ("rendering", "TRACE")])
+            ),
+            "warn,noctua::infrastructure::loaders=debug,noctua_core=trace"
+        );
+        // Unknown subsystems and levels are skipped, an empty base is dropped
+        assert_eq!(
+            filter_directives(" ", &levels(&[("network", "debug"), ("cache", "loud")])),
+            ""
+        );
+        assert_eq!(
+            filter_directives("", &levels(&[("interface", "info")])),
+            "noctua::ui=info"
+        );
+    }
+
+    #[test]
+    fn test_level_of() {
+        let levels = levels(&[("color", "info")]);
+        assert_eq!(level_of(&levels, Subsystem::Color), Some(Level::INFO));
+        assert_eq!(level_of(&levels, Subsystem::Cache), None);
+    }
+}
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/logging/recent.rs
+//
+// Recent warnings and errors kept in memory for the log viewer.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_log::NormalizeEvent;
+use tracing_subscriber::layer::{Context, Layer};
+
+/// Number of entries kept; older ones are dropped.
+const CAPACITY: usize = 500;
+
+static ENTRIES: Mutex<VecDeque<LogEntry>> = Mutex::new(VecDeque::new());
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// A logged message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Time since startup.
+    pub uptime: Duration,
+    pub level: Level,
+    /// Module that logged the message.
+    pub target: String,
+    /// Message followed by its other fields as `name=value`.
+    pub message: String,
+}
+
+impl fmt::Display for LogEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "[{:9.3}s] {:<5} {}: {}",
+            self.uptime.as_secs_f64(),
+            self.level,
+            self.target,
+            self.message
+        )
+    }
+}
+
+/// Entries kept so far, oldest first.
+#[must_use]
+pub fn entries() -> Vec<LogEntry> {
+    ENTRIES
+        .lock()
+        .map(|entries| entries.iter().cloned().collect())
+        .unwrap_or_default()
+}
+
+/// Start counting the uptime of the entries.
+pub(super) fn start_clock() {
+    START.get_or_init(Instant::now);
+}
+
+/// Append `entry`, dropping the oldest one when full.
+fn push(entries: &mut VecDeque<LogEntry>, entry: LogEntry) {
+    if entries.len() == CAPACITY {
+        entries.pop_front();
+    }
+    entries.push_back(entry);
+}
+
+/// Layer keeping every event it sees (filtered to warnings and errors on install).
+pub struct RecentLayer;
+
+impl<S: Subscriber> Layer<S> for RecentLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        // Messages of the `log` crate carry their module in the fields
+        let normalized = event.normalized_metadata();
+        let metadata = normalized.as_ref().unwrap_or_else(|| event.metadata());
+
+        let mut fields = FieldText::default();
+        event.record(&mut fields);
+
+        let entry = LogEntry {
+            uptime: START.get_or_init(Instant::now).elapsed(),
+            level: *metadata.level(),
+            target: metadata.target().to_string(),
+            message: fields.message + &fields.rest,
+        };
+        if let Ok(mut entries) = ENTRIES.lock() {
+            push(&mut entries, entry);
+        }
+    }
+}
+
+/// Message and the other fields of an event as text.
+#[derive(Default)]
+struct FieldText {
+    message: String,
+    rest: String,
+}
+
+impl Visit for FieldText {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record_debug(field, &format_args!("{value}"));
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        match field.name() {
+            "message" => self.message = format!("{value:?}"),
+            // Module, file and line of `log` messages
+            name if name.starts_with("log.") => {}
+            name => {
+                let _ = write!(self.rest, " {name}={value:?}");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    fn entry(message: &str) -> LogEntry {
+        LogEntry {
+            uptime: Duration::from_millis(1500),
+            level: Level::WARN,
+            target: "noctua::ui::update".to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_push_drops_oldest() {
+        let mut entries = VecDeque::new();
+        for index in 0..=CAPACITY {
+            push(&mut entries, entry(&index.to_string()));
+        }
+        assert_eq!(entries.len(), CAPACITY);
+        assert_eq!(entries.front().unwrap().message, "1");
+        assert_eq!(entries.back().unwrap().message, CAPACITY.to_string());
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            entry("Render failed").to_string(),
+            "[    1.500s] WARN  noctua::ui::update: Render failed"
+        );
+    }
+
+    #[test]
+    fn test_layer_records_fields() {
+        let subscriber = tracing_subscriber::registry().with(RecentLayer);
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::error!(page = 3, file = "owl.pdf", "Render failed");
+        });
+        let entries = entries();
+        let recorded = entries
+            .iter()
+            .find(|entry| entry.message.starts_with("Render failed"))
+            .unwrap();
+        assert_eq!(recorded.message, "Render failed page=3 file=owl.pdf");
+        assert_eq!(recorded.level, Level::ERROR);
+    }
+}
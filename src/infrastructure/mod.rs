@@ -4,8 +4,10 @@
 // Infrastructure layer: external dependencies, loaders, cache, and filesystem.
 
 pub mod cache;
+pub mod color;
 pub mod filesystem;
 pub mod loaders;
+pub mod logging;
 pub mod system;
 
 // Re-export loader factory
@@ -1,9 +1,14 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // src/infrastructure/system/mod.rs
 //
-// System integration: wallpaper, desktop environment utilities.
+// System integration: wallpaper, share targets, uploads, text recognition,
+// power state, desktop environment utilities.
 
+pub mod ocr;
+pub mod power;
+pub mod share;
+pub mod upload;
 pub mod wallpaper;
 
-// Re-export wallpaper function
-pub use wallpaper::set_as_wallpaper;
+// Re-export wallpaper functions
+pub use wallpaper::{set_as_wallpaper, set_cropped_as_wallpaper};
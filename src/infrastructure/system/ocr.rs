@@ -0,0 +1,159 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/system/ocr.rs
+//
+// Text recognition of rendered pages via the tesseract command.
+
+use std::io::{Cursor, Write};
+use std::process::{Command, Stdio};
+
+use anyhow::Context;
+use image::{DynamicImage, ImageFormat};
+
+/// Line of text recognized on a page, in pixels of the image.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OcrLine {
+    /// Words of the line, separated by single spaces.
+    pub text: String,
+    /// Top edge.
+    pub top: u32,
+    /// Height of the line box (ascenders to descenders).
+    pub height: u32,
+    /// Mean confidence of the words (0-100).
+    pub confidence: f32,
+}
+
+impl OcrLine {
+    /// Bottom edge.
+    #[must_use]
+    pub fn bottom(&self) -> u32 {
+        self.top + self.height
+    }
+}
+
+/// Recognize the lines of text in `image`, top to bottom.
+///
+/// `language` is a tesseract language code, several joined by `+`
+/// (e.g. "deu+eng"). Blocks until tesseract is done.
+pub fn recognize_lines(image: &DynamicImage, language: &str) -> anyhow::Result<Vec<OcrLine>> {
+    let mut png = Vec::new();
+    DynamicImage::ImageLuma8(image.to_luma8())
+        .write_to(&mut Cursor::new(&mut png), ImageFormat::Png)
+        .context("Failed to encode the page")?;
+
+    let mut child = Command::new("tesseract")
+        .args(["stdin", "stdout", "-l", language, "tsv"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .context("Failed to run tesseract")?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(&png)
+            .context("Failed to send the page to tesseract")?;
+    }
+    let output = child.wait_with_output()?;
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let reason = stderr.lines().map(str::trim).rfind(|line| !line.is_empty());
+        anyhow::bail!("{}", reason.unwrap_or("Text recognition failed"));
+    }
+    Ok(parse_tsv(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Lines of tesseract's TSV output, in reading order.
+///
+/// The columns are level, page, block, paragraph, line and word number,
+/// left, top, width, height, confidence and text; the words (level 5)
+/// are gathered into their lines.
+fn parse_tsv(tsv: &str) -> Vec<OcrLine> {
+    let mut lines: Vec<((u32, u32, u32), OcrLine, usize)> = Vec::new();
+    for row in tsv.lines().skip(1) {
+        let columns: Vec<&str> = row.split('\t').collect();
+        if columns.len() != 12 {
+            continue;
+        }
+        let number = |index: usize| columns[index].trim().parse::<u32>().ok();
+        let (Some(5), Some(block), Some(paragraph), Some(line), Some(top), Some(height)) = (
+            number(0),
+            number(2),
+            number(3),
+            number(4),
+            number(7),
+            number(9),
+        ) else {
+            continue;
+        };
+        let Ok(confidence) = columns[10].trim().parse::<f32>() else {
+            continue;
+        };
+        let text = columns[11].trim();
+        if text.is_empty() || confidence < 0.0 {
+            continue;
+        }
+
+        let key = (block, paragraph, line);
+        match lines.last_mut() {
+            Some((last, current, words)) if *last == key => {
+                let bottom = current.bottom().max(top + height);
+                current.top = current.top.min(top);
+                current.height = bottom - current.top;
+                current.text.push(' ');
+                current.text.push_str(text);
+                current.confidence += confidence;
+                *words += 1;
+            }
+            _ => lines.push((
+                key,
+                OcrLine {
+                    text: text.to_string(),
+                    top,
+                    height,
+                    confidence,
+                },
+                1,
+            )),
+        }
+    }
+    lines
+        .into_iter()
+        .map(|(_, mut line, words)| {
+            line.confidence /= words as f32;
+            line
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tsv() {
+        let tsv = "level\tpage_num\tblock_num\tpar_num\tline_num\tword_num\tleft\ttop\twidth\theight\tconf\ttext\n\
+            1\t1\t0\t0\t0\t0\t0\t0\t1240\t1754\t-1\t\n\
+            4\t1\t1\t1\t1\t0\t120\t200\t600\t64\t-1\t\n\
+            5\t1\t1\t1\t1\t1\t120\t204\t180\t56\t95.5\tChapter\n\
+            5\t1\t1\t1\t1\t2\t320\t200\t60\t64\t88.5\tOne\n\
+            5\t1\t2\t1\t1\t1\t120\t320\t90\t24\t91\tThe\n\
+            5\t1\t2\t1\t1\t2\t220\t322\t60\t20\t-1\t \n\
+            5\t1\t2\t1\t1\t3\t290\t318\t120\t26\t85\towls\n\
+            5\t1\t2\t1\t2\t1\t120\t350\t140\t24\t90\treturned.\n";
+        let lines = parse_tsv(tsv);
+        assert_eq!(lines.len(), 3);
+        assert_eq!(
+            lines[0],
+            OcrLine {
+                text: "Chapter One".to_string(),
+                top: 200,
+                height: 64,
+                confidence: 92.0,
+            }
+        );
+        assert_eq!(lines[1].text, "The owls");
+        assert_eq!((lines[1].top, lines[1].bottom()), (318, 344));
+        assert_eq!(lines[1].confidence, 88.0);
+        assert_eq!(lines[2].text, "returned.");
+        assert!(parse_tsv("").is_empty());
+    }
+}
@@ -0,0 +1,240 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/system/power.rs
+//
+// Power state: battery (UPower, else the kernel's power supplies) and the
+// power saver profile of power-profiles-daemon, watched on the system bus.
+
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use futures_util::stream::{self, BoxStream};
+use futures_util::{Stream, StreamExt, future};
+use zbus::Connection;
+use zbus::zvariant::OwnedValue;
+
+/// Kernel directory of the power supplies (AC adapters, batteries).
+const POWER_SUPPLY_DIR: &str = "/sys/class/power_supply";
+
+/// How often the power supplies are read on systems without UPower.
+const SUPPLY_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// D-Bus object whose property is watched: bus name, path and interface.
+type Service = (&'static str, &'static str, &'static str);
+
+/// UPower, for `OnBattery`.
+const UPOWER: Service = (
+    "org.freedesktop.UPower",
+    "/org/freedesktop/UPower",
+    "org.freedesktop.UPower",
+);
+
+/// power-profiles-daemon, for `ActiveProfile`; versions before 0.20 only
+/// have the second name.
+const POWER_PROFILES: [Service; 2] = [
+    (
+        "org.freedesktop.UPower.PowerProfiles",
+        "/org/freedesktop/UPower/PowerProfiles",
+        "org.freedesktop.UPower.PowerProfiles",
+    ),
+    (
+        "net.hadess.PowerProfiles",
+        "/net/hadess/PowerProfiles",
+        "net.hadess.PowerProfiles",
+    ),
+];
+
+/// Power source and profile of the machine.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PowerState {
+    /// Running on battery.
+    pub on_battery: bool,
+    /// The power saver profile is active.
+    pub power_saver: bool,
+}
+
+impl PowerState {
+    /// Check if background work should be cut back.
+    #[must_use]
+    pub fn is_low(self) -> bool {
+        self.on_battery || self.power_saver
+    }
+}
+
+/// Change of one part of the power state.
+enum Change {
+    OnBattery(bool),
+    Profile(String),
+}
+
+/// Watch the power state: the current one, then every change UPower and
+/// power-profiles-daemon announce with `PropertiesChanged`.
+///
+/// Unknown parts count as on mains power with the balanced profile.
+/// Without UPower the kernel's power supplies are read every
+/// `SUPPLY_CHECK_INTERVAL` instead.
+pub fn watch() -> impl Stream<Item = PowerState> + Send + 'static {
+    states(stream::once(changes()).flatten())
+}
+
+/// Power state after each of `changes` that alters it, and after the first.
+fn states(changes: impl Stream<Item = Change>) -> impl Stream<Item = PowerState> {
+    changes
+        .scan((PowerState::default(), None), |(state, sent), change| {
+            match change {
+                Change::OnBattery(on_battery) => state.on_battery = on_battery,
+                Change::Profile(profile) => state.power_saver = profile == "power-saver",
+            }
+            // The first state is always sent, replacing an older one
+            let changed = *sent != Some(*state);
+            *sent = Some(*state);
+            future::ready(Some(changed.then_some(*state)))
+        })
+        .filter_map(future::ready)
+}
+
+/// Changes of the battery and the power profile, their current values first.
+async fn changes() -> BoxStream<'static, Change> {
+    let (battery, profile) = match Connection::system().await {
+        Ok(connection) => {
+            let battery =
+                property_changes(&connection, UPOWER, "OnBattery", Change::OnBattery).await;
+            let mut profile = None;
+            for service in POWER_PROFILES {
+                profile =
+                    property_changes(&connection, service, "ActiveProfile", Change::Profile).await;
+                if profile.is_some() {
+                    break;
+                }
+            }
+            (battery, profile)
+        }
+        Err(e) => {
+            tracing::debug!("No system bus to watch the power state on: {e}");
+            (None, None)
+        }
+    };
+    stream::select(
+        battery.unwrap_or_else(supply_changes),
+        profile.unwrap_or_else(|| stream::empty().boxed()),
+    )
+    .boxed()
+}
+
+/// Values of `property` of `service`, the current one first; None if the
+/// service neither runs nor is started on demand.
+async fn property_changes<T>(
+    connection: &Connection,
+    (name, path, interface): Service,
+    property: &'static str,
+    change: fn(T) -> Change,
+) -> Option<BoxStream<'static, Change>>
+where
+    T: TryFrom<OwnedValue> + Send + Sync + Unpin + 'static,
+    T::Error: Into<zbus::Error>,
+{
+    let proxy = zbus::Proxy::new(connection, name, path, interface)
+        .await
+        .ok()?;
+    if let Err(e) = proxy.get_property::<T>(property).await {
+        tracing::debug!("Cannot read {property} of {name}: {e}");
+        return None;
+    }
+    let changes = proxy.receive_property_changed::<T>(property).await;
+    Some(
+        changes
+            .filter_map(move |changed| async move { changed.get().await.ok().map(change) })
+            .boxed(),
+    )
+}
+
+/// Battery state of the kernel's power supplies, read now and then every
+/// `SUPPLY_CHECK_INTERVAL`.
+fn supply_changes() -> BoxStream<'static, Change> {
+    stream::unfold(true, |first| async move {
+        if !first {
+            tokio::time::sleep(SUPPLY_CHECK_INTERVAL).await;
+        }
+        let on_battery = supplies_on_battery(Path::new(POWER_SUPPLY_DIR)).unwrap_or(false);
+        Some((Change::OnBattery(on_battery), false))
+    })
+    .boxed()
+}
+
+/// Whether the AC adapters in `dir` are all unplugged while a battery is
+/// present; None on desktops without either.
+fn supplies_on_battery(dir: &Path) -> Option<bool> {
+    let read = |supply: &Path, name: &str| {
+        fs::read_to_string(supply.join(name)).map(|value| value.trim().to_string())
+    };
+    let mut battery = false;
+    let mut mains = Vec::new();
+    for entry in fs::read_dir(dir).ok()?.flatten() {
+        let supply = entry.path();
+        match read(&supply, "type").ok().as_deref() {
+            Some("Battery") => battery = true,
+            Some("Mains") => mains.push(read(&supply, "online").is_ok_and(|online| online == "1")),
+            _ => {}
+        }
+    }
+    (battery && !mains.is_empty()).then(|| mains.iter().all(|online| !online))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_states_are_sent_when_changed() {
+        let changes = stream::iter([
+            Change::OnBattery(false),
+            Change::Profile("balanced".to_string()),
+            Change::OnBattery(true),
+            Change::OnBattery(true),
+            Change::Profile("power-saver".to_string()),
+            Change::Profile("performance".to_string()),
+        ]);
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let states: Vec<PowerState> = runtime.block_on(states(changes).collect());
+        let state = |on_battery, power_saver| PowerState {
+            on_battery,
+            power_saver,
+        };
+        assert_eq!(
+            states,
+            [
+                state(false, false),
+                state(true, false),
+                state(true, true),
+                state(true, false),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_supplies_on_battery() {
+        let dir = std::env::temp_dir().join(format!("noctua-power-{}", std::process::id()));
+        let supply = |name: &str, kind: &str, online: Option<&str>| {
+            let supply = dir.join(name);
+            fs::create_dir_all(&supply).unwrap();
+            fs::write(supply.join("type"), format!("{kind}\n")).unwrap();
+            if let Some(online) = online {
+                fs::write(supply.join("online"), format!("{online}\n")).unwrap();
+            }
+        };
+
+        // A desktop has neither
+        fs::create_dir_all(&dir).unwrap();
+        assert_eq!(supplies_on_battery(&dir), None);
+
+        supply("BAT0", "Battery", None);
+        supply("AC", "Mains", Some("1"));
+        supply("hidpp_battery_0", "Battery", None);
+        assert_eq!(supplies_on_battery(&dir), Some(false));
+        supply("AC", "Mains", Some("0"));
+        assert_eq!(supplies_on_battery(&dir), Some(true));
+
+        let _ = fs::remove_dir_all(&dir);
+        assert_eq!(supplies_on_battery(&dir), None);
+    }
+}
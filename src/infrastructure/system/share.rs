@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/infrastructure/system/share.rs
+//
+// Share targets: upload scripts and servers that turn a file into a link.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+use anyhow::Context;
+
+use super::upload::{UploadEndpoint, UploadMethod};
+use crate::domain::document::core::cancel::CancelToken;
+
+/// Folder of the images being shared, under the temp directory.
+pub const SHARE_DIR: &str = "share";
+
+/// Placeholder of the shared file in a command template.
+pub const FILE_PLACEHOLDER: &str = "{file}";
+
+/// Where a shared file goes, as written in the config.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShareTarget {
+    /// Shell command, with `{file}` standing for the path of the file
+    /// (appended if the command does not name it).
+    Command(String),
+    /// HTTP(S) address the file is posted to as the form field `file`.
+    Upload(String),
+}
+
+impl ShareTarget {
+    /// Target described by a config value: an address or a command.
+    #[must_use]
+    pub fn parse(value: &str) -> Self {
+        let value = value.trim();
+        if (value.starts_with("https://") || value.starts_with("http://"))
+            && !value.contains(char::is_whitespace)
+        {
+            Self::Upload(value.to_string())
+        } else {
+            Self::Command(value.to_string())
+        }
+    }
+
+    /// Send `file` to the target and return the link it answered with.
+    ///
+    /// Blocks until the command or upload finishes; the first URL in its
+    /// output is the link. Uploads go through [`UploadEndpoint::upload`].
+    pub fn share(&self, file: &Path) -> anyhow::Result<String> {
+        match self {
+            Self::Command(template) => {
+                let output = Command::new("sh")
+                    .arg("-c")
+                    .arg(command_line(template, file))
+                    .output()
+                    .context("Failed to run the share command")?;
+                link_from(&output)
+            }
+            Self::Upload(url) => UploadEndpoint {
+                method: UploadMethod::Form,
+                url: url.clone(),
+            }
+            .upload(file, &CancelToken::new(), |_| {}),
+        }
+    }
+}
+
+/// Shell command of `template` for `file`.
+fn command_line(template: &str, file: &Path) -> String {
+    let quoted = shell_quote(&file.to_string_lossy());
+    if template.contains(FILE_PLACEHOLDER) {
+        template.replace(FILE_PLACEHOLDER, &quoted)
+    } else {
+        format!("{template} {quoted}")
+    }
+}
+
+/// `value` as a single shell word.
+fn shell_quote(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "'\\''"))
+}
+
+/// Link in the output of a finished command, or why there is none.
+fn link_from(output: &Output) -> anyhow::Result<String> {
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    if output.status.success() {
+        return first_url(&stdout).context("No link in the output of the share target");
+    }
+    // The last message the command printed before failing
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    match stderr
+        .lines()
+        .chain(stdout.lines())
+        .map(str::trim)
+        .rfind(|line| !line.is_empty())
+    {
+        Some(reason) => anyhow::bail!("{reason} ({})", output.status),
+        None => anyhow::bail!("Share target failed ({})", output.status),
+    }
+}
+
+/// First http(s) URL in `text`.
+///
+/// JSON answers escape their slashes (`https:\/\/`), these are undone. The
+/// URL ends at whitespace, a quote or a bracket.
+#[must_use]
+pub fn first_url(text: &str) -> Option<String> {
+    let text = text.replace("\\/", "/");
+    let start = ["https://", "http://"]
+        .iter()
+        .filter_map(|scheme| text.find(scheme))
+        .min()?;
+    let url: String = text[start..]
+        .chars()
+        .take_while(|c| !c.is_whitespace() && !matches!(c, '"' | '\'' | '<' | '>' | ')' | ']'))
+        .collect();
+    Some(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            ShareTarget::parse(" https://assets.example.com/upload "),
+            ShareTarget::Upload("https://assets.example.com/upload".to_string())
+        );
+        assert_eq!(
+            ShareTarget::parse("upload-image --public {file}"),
+            ShareTarget::Command("upload-image --public {file}".to_string())
+        );
+        assert_eq!(
+            ShareTarget::parse("http://host/up?x=1 && echo"),
+            ShareTarget::Command("http://host/up?x=1 && echo".to_string())
+        );
+    }
+
+    #[test]
+    fn test_command_line() {
+        let file = Path::new("/tmp/owl's nest.png");
+        assert_eq!(
+            command_line("upload --to work {file}", file),
+            "upload --to work '/tmp/owl'\\''s nest.png'"
+        );
+        assert_eq!(
+            command_line("upload", file),
+            "upload '/tmp/owl'\\''s nest.png'"
+        );
+    }
+
+    #[test]
+    fn test_first_url() {
+        assert_eq!(
+            first_url("Uploaded.\nLink: https://example.com/a/b.png\n"),
+            Some("https://example.com/a/b.png".to_string())
+        );
+        assert_eq!(
+            first_url(r#"{"data":{"url":"https:\/\/i.ibb.co\/x\/owl.png","id":"x"}}"#),
+            Some("https://i.ibb.co/x/owl.png".to_string())
+        );
+        assert_eq!(
+            first_url("see <http://a.test/1> or https://b.test"),
+            Some("http://a.test/1".to_string())
+        );
+        assert_eq!(first_url("done"), None);
+    }
+
+    #[test]
+    fn test_share_command() {
+        let target = ShareTarget::Command(
+            "echo uploaded to https://files.test/$(basename {file})".to_string(),
+        );
+        assert_eq!(
+            target.share(Path::new("/tmp/owl.png")).unwrap(),
+            "https://files.test/owl.png"
+        );
+        let failing = ShareTarget::Command("echo 'quota exceeded' >&2; exit 3".to_string());
+        let error = failing.share(Path::new("/tmp/owl.png")).unwrap_err();
+        assert!(error.to_string().starts_with("quota exceeded"));
+    }
+}
@@ -3,7 +3,14 @@
 //
 // Set desktop wallpaper across different desktop environments.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::Context;
+use image::DynamicImage;
+
+/// Folder of the cropped wallpapers under the user data directory.
+const WALLPAPER_DIR: &str = "noctua/wallpapers";
 
 /// Set an image as desktop wallpaper using multiple fallback methods.
 ///
@@ -12,44 +19,93 @@ use std::path::Path;
 /// 2. wallpaper crate (KDE, XFCE, Windows, macOS)
 /// 3. gsettings (GNOME)
 /// 4. feh (tiling window managers)
-pub fn set_as_wallpaper(path: &Path) {
+///
+/// Returns whether one of them worked.
+pub fn set_as_wallpaper(path: &Path) -> bool {
     // Canonicalize to absolute path.
     let abs_path = match path.canonicalize() {
         Ok(p) => p,
         Err(e) => {
-            log::error!("Failed to canonicalize path {}: {}", path.display(), e);
-            return;
+            tracing::error!("Failed to canonicalize path {}: {}", path.display(), e);
+            return false;
         }
     };
 
     let Some(path_str) = abs_path.to_str() else {
-        log::error!("Invalid UTF-8 in path: {}", abs_path.display());
-        return;
+        tracing::error!("Invalid UTF-8 in path: {}", abs_path.display());
+        return false;
     };
 
-    log::info!("Attempting to set wallpaper: {path_str}");
+    tracing::info!("Attempting to set wallpaper: {path_str}");
 
     // Method 1: Try COSMIC Desktop (direct config file modification).
     if try_cosmic_wallpaper(path_str) {
-        return;
+        return true;
     }
 
     // Method 2: Try wallpaper crate (supports KDE, XFCE, Windows, macOS).
     if try_wallpaper_crate(path_str) {
-        return;
+        return true;
     }
 
     // Method 3: Try GNOME via gsettings.
     if try_gsettings_wallpaper(path_str) {
-        return;
+        return true;
     }
 
     // Method 4: Try feh (common on tiling WMs like i3, sway).
     if try_feh_wallpaper(path_str) {
-        return;
+        return true;
     }
 
-    log::error!("All methods failed to set wallpaper");
+    tracing::error!("All methods failed to set wallpaper");
+    false
+}
+
+/// Save a cropped image as PNG and set it as desktop wallpaper.
+///
+/// The file goes to `~/.local/share/noctua/wallpapers/`, which the desktop
+/// reads from for as long as it shows the wallpaper. Each one gets a new
+/// name, so desktops caching by path pick it up; the previous ones are
+/// removed.
+pub fn set_cropped_as_wallpaper(image: &DynamicImage) -> anyhow::Result<()> {
+    let dir = dirs::data_dir()
+        .context("No user data directory")?
+        .join(WALLPAPER_DIR);
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    for old in previous_wallpapers(&dir) {
+        if let Err(e) = std::fs::remove_file(&old) {
+            tracing::warn!("Failed to remove old wallpaper {}: {e}", old.display());
+        }
+    }
+
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_millis());
+    let path = dir.join(format!("wallpaper-{stamp}.png"));
+    image
+        .to_rgba8()
+        .save_with_format(&path, image::ImageFormat::Png)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    anyhow::ensure!(set_as_wallpaper(&path), "No desktop accepted the wallpaper");
+    Ok(())
+}
+
+/// Cropped wallpapers written before.
+fn previous_wallpapers(dir: &Path) -> Vec<PathBuf> {
+    std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| name.starts_with("wallpaper-") && name.ends_with(".png"))
+        })
+        .collect()
 }
 
 /// Try setting wallpaper via COSMIC config file.
@@ -77,11 +133,11 @@ fn try_cosmic_wallpaper(path_str: &str) -> bool {
 
     match std::fs::write(&cosmic_config, config_content) {
         Ok(()) => {
-            log::info!("Wallpaper set via COSMIC config");
+            tracing::info!("Wallpaper set via COSMIC config");
             true
         }
         Err(e) => {
-            log::warn!("Failed to write COSMIC config: {e}");
+            tracing::warn!("Failed to write COSMIC config: {e}");
             false
         }
     }
@@ -91,11 +147,11 @@ fn try_cosmic_wallpaper(path_str: &str) -> bool {
 fn try_wallpaper_crate(path_str: &str) -> bool {
     match wallpaper::set_from_path(path_str) {
         Ok(()) => {
-            log::info!("Wallpaper set via wallpaper crate");
+            tracing::info!("Wallpaper set via wallpaper crate");
             true
         }
         Err(e) => {
-            log::warn!("wallpaper crate failed: {e}");
+            tracing::warn!("wallpaper crate failed: {e}");
             false
         }
     }
@@ -111,20 +167,20 @@ fn try_gsettings_wallpaper(path_str: &str) -> bool {
     {
         Ok(o) => o,
         Err(e) => {
-            log::warn!("gsettings command failed: {e}");
+            tracing::warn!("gsettings command failed: {e}");
             return false;
         }
     };
 
     if !output.status.success() {
-        log::warn!(
+        tracing::warn!(
             "gsettings failed: {}",
             String::from_utf8_lossy(&output.stderr)
         );
         return false;
     }
 
-    log::info!("Wallpaper set via gsettings");
+    tracing::info!("Wallpaper set via gsettings");
 
     // Also set dark mode wallpaper.
     let _ = std::process::Command::new("gsettings")
@@ -145,15 +201,15 @@ fn try_feh_wallpaper(path_str: &str) -> bool {
         .args(["--bg-scale", path_str])
         .output()
     else {
-        log::warn!("feh not available");
+        tracing::warn!("feh not available");
         return false;
     };
 
     if output.status.success() {
-        log::info!("Wallpaper set via feh");
+        tracing::info!("Wallpaper set via feh");
         true
     } else {
-        log::warn!("feh failed");
+        tracing::warn!("feh failed");
         false
     }
 }
@@ -5,8 +5,9 @@
 
 mod ui;
 mod application;
-mod domain;
+use noctua_core as domain;
 mod infrastructure;
+mod cli;
 
 mod config;
 mod i18n;
@@ -17,8 +18,11 @@ use cosmic::app::Settings;
 use crate::ui::NoctuaApp;
 
 #[derive(Parser, Debug, Clone)]
-#[command(version, about)]
+#[command(version, about, args_conflicts_with_subcommands = true)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<cli::Command>,
+
     /// File to open on startup
     #[arg(value_name = "FILE")]
     pub file: Option<std::path::PathBuf>,
@@ -26,6 +30,11 @@ pub struct Args {
     /// UI language (e.g. "en", "de")
     #[arg(short, long, default_value = "en")]
     pub language: String,
+
+    /// Fullscreen display without editing, dialogs or settings (quit with
+    /// the key combination from the config, Ctrl+Alt+Q by default)
+    #[arg(long)]
+    pub kiosk: bool,
 }
 
 fn main() -> Result<()> {
@@ -35,8 +44,11 @@ fn main() -> Result<()> {
     // Enable localizations to be applied.
     i18n::init(&requested_languages);
 
-    env_logger::init();
+    infrastructure::logging::init();
     let args = Args::parse();
+    if let Some(command) = &args.command {
+        return cli::run(command);
+    }
 
     cosmic::app::run::<NoctuaApp>(Settings::default(), ui::app::Flags::Args(args))
         .map_err(|e| anyhow::anyhow!(e))
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/documents.rs
+//
+// Documents opened by name or stepped to: recent files and the page
+// thumbnails rendered in the background.
+
+use std::path::{Path, PathBuf};
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::application::services::{recent_thumbnails, thumbnail_worker};
+use crate::domain::document::core::content::DocumentContent;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppModel, CompareSide};
+use crate::ui::update;
+
+impl NoctuaApp {
+    /// Open documents, remembering the recent ones, and render their thumbnails.
+    pub(super) fn update_documents(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::ThumbnailsScrolled(pages) => {
+                // The worker renders the pages in view next
+                if let Some((_, queue, _)) = &self.thumbnail_worker {
+                    queue.set_visible(pages.clone());
+                }
+                return Task::none();
+            }
+
+            AppMessage::ClearRecentFiles => {
+                self.config.recent_files.clear();
+                self.model.recent_labels = AppModel::recent_labels(&self.config.recent_files);
+                self.model.recent_thumbnails.clear();
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::OpenPath(_)
+            | AppMessage::NextDocument
+            | AppMessage::PrevDocument
+            | AppMessage::NavigateLeft
+            | AppMessage::NavigateRight
+            | AppMessage::TurnPage(_)
+            | AppMessage::ToggleStripMode
+            | AppMessage::AssignCompareSlot(CompareSide::A, _)
+            | AppMessage::SwapCompareSlots
+            | AppMessage::ConfirmDelete
+            | AppMessage::OpenSession(_)
+            | AppMessage::OpenRecentFile(_) => {
+                // Files opened by name are remembered; stepping through a
+                // folder or a slideshow is not
+                let opened = match message {
+                    AppMessage::OpenPath(path) if self.model.presentation.is_none() => {
+                        Some(path.clone())
+                    }
+                    AppMessage::OpenRecentFile(index) => {
+                        self.config.recent_files.get(*index).cloned()
+                    }
+                    _ => None,
+                };
+                // The view left is kept for a saved session
+                if let Some(path) = self.document_manager.current_path() {
+                    let page = self
+                        .document_manager
+                        .current_document()
+                        .map_or(0, DocumentContent::current_page);
+                    let view = self.model.viewport.document_view(page);
+                    self.model.view_states.insert(path.to_path_buf(), view);
+                }
+                let result = update::update(self, message);
+                if let Some(path) = opened {
+                    self.update_recent_file(&path);
+                }
+                self.model
+                    .selection
+                    .retain_existing(self.document_manager.folder_entries());
+                self.model.search.reset();
+                // A manual turn gives the new slide its full time
+                if let Some(presentation) = self.model.presentation.as_mut() {
+                    presentation.restart_countdown(self.config.slide_interval);
+                }
+                let thumb_task =
+                    Task::batch([self.start_thumbnails(), self.load_recent_thumbnails()]);
+                return match result {
+                    update::UpdateResult::None => thumb_task,
+                    update::UpdateResult::Task(task) => Task::batch([task, thumb_task]),
+                };
+            }
+
+            // Documents opened later: the next file when a rescan finds the
+            // current one removed, an image when its background decode is done
+            AppMessage::FolderScanned(..) | AppMessage::DocumentDecoded(_) => {
+                let result = update::update(self, message);
+                let thumb_task = self.start_thumbnails();
+                return match result {
+                    update::UpdateResult::None => thumb_task,
+                    update::UpdateResult::Task(task) => Task::batch([task, thumb_task]),
+                };
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+
+    /// Put `path` at the top of the recent files if it opened, or drop it
+    /// from them if it is gone. A folder is listed by the file it opened at.
+    ///
+    /// Kiosks leave the list alone.
+    pub(super) fn update_recent_file(&mut self, path: &Path) {
+        if self.kiosk.is_some() {
+            return;
+        }
+        let current = self
+            .document_manager
+            .current_path()
+            .filter(|current| *current == path || current.parent() == Some(path));
+        let changed = if let Some(current) = current {
+            // Documents opened by name keep their own settings longest
+            let touched = self.config.touch_document_settings(current)
+                | update::reading_order_key(&self.document_manager)
+                    .is_some_and(|key| self.config.touch_document_settings(&key));
+            self.config.add_recent_file(current) | touched
+        } else if !path.exists() {
+            let count = self.config.recent_files.len();
+            self.config.recent_files.retain(|recent| recent != path);
+            self.config.recent_files.len() != count
+        } else {
+            false
+        };
+        if changed {
+            self.model.recent_labels = AppModel::recent_labels(&self.config.recent_files);
+            self.save_config();
+        }
+    }
+
+    /// Load the thumbnails of the recent files for the start screen, shown
+    /// while no document is open.
+    ///
+    /// Kiosks show no start screen, so they don't read the files.
+    pub(super) fn load_recent_thumbnails(&self) -> Task<Action<AppMessage>> {
+        if self.kiosk.is_some() || self.document_manager.current_document().is_some() {
+            return Task::none();
+        }
+        let missing: Vec<PathBuf> = self
+            .config
+            .recent_files
+            .iter()
+            .filter(|path| !self.model.recent_thumbnails.contains_key(*path))
+            .cloned()
+            .collect();
+        if missing.is_empty() {
+            return Task::none();
+        }
+        let limits = OpenLimits::new(self.config.max_image_megapixels, self.config.max_page_size);
+        Task::run(
+            recent_thumbnails::load_in_background(missing, limits, self.config.auto_orient),
+            |(path, thumbnail)| Action::App(AppMessage::RecentThumbnailReady(path, thumbnail)),
+        )
+    }
+
+    /// Render the missing thumbnails of the current document in the background.
+    ///
+    /// A worker already running for the document is kept, unless the
+    /// document has no thumbnails yet: it was opened again then, and the
+    /// worker may have handed out pages it needs.
+    pub(super) fn start_thumbnails(&mut self) -> Task<Action<AppMessage>> {
+        let Some(job) = self.document_manager.thumbnail_job() else {
+            self.thumbnail_worker = None;
+            return Task::none();
+        };
+        let started = self
+            .document_manager
+            .current_document()
+            .is_some_and(|doc| doc.thumbnails_loaded() > 0);
+        if started
+            && let Some((path, _, _)) = &self.thumbnail_worker
+            && *path == job.path
+        {
+            return Task::none();
+        }
+
+        let worker_path = job.path.clone();
+        let queue = job.queue.clone();
+        let path = job.path.clone();
+        let (task, handle) = Task::run(
+            thumbnail_worker::render_in_background(job),
+            move |(page, thumbnail)| {
+                Action::App(AppMessage::ThumbnailReady(path.clone(), page, thumbnail))
+            },
+        )
+        .abortable();
+        self.thumbnail_worker = Some((worker_path, queue, handle.abort_on_drop()));
+        task
+    }
+}
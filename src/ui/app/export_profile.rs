@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/export_profile.rs
+//
+// Adding and deleting export profiles.
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::domain::document::operations::export_profile::ExportProfile;
+use crate::ui::message::AppMessage;
+
+impl NoctuaApp {
+    /// Add or delete an export profile.
+    pub(super) fn update_export_profiles(
+        &mut self,
+        message: &AppMessage,
+    ) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::AddExportProfile => {
+                let name = self.model.export_profiles.name_input.trim().to_string();
+                if !name.is_empty()
+                    && let Ok(profile) =
+                        ExportProfile::parse(&self.model.export_profiles.settings_input)
+                {
+                    self.config
+                        .export_profiles
+                        .insert(name.clone(), profile.to_string());
+                    self.save_config();
+
+                    let state = &mut self.model.export_profiles;
+                    state.name_input.clear();
+                    state.settings_input.clear();
+                    state.selected = Some(name);
+                    state.refresh(&self.config);
+                }
+                return Task::none();
+            }
+
+            AppMessage::DeleteExportProfile(name) => {
+                if self.config.export_profiles.remove(name).is_some() {
+                    self.save_config();
+                    self.model.export_profiles.refresh(&self.config);
+                }
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+}
@@ -0,0 +1,163 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/keys.rs
+//
+// Keyboard shortcuts.
+
+use cosmic::iced::keyboard::key::Named;
+use cosmic::iced::keyboard::{Key, Modifiers};
+
+use super::ContextPage;
+use crate::ui::message::AppMessage;
+
+/// Map raw key presses + modifiers into high-level application messages.
+pub(super) fn handle_key_press(key: Key, modifiers: Modifiers) -> Option<AppMessage> {
+    use AppMessage::{
+        PanLeft, PanRight, PanUp, PanDown, OpenFindBar, NavigateLeft, NavigateRight,
+        FlipHorizontal, FlipVertical, RotateCCW, RotateCW, ToggleOrientationLock, ZoomIn, ZoomOut,
+        ZoomReset, ZoomFit, ToggleCropMode, ToggleScaleMode, PanReset, ToggleContextPage,
+        ToggleNavBar, SetAsWallpaper, TogglePageBookmark, RotateView, EnterCropGeometry, Undo,
+        Redo, ToggleAlphaMask, CycleTilePreview, ToggleMagnifier, PageLeft, PageRight,
+        ToggleStripMode, ScrollScreen, TurnPage, ToggleFullscreen, ToggleSlideshow,
+        ToggleAutoAdvance, ToggleTextSelectMode, CopySelectedText, CopyAsSvg, OpenFileDialog,
+        ToggleCompare, DeleteCurrentFile, SaveSession, OpenSessionDialog, GoToZoomBookmark,
+    };
+
+    // Hidden log viewer for bug reports.
+    if modifiers.control()
+        && modifiers.shift()
+        && !modifiers.alt()
+        && !modifiers.logo()
+        && matches!(key.as_ref(), Key::Character(ch) if ch.eq_ignore_ascii_case("l"))
+    {
+        return Some(ToggleContextPage(ContextPage::Log));
+    }
+
+    // Ctrl+Shift+C copies a vector document as SVG.
+    if modifiers.control()
+        && modifiers.shift()
+        && !modifiers.alt()
+        && !modifiers.logo()
+        && matches!(key.as_ref(), Key::Character(ch) if ch.eq_ignore_ascii_case("c"))
+    {
+        return Some(CopyAsSvg);
+    }
+
+    // Ctrl+Shift+S saves the session, Ctrl+Shift+O opens one.
+    if modifiers.control() && modifiers.shift() && !modifiers.alt() && !modifiers.logo() {
+        match key.as_ref() {
+            Key::Character(ch) if ch.eq_ignore_ascii_case("s") => return Some(SaveSession),
+            Key::Character(ch) if ch.eq_ignore_ascii_case("o") => return Some(OpenSessionDialog),
+            _ => {}
+        }
+    }
+
+    // Handle Ctrl + arrow keys for panning.
+    if modifiers.control() && !modifiers.shift() && !modifiers.alt() && !modifiers.logo() {
+        return match key.as_ref() {
+            Key::Named(Named::ArrowLeft) => Some(PanLeft),
+            Key::Named(Named::ArrowRight) => Some(PanRight),
+            Key::Named(Named::ArrowUp) => Some(PanUp),
+            Key::Named(Named::ArrowDown) => Some(PanDown),
+            Key::Character(ch) if ch.eq_ignore_ascii_case("f") => Some(OpenFindBar),
+            Key::Character(ch) if ch.eq_ignore_ascii_case("o") => Some(OpenFileDialog),
+            Key::Character(ch) if ch.eq_ignore_ascii_case("r") => Some(RotateView),
+            Key::Character(ch) if ch.eq_ignore_ascii_case("s") => {
+                Some(ToggleContextPage(ContextPage::SaveAs))
+            }
+            Key::Character(ch) if ch.eq_ignore_ascii_case("z") => Some(Undo),
+            Key::Character(ch) if ch.eq_ignore_ascii_case("y") => Some(Redo),
+            Key::Character(ch) if ch.eq_ignore_ascii_case("c") => Some(CopySelectedText),
+            // Ctrl+1…9 show the zoom bookmarks of the document
+            Key::Character(ch) => match ch.parse::<usize>() {
+                Ok(number @ 1..=9) => Some(GoToZoomBookmark(number - 1)),
+                _ => None,
+            },
+            _ => None,
+        };
+    }
+
+    // Ignore key presses when command-style modifiers are pressed.
+    if modifiers.command() || modifiers.alt() || modifiers.logo() || modifiers.control() {
+        return None;
+    }
+
+    match key.as_ref() {
+        // Navigation with arrow keys (swapped for right-to-left reading in update);
+        // Shift turns pages of multi-page documents instead.
+        Key::Named(Named::ArrowRight) if modifiers.shift() => Some(PageRight),
+        Key::Named(Named::ArrowLeft) if modifiers.shift() => Some(PageLeft),
+        Key::Named(Named::ArrowRight) => Some(NavigateRight),
+        Key::Named(Named::ArrowLeft) => Some(NavigateLeft),
+
+        // Transformations.
+        Key::Character(ch) if ch.eq_ignore_ascii_case("h") => Some(FlipHorizontal),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("v") => Some(FlipVertical),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("r") => {
+            if modifiers.shift() {
+                Some(RotateCCW)
+            } else {
+                Some(RotateCW)
+            }
+        }
+        Key::Character(ch) if ch.eq_ignore_ascii_case("l") => Some(ToggleOrientationLock),
+
+        // Zoom.
+        Key::Character("+" | "=") => Some(ZoomIn),
+        Key::Character("-") => Some(ZoomOut),
+        Key::Character("1") => Some(ZoomReset),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("f") => Some(ZoomFit),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("a") => Some(ToggleAlphaMask),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("t") => Some(CycleTilePreview),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("m") => Some(ToggleMagnifier),
+
+        // Tool modes (Shift+C compares the image with another one).
+        Key::Character(ch) if ch.eq_ignore_ascii_case("c") && modifiers.shift() => {
+            Some(ToggleCompare)
+        }
+        Key::Character(ch) if ch.eq_ignore_ascii_case("c") => Some(ToggleCropMode),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("s") => Some(ToggleScaleMode),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("g") => Some(EnterCropGeometry),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("x") => Some(ToggleTextSelectMode),
+
+        // Crop mode actions (Enter/Escape handled via key press, validated in update).
+        Key::Named(Named::Enter) => Some(AppMessage::ApplyCrop),
+        Key::Named(Named::Escape) => Some(AppMessage::CancelCrop),
+
+        // Reset pan.
+        Key::Character("0") => Some(PanReset),
+
+        // Move the file to the trash (asks first, see settings).
+        Key::Named(Named::Delete) => Some(DeleteCurrentFile),
+
+        // Fullscreen (Escape leaves it, see CancelCrop).
+        Key::Named(Named::F11) => Some(ToggleFullscreen),
+        Key::Named(Named::F5) => Some(ToggleSlideshow),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("p") => Some(ToggleAutoAdvance),
+
+        // Toggle panels.
+        Key::Character(ch) if ch.eq_ignore_ascii_case("i") => {
+            Some(ToggleContextPage(ContextPage::Properties))
+        }
+        Key::Character(ch) if ch.eq_ignore_ascii_case("n") => Some(ToggleNavBar),
+        Key::Character(ch) if ch.eq_ignore_ascii_case("b") => Some(TogglePageBookmark),
+
+        // Webtoon mode and scrolling it by screen.
+        Key::Character(ch) if ch.eq_ignore_ascii_case("w") && modifiers.shift() => {
+            Some(ToggleStripMode)
+        }
+        Key::Named(Named::Space) => Some(ScrollScreen(!modifiers.shift())),
+
+        // Page turners: keyboard, browser and media keys, presenter remotes.
+        Key::Named(Named::PageDown | Named::BrowserForward | Named::MediaTrackNext) => {
+            Some(TurnPage(true))
+        }
+        Key::Named(Named::PageUp | Named::BrowserBack | Named::MediaTrackPrevious) => {
+            Some(TurnPage(false))
+        }
+
+        // Wallpaper.
+        Key::Character(ch) if ch.eq_ignore_ascii_case("w") => Some(SetAsWallpaper),
+
+        _ => None,
+    }
+}
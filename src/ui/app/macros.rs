@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/macros.rs
+//
+// Saving and deleting edit macros.
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::domain::document::operations::edit_macro;
+use crate::ui::message::AppMessage;
+
+impl NoctuaApp {
+    /// Save or delete an edit macro.
+    pub(super) fn update_macros(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::SaveMacro => {
+                let name = self.model.macros.name_input.trim().to_string();
+                if let Some(steps) = &self.model.macros.recording
+                    && !steps.is_empty()
+                    && !name.is_empty()
+                {
+                    self.config
+                        .macros
+                        .insert(name, edit_macro::format_steps(steps));
+                    self.save_config();
+                    self.model.macros.recording = None;
+                    self.model.macros.name_input.clear();
+                }
+                return Task::none();
+            }
+
+            AppMessage::DeleteMacro(name) => {
+                if self.config.macros.remove(name).is_some() {
+                    self.save_config();
+                }
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+}
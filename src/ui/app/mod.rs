@@ -0,0 +1,678 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/mod.rs
+//
+// COSMIC application wiring and main app struct.
+
+mod documents;
+mod export_profile;
+mod keys;
+mod macros;
+mod panels;
+mod presentation;
+mod settings;
+mod share;
+mod upload;
+mod wallpaper;
+mod zoom_bookmarks;
+
+use super::kiosk::{self, KeyCombo};
+use super::message::AppMessage;
+use super::model::{AnimationPlayer, AppMode, AppModel, CompareSide, LeftPanel, LowPowerMode};
+use super::screen_cover;
+use super::update;
+use crate::ui::views;
+
+use std::path::PathBuf;
+use std::time::Duration;
+
+use cosmic::app::{context_drawer, Core};
+use cosmic::cosmic_config::{self, CosmicConfigEntry};
+use cosmic::iced::event::{self, Event};
+use cosmic::iced::keyboard;
+use cosmic::iced::task;
+use cosmic::iced::time;
+use cosmic::iced::window;
+use cosmic::iced::Subscription;
+use cosmic::widget::nav_bar;
+use cosmic::{Action, Application, Element, Task};
+
+use crate::application::DocumentManager;
+use crate::application::session;
+use crate::config::AppConfig;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::domain::document::core::page::ThumbnailQueue;
+use crate::domain::document::core::svg_text::set_svg_text_options;
+use crate::infrastructure::filesystem::SortOrder;
+use crate::infrastructure::filesystem::locations;
+use crate::infrastructure::logging;
+use crate::infrastructure::system::power;
+use crate::Args;
+
+/// Shortest folder rescan interval in low-power mode, in seconds.
+const LOW_POWER_RESCAN_INTERVAL: u32 = 300;
+
+/// Flags passed from `main` into the application.
+#[derive(Debug, Clone)]
+pub enum Flags {
+    Args(Args),
+}
+
+/// Context page displayed in right drawer.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum ContextPage {
+    #[default]
+    Properties,
+    /// Every EXIF, XMP and IPTC tag of the image
+    MetadataBrowser,
+    /// Author, copyright, description, rating and capture date
+    EditMetadata,
+    Settings,
+    DateShift,
+    Geotag,
+    PrivacyExport,
+    /// Folder images converted to another format, size and quality
+    BatchConvert,
+    CropGeometry,
+    History,
+    Macros,
+    SaveAs,
+    SpriteSheet,
+    Animation,
+    Adjustments,
+    Resize,
+    ScanCleanup,
+    ExportPages,
+    Upload,
+    ZoomBookmarks,
+    Wallpaper,
+    /// Recent warnings and errors (Ctrl+Shift+L, not in the menus)
+    Log,
+}
+
+/// Main application type.
+pub struct NoctuaApp {
+    core: Core,
+    pub model: AppModel,
+    nav: nav_bar::Model,
+    context_page: ContextPage,
+    pub config: AppConfig,
+    config_handler: Option<cosmic_config::Config>,
+    pub document_manager: DocumentManager,
+    /// Kiosk mode with its exit key combination (None = normal mode)
+    kiosk: Option<KeyCombo>,
+    /// Black surface hiding the other screens while in fullscreen
+    screen_cover: Option<window::Id>,
+    /// Document the thumbnail worker renders for and the pages it has left;
+    /// dropping the handle stops it
+    thumbnail_worker: Option<(PathBuf, ThumbnailQueue, task::Handle)>,
+}
+
+impl cosmic::Application for NoctuaApp {
+    type Executor = cosmic::SingleThreadExecutor;
+    type Flags = Flags;
+    type Message = AppMessage;
+
+    const APP_ID: &'static str = "org.codeberg.wfx.Noctua";
+
+    fn core(&self) -> &Core {
+        &self.core
+    }
+
+    fn core_mut(&mut self) -> &mut Core {
+        &mut self.core
+    }
+
+    fn init(mut core: Core, flags: Self::Flags) -> (Self, Task<Action<Self::Message>>) {
+        // Load persisted config.
+        let (config, config_handler) =
+            match cosmic_config::Config::new(Self::APP_ID, AppConfig::VERSION) {
+                Ok(handler) => {
+                    let config = AppConfig::get_entry(&handler).unwrap_or_default();
+                    (config, Some(handler))
+                }
+                Err(_) => (AppConfig::default(), None),
+            };
+        logging::set_levels(&config.log_levels);
+        locations::configure(config.cache_dir.clone(), config.temp_dir.clone());
+        locations::remove_stale_files();
+
+        let Flags::Args(args) = flags;
+
+        let kiosk = args.kiosk.then(|| {
+            KeyCombo::parse(&config.kiosk_exit_keys).unwrap_or_else(|| {
+                tracing::warn!(
+                    "Invalid kiosk exit keys {:?}, using {}",
+                    config.kiosk_exit_keys,
+                    kiosk::DEFAULT_EXIT_KEYS
+                );
+                KeyCombo::parse(kiosk::DEFAULT_EXIT_KEYS).expect("valid default")
+            })
+        });
+
+        // Determine initial path: CLI argument takes priority.
+        // Fall back to configured default directory only if it exists.
+        let initial_path = args.file.or_else(|| {
+            config
+                .default_image_dir
+                .as_ref()
+                .filter(|p| p.exists())
+                .cloned()
+        });
+
+        // Initialize document manager
+        let mut document_manager = DocumentManager::new();
+        document_manager.set_exclude_patterns(&config.nav_exclude_patterns);
+        document_manager
+            .set_sort_order(SortOrder::from_key(&config.nav_sort_order).unwrap_or_default());
+        document_manager.set_auto_orient(config.auto_orient);
+        document_manager.set_open_limits(OpenLimits::new(
+            config.max_image_megapixels,
+            config.max_page_size,
+        ));
+        set_svg_text_options(settings::svg_text_options(&config));
+
+        // Initialize model
+        let mut model = AppModel::new(config.clone());
+        let detect_monitor = model.color.load_monitor(config.monitor_profile.as_deref());
+
+        // A session file opens once the app is set up, instead of a document
+        let (initial_path, initial_session) = match initial_path {
+            Some(path) if session::is_session_file(&path) => (None, Some(path)),
+            path => (path, None),
+        };
+
+        // Load initial document if provided
+        if let Some(path) = &initial_path {
+            if let Err(e) = document_manager.open_document(path) {
+                tracing::error!("Failed to open initial path {}: {}", path.display(), e);
+            } else {
+                // Set initial view mode for the document kind
+                update::reset_view(&mut model, &document_manager, &config);
+
+                // Cache initial render so image is displayed immediately
+                update::cache_render(&mut model, &mut document_manager);
+            }
+        }
+
+        // Initialize nav bar model (required for COSMIC to show toggle icon).
+        let nav = nav_bar::Model::default();
+
+        // Apply persisted panel states.
+        core.window.show_context = config.context_drawer_visible && kiosk.is_none();
+        if kiosk.is_some() {
+            core.window.show_headerbar = false;
+        }
+
+        // Auto-open nav bar for multi-page documents
+        let should_show_nav = if let Some(doc) = document_manager.current_document() {
+            doc.is_multi_page()
+        } else {
+            false
+        };
+
+        if should_show_nav {
+            core.nav_bar_set_toggled(true);
+            model.panels.left = Some(left_panel_tab(&config, &document_manager));
+        } else {
+            core.nav_bar_set_toggled(config.nav_bar_visible);
+        }
+
+        let mut init_task = Task::none();
+        let mut screen_cover = None;
+        if kiosk.is_some()
+            && let Some(id) = core.main_window_id()
+        {
+            init_task = Task::batch([init_task, window::change_mode(id, window::Mode::Fullscreen)]);
+            if config.cover_other_screens {
+                let (cover, cover_task) = screen_cover::open();
+                screen_cover = Some(cover);
+                init_task = Task::batch([init_task, cover_task]);
+            }
+        }
+
+        let mut app = Self {
+            core,
+            model,
+            nav,
+            context_page: ContextPage::default(),
+            config,
+            config_handler,
+            document_manager,
+            kiosk,
+            screen_cover,
+            thumbnail_worker: None,
+        };
+        let session_task = match initial_session
+            .map(|path| update::update(&mut app, &AppMessage::OpenSession(path)))
+        {
+            Some(update::UpdateResult::Task(task)) => task,
+            _ => Task::none(),
+        };
+        if let Some(path) = initial_path {
+            app.update_recent_file(&path);
+        }
+        // Thumbnails of the initial document, if it has pages, or else of
+        // the recent files
+        let thumbnail_task = Task::batch([app.start_thumbnails(), app.load_recent_thumbnails()]);
+        let monitor_task = if detect_monitor {
+            settings::detect_monitor_profile()
+        } else {
+            Task::none()
+        };
+        (
+            app,
+            Task::batch([
+                init_task,
+                session_task,
+                thumbnail_task,
+                monitor_task,
+                Task::done(Action::App(AppMessage::CheckScaleFactor)),
+            ]),
+        )
+    }
+
+    fn on_close_requested(&self, _id: window::Id) -> Option<Self::Message> {
+        None
+    }
+
+    fn update(&mut self, message: Self::Message) -> Task<Action<Self::Message>> {
+        let task = self.handle_message(message);
+        // Any message may zoom, turn or replace a vector document
+        let zoom_task = update::sync_zoom_render(&mut self.model, &self.document_manager);
+        // ...or turn to a large PDF page shown as a preview first
+        let refine_task = update::sync_page_refine(&mut self.document_manager);
+        // ...or go to another image while the metadata browser is open
+        let browser_task = self.sync_metadata_browser();
+        // ...or while the metadata editor is open
+        self.sync_metadata_edit();
+        // ...or while Save As is open
+        let advice_task = self.sync_format_advice();
+        Task::batch([task, zoom_task, refine_task, browser_task, advice_task])
+    }
+
+    fn header_start(&self) -> Vec<Element<'_, Self::Message>> {
+        if self.kiosk.is_some() {
+            return Vec::new();
+        }
+        views::header::start(&self.model, &self.document_manager)
+    }
+
+    fn header_end(&self) -> Vec<Element<'_, Self::Message>> {
+        if self.kiosk.is_some() {
+            return Vec::new();
+        }
+        views::header::end(&self.model, &self.document_manager, &self.config)
+    }
+
+    fn view(&self) -> Element<'_, Self::Message> {
+        // Without a document, the recent files are offered (not in kiosks)
+        if self.kiosk.is_none()
+            && self.document_manager.current_document().is_none()
+            && !self.config.recent_files.is_empty()
+        {
+            return views::start_screen::view(&self.model, &self.config);
+        }
+        views::view(&self.model, &self.document_manager, &self.config)
+    }
+
+    fn dialog(&self) -> Option<Element<'_, Self::Message>> {
+        if self.model.date_shift.confirm {
+            return Some(views::date_shift_dialog::view(&self.model.date_shift));
+        }
+        let path = self.model.delete_confirm.as_deref()?;
+        Some(views::delete_dialog::view(path))
+    }
+
+    fn view_window(&self, _id: window::Id) -> Element<'_, Self::Message> {
+        // The only other surface is the screen cover
+        screen_cover::view()
+    }
+
+    fn context_drawer(&self) -> Option<context_drawer::ContextDrawer<'_, Self::Message>> {
+        if !self.core.window.show_context
+            || self.kiosk.is_some()
+            || matches!(self.model.mode, AppMode::Fullscreen)
+        {
+            return None;
+        }
+        let content = match self.context_page {
+            ContextPage::Properties => views::panels::view(&self.model, &self.document_manager),
+            ContextPage::MetadataBrowser => views::metadata_browser_panel::view(&self.model),
+            ContextPage::EditMetadata => {
+                views::metadata_edit_panel::view(&self.model, &self.document_manager)
+            }
+            ContextPage::Settings => {
+                views::settings_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::DateShift => views::date_shift_panel::view(&self.model),
+            ContextPage::Geotag => views::geotag_panel::view(&self.model, &self.document_manager),
+            ContextPage::PrivacyExport => {
+                views::privacy_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::BatchConvert => {
+                views::batch_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::CropGeometry => {
+                views::crop_geometry_panel::view(&self.model, &self.document_manager)
+            }
+            ContextPage::History => views::history_panel::view(&self.model),
+            ContextPage::Macros => {
+                views::macro_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::SaveAs => {
+                views::save_as_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::SpriteSheet => {
+                views::sprite_sheet_panel::view(&self.model, &self.document_manager)
+            }
+            ContextPage::Animation => {
+                views::animation_panel::view(&self.model, &self.document_manager)
+            }
+            ContextPage::Adjustments => {
+                views::adjust_panel::view(&self.model, &self.document_manager)
+            }
+            ContextPage::Resize => {
+                views::resize_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::ScanCleanup => {
+                views::scan_cleanup_panel::view(&self.model, &self.document_manager)
+            }
+            ContextPage::ExportPages => {
+                views::export_pages_panel::view(&self.model, &self.document_manager)
+            }
+            ContextPage::Upload => {
+                views::upload_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::ZoomBookmarks => {
+                views::zoom_bookmarks_panel::view(&self.model, &self.document_manager, &self.config)
+            }
+            ContextPage::Wallpaper => views::wallpaper_panel::view(&self.config),
+            ContextPage::Log => views::log_panel::view(&self.model, &self.config),
+        };
+        Some(context_drawer::context_drawer(
+            content,
+            AppMessage::ToggleContextPage(self.context_page),
+        ))
+    }
+
+    fn nav_model(&self) -> Option<&nav_bar::Model> {
+        Some(&self.nav)
+    }
+
+    fn nav_bar(&self) -> Option<Element<'_, Action<Self::Message>>> {
+        if !self.core.nav_bar_active() || matches!(self.model.mode, AppMode::Fullscreen) {
+            return None;
+        }
+        views::nav_bar(&self.model, &self.document_manager)
+    }
+
+    fn footer(&self) -> Option<Element<'_, Self::Message>> {
+        // Slideshows keep a slim footer with their play/pause state
+        if let Some(presentation) = &self.model.presentation
+            && presentation.slideshow.is_some()
+        {
+            return Some(views::footer::slideshow_view(
+                presentation,
+                &self.document_manager,
+            ));
+        }
+        if self.kiosk.is_some() || matches!(self.model.mode, AppMode::Fullscreen) {
+            return None;
+        }
+        Some(views::footer::view(&self.model, &self.document_manager))
+    }
+
+    fn subscription(&self) -> Subscription<Self::Message> {
+        Subscription::batch([
+            keyboard::on_key_press(keys::handle_key_press),
+            folder_rescan_subscription(self),
+            // Battery and power profile, for the automatic low-power mode
+            if LowPowerMode::from_key(&self.config.low_power_mode).unwrap_or_default()
+                == LowPowerMode::Auto
+            {
+                Subscription::run(power::watch).map(AppMessage::PowerStateChanged)
+            } else {
+                Subscription::none()
+            },
+            if self.model.presentation.is_some() {
+                time::every(Duration::from_millis(250)).map(AppMessage::PresentationTick)
+            } else {
+                Subscription::none()
+            },
+            // Animation preview frames, short enough for 4× speed; fewer
+            // frames when played on in low-power mode
+            if self
+                .model
+                .animation
+                .player
+                .as_ref()
+                .is_some_and(AnimationPlayer::is_playing)
+            {
+                let tick = if self.model.low_power { 40 } else { 10 };
+                time::every(Duration::from_millis(tick)).map(AppMessage::AnimationTick)
+            } else {
+                Subscription::none()
+            },
+            // Hide the canvas controls again once the pointer rests
+            if self.model.canvas_controls_until.is_some() {
+                time::every(Duration::from_millis(500)).map(AppMessage::CanvasControlsTick)
+            } else {
+                Subscription::none()
+            },
+            // A window moved to a screen with another scale factor is resized
+            event::listen_with(|event, _status, _window| match event {
+                Event::Window(window::Event::Resized(_)) => Some(AppMessage::CheckScaleFactor),
+                _ => None,
+            }),
+            // Shift+Enter in the find field, which captures the key press
+            if self.model.find.is_some() {
+                event::listen_with(|event, _status, _window| match event {
+                    Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                        Some(AppMessage::FindShift(modifiers.shift()))
+                    }
+                    _ => None,
+                })
+            } else {
+                Subscription::none()
+            },
+            if self.kiosk.is_some() {
+                keyboard::on_key_press(|key, modifiers| Some(AppMessage::KioskKey(key, modifiers)))
+            } else {
+                Subscription::none()
+            },
+        ])
+    }
+}
+
+impl NoctuaApp {
+    /// Save current config to disk.
+    fn save_config(&self) {
+        if let Some(ref handler) = self.config_handler {
+            let _ = self.config.write_entry(handler);
+        }
+    }
+
+    /// Handle one message; `update` wraps it.
+    fn handle_message(&mut self, message: AppMessage) -> Task<Action<AppMessage>> {
+        if let Some(exit_keys) = &self.kiosk {
+            if let AppMessage::KioskKey(key, modifiers) = &message
+                && exit_keys.matches(key, *modifiers)
+                && let Some(id) = self.core.main_window_id()
+            {
+                return window::close(id);
+            }
+            if !kiosk::allows(&message) {
+                return Task::none();
+            }
+        }
+
+        match &message {
+            // Tools need the panels: leave fullscreen first
+            AppMessage::ToggleCropMode
+            | AppMessage::ToggleScaleMode
+            | AppMessage::OpenFormatPanel
+                if matches!(self.model.mode, AppMode::Fullscreen) =>
+            {
+                let leave = self.set_fullscreen(false);
+                Task::batch([leave, self.update(message.clone())])
+            }
+
+            // Enter sets the wallpaper rather than cropping the image
+            AppMessage::ApplyCrop
+                if self.context_page == ContextPage::Wallpaper && self.core.window.show_context =>
+            {
+                self.update(AppMessage::ApplyWallpaper)
+            }
+
+            AppMessage::CheckScaleFactor => {
+                let Some(id) = self.core.main_window_id() else {
+                    return Task::none();
+                };
+                window::get_scale_factor(id)
+                    .map(|factor| Action::App(AppMessage::ScaleFactorChanged(factor)))
+            }
+
+            // Escape leaves fullscreen
+            AppMessage::CancelCrop
+                if self.kiosk.is_none()
+                    && self.model.delete_confirm.is_none()
+                    && matches!(self.model.mode, AppMode::Fullscreen) =>
+            {
+                self.set_fullscreen(false)
+            }
+
+            AppMessage::ToggleNavBar
+            | AppMessage::SelectLeftPanel(_)
+            | AppMessage::OpenFormatPanel
+            | AppMessage::ToggleContextPage(_)
+            | AppMessage::OpenFindBar
+            | AppMessage::EnterCropGeometry => self.update_panels(&message),
+
+            AppMessage::ThumbnailsScrolled(_)
+            | AppMessage::ClearRecentFiles
+            | AppMessage::OpenPath(_)
+            | AppMessage::NextDocument
+            | AppMessage::PrevDocument
+            | AppMessage::NavigateLeft
+            | AppMessage::NavigateRight
+            | AppMessage::TurnPage(_)
+            | AppMessage::ToggleStripMode
+            | AppMessage::AssignCompareSlot(CompareSide::A, _)
+            | AppMessage::SwapCompareSlots
+            | AppMessage::ConfirmDelete
+            | AppMessage::OpenSession(_)
+            | AppMessage::OpenRecentFile(_)
+            | AppMessage::FolderScanned(..)
+            | AppMessage::DocumentDecoded(_) => self.update_documents(&message),
+
+            AppMessage::SetAsWallpaper | AppMessage::SetWallpaperRatio(_) => {
+                self.update_wallpaper(&message)
+            }
+
+            AppMessage::ExcludePatternsInput(_)
+            | AppMessage::ApplyExcludePatterns
+            | AppMessage::SvgFontsInput(_)
+            | AppMessage::ApplySvgFonts
+            | AppMessage::SetSvgFontSize(_)
+            | AppMessage::SetSvgTextToPath(_)
+            | AppMessage::SetSortOrder(_)
+            | AppMessage::SetColorManagement(_)
+            | AppMessage::SetDefaultViewMode(..)
+            | AppMessage::SetViewMode(_)
+            | AppMessage::SetLogLevel(..)
+            | AppMessage::SetRescanInterval(_)
+            | AppMessage::SetLowPowerMode(_)
+            | AppMessage::SetSidecarOnSave(_)
+            | AppMessage::SetImportXmpEdits(_)
+            | AppMessage::SetAutoOrient(_)
+            | AppMessage::SetConfirmDelete(_)
+            | AppMessage::SetCanvasControls(_)
+            | AppMessage::SetCanvasBackground(_)
+            | AppMessage::CanvasColorInput(_)
+            | AppMessage::SetDocumentBackground(_)
+            | AppMessage::DocumentColorInput(_)
+            | AppMessage::SetArrowsTurnPages(_)
+            | AppMessage::SetIntegerZoom(_)
+            | AppMessage::SetFitSharpening(_)
+            | AppMessage::SetMagnifierZoom(_)
+            | AppMessage::SetMagnifierCrosshair(_)
+            | AppMessage::SelectMonitorProfile(_)
+            | AppMessage::MonitorProfileDetected(_)
+            | AppMessage::ToggleRightToLeft => self.update_settings(&message),
+
+            AppMessage::SaveMacro | AppMessage::DeleteMacro(_) => self.update_macros(&message),
+
+            AppMessage::AddExportProfile | AppMessage::DeleteExportProfile(_) => {
+                self.update_export_profiles(&message)
+            }
+
+            AppMessage::AddShareTarget | AppMessage::DeleteShareTarget(_) => {
+                self.update_share(&message)
+            }
+
+            AppMessage::UploadProgress(_)
+            | AppMessage::SaveUploadEndpoint
+            | AppMessage::ClearUploadHistory => self.update_upload(&message),
+
+            AppMessage::AddZoomBookmark | AppMessage::DeleteZoomBookmark(_) => {
+                self.update_zoom_bookmarks(&message)
+            }
+
+            AppMessage::ToggleFullscreen
+            | AppMessage::SetSlideInterval(_)
+            | AppMessage::SetSlideshowLoop(_)
+            | AppMessage::SetSlideshowShuffle(_)
+            | AppMessage::SetSlideshowMinRating(_)
+            | AppMessage::SetSlideshowTags(_)
+            | AppMessage::ToggleSlideshow
+            | AppMessage::SlideshowFiltered(_)
+            | AppMessage::SetCoverOtherScreens(_)
+            | AppMessage::SetHideCursorInFullscreen(_) => self.update_presentation(&message),
+
+            _ => match update::update(self, &message) {
+                update::UpdateResult::None => Task::none(),
+                update::UpdateResult::Task(task) => task,
+            },
+        }
+    }
+
+    /// Update nav bar visibility based on current document type.
+    pub fn update_nav_bar_for_document(&mut self) {
+        if let Some(doc) = self.document_manager.current_document() {
+            if doc.is_multi_page() {
+                // Multi-page document: open nav bar with the last used tab
+                self.core.nav_bar_set_toggled(true);
+                self.model.panels.left = Some(left_panel_tab(&self.config, &self.document_manager));
+            } else {
+                // Single-page document: close nav bar
+                self.core.nav_bar_set_toggled(false);
+                self.model.panels.left = None;
+            }
+        }
+    }
+}
+
+/// Left panel tab last used for the current document kind (thumbnails by default).
+fn left_panel_tab(config: &AppConfig, manager: &DocumentManager) -> LeftPanel {
+    manager
+        .current_document()
+        .and_then(|doc| config.left_panel_tabs.get(doc.kind().name()))
+        .and_then(|key| LeftPanel::from_key(key))
+        .unwrap_or(LeftPanel::Thumbnails)
+}
+
+/// Periodic check of the folder for added or removed files (signage).
+///
+/// In low-power mode the folder is checked every few minutes at most.
+fn folder_rescan_subscription(app: &NoctuaApp) -> Subscription<AppMessage> {
+    match app.config.folder_rescan_interval {
+        0 => Subscription::none(),
+        seconds if app.model.low_power => time::every(Duration::from_secs(
+            seconds.max(LOW_POWER_RESCAN_INTERVAL).into(),
+        ))
+        .map(|_| AppMessage::RescanFolder),
+        seconds => {
+            time::every(Duration::from_secs(seconds.into())).map(|_| AppMessage::RescanFolder)
+        }
+    }
+}
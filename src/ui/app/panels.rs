@@ -0,0 +1,277 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/panels.rs
+//
+// Side panels and the context drawer, and the panels kept in step with the
+// document shown.
+
+use std::path::Path;
+
+use cosmic::widget::text_input;
+use cosmic::{Action, Application, Task};
+
+use super::{ContextPage, NoctuaApp, left_panel_tab};
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::operations::geotag::GpsCoordinate;
+use crate::domain::document::operations::{animation, format_advice, metadata_tags};
+use crate::infrastructure::logging;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, FindState, ResizeUnit};
+use crate::ui::views;
+use crate::ui::widgets::CropSelection;
+
+impl NoctuaApp {
+    /// Open and switch the side panels and context pages.
+    pub(super) fn update_panels(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::ToggleNavBar => {
+                self.core.nav_bar_toggle();
+                let is_visible = self.core.nav_bar_active();
+                self.config.nav_bar_visible = is_visible;
+                self.save_config();
+
+                if is_visible {
+                    // Opening nav bar - show thumbnails for multi-page docs
+                    if let Some(doc) = self.document_manager.current_document()
+                        && doc.is_multi_page()
+                    {
+                        self.model.panels.left =
+                            Some(left_panel_tab(&self.config, &self.document_manager));
+                    }
+                } else {
+                    // Closing nav bar - hide left panel
+                    self.model.panels.left = None;
+                }
+                return Task::none();
+            }
+
+            AppMessage::SelectLeftPanel(tab) => {
+                self.model.panels.left = Some(*tab);
+                if let Some(doc) = self.document_manager.current_document() {
+                    self.config
+                        .left_panel_tabs
+                        .insert(doc.kind().name().to_string(), tab.key().to_string());
+                    self.save_config();
+                }
+                return Task::none();
+            }
+
+            AppMessage::OpenFormatPanel => {
+                // Format panel is now part of Transform mode
+                // Switch to Transform mode which shows format tools in right panel
+                self.model.mode = crate::ui::model::AppMode::Transform {
+                    paper_format: None,
+                    orientation: crate::ui::model::Orientation::default(),
+                    straighten: crate::ui::model::StraightenTool::default(),
+                };
+
+                return Task::none();
+            }
+
+            AppMessage::ToggleContextPage(page) => {
+                if self.context_page == *page {
+                    self.core.window.show_context = !self.core.window.show_context;
+                } else {
+                    self.context_page = *page;
+                    self.core.window.show_context = true;
+                }
+                self.config.context_drawer_visible = self.core.window.show_context;
+                self.save_config();
+
+                // Read folder dates when the date shift tool opens
+                if self.context_page == ContextPage::DateShift && self.core.window.show_context {
+                    self.model
+                        .date_shift
+                        .load(self.document_manager.folder_entries());
+                }
+
+                // Sliders start from the adjustments shown on the image
+                if self.context_page == ContextPage::Adjustments && self.core.window.show_context {
+                    self.model.adjust.values = self
+                        .document_manager
+                        .current_document()
+                        .map(|doc| doc.adjustments())
+                        .unwrap_or_default();
+                }
+
+                // Start from the current size, or the paper chosen in transform mode
+                if self.context_page == ContextPage::Resize && self.core.window.show_context {
+                    let size = self
+                        .document_manager
+                        .current_document()
+                        .map(|doc| doc.dimensions())
+                        .unwrap_or_default();
+                    let resize = &mut self.model.resize;
+                    if let AppMode::Transform {
+                        paper_format: Some(paper),
+                        orientation,
+                        ..
+                    } = &self.model.mode
+                    {
+                        resize.paper = *paper;
+                        resize.orientation = *orientation;
+                        resize.set_unit(ResizeUnit::Paper, size);
+                    } else {
+                        resize.set_unit(resize.unit, size);
+                    }
+                }
+
+                // Read the frames of an animated image; the preview ends with the panel
+                if self.context_page == ContextPage::Animation && self.core.window.show_context {
+                    self.model
+                        .animation
+                        .load(self.document_manager.current_path());
+                } else {
+                    self.model.animation.player = None;
+                }
+
+                // Prefill the geotag editor with the current location
+                if self.context_page == ContextPage::Geotag && self.core.window.show_context {
+                    let current = self
+                        .document_manager
+                        .current_metadata()
+                        .and_then(|meta| meta.exif.as_ref())
+                        .and_then(|exif| exif.gps_latitude.zip(exif.gps_longitude))
+                        .and_then(|(lat, lon)| GpsCoordinate::new(lat, lon));
+                    self.model.geotag.load(current);
+                }
+
+                // Read the fields of the file as it is now
+                if self.context_page == ContextPage::EditMetadata && self.core.window.show_context {
+                    let current = self.document_manager.current_path();
+                    self.model.metadata_edit.load(current);
+                }
+
+                // Read the tags again, the file may have changed since
+                if self.context_page == ContextPage::MetadataBrowser
+                    && self.core.window.show_context
+                {
+                    self.model.metadata_browser.clear();
+                }
+
+                // Advise on the image as edited so far
+                if self.context_page == ContextPage::SaveAs && self.core.window.show_context {
+                    self.model.export_profiles.clear_advice();
+                }
+
+                // Read the messages logged so far
+                if self.context_page == ContextPage::Log && self.core.window.show_context {
+                    self.model.log_entries = logging::recent::entries();
+                }
+                return Task::none();
+            }
+
+            // Ctrl+F finds text in PDFs; other documents keep the format panel on it
+            AppMessage::OpenFindBar => {
+                let has_text = self
+                    .document_manager
+                    .current_document()
+                    .is_some_and(|doc| doc.kind() == DocumentKind::Portable);
+                if !has_text {
+                    return self.update(AppMessage::OpenFormatPanel);
+                }
+                self.model.find.get_or_insert_with(FindState::default);
+                return text_input::focus(views::find_bar::input_id());
+            }
+
+            AppMessage::EnterCropGeometry => {
+                if self.document_manager.current_document().is_none() {
+                    return Task::none();
+                }
+                if !matches!(self.model.mode, AppMode::Crop { .. }) {
+                    self.model.mode = AppMode::Crop {
+                        selection: CropSelection::default(),
+                    };
+                }
+                self.context_page = ContextPage::CropGeometry;
+                self.core.window.show_context = true;
+                return text_input::focus(views::crop_geometry_panel::input_id());
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+
+    /// Read the tags of the current image in the background while the
+    /// metadata browser is shown and has none for it.
+    pub(super) fn sync_metadata_browser(&mut self) -> Task<Action<AppMessage>> {
+        if self.context_page != ContextPage::MetadataBrowser || !self.core.window.show_context {
+            return Task::none();
+        }
+        let browser = &mut self.model.metadata_browser;
+        let current = self.document_manager.current_path();
+        if browser.path.as_deref() == current {
+            return Task::none();
+        }
+        browser.path = current.map(Path::to_path_buf);
+        browser.groups = None;
+        let Some(path) = browser.path.clone() else {
+            return Task::none();
+        };
+        Task::perform(
+            tokio::task::spawn_blocking({
+                let path = path.clone();
+                move || metadata_tags::read(&path)
+            }),
+            move |groups| {
+                Action::App(AppMessage::MetadataTagsRead(
+                    path.clone(),
+                    groups.unwrap_or_default(),
+                ))
+            },
+        )
+    }
+
+    /// Fill the metadata editor with the fields of the current image when
+    /// it shows those of another one.
+    pub(super) fn sync_metadata_edit(&mut self) {
+        if self.context_page != ContextPage::EditMetadata || !self.core.window.show_context {
+            return;
+        }
+        let current = self.document_manager.current_path();
+        if self.model.metadata_edit.path.as_deref() != current {
+            self.model.metadata_edit.load(current);
+        }
+    }
+
+    /// Work out the format advice for the current image in the background
+    /// while Save As is shown and has none for it.
+    ///
+    /// The image is sampled here, so only the sample goes to the worker.
+    pub(super) fn sync_format_advice(&mut self) -> Task<Action<AppMessage>> {
+        if self.context_page != ContextPage::SaveAs || !self.core.window.show_context {
+            return Task::none();
+        }
+        let state = &mut self.model.export_profiles;
+        let current = self.document_manager.current_path();
+        if state.advice_path.as_deref() == current {
+            return Task::none();
+        }
+        state.advice_path = current.map(Path::to_path_buf);
+        state.advice = None;
+        let (Some(path), Some(doc)) = (
+            state.advice_path.clone(),
+            self.document_manager.current_document(),
+        ) else {
+            return Task::none();
+        };
+        if !matches!(doc.kind(), DocumentKind::Raster | DocumentKind::Vector) {
+            return Task::none();
+        }
+        let sample = format_advice::Sample::new(doc.rendered_image());
+        let quality = state.quality;
+        Task::perform(
+            tokio::task::spawn_blocking({
+                let path = path.clone();
+                move || sample.advise(animation::is_animated(&path), quality)
+            }),
+            move |advice| match advice {
+                Ok(advice) => Action::App(AppMessage::FormatAdviceReady(path.clone(), advice)),
+                Err(e) => {
+                    tracing::warn!("Format advice failed: {e}");
+                    Action::None
+                }
+            },
+        )
+    }
+}
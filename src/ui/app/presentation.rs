@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/presentation.rs
+//
+// Fullscreen, the screen cover and slideshows.
+
+use std::collections::HashSet;
+use std::hash::{BuildHasher, RandomState};
+use std::path::PathBuf;
+use std::time::Instant;
+
+use cosmic::iced::window;
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::domain::document::operations::labels::LabelFilter;
+use crate::domain::document::operations::slideshow::SlideOrder;
+use crate::fl;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, Presentation, Slideshow};
+use crate::ui::screen_cover;
+
+impl NoctuaApp {
+    /// Enter or leave fullscreen and run slideshows.
+    pub(super) fn update_presentation(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::ToggleFullscreen => {
+                if self.kiosk.is_none() {
+                    match self.model.mode {
+                        AppMode::View => return self.set_fullscreen(true),
+                        AppMode::Fullscreen => return self.set_fullscreen(false),
+                        _ => {}
+                    }
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetSlideInterval(seconds) => {
+                self.config.slide_interval = *seconds;
+                self.save_config();
+                if let Some(presentation) = self.model.presentation.as_mut() {
+                    presentation.restart_countdown(self.config.slide_interval);
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetSlideshowLoop(enabled) => {
+                self.config.slideshow_loop = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetSlideshowShuffle(enabled) => {
+                self.config.slideshow_shuffle = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetSlideshowMinRating(stars) => {
+                self.config.slideshow_min_rating = *stars;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetSlideshowTags(tags) => {
+                self.config.slideshow_tags = tags.clone();
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::ToggleSlideshow => {
+                return self.toggle_slideshow();
+            }
+
+            AppMessage::SlideshowFiltered(matching) => {
+                // Toggled again while the labels were read
+                if self
+                    .model
+                    .presentation
+                    .as_ref()
+                    .is_some_and(|presentation| presentation.slideshow.is_some())
+                {
+                    return Task::none();
+                }
+                return self.start_slideshow(Some(matching));
+            }
+
+            AppMessage::SetCoverOtherScreens(enabled) => {
+                self.config.cover_other_screens = *enabled;
+                self.save_config();
+                if matches!(self.model.mode, AppMode::Fullscreen) {
+                    return self.update_screen_cover(*enabled);
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetHideCursorInFullscreen(enabled) => {
+                self.config.hide_cursor_in_fullscreen = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+
+    /// Enter or leave fullscreen: hides header, footer, nav bar and side
+    /// panel, and covers the other screens if configured. Kiosk windows
+    /// stay fullscreen and covered when a slideshow leaves it.
+    pub(super) fn set_fullscreen(&mut self, fullscreen: bool) -> Task<Action<AppMessage>> {
+        let Some(id) = self.core.main_window_id() else {
+            return Task::none();
+        };
+        self.model.mode = if fullscreen {
+            AppMode::Fullscreen
+        } else {
+            AppMode::View
+        };
+        self.model.presentation = fullscreen.then(|| Presentation::new(Instant::now()));
+        let window_fullscreen = fullscreen || self.kiosk.is_some();
+        self.core.window.show_headerbar = !window_fullscreen;
+        let mode = if window_fullscreen {
+            window::Mode::Fullscreen
+        } else {
+            window::Mode::Windowed
+        };
+        Task::batch([
+            window::change_mode(id, mode),
+            self.update_screen_cover(window_fullscreen && self.config.cover_other_screens),
+        ])
+    }
+
+    /// Start a slideshow through the folder (entering fullscreen if needed),
+    /// or stop the running one and leave the fullscreen it entered.
+    fn toggle_slideshow(&mut self) -> Task<Action<AppMessage>> {
+        if let Some(presentation) = self.model.presentation.as_mut()
+            && let Some(slideshow) = presentation.slideshow.take()
+        {
+            presentation.next_slide = None;
+            if slideshow.entered_fullscreen {
+                return self.set_fullscreen(false);
+            }
+            return Task::none();
+        }
+
+        if !matches!(self.model.mode, AppMode::View | AppMode::Fullscreen) {
+            return Task::none();
+        }
+        let filter = LabelFilter::new(
+            self.config.slideshow_min_rating,
+            &self.config.slideshow_tags,
+        );
+        if filter.is_empty() {
+            return self.start_slideshow(None);
+        }
+        // Reading the labels of every document may take a while
+        let entries = self.document_manager.folder_entries().to_vec();
+        Task::perform(
+            tokio::task::spawn_blocking(move || filter.select(&entries)),
+            |matching| Action::App(AppMessage::SlideshowFiltered(matching.unwrap_or_default())),
+        )
+    }
+
+    /// Start a slideshow through the folder, or through the documents in
+    /// `matching` only, entering fullscreen if needed.
+    fn start_slideshow(&mut self, matching: Option<&[PathBuf]>) -> Task<Action<AppMessage>> {
+        let entered_fullscreen = match self.model.mode {
+            AppMode::View => true,
+            AppMode::Fullscreen => false,
+            _ => return Task::none(),
+        };
+
+        let entries = self.document_manager.folder_entries();
+        let start = self.document_manager.current_index().unwrap_or(0);
+        let mut order = (self.config.slideshow_shuffle || matching.is_some()).then(|| {
+            if self.config.slideshow_shuffle {
+                let seed = RandomState::new().hash_one(Instant::now());
+                SlideOrder::shuffled(entries.len(), start, seed)
+            } else {
+                SlideOrder::sequential(entries.len(), start)
+            }
+        });
+        let mut first = None;
+        if let (Some(order), Some(matching)) = (order.as_mut(), matching) {
+            let matching: HashSet<&PathBuf> = matching.iter().collect();
+            order.retain(|index| {
+                entries
+                    .get(index)
+                    .is_some_and(|path| matching.contains(path))
+            });
+            match order.current() {
+                None => {
+                    self.model.set_error(fl!("slideshow-no-matches"));
+                    return Task::none();
+                }
+                // The current document does not match: begin at the first that does
+                Some(index) if index != start => first = entries.get(index).cloned(),
+                Some(_) => {}
+            }
+        }
+
+        let mut tasks = Vec::with_capacity(2);
+        if entered_fullscreen {
+            tasks.push(self.set_fullscreen(true));
+        }
+        if let Some(presentation) = self.model.presentation.as_mut() {
+            let slideshow = Slideshow {
+                order,
+                entered_fullscreen,
+            };
+            presentation.start_slideshow(slideshow, self.config.slide_interval);
+        }
+        if let Some(path) = first {
+            tasks.push(Task::done(Action::App(AppMessage::OpenPath(path))));
+        }
+        Task::batch(tasks)
+    }
+
+    /// Open or close the black cover over the other screens.
+    fn update_screen_cover(&mut self, covered: bool) -> Task<Action<AppMessage>> {
+        match (covered, self.screen_cover) {
+            (true, None) => {
+                let (cover, task) = screen_cover::open();
+                self.screen_cover = Some(cover);
+                task
+            }
+            (false, Some(cover)) => {
+                self.screen_cover = None;
+                screen_cover::close(cover)
+            }
+            _ => Task::none(),
+        }
+    }
+}
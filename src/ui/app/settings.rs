@@ -0,0 +1,348 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/settings.rs
+//
+// Settings page: folder, SVG, view, canvas and color settings.
+
+use std::path::Path;
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::config::{AppConfig, parse_hex_color};
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::core::svg_text::{SvgTextOptions, set_svg_text_options};
+use crate::infrastructure::color::MonitorProfile;
+use crate::infrastructure::filesystem::ExcludePatterns;
+use crate::infrastructure::logging;
+use crate::ui::message::AppMessage;
+use crate::ui::model::CanvasBackground;
+use crate::ui::update;
+
+impl NoctuaApp {
+    /// Change a setting and save the config.
+    pub(super) fn update_settings(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::ExcludePatternsInput(input) => {
+                self.model.exclude_patterns_input = input.clone();
+                return Task::none();
+            }
+
+            AppMessage::ApplyExcludePatterns => {
+                let patterns = ExcludePatterns::parse_list(&self.model.exclude_patterns_input);
+                self.model.exclude_patterns_input = patterns.join(", ");
+                self.document_manager.set_exclude_patterns(&patterns);
+                self.document_manager.rescan_folder();
+                self.config.nav_exclude_patterns = patterns;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SvgFontsInput(input) => {
+                self.model.svg_fonts_input = input.clone();
+                return Task::none();
+            }
+
+            AppMessage::ApplySvgFonts => {
+                let families = SvgTextOptions::parse_families(&self.model.svg_fonts_input);
+                self.model.svg_fonts_input = families.join(", ");
+                self.config.svg_fallback_fonts = families;
+                self.save_config();
+                self.apply_svg_text(true);
+                return Task::none();
+            }
+
+            AppMessage::SetSvgFontSize(size) => {
+                self.config.svg_font_size = *size;
+                self.save_config();
+                self.apply_svg_text(true);
+                return Task::none();
+            }
+
+            AppMessage::SetSvgTextToPath(enabled) => {
+                self.config.svg_text_to_path = *enabled;
+                self.save_config();
+                // Only copied and saved markup changes
+                self.apply_svg_text(false);
+                return Task::none();
+            }
+
+            AppMessage::SetSortOrder(order) => {
+                self.document_manager.set_sort_order(*order);
+                self.document_manager.rescan_folder();
+                self.config.nav_sort_order = order.key().to_string();
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetColorManagement(enabled) => {
+                self.config.color_management = *enabled;
+                self.save_config();
+                self.model.color.enabled = *enabled;
+                let detect = self.load_monitor_profile();
+                // Zoom renders were converted to the previous profile
+                self.model.viewport.zoom_cache.clear();
+                update::cache_render(&mut self.model, &mut self.document_manager);
+                return detect;
+            }
+
+            AppMessage::SetDefaultViewMode(kind, mode) => {
+                self.config
+                    .view_modes
+                    .insert(kind.name().to_string(), mode.key().to_string());
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetViewMode(mode) => {
+                if let Some(doc) = self.document_manager.current_document() {
+                    self.config
+                        .view_modes
+                        .insert(doc.kind().name().to_string(), mode.key().to_string());
+                    self.save_config();
+                }
+                self.model.viewport.fit_mode = *mode;
+                self.model.viewport.scale = 1.0;
+                self.model.reset_pan();
+                return Task::none();
+            }
+
+            AppMessage::SetLogLevel(subsystem, level) => {
+                self.config
+                    .log_levels
+                    .insert(subsystem.key().to_string(), level.as_str().to_lowercase());
+                self.save_config();
+                logging::set_levels(&self.config.log_levels);
+                return Task::none();
+            }
+
+            AppMessage::SetRescanInterval(seconds) => {
+                self.config.folder_rescan_interval = *seconds;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetLowPowerMode(mode) => {
+                self.config.low_power_mode = mode.key().to_string();
+                self.save_config();
+                update::apply_low_power(&mut self.model, &self.config);
+                return Task::none();
+            }
+
+            AppMessage::SetSidecarOnSave(enabled) => {
+                self.config.edit_sidecar_on_save = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetImportXmpEdits(enabled) => {
+                self.config.import_xmp_edits = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetAutoOrient(enabled) => {
+                self.config.auto_orient = *enabled;
+                self.save_config();
+                self.document_manager.set_auto_orient(*enabled);
+                // Open the current photo again so it shows the new setting
+                if let Some(path) = self.document_manager.current_path() {
+                    return Task::done(Action::App(AppMessage::OpenPath(path.to_path_buf())));
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetConfirmDelete(enabled) => {
+                self.config.confirm_delete = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetCanvasControls(enabled) => {
+                self.config.canvas_controls = *enabled;
+                self.save_config();
+                if !*enabled {
+                    self.model.canvas_controls_until = None;
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetCanvasBackground(background) => {
+                self.config.canvas_background = background.key().to_string();
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::CanvasColorInput(input) => {
+                self.model.canvas_color_input = input.clone();
+                if let Some([red, green, blue]) = parse_hex_color(input) {
+                    self.config.canvas_color = format!("#{red:02x}{green:02x}{blue:02x}");
+                    self.save_config();
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetDocumentBackground(background) => {
+                let Some(path) = self.document_manager.current_path().map(Path::to_path_buf) else {
+                    return Task::none();
+                };
+                match background {
+                    None => self.config.set_document_background(&path, None),
+                    Some(CanvasBackground::Color) => {
+                        // Start from the document's own color, else the configured one
+                        let color = self
+                            .config
+                            .document_background(&path)
+                            .filter(|own| parse_hex_color(own).is_some())
+                            .map_or_else(|| self.config.canvas_color.clone(), String::from);
+                        self.model.document_color_input = Some((path.clone(), color.clone()));
+                        self.config.set_document_background(&path, Some(color));
+                    }
+                    Some(background) => self
+                        .config
+                        .set_document_background(&path, Some(background.key().to_string())),
+                }
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::DocumentColorInput(input) => {
+                let Some(path) = self.document_manager.current_path().map(Path::to_path_buf) else {
+                    return Task::none();
+                };
+                self.model.document_color_input = Some((path.clone(), input.clone()));
+                if let Some([red, green, blue]) = parse_hex_color(input) {
+                    self.config.set_document_background(
+                        &path,
+                        Some(format!("#{red:02x}{green:02x}{blue:02x}")),
+                    );
+                    self.save_config();
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetArrowsTurnPages(enabled) => {
+                self.config.arrows_turn_pages = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetIntegerZoom(enabled) => {
+                self.config.integer_zoom = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SetFitSharpening(enabled) => {
+                self.config.fit_sharpening = *enabled;
+                self.save_config();
+                self.model.viewport.sharpen = *enabled;
+                update::cache_render(&mut self.model, &mut self.document_manager);
+                return Task::none();
+            }
+
+            AppMessage::SetMagnifierZoom(zoom) => {
+                self.config.magnifier_zoom = *zoom;
+                self.save_config();
+                if self.model.viewport.magnifier {
+                    self.model.viewport.scale = *zoom;
+                }
+                return Task::none();
+            }
+
+            AppMessage::SetMagnifierCrosshair(enabled) => {
+                self.config.magnifier_crosshair = *enabled;
+                self.save_config();
+                return Task::none();
+            }
+
+            AppMessage::SelectMonitorProfile(index) => {
+                // Index 0 is "Automatic", installed profiles follow.
+                self.config.monitor_profile = index
+                    .checked_sub(1)
+                    .and_then(|idx| self.model.color.profiles.get(idx))
+                    .cloned();
+                self.save_config();
+                let detect = self.load_monitor_profile();
+                // Zoom renders were converted to the previous profile
+                self.model.viewport.zoom_cache.clear();
+                update::cache_render(&mut self.model, &mut self.document_manager);
+                return detect;
+            }
+
+            AppMessage::MonitorProfileDetected(profile) => {
+                if self
+                    .model
+                    .color
+                    .apply_detected(self.config.monitor_profile.as_deref(), profile.clone())
+                {
+                    self.model.viewport.zoom_cache.clear();
+                    update::cache_render(&mut self.model, &mut self.document_manager);
+                }
+                return Task::none();
+            }
+
+            AppMessage::ToggleRightToLeft => {
+                if let Some(key) = update::reading_order_key(&self.document_manager) {
+                    self.model.right_to_left = !self.model.right_to_left;
+                    self.config
+                        .set_right_to_left(&key, self.model.right_to_left);
+                    self.save_config();
+                }
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+
+    /// Load the selected monitor profile, or detect it if none is selected.
+    fn load_monitor_profile(&mut self) -> Task<Action<AppMessage>> {
+        if self
+            .model
+            .color
+            .load_monitor(self.config.monitor_profile.as_deref())
+        {
+            detect_monitor_profile()
+        } else {
+            Task::none()
+        }
+    }
+
+    /// Hand the SVG text settings to the core; with `rerender` an open SVG
+    /// is drawn again with them, keeping its edits.
+    fn apply_svg_text(&mut self, rerender: bool) {
+        set_svg_text_options(svg_text_options(&self.config));
+        let Some(doc) = self.document_manager.current_document_mut() else {
+            return;
+        };
+        if !rerender || doc.kind() != DocumentKind::Vector {
+            return;
+        }
+        match doc.refresh_svg_text() {
+            Ok(()) => update::cache_render(&mut self.model, &mut self.document_manager),
+            Err(e) => {
+                let message = format!("Rendering the SVG text failed: {e}");
+                self.model.set_error(message);
+            }
+        }
+    }
+}
+
+/// Detect the monitor profile off the UI thread, as colord and X11 may
+/// answer slowly or not at all.
+pub(super) fn detect_monitor_profile() -> Task<Action<AppMessage>> {
+    Task::perform(
+        tokio::task::spawn_blocking(MonitorProfile::detect),
+        |profile| Action::App(AppMessage::MonitorProfileDetected(profile.ok().flatten())),
+    )
+}
+
+/// SVG text options from the settings.
+pub(super) fn svg_text_options(config: &AppConfig) -> SvgTextOptions {
+    SvgTextOptions {
+        fallback_families: config.svg_fallback_fonts.clone(),
+        text_to_path: config.svg_text_to_path,
+        font_size: config.svg_font_size,
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/share.rs
+//
+// Adding and deleting share targets.
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+impl NoctuaApp {
+    /// Add or delete a share target.
+    pub(super) fn update_share(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::AddShareTarget => {
+                let state = &mut self.model.share;
+                let name = state.name_input.trim().to_string();
+                let target = state.target_input.trim().to_string();
+                if !name.is_empty() && !target.is_empty() {
+                    self.config.share_targets.insert(name, target);
+                    self.save_config();
+
+                    let state = &mut self.model.share;
+                    state.name_input.clear();
+                    state.target_input.clear();
+                    state.refresh(&self.config);
+                }
+                return Task::none();
+            }
+
+            AppMessage::DeleteShareTarget(name) => {
+                if self.config.share_targets.remove(name).is_some() {
+                    self.save_config();
+                    self.model.share.refresh(&self.config);
+                }
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+}
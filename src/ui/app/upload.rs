@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/upload.rs
+//
+// Upload progress, the upload endpoint and the upload history.
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::application::services::upload::UploadEvent;
+use crate::fl;
+use crate::infrastructure::system::upload::UploadEndpoint;
+use crate::ui::message::AppMessage;
+
+impl NoctuaApp {
+    /// Follow an upload and edit the upload settings.
+    pub(super) fn update_upload(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::UploadProgress(event) => {
+                let state = &mut self.model.upload;
+                match event {
+                    UploadEvent::Progress(percent) => state.progress = Some(*percent),
+                    UploadEvent::Finished(result) => {
+                        state.progress = None;
+                        let file_name = state.file_name.take().unwrap_or_default();
+                        let cancelled = state
+                            .cancel
+                            .take()
+                            .is_some_and(|token| token.is_cancelled());
+                        match result {
+                            _ if cancelled => state.status = Some(fl!("upload-cancelled")),
+                            Ok(link) => {
+                                state.status = Some(fl!("upload-done"));
+                                self.config.add_upload(file_name, link.clone());
+                                self.save_config();
+                                return cosmic::iced::clipboard::write(link.clone());
+                            }
+                            Err(e) => self.model.set_error(format!("Upload failed: {e}")),
+                        }
+                    }
+                }
+                return Task::none();
+            }
+
+            AppMessage::SaveUploadEndpoint => {
+                let endpoint = self.model.upload.endpoint_input.trim().to_string();
+                if endpoint.is_empty() || UploadEndpoint::parse(&endpoint).is_some() {
+                    self.config.upload_endpoint = endpoint;
+                    self.save_config();
+                }
+                return Task::none();
+            }
+
+            AppMessage::ClearUploadHistory => {
+                self.config.upload_history.clear();
+                self.save_config();
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+}
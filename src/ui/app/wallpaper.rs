@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/wallpaper.rs
+//
+// Setting the image as wallpaper.
+
+use cosmic::{Action, Task};
+
+use super::{ContextPage, NoctuaApp};
+use crate::ui::message::AppMessage;
+use crate::ui::model::AppMode;
+use crate::ui::update;
+
+impl NoctuaApp {
+    /// Frame the image for the wallpaper.
+    pub(super) fn update_wallpaper(&mut self, message: &AppMessage) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::SetAsWallpaper => {
+                if self.document_manager.current_document().is_none() {
+                    self.model.set_error("No image loaded".to_string());
+                    return Task::none();
+                }
+                if let Some(selection) = update::wallpaper_frame(
+                    &self.model,
+                    &self.document_manager,
+                    self.config.wallpaper_ratio,
+                ) {
+                    self.model.mode = AppMode::Crop { selection };
+                }
+                self.context_page = ContextPage::Wallpaper;
+                self.core.window.show_context = true;
+                return Task::none();
+            }
+
+            AppMessage::SetWallpaperRatio(ratio) => {
+                self.config.wallpaper_ratio = *ratio;
+                self.save_config();
+                if matches!(self.model.mode, AppMode::Crop { .. })
+                    && let Some(selection) =
+                        update::wallpaper_frame(&self.model, &self.document_manager, *ratio)
+                {
+                    self.model.mode = AppMode::Crop { selection };
+                }
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+}
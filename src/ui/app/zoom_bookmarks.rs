@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/app/zoom_bookmarks.rs
+//
+// Adding and deleting the zoom bookmarks of a document.
+
+use std::path::Path;
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use crate::config::AppConfig;
+use crate::fl;
+use crate::ui::message::AppMessage;
+
+impl NoctuaApp {
+    /// Add or delete a zoom bookmark.
+    pub(super) fn update_zoom_bookmarks(
+        &mut self,
+        message: &AppMessage,
+    ) -> Task<Action<AppMessage>> {
+        match message {
+            AppMessage::AddZoomBookmark => {
+                let Some(path) = self.document_manager.current_path().map(Path::to_path_buf) else {
+                    return Task::none();
+                };
+                let kept = self.config.zoom_bookmarks_of(&path);
+                // Unnamed bookmarks are numbered, never replacing another one
+                let name = match self.model.zoom_bookmark_name_input.trim() {
+                    "" => (kept.len() + 1..)
+                        .map(|number| fl!("zoom-bookmark-default", number: number))
+                        .find(|name| kept.iter().all(|bookmark| bookmark.0 != *name))
+                        .unwrap_or_default(),
+                    name => name.to_string(),
+                };
+                let viewport = &self.model.viewport;
+                let bookmark = (
+                    name,
+                    self.document_manager.current_page(),
+                    viewport.display_scale(),
+                    (viewport.pan_x, viewport.pan_y),
+                );
+                if self.config.add_zoom_bookmark(&path, bookmark) {
+                    self.model.zoom_bookmark_name_input.clear();
+                    self.save_config();
+                } else {
+                    self.model.set_error(fl!(
+                        "zoom-bookmarks-full",
+                        count: AppConfig::MAX_ZOOM_BOOKMARKS
+                    ));
+                }
+                return Task::none();
+            }
+
+            AppMessage::DeleteZoomBookmark(index) => {
+                if let Some(path) = self.document_manager.current_path().map(Path::to_path_buf) {
+                    self.config.remove_zoom_bookmark(&path, *index);
+                    self.save_config();
+                }
+                return Task::none();
+            }
+
+            _ => {}
+        }
+        Task::none()
+    }
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/clipboard.rs
+//
+// Clipboard contents offered under their own MIME types.
+
+use std::borrow::Cow;
+
+use cosmic::iced::clipboard::mime::AsMimeTypes;
+
+const SVG_MIME_TYPES: [&str; 3] = ["image/svg+xml", "text/plain;charset=utf-8", "text/plain"];
+
+/// SVG markup, pasted as a drawing by editors and browsers and as text
+/// elsewhere.
+#[derive(Debug, Clone)]
+pub struct SvgContents(pub String);
+
+impl AsMimeTypes for SvgContents {
+    fn available(&self) -> Cow<'static, [String]> {
+        Cow::Owned(SVG_MIME_TYPES.iter().map(ToString::to_string).collect())
+    }
+
+    fn as_bytes(&self, mime_type: &str) -> Option<Cow<'static, [u8]>> {
+        SVG_MIME_TYPES
+            .contains(&mime_type)
+            .then(|| Cow::Owned(self.0.as_bytes().to_vec()))
+    }
+}
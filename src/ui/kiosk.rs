@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/kiosk.rs
+//
+// Kiosk mode: allowed actions and the key combination that quits.
+
+use cosmic::iced::keyboard::{Key, Modifiers};
+
+use super::message::AppMessage;
+
+/// Exit combination used when the configured one cannot be read.
+pub const DEFAULT_EXIT_KEYS: &str = "Ctrl+Alt+Q";
+
+/// Key combination such as `Ctrl+Alt+Q` or `Shift+Escape`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KeyCombo {
+    ctrl: bool,
+    alt: bool,
+    shift: bool,
+    logo: bool,
+    /// Character (`q`) or named key (`Escape`, `F12`), compared case-insensitively
+    key: String,
+}
+
+impl KeyCombo {
+    /// Parse `+`-separated modifiers followed by one key.
+    pub fn parse(input: &str) -> Option<Self> {
+        let mut parts: Vec<&str> = input.split('+').map(str::trim).collect();
+        let key = parts.pop().filter(|key| !key.is_empty())?;
+        let mut combo = Self {
+            ctrl: false,
+            alt: false,
+            shift: false,
+            logo: false,
+            key: key.to_string(),
+        };
+        for part in parts {
+            match part.to_lowercase().as_str() {
+                "ctrl" | "control" => combo.ctrl = true,
+                "alt" => combo.alt = true,
+                "shift" => combo.shift = true,
+                "super" | "logo" | "meta" => combo.logo = true,
+                _ => return None,
+            }
+        }
+        Some(combo)
+    }
+
+    /// Whether the pressed key and modifiers are this combination.
+    pub fn matches(&self, key: &Key, modifiers: Modifiers) -> bool {
+        let name = match key {
+            Key::Character(ch) => ch.to_string(),
+            Key::Named(named) => format!("{named:?}"),
+            Key::Unidentified => return false,
+        };
+        name.eq_ignore_ascii_case(&self.key)
+            && modifiers.control() == self.ctrl
+            && modifiers.alt() == self.alt
+            && modifiers.shift() == self.shift
+            && modifiers.logo() == self.logo
+    }
+}
+
+/// Whether a message may run in kiosk mode: viewing, navigation and
+/// slideshows only, nothing that opens dialogs, changes files or writes
+/// settings.
+pub fn allows(message: &AppMessage) -> bool {
+    matches!(
+        message,
+        AppMessage::NextDocument
+            | AppMessage::PrevDocument
+            | AppMessage::DocumentDecoded(_)
+            | AppMessage::DocumentPreviewed(..)
+            | AppMessage::NavigateLeft
+            | AppMessage::NavigateRight
+            | AppMessage::GotoPage(_)
+            | AppMessage::PageLeft
+            | AppMessage::PageRight
+            | AppMessage::TurnPage(_)
+            | AppMessage::ThumbnailReady(..)
+            | AppMessage::ThumbnailsScrolled(_)
+            | AppMessage::RescanFolder
+            | AppMessage::FolderScanned(..)
+            | AppMessage::PowerStateChanged(_)
+            | AppMessage::MonitorProfileDetected(_)
+            | AppMessage::ToggleSlideshow
+            | AppMessage::SlideshowFiltered(_)
+            | AppMessage::PresentationTick(_)
+            | AppMessage::PresentationActivity
+            | AppMessage::ToggleAutoAdvance
+            // Shuffled and filtered slideshows step through the folder by path
+            | AppMessage::OpenPath(_)
+            | AppMessage::ZoomIn
+            | AppMessage::ZoomOut
+            | AppMessage::ZoomReset
+            | AppMessage::ZoomFit
+            | AppMessage::RotateView
+            | AppMessage::ToggleMagnifier
+            | AppMessage::ToggleStripMode
+            | AppMessage::ToggleHalfPageTurn
+            | AppMessage::StripScrolled { .. }
+            | AppMessage::ScrollScreen(_)
+            | AppMessage::ViewerStateChanged { .. }
+            | AppMessage::RenderZoom(_)
+            | AppMessage::ZoomRendered(..)
+            | AppMessage::PageRefined(_)
+            | AppMessage::PanLeft
+            | AppMessage::PanRight
+            | AppMessage::PanUp
+            | AppMessage::PanDown
+            | AppMessage::PanReset
+            | AppMessage::ShowError(_)
+            | AppMessage::ClearError
+            | AppMessage::NoOp
+    )
+}
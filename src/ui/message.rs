@@ -3,8 +3,18 @@
 //
 // Application messages: events, user actions, and internal signals.
 
+use std::ops::Range;
 use std::path::PathBuf;
 
+use crate::application::services::decode_service::DecodeResult;
+use crate::application::services::page_refine::RefineResult;
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::core::document::ImageHandle;
+use crate::domain::document::operations::CropRegion;
+use crate::domain::document::operations::adjust::Adjustment;
+use crate::domain::document::operations::region_stats::RegionStats;
+use crate::domain::document::operations::threshold::Threshold;
+use crate::infrastructure::color::MonitorProfile;
 use crate::ui::widgets::DragHandle;
 
 #[derive(Debug, Clone)]
@@ -12,22 +22,89 @@ pub enum AppMessage {
     // File / navigation.
     #[allow(dead_code)]
     OpenPath(PathBuf),
+    /// Choose a file to open in the portal file chooser.
+    OpenFileDialog,
     NextDocument,
     PrevDocument,
+    /// Image decoded in the background after navigating to it.
+    DocumentDecoded(DecodeResult),
+    /// Thumbnail embedded in an image that is still being decoded.
+    DocumentPreviewed(PathBuf, ImageHandle),
+    NavigateLeft,
+    NavigateRight,
     GotoPage(usize),
-    GenerateThumbnailPage(usize),
+    PageLeft,
+    PageRight,
+    TurnPage(bool),
+    ToggleRightToLeft,
+    TogglePageBookmark,
+    SearchInput(String),
+    RunSearch,
+    /// Look for headings on the pages of a scanned document to outline it.
+    FindHeadings,
+    HeadingsProgress(crate::application::services::heading_outline::OutlineEvent),
+    CancelFindHeadings,
+    /// Thumbnail of a page rendered by the worker for the document at the path.
+    ThumbnailReady(PathBuf, usize, ImageHandle),
+    /// Pages whose thumbnails are scrolled into view, to render first.
+    ThumbnailsScrolled(Range<usize>),
+    RescanFolder,
+    FolderScanned(PathBuf, Option<Vec<PathBuf>>),
+    /// Move the current file to the trash, asking first if so configured.
+    DeleteCurrentFile,
+    ConfirmDelete,
+    CancelDelete,
+    /// Save the files, their views and the compare pair as a session.
+    SaveSession,
+    SaveSessionTo(PathBuf),
+    /// Choose a session file to open.
+    OpenSessionDialog,
+    OpenSession(PathBuf),
+    /// Open the file at this index of the recent files.
+    OpenRecentFile(usize),
+    ClearRecentFiles,
+    /// Thumbnail of a recent file for the start screen.
+    RecentThumbnailReady(PathBuf, ImageHandle),
 
     // Transformations.
     RotateCW,
     RotateCCW,
     FlipHorizontal,
     FlipVertical,
+    ToggleOrientationLock,
+
+    // Edit history.
+    Undo,
+    Redo,
+    RevertHistory(usize),
 
     // View / zoom.
     ZoomIn,
     ZoomOut,
     ZoomReset,
     ZoomFit,
+    TogglePixelArt,
+    RotateView,
+    ToggleAlphaMask,
+    CycleTilePreview,
+    ToggleMagnifier,
+    ToggleFullscreen,
+    ToggleAutoAdvance,
+    ToggleSlideshow,
+    /// Folder documents passing the slideshow filter, read in the background.
+    SlideshowFiltered(Vec<PathBuf>),
+    PresentationTick(std::time::Instant),
+    PresentationActivity,
+    /// Pointer moved or finger touched the canvas: show the canvas controls.
+    CanvasActivity,
+    CanvasControlsTick(std::time::Instant),
+    ToggleStripMode,
+    ToggleHalfPageTurn,
+    StripScrolled {
+        offset: f32,
+        view_size: cosmic::iced::Size,
+    },
+    ScrollScreen(bool),
     ViewerStateChanged {
         scale: f32,
         offset_x: f32,
@@ -35,6 +112,34 @@ pub enum AppMessage {
         canvas_size: cosmic::iced::Size,
         image_size: cosmic::iced::Size,
     },
+    /// Ask the window for its scale factor (it may have moved to another screen).
+    CheckScaleFactor,
+    /// Physical pixels per logical pixel of the window.
+    ScaleFactorChanged(f32),
+    /// Redo the sharpened fit view, unless the canvas was resized again since.
+    RefreshSharpening(u64),
+    /// Render a vector document for the zoom, unless it changed again since.
+    RenderZoom(u64),
+    /// Sharper render of a vector document for a zoom: its scale and pixels.
+    ZoomRendered(u64, Option<(f64, cosmic::widget::image::Handle)>),
+    /// Full render of a large PDF page shown as a quick preview until then.
+    PageRefined(RefineResult),
+
+    // Compare mode.
+    ToggleCompare,
+    /// Put the folder entry at the index into a slot.
+    AssignCompareSlot(super::model::CompareSide, usize),
+    /// Slot B decoded in the background.
+    CompareSlotLoaded(DecodeResult),
+    SwapCompareSlots,
+    SetCompareMode(super::model::CompareMode),
+    SetCompareDivider(f32),
+    /// Zoom or pan in the slot B pane (mirrored to slot A).
+    CompareViewChanged {
+        scale: f32,
+        offset_x: f32,
+        offset_y: f32,
+    },
 
     // Pan control.
     PanLeft,
@@ -51,6 +156,12 @@ pub enum AppMessage {
     StartCrop,
     CancelCrop,
     ApplyCrop,
+    EnterCropGeometry,
+    CropGeometryInput(String),
+    ApplyCropGeometry,
+    /// Compute the statistics of the pixels under the crop selection.
+    AnalyzeSelection,
+    SelectionAnalyzed(CropRegion, Option<RegionStats>),
     CropDragStart {
         x: f32,
         y: f32,
@@ -65,17 +176,72 @@ pub enum AppMessage {
 
     CropDragEnd,
 
+    // Text selection (PDF pages).
+    ToggleTextSelectMode,
+    TextDragStart {
+        x: f32,
+        y: f32,
+    },
+    TextDragMove {
+        x: f32,
+        y: f32,
+        max_x: f32,
+        max_y: f32,
+    },
+    TextDragEnd,
+    CopySelectedText,
+    /// Copy the SVG markup of a vector document, as shown.
+    CopyAsSvg,
+
+    // Find bar (text search on the page).
+    OpenFindBar,
+    CloseFindBar,
+    FindInput(String),
+    FindSubmit,
+    FindNext,
+    FindPrevious,
+    FindShift(bool),
+
     // Panels.
     ToggleContextPage(crate::ui::app::ContextPage),
     ToggleNavBar,
+    SelectLeftPanel(super::model::LeftPanel),
     OpenFormatPanel,
 
     // Menu.
     ToggleMainMenu,
 
+    // Kiosk mode: every key press, checked against the exit combination.
+    KioskKey(
+        cosmic::iced::keyboard::Key,
+        cosmic::iced::keyboard::Modifiers,
+    ),
+
     // Format operations.
     SetPaperFormat(super::model::PaperFormat),
     SetOrientation(super::model::Orientation),
+    ExportDpiInput(String),
+    ExportPagesInput(String),
+
+    // Straighten (fine rotation in Transform mode).
+    SetStraightenAngle(f32),
+    SetStraightenEdges(crate::domain::document::operations::straighten::RotateEdges),
+    SetStraightenFilter(crate::domain::document::operations::straighten::RotateFilter),
+    ToggleStraightenLine,
+    StraightenLineStart {
+        x: f32,
+        y: f32,
+    },
+    StraightenLineMove {
+        x: f32,
+        y: f32,
+    },
+    StraightenLineEnd,
+    /// Clockwise degrees a two-finger gesture turned since it started.
+    RotateGesture(f32),
+    RotateGestureEnd,
+    ApplyStraighten,
+    ResetStraighten,
 
     // Metadata.
     #[allow(dead_code)]
@@ -83,9 +249,275 @@ pub enum AppMessage {
 
     // Save operations.
     SaveAs,
+    SaveAsTo(PathBuf),
+    ConfirmOverwrite,
+    CancelOverwrite,
+    SaveEditSidecar,
+    SetSidecarOnSave(bool),
+    SetImportXmpEdits(bool),
+    SetAutoOrient(bool),
+    SetConfirmDelete(bool),
+    ExportIccProfile,
+    ExportAlphaMask,
+    SaveAlphaMask(PathBuf),
+    SaveIccProfile(PathBuf),
+    /// Copy the shown metadata as plain text, or as JSON with `true`.
+    CopyMetadata(bool),
+    /// Copy the GPS coordinates of the image as "latitude, longitude".
+    CopyGpsCoordinates,
+    /// Show the GPS location of the image in the map application.
+    OpenGpsInMap,
+    /// Tags of an image read for the metadata browser.
+    MetadataTagsRead(
+        PathBuf,
+        Vec<crate::domain::document::operations::metadata_tags::MetadataGroup>,
+    ),
+    MetadataFilterInput(String),
+    /// Copy a value shown in the metadata browser.
+    CopyMetadataValue(String),
+
+    // Metadata editor.
+    MetadataAuthorInput(String),
+    MetadataCopyrightInput(String),
+    MetadataDescriptionInput(String),
+    MetadataDateInput(String),
+    SetMetadataRating(u8),
+    /// Write the edited fields into the file.
+    SaveMetadataEdits,
+    /// Ask where to write the metadata catalog of the folder.
+    ExportCatalog,
+    ExportCatalogTo(PathBuf),
+    CatalogProgress(crate::application::services::folder_catalog::CatalogEvent),
+    CancelCatalog,
+
+    // Share targets.
+    /// Send the edited image to the share target with this menu index.
+    ShareTo(usize),
+    /// Link the share target answered with, or why it failed.
+    ShareFinished(Result<String, String>),
+    ShareNameInput(String),
+    ShareTargetInput(String),
+    AddShareTarget,
+    DeleteShareTarget(String),
+
+    // Uploads.
+    /// Upload the edited image to the configured endpoint.
+    Upload,
+    UploadProgress(crate::application::services::upload::UploadEvent),
+    CancelUpload,
+    UploadEndpointInput(String),
+    SaveUploadEndpoint,
+    /// Copy the link of the upload history entry with this index.
+    CopyUploadLink(usize),
+    /// Open the link of the upload history entry with this index in the browser.
+    OpenUploadLink(usize),
+    ClearUploadHistory,
+
+    // Zoom bookmarks.
+    ZoomBookmarkNameInput(String),
+    /// Bookmark the zoom region shown under the typed name.
+    AddZoomBookmark,
+    /// Show the zoom bookmark with this index (Ctrl+1…9).
+    GoToZoomBookmark(usize),
+    DeleteZoomBookmark(usize),
 
     // Wallpaper.
+    /// Open the wallpaper assistant with a frame of the screen's shape.
     SetAsWallpaper,
+    /// Aspect ratio of the screen (width, height) to frame the wallpaper for.
+    SetWallpaperRatio((u32, u32)),
+    /// Set the part of the image inside the frame as wallpaper.
+    ApplyWallpaper,
+    /// Set the whole file as wallpaper, left to the desktop to fit.
+    SetWholeImageAsWallpaper,
+    /// The cropped wallpaper was written and handed to the desktop, or failed.
+    WallpaperApplied(Result<(), String>),
+
+    // Settings.
+    ExcludePatternsInput(String),
+    ApplyExcludePatterns,
+    SvgFontsInput(String),
+    ApplySvgFonts,
+    SetSvgFontSize(f32),
+    SetSvgTextToPath(bool),
+    SetSortOrder(crate::infrastructure::filesystem::SortOrder),
+    SetColorManagement(bool),
+    SelectMonitorProfile(usize),
+    /// The monitor profile was detected in the background (None = sRGB).
+    MonitorProfileDetected(Option<MonitorProfile>),
+    SetDefaultViewMode(DocumentKind, super::model::ViewMode),
+    /// Fit the current document this way, and documents of its kind from now on.
+    SetViewMode(super::model::ViewMode),
+    SetIntegerZoom(bool),
+    SetFitSharpening(bool),
+    SetArrowsTurnPages(bool),
+    SetCoverOtherScreens(bool),
+    SetHideCursorInFullscreen(bool),
+    SetCanvasControls(bool),
+    SetCanvasBackground(super::model::CanvasBackground),
+    /// Canvas color typed in the settings; applied once it is a valid `#rrggbb`.
+    CanvasColorInput(String),
+    /// Canvas background of the current document alone (None: the configured one).
+    SetDocumentBackground(Option<super::model::CanvasBackground>),
+    /// Canvas color of the current document typed in the settings.
+    DocumentColorInput(String),
+    SetSlideInterval(u32),
+    SetSlideshowLoop(bool),
+    SetSlideshowShuffle(bool),
+    SetSlideshowMinRating(u8),
+    SetSlideshowTags(String),
+    SetRescanInterval(u32),
+    SetLowPowerMode(super::model::LowPowerMode),
+    PowerStateChanged(crate::infrastructure::system::power::PowerState),
+    SetMagnifierZoom(f32),
+    SetMagnifierCrosshair(bool),
+
+    // Date shift tool.
+    DateShiftInput(String),
+    SetDateShiftSidecar(bool),
+    ApplyDateShift,
+    ConfirmDateShift,
+    CancelDateShift,
+    DateShiftProgress(crate::application::services::date_shift::DateShiftEvent),
+
+    // Folder selection (batch operations).
+    SelectFolderImage(PathBuf, bool),
+    SelectAllFolderImages(bool),
+    SetSelectionMinRating(u8),
+    SelectionTagsInput(String),
+    SelectMatchingImages,
+    /// Folder images passing the selection filter, read in the background.
+    MatchingImagesFound(Vec<PathBuf>),
+
+    // Geotag editor.
+    GeotagInput(String),
+    ApplyGeotag,
+    ApplyGeotagToSelected,
+    ClearGeotag,
+    ShowGeotagOnMap,
+    GeotagProgress(crate::application::services::geotag::GeotagEvent),
+
+    // Privacy export.
+    AddPrivacyRegion,
+    RemovePrivacyRegion(usize),
+    ClearPrivacyRegions,
+    ExportPrivacyCopies,
+    PrivacyExportTo(PathBuf),
+    PrivacyExportProgress(crate::application::services::privacy_export::PrivacyExportEvent),
+
+    // Batch conversion.
+    BatchFitInput(String),
+    /// Ask for the folder the selected (or all) folder images are converted into.
+    ConvertImages,
+    /// Queue the conversion into this folder.
+    BatchConvertTo(PathBuf),
+    BatchProgress(crate::application::services::batch_convert::BatchEvent),
+    /// Stop the running job and drop the queued ones.
+    CancelBatch,
+
+    // Edit macros.
+    StartMacroRecording,
+    CancelMacroRecording,
+    MacroStepInput(String),
+    AddMacroStep,
+    RemoveMacroStep(usize),
+    MacroNameInput(String),
+    SaveMacro,
+    DeleteMacro(String),
+    RunMacro(String),
+    RunMacroOnFolder(String),
+    MacroExportTo(PathBuf),
+    MacroProgress(crate::application::services::macro_run::MacroEvent),
+    /// Stop the running macro.
+    CancelMacro,
+
+    // Sprite sheet slicing.
+    SpriteGridInput(String),
+    SetSpriteGridByCount(bool),
+    SetSpriteGridVisible(bool),
+    ExportSpriteCells,
+    SpriteCellsExportTo(PathBuf),
+    ExportSpriteAtlas,
+    SaveSpriteAtlas(PathBuf),
+
+    // Animation playback preview, frames and animated GIF/WebP export.
+    ToggleAnimationPlayback,
+    /// Show the frames due by this time while the preview plays.
+    AnimationTick(std::time::Instant),
+    /// Playback speed, as an index into `PLAYBACK_SPEEDS`.
+    SetAnimationSpeed(usize),
+    SeekAnimation(u32),
+    AnimationStepInput(String),
+    /// Step by the entered number of frames, forward if true.
+    StepAnimation(bool),
+    AnimationFrameInput(String),
+    SaveAnimationFrame,
+    AnimationFrameSaveTo(PathBuf),
+    ExtractAnimationFrames,
+    AnimationFramesExportTo(PathBuf),
+    AnimationFpsInput(String),
+    AnimationLoopsInput(String),
+    /// Export format, as an index into `AnimationFormat::ALL`.
+    SetAnimationFormat(usize),
+    ExportAnimation,
+    AnimationExportTo(PathBuf),
+
+    // Export profiles.
+    SelectExportProfile(usize),
+    ExportProfileNameInput(String),
+    ExportProfileSettingsInput(String),
+    AddExportProfile,
+    DeleteExportProfile(String),
+    SetExportPremultiplied(bool),
+    SelectSaveFormat(usize),
+    SetSaveQuality(u8),
+    /// Format advice worked out for an image.
+    FormatAdviceReady(
+        PathBuf,
+        crate::domain::document::operations::format_advice::FormatAdvice,
+    ),
+    /// Save in the suggested format and quality.
+    UseFormatAdvice,
+    SetStripMetadata(bool),
+
+    // Color adjustments.
+    SetAdjustment(Adjustment, f32),
+    ResetAdjustment(Adjustment),
+    ResetAdjustments,
+    /// Turn the image black and white at a threshold, or back to color.
+    SetThreshold(Option<Threshold>),
+    /// Show the slider values on the image, unless they moved again since.
+    ApplyAdjustments(u64),
+
+    // Resize.
+    SetResizeUnit(super::model::ResizeUnit),
+    ResizeWidthInput(String),
+    ResizeHeightInput(String),
+    SetResizeLockAspect(bool),
+    SetResizePaper(super::model::PaperFormat),
+    SetResizeOrientation(super::model::Orientation),
+    ResizeDpiInput(String),
+    SetResampleFilter(crate::domain::document::operations::resize::ResampleFilter),
+    ApplyResize,
+
+    // Scan cleanup.
+    SetScanCleanup(crate::domain::document::operations::scan_cleanup::ScanCleanup),
+    ApplyScanCleanup,
+
+    // Page export.
+    SetPageExportScope(super::model::PageScope),
+    SetPageExportFormat(crate::domain::document::operations::export::ExportFormat),
+    SetPageExportQuality(u8),
+    PageExportDpiInput(String),
+    ExportPages,
+    ExportPagesTo(PathBuf),
+    PageExportProgress(crate::application::services::page_export::PageExportEvent),
+    CancelPageExport,
+
+    // Log viewer.
+    SetLogLevel(crate::infrastructure::logging::Subsystem, tracing::Level),
+    RefreshLog,
+    CopyLog,
 
     // Errors.
     #[allow(dead_code)]
@@ -93,9 +525,6 @@ pub enum AppMessage {
     #[allow(dead_code)]
     ClearError,
 
-    // UI refresh.
-    RefreshView,
-
     // Fallback.
     #[allow(dead_code)]
     NoOp,
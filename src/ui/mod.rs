@@ -4,8 +4,11 @@
 // UI layer: COSMIC application, views, and widgets.
 
 pub mod app;
+pub mod clipboard;
+pub mod kiosk;
 pub mod message;
 pub mod model;
+pub mod screen_cover;
 pub mod update;
 pub mod components;
 pub mod views;
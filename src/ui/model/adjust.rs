@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/adjust.rs
+//
+// Color adjustments.
+
+use std::time::Duration;
+
+use crate::domain::document::operations::adjust::Adjustments;
+
+/// Color adjustments panel state.
+#[derive(Debug, Clone, Default)]
+pub struct AdjustState {
+    /// Slider values, shown on the image once the sliders rest
+    pub values: Adjustments,
+
+    /// Number of the last slider change (previews of older ones are skipped)
+    pub revision: u64,
+}
+
+impl AdjustState {
+    /// How long a slider has to rest before the image is recomputed.
+    pub const PREVIEW_DELAY: Duration = Duration::from_millis(150);
+}
@@ -0,0 +1,264 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/animation.rs
+//
+// Animation playback and export.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::domain::document::operations::animation::{
+    self, AnimationFormat, AnimationSettings, Timeline,
+};
+
+/// Largest size of the decoded frames kept for the playback preview.
+const MAX_PLAYBACK_BYTES: usize = 512 * 1024 * 1024;
+
+/// Animation panel state: playback preview, frame extraction and animated
+/// GIF export.
+#[derive(Debug, Clone)]
+pub struct AnimationState {
+    /// Frame count of the current image (None if not read or not animated)
+    pub frame_count: Option<usize>,
+
+    /// Playback preview of the current image (None if not animated or too
+    /// large to keep all frames)
+    pub player: Option<AnimationPlayer>,
+
+    /// Labels of the playback speeds, for the dropdown
+    pub speed_labels: Vec<String>,
+
+    /// Number of frames to step by, typed in the panel
+    pub step_input: String,
+
+    /// Frame number to save, starting at 1
+    pub frame_input: String,
+
+    /// Frames per second typed in the panel
+    pub fps_input: String,
+
+    /// Loop count typed in the panel (0 = forever)
+    pub loops_input: String,
+
+    /// Format of the export
+    pub format: AnimationFormat,
+
+    /// Labels of the export formats, for the dropdown
+    pub format_labels: Vec<String>,
+
+    /// Result of the last export
+    pub status: Option<String>,
+}
+
+impl Default for AnimationState {
+    fn default() -> Self {
+        let settings = AnimationSettings::default();
+        Self {
+            frame_count: None,
+            player: None,
+            speed_labels: animation::PLAYBACK_SPEEDS
+                .iter()
+                .map(|speed| format!("{speed}×"))
+                .collect(),
+            step_input: "1".to_string(),
+            frame_input: "1".to_string(),
+            fps_input: settings.fps.to_string(),
+            loops_input: settings.loops.to_string(),
+            format: AnimationFormat::default(),
+            format_labels: AnimationFormat::ALL
+                .iter()
+                .map(|format| format.extension().to_uppercase())
+                .collect(),
+            status: None,
+        }
+    }
+}
+
+impl AnimationState {
+    /// Read the frames of the current image when the panel opens.
+    pub fn load(&mut self, current: Option<&Path>) {
+        let frames = current
+            .and_then(|path| animation::decode_timed_frames(path).ok())
+            .filter(|frames| frames.len() > 1);
+        self.frame_count = frames.as_ref().map(Vec::len);
+        self.player = current
+            .zip(frames)
+            .and_then(|(path, frames)| AnimationPlayer::new(path, frames));
+        self.status = None;
+    }
+
+    /// Player of the animation shown as `current`, if its preview is open.
+    pub fn player_of(&self, current: Option<&Path>) -> Option<&AnimationPlayer> {
+        self.player
+            .as_ref()
+            .filter(|player| current == Some(player.path.as_path()))
+    }
+
+    /// Entered number of frames to step by (None if invalid).
+    pub fn step(&self) -> Option<usize> {
+        self.step_input
+            .trim()
+            .parse()
+            .ok()
+            .filter(|&frames| frames > 0)
+    }
+
+    /// Entered frame as an index (None if invalid or out of range).
+    pub fn frame_index(&self) -> Option<usize> {
+        let number = self.frame_input.trim().parse::<usize>().ok()?;
+        (number >= 1 && number <= self.frame_count?).then(|| number - 1)
+    }
+
+    /// Entered playback settings (None if invalid).
+    pub fn settings(&self) -> Option<AnimationSettings> {
+        let fps = self.fps_input.trim().parse::<f32>().ok()?;
+        let loops = self.loops_input.trim().parse::<u16>().ok()?;
+        (fps > 0.0 && fps <= 100.0).then_some(AnimationSettings { fps, loops })
+    }
+
+    /// Frames for the export: the current GIF, or the numbered sequence the
+    /// current image belongs to (empty if there is none).
+    pub fn sources(entries: &[PathBuf], current: Option<&Path>) -> Vec<PathBuf> {
+        match current {
+            Some(path) if animation::is_gif(path) => vec![path.to_path_buf()],
+            Some(path) => animation::numbered_sequence(entries, path),
+            None => Vec::new(),
+        }
+    }
+}
+
+/// Playback preview of an animated image: its frames shown in place of the
+/// document, at an adjustable speed.
+#[derive(Debug, Clone)]
+pub struct AnimationPlayer {
+    /// Animated file the frames were read from
+    pub path: PathBuf,
+
+    /// Frames as shown during playback
+    pub frames: Vec<cosmic::widget::image::Handle>,
+
+    /// Display times of the frames
+    pub timeline: Timeline,
+
+    /// Index of the frame shown
+    pub current: usize,
+
+    /// Index into `animation::PLAYBACK_SPEEDS`
+    pub speed: usize,
+
+    /// When the next frame is due (None while paused)
+    pub next_frame_at: Option<Instant>,
+}
+
+impl AnimationPlayer {
+    /// Player of the decoded `frames` of `path`, paused on the first one.
+    ///
+    /// None if the frames are too large to keep for playback.
+    pub fn new(path: &Path, frames: Vec<(image::DynamicImage, Duration)>) -> Option<Self> {
+        let bytes: usize = frames
+            .iter()
+            .map(|(frame, _)| frame.width() as usize * frame.height() as usize * 4)
+            .sum();
+        if bytes > MAX_PLAYBACK_BYTES {
+            return None;
+        }
+        let timeline = Timeline::new(frames.iter().map(|(_, delay)| *delay));
+        let frames = frames
+            .into_iter()
+            .map(|(frame, _)| {
+                let rgba = frame.into_rgba8();
+                cosmic::widget::image::Handle::from_rgba(
+                    rgba.width(),
+                    rgba.height(),
+                    rgba.into_raw(),
+                )
+            })
+            .collect();
+        Some(Self {
+            path: path.to_path_buf(),
+            frames,
+            timeline,
+            current: 0,
+            speed: animation::PLAYBACK_SPEEDS
+                .iter()
+                .position(|&speed| speed == 1.0)
+                .unwrap_or(0),
+            next_frame_at: None,
+        })
+    }
+
+    /// Whether the animation is playing.
+    pub fn is_playing(&self) -> bool {
+        self.next_frame_at.is_some()
+    }
+
+    /// Playback speed factor.
+    pub fn speed(&self) -> f32 {
+        animation::PLAYBACK_SPEEDS
+            .get(self.speed)
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// Frame shown.
+    pub fn frame(&self) -> Option<&cosmic::widget::image::Handle> {
+        self.frames.get(self.current)
+    }
+
+    /// Pause playback on the frame shown.
+    pub fn pause(&mut self) {
+        self.next_frame_at = None;
+    }
+
+    /// Start or pause playback.
+    pub fn toggle(&mut self, now: Instant) {
+        self.next_frame_at = match self.next_frame_at {
+            Some(_) => None,
+            None => Some(now + self.timeline.delay(self.current, self.speed())),
+        };
+    }
+
+    /// Play at the speed at `index` of `animation::PLAYBACK_SPEEDS`; the
+    /// frame shown keeps its remaining time, rescaled.
+    pub fn set_speed(&mut self, index: usize, now: Instant) {
+        let previous = self.speed();
+        self.speed = index.min(animation::PLAYBACK_SPEEDS.len() - 1);
+        if let Some(at) = self.next_frame_at {
+            let remaining = at
+                .saturating_duration_since(now)
+                .mul_f32(previous / self.speed());
+            self.next_frame_at = Some(now + remaining);
+        }
+    }
+
+    /// Show frame `index`, pausing playback.
+    pub fn seek(&mut self, index: usize) {
+        self.current = index.min(self.frames.len().saturating_sub(1));
+        self.next_frame_at = None;
+    }
+
+    /// Show the frame `frames` away (back if negative), wrapping around and
+    /// pausing playback.
+    pub fn step(&mut self, frames: isize) {
+        self.seek(self.timeline.step(self.current, frames));
+    }
+
+    /// Advance to the frame due at `now` while playing.
+    pub fn tick(&mut self, now: Instant) {
+        let Some(mut at) = self.next_frame_at else {
+            return;
+        };
+        // Frames whose time passed between ticks are skipped, up to a full
+        // pass; after a longer stall the timing starts over
+        for _ in 0..self.frames.len() {
+            if now < at {
+                break;
+            }
+            self.current = self.timeline.step(self.current, 1);
+            at += self.timeline.delay(self.current, self.speed());
+        }
+        if now >= at {
+            at = now + self.timeline.delay(self.current, self.speed());
+        }
+        self.next_frame_at = Some(at);
+    }
+}
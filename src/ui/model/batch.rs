@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/batch.rs
+//
+// Batch conversion.
+
+use std::path::PathBuf;
+
+use crate::application::services::batch_convert::JobQueue;
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::operations::export_profile;
+
+/// Batch conversion of folder images (batch conversion panel).
+#[derive(Debug, Clone, Default)]
+pub struct BatchState {
+    /// Jobs waiting for the running one
+    pub queue: JobQueue,
+
+    /// Images handled and their total while a job runs
+    pub progress: Option<(usize, usize)>,
+
+    /// Stops the running job
+    pub cancel: Option<CancelToken>,
+
+    /// Bounding box typed for converting without a profile (empty = keep the size)
+    pub fit_input: String,
+
+    /// Images written since the queue was started
+    pub written: usize,
+
+    /// Images that could not be converted since the queue was started, and why
+    pub failures: Vec<(PathBuf, String)>,
+
+    /// Result of the last run of the queue
+    pub status: Option<String>,
+}
+
+impl BatchState {
+    /// Check if a job is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    /// Bounding box typed (None if empty), or the invalid input.
+    pub fn fit(&self) -> Result<Option<(u32, u32)>, &str> {
+        let input = self.fit_input.trim();
+        if input.is_empty() {
+            return Ok(None);
+        }
+        export_profile::parse_size(input).map(Some).ok_or(input)
+    }
+}
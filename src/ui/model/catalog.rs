@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/catalog.rs
+//
+// Catalog export.
+
+use crate::domain::document::core::cancel::CancelToken;
+
+/// Folder catalog export state (properties panel).
+#[derive(Debug, Clone, Default)]
+pub struct CatalogState {
+    /// Files read and their total while an export runs
+    pub progress: Option<(usize, usize)>,
+
+    /// Stops the running export
+    pub cancel: Option<CancelToken>,
+
+    /// Result of the last export
+    pub status: Option<String>,
+}
+
+impl CatalogState {
+    /// Check if an export is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+}
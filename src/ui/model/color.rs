@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/color.rs
+//
+// Color management state.
+
+use std::path::{Path, PathBuf};
+
+use crate::config::AppConfig;
+use crate::fl;
+#[cfg(feature = "color")]
+use crate::infrastructure::color::CachedTransform;
+use crate::infrastructure::color::{MonitorProfile, monitor_profile};
+
+/// Display color management state.
+#[derive(Debug, Clone, Default)]
+pub struct ColorState {
+    /// Convert displayed colors to the monitor profile
+    pub enabled: bool,
+
+    /// Active monitor profile (None = sRGB output)
+    pub monitor: Option<MonitorProfile>,
+
+    /// Installed ICC profiles offered in settings
+    pub profiles: Vec<PathBuf>,
+
+    /// Profile picker labels ("Automatic" followed by installed profiles)
+    pub profile_labels: Vec<String>,
+
+    /// Transform for the last document and monitor profile pair
+    #[cfg(feature = "color")]
+    pub transform: CachedTransform,
+}
+
+impl ColorState {
+    /// Build color state from config and the installed profiles.
+    pub fn new(config: &AppConfig) -> Self {
+        let profiles = monitor_profile::available_profiles();
+        let profile_labels = std::iter::once(fl!("settings-monitor-profile-auto"))
+            .chain(profiles.iter().map(|p| monitor_profile::profile_label(p)))
+            .collect();
+
+        Self {
+            enabled: config.color_management,
+            monitor: None,
+            profiles,
+            profile_labels,
+            ..Self::default()
+        }
+    }
+
+    /// Load the selected monitor profile.
+    ///
+    /// Returns true if none is selected: the profile of the monitor is then
+    /// to be detected off the UI thread and applied with
+    /// [`apply_detected`](Self::apply_detected); output is sRGB meanwhile.
+    #[must_use]
+    pub fn load_monitor(&mut self, selected: Option<&Path>) -> bool {
+        self.monitor = None;
+        if !self.enabled {
+            return false;
+        }
+        match selected {
+            Some(path) => {
+                self.monitor = MonitorProfile::load(path);
+                false
+            }
+            None => true,
+        }
+    }
+
+    /// Use a detected monitor profile, unless color management was turned
+    /// off or a profile selected while detecting. Returns whether it is used.
+    pub fn apply_detected(
+        &mut self,
+        selected: Option<&Path>,
+        detected: Option<MonitorProfile>,
+    ) -> bool {
+        if !self.enabled || selected.is_some() || self.monitor == detected {
+            return false;
+        }
+        self.monitor = detected;
+        true
+    }
+
+    /// Index of the selected profile in `profile_labels` (0 = automatic).
+    pub fn selected_index(&self, selected: Option<&Path>) -> usize {
+        selected
+            .and_then(|path| self.profiles.iter().position(|p| p == path))
+            .map_or(0, |idx| idx + 1)
+    }
+
+    /// Convert display pixels from the `source` profile (sRGB if None) to the
+    /// monitor profile. Returns false if no transform could be created.
+    #[cfg(feature = "color")]
+    pub fn convert(&mut self, source: Option<&[u8]>, pixels: &mut image::RgbaImage) -> bool {
+        let monitor = self.monitor.as_ref().map(|p| p.data.as_slice());
+        match self.transform.get(source, monitor) {
+            Ok(transform) => {
+                transform.apply(pixels);
+                true
+            }
+            Err(e) => {
+                tracing::warn!("Failed to create display color transform: {e}");
+                false
+            }
+        }
+    }
+
+    #[cfg(not(feature = "color"))]
+    pub fn convert(&mut self, _source: Option<&[u8]>, _pixels: &mut image::RgbaImage) -> bool {
+        false
+    }
+}
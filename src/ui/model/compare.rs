@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/compare.rs
+//
+// Canvas layout and compare mode.
+
+use std::path::{Path, PathBuf};
+
+/// How the canvas shows documents.
+#[derive(Debug, Clone, Default)]
+pub enum ViewLayout {
+    /// The current document alone
+    #[default]
+    Single,
+    /// The current document next to a second one
+    Compare(Comparison),
+}
+
+/// Arrangement of the two documents in compare mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompareMode {
+    /// Two panes next to each other
+    #[default]
+    SideBySide,
+    /// One pane, A left and B right of a draggable divider
+    Swipe,
+}
+
+/// Slot of a document in compare mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompareSide {
+    A,
+    B,
+}
+
+/// Compare mode: slot A is the current document (with its unsaved edits),
+/// slot B a file of the folder as saved. Both share zoom and pan.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    /// File in slot B
+    pub b_path: PathBuf,
+
+    /// Display handle of slot B (None while it decodes)
+    pub b_handle: Option<cosmic::widget::image::Handle>,
+
+    /// Side by side or swipe
+    pub mode: CompareMode,
+
+    /// Swipe divider position as a fraction of the canvas width
+    pub divider: f32,
+
+    /// Dropdown labels: the file names of the folder entries
+    pub names: Vec<String>,
+}
+
+impl Comparison {
+    /// Compare the current document with `b_path`, which still has to be decoded.
+    pub fn new(b_path: PathBuf, entries: &[PathBuf]) -> Self {
+        Self {
+            b_path,
+            b_handle: None,
+            mode: CompareMode::default(),
+            divider: 0.5,
+            names: Self::labels(entries),
+        }
+    }
+
+    /// File names of `entries`, for the slot dropdowns.
+    pub fn labels(entries: &[PathBuf]) -> Vec<String> {
+        entries
+            .iter()
+            .map(|path| {
+                path.file_name()
+                    .unwrap_or(path.as_os_str())
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect()
+    }
+
+    /// File to put in slot B when compare mode starts: the current file
+    /// itself if it has unsaved changes (before and after), otherwise the
+    /// next file of the folder.
+    pub fn default_b(entries: &[PathBuf], current: &Path, edited: bool) -> PathBuf {
+        let next = entries
+            .iter()
+            .position(|path| path == current)
+            .and_then(|index| entries.get((index + 1) % entries.len()));
+        match next {
+            Some(path) if !edited => path.clone(),
+            _ => current.to_path_buf(),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/crop.rs
+//
+// Statistics of the crop selection.
+
+use crate::domain::document::operations::CropRegion;
+use crate::domain::document::operations::region_stats::RegionStats;
+
+/// Statistics of the pixels under the crop selection (properties panel).
+#[derive(Debug, Clone)]
+pub struct SelectionStats {
+    /// Image region the statistics were computed for
+    pub region: CropRegion,
+
+    pub stats: RegionStats,
+
+    /// Luminance histogram drawn for the panel
+    pub histogram: cosmic::widget::image::Handle,
+}
+
+impl SelectionStats {
+    /// Size of the drawn histogram in pixels.
+    pub const HISTOGRAM_SIZE: (u32, u32) = (256, 64);
+
+    pub fn new(region: CropRegion, stats: RegionStats) -> Self {
+        let (width, height) = Self::HISTOGRAM_SIZE;
+        let histogram = cosmic::widget::image::Handle::from_rgba(
+            width,
+            height,
+            stats.histogram_image(width, height).into_raw(),
+        );
+        Self {
+            region,
+            stats,
+            histogram,
+        }
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/date_shift.rs
+//
+// Date shift tool.
+
+use std::path::PathBuf;
+
+use crate::domain::document::operations::date_shift::{self, DateShiftEntry};
+
+/// Batch EXIF date shift tool state.
+#[derive(Debug, Clone, Default)]
+pub struct DateShiftState {
+    /// Offset text being edited (e.g. "-1d 02:30")
+    pub offset_input: String,
+
+    /// Write XMP sidecars instead of modifying the images
+    pub sidecar: bool,
+
+    /// Dated files of the current folder (read when the tool opens)
+    pub entries: Vec<DateShiftEntry>,
+
+    /// Result of the last apply
+    pub status: Option<String>,
+
+    /// Asking whether to rewrite the files
+    pub confirm: bool,
+
+    /// Files handled of the total while the shift runs
+    pub progress: Option<(usize, usize)>,
+}
+
+impl DateShiftState {
+    /// Read the capture times of the given files for the preview.
+    pub fn load(&mut self, paths: &[PathBuf]) {
+        self.entries = date_shift::plan_date_shift(paths);
+        self.status = None;
+    }
+
+    /// Parsed offset in seconds (None if empty or invalid).
+    pub fn offset(&self) -> Option<i64> {
+        date_shift::parse_offset(&self.offset_input)
+    }
+
+    /// Check if a shift is being written.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+}
@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/export_profile.rs
+//
+// Export profiles.
+
+use std::path::PathBuf;
+
+use crate::config::AppConfig;
+use crate::domain::document::operations::export::{ExportFormat, ImageExportOptions};
+use crate::domain::document::operations::export_profile::ExportProfile;
+use crate::domain::document::operations::format_advice::FormatAdvice;
+use crate::fl;
+
+/// Export profile selection (Save As and batch tools) and editing state.
+#[derive(Debug, Clone, Default)]
+pub struct ExportProfileState {
+    /// Selected profile name (`None` = original format and size)
+    pub selected: Option<String>,
+
+    /// Picker labels ("Original" followed by the profile names)
+    pub labels: Vec<String>,
+
+    /// Name typed in the settings panel
+    pub name_input: String,
+
+    /// Settings typed in the settings panel
+    pub settings_input: String,
+
+    /// Write premultiplied alpha when saving without a profile
+    pub premultiplied: bool,
+
+    /// Format when saving without a profile (`None` = that of the original)
+    pub format: Option<ExportFormat>,
+
+    /// Format picker labels ("Original" followed by `ExportFormat::SAVE_FORMATS`)
+    pub format_labels: Vec<String>,
+
+    /// JPEG quality when saving without a profile (1-100)
+    pub quality: u8,
+
+    /// Path chosen in the save dialog, waiting for confirmation to replace
+    /// the existing file it is written to
+    pub overwrite: Option<PathBuf>,
+
+    /// Image the format advice is for (None: not worked out yet)
+    pub advice_path: Option<PathBuf>,
+
+    /// Suggested format and size estimates (None while they are worked out)
+    pub advice: Option<FormatAdvice>,
+
+    /// Leave all metadata out of the saved file
+    pub strip_metadata: bool,
+}
+
+impl ExportProfileState {
+    pub fn new(config: &AppConfig) -> Self {
+        let mut state = Self {
+            format_labels: std::iter::once(fl!("export-profile-none"))
+                .chain(
+                    ExportFormat::SAVE_FORMATS
+                        .iter()
+                        .map(|format| format.extension().to_uppercase()),
+                )
+                .collect(),
+            quality: ImageExportOptions::default().quality,
+            ..Self::default()
+        };
+        state.refresh(config);
+        state
+    }
+
+    /// Index of the save format in `format_labels` (0 = original).
+    pub fn format_index(&self) -> usize {
+        self.format
+            .and_then(|format| ExportFormat::SAVE_FORMATS.iter().position(|f| *f == format))
+            .map_or(0, |index| index + 1)
+    }
+
+    /// Rebuild the picker labels after the configured profiles changed.
+    pub fn refresh(&mut self, config: &AppConfig) {
+        self.labels = std::iter::once(fl!("export-profile-none"))
+            .chain(config.export_profiles.keys().cloned())
+            .collect();
+        if self
+            .selected
+            .as_ref()
+            .is_some_and(|name| !config.export_profiles.contains_key(name))
+        {
+            self.selected = None;
+        }
+    }
+
+    /// Index of the selected profile in `labels` (0 = none).
+    pub fn selected_index(&self) -> usize {
+        self.selected
+            .as_ref()
+            .and_then(|name| self.labels.iter().skip(1).position(|label| label == name))
+            .map_or(0, |index| index + 1)
+    }
+
+    /// Forget the format advice, so it is worked out again.
+    pub fn clear_advice(&mut self) {
+        self.advice_path = None;
+        self.advice = None;
+    }
+
+    /// The selected profile (None if none is selected or its settings are invalid).
+    pub fn profile(&self, config: &AppConfig) -> Option<ExportProfile> {
+        let settings = config.export_profiles.get(self.selected.as_ref()?)?;
+        ExportProfile::parse(settings)
+            .inspect_err(|setting| tracing::warn!("Invalid export profile setting: {setting}"))
+            .ok()
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/geotag.rs
+//
+// Geotag editor.
+
+use std::path::PathBuf;
+
+use crate::domain::document::operations::geotag::GpsCoordinate;
+
+/// GPS location editor state.
+#[derive(Debug, Clone, Default)]
+pub struct GeotagState {
+    /// Location text being edited (coordinates or map link)
+    pub input: String,
+
+    /// Files handled and their total while a write runs
+    pub progress: Option<(usize, usize)>,
+
+    /// Files the last write could not update, and why
+    pub failures: Vec<(PathBuf, String)>,
+
+    /// Result of the last write
+    pub status: Option<String>,
+}
+
+impl GeotagState {
+    /// Prefill the editor with the location of the current image.
+    pub fn load(&mut self, current: Option<GpsCoordinate>) {
+        self.input = current.map(|c| c.display()).unwrap_or_default();
+        self.status = None;
+    }
+
+    /// Check if a write is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    /// Parsed location (None if empty or invalid).
+    pub fn location(&self) -> Option<GpsCoordinate> {
+        GpsCoordinate::parse(&self.input)
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/macros.rs
+//
+// Edit macros.
+
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::operations::edit_macro::MacroStep;
+
+/// Edit macro recording and playback state.
+#[derive(Debug, Clone, Default)]
+pub struct MacroState {
+    /// Steps recorded so far (`None` when not recording)
+    pub recording: Option<Vec<MacroStep>>,
+
+    /// Step typed into the "add step" field (e.g. `resize 1920w`)
+    pub step_input: String,
+
+    /// Name for saving the recording
+    pub name_input: String,
+
+    /// Macro waiting for an output folder: (name, run on the whole folder)
+    pub pending: Option<(String, bool)>,
+
+    /// Name of the running macro
+    pub running: Option<String>,
+
+    /// Images handled and their total while a macro runs
+    pub progress: Option<(usize, usize)>,
+
+    /// Stops the running macro
+    pub cancel: Option<CancelToken>,
+
+    /// Result of the last run
+    pub status: Option<String>,
+}
+
+impl MacroState {
+    /// Check if a macro is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+}
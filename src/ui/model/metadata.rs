@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/metadata.rs
+//
+// Metadata editor and browser.
+
+use std::path::{Path, PathBuf};
+
+use crate::domain::document::operations::date_shift::ExifDateTime;
+use crate::domain::document::operations::metadata_edit::MetadataFields;
+use crate::domain::document::operations::metadata_tags::{self, MetadataGroup};
+
+/// Metadata editor state: the descriptive fields of the current image.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataEditState {
+    /// Image the fields were read from
+    pub path: Option<PathBuf>,
+
+    pub author: String,
+    pub copyright: String,
+    pub description: String,
+
+    /// Stars from 0 to 5
+    pub rating: u8,
+
+    /// Capture date being edited (`YYYY:MM:DD HH:MM:SS`, empty = none)
+    pub date_input: String,
+
+    /// Result of the last write
+    pub status: Option<String>,
+}
+
+impl MetadataEditState {
+    /// Fill the editor with the fields stored in `path`.
+    pub fn load(&mut self, path: Option<&Path>) {
+        let fields = path.map(MetadataFields::read).unwrap_or_default();
+        *self = Self {
+            path: path.map(Path::to_path_buf),
+            author: fields.author,
+            copyright: fields.copyright,
+            description: fields.description,
+            rating: fields.rating,
+            date_input: fields
+                .capture_date
+                .map(|date| date.to_string())
+                .unwrap_or_default(),
+            status: None,
+        };
+    }
+
+    /// Fields to write (None while the capture date is invalid).
+    pub fn fields(&self) -> Option<MetadataFields> {
+        let date = self.date_input.trim();
+        let capture_date = if date.is_empty() {
+            None
+        } else {
+            Some(ExifDateTime::parse(date)?)
+        };
+        Some(MetadataFields {
+            author: self.author.clone(),
+            copyright: self.copyright.clone(),
+            description: self.description.clone(),
+            rating: self.rating,
+            capture_date,
+        })
+    }
+}
+
+/// Metadata browser state: every tag of the current image, read once the
+/// browser shows it.
+#[derive(Debug, Clone, Default)]
+pub struct MetadataBrowserState {
+    /// Image the tags belong to (None: not read yet)
+    pub path: Option<PathBuf>,
+
+    /// Tags by group (None while they are read)
+    pub groups: Option<Vec<MetadataGroup>>,
+
+    /// Search text
+    pub filter_input: String,
+}
+
+impl MetadataBrowserState {
+    /// Forget the tags, so they are read again.
+    pub fn clear(&mut self) {
+        self.path = None;
+        self.groups = None;
+    }
+
+    /// Groups with the tags matching the search text.
+    pub fn visible(&self) -> Vec<MetadataGroup> {
+        metadata_tags::filter(
+            self.groups.as_deref().unwrap_or_default(),
+            &self.filter_input,
+        )
+    }
+}
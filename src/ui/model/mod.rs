@@ -0,0 +1,476 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/mod.rs
+//
+// UI state (view, tools, panels).
+//
+// AppModel contains ONLY UI-specific state.
+// Document state lives in DocumentManager (application layer).
+
+mod adjust;
+mod animation;
+mod batch;
+mod catalog;
+mod color;
+mod compare;
+mod crop;
+mod date_shift;
+mod export_profile;
+mod geotag;
+mod macros;
+mod metadata;
+mod mode;
+mod page_export;
+mod panel;
+mod presentation;
+mod privacy;
+mod resize;
+mod search;
+mod selection;
+mod share;
+mod sprite_sheet;
+mod strip;
+mod transform;
+mod upload;
+mod view;
+mod viewport;
+mod wallpaper;
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use crate::application::edit_history::EditHistory;
+use crate::application::session::DocumentView;
+use crate::config::AppConfig;
+use crate::domain::document::core::document::TransformState;
+use crate::domain::document::core::metadata::Resolution;
+use crate::domain::document::operations::labels;
+use crate::domain::document::operations::resize::ResampleFilter;
+use crate::domain::document::operations::scan_cleanup::ScanCleanup;
+use crate::fl;
+use crate::infrastructure::filesystem::SortOrder;
+use crate::infrastructure::logging::{self, LogEntry};
+use crate::infrastructure::system::power::PowerState;
+
+pub use adjust::AdjustState;
+pub use animation::{AnimationPlayer, AnimationState};
+pub use batch::BatchState;
+pub use catalog::CatalogState;
+pub use color::ColorState;
+pub use compare::{CompareMode, CompareSide, Comparison, ViewLayout};
+pub use crop::SelectionStats;
+pub use date_shift::DateShiftState;
+pub use export_profile::ExportProfileState;
+pub use geotag::GeotagState;
+pub use macros::MacroState;
+pub use metadata::{MetadataBrowserState, MetadataEditState};
+pub use mode::AppMode;
+pub use page_export::{PageExportState, PageScope};
+pub use panel::{LeftPanel, PanelState, RightPanel};
+pub use presentation::{Presentation, Slideshow};
+pub use privacy::PrivacyState;
+pub use resize::{ResizeState, ResizeUnit};
+pub use search::{FindState, HeadingOutlineState, SearchState};
+pub use selection::FolderSelection;
+pub use share::ShareState;
+pub use sprite_sheet::SpriteSheetState;
+pub use strip::StripState;
+pub use transform::{Orientation, PaperFormat, RotateGesture, StraightenTool};
+pub use upload::UploadState;
+pub use view::{CanvasBackground, LowPowerMode, ViewMode};
+pub use viewport::{HalfPage, TilePreview, Viewport};
+pub use wallpaper::WALLPAPER_RATIOS;
+
+// =============================================================================
+// AppModel (UI State Only)
+// =============================================================================
+
+/// UI state for the application.
+///
+/// Contains ONLY UI-specific state:
+/// - Current mode (view/tool)
+/// - Viewport (zoom/pan)
+/// - Panel visibility
+/// - Transient UI state (errors, menu)
+///
+/// Document state (current file, metadata, etc.) lives in DocumentManager!
+pub struct AppModel {
+    /// Current application mode
+    pub mode: AppMode,
+
+    /// Viewport state
+    pub viewport: Viewport,
+
+    /// Panel visibility
+    pub panels: PanelState,
+
+    /// Error message to display
+    pub error: Option<String>,
+
+    /// Is main menu open?
+    pub menu_open: bool,
+
+    /// Exclude patterns text being edited in settings (comma-separated)
+    pub exclude_patterns_input: String,
+
+    /// SVG fallback fonts being edited in settings (comma-separated)
+    pub svg_fonts_input: String,
+
+    /// Dropdown labels for `AppModel::SVG_FONT_SIZES` (settings panel)
+    pub svg_font_size_labels: Vec<String>,
+
+    /// Orientation lock: rotation and flips applied to every newly opened
+    /// document (session only, `None` when unlocked)
+    pub orientation_lock: Option<TransformState>,
+
+    /// Edits applied to the current document (undo/redo, history panel)
+    pub history: EditHistory,
+
+    /// XMP sidecar of another tool whose edits were applied on open
+    pub xmp_sidecar: Option<PathBuf>,
+
+    /// Display color management
+    pub color: ColorState,
+
+    /// Print resolution (DPI) entered for export; empty keeps the file's value
+    pub export_dpi_input: String,
+
+    /// Page range entered for export of multi-page documents; empty = all pages
+    pub export_pages_input: String,
+
+    /// Crop region typed in the crop dimensions panel (`x,y,w,h` or `WxH+X+Y`)
+    pub crop_geometry_input: String,
+
+    /// Name typed for a new zoom bookmark; empty = numbered
+    pub zoom_bookmark_name_input: String,
+
+    /// Statistics of the crop selection, computed on demand
+    pub selection_stats: Option<SelectionStats>,
+
+    /// Dropdown labels for `ViewMode::PRESETS` (settings panel)
+    pub view_mode_labels: Vec<String>,
+
+    /// Dropdown labels for `CanvasBackground::ALL` (settings panel)
+    pub canvas_background_labels: Vec<String>,
+
+    /// Canvas color being edited in settings (`#rrggbb`)
+    pub canvas_color_input: String,
+
+    /// Dropdown labels for the background of the current document: the
+    /// configured one, then `CanvasBackground::ALL` (settings panel)
+    pub document_background_labels: Vec<String>,
+
+    /// Canvas color of a document being edited in settings, with the document
+    pub document_color_input: Option<(PathBuf, String)>,
+
+    /// Dropdown labels for `Viewport::MAGNIFIER_ZOOMS` (settings panel)
+    pub magnifier_zoom_labels: Vec<String>,
+
+    /// Batch EXIF date shift tool
+    pub date_shift: DateShiftState,
+
+    /// GPS location editor
+    pub geotag: GeotagState,
+
+    /// All metadata of the current image
+    pub metadata_browser: MetadataBrowserState,
+
+    /// Editable fields of the current image (author, rating, ...)
+    pub metadata_edit: MetadataEditState,
+
+    /// Folder images selected for batch operations
+    pub selection: FolderSelection,
+
+    /// Privacy export regions
+    pub privacy: PrivacyState,
+
+    /// Color adjustment sliders
+    pub adjust: AdjustState,
+
+    /// Resize panel
+    pub resize: ResizeState,
+
+    /// Scan cleanup panel
+    pub scan_cleanup: ScanCleanup,
+
+    /// Export pages panel (PDF pages as images)
+    pub page_export: PageExportState,
+
+    /// Metadata catalog of the folder
+    pub catalog: CatalogState,
+
+    /// Batch conversion queue
+    pub batch: BatchState,
+
+    /// Share targets
+    pub share: ShareState,
+
+    /// Upload panel
+    pub upload: UploadState,
+
+    /// Edit macros
+    pub macros: MacroState,
+
+    /// Sprite sheet slicing
+    pub sprite_sheet: SpriteSheetState,
+
+    /// Animated GIF export
+    pub animation: AnimationState,
+
+    /// Export profiles
+    pub export_profiles: ExportProfileState,
+
+    /// Document text search
+    pub search: SearchState,
+
+    /// Outline found from headings of a scanned document
+    pub heading_outline: HeadingOutlineState,
+
+    /// Find bar over the canvas (None: hidden)
+    pub find: Option<FindState>,
+
+    /// Bookmarked pages per document (session only)
+    pub page_bookmarks: BTreeMap<PathBuf, Vec<usize>>,
+
+    /// Views of the documents navigated away from, kept in a saved session
+    pub view_states: BTreeMap<PathBuf, DocumentView>,
+
+    /// Views from an opened session, applied once their document is shown
+    pub restore_views: BTreeMap<PathBuf, DocumentView>,
+
+    /// Right-to-left reading order for the current document (arrows swapped)
+    pub right_to_left: bool,
+
+    /// Webtoon mode (None = normal page view)
+    pub strip: Option<StripState>,
+
+    /// Single document or compare mode
+    pub layout: ViewLayout,
+
+    /// A background folder rescan is running
+    pub rescan_pending: bool,
+
+    /// The last folder rescan failed (e.g. network mount gone)
+    pub folder_unavailable: bool,
+
+    /// The current file or its folder cannot be written (e.g. read-only mount)
+    pub read_only: bool,
+
+    /// File waiting for confirmation to be moved to the trash
+    pub delete_confirm: Option<PathBuf>,
+
+    /// Dropdown labels for `AppModel::RESCAN_INTERVALS` (settings panel)
+    pub rescan_interval_labels: Vec<String>,
+
+    /// Dropdown labels for `LowPowerMode::ALL` (settings panel)
+    pub low_power_labels: Vec<String>,
+
+    /// Battery and power profile, as last detected
+    pub power: PowerState,
+
+    /// Whether background work is cut back (see `LowPowerMode`)
+    pub low_power: bool,
+
+    /// Dropdown labels for `SortOrder::ALL` (settings panel and header)
+    pub sort_order_labels: Vec<String>,
+
+    /// Fullscreen presentation (None = not fullscreen)
+    pub presentation: Option<Presentation>,
+
+    /// Touch controls on the canvas are shown until then (None = hidden)
+    pub canvas_controls_until: Option<Instant>,
+
+    /// Dropdown labels for `AppModel::SLIDE_INTERVALS` (settings panel)
+    pub slide_interval_labels: Vec<String>,
+
+    /// Dropdown labels for the minimum star rating, by stars (slideshow, selection)
+    pub rating_labels: Vec<String>,
+
+    /// Dropdown labels for `PaperFormat::ALL` (resize panel)
+    pub paper_format_labels: Vec<String>,
+
+    /// Dropdown labels for `ResampleFilter::ALL` (resize panel)
+    pub resample_filter_labels: Vec<String>,
+
+    /// Dropdown labels for `PageExportState::FORMATS` (export pages panel)
+    pub page_format_labels: Vec<String>,
+
+    /// Warnings and errors shown in the log viewer (read when it opens)
+    pub log_entries: Vec<LogEntry>,
+
+    /// Dropdown labels for `logging::LEVELS` (log viewer)
+    pub log_level_labels: Vec<String>,
+
+    /// Dropdown labels of the recent files menu: the file names, then clearing the list
+    pub recent_labels: Vec<String>,
+
+    /// Thumbnails of the recent files on the start screen, as far as loaded
+    pub recent_thumbnails: BTreeMap<PathBuf, cosmic::widget::image::Handle>,
+}
+
+impl AppModel {
+    /// How long the canvas controls stay visible after the pointer last moved.
+    pub const CANVAS_CONTROLS_TIMEOUT: Duration = Duration::from_secs(3);
+
+    /// Folder rescan intervals in seconds selectable in the settings (0 = off).
+    pub const RESCAN_INTERVALS: [u32; 5] = [0, 10, 60, 300, 900];
+
+    /// Automatic advance intervals in seconds selectable in the settings.
+    pub const SLIDE_INTERVALS: [u32; 6] = [3, 5, 10, 20, 30, 60];
+
+    /// Default SVG font sizes selectable in the settings.
+    pub const SVG_FONT_SIZES: [f32; 6] = [8.0, 10.0, 12.0, 14.0, 16.0, 20.0];
+
+    pub fn new(config: AppConfig) -> Self {
+        Self {
+            mode: AppMode::default(),
+            viewport: Viewport {
+                sharpen: config.fit_sharpening,
+                ..Viewport::default()
+            },
+            panels: PanelState::default(),
+            error: None,
+            menu_open: false,
+            exclude_patterns_input: config.nav_exclude_patterns.join(", "),
+            svg_fonts_input: config.svg_fallback_fonts.join(", "),
+            svg_font_size_labels: Self::SVG_FONT_SIZES
+                .map(|size| fl!("settings-svg-font-size-value", size: size))
+                .to_vec(),
+            orientation_lock: None,
+            history: EditHistory::default(),
+            xmp_sidecar: None,
+            color: ColorState::new(&config),
+            export_dpi_input: String::new(),
+            export_pages_input: String::new(),
+            crop_geometry_input: String::new(),
+            zoom_bookmark_name_input: String::new(),
+            selection_stats: None,
+            view_mode_labels: ViewMode::PRESETS.map(ViewMode::label).to_vec(),
+            canvas_background_labels: CanvasBackground::ALL.map(CanvasBackground::label).to_vec(),
+            canvas_color_input: config.canvas_color.clone(),
+            document_background_labels: std::iter::once(fl!("canvas-background-default"))
+                .chain(CanvasBackground::ALL.map(CanvasBackground::label))
+                .collect(),
+            document_color_input: None,
+            magnifier_zoom_labels: Viewport::MAGNIFIER_ZOOMS
+                .map(|zoom| fl!("settings-magnifier-factor", factor: zoom))
+                .to_vec(),
+            date_shift: DateShiftState::default(),
+            geotag: GeotagState::default(),
+            metadata_browser: MetadataBrowserState::default(),
+            metadata_edit: MetadataEditState::default(),
+            selection: FolderSelection::default(),
+            privacy: PrivacyState::default(),
+            adjust: AdjustState::default(),
+            resize: ResizeState::default(),
+            scan_cleanup: ScanCleanup::default(),
+            page_export: PageExportState::default(),
+            catalog: CatalogState::default(),
+            batch: BatchState::default(),
+            share: ShareState::new(&config),
+            upload: UploadState::new(&config),
+            macros: MacroState::default(),
+            sprite_sheet: SpriteSheetState::default(),
+            animation: AnimationState::default(),
+            export_profiles: ExportProfileState::new(&config),
+            search: SearchState::default(),
+            heading_outline: HeadingOutlineState::default(),
+            find: None,
+            page_bookmarks: BTreeMap::new(),
+            view_states: BTreeMap::new(),
+            restore_views: BTreeMap::new(),
+            right_to_left: false,
+            strip: None,
+            layout: ViewLayout::default(),
+            rescan_pending: false,
+            folder_unavailable: false,
+            read_only: false,
+            delete_confirm: None,
+            rescan_interval_labels: Self::RESCAN_INTERVALS
+                .map(|seconds| match seconds {
+                    0 => fl!("settings-rescan-off"),
+                    s if s < 60 => fl!("settings-rescan-seconds", seconds: s),
+                    s => fl!("settings-rescan-minutes", minutes: s / 60),
+                })
+                .to_vec(),
+            low_power_labels: LowPowerMode::ALL.map(LowPowerMode::label).to_vec(),
+            power: PowerState::default(),
+            low_power: LowPowerMode::from_key(&config.low_power_mode) == Some(LowPowerMode::Always),
+            sort_order_labels: SortOrder::ALL
+                .map(|order| match order {
+                    SortOrder::Name => fl!("sort-order-name"),
+                    SortOrder::Modified => fl!("sort-order-modified"),
+                    SortOrder::Size => fl!("sort-order-size"),
+                    SortOrder::CaptureDate => fl!("sort-order-capture-date"),
+                })
+                .to_vec(),
+            presentation: None,
+            canvas_controls_until: None,
+            slide_interval_labels: Self::SLIDE_INTERVALS
+                .map(|seconds| fl!("settings-slide-seconds", seconds: seconds))
+                .to_vec(),
+            rating_labels: (0..=labels::MAX_RATING)
+                .map(|stars| match stars {
+                    0 => fl!("rating-any"),
+                    _ => fl!("rating-at-least", stars: stars),
+                })
+                .collect(),
+            paper_format_labels: PaperFormat::ALL
+                .map(|format| format.display_name().to_string())
+                .to_vec(),
+            resample_filter_labels: ResampleFilter::ALL
+                .map(|filter| match filter {
+                    ResampleFilter::Nearest => fl!("resize-filter-nearest"),
+                    ResampleFilter::Bilinear => fl!("resize-filter-bilinear"),
+                    ResampleFilter::Lanczos3 => fl!("resize-filter-lanczos3"),
+                })
+                .to_vec(),
+            page_format_labels: PageExportState::FORMATS
+                .map(|format| format.extension().to_uppercase())
+                .to_vec(),
+            log_entries: Vec::new(),
+            log_level_labels: logging::LEVELS
+                .map(|level| fl!("log-level", level: level.as_str().to_lowercase()))
+                .to_vec(),
+            recent_labels: Self::recent_labels(&config.recent_files),
+            recent_thumbnails: BTreeMap::new(),
+        }
+    }
+
+    /// Labels of the recent files menu for `recent`.
+    pub fn recent_labels(recent: &[PathBuf]) -> Vec<String> {
+        recent
+            .iter()
+            .map(|path| match path.file_name() {
+                Some(name) => name.to_string_lossy().into_owned(),
+                None => path.display().to_string(),
+            })
+            .chain(std::iter::once(fl!("recent-clear")))
+            .collect()
+    }
+
+    /// Set error message
+    pub fn set_error<S: Into<String>>(&mut self, msg: S) {
+        self.error = Some(msg.into());
+    }
+
+    /// Clear error message
+    pub fn clear_error(&mut self) {
+        self.error = None;
+    }
+
+    /// Reset viewport pan to center
+    pub fn reset_pan(&mut self) {
+        self.viewport.reset_pan();
+    }
+
+    /// Print resolution entered for export (None if empty or invalid).
+    pub fn export_resolution(&self) -> Option<Resolution> {
+        self.export_dpi_input
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|dpi| *dpi > 0.0 && *dpi <= f64::from(u16::MAX))
+            .map(Resolution::uniform)
+    }
+}
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/mode.rs
+//
+// Application mode: the active tool and its state.
+
+use super::{Orientation, PaperFormat, RightPanel, StraightenTool};
+use crate::ui::widgets::CropSelection;
+
+/// Application mode - unified tool and panel state.
+///
+/// Each mode determines:
+/// - Active tool behavior
+/// - Right panel content
+/// - Available shortcuts
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub enum AppMode {
+    /// Normal viewing mode - no active tool
+    View,
+
+    /// Crop mode with selection
+    Crop { selection: CropSelection },
+
+    /// Text selection on a PDF page, with the boxes of the selected
+    /// characters in canvas coordinates
+    SelectText {
+        selection: CropSelection,
+        highlights: Vec<(f32, f32, f32, f32)>,
+    },
+
+    /// Transform/export mode
+    Transform {
+        paper_format: Option<PaperFormat>,
+        orientation: Orientation,
+        straighten: StraightenTool,
+    },
+
+    /// Fullscreen mode (all panels hidden)
+    Fullscreen,
+}
+
+impl Default for AppMode {
+    fn default() -> Self {
+        Self::View
+    }
+}
+
+impl AppMode {
+    /// Get the right panel that should be shown for this mode
+    pub fn right_panel(&self) -> Option<RightPanel> {
+        match self {
+            Self::View | Self::SelectText { .. } => Some(RightPanel::Properties),
+            Self::Crop { .. } => Some(RightPanel::CropTools),
+            Self::Transform { .. } => Some(RightPanel::TransformTools),
+            Self::Fullscreen => None,
+        }
+    }
+
+    /// Check if mode is an active tool (not View/Fullscreen)
+    pub fn is_tool_active(&self) -> bool {
+        matches!(
+            self,
+            Self::Crop { .. } | Self::SelectText { .. } | Self::Transform { .. }
+        )
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/page_export.rs
+//
+// Page export.
+
+use crate::application::services::page_export;
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::page::PageRange;
+use crate::domain::document::operations::export::{ExportFormat, ImageExportOptions};
+
+/// Which pages of a document are exported as images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PageScope {
+    #[default]
+    Current,
+    /// The pages entered as page range
+    Range,
+    All,
+}
+
+impl PageScope {
+    pub const ALL: [Self; 3] = [Self::Current, Self::Range, Self::All];
+}
+
+/// Export pages panel state (the page range is `AppModel::export_pages_input`).
+#[derive(Debug, Clone)]
+pub struct PageExportState {
+    pub scope: PageScope,
+
+    /// PNG or JPEG
+    pub format: ExportFormat,
+
+    /// JPEG quality (1-100)
+    pub quality: u8,
+
+    /// Resolution the pages are rendered at
+    pub dpi_input: String,
+
+    /// Pages written and their total while an export runs
+    pub progress: Option<(usize, usize)>,
+
+    /// Stops the running export
+    pub cancel: Option<CancelToken>,
+
+    /// Result of the last export
+    pub status: Option<String>,
+}
+
+impl Default for PageExportState {
+    fn default() -> Self {
+        Self {
+            scope: PageScope::default(),
+            format: ExportFormat::Png,
+            quality: ImageExportOptions::default().quality,
+            dpi_input: "150".to_string(),
+            progress: None,
+            cancel: None,
+            status: None,
+        }
+    }
+}
+
+impl PageExportState {
+    /// Formats pages can be exported as.
+    pub const FORMATS: [ExportFormat; 2] = [ExportFormat::Png, ExportFormat::Jpeg];
+
+    /// Resolution entered (None if invalid or above `page_export::MAX_DPI`).
+    pub fn dpi(&self) -> Option<f64> {
+        self.dpi_input
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|dpi| (1.0..=page_export::MAX_DPI).contains(dpi))
+    }
+
+    /// Pages to export (0-based), None if the page range is invalid.
+    pub fn pages(
+        &self,
+        range_input: &str,
+        current: usize,
+        page_count: usize,
+    ) -> Option<Vec<usize>> {
+        match self.scope {
+            PageScope::Current => Some(vec![current]),
+            PageScope::Range => PageRange::parse(range_input, page_count)
+                .ok()
+                .map(|range| range.pages().to_vec()),
+            PageScope::All => Some((0..page_count).collect()),
+        }
+    }
+
+    /// Check if an export is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+}
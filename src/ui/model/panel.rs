@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/panel.rs
+//
+// Panel state: which side panels are open.
+
+use crate::fl;
+
+/// Panel visibility state.
+#[derive(Debug, Clone, Default)]
+pub struct PanelState {
+    /// Left panel (thumbnails for multi-page)
+    pub left: Option<LeftPanel>,
+
+    /// Right panel (context-dependent tools/properties)
+    pub right: Option<RightPanel>,
+}
+
+/// Left panel tabs (multi-page documents)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LeftPanel {
+    /// Thumbnail navigation for multi-page documents
+    Thumbnails,
+
+    /// Document outline (table of contents)
+    Outline,
+
+    /// User page bookmarks
+    Bookmarks,
+
+    /// Text search results
+    Search,
+}
+
+impl LeftPanel {
+    /// All tabs in display order.
+    pub const ALL: [Self; 4] = [
+        Self::Thumbnails,
+        Self::Outline,
+        Self::Bookmarks,
+        Self::Search,
+    ];
+
+    /// Stable key used in the config.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Thumbnails => "thumbnails",
+            Self::Outline => "outline",
+            Self::Bookmarks => "bookmarks",
+            Self::Search => "search",
+        }
+    }
+
+    /// Parse a config key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|tab| tab.key() == key)
+    }
+
+    /// Tab icon name.
+    pub fn icon_name(self) -> &'static str {
+        match self {
+            Self::Thumbnails => "view-grid-symbolic",
+            Self::Outline => "view-list-symbolic",
+            Self::Bookmarks => "bookmark-new-symbolic",
+            Self::Search => "system-search-symbolic",
+        }
+    }
+
+    /// Tab tooltip.
+    pub fn label(self) -> String {
+        match self {
+            Self::Thumbnails => fl!("left-tab-thumbnails"),
+            Self::Outline => fl!("left-tab-outline"),
+            Self::Bookmarks => fl!("left-tab-bookmarks"),
+            Self::Search => fl!("left-tab-search"),
+        }
+    }
+}
+
+/// Right panel types
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[allow(dead_code)]
+pub enum RightPanel {
+    /// Document properties and metadata
+    Properties,
+
+    /// Crop mode tools
+    CropTools,
+
+    /// Transform/export tools
+    TransformTools,
+}
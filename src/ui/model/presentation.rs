@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/presentation.rs
+//
+// Fullscreen presentation and slideshows.
+
+use std::time::{Duration, Instant};
+
+use crate::domain::document::operations::slideshow::SlideOrder;
+
+/// Slideshow through the folder, running inside a presentation.
+#[derive(Debug, Clone)]
+pub struct Slideshow {
+    /// Shuffled or filtered order (None = folder order via `NextDocument`)
+    pub order: Option<SlideOrder>,
+    /// Fullscreen was entered for the slideshow and is left when it ends
+    pub entered_fullscreen: bool,
+}
+
+/// Fullscreen presentation: running time, automatic advance and the
+/// controls overlay.
+#[derive(Debug, Clone)]
+pub struct Presentation {
+    /// When the presentation started
+    pub started: Instant,
+    /// Time of the last timer tick (the view reads the clock from here)
+    pub now: Instant,
+    /// The controls overlay is shown until then
+    pub controls_until: Instant,
+    /// When the next slide is shown automatically (None = paused)
+    pub next_slide: Option<Instant>,
+    /// Running slideshow (None = single document, advancing turns pages)
+    pub slideshow: Option<Slideshow>,
+}
+
+impl Presentation {
+    /// How long the controls stay visible after the mouse last moved.
+    pub const CONTROLS_TIMEOUT: Duration = Duration::from_secs(3);
+
+    pub fn new(now: Instant) -> Self {
+        Self {
+            started: now,
+            now,
+            controls_until: now + Self::CONTROLS_TIMEOUT,
+            next_slide: None,
+            slideshow: None,
+        }
+    }
+
+    /// Time since the presentation started.
+    pub fn elapsed(&self) -> Duration {
+        self.now.saturating_duration_since(self.started)
+    }
+
+    /// Time left until the next automatic advance.
+    pub fn countdown(&self) -> Option<Duration> {
+        self.next_slide
+            .map(|at| at.saturating_duration_since(self.now))
+    }
+
+    pub fn controls_visible(&self) -> bool {
+        self.now < self.controls_until
+    }
+
+    /// Show the controls again (mouse moved or a control was used).
+    pub fn show_controls(&mut self) {
+        self.now = Instant::now();
+        self.controls_until = self.now + Self::CONTROLS_TIMEOUT;
+    }
+
+    /// Restart the countdown of `seconds` if advancing automatically.
+    pub fn restart_countdown(&mut self, seconds: u32) {
+        if self.next_slide.is_some() {
+            self.next_slide = Some(Instant::now() + Duration::from_secs(seconds.into()));
+        }
+    }
+
+    /// Start or pause advancing automatically every `seconds`.
+    pub fn toggle_auto_advance(&mut self, seconds: u32) {
+        self.next_slide = match self.next_slide {
+            Some(_) => None,
+            None => Some(Instant::now() + Duration::from_secs(seconds.into())),
+        };
+        self.show_controls();
+    }
+
+    /// Start a slideshow advancing every `seconds`.
+    pub fn start_slideshow(&mut self, slideshow: Slideshow, seconds: u32) {
+        self.slideshow = Some(slideshow);
+        self.next_slide = Some(Instant::now() + Duration::from_secs(seconds.into()));
+        self.show_controls();
+    }
+}
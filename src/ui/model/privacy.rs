@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/privacy.rs
+//
+// Privacy export.
+
+use crate::domain::document::operations::privacy::BlurRegion;
+
+/// Privacy export state (regions blurred in exported copies).
+#[derive(Debug, Clone, Default)]
+pub struct PrivacyState {
+    /// Regions to blur, relative to the image size
+    pub regions: Vec<BlurRegion>,
+
+    /// Images handled and their total while an export runs
+    pub progress: Option<(usize, usize)>,
+
+    /// Result of the last export
+    pub status: Option<String>,
+}
+
+impl PrivacyState {
+    /// Check if an export is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+}
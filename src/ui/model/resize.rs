@@ -0,0 +1,166 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/resize.rs
+//
+// Resize tool.
+
+use super::{Orientation, PaperFormat};
+use crate::domain::document::operations::resize::{self, ResampleFilter, Resize};
+
+/// How the new size of a resize is entered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResizeUnit {
+    #[default]
+    Pixels,
+    Percent,
+    /// Paper format printed at a resolution
+    Paper,
+}
+
+impl ResizeUnit {
+    pub const ALL: [Self; 3] = [Self::Pixels, Self::Percent, Self::Paper];
+}
+
+/// Resize panel state.
+#[derive(Debug, Clone)]
+pub struct ResizeState {
+    pub unit: ResizeUnit,
+
+    /// Width in pixels or percent
+    pub width_input: String,
+
+    /// Height in pixels or percent
+    pub height_input: String,
+
+    /// Change the other side along (pixels, percent) or fit into the paper
+    /// instead of filling it
+    pub lock_aspect: bool,
+
+    pub paper: PaperFormat,
+    pub orientation: Orientation,
+
+    /// Print resolution for the paper format
+    pub dpi_input: String,
+
+    pub filter: ResampleFilter,
+}
+
+impl Default for ResizeState {
+    fn default() -> Self {
+        Self {
+            unit: ResizeUnit::default(),
+            width_input: String::new(),
+            height_input: String::new(),
+            lock_aspect: true,
+            paper: PaperFormat::default(),
+            orientation: Orientation::default(),
+            dpi_input: "300".to_string(),
+            filter: ResampleFilter::default(),
+        }
+    }
+}
+
+impl ResizeState {
+    /// Switch to `unit`, starting from the unchanged size of a `width` x `height` image.
+    pub fn set_unit(&mut self, unit: ResizeUnit, (width, height): (u32, u32)) {
+        self.unit = unit;
+        (self.width_input, self.height_input) = match unit {
+            ResizeUnit::Percent => ("100".to_string(), "100".to_string()),
+            _ => (width.to_string(), height.to_string()),
+        };
+    }
+
+    /// Enter the width, changing the height along if the aspect is locked.
+    pub fn set_width(&mut self, input: &str, (width, height): (u32, u32)) {
+        self.width_input = Self::number_input(input, self.unit);
+        if self.lock_aspect {
+            self.height_input = self.other_side(&self.width_input, width, height);
+        }
+    }
+
+    /// Enter the height, changing the width along if the aspect is locked.
+    pub fn set_height(&mut self, input: &str, (width, height): (u32, u32)) {
+        self.height_input = Self::number_input(input, self.unit);
+        if self.lock_aspect {
+            self.width_input = self.other_side(&self.height_input, height, width);
+        }
+    }
+
+    /// Print resolution entered for the paper format (None if invalid).
+    pub fn dpi(&self) -> Option<f64> {
+        self.dpi_input
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|dpi| (1.0..=10_000.0).contains(dpi))
+    }
+
+    /// New size of a `width` x `height` image (None if the input is invalid).
+    pub fn target(&self, (width, height): (u32, u32)) -> Option<(u32, u32)> {
+        match self.unit {
+            ResizeUnit::Pixels => {
+                let pixels = |input: &str| input.parse::<u32>().ok().filter(|p| *p > 0);
+                Some((pixels(&self.width_input)?, pixels(&self.height_input)?))
+            }
+            ResizeUnit::Percent => {
+                let percent = |input: &str| input.parse::<f64>().ok().filter(|p| *p > 0.0);
+                let (new_width, _) =
+                    resize::percent_size(width, height, percent(&self.width_input)?);
+                let (_, new_height) =
+                    resize::percent_size(width, height, percent(&self.height_input)?);
+                Some((new_width, new_height))
+            }
+            ResizeUnit::Paper => {
+                let (short, long) = self.paper.dimensions_mm();
+                let (mm_width, mm_height) = match self.orientation {
+                    Orientation::Horizontal => (long, short),
+                    Orientation::Vertical => (short, long),
+                };
+                let (paper_width, paper_height) =
+                    resize::paper_size(mm_width, mm_height, self.dpi()?);
+                if !self.lock_aspect {
+                    return Some((paper_width, paper_height));
+                }
+                // Largest size inside the paper
+                let fit_height = resize::proportional(height, width, paper_width);
+                Some(if fit_height <= paper_height {
+                    (paper_width, fit_height)
+                } else {
+                    (
+                        resize::proportional(width, height, paper_height),
+                        paper_height,
+                    )
+                })
+            }
+        }
+    }
+
+    /// Resize for a `width` x `height` image, None if the input is invalid
+    /// or the result would exceed `max_megapixels`.
+    pub fn resize(&self, size: (u32, u32), max_megapixels: u32) -> Option<Resize> {
+        let (width, height) = self.target(size)?;
+        let limit = u64::from(max_megapixels) * 1_000_000;
+        if u64::from(width) * u64::from(height) > limit {
+            return None;
+        }
+        Resize::new(width, height, self.filter)
+    }
+
+    /// Digits of `input`, and a decimal point for percentages.
+    fn number_input(input: &str, unit: ResizeUnit) -> String {
+        input
+            .chars()
+            .filter(|c| c.is_ascii_digit() || (unit == ResizeUnit::Percent && *c == '.'))
+            .collect()
+    }
+
+    /// The other side for an entered side (`side` of `from` to `to`).
+    fn other_side(&self, input: &str, from: u32, to: u32) -> String {
+        match self.unit {
+            ResizeUnit::Pixels => input
+                .parse::<u32>()
+                .map(|side| resize::proportional(to, from, side).to_string())
+                .unwrap_or_default(),
+            ResizeUnit::Percent | ResizeUnit::Paper => input.to_string(),
+        }
+    }
+}
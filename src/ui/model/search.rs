@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/search.rs
+//
+// Document search, heading outline and the find bar.
+
+use std::path::{Path, PathBuf};
+
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::page::{OutlineEntry, TextHit, TextMatch};
+
+/// Text search state (left panel search tab).
+#[derive(Debug, Clone, Default)]
+pub struct SearchState {
+    /// Query being edited
+    pub query: String,
+
+    /// Hits of the last search
+    pub results: Vec<TextMatch>,
+
+    /// Whether a search ran for the current document (distinguishes "no hits")
+    pub searched: bool,
+}
+
+impl SearchState {
+    /// Forget results (e.g. when another document is opened).
+    pub fn reset(&mut self) {
+        self.results.clear();
+        self.searched = false;
+    }
+}
+
+/// Outline found from the headings of a scanned document (left panel
+/// outline tab).
+#[derive(Debug, Clone, Default)]
+pub struct HeadingOutlineState {
+    /// Document the headings were searched in
+    pub path: Option<PathBuf>,
+
+    /// Headings found so far, in page order
+    pub entries: Vec<OutlineEntry>,
+
+    /// Pages read and their total while the search runs
+    pub progress: Option<(usize, usize)>,
+
+    /// Stops the running search
+    pub cancel: Option<CancelToken>,
+
+    /// Result of the last search
+    pub status: Option<String>,
+}
+
+impl HeadingOutlineState {
+    /// Check if a search is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+
+    /// Check if the search ran (or runs) for the document at `path`.
+    pub fn is_for(&self, path: Option<&Path>) -> bool {
+        path.is_some() && self.path.as_deref() == path
+    }
+}
+
+/// Find bar state (Ctrl+F): hits of the query with their position on the page.
+#[derive(Debug, Clone, Default)]
+pub struct FindState {
+    /// Query being edited
+    pub query: String,
+
+    /// Query the hits were found for; a changed query searches again
+    pub searched: Option<String>,
+
+    /// Hits in reading order
+    pub hits: Vec<TextHit>,
+
+    /// Index of the hit jumped to last
+    pub current: Option<usize>,
+
+    /// Shift is held, so Enter in the find field goes back
+    pub shift: bool,
+}
+
+impl FindState {
+    /// Move to the next (or previous) hit, wrapping around at the ends.
+    ///
+    /// The first step starts from the hits on the shown page.
+    pub fn step(&mut self, forward: bool, page: usize) -> Option<&TextHit> {
+        let count = self.hits.len();
+        if count == 0 {
+            return None;
+        }
+        let next = match self.current {
+            Some(i) if forward => (i + 1) % count,
+            Some(i) => (i + count - 1) % count,
+            None if forward => self
+                .hits
+                .iter()
+                .position(|hit| hit.page >= page)
+                .unwrap_or(0),
+            None => self
+                .hits
+                .iter()
+                .rposition(|hit| hit.page <= page)
+                .unwrap_or(count - 1),
+        };
+        self.current = Some(next);
+        self.hits.get(next)
+    }
+
+    /// Hits on a page, with whether each is the current one.
+    pub fn hits_on(&self, page: usize) -> impl Iterator<Item = (&TextHit, bool)> {
+        self.hits
+            .iter()
+            .enumerate()
+            .filter(move |(_, hit)| hit.page == page)
+            .map(|(i, hit)| (hit, self.current == Some(i)))
+    }
+}
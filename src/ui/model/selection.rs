@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/selection.rs
+//
+// Selection of folder images.
+
+use std::path::{Path, PathBuf};
+
+use crate::domain::document::operations::labels::LabelFilter;
+
+/// Folder images selected for batch operations (geotag, privacy export, batch conversion).
+#[derive(Debug, Clone, Default)]
+pub struct FolderSelection {
+    paths: Vec<PathBuf>,
+    /// Fewest stars for "select matching" (0 = any)
+    pub min_rating: u8,
+    /// Comma-separated tags for "select matching" (empty = any)
+    pub tags_input: String,
+    /// Labels of the folder images are being read
+    pub matching: bool,
+}
+
+impl FolderSelection {
+    /// Selected paths in selection order.
+    pub fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Number of selected images.
+    pub fn len(&self) -> usize {
+        self.paths.len()
+    }
+
+    /// Check if nothing is selected.
+    pub fn is_empty(&self) -> bool {
+        self.paths.is_empty()
+    }
+
+    /// Check if an image is selected.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.paths.iter().any(|p| p == path)
+    }
+
+    /// Select or deselect an image.
+    pub fn set(&mut self, path: &Path, selected: bool) {
+        self.paths.retain(|p| p != path);
+        if selected {
+            self.paths.push(path.to_path_buf());
+        }
+    }
+
+    /// Replace the selection.
+    pub fn set_all(&mut self, paths: &[PathBuf]) {
+        self.paths = paths.to_vec();
+    }
+
+    /// Filter on ratings and tags chosen for "select matching".
+    pub fn filter(&self) -> LabelFilter {
+        LabelFilter::new(self.min_rating, &self.tags_input)
+    }
+
+    /// Drop selected paths that are no longer in the folder.
+    pub fn retain_existing(&mut self, entries: &[PathBuf]) {
+        self.paths.retain(|p| entries.contains(p));
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/share.rs
+//
+// Share targets and their editing state.
+
+use crate::config::AppConfig;
+use crate::fl;
+
+/// Share targets (header menu) and their editing state (settings panel).
+#[derive(Debug, Clone, Default)]
+pub struct ShareState {
+    /// Menu labels, one per configured target
+    pub labels: Vec<String>,
+
+    /// Target the image is being sent to
+    pub running: Option<String>,
+
+    /// Result of the last share
+    pub status: Option<String>,
+
+    /// Name typed in the settings panel
+    pub name_input: String,
+
+    /// Command or address typed in the settings panel
+    pub target_input: String,
+}
+
+impl ShareState {
+    pub fn new(config: &AppConfig) -> Self {
+        let mut state = Self::default();
+        state.refresh(config);
+        state
+    }
+
+    /// Rebuild the menu labels after the configured targets changed.
+    pub fn refresh(&mut self, config: &AppConfig) {
+        self.labels = config
+            .share_targets
+            .keys()
+            .map(|name| fl!("share-to", name: name.as_str()))
+            .collect();
+    }
+}
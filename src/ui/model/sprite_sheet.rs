@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/sprite_sheet.rs
+//
+// Sprite sheet slicing.
+
+use crate::domain::document::operations::sprite_sheet::SliceGrid;
+
+/// Sprite sheet slicing tool state.
+#[derive(Debug, Clone, Default)]
+pub struct SpriteSheetState {
+    /// Read the input as columns × rows instead of a cell size
+    pub by_count: bool,
+
+    /// Grid typed in the panel (e.g. `32x32`)
+    pub input: String,
+
+    /// Draw the grid over the image
+    pub show_grid: bool,
+
+    /// Result of the last export
+    pub status: Option<String>,
+}
+
+impl SpriteSheetState {
+    /// The entered grid (None if the input is invalid).
+    pub fn grid(&self) -> Option<SliceGrid> {
+        SliceGrid::parse(&self.input, self.by_count)
+    }
+
+    /// Grid to draw over the image, if shown and valid.
+    pub fn overlay(&self) -> Option<SliceGrid> {
+        self.show_grid.then(|| self.grid()).flatten()
+    }
+}
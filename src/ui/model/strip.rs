@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/strip.rs
+//
+// Webtoon strip of the folder images.
+
+use cosmic::iced::Size;
+
+use crate::domain::document::operations::strip::{StripLayout, StripPage};
+
+/// Webtoon mode: the folder images stitched into one vertical strip.
+#[derive(Debug, Clone, Default)]
+pub struct StripState {
+    /// Images of the strip in folder order
+    pub pages: Vec<StripPage>,
+
+    /// Scroll offset from the top of the strip
+    pub offset: f32,
+
+    /// Size of the visible part of the strip
+    pub view_size: Size,
+}
+
+impl StripState {
+    /// Page positions for the current view width (unscaled until it is known).
+    pub fn layout(&self) -> StripLayout {
+        let width = if self.view_size.width > 0.0 {
+            self.view_size.width
+        } else {
+            f32::INFINITY
+        };
+        StripLayout::new(&self.pages, width)
+    }
+
+    /// Pages to show: the visible ones and one screen ahead and behind.
+    pub fn loaded(&self) -> std::ops::Range<usize> {
+        let screen = self.view_size.height;
+        self.layout().visible(self.offset, screen, screen)
+    }
+
+    /// Offset one screen further (or back), keeping a little overlap.
+    pub fn screen_offset(&self, forward: bool) -> f32 {
+        let step = self.view_size.height * 0.9;
+        let last = (self.layout().total_height() - self.view_size.height).max(0.0);
+        let offset = if forward {
+            self.offset + step
+        } else {
+            self.offset - step
+        };
+        offset.clamp(0.0, last)
+    }
+}
@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/transform.rs
+//
+// Paper formats, orientation and straightening of the transform mode.
+
+use crate::domain::document::operations::straighten::{RotateEdges, RotateFilter};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaperFormat {
+    UsLetter,
+    IsoA0,
+    IsoA1,
+    IsoA2,
+    IsoA3,
+    #[default]
+    IsoA4,
+    IsoA5,
+    IsoA6,
+}
+
+impl PaperFormat {
+    pub const ALL: [Self; 8] = [
+        Self::UsLetter,
+        Self::IsoA0,
+        Self::IsoA1,
+        Self::IsoA2,
+        Self::IsoA3,
+        Self::IsoA4,
+        Self::IsoA5,
+        Self::IsoA6,
+    ];
+
+    /// Returns (width, height) in millimeters
+    pub fn dimensions_mm(self) -> (u32, u32) {
+        match self {
+            Self::UsLetter => (216, 279), // 8.5 x 11 inches
+            Self::IsoA0 => (841, 1189),
+            Self::IsoA1 => (594, 841),
+            Self::IsoA2 => (420, 594),
+            Self::IsoA3 => (297, 420),
+            Self::IsoA4 => (210, 297),
+            Self::IsoA5 => (148, 210),
+            Self::IsoA6 => (105, 148),
+        }
+    }
+
+    /// Returns display name
+    pub fn display_name(self) -> &'static str {
+        match self {
+            Self::UsLetter => "US Letter",
+            Self::IsoA0 => "A0 (841 × 1189 mm)",
+            Self::IsoA1 => "A1",
+            Self::IsoA2 => "A2",
+            Self::IsoA3 => "A3",
+            Self::IsoA4 => "A4",
+            Self::IsoA5 => "A5 (148 × 210 mm)",
+            Self::IsoA6 => "A6",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Orientation {
+    Horizontal,
+    #[default]
+    Vertical,
+}
+
+/// Fine rotation set up in Transform mode, previewed until applied.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StraightenTool {
+    /// Clockwise angle in degrees.
+    pub angle: f32,
+    /// Transparent, clamped or cropped corners when applying.
+    pub edges: RotateEdges,
+    /// Resampling filter when applying.
+    pub filter: RotateFilter,
+    /// Drawing a line on the canvas to straighten by.
+    pub drawing: bool,
+    /// Line being drawn (start, end) in canvas coordinates.
+    pub line: Option<((f32, f32), (f32, f32))>,
+    /// Two-finger rotate gesture under way.
+    pub gesture: Option<RotateGesture>,
+}
+
+impl StraightenTool {
+    /// Shorter lines (in canvas pixels) are taken for clicks.
+    pub const MIN_LINE_LENGTH: f32 = 10.0;
+    /// Gestures ending this close (in degrees) to a quarter turn snap onto it.
+    pub const GESTURE_SNAP: f32 = 4.0;
+
+    /// Clockwise angle the preview is turned by, including a gesture.
+    pub fn preview_angle(&self) -> f32 {
+        self.gesture
+            .map_or(self.angle, |gesture| gesture.start_angle + gesture.turned)
+    }
+}
+
+/// Two-finger rotate gesture on the canvas.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotateGesture {
+    /// Fine rotation when the gesture started.
+    pub start_angle: f32,
+    /// Clockwise degrees the fingers turned since.
+    pub turned: f32,
+    /// Close enough to a quarter turn to snap onto it.
+    pub snapped: bool,
+}
+
+impl Default for StraightenTool {
+    fn default() -> Self {
+        Self {
+            angle: 0.0,
+            edges: RotateEdges::default(),
+            filter: RotateFilter::default(),
+            drawing: false,
+            line: None,
+            gesture: None,
+        }
+    }
+}
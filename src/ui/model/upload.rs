@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/upload.rs
+//
+// Uploading the edited image: progress and the endpoint being edited.
+
+use crate::config::AppConfig;
+use crate::domain::document::core::cancel::CancelToken;
+
+/// Upload of the edited image (upload panel).
+#[derive(Debug, Clone, Default)]
+pub struct UploadState {
+    /// Percentage sent while an upload runs
+    pub progress: Option<u8>,
+
+    /// Stops the running upload
+    pub cancel: Option<CancelToken>,
+
+    /// Result of the last upload
+    pub status: Option<String>,
+
+    /// Name of the file being uploaded, for the history
+    pub file_name: Option<String>,
+
+    /// Endpoint typed in the upload panel
+    pub endpoint_input: String,
+}
+
+impl UploadState {
+    pub fn new(config: &AppConfig) -> Self {
+        Self {
+            endpoint_input: config.upload_endpoint.clone(),
+            ..Self::default()
+        }
+    }
+
+    /// Check if an upload is running.
+    pub fn is_running(&self) -> bool {
+        self.progress.is_some()
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/view.rs
+//
+// How documents are fitted and shown: view modes, canvas backgrounds and
+// low-power mode.
+
+use crate::fl;
+use crate::infrastructure::system::power::PowerState;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ViewMode {
+    #[default]
+    Fit,
+    FitWidth,
+    ActualSize,
+    /// Fill the canvas, cropping the overflow (wallpaper and thumbnail crops)
+    Cover,
+    /// Stretch to the canvas, ignoring the aspect ratio
+    Fill,
+    Custom,
+}
+
+impl ViewMode {
+    /// Modes selectable as per-kind default.
+    pub const PRESETS: [Self; 5] = [
+        Self::Fit,
+        Self::FitWidth,
+        Self::ActualSize,
+        Self::Cover,
+        Self::Fill,
+    ];
+
+    /// Stable key used in the config.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Fit => "fit",
+            Self::FitWidth => "fit-width",
+            Self::ActualSize => "actual-size",
+            Self::Cover => "cover",
+            Self::Fill => "fill",
+            Self::Custom => "custom",
+        }
+    }
+
+    /// Parse a config key (presets only).
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::PRESETS.into_iter().find(|mode| mode.key() == key)
+    }
+
+    /// Label for the settings dropdown.
+    pub fn label(self) -> String {
+        match self {
+            Self::Fit => fl!("view-mode-fit"),
+            Self::FitWidth => fl!("view-mode-fit-width"),
+            Self::ActualSize => fl!("view-mode-actual-size"),
+            Self::Cover => fl!("view-mode-cover"),
+            Self::Fill => fl!("view-mode-fill"),
+            Self::Custom => fl!("view-mode-custom"),
+        }
+    }
+}
+
+/// What the canvas shows behind transparent parts of the image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CanvasBackground {
+    /// Window background of the theme
+    #[default]
+    Theme,
+    /// Light and dark gray squares under the image
+    Checkerboard,
+    /// Solid color chosen in the settings
+    Color,
+}
+
+impl CanvasBackground {
+    /// All backgrounds in settings order.
+    pub const ALL: [Self; 3] = [Self::Theme, Self::Checkerboard, Self::Color];
+
+    /// Stable key used in the config.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Theme => "theme",
+            Self::Checkerboard => "checkerboard",
+            Self::Color => "color",
+        }
+    }
+
+    /// Parse a config key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|option| option.key() == key)
+    }
+
+    /// Label for the settings dropdown.
+    pub fn label(self) -> String {
+        match self {
+            Self::Theme => fl!("canvas-background-theme"),
+            Self::Checkerboard => fl!("canvas-background-checkerboard"),
+            Self::Color => fl!("canvas-background-color"),
+        }
+    }
+}
+
+/// When background work is cut back to save battery.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LowPowerMode {
+    /// On battery or with the power saver profile
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl LowPowerMode {
+    /// All modes in settings order.
+    pub const ALL: [Self; 3] = [Self::Auto, Self::Always, Self::Never];
+
+    /// Stable key used in the config.
+    pub fn key(self) -> &'static str {
+        match self {
+            Self::Auto => "auto",
+            Self::Always => "always",
+            Self::Never => "never",
+        }
+    }
+
+    /// Parse a config key.
+    pub fn from_key(key: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|mode| mode.key() == key)
+    }
+
+    /// Label for the settings dropdown.
+    pub fn label(self) -> String {
+        match self {
+            Self::Auto => fl!("low-power-auto"),
+            Self::Always => fl!("low-power-always"),
+            Self::Never => fl!("low-power-never"),
+        }
+    }
+
+    /// Check if background work is cut back in `power`.
+    pub fn is_active(self, power: PowerState) -> bool {
+        match self {
+            Self::Auto => power.is_low(),
+            Self::Always => true,
+            Self::Never => false,
+        }
+    }
+}
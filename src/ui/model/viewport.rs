@@ -0,0 +1,292 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/viewport.rs
+//
+// Viewport: zoom, pan and the rendered canvas.
+
+use std::time::Duration;
+
+use cosmic::iced::Size;
+
+use super::ViewMode;
+use crate::application::commands::crop_document::CropDocumentCommand;
+use crate::application::session::DocumentView;
+use crate::domain::document::operations::CropRegion;
+use crate::ui::widgets::CropSelection;
+
+/// Seamless texture preview shown instead of the plain image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TilePreview {
+    #[default]
+    Off,
+    /// Image repeated 3×3, center highlighted
+    Grid,
+    /// Image shifted by half its size, seams in the middle
+    Offset,
+}
+
+impl TilePreview {
+    /// Next mode when cycling with the `t` key or footer button.
+    pub fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Grid,
+            Self::Grid => Self::Offset,
+            Self::Offset => Self::Off,
+        }
+    }
+}
+
+/// Half of the page shown in sheet-music mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HalfPage {
+    Upper,
+    Lower,
+}
+
+/// Viewport state - zoom, pan, canvas dimensions.
+#[derive(Debug, Clone)]
+pub struct Viewport {
+    /// Current scale factor
+    pub scale: f32,
+
+    /// Pan offset X
+    pub pan_x: f32,
+
+    /// Pan offset Y
+    pub pan_y: f32,
+
+    /// Canvas size (container)
+    pub canvas_size: Size,
+
+    /// Image size (after scaling)
+    pub image_size: Size,
+
+    /// Fit mode
+    pub fit_mode: ViewMode,
+
+    /// Pixel-art display: nearest-neighbor scaling and integer zoom steps
+    pub pixel_art: bool,
+
+    /// Physical pixels per logical pixel of the window (1.25 at 125 %)
+    pub scale_factor: f32,
+
+    /// Temporary view rotation in clockwise quarter turns (document untouched)
+    pub view_rotation: u8,
+
+    /// Show the alpha channel as a grayscale mask instead of the image
+    pub alpha_mask: bool,
+
+    /// Seamless texture preview mode
+    pub tile_preview: TilePreview,
+
+    /// Magnifier: zoomed in, panning follows the mouse pointer
+    pub magnifier: bool,
+
+    /// Sheet-music mode: page turners move by half a page (None = off)
+    pub half_page: Option<HalfPage>,
+
+    /// Scroll container ID
+    pub scroll_id: cosmic::widget::Id,
+
+    /// Cached image handle for rendering (updated when document or scale changes)
+    pub cached_image_handle: Option<cosmic::widget::image::Handle>,
+
+    /// Display handle of the last document render and the id of its core image
+    pub render_source: Option<(u64, cosmic::widget::image::Handle)>,
+
+    /// Tile preview, sprite grid or sharpened fit view, shown instead of the
+    /// cached image outside crop mode
+    pub preview_handle: Option<cosmic::widget::image::Handle>,
+
+    /// Sharpen large images scaled down in the fit view (from the settings)
+    pub sharpen: bool,
+
+    /// Number of the last canvas resize (sharpened copies of older sizes are skipped)
+    pub sharpen_revision: u64,
+
+    /// The cached image is the thumbnail embedded in a photo still being decoded
+    pub embedded_preview: bool,
+
+    /// Vector document rendered sharper for the zoom, and the scale it is at
+    pub zoom_render: Option<(f64, cosmic::widget::image::Handle)>,
+
+    /// Scale of the zoom render shown or under way (None = not needed)
+    pub zoom_target: Option<f64>,
+
+    /// Recent zoom renders with the raster they were made for (image id and
+    /// whether color management was on) and their scale, oldest first
+    pub zoom_cache: Vec<((u64, bool), f64, cosmic::widget::image::Handle)>,
+
+    /// Number of the last zoom render scheduled (older ones are dropped)
+    pub zoom_revision: u64,
+}
+
+impl Default for Viewport {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            pan_x: 0.0,
+            pan_y: 0.0,
+            canvas_size: Size::ZERO,
+            image_size: Size::ZERO,
+            fit_mode: ViewMode::Fit,
+            pixel_art: false,
+            scale_factor: 1.0,
+            view_rotation: 0,
+            alpha_mask: false,
+            tile_preview: TilePreview::Off,
+            magnifier: false,
+            half_page: None,
+            scroll_id: cosmic::widget::Id::new("canvas-scroll"),
+            cached_image_handle: None,
+            render_source: None,
+            preview_handle: None,
+            sharpen: false,
+            sharpen_revision: 0,
+            embedded_preview: false,
+            zoom_render: None,
+            zoom_target: None,
+            zoom_cache: Vec::new(),
+            zoom_revision: 0,
+        }
+    }
+}
+
+impl Viewport {
+    /// Magnifications selectable for the magnifier mode.
+    pub const MAGNIFIER_ZOOMS: [f32; 5] = [2.0, 3.0, 4.0, 6.0, 8.0];
+
+    /// How long the canvas size has to rest before the sharpened copy is redone.
+    pub const SHARPEN_DELAY: Duration = Duration::from_millis(150);
+
+    /// How long the zoom has to rest before a vector document is rendered for it.
+    pub const ZOOM_RENDER_DELAY: Duration = Duration::from_millis(200);
+
+    /// Rest before a zoom render in low-power mode, so that a zoom gesture
+    /// with pauses renders once.
+    pub const LOW_POWER_ZOOM_RENDER_DELAY: Duration = Duration::from_millis(800);
+
+    /// Number of recent zoom renders kept for zooming back to their scale.
+    pub const ZOOM_CACHE_SIZE: usize = 3;
+
+    /// A recent zoom render of the raster `key` at `scale`.
+    #[must_use]
+    pub fn cached_zoom(
+        &self,
+        key: (u64, bool),
+        scale: f64,
+    ) -> Option<cosmic::widget::image::Handle> {
+        self.zoom_cache
+            .iter()
+            .find(|(cached, cached_scale, _)| *cached == key && (cached_scale - scale).abs() < 1e-3)
+            .map(|(_, _, handle)| handle.clone())
+    }
+
+    /// Keep a zoom render, dropping the oldest beyond `ZOOM_CACHE_SIZE`.
+    pub fn cache_zoom(
+        &mut self,
+        key: (u64, bool),
+        scale: f64,
+        handle: cosmic::widget::image::Handle,
+    ) {
+        self.zoom_cache.retain(|(cached, cached_scale, _)| {
+            *cached != key || (cached_scale - scale).abs() >= 1e-3
+        });
+        self.zoom_cache.push((key, scale, handle));
+        let excess = self.zoom_cache.len().saturating_sub(Self::ZOOM_CACHE_SIZE);
+        self.zoom_cache.drain(..excess);
+    }
+
+    /// Screen pixels per image pixel, with the fit modes worked out.
+    #[must_use]
+    pub fn display_scale(&self) -> f32 {
+        // A quarter turn shows the image the other way around
+        let (width, height) = if self.view_rotation % 2 == 1 {
+            (self.image_size.height, self.image_size.width)
+        } else {
+            (self.image_size.width, self.image_size.height)
+        };
+        if width <= 0.0 || height <= 0.0 {
+            return self.scale;
+        }
+        let fit = match self.fit_mode {
+            ViewMode::Fit => (self.canvas_size.width / width).min(self.canvas_size.height / height),
+            ViewMode::FitWidth => self.canvas_size.width / width,
+            // Fill stretches unevenly; the larger factor needs the most pixels
+            ViewMode::Cover | ViewMode::Fill => {
+                (self.canvas_size.width / width).max(self.canvas_size.height / height)
+            }
+            ViewMode::ActualSize | ViewMode::Custom => 1.0,
+        };
+        fit * self.scale
+    }
+
+    /// Region of the image under a canvas selection, in pixels.
+    #[must_use]
+    pub fn image_region(&self, selection: &CropSelection) -> Option<CropRegion> {
+        let cmd = CropDocumentCommand::from_canvas_selection(
+            selection.canvas_rect()?,
+            self.canvas_size,
+            self.image_size,
+            self.scale,
+            cosmic::iced::Vector::new(self.pan_x, self.pan_y),
+        )
+        .ok()?;
+        Some(CropRegion::new(cmd.x, cmd.y, cmd.width, cmd.height))
+    }
+
+    /// The view kept in a session, showing page `page`.
+    pub fn document_view(&self, page: usize) -> DocumentView {
+        DocumentView {
+            page,
+            fit: (self.fit_mode != ViewMode::Custom).then(|| self.fit_mode.key().to_string()),
+            scale: self.scale,
+            pan: (self.pan_x, self.pan_y),
+            rotation: self.view_rotation,
+        }
+    }
+
+    /// Show a view kept in a session (its page is turned to separately).
+    pub fn apply_document_view(&mut self, view: &DocumentView) {
+        self.fit_mode = view
+            .fit
+            .as_deref()
+            .and_then(ViewMode::from_key)
+            .unwrap_or(ViewMode::Custom);
+        if self.fit_mode == ViewMode::Custom {
+            self.scale = view.scale.clamp(0.1, 10.0);
+        }
+        self.pan_x = view.pan.0;
+        self.pan_y = view.pan.1;
+        self.view_rotation = view.rotation % 4;
+    }
+
+    /// Reset pan to center
+    pub fn reset_pan(&mut self) {
+        self.pan_x = 0.0;
+        self.pan_y = 0.0;
+    }
+
+    /// Pan to the upper or lower half of a page of the given size, shown at
+    /// the full window width. The lower half starts half a page below the top,
+    /// so the music seen last before a turn stays on screen.
+    pub fn show_half_page(&mut self, half: HalfPage, (width, height): (u32, u32)) {
+        self.half_page = Some(half);
+        self.fit_mode = ViewMode::FitWidth;
+        self.pan_x = 0.0;
+        if width == 0 {
+            self.pan_y = 0.0;
+            return;
+        }
+        let page_height = self.canvas_size.width * height as f32 / width as f32 * self.scale;
+        let max_offset = ((page_height - self.canvas_size.height) / 2.0).max(0.0);
+        self.pan_y = match half {
+            HalfPage::Upper => -max_offset,
+            HalfPage::Lower => (page_height / 2.0 - max_offset).min(max_offset),
+        };
+    }
+
+    /// Whether the lower half of the page is not fully in view yet.
+    pub fn can_show_lower_half(&self) -> bool {
+        self.half_page == Some(HalfPage::Upper) && self.pan_y < 0.0
+    }
+}
@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/model/wallpaper.rs
+//
+// Wallpaper assistant.
+
+/// Screen shapes offered by the wallpaper assistant (width, height).
+pub const WALLPAPER_RATIOS: [(u32, u32); 8] = [
+    (16, 9),
+    (16, 10),
+    (21, 9),
+    (32, 9),
+    (4, 3),
+    (3, 2),
+    (5, 4),
+    (9, 16),
+];
@@ -0,0 +1,58 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/screen_cover.rs
+//
+// Screen cover: black surfaces that hide the other screens while presenting.
+
+use cosmic::cctk::sctk::shell::wlr_layer::{Anchor, KeyboardInteractivity, Layer};
+use cosmic::iced::platform_specific::runtime::wayland::layer_surface::{
+    IcedOutput, SctkLayerSurfaceSettings,
+};
+use cosmic::iced::platform_specific::shell::commands::layer_surface::{
+    destroy_layer_surface, get_layer_surface,
+};
+use cosmic::iced::widget::container as iced_container;
+use cosmic::iced::{window, Background, Color, Length};
+use cosmic::widget::{container, horizontal_space};
+use cosmic::{Action, Element, Task};
+
+use super::message::AppMessage;
+
+/// Cover every screen with black.
+///
+/// The cover sits in the top layer, which compositors draw below fullscreen
+/// windows: the screen showing the document stays visible, all others go dark
+/// instead of showing the desktop. Returns the surface id and the task opening it.
+pub fn open() -> (window::Id, Task<Action<AppMessage>>) {
+    let id = window::Id::unique();
+    let task = get_layer_surface(SctkLayerSurfaceSettings {
+        id,
+        layer: Layer::Top,
+        keyboard_interactivity: KeyboardInteractivity::None,
+        anchor: Anchor::all(),
+        output: IcedOutput::All,
+        namespace: "noctua-screen-cover".to_string(),
+        size: Some((None, None)),
+        exclusive_zone: -1,
+        ..Default::default()
+    });
+    (id, task)
+}
+
+/// Remove the cover again.
+pub fn close(id: window::Id) -> Task<Action<AppMessage>> {
+    destroy_layer_surface(id)
+}
+
+/// Content of the cover surfaces.
+pub fn view() -> Element<'static, AppMessage> {
+    container(horizontal_space())
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .class(cosmic::theme::Container::custom(|_| {
+            iced_container::Style {
+                background: Some(Background::Color(Color::BLACK)),
+                ..Default::default()
+            }
+        }))
+        .into()
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/adjust.rs
+//
+// Color adjustments.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use super::render::cache_render;
+use crate::domain::document::operations::adjust::Adjustments;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AdjustState, AppModel};
+
+/// Change the color adjustments and preview them.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SetAdjustment(adjustment, value) => {
+            app.model.adjust.values.set(*adjustment, *value);
+            return preview_adjustments_later(&mut app.model.adjust);
+        }
+
+        AppMessage::ResetAdjustment(adjustment) => {
+            app.model
+                .adjust
+                .values
+                .set(*adjustment, adjustment.neutral());
+            apply_adjustments(&mut app.model, &mut app.document_manager);
+        }
+
+        AppMessage::ResetAdjustments => {
+            app.model.adjust.values = Adjustments::default();
+            apply_adjustments(&mut app.model, &mut app.document_manager);
+        }
+
+        AppMessage::SetThreshold(threshold) => {
+            app.model.adjust.values.threshold = *threshold;
+            return preview_adjustments_later(&mut app.model.adjust);
+        }
+
+        AppMessage::ApplyAdjustments(revision) => {
+            if *revision == app.model.adjust.revision {
+                apply_adjustments(&mut app.model, &mut app.document_manager);
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Show the changed adjustments once the slider rests, not on every step.
+fn preview_adjustments_later(adjust: &mut AdjustState) -> UpdateResult {
+    adjust.revision += 1;
+    let revision = adjust.revision;
+    UpdateResult::Task(Task::perform(
+        tokio::time::sleep(AdjustState::PREVIEW_DELAY),
+        move |()| Action::App(AppMessage::ApplyAdjustments(revision)),
+    ))
+}
+
+/// Show the slider values of the adjustments panel on the image.
+fn apply_adjustments(model: &mut AppModel, manager: &mut crate::application::DocumentManager) {
+    // A pending preview of an earlier slider position must not follow
+    model.adjust.revision += 1;
+    let Some(doc) = manager.current_document_mut() else {
+        return;
+    };
+    if doc.adjustments() != model.adjust.values {
+        doc.set_adjustments(model.adjust.values);
+        cache_render(model, manager);
+    }
+}
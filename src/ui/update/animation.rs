@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/animation.rs
+//
+// Animation playback, frame export and animation export.
+
+use super::{UpdateResult, open_folder_dialog, save_file_dialog};
+use crate::application::commands::export_animation::ExportAnimationCommand;
+use crate::domain::document::operations::animation::{self, AnimationFormat};
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::AnimationState;
+
+/// Play animations and export their frames.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ToggleAnimationPlayback => {
+            if let Some(player) = app.model.animation.player.as_mut() {
+                player.toggle(std::time::Instant::now());
+            }
+        }
+
+        AppMessage::AnimationTick(now) => {
+            let current = app.document_manager.current_path();
+            if let Some(player) = app.model.animation.player.as_mut() {
+                if current == Some(player.path.as_path()) {
+                    player.tick(*now);
+                } else {
+                    // Another document was opened meanwhile
+                    player.next_frame_at = None;
+                }
+            }
+        }
+
+        AppMessage::SetAnimationSpeed(index) => {
+            if let Some(player) = app.model.animation.player.as_mut() {
+                player.set_speed(*index, std::time::Instant::now());
+            }
+        }
+
+        AppMessage::SeekAnimation(index) => {
+            if let Some(player) = app.model.animation.player.as_mut() {
+                player.seek(*index as usize);
+            }
+        }
+
+        AppMessage::AnimationStepInput(input) => {
+            app.model.animation.step_input = input.clone();
+        }
+
+        AppMessage::StepAnimation(forward) => {
+            let step = app
+                .model
+                .animation
+                .step()
+                .and_then(|n| isize::try_from(n).ok());
+            if let Some(frames) = step
+                && let Some(player) = app.model.animation.player.as_mut()
+            {
+                player.step(if *forward { frames } else { -frames });
+            }
+        }
+
+        AppMessage::AnimationFrameInput(input) => {
+            app.model.animation.frame_input = input.clone();
+        }
+
+        AppMessage::SaveAnimationFrame => {
+            if let Some(index) = app.model.animation.frame_index()
+                && let Some(path) = app.document_manager.current_path()
+            {
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-save-frame"),
+                    animation::frame_file_name(path, index),
+                    AppMessage::AnimationFrameSaveTo,
+                ));
+            }
+        }
+
+        AppMessage::AnimationFrameSaveTo(target) => {
+            if let Some(index) = app.model.animation.frame_index()
+                && let Some(path) = app.document_manager.current_path()
+            {
+                let result = animation::decode_frames(path).and_then(|frames| {
+                    let frame = frames
+                        .get(index)
+                        .ok_or_else(|| anyhow::anyhow!("Frame {} not found", index + 1))?;
+                    animation::save_frame(frame, target)
+                });
+                match result {
+                    Ok(()) => {
+                        app.model.animation.status =
+                            Some(fl!("animation-frame-saved", frame: index + 1));
+                    }
+                    Err(e) => app.model.set_error(format!("Failed to save frame: {e}")),
+                }
+            }
+        }
+
+        AppMessage::ExtractAnimationFrames => {
+            if app.model.animation.frame_count.is_some() {
+                return UpdateResult::Task(open_folder_dialog(
+                    fl!("dialog-extract-frames"),
+                    AppMessage::AnimationFramesExportTo,
+                ));
+            }
+        }
+
+        AppMessage::AnimationFramesExportTo(output_dir) => {
+            if let Some(path) = app.document_manager.current_path() {
+                match animation::extract_frames(path, output_dir) {
+                    Ok(count) => {
+                        app.model.animation.status =
+                            Some(fl!("animation-frames-done", count: count));
+                    }
+                    Err(e) => app.model.set_error(format!("Frame extraction failed: {e}")),
+                }
+            }
+        }
+
+        AppMessage::AnimationFpsInput(input) => {
+            app.model.animation.fps_input = input.clone();
+        }
+
+        AppMessage::AnimationLoopsInput(input) => {
+            app.model.animation.loops_input = input.clone();
+        }
+
+        AppMessage::SetAnimationFormat(index) => {
+            if let Some(format) = AnimationFormat::ALL.get(*index) {
+                app.model.animation.format = *format;
+            }
+        }
+
+        AppMessage::ExportAnimation => {
+            if let Some(path) = app.document_manager.current_path()
+                && app.model.animation.settings().is_some()
+            {
+                // Name after the sequence, without the frame number
+                let stem = path
+                    .file_stem()
+                    .map(|s| s.to_string_lossy())
+                    .unwrap_or_default();
+                let name = stem.trim_end_matches(|c: char| c.is_ascii_digit() || "_- ".contains(c));
+                let extension = app.model.animation.format.extension();
+                let file_name = if name.is_empty() {
+                    format!("animation.{extension}")
+                } else {
+                    format!("{name}-animated.{extension}")
+                };
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-export-animation"),
+                    file_name,
+                    AppMessage::AnimationExportTo,
+                ));
+            }
+        }
+
+        AppMessage::AnimationExportTo(target) => {
+            let sources = AnimationState::sources(
+                app.document_manager.folder_entries(),
+                app.document_manager.current_path(),
+            );
+            let Some(settings) = app.model.animation.settings() else {
+                return UpdateResult::None;
+            };
+            if sources.is_empty() {
+                return UpdateResult::None;
+            }
+            // A typed extension wins over the selected format
+            let format = AnimationFormat::from_path(target).unwrap_or(app.model.animation.format);
+            match ExportAnimationCommand::new(sources, settings, format, target.clone()).execute() {
+                Ok(count) => {
+                    app.model.animation.status = Some(fl!("animation-done", count: count));
+                }
+                Err(e) => app.model.set_error(format!("Animation export failed: {e}")),
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
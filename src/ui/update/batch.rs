@@ -0,0 +1,149 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/batch.rs
+//
+// Batch conversion of the selected images.
+
+use std::path::PathBuf;
+
+use cosmic::{Action, Task};
+
+use super::{UpdateResult, open_folder_dialog};
+use crate::application::services::batch_convert::{self, BatchEvent, BatchJob};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::domain::document::operations::alpha::AlphaMode;
+use crate::domain::document::operations::export::ExportFormat;
+use crate::domain::document::operations::export_profile::ExportProfile;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::BatchState;
+
+/// Convert images in the background.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::BatchFitInput(input) => {
+            app.model.batch.fit_input = input.clone();
+        }
+
+        AppMessage::ConvertImages => {
+            if !app.document_manager.folder_entries().is_empty() {
+                return UpdateResult::Task(open_folder_dialog(
+                    fl!("dialog-batch-convert"),
+                    AppMessage::BatchConvertTo,
+                ));
+            }
+        }
+
+        AppMessage::BatchConvertTo(output_dir) => {
+            let Some(job) = batch_job(app, output_dir.clone()) else {
+                return UpdateResult::None;
+            };
+            // Waits for the running job
+            let state = &mut app.model.batch;
+            if state.is_running() {
+                state.queue.push(job);
+            } else {
+                state.written = 0;
+                state.failures.clear();
+                state.status = None;
+                return UpdateResult::Task(start_batch(state, job));
+            }
+        }
+
+        AppMessage::BatchProgress(event) => {
+            let state = &mut app.model.batch;
+            match event {
+                BatchEvent::Progress(done, total) => state.progress = Some((*done, *total)),
+                BatchEvent::Failed(path, reason) => {
+                    state.failures.push((path.clone(), reason.clone()));
+                }
+                BatchEvent::Finished(written) => {
+                    state.progress = None;
+                    state.written += written;
+                    let cancelled = state
+                        .cancel
+                        .take()
+                        .is_some_and(|token| token.is_cancelled());
+                    if cancelled {
+                        state.queue.clear();
+                        state.status = Some(fl!("batch-cancelled", count: state.written));
+                    } else if let Some(job) = state.queue.take_next() {
+                        return UpdateResult::Task(start_batch(state, job));
+                    } else {
+                        state.status = Some(fl!(
+                            "batch-done",
+                            count: state.written,
+                            failed: state.failures.len()
+                        ));
+                    }
+                }
+            }
+        }
+
+        AppMessage::CancelBatch => {
+            if let Some(token) = &app.model.batch.cancel {
+                token.cancel();
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Conversion of the selected folder images (all of them without a
+/// selection) into `output_dir`, with the chosen profile or the format, size
+/// and quality of the batch panel (None without images or with an invalid size).
+fn batch_job(app: &NoctuaApp, output_dir: PathBuf) -> Option<BatchJob> {
+    let paths = if app.model.selection.is_empty() {
+        app.document_manager.folder_entries().to_vec()
+    } else {
+        app.model.selection.paths().to_vec()
+    };
+    if paths.is_empty() {
+        return None;
+    }
+
+    let state = &app.model.export_profiles;
+    let (profile, keep_format) = match state.profile(&app.config) {
+        Some(profile) => (profile, false),
+        None => {
+            let alpha = if state.premultiplied {
+                AlphaMode::Premultiplied
+            } else {
+                AlphaMode::Straight
+            };
+            let profile = ExportProfile {
+                format: state.format.unwrap_or(ExportFormat::Png),
+                fit: app.model.batch.fit().ok()?,
+                quality: state.quality,
+                alpha,
+                ..ExportProfile::default()
+            };
+            (profile, state.format.is_none())
+        }
+    };
+    Some(BatchJob {
+        paths,
+        output_dir,
+        // Also overrides "metadata keep" of a profile
+        profile: ExportProfile {
+            keep_metadata: profile.keep_metadata && !state.strip_metadata,
+            ..profile
+        },
+        keep_format,
+        limits: OpenLimits::new(app.config.max_image_megapixels, app.config.max_page_size),
+    })
+}
+
+/// Run `job`, reporting its progress to the batch panel.
+fn start_batch(state: &mut BatchState, job: BatchJob) -> Task<Action<AppMessage>> {
+    let token = CancelToken::new();
+    state.progress = Some((0, job.paths.len()));
+    state.cancel = Some(token.clone());
+    Task::run(batch_convert::convert_in_background(job, token), |event| {
+        Action::App(AppMessage::BatchProgress(event))
+    })
+}
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/catalog.rs
+//
+// Exporting the metadata catalog of a folder.
+
+use cosmic::{Action, Task};
+
+use super::{UpdateResult, save_file_dialog};
+use crate::application::services::folder_catalog::{self, CatalogEvent};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Export the catalog of the folder in the background.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ExportCatalog => {
+            if !app.model.catalog.is_running() && !app.document_manager.folder_entries().is_empty()
+            {
+                let file_name = app
+                    .document_manager
+                    .current_path()
+                    .and_then(|p| p.parent()?.file_name())
+                    .map(|s| format!("{}.csv", s.to_string_lossy()))
+                    .unwrap_or_else(|| "catalog.csv".to_string());
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-export-catalog"),
+                    file_name,
+                    AppMessage::ExportCatalogTo,
+                ));
+            }
+        }
+
+        AppMessage::ExportCatalogTo(target) => {
+            let paths = app.document_manager.folder_entries().to_vec();
+            if app.model.catalog.is_running() || paths.is_empty() {
+                return UpdateResult::None;
+            }
+            let token = CancelToken::new();
+            let state = &mut app.model.catalog;
+            state.progress = Some((0, paths.len()));
+            state.cancel = Some(token.clone());
+            state.status = None;
+            return UpdateResult::Task(Task::run(
+                folder_catalog::export_in_background(paths, target.clone(), token),
+                |event| Action::App(AppMessage::CatalogProgress(event)),
+            ));
+        }
+
+        AppMessage::CatalogProgress(event) => {
+            let state = &mut app.model.catalog;
+            match event {
+                CatalogEvent::Progress(done, total) => state.progress = Some((*done, *total)),
+                CatalogEvent::Finished(result) => {
+                    state.progress = None;
+                    let cancelled = state
+                        .cancel
+                        .take()
+                        .is_some_and(|token| token.is_cancelled());
+                    match result {
+                        Ok(_) if cancelled => state.status = Some(fl!("catalog-cancelled")),
+                        Ok(count) => state.status = Some(fl!("catalog-done", count: count)),
+                        Err(e) => app.model.set_error(format!("Folder catalog failed: {e}")),
+                    }
+                }
+            }
+        }
+
+        AppMessage::CancelCatalog => {
+            if let Some(token) = &app.model.catalog.cancel {
+                token.cancel();
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
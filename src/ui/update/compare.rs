@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/compare.rs
+//
+// Comparing two documents side by side.
+
+use std::path::Path;
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use super::render::{cache_render, color_managed_handle};
+use crate::application::document_manager::display_handle;
+use crate::domain::document::core::content::DocumentContent;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, CompareSide, Comparison, ViewLayout, ViewMode};
+
+/// Pick, load and arrange the compared documents.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ToggleCompare => {
+            if matches!(app.model.layout, ViewLayout::Compare(_)) {
+                app.model.layout = ViewLayout::Single;
+            } else if app.model.strip.is_none()
+                && matches!(app.model.mode, AppMode::View)
+                && let Some(current) = app.document_manager.current_path()
+            {
+                let edited = app.model.history.can_undo() || !app.model.adjust.values.is_neutral();
+                let entries = app.document_manager.folder_entries();
+                let b_path = Comparison::default_b(entries, current, edited);
+                app.model.layout = ViewLayout::Compare(Comparison::new(b_path.clone(), entries));
+                let result = load_compare_slot(app, &b_path);
+                if matches!(result, UpdateResult::None) {
+                    app.model.layout = ViewLayout::Single;
+                }
+                return result;
+            }
+        }
+
+        AppMessage::AssignCompareSlot(side, index) => {
+            if let Some(path) = app.document_manager.folder_entries().get(*index).cloned() {
+                // Slot A is the current document
+                return match side {
+                    CompareSide::A => super::update(app, &AppMessage::OpenPath(path)),
+                    CompareSide::B => load_compare_slot(app, &path),
+                };
+            }
+        }
+
+        AppMessage::CompareSlotLoaded(decoded) => {
+            // Dropped if another file was put into the slot meanwhile
+            if let ViewLayout::Compare(comparison) = &mut app.model.layout
+                && decoded.path() == comparison.b_path
+            {
+                match decoded.take() {
+                    Some(Ok(image)) => {
+                        let doc = DocumentContent::Raster(image.document);
+                        let handle = doc.handle();
+                        comparison.b_handle = handle
+                            .as_ref()
+                            .and_then(|handle| {
+                                color_managed_handle(
+                                    &mut app.model.color,
+                                    doc.icc_profile(),
+                                    handle,
+                                )
+                            })
+                            .or_else(|| handle.as_ref().map(display_handle));
+                    }
+                    Some(Err(e)) => {
+                        tracing::warn!("Failed to decode {}: {e}", decoded.path().display());
+                        app.model.layout = ViewLayout::Single;
+                        app.model.set_error(fl!("compare-load-failed"));
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        AppMessage::SwapCompareSlots => {
+            if let ViewLayout::Compare(comparison) = &app.model.layout
+                && let Some(current) = app.document_manager.current_path()
+                && current != comparison.b_path
+            {
+                let (a_path, b_path) = (comparison.b_path.clone(), current.to_path_buf());
+                let result = load_compare_slot(app, &b_path);
+                if matches!(result, UpdateResult::Task(_)) {
+                    super::update(app, &AppMessage::OpenPath(a_path));
+                }
+                return result;
+            }
+        }
+
+        AppMessage::SetCompareMode(mode) => {
+            if let ViewLayout::Compare(comparison) = &mut app.model.layout {
+                comparison.mode = *mode;
+            }
+        }
+
+        AppMessage::SetCompareDivider(divider) => {
+            if let ViewLayout::Compare(comparison) = &mut app.model.layout {
+                comparison.divider = divider.clamp(0.0, 1.0);
+            }
+        }
+
+        AppMessage::CompareViewChanged {
+            scale,
+            offset_x,
+            offset_y,
+        } => {
+            let old_scale = app.model.viewport.scale;
+            app.model.viewport.scale = *scale;
+            app.model.viewport.pan_x = *offset_x;
+            app.model.viewport.pan_y = *offset_y;
+            if (old_scale - *scale).abs() > 0.001 {
+                app.model.viewport.fit_mode = ViewMode::Custom;
+                cache_render(&mut app.model, &mut app.document_manager);
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Put `path` into compare slot B, shown once it is decoded in the
+/// background. The slot keeps its file if `path` is not an image.
+pub(super) fn load_compare_slot(app: &mut NoctuaApp, path: &Path) -> UpdateResult {
+    let ViewLayout::Compare(comparison) = &mut app.model.layout else {
+        return UpdateResult::None;
+    };
+    let Some(job) = app.document_manager.begin_compare_decode(path) else {
+        app.model.set_error(fl!("compare-unsupported"));
+        return UpdateResult::None;
+    };
+    comparison.b_path = path.to_path_buf();
+    comparison.b_handle = None;
+    UpdateResult::Task(Task::perform(job.decode(), |decoded| {
+        Action::App(AppMessage::CompareSlotLoaded(decoded))
+    }))
+}
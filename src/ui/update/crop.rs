@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/crop.rs
+//
+// Cropping and analysing a selection.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use super::render::cache_render;
+use crate::application::commands::crop_document::CropDocumentCommand;
+use crate::application::edit_history::EditOperation;
+use crate::domain::document::operations::edit_macro::MacroStep;
+use crate::domain::document::operations::{CropRegion, region_stats};
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, SelectionStats, ViewMode};
+use crate::ui::widgets::{CropSelection, DragHandle};
+
+/// Select, analyse and crop a region.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ToggleCropMode => {
+            app.model.mode = match &app.model.mode {
+                AppMode::Crop { .. } => AppMode::View,
+                _ => AppMode::Crop {
+                    selection: CropSelection::default(),
+                },
+            };
+        }
+
+        AppMessage::CropGeometryInput(input) => {
+            app.model.crop_geometry_input = input.clone();
+        }
+
+        AppMessage::ApplyCropGeometry => {
+            let Some(doc) = app.document_manager.current_document() else {
+                return UpdateResult::None;
+            };
+            let (width, height) = doc.dimensions();
+            match CropRegion::parse_geometry(&app.model.crop_geometry_input) {
+                Some(region) if region.fits_within(width, height) => {
+                    let viewport = &app.model.viewport;
+                    let canvas_rect =
+                        CropDocumentCommand::new(region.x, region.y, region.width, region.height)
+                            .to_canvas_selection(
+                                viewport.canvas_size,
+                                viewport.image_size,
+                                viewport.scale,
+                                cosmic::iced::Vector::new(viewport.pan_x, viewport.pan_y),
+                            );
+
+                    app.model.mode = AppMode::Crop {
+                        selection: CropSelection::from_region(canvas_rect),
+                    };
+                }
+                Some(_) => app.model.set_error("Crop region lies outside the image"),
+                None => app.model.set_error("Invalid crop dimensions"),
+            }
+        }
+
+        AppMessage::AnalyzeSelection => {
+            let AppMode::Crop { selection } = &app.model.mode else {
+                return UpdateResult::None;
+            };
+            let (Some(region), Some(doc)) = (
+                app.model.viewport.image_region(selection),
+                app.document_manager.current_document(),
+            ) else {
+                return UpdateResult::None;
+            };
+            // Only the selected pixels go to the worker thread
+            let image = doc.rendered_image();
+            let pixels = image.crop_imm(region.x, region.y, region.width, region.height);
+            return UpdateResult::Task(Task::perform(
+                tokio::task::spawn_blocking(move || {
+                    let whole = CropRegion::new(0, 0, pixels.width(), pixels.height());
+                    region_stats::region_stats(&pixels, whole)
+                }),
+                move |stats| {
+                    Action::App(AppMessage::SelectionAnalyzed(region, stats.ok().flatten()))
+                },
+            ));
+        }
+
+        AppMessage::SelectionAnalyzed(region, stats) => match stats {
+            Some(stats) => {
+                app.model.selection_stats = Some(SelectionStats::new(*region, stats.clone()));
+            }
+            None => app.model.set_error(fl!("selection-analyze-failed")),
+        },
+
+        AppMessage::StartCrop => {
+            if app.document_manager.current_document().is_some() {
+                app.model.mode = AppMode::Crop {
+                    selection: CropSelection::default(),
+                };
+            }
+        }
+
+        AppMessage::CancelCrop => {
+            // Escape answers the trash dialog first
+            if app.model.delete_confirm.take().is_some() {
+                return UpdateResult::None;
+            }
+            // Only cancel if actually in Crop or text selection mode
+            if matches!(
+                app.model.mode,
+                AppMode::Crop { .. } | AppMode::SelectText { .. }
+            ) {
+                app.model.mode = AppMode::View;
+            } else {
+                // Outside of the tools Escape closes the find bar
+                app.model.find = None;
+            }
+        }
+
+        AppMessage::ApplyCrop => {
+            if let AppMode::Crop { selection } = &app.model.mode {
+                // Get crop selection region
+                if let Some(canvas_rect) = selection.canvas_rect() {
+                    // Create crop command from canvas selection
+                    let pan_offset = cosmic::iced::Vector::new(
+                        app.model.viewport.pan_x,
+                        app.model.viewport.pan_y,
+                    );
+
+                    match CropDocumentCommand::from_canvas_selection(
+                        canvas_rect,
+                        app.model.viewport.canvas_size,
+                        app.model.viewport.image_size,
+                        app.model.viewport.scale,
+                        pan_offset,
+                    ) {
+                        Ok(cmd) => {
+                            let dimensions = app
+                                .document_manager
+                                .current_document()
+                                .map(|d| d.dimensions());
+                            // Execute crop command
+                            if let Err(e) = cmd.execute(&mut app.document_manager) {
+                                app.model.set_error(format!("Crop failed: {e}"));
+                            } else {
+                                app.model.history.record(
+                                    EditOperation::Crop(CropRegion::new(
+                                        cmd.x, cmd.y, cmd.width, cmd.height,
+                                    )),
+                                    app.document_manager.current_page(),
+                                );
+                                if let Some(steps) = &mut app.model.macros.recording
+                                    && let Some((width, height)) = dimensions
+                                {
+                                    let rect = (cmd.x, cmd.y, cmd.width, cmd.height);
+                                    steps.extend(MacroStep::crop_from_pixels(rect, width, height));
+                                }
+                                // Success - exit crop mode
+                                app.model.mode = AppMode::View;
+                                // Reset view to fit the cropped image
+                                app.model.viewport.scale = 1.0;
+                                app.model.viewport.fit_mode = ViewMode::Fit;
+                                app.model.reset_pan();
+                                cache_render(&mut app.model, &mut app.document_manager);
+                            }
+                        }
+                        Err(e) => {
+                            app.model.set_error(format!("Invalid crop region: {e}"));
+                        }
+                    }
+                } else {
+                    app.model.set_error("No crop region selected".to_string());
+                }
+            }
+        }
+
+        AppMessage::CropDragStart { x, y, handle } => {
+            if let AppMode::Crop { selection } = &mut app.model.mode {
+                if *handle == DragHandle::None {
+                    selection.start_new_selection(*x, *y);
+                } else {
+                    selection.start_handle_drag(*handle, *x, *y);
+                }
+            }
+        }
+
+        AppMessage::CropDragMove { x, y, max_x, max_y } => {
+            if let AppMode::Crop { selection } = &mut app.model.mode {
+                selection.update_drag(*x, *y, *max_x, *max_y);
+            }
+        }
+
+        AppMessage::CropDragEnd => {
+            if let AppMode::Crop { selection } = &mut app.model.mode {
+                selection.end_drag();
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/date_shift.rs
+//
+// Shifting the capture dates of the folder.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use crate::application::commands::date_shift::{DateShiftTarget, ShiftDatesCommand};
+use crate::application::services::date_shift::{self, DateShiftEvent};
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Shift the capture dates in the background.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::DateShiftInput(input) => {
+            app.model.date_shift.offset_input = input.clone();
+        }
+
+        AppMessage::SetDateShiftSidecar(sidecar) => {
+            app.model.date_shift.sidecar = *sidecar;
+        }
+
+        AppMessage::ApplyDateShift => {
+            let state = &mut app.model.date_shift;
+            // Every dated file of the folder is rewritten, so ask first
+            if state.offset().is_some_and(|o| o != 0)
+                && !state.entries.is_empty()
+                && !state.is_running()
+            {
+                state.confirm = true;
+            }
+        }
+
+        AppMessage::ConfirmDateShift => {
+            let state = &mut app.model.date_shift;
+            if !std::mem::take(&mut state.confirm) || state.is_running() {
+                return UpdateResult::None;
+            }
+            if let Some(offset) = state.offset() {
+                let target = if state.sidecar {
+                    DateShiftTarget::Sidecar
+                } else {
+                    DateShiftTarget::File
+                };
+                let entries = state.entries.clone();
+                state.progress = Some((0, entries.len()));
+                state.status = None;
+                let command = ShiftDatesCommand::new(offset, target);
+                return UpdateResult::Task(Task::run(
+                    date_shift::shift_in_background(command, entries),
+                    |event| Action::App(AppMessage::DateShiftProgress(event)),
+                ));
+            }
+        }
+
+        AppMessage::CancelDateShift => {
+            app.model.date_shift.confirm = false;
+        }
+
+        AppMessage::DateShiftProgress(event) => match event {
+            DateShiftEvent::Progress(done, total) => {
+                app.model.date_shift.progress = Some((*done, *total));
+            }
+            DateShiftEvent::Finished(result) => {
+                app.model.date_shift.progress = None;
+                match result {
+                    Ok(count) => {
+                        let folder = app.document_manager.folder_entries().to_vec();
+                        let state = &mut app.model.date_shift;
+                        state.load(&folder);
+                        state.offset_input.clear();
+                        state.status = Some(fl!("date-shift-done", count: count));
+                        // The properties panel shows the new date
+                        app.document_manager.refresh_metadata();
+                    }
+                    Err(e) => app.model.set_error(format!("Date shift failed: {e}")),
+                }
+            }
+        },
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/export_profile.rs
+//
+// Export profiles.
+
+use super::UpdateResult;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Pick and edit export profiles.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SelectExportProfile(index) => {
+            app.model.export_profiles.selected = index
+                .checked_sub(1)
+                .and_then(|index| app.config.export_profiles.keys().nth(index).cloned());
+        }
+
+        AppMessage::ExportProfileNameInput(input) => {
+            app.model.export_profiles.name_input = input.clone();
+        }
+
+        AppMessage::ExportProfileSettingsInput(input) => {
+            app.model.export_profiles.settings_input = input.clone();
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
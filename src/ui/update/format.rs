@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/format.rs
+//
+// Paper format and orientation of the transform mode.
+
+use super::UpdateResult;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::AppMode;
+
+/// Enter the transform mode and pick its paper format.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ToggleScaleMode => {
+            // Scale mode -> Transform mode
+            app.model.mode = match &app.model.mode {
+                AppMode::Transform { .. } => AppMode::View,
+                _ => AppMode::Transform {
+                    paper_format: None,
+                    orientation: Default::default(),
+                    straighten: Default::default(),
+                },
+            };
+        }
+
+        AppMessage::SetPaperFormat(format) => {
+            if let AppMode::Transform { paper_format, .. } = &mut app.model.mode {
+                *paper_format = Some(*format);
+            }
+        }
+
+        AppMessage::SetOrientation(orientation) => {
+            if let AppMode::Transform {
+                orientation: ori, ..
+            } = &mut app.model.mode
+            {
+                *ori = *orientation;
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/geotag.rs
+//
+// Geotag editor.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use crate::application::commands::geotag::GeotagCommand;
+use crate::application::services::geotag::{self, GeotagEvent};
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Set, clear and show the location of images.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::GeotagInput(input) => {
+            app.model.geotag.input = input.clone();
+        }
+
+        AppMessage::ApplyGeotag | AppMessage::ClearGeotag => {
+            let location = match msg {
+                AppMessage::ApplyGeotag => app.model.geotag.location(),
+                _ => None,
+            };
+            if let Some(path) = app.document_manager.current_path()
+                && !app.model.read_only
+                && (location.is_some() || matches!(msg, AppMessage::ClearGeotag))
+            {
+                let paths = vec![path.to_path_buf()];
+                return run_geotag(app, GeotagCommand::new(location, paths));
+            }
+        }
+
+        AppMessage::ApplyGeotagToSelected => {
+            if let Some(location) = app.model.geotag.location() {
+                let paths = app.model.selection.paths().to_vec();
+                return run_geotag(app, GeotagCommand::new(Some(location), paths));
+            }
+        }
+
+        AppMessage::GeotagProgress(event) => {
+            let state = &mut app.model.geotag;
+            match event {
+                GeotagEvent::Progress(done, total) => state.progress = Some((*done, *total)),
+                GeotagEvent::Failed(path, reason) => {
+                    state.failures.push((path.clone(), reason.clone()));
+                }
+                GeotagEvent::Finished(result) => {
+                    state.progress = None;
+                    match result {
+                        Ok(count) => state.status = Some(fl!("geotag-done", count: count)),
+                        Err(e) => app.model.set_error(format!("Geotag failed: {e}")),
+                    }
+                    // The properties panel shows the new location
+                    app.document_manager.refresh_metadata();
+                }
+            }
+        }
+
+        AppMessage::ShowGeotagOnMap => {
+            if let Some(location) = app.model.geotag.location()
+                && let Err(e) = open::that_detached(location.map_url())
+            {
+                app.model.set_error(format!("Failed to open map: {e}"));
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Write GPS tags in the background, reporting to the geotag editor.
+fn run_geotag(app: &mut NoctuaApp, cmd: GeotagCommand) -> UpdateResult {
+    let state = &mut app.model.geotag;
+    if state.is_running() {
+        return UpdateResult::None;
+    }
+    state.progress = Some((0, cmd.paths.len()));
+    state.failures.clear();
+    state.status = None;
+    UpdateResult::Task(Task::run(geotag::write_in_background(cmd), |event| {
+        Action::App(AppMessage::GeotagProgress(event))
+    }))
+}
@@ -0,0 +1,160 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/history.rs
+//
+// Edit history: undo, redo and replaying stored edits.
+
+use std::path::Path;
+
+use super::UpdateResult;
+use super::render::cache_render;
+use crate::application::commands::transform_document::{TransformDocumentCommand, TransformOperation};
+use crate::application::edit_history::EditOperation;
+use crate::application::edit_sidecar;
+use crate::domain::document::core::document::TransformState;
+use crate::domain::document::operations::adjust::Adjustments;
+use crate::domain::document::operations::xmp_edits;
+use crate::infrastructure::filesystem::file_ops;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, AppModel, ViewMode};
+
+/// Step through the edit history.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::Undo => {
+            if !matches!(app.model.mode, AppMode::Crop { .. }) && app.model.history.can_undo() {
+                let result = app.model.history.undo(&mut app.document_manager);
+                finish_history_step(&mut app.model, &mut app.document_manager, result);
+            }
+        }
+
+        AppMessage::Redo => {
+            if !matches!(app.model.mode, AppMode::Crop { .. }) && app.model.history.can_redo() {
+                let result = app.model.history.redo(&mut app.document_manager);
+                finish_history_step(&mut app.model, &mut app.document_manager, result);
+            }
+        }
+
+        AppMessage::RevertHistory(count) => {
+            if !matches!(app.model.mode, AppMode::Crop { .. }) {
+                let result = app
+                    .model
+                    .history
+                    .revert_to(*count, &mut app.document_manager);
+                finish_history_step(&mut app.model, &mut app.document_manager, result);
+            }
+        }
+
+        AppMessage::ToggleOrientationLock => {
+            // The turns and flips of the page shown, as one net state
+            app.model.orientation_lock = match app.model.orientation_lock {
+                Some(_) => None,
+                None => Some(
+                    app.model
+                        .history
+                        .applied_on(app.document_manager.current_page())
+                        .fold(
+                            TransformState::default(),
+                            |state, operation| match operation {
+                                EditOperation::Transform(transform) => transform.apply_to(state),
+                                _ => state,
+                            },
+                        ),
+                ),
+            };
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Replay saved edits on a freshly opened document: those of its edit
+/// sidecar if it has one, else those of another tool's XMP sidecar (if
+/// `import_xmp` is on), otherwise the locked transforms.
+///
+/// Also starts a new edit history, so the replayed edits can be undone, and
+/// checks if the file can be saved in place.
+pub(super) fn apply_open_edits(
+    model: &mut AppModel,
+    manager: &mut crate::application::DocumentManager,
+    import_xmp: bool,
+) {
+    model.history.clear();
+    model.xmp_sidecar = None;
+    model.adjust.values = Adjustments::default();
+    model.read_only = false;
+    let Some(path) = manager.current_path().map(Path::to_path_buf) else {
+        return;
+    };
+    model.read_only = !file_ops::is_writable(&path);
+    if model.read_only {
+        tracing::info!("{} is read-only", path.display());
+    }
+
+    if let Some(stored) = edit_sidecar::load(&path) {
+        replay_edits(model, manager, stored.edits);
+        model.adjust.values = stored.adjustments;
+        if let Some(doc) = manager.current_document_mut() {
+            doc.set_adjustments(stored.adjustments);
+        }
+        return;
+    }
+    if import_xmp
+        && let Some((sidecar, xmp)) = xmp_edits::read(&path)
+        && let Some(doc) = manager.current_document()
+    {
+        let applied = u16::from(doc.applied_orientation());
+        let edits = edit_sidecar::from_xmp(xmp, doc.dimensions(), applied);
+        // Nothing left to do if the sidecar only repeats the EXIF orientation
+        if !edits.is_empty() {
+            tracing::info!("Applying edits from {}", sidecar.display());
+            replay_edits(model, manager, edits);
+            model.xmp_sidecar = Some(sidecar);
+            return;
+        }
+    }
+
+    let Some(lock) = model.orientation_lock else {
+        return;
+    };
+    for operation in TransformOperation::sequence_for(lock) {
+        if let Err(e) = TransformDocumentCommand::new(operation).execute(manager) {
+            tracing::warn!("Failed to apply orientation lock: {e}");
+            break;
+        }
+        model
+            .history
+            .record(EditOperation::Transform(operation), manager.current_page());
+    }
+}
+
+/// Apply stored edits and record them in the edit history.
+fn replay_edits(
+    model: &mut AppModel,
+    manager: &mut crate::application::DocumentManager,
+    edits: Vec<EditOperation>,
+) {
+    for operation in edits {
+        if let Err(e) = operation.execute(manager) {
+            tracing::warn!("Failed to apply saved edits: {e}");
+            break;
+        }
+        model.history.record(operation, manager.current_page());
+    }
+}
+
+/// Re-render after undo, redo or a history revert (or report its failure).
+fn finish_history_step(
+    model: &mut AppModel,
+    manager: &mut crate::application::DocumentManager,
+    result: crate::domain::document::core::document::DocResult<()>,
+) {
+    if let Err(e) = result {
+        model.set_error(format!("Failed to restore history state: {e}"));
+    }
+    model.viewport.fit_mode = ViewMode::Fit;
+    model.reset_pan();
+    cache_render(model, manager);
+}
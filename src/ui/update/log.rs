@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/log.rs
+//
+// Log viewer.
+
+use super::UpdateResult;
+use crate::infrastructure::logging;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Refresh and copy the recent log.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::RefreshLog => {
+            app.model.log_entries = logging::recent::entries();
+        }
+
+        AppMessage::CopyLog => {
+            let entries = &app.model.log_entries;
+            if !entries.is_empty() {
+                let lines: Vec<String> = entries.iter().map(ToString::to_string).collect();
+                return UpdateResult::Task(cosmic::iced::clipboard::write(lines.join("\n")));
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
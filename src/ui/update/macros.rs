@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/macros.rs
+//
+// Recording and running edit macros.
+
+use cosmic::{Action, Task};
+
+use super::{UpdateResult, open_folder_dialog};
+use crate::application::commands::run_macro::RunMacroCommand;
+use crate::application::services::macro_run::{self, MacroEvent};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::operations::edit_macro::{self, MacroStep};
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Record edit macros and run them.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::StartMacroRecording => {
+            app.model.macros.recording = Some(Vec::new());
+            app.model.macros.status = None;
+        }
+
+        AppMessage::CancelMacroRecording => {
+            app.model.macros.recording = None;
+        }
+
+        AppMessage::MacroStepInput(input) => {
+            app.model.macros.step_input = input.clone();
+        }
+
+        AppMessage::AddMacroStep => {
+            if let Some(steps) = &mut app.model.macros.recording
+                && let Some(step) = MacroStep::parse(&app.model.macros.step_input)
+            {
+                steps.push(step);
+                app.model.macros.step_input.clear();
+            }
+        }
+
+        AppMessage::RemoveMacroStep(index) => {
+            if let Some(steps) = &mut app.model.macros.recording
+                && *index < steps.len()
+            {
+                steps.remove(*index);
+            }
+        }
+
+        AppMessage::MacroNameInput(input) => {
+            app.model.macros.name_input = input.clone();
+        }
+
+        AppMessage::RunMacro(name) | AppMessage::RunMacroOnFolder(name) => {
+            if app.config.macros.contains_key(name) && !app.model.macros.is_running() {
+                let whole_folder = matches!(msg, AppMessage::RunMacroOnFolder(_));
+                app.model.macros.pending = Some((name.clone(), whole_folder));
+                return UpdateResult::Task(open_folder_dialog(
+                    fl!("dialog-macro-output"),
+                    AppMessage::MacroExportTo,
+                ));
+            }
+        }
+
+        AppMessage::MacroExportTo(output_dir) => {
+            let Some((name, whole_folder)) = app.model.macros.pending.take() else {
+                return UpdateResult::None;
+            };
+            let steps = match app
+                .config
+                .macros
+                .get(&name)
+                .map(|s| edit_macro::parse_steps(s))
+            {
+                Some(Ok(steps)) => steps,
+                Some(Err(step)) => {
+                    app.model.set_error(format!("Invalid macro step: {step}"));
+                    return UpdateResult::None;
+                }
+                None => return UpdateResult::None,
+            };
+            let paths = if whole_folder {
+                app.document_manager.folder_entries().to_vec()
+            } else {
+                app.document_manager
+                    .current_path()
+                    .map(|p| p.to_path_buf())
+                    .into_iter()
+                    .collect()
+            };
+
+            let cmd = RunMacroCommand::new(steps, paths, output_dir.clone())
+                .with_profile(app.model.export_profiles.profile(&app.config));
+            let token = CancelToken::new();
+            let state = &mut app.model.macros;
+            state.progress = Some((0, cmd.paths.len()));
+            state.cancel = Some(token.clone());
+            state.running = Some(name);
+            state.status = None;
+            return UpdateResult::Task(Task::run(
+                macro_run::run_in_background(cmd, token),
+                |event| Action::App(AppMessage::MacroProgress(event)),
+            ));
+        }
+
+        AppMessage::MacroProgress(event) => {
+            let state = &mut app.model.macros;
+            match event {
+                MacroEvent::Progress(done, total) => state.progress = Some((*done, *total)),
+                MacroEvent::Finished(result) => {
+                    state.progress = None;
+                    let cancelled = state
+                        .cancel
+                        .take()
+                        .is_some_and(|token| token.is_cancelled());
+                    let name = state.running.take().unwrap_or_default();
+                    match result {
+                        Ok(count) if cancelled => {
+                            state.status = Some(fl!("macro-cancelled", name: name, count: count));
+                        }
+                        Ok(count) => {
+                            state.status = Some(fl!("macro-done", name: name, count: count));
+                        }
+                        Err(e) => app.model.set_error(format!("Macro failed: {e}")),
+                    }
+                }
+            }
+        }
+
+        AppMessage::CancelMacro => {
+            if let Some(token) = &app.model.macros.cancel {
+                token.cancel();
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
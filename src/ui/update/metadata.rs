@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/metadata.rs
+//
+// Metadata panel, browser and editor.
+
+use super::UpdateResult;
+use super::resize::current_size;
+use crate::application::commands::edit_metadata::EditMetadataCommand;
+use crate::domain::document::core::metadata;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::views::meta_panel;
+
+/// Show, copy and edit the metadata of the document.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::CopyMetadata(json) => {
+            if let Some(meta) = app.document_manager.current_metadata() {
+                let fields = meta.summary(current_size(&app.document_manager));
+                let summary = if *json {
+                    metadata::summary_json(&fields)
+                } else {
+                    meta_panel::summary_text(&fields)
+                };
+                return UpdateResult::Task(cosmic::iced::clipboard::write(summary));
+            }
+        }
+
+        AppMessage::CopyGpsCoordinates => {
+            if let Some(gps) = app
+                .document_manager
+                .current_metadata()
+                .and_then(|meta| meta.exif.as_ref()?.gps_display())
+            {
+                return UpdateResult::Task(cosmic::iced::clipboard::write(gps));
+            }
+        }
+
+        AppMessage::OpenGpsInMap => {
+            // xdg-open hands geo: URIs to the map application of the desktop
+            if let Some(uri) = app
+                .document_manager
+                .current_metadata()
+                .and_then(|meta| meta.exif.as_ref()?.geo_uri())
+                && let Err(e) = open::that_detached(uri)
+            {
+                app.model.set_error(format!("Failed to open map: {e}"));
+            }
+        }
+
+        AppMessage::MetadataTagsRead(path, groups) => {
+            // Navigated to another image meanwhile
+            let browser = &mut app.model.metadata_browser;
+            if browser.path.as_ref() == Some(path) {
+                browser.groups = Some(groups.clone());
+            }
+        }
+
+        AppMessage::MetadataFilterInput(input) => {
+            app.model.metadata_browser.filter_input = input.clone();
+        }
+
+        AppMessage::CopyMetadataValue(value) => {
+            return UpdateResult::Task(cosmic::iced::clipboard::write(value.clone()));
+        }
+
+        AppMessage::MetadataAuthorInput(input) => {
+            app.model.metadata_edit.author = input.clone();
+        }
+
+        AppMessage::MetadataCopyrightInput(input) => {
+            app.model.metadata_edit.copyright = input.clone();
+        }
+
+        AppMessage::MetadataDescriptionInput(input) => {
+            app.model.metadata_edit.description = input.clone();
+        }
+
+        AppMessage::MetadataDateInput(input) => {
+            app.model.metadata_edit.date_input = input.clone();
+        }
+
+        AppMessage::SetMetadataRating(stars) => {
+            // Clicking the current rating again clears it
+            let edit = &mut app.model.metadata_edit;
+            edit.rating = if edit.rating == *stars { 0 } else { *stars };
+        }
+
+        AppMessage::SaveMetadataEdits => {
+            if let Some(path) = app.document_manager.current_path()
+                && app.model.metadata_edit.path.as_deref() == Some(path)
+                && !app.model.read_only
+                && let Some(fields) = app.model.metadata_edit.fields()
+            {
+                let cmd = EditMetadataCommand::new(fields, path.to_path_buf());
+                match cmd.execute(&mut app.document_manager) {
+                    Ok(()) => {
+                        app.model.metadata_edit.status = Some(fl!("metadata-edit-saved"));
+                        app.model.metadata_browser.clear();
+                    }
+                    Err(e) => app.model.set_error(format!("Failed to save metadata: {e}")),
+                }
+            }
+        }
+
+        AppMessage::RefreshMetadata => {
+            app.document_manager.refresh_metadata();
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,498 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/mod.rs
+//
+// Application update loop: applies messages to the global model state, one
+// submodule per feature.
+
+mod adjust;
+mod animation;
+mod batch;
+mod catalog;
+mod compare;
+mod crop;
+mod date_shift;
+mod export_profile;
+mod format;
+mod geotag;
+mod history;
+mod log;
+mod macros;
+mod metadata;
+mod navigation;
+mod page_export;
+mod presentation;
+mod privacy;
+mod render;
+mod resize;
+mod save;
+mod scan_cleanup;
+mod search;
+mod selection;
+mod session;
+mod share;
+mod sprite_sheet;
+mod straighten;
+mod text_selection;
+mod transform;
+mod upload;
+mod view;
+mod wallpaper;
+
+use std::path::PathBuf;
+
+use cosmic::{Action, Task};
+
+use super::NoctuaApp;
+use super::message::AppMessage;
+use crate::fl;
+use crate::infrastructure::loaders::registry;
+
+pub(super) use render::{cache_render, sync_page_refine, sync_zoom_render};
+pub(super) use view::{apply_low_power, reading_order_key, reset_view};
+pub(super) use wallpaper::wallpaper_frame;
+
+// =============================================================================
+// Update Result
+// =============================================================================
+
+#[allow(dead_code)]
+pub enum UpdateResult {
+    None,
+    Task(Task<Action<AppMessage>>),
+}
+
+// =============================================================================
+// Main Update Function
+// =============================================================================
+
+pub fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::OpenPath(_)
+        | AppMessage::OpenFileDialog
+        | AppMessage::NextDocument
+        | AppMessage::PrevDocument
+        | AppMessage::DocumentDecoded(_)
+        | AppMessage::DocumentPreviewed(..)
+        | AppMessage::NavigateLeft
+        | AppMessage::NavigateRight
+        | AppMessage::PageLeft
+        | AppMessage::PageRight
+        | AppMessage::TurnPage(_)
+        | AppMessage::ToggleHalfPageTurn
+        | AppMessage::GotoPage(_)
+        | AppMessage::TogglePageBookmark
+        | AppMessage::RescanFolder
+        | AppMessage::FolderScanned(..)
+        | AppMessage::DeleteCurrentFile
+        | AppMessage::ConfirmDelete
+        | AppMessage::CancelDelete
+        | AppMessage::OpenRecentFile(_)
+        | AppMessage::ThumbnailReady(..)
+        | AppMessage::RecentThumbnailReady(..) => navigation::update(app, msg),
+        AppMessage::PresentationTick(_)
+        | AppMessage::PresentationActivity
+        | AppMessage::CanvasActivity
+        | AppMessage::CanvasControlsTick(_)
+        | AppMessage::ToggleAutoAdvance => presentation::update(app, msg),
+        AppMessage::SearchInput(_)
+        | AppMessage::RunSearch
+        | AppMessage::FindHeadings
+        | AppMessage::HeadingsProgress(_)
+        | AppMessage::CancelFindHeadings
+        | AppMessage::CloseFindBar
+        | AppMessage::FindInput(_)
+        | AppMessage::FindShift(_)
+        | AppMessage::FindSubmit
+        | AppMessage::FindNext
+        | AppMessage::FindPrevious => search::update(app, msg),
+        AppMessage::PowerStateChanged(_)
+        | AppMessage::ZoomIn
+        | AppMessage::ZoomOut
+        | AppMessage::RotateView
+        | AppMessage::TogglePixelArt
+        | AppMessage::ToggleAlphaMask
+        | AppMessage::CycleTilePreview
+        | AppMessage::ToggleMagnifier
+        | AppMessage::ToggleStripMode
+        | AppMessage::StripScrolled { .. }
+        | AppMessage::ScrollScreen(_)
+        | AppMessage::ZoomReset
+        | AppMessage::ZoomFit
+        | AppMessage::ScaleFactorChanged(_)
+        | AppMessage::ViewerStateChanged { .. }
+        | AppMessage::RefreshSharpening(_)
+        | AppMessage::RenderZoom(_)
+        | AppMessage::ZoomRendered(..)
+        | AppMessage::PageRefined(_)
+        | AppMessage::PanLeft
+        | AppMessage::PanRight
+        | AppMessage::PanUp
+        | AppMessage::PanDown
+        | AppMessage::PanReset
+        | AppMessage::ZoomBookmarkNameInput(_)
+        | AppMessage::GoToZoomBookmark(_) => view::update(app, msg),
+        AppMessage::SaveSession
+        | AppMessage::SaveSessionTo(_)
+        | AppMessage::OpenSessionDialog
+        | AppMessage::OpenSession(_) => session::update(app, msg),
+        AppMessage::ToggleCompare
+        | AppMessage::AssignCompareSlot(..)
+        | AppMessage::CompareSlotLoaded(_)
+        | AppMessage::SwapCompareSlots
+        | AppMessage::SetCompareMode(_)
+        | AppMessage::SetCompareDivider(_)
+        | AppMessage::CompareViewChanged { .. } => compare::update(app, msg),
+        AppMessage::ToggleCropMode
+        | AppMessage::CropGeometryInput(_)
+        | AppMessage::ApplyCropGeometry
+        | AppMessage::AnalyzeSelection
+        | AppMessage::SelectionAnalyzed(..)
+        | AppMessage::StartCrop
+        | AppMessage::CancelCrop
+        | AppMessage::ApplyCrop
+        | AppMessage::CropDragStart { .. }
+        | AppMessage::CropDragMove { .. }
+        | AppMessage::CropDragEnd => crop::update(app, msg),
+        AppMessage::ToggleScaleMode
+        | AppMessage::SetPaperFormat(_)
+        | AppMessage::SetOrientation(_) => format::update(app, msg),
+        AppMessage::ToggleTextSelectMode
+        | AppMessage::TextDragStart { .. }
+        | AppMessage::TextDragMove { .. }
+        | AppMessage::TextDragEnd
+        | AppMessage::CopySelectedText
+        | AppMessage::CopyAsSvg => text_selection::update(app, msg),
+        AppMessage::SaveAs
+        | AppMessage::SaveAsTo(_)
+        | AppMessage::ConfirmOverwrite
+        | AppMessage::CancelOverwrite
+        | AppMessage::SaveEditSidecar
+        | AppMessage::ExportIccProfile
+        | AppMessage::ExportAlphaMask
+        | AppMessage::SaveAlphaMask(_)
+        | AppMessage::SaveIccProfile(_)
+        | AppMessage::SetExportPremultiplied(_)
+        | AppMessage::SelectSaveFormat(_)
+        | AppMessage::SetSaveQuality(_)
+        | AppMessage::FormatAdviceReady(..)
+        | AppMessage::SetStripMetadata(_)
+        | AppMessage::UseFormatAdvice => save::update(app, msg),
+        AppMessage::CopyMetadata(_)
+        | AppMessage::CopyGpsCoordinates
+        | AppMessage::OpenGpsInMap
+        | AppMessage::MetadataTagsRead(..)
+        | AppMessage::MetadataFilterInput(_)
+        | AppMessage::CopyMetadataValue(_)
+        | AppMessage::MetadataAuthorInput(_)
+        | AppMessage::MetadataCopyrightInput(_)
+        | AppMessage::MetadataDescriptionInput(_)
+        | AppMessage::MetadataDateInput(_)
+        | AppMessage::SetMetadataRating(_)
+        | AppMessage::SaveMetadataEdits
+        | AppMessage::RefreshMetadata => metadata::update(app, msg),
+        AppMessage::ExportCatalog
+        | AppMessage::ExportCatalogTo(_)
+        | AppMessage::CatalogProgress(_)
+        | AppMessage::CancelCatalog => catalog::update(app, msg),
+        AppMessage::ShareTo(_)
+        | AppMessage::ShareFinished(_)
+        | AppMessage::ShareNameInput(_)
+        | AppMessage::ShareTargetInput(_) => share::update(app, msg),
+        AppMessage::Upload
+        | AppMessage::CancelUpload
+        | AppMessage::UploadEndpointInput(_)
+        | AppMessage::CopyUploadLink(_)
+        | AppMessage::OpenUploadLink(_) => upload::update(app, msg),
+        AppMessage::FlipHorizontal
+        | AppMessage::FlipVertical
+        | AppMessage::RotateCW
+        | AppMessage::RotateCCW => transform::update(app, msg),
+        AppMessage::Undo
+        | AppMessage::Redo
+        | AppMessage::RevertHistory(_)
+        | AppMessage::ToggleOrientationLock => history::update(app, msg),
+        AppMessage::DateShiftInput(_)
+        | AppMessage::SetDateShiftSidecar(_)
+        | AppMessage::ApplyDateShift
+        | AppMessage::ConfirmDateShift
+        | AppMessage::CancelDateShift
+        | AppMessage::DateShiftProgress(_) => date_shift::update(app, msg),
+        AppMessage::SelectFolderImage(..)
+        | AppMessage::SelectAllFolderImages(_)
+        | AppMessage::SetSelectionMinRating(_)
+        | AppMessage::SelectionTagsInput(_)
+        | AppMessage::SelectMatchingImages
+        | AppMessage::MatchingImagesFound(_) => selection::update(app, msg),
+        AppMessage::GeotagInput(_)
+        | AppMessage::ApplyGeotag
+        | AppMessage::ClearGeotag
+        | AppMessage::ApplyGeotagToSelected
+        | AppMessage::ShowGeotagOnMap
+        | AppMessage::GeotagProgress(_) => geotag::update(app, msg),
+        AppMessage::AddPrivacyRegion
+        | AppMessage::RemovePrivacyRegion(_)
+        | AppMessage::ClearPrivacyRegions
+        | AppMessage::ExportPrivacyCopies
+        | AppMessage::PrivacyExportTo(_)
+        | AppMessage::PrivacyExportProgress(_) => privacy::update(app, msg),
+        AppMessage::BatchFitInput(_)
+        | AppMessage::ConvertImages
+        | AppMessage::BatchConvertTo(_)
+        | AppMessage::BatchProgress(_)
+        | AppMessage::CancelBatch => batch::update(app, msg),
+        AppMessage::StartMacroRecording
+        | AppMessage::CancelMacroRecording
+        | AppMessage::MacroStepInput(_)
+        | AppMessage::AddMacroStep
+        | AppMessage::RemoveMacroStep(_)
+        | AppMessage::MacroNameInput(_)
+        | AppMessage::RunMacro(_)
+        | AppMessage::RunMacroOnFolder(_)
+        | AppMessage::MacroExportTo(_)
+        | AppMessage::MacroProgress(_)
+        | AppMessage::CancelMacro => macros::update(app, msg),
+        AppMessage::SpriteGridInput(_)
+        | AppMessage::SetSpriteGridByCount(_)
+        | AppMessage::SetSpriteGridVisible(_)
+        | AppMessage::ExportSpriteCells
+        | AppMessage::SpriteCellsExportTo(_)
+        | AppMessage::ExportSpriteAtlas
+        | AppMessage::SaveSpriteAtlas(_) => sprite_sheet::update(app, msg),
+        AppMessage::ToggleAnimationPlayback
+        | AppMessage::AnimationTick(_)
+        | AppMessage::SetAnimationSpeed(_)
+        | AppMessage::SeekAnimation(_)
+        | AppMessage::AnimationStepInput(_)
+        | AppMessage::StepAnimation(_)
+        | AppMessage::AnimationFrameInput(_)
+        | AppMessage::SaveAnimationFrame
+        | AppMessage::AnimationFrameSaveTo(_)
+        | AppMessage::ExtractAnimationFrames
+        | AppMessage::AnimationFramesExportTo(_)
+        | AppMessage::AnimationFpsInput(_)
+        | AppMessage::AnimationLoopsInput(_)
+        | AppMessage::SetAnimationFormat(_)
+        | AppMessage::ExportAnimation
+        | AppMessage::AnimationExportTo(_) => animation::update(app, msg),
+        AppMessage::SelectExportProfile(_)
+        | AppMessage::ExportProfileNameInput(_)
+        | AppMessage::ExportProfileSettingsInput(_) => export_profile::update(app, msg),
+        AppMessage::SetAdjustment(..)
+        | AppMessage::ResetAdjustment(_)
+        | AppMessage::ResetAdjustments
+        | AppMessage::SetThreshold(_)
+        | AppMessage::ApplyAdjustments(_) => adjust::update(app, msg),
+        AppMessage::SetResizeUnit(_)
+        | AppMessage::ResizeWidthInput(_)
+        | AppMessage::ResizeHeightInput(_)
+        | AppMessage::SetResizeLockAspect(_)
+        | AppMessage::SetResizePaper(_)
+        | AppMessage::SetResizeOrientation(_)
+        | AppMessage::ResizeDpiInput(_)
+        | AppMessage::SetResampleFilter(_)
+        | AppMessage::ApplyResize => resize::update(app, msg),
+        AppMessage::SetScanCleanup(_) | AppMessage::ApplyScanCleanup => {
+            scan_cleanup::update(app, msg)
+        }
+        AppMessage::SetPageExportScope(_)
+        | AppMessage::SetPageExportFormat(_)
+        | AppMessage::SetPageExportQuality(_)
+        | AppMessage::PageExportDpiInput(_)
+        | AppMessage::ExportPages
+        | AppMessage::ExportPagesTo(_)
+        | AppMessage::PageExportProgress(_)
+        | AppMessage::CancelPageExport
+        | AppMessage::ExportDpiInput(_)
+        | AppMessage::ExportPagesInput(_) => page_export::update(app, msg),
+        AppMessage::SetStraightenAngle(_)
+        | AppMessage::SetStraightenEdges(_)
+        | AppMessage::SetStraightenFilter(_)
+        | AppMessage::ToggleStraightenLine
+        | AppMessage::StraightenLineStart { .. }
+        | AppMessage::StraightenLineMove { .. }
+        | AppMessage::StraightenLineEnd
+        | AppMessage::RotateGesture(_)
+        | AppMessage::RotateGestureEnd
+        | AppMessage::ApplyStraighten
+        | AppMessage::ResetStraighten => straighten::update(app, msg),
+        AppMessage::ApplyWallpaper
+        | AppMessage::SetWholeImageAsWallpaper
+        | AppMessage::WallpaperApplied(_) => wallpaper::update(app, msg),
+        AppMessage::RefreshLog | AppMessage::CopyLog => log::update(app, msg),
+
+        AppMessage::ToggleMainMenu => {
+            app.model.menu_open = !app.model.menu_open;
+            UpdateResult::None
+        }
+
+        AppMessage::ShowError(msg) => {
+            app.model.set_error(msg.clone());
+            UpdateResult::None
+        }
+
+        AppMessage::ClearError => {
+            app.model.clear_error();
+            UpdateResult::None
+        }
+
+        AppMessage::ToggleContextPage(_)
+        | AppMessage::ToggleNavBar
+        | AppMessage::SelectLeftPanel(_)
+        | AppMessage::ThumbnailsScrolled(_)
+        | AppMessage::OpenFormatPanel
+        | AppMessage::ExcludePatternsInput(_)
+        | AppMessage::ApplyExcludePatterns
+        | AppMessage::SvgFontsInput(_)
+        | AppMessage::ApplySvgFonts
+        | AppMessage::SetSvgFontSize(_)
+        | AppMessage::SetSvgTextToPath(_)
+        | AppMessage::SetSortOrder(_)
+        | AppMessage::SetColorManagement(_)
+        | AppMessage::SelectMonitorProfile(_)
+        | AppMessage::MonitorProfileDetected(_)
+        | AppMessage::SetDefaultViewMode(..)
+        | AppMessage::SetViewMode(_)
+        | AppMessage::SetIntegerZoom(_)
+        | AppMessage::SetFitSharpening(_)
+        | AppMessage::SetArrowsTurnPages(_)
+        | AppMessage::SetSidecarOnSave(_)
+        | AppMessage::SetImportXmpEdits(_)
+        | AppMessage::SetAutoOrient(_)
+        | AppMessage::SetConfirmDelete(_)
+        | AppMessage::ClearRecentFiles
+        | AppMessage::SetCoverOtherScreens(_)
+        | AppMessage::SetHideCursorInFullscreen(_)
+        | AppMessage::SetCanvasControls(_)
+        | AppMessage::SetCanvasBackground(_)
+        | AppMessage::CanvasColorInput(_)
+        | AppMessage::SetDocumentBackground(_)
+        | AppMessage::DocumentColorInput(_)
+        | AppMessage::SetSlideInterval(_)
+        | AppMessage::SetSlideshowLoop(_)
+        | AppMessage::SetSlideshowShuffle(_)
+        | AppMessage::SetSlideshowMinRating(_)
+        | AppMessage::SetSlideshowTags(_)
+        | AppMessage::ToggleSlideshow
+        | AppMessage::SlideshowFiltered(_)
+        | AppMessage::ToggleFullscreen
+        | AppMessage::SetRescanInterval(_)
+        | AppMessage::SetMagnifierZoom(_)
+        | AppMessage::SetMagnifierCrosshair(_)
+        | AppMessage::ToggleRightToLeft
+        | AppMessage::KioskKey(..)
+        | AppMessage::EnterCropGeometry
+        | AppMessage::SetAsWallpaper
+        | AppMessage::SetWallpaperRatio(_)
+        | AppMessage::OpenFindBar
+        | AppMessage::SaveMacro
+        | AppMessage::DeleteMacro(_)
+        | AppMessage::AddExportProfile
+        | AppMessage::DeleteExportProfile(_)
+        | AppMessage::AddShareTarget
+        | AppMessage::DeleteShareTarget(_)
+        | AppMessage::UploadProgress(_)
+        | AppMessage::SaveUploadEndpoint
+        | AppMessage::ClearUploadHistory
+        | AppMessage::AddZoomBookmark
+        | AppMessage::SetLowPowerMode(_)
+        | AppMessage::CheckScaleFactor
+        | AppMessage::DeleteZoomBookmark(_)
+        | AppMessage::SetLogLevel(..) => {
+            // These are handled in app.rs
+            UpdateResult::None
+        }
+
+        AppMessage::NoOp => UpdateResult::None,
+    }
+}
+
+// =============================================================================
+// Helper Functions
+// =============================================================================
+
+/// Show a portal "save file" dialog and map the chosen path to a message.
+fn save_file_dialog(
+    title: String,
+    file_name: String,
+    on_selected: fn(PathBuf) -> AppMessage,
+) -> Task<Action<AppMessage>> {
+    use cosmic::dialog::file_chooser;
+
+    Task::perform(
+        async move {
+            let dialog = file_chooser::save::Dialog::new()
+                .title(title)
+                .current_name(file_name);
+            match dialog.save_file().await {
+                Ok(response) => response.url().and_then(|url| url.to_file_path().ok()),
+                Err(e) => {
+                    tracing::warn!("Save dialog failed: {e}");
+                    None
+                }
+            }
+        },
+        move |path| Action::App(path.map_or(AppMessage::NoOp, on_selected)),
+    )
+}
+
+/// Filter of the portal "open file" dialog for the supported documents.
+fn documents_filter() -> cosmic::dialog::file_chooser::FileFilter {
+    let mut filter = cosmic::dialog::file_chooser::FileFilter::new(fl!("dialog-filter-supported"));
+    for backend in registry::BACKENDS {
+        for mime in backend.mime_types {
+            filter = filter.mimetype(*mime);
+        }
+        for extension in backend.extensions {
+            filter = filter.glob(format!("*.{extension}"));
+        }
+    }
+    filter
+}
+
+/// Show a portal "open file" dialog, limited to the files `filter` matches,
+/// and map the chosen path to a message.
+fn open_file_dialog(
+    title: String,
+    filter: cosmic::dialog::file_chooser::FileFilter,
+    on_selected: fn(PathBuf) -> AppMessage,
+) -> Task<Action<AppMessage>> {
+    use cosmic::dialog::file_chooser;
+
+    Task::perform(
+        async move {
+            let dialog = file_chooser::open::Dialog::new()
+                .title(title)
+                .filter(filter);
+            match dialog.open_file().await {
+                Ok(response) => response.url().to_file_path().ok(),
+                Err(e) => {
+                    tracing::warn!("Open dialog failed: {e}");
+                    None
+                }
+            }
+        },
+        move |path| Action::App(path.map_or(AppMessage::NoOp, on_selected)),
+    )
+}
+
+/// Show a portal "select folder" dialog and map the chosen path to a message.
+fn open_folder_dialog(
+    title: String,
+    on_selected: fn(PathBuf) -> AppMessage,
+) -> Task<Action<AppMessage>> {
+    use cosmic::dialog::file_chooser;
+
+    Task::perform(
+        async move {
+            let dialog = file_chooser::open::Dialog::new().title(title);
+            match dialog.open_folder().await {
+                Ok(response) => response.url().to_file_path().ok(),
+                Err(e) => {
+                    tracing::warn!("Folder dialog failed: {e}");
+                    None
+                }
+            }
+        },
+        move |path| Action::App(path.map_or(AppMessage::NoOp, on_selected)),
+    )
+}
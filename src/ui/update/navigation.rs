@@ -0,0 +1,422 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/navigation.rs
+//
+// Opening documents, moving between documents and pages, and rescanning and
+// deleting in the folder.
+
+use std::path::Path;
+
+use cosmic::{Action, Task};
+
+use super::history::apply_open_edits;
+use super::render::cache_render;
+use super::view::reset_view;
+use super::{UpdateResult, documents_filter, open_file_dialog};
+use crate::application::document_manager::{FolderSync, display_handle};
+use crate::application::session;
+use crate::domain::document::operations::strip;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, Comparison, HalfPage, ViewLayout};
+use crate::ui::widgets::CropSelection;
+
+/// Open, step through and delete documents.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::OpenPath(path) if session::is_session_file(path) => {
+            return super::update(app, &AppMessage::OpenSession(path.clone()));
+        }
+
+        AppMessage::OpenPath(path) => {
+            if let Err(e) = app.document_manager.open_document(path) {
+                app.model.set_error(format!("Failed to open document: {e}"));
+            } else {
+                reset_view(&mut app.model, &app.document_manager, &app.config);
+                apply_open_edits(
+                    &mut app.model,
+                    &mut app.document_manager,
+                    app.config.import_xmp_edits,
+                );
+                cache_render(&mut app.model, &mut app.document_manager);
+
+                // Auto-toggle nav bar for multi-page documents
+                app.update_nav_bar_for_document();
+            }
+        }
+
+        AppMessage::OpenFileDialog => {
+            return UpdateResult::Task(open_file_dialog(
+                fl!("dialog-open-file"),
+                documents_filter(),
+                AppMessage::OpenPath,
+            ));
+        }
+
+        AppMessage::NextDocument | AppMessage::PrevDocument => {
+            // Ignore navigation in Crop mode
+            if !matches!(app.model.mode, AppMode::Crop { .. })
+                && let Some(path) = app
+                    .document_manager
+                    .step(matches!(msg, AppMessage::NextDocument))
+            {
+                return load_stepped(app, &path);
+            }
+        }
+
+        AppMessage::DocumentDecoded(decoded) => {
+            // Decodes superseded by further navigation are dropped
+            match app.document_manager.finish_decode(decoded) {
+                Ok(true) => show_loaded(app),
+                Ok(false) => {}
+                Err(e) => {
+                    app.model.set_error(format!("Failed to open document: {e}"));
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+            }
+        }
+
+        AppMessage::DocumentPreviewed(path, thumbnail) => {
+            // Too late once the full image is shown or the user moved on
+            if app.document_manager.is_decoding(path) {
+                let viewport = &mut app.model.viewport;
+                viewport.cached_image_handle = Some(display_handle(thumbnail));
+                viewport.preview_handle = None;
+                viewport.embedded_preview = true;
+            }
+        }
+
+        AppMessage::NavigateLeft | AppMessage::NavigateRight => {
+            // Right-to-left reading turns the arrow directions around
+            let forward = matches!(msg, AppMessage::NavigateRight) != app.model.right_to_left;
+            let navigate = if app.config.arrows_turn_pages {
+                AppMessage::TurnPage(forward)
+            } else if forward {
+                AppMessage::NextDocument
+            } else {
+                AppMessage::PrevDocument
+            };
+            return super::update(app, &navigate);
+        }
+
+        AppMessage::PageLeft | AppMessage::PageRight => {
+            let forward = matches!(msg, AppMessage::PageRight) != app.model.right_to_left;
+            if let Some(page) = adjacent_page(&app.document_manager, forward) {
+                return super::update(app, &AppMessage::GotoPage(page));
+            }
+        }
+
+        AppMessage::TurnPage(forward) => {
+            // Page turners: next page, or the next file after the last page
+            if app.model.strip.is_some() {
+                return super::update(app, &AppMessage::ScrollScreen(*forward));
+            }
+            if let Some(half) = app.model.viewport.half_page {
+                return turn_half_page(app, half, *forward);
+            }
+            return super::update(app, &page_turn(&app.document_manager, *forward));
+        }
+
+        AppMessage::ToggleHalfPageTurn => {
+            if app.model.viewport.half_page.take().is_some() {
+                reset_view(&mut app.model, &app.document_manager, &app.config);
+            } else if let Some(doc) = app.document_manager.current_document() {
+                app.model.viewport.scale = 1.0;
+                app.model
+                    .viewport
+                    .show_half_page(HalfPage::Upper, doc.dimensions());
+            }
+        }
+
+        AppMessage::GotoPage(page) => {
+            if let Some(doc) = app.document_manager.current_document_mut() {
+                if let Err(e) = doc.go_to_page(*page) {
+                    tracing::error!("Failed to navigate to page {page}: {e}");
+                } else {
+                    if let AppMode::SelectText {
+                        selection,
+                        highlights,
+                    } = &mut app.model.mode
+                    {
+                        *selection = CropSelection::default();
+                        highlights.clear();
+                    }
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+            }
+        }
+
+        AppMessage::TogglePageBookmark => {
+            if let Some(path) = app.document_manager.current_path()
+                && let Some(doc) = app.document_manager.current_document()
+                && doc.is_multi_page()
+            {
+                let page = doc.current_page();
+                let pages = app
+                    .model
+                    .page_bookmarks
+                    .entry(path.to_path_buf())
+                    .or_default();
+                if let Some(idx) = pages.iter().position(|&p| p == page) {
+                    pages.remove(idx);
+                } else {
+                    pages.push(page);
+                    pages.sort_unstable();
+                }
+            }
+        }
+
+        AppMessage::RescanFolder => {
+            // A scan stuck on an unreachable network folder must not pile up
+            if !app.model.rescan_pending
+                && let Some((folder, scan)) = app.document_manager.folder_scanner()
+            {
+                app.model.rescan_pending = true;
+                return UpdateResult::Task(Task::perform(
+                    tokio::task::spawn_blocking(scan),
+                    move |paths| {
+                        Action::App(AppMessage::FolderScanned(folder, paths.ok().flatten()))
+                    },
+                ));
+            }
+        }
+
+        AppMessage::FolderScanned(folder, paths) => {
+            app.model.rescan_pending = false;
+            let current_folder = app.document_manager.current_path().and_then(|p| p.parent());
+            if current_folder != Some(folder.as_path()) {
+                // Navigated to another folder meanwhile
+                return UpdateResult::None;
+            }
+
+            let sync = app.document_manager.apply_folder_scan(paths.clone());
+            let unavailable = sync == FolderSync::Unavailable;
+            if unavailable != app.model.folder_unavailable {
+                if unavailable {
+                    tracing::warn!(
+                        folder = %folder.display(),
+                        "Folder unavailable, keeping the last list"
+                    );
+                } else {
+                    tracing::info!(folder = %folder.display(), "Folder available again");
+                }
+                app.model.folder_unavailable = unavailable;
+            }
+
+            if sync == FolderSync::CurrentRemoved {
+                reset_view(&mut app.model, &app.document_manager, &app.config);
+                apply_open_edits(
+                    &mut app.model,
+                    &mut app.document_manager,
+                    app.config.import_xmp_edits,
+                );
+                cache_render(&mut app.model, &mut app.document_manager);
+                app.update_nav_bar_for_document();
+            }
+            if matches!(sync, FolderSync::Changed | FolderSync::CurrentRemoved) {
+                sync_folder_entries(app);
+            }
+        }
+
+        AppMessage::DeleteCurrentFile => {
+            // Like navigation, ignored while cropping
+            if matches!(app.model.mode, AppMode::Crop { .. }) {
+                return UpdateResult::None;
+            }
+            if app.model.read_only {
+                app.model.set_error(fl!("read-only-delete"));
+            } else if let Some(path) = app.document_manager.current_path() {
+                app.model.delete_confirm = Some(path.to_path_buf());
+                if !app.config.confirm_delete {
+                    return UpdateResult::Task(Task::done(Action::App(AppMessage::ConfirmDelete)));
+                }
+            }
+        }
+
+        AppMessage::ConfirmDelete => {
+            // Only the file the dialog asked about
+            if let Some(path) = app.model.delete_confirm.take()
+                && app.document_manager.current_path() == Some(path.as_path())
+            {
+                return trash_current(app);
+            }
+        }
+
+        AppMessage::CancelDelete => {
+            app.model.delete_confirm = None;
+        }
+
+        AppMessage::OpenRecentFile(index) => {
+            if let Some(path) = app.config.recent_files.get(*index).cloned() {
+                return super::update(app, &AppMessage::OpenPath(path));
+            }
+        }
+
+        AppMessage::ThumbnailReady(path, page, handle) => {
+            // Thumbnails of a document navigated away from are dropped
+            app.document_manager
+                .add_thumbnail(path, *page, handle.clone());
+        }
+
+        AppMessage::RecentThumbnailReady(path, handle) => {
+            app.model
+                .recent_thumbnails
+                .insert(path.clone(), display_handle(handle));
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Load the file navigated to: images are decoded in the background, so
+/// holding an arrow key skims the folder; other documents open right away.
+pub(super) fn load_stepped(app: &mut NoctuaApp, path: &Path) -> UpdateResult {
+    if let Some(job) = app.document_manager.begin_decode(path) {
+        // Undo must not replay the edits of the previous file meanwhile
+        app.model.history.clear();
+        let path = path.to_path_buf();
+        let preview = Task::perform(job.preview(), move |thumbnail| {
+            Action::App(thumbnail.map_or(AppMessage::NoOp, |thumbnail| {
+                AppMessage::DocumentPreviewed(path, thumbnail)
+            }))
+        });
+        let decode = Task::perform(job.decode(), |decoded| {
+            Action::App(AppMessage::DocumentDecoded(decoded))
+        });
+        return UpdateResult::Task(Task::batch([preview, decode]));
+    }
+    match app.document_manager.open_document(path) {
+        Ok(()) => show_loaded(app),
+        Err(e) => tracing::warn!("Failed to open {}: {e}", path.display()),
+    }
+    UpdateResult::None
+}
+
+/// Move the current file to the trash and show the next one.
+fn trash_current(app: &mut NoctuaApp) -> UpdateResult {
+    let next = match app.document_manager.trash_current() {
+        Ok(next) => next,
+        Err(e) => {
+            app.model.set_error(e.to_string());
+            return UpdateResult::None;
+        }
+    };
+    sync_folder_entries(app);
+    match next {
+        Some(path) => load_stepped(app, &path),
+        None => {
+            // The last file of the folder is gone
+            reset_view(&mut app.model, &app.document_manager, &app.config);
+            apply_open_edits(
+                &mut app.model,
+                &mut app.document_manager,
+                app.config.import_xmp_edits,
+            );
+            cache_render(&mut app.model, &mut app.document_manager);
+            app.update_nav_bar_for_document();
+            UpdateResult::None
+        }
+    }
+}
+
+/// Bring the views listing the folder in line with its changed entries.
+pub(super) fn sync_folder_entries(app: &mut NoctuaApp) {
+    let entries = app.document_manager.folder_entries();
+    app.model.selection.retain_existing(entries);
+    if let Some(state) = app.model.strip.as_mut() {
+        state.pages = strip::load_pages(entries);
+    }
+    if let ViewLayout::Compare(comparison) = &mut app.model.layout {
+        comparison.names = Comparison::labels(entries);
+    }
+}
+
+/// Apply the view an opened session kept for the document just shown.
+fn restore_document_view(app: &mut NoctuaApp) {
+    let Some(view) = app
+        .document_manager
+        .current_path()
+        .and_then(|path| app.model.restore_views.remove(path))
+    else {
+        return;
+    };
+    if let Some(doc) = app.document_manager.current_document_mut()
+        && view.page != doc.current_page()
+        && view.page < doc.page_count()
+        && let Err(e) = doc.go_to_page(view.page)
+    {
+        tracing::warn!("Failed to turn to page {} of the session: {e}", view.page);
+    }
+    app.model.viewport.apply_document_view(&view);
+}
+
+/// Show a document reached by navigation with a fresh view.
+fn show_loaded(app: &mut NoctuaApp) {
+    // Reset zoom when navigating to new document
+    reset_view(&mut app.model, &app.document_manager, &app.config);
+    apply_open_edits(
+        &mut app.model,
+        &mut app.document_manager,
+        app.config.import_xmp_edits,
+    );
+    // ...unless a session kept one for it
+    restore_document_view(app);
+    cache_render(&mut app.model, &mut app.document_manager);
+
+    // Auto-toggle nav bar for multi-page documents
+    app.update_nav_bar_for_document();
+}
+
+/// Page before or after the current one in a multi-page document.
+fn adjacent_page(manager: &crate::application::DocumentManager, forward: bool) -> Option<usize> {
+    let doc = manager
+        .current_document()
+        .filter(|doc| doc.is_multi_page())?;
+    if forward {
+        Some(doc.current_page() + 1).filter(|&page| page < doc.page_count())
+    } else {
+        doc.current_page().checked_sub(1)
+    }
+}
+
+/// Message turning to the adjacent page, or to the adjacent file at the
+/// first or last page.
+fn page_turn(manager: &crate::application::DocumentManager, forward: bool) -> AppMessage {
+    match adjacent_page(manager, forward) {
+        Some(page) => AppMessage::GotoPage(page),
+        None if forward => AppMessage::NextDocument,
+        None => AppMessage::PrevDocument,
+    }
+}
+
+/// Sheet-music page turn: upper half, lower half, next page (and back).
+fn turn_half_page(app: &mut NoctuaApp, half: HalfPage, forward: bool) -> UpdateResult {
+    let dimensions = |app: &NoctuaApp| {
+        app.document_manager
+            .current_document()
+            .map_or((0, 0), |doc| doc.dimensions())
+    };
+
+    if forward && app.model.viewport.can_show_lower_half() {
+        let size = dimensions(app);
+        app.model.viewport.show_half_page(HalfPage::Lower, size);
+        return UpdateResult::None;
+    }
+    if !forward && half == HalfPage::Lower {
+        let size = dimensions(app);
+        app.model.viewport.show_half_page(HalfPage::Upper, size);
+        return UpdateResult::None;
+    }
+
+    let result = super::update(app, &page_turn(&app.document_manager, forward));
+    let size = dimensions(app);
+    let half = if forward {
+        HalfPage::Upper
+    } else {
+        HalfPage::Lower
+    };
+    app.model.viewport.show_half_page(half, size);
+    result
+}
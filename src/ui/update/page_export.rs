@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/page_export.rs
+//
+// Exporting pages as images.
+
+use std::path::PathBuf;
+
+use cosmic::{Action, Task};
+
+use super::{UpdateResult, open_folder_dialog};
+use crate::application::services::page_export::{self, PageExport, PageExportEvent};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Export pages in the background.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SetPageExportScope(scope) => {
+            app.model.page_export.scope = *scope;
+        }
+
+        AppMessage::SetPageExportFormat(format) => {
+            app.model.page_export.format = *format;
+        }
+
+        AppMessage::SetPageExportQuality(quality) => {
+            app.model.page_export.quality = *quality;
+        }
+
+        AppMessage::PageExportDpiInput(input) => {
+            app.model.page_export.dpi_input = input
+                .chars()
+                .filter(|c| c.is_ascii_digit() || *c == '.')
+                .collect();
+        }
+
+        AppMessage::ExportPages => {
+            if !app.model.page_export.is_running() && page_export_to(app, PathBuf::new()).is_some()
+            {
+                return UpdateResult::Task(open_folder_dialog(
+                    fl!("dialog-export-pages"),
+                    AppMessage::ExportPagesTo,
+                ));
+            }
+        }
+
+        AppMessage::ExportPagesTo(output_dir) => {
+            if app.model.page_export.is_running() {
+                return UpdateResult::None;
+            }
+            let Some(export) = page_export_to(app, output_dir.clone()) else {
+                return UpdateResult::None;
+            };
+            let token = CancelToken::new();
+            let state = &mut app.model.page_export;
+            state.progress = Some((0, export.job.pages.len()));
+            state.cancel = Some(token.clone());
+            state.status = None;
+            return UpdateResult::Task(Task::run(
+                page_export::export_in_background(export, token),
+                |event| Action::App(AppMessage::PageExportProgress(event)),
+            ));
+        }
+
+        AppMessage::PageExportProgress(event) => {
+            let state = &mut app.model.page_export;
+            match event {
+                PageExportEvent::Progress(done, total) => state.progress = Some((*done, *total)),
+                PageExportEvent::Finished(result) => {
+                    state.progress = None;
+                    let cancelled = state
+                        .cancel
+                        .take()
+                        .is_some_and(|token| token.is_cancelled());
+                    match result {
+                        Ok(count) if cancelled => {
+                            state.status = Some(fl!("export-pages-cancelled", count: count));
+                        }
+                        Ok(count) => state.status = Some(fl!("export-pages-done", count: count)),
+                        Err(e) => app.model.set_error(format!("Page export failed: {e}")),
+                    }
+                }
+            }
+        }
+
+        AppMessage::CancelPageExport => {
+            if let Some(token) = &app.model.page_export.cancel {
+                token.cancel();
+            }
+        }
+
+        AppMessage::ExportDpiInput(input) => {
+            // Keep digits and a decimal point only
+            app.model.export_dpi_input = input
+                .chars()
+                .filter(|c| c.is_ascii_digit() || *c == '.')
+                .collect();
+        }
+
+        AppMessage::ExportPagesInput(input) => {
+            app.model.export_pages_input = input.clone();
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Export of the pages chosen in the export pages panel into `output_dir`
+/// (None without a PDF, or if the page range or resolution is invalid).
+fn page_export_to(app: &NoctuaApp, output_dir: PathBuf) -> Option<PageExport> {
+    let doc = app.document_manager.current_document()?;
+    let state = &app.model.page_export;
+    let page_count = doc.page_count();
+    let pages = state.pages(
+        &app.model.export_pages_input,
+        doc.current_page(),
+        page_count,
+    )?;
+    let job = doc.page_render_job(pages, state.dpi()?)?;
+    Some(PageExport {
+        job,
+        page_count,
+        output_dir,
+        format: state.format,
+        quality: state.quality,
+        limits: OpenLimits::new(app.config.max_image_megapixels, app.config.max_page_size),
+    })
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/presentation.rs
+//
+// Fullscreen presentation: slide timing and the canvas controls.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::AppModel;
+
+/// Advance the presentation and show or hide its controls.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::PresentationTick(now) => {
+            if let Some(presentation) = app.model.presentation.as_mut() {
+                presentation.now = *now;
+                if presentation.next_slide.is_some_and(|at| *now >= at) {
+                    presentation.restart_countdown(app.config.slide_interval);
+                    let next = match presentation.slideshow.as_mut() {
+                        Some(slideshow) => {
+                            let looping = app.config.slideshow_loop;
+                            let manager = &app.document_manager;
+                            let next = match slideshow.order.as_mut() {
+                                Some(slides) => slides
+                                    .advance(looping)
+                                    .and_then(|index| manager.folder_entries().get(index).cloned())
+                                    .map(AppMessage::OpenPath),
+                                None => (looping || manager.has_next())
+                                    .then_some(AppMessage::NextDocument),
+                            };
+                            // Past the last document without looping
+                            next.unwrap_or(AppMessage::ToggleSlideshow)
+                        }
+                        None => AppMessage::TurnPage(true),
+                    };
+                    // Through app.rs like a key press, for its navigation bookkeeping
+                    return UpdateResult::Task(Task::done(Action::App(next)));
+                }
+            }
+        }
+
+        AppMessage::PresentationActivity => {
+            if let Some(presentation) = app.model.presentation.as_mut() {
+                presentation.show_controls();
+            }
+        }
+
+        AppMessage::CanvasActivity => {
+            app.model.canvas_controls_until =
+                Some(std::time::Instant::now() + AppModel::CANVAS_CONTROLS_TIMEOUT);
+        }
+
+        AppMessage::CanvasControlsTick(now) => {
+            let until = &mut app.model.canvas_controls_until;
+            if until.is_some_and(|until| *now >= until) {
+                *until = None;
+            }
+        }
+
+        AppMessage::ToggleAutoAdvance => {
+            if let Some(presentation) = app.model.presentation.as_mut() {
+                presentation.toggle_auto_advance(app.config.slide_interval);
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,117 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/privacy.rs
+//
+// Privacy export: blurred regions and metadata-free copies.
+
+use cosmic::{Action, Task};
+
+use super::{UpdateResult, open_folder_dialog};
+use crate::application::commands::crop_document::CropDocumentCommand;
+use crate::application::commands::privacy_export::PrivacyExportCommand;
+use crate::application::services::privacy_export::{self, PrivacyExportEvent};
+use crate::domain::document::operations::privacy::BlurRegion;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::AppMode;
+use crate::ui::widgets::CropSelection;
+
+/// Mark regions to blur and export privacy copies.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::AddPrivacyRegion => {
+            if let AppMode::Crop { selection } = &mut app.model.mode
+                && let Some(canvas_rect) = selection.canvas_rect()
+            {
+                let pan_offset =
+                    cosmic::iced::Vector::new(app.model.viewport.pan_x, app.model.viewport.pan_y);
+                let rect = CropDocumentCommand::from_canvas_selection(
+                    canvas_rect,
+                    app.model.viewport.canvas_size,
+                    app.model.viewport.image_size,
+                    app.model.viewport.scale,
+                    pan_offset,
+                );
+                let dimensions = app
+                    .document_manager
+                    .current_document()
+                    .map(|d| d.dimensions());
+
+                match (rect, dimensions) {
+                    (Ok(cmd), Some((width, height))) => {
+                        if let Some(region) = BlurRegion::from_pixels(
+                            (cmd.x, cmd.y, cmd.width, cmd.height),
+                            width,
+                            height,
+                        ) {
+                            app.model.privacy.regions.push(region);
+                        }
+                        // Keep crop mode active for the next region
+                        *selection = CropSelection::default();
+                    }
+                    (Err(e), _) => app.model.set_error(format!("Invalid region: {e}")),
+                    (_, None) => {}
+                }
+            }
+        }
+
+        AppMessage::RemovePrivacyRegion(index) => {
+            if *index < app.model.privacy.regions.len() {
+                app.model.privacy.regions.remove(*index);
+            }
+        }
+
+        AppMessage::ClearPrivacyRegions => {
+            app.model.privacy.regions.clear();
+        }
+
+        AppMessage::ExportPrivacyCopies => {
+            if !app.model.privacy.regions.is_empty()
+                && !app.model.selection.is_empty()
+                && !app.model.privacy.is_running()
+            {
+                return UpdateResult::Task(open_folder_dialog(
+                    fl!("dialog-privacy-export"),
+                    AppMessage::PrivacyExportTo,
+                ));
+            }
+        }
+
+        AppMessage::PrivacyExportTo(output_dir) => {
+            if app.model.privacy.is_running() {
+                return UpdateResult::None;
+            }
+            let cmd = PrivacyExportCommand::new(
+                app.model.privacy.regions.clone(),
+                app.model.selection.paths().to_vec(),
+                output_dir.clone(),
+            )
+            .with_profile(app.model.export_profiles.profile(&app.config));
+            app.model.privacy.progress = Some((0, cmd.paths.len()));
+            app.model.privacy.status = None;
+            return UpdateResult::Task(Task::run(
+                privacy_export::export_in_background(cmd),
+                |event| Action::App(AppMessage::PrivacyExportProgress(event)),
+            ));
+        }
+
+        AppMessage::PrivacyExportProgress(event) => match event {
+            PrivacyExportEvent::Progress(done, total) => {
+                app.model.privacy.progress = Some((*done, *total));
+            }
+            PrivacyExportEvent::Finished(result) => {
+                app.model.privacy.progress = None;
+                match result {
+                    Ok(count) => {
+                        app.model.privacy.status = Some(fl!("privacy-done", count: count));
+                    }
+                    Err(e) => app.model.set_error(format!("Privacy export failed: {e}")),
+                }
+            }
+        },
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
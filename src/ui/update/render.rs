@@ -0,0 +1,295 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/render.rs
+//
+// Rendering the current document for the canvas.
+
+use cosmic::{Action, Task};
+
+use crate::application::document_manager::display_handle;
+use crate::domain::document::core::content::{DocumentContent, ZoomRenderJob};
+use crate::domain::document::core::document::{ImageHandle, Renderable};
+use crate::domain::document::operations::{alpha, sharpen, sprite_sheet, tile};
+use crate::infrastructure::color::MonitorProfile;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppModel, ColorState, TilePreview, ViewMode, Viewport};
+
+/// Cache rendered image handle in viewport for view performance.
+pub fn cache_render(model: &mut AppModel, manager: &mut crate::application::DocumentManager) {
+    model.viewport.embedded_preview = false;
+    // A zoom render shows the old pixels or zoom; the next sync redoes it
+    model.viewport.zoom_render = None;
+    model.viewport.zoom_target = None;
+    // Statistics of the old pixels
+    model.selection_stats = None;
+    if let Some(doc) = manager.current_document_mut() {
+        match doc.render(model.viewport.scale as f64) {
+            Ok(output) => {
+                let handle = model
+                    .viewport
+                    .alpha_mask
+                    .then(|| alpha_mask_handle(doc))
+                    .flatten()
+                    .or_else(|| {
+                        color_managed_handle(&mut model.color, doc.icc_profile(), &output.handle)
+                    })
+                    .unwrap_or_else(|| render_handle(&mut model.viewport, &output.handle));
+                model.viewport.cached_image_handle = Some(handle);
+                model.viewport.preview_handle =
+                    preview_handle(model, doc).or_else(|| sharpened_handle(model, doc));
+            }
+            Err(e) => {
+                tracing::error!("Failed to cache render: {e}");
+                model.viewport.cached_image_handle = None;
+                model.viewport.preview_handle = None;
+            }
+        }
+    } else {
+        model.viewport.cached_image_handle = None;
+        model.viewport.preview_handle = None;
+    }
+}
+
+/// Keep the sharper render of a vector document in step with the zoom.
+///
+/// Schedules a new render once the zoom rests, or drops the render when the
+/// plain raster is sharp enough. Run after every message; nothing happens
+/// while the zoom render matches.
+pub fn sync_zoom_render(
+    model: &mut AppModel,
+    manager: &crate::application::DocumentManager,
+) -> Task<Action<AppMessage>> {
+    let key = zoom_cache_key(model, manager);
+    let delay = if model.low_power {
+        Viewport::LOW_POWER_ZOOM_RENDER_DELAY
+    } else {
+        Viewport::ZOOM_RENDER_DELAY
+    };
+    let viewport = &mut model.viewport;
+    let target = manager
+        .current_document()
+        .filter(|_| !viewport.alpha_mask)
+        .and_then(|doc| doc.zoom_render_job(f64::from(viewport.display_scale())))
+        .map(|job| job.scale());
+    let unchanged = match (target, viewport.zoom_target) {
+        (Some(target), Some(current)) => (target - current).abs() < 1e-3,
+        (None, None) => true,
+        _ => false,
+    };
+    if unchanged {
+        return Task::none();
+    }
+
+    viewport.zoom_target = target;
+    viewport.zoom_revision += 1;
+    let Some(scale) = target else {
+        viewport.zoom_render = None;
+        return Task::none();
+    };
+    // Zooming back to a recent scale shows its render at once
+    if let Some(handle) = key.and_then(|key| viewport.cached_zoom(key, scale)) {
+        viewport.zoom_render = Some((scale, handle));
+        return Task::none();
+    }
+    let revision = viewport.zoom_revision;
+    Task::perform(tokio::time::sleep(delay), move |()| {
+        Action::App(AppMessage::RenderZoom(revision))
+    })
+}
+
+/// Render a large PDF page in full while its quick preview is shown.
+///
+/// Cancels the render of a page that was left, turned or flipped. Run after
+/// every message; nothing happens while the render for the page is running.
+pub fn sync_page_refine(
+    manager: &mut crate::application::DocumentManager,
+) -> Task<Action<AppMessage>> {
+    match manager.begin_refine() {
+        Some(job) => Task::perform(job.render(), |refined| {
+            Action::App(AppMessage::PageRefined(refined))
+        }),
+        None => Task::none(),
+    }
+}
+
+/// Key of the zoom renders of the current raster: its image id, and whether
+/// they were converted to the monitor profile.
+pub(super) fn zoom_cache_key(
+    model: &AppModel,
+    manager: &crate::application::DocumentManager,
+) -> Option<(u64, bool)> {
+    let handle = manager.current_document()?.handle()?;
+    Some((handle.id(), model.color.enabled))
+}
+
+/// Run a zoom render and convert it for display, to the monitor profile if
+/// `monitor` is set (color management on). None if it failed.
+pub(super) fn render_zoom(
+    job: &ZoomRenderJob,
+    monitor: Option<Option<MonitorProfile>>,
+) -> Option<(f64, cosmic::widget::image::Handle)> {
+    let image = job
+        .run()
+        .map_err(|e| tracing::warn!("Failed to render for the zoom: {e}"))
+        .ok()?;
+    let mut pixels = image.into_rgba8();
+    if let Some(monitor) = monitor {
+        to_monitor_profile(None, monitor.as_ref(), &mut pixels);
+    }
+    let (width, height) = pixels.dimensions();
+    Some((
+        job.scale(),
+        cosmic::widget::image::Handle::from_rgba(width, height, pixels.into_raw()),
+    ))
+}
+
+/// Display handle of a document render; the same rendered image (e.g. a
+/// raster shown at another zoom) is not converted and uploaded again.
+fn render_handle(viewport: &mut Viewport, image: &ImageHandle) -> cosmic::widget::image::Handle {
+    match &viewport.render_source {
+        Some((id, handle)) if *id == image.id() => handle.clone(),
+        _ => {
+            let handle = display_handle(image);
+            viewport.render_source = Some((image.id(), handle.clone()));
+            handle
+        }
+    }
+}
+
+/// Tile preview and sprite grid overlay of the document (or its alpha mask).
+///
+/// None if neither is enabled.
+fn preview_handle(
+    model: &AppModel,
+    doc: &DocumentContent,
+) -> Option<cosmic::widget::image::Handle> {
+    let viewport = &model.viewport;
+    let grid = model.sprite_sheet.overlay();
+    if viewport.tile_preview == TilePreview::Off && grid.is_none() {
+        return None;
+    }
+
+    let mask = viewport
+        .alpha_mask
+        .then(|| alpha::alpha_mask(doc.rendered_image()))
+        .flatten()
+        .map(image::DynamicImage::ImageLuma8);
+    let mut source = mask.as_ref().unwrap_or(doc.rendered_image());
+    let with_grid =
+        grid.map(|grid| image::DynamicImage::ImageRgba8(sprite_sheet::draw_grid(source, grid)));
+    if let Some(ref img) = with_grid {
+        source = img;
+    }
+
+    let pixels = match viewport.tile_preview {
+        TilePreview::Off => source.to_rgba8(),
+        TilePreview::Grid => tile::tile_grid(source),
+        TilePreview::Offset => tile::offset_half(source),
+    };
+    let (width, height) = pixels.dimensions();
+    Some(cosmic::widget::image::Handle::from_rgba(
+        width,
+        height,
+        pixels.into_raw(),
+    ))
+}
+
+/// Fit view of an image larger than the canvas, scaled down and mildly
+/// sharpened here instead of on the GPU, where it looks soft.
+///
+/// None unless enabled, or while the canvas size is not known yet.
+fn sharpened_handle(
+    model: &mut AppModel,
+    doc: &DocumentContent,
+) -> Option<cosmic::widget::image::Handle> {
+    let viewport = &model.viewport;
+    if !viewport.sharpen || viewport.fit_mode != ViewMode::Fit || viewport.alpha_mask {
+        return None;
+    }
+
+    // A quarter turn shows the image the other way around
+    let canvas = viewport.canvas_size;
+    let bounds = if viewport.view_rotation % 2 == 1 {
+        (canvas.height, canvas.width)
+    } else {
+        (canvas.width, canvas.height)
+    };
+    let image = doc.rendered_image();
+    let (width, height) = sharpen::display_size((image.width(), image.height()), bounds)?;
+    let mut pixels = sharpen::sharpen_for_display(image, width, height);
+    if model.color.enabled {
+        model.color.convert(doc.icc_profile(), &mut pixels);
+    }
+    Some(cosmic::widget::image::Handle::from_rgba(
+        width,
+        height,
+        pixels.into_raw(),
+    ))
+}
+
+/// Grayscale view of the document's alpha channel (None without alpha).
+fn alpha_mask_handle(doc: &DocumentContent) -> Option<cosmic::widget::image::Handle> {
+    let mask = alpha::alpha_mask(doc.rendered_image())?;
+    let (width, height) = mask.dimensions();
+    let pixels = image::DynamicImage::ImageLuma8(mask).to_rgba8();
+    Some(cosmic::widget::image::Handle::from_rgba(
+        width,
+        height,
+        pixels.into_raw(),
+    ))
+}
+
+/// Convert rendered pixels from the `source` profile to the monitor profile,
+/// if color management is enabled.
+pub(super) fn color_managed_handle(
+    color: &mut ColorState,
+    source: Option<&[u8]>,
+    image: &ImageHandle,
+) -> Option<cosmic::widget::image::Handle> {
+    if !color.enabled {
+        return None;
+    }
+
+    let mut pixels =
+        image::RgbaImage::from_raw(image.width(), image.height(), image.pixels().to_vec())?;
+    if !color.convert(source, &mut pixels) {
+        return None;
+    }
+    let (width, height) = pixels.dimensions();
+    Some(cosmic::widget::image::Handle::from_rgba(
+        width,
+        height,
+        pixels.into_raw(),
+    ))
+}
+
+/// Convert display pixels from the `source` profile (sRGB if None) to the
+/// monitor profile. Returns false if no transform could be created.
+#[cfg(feature = "color")]
+fn to_monitor_profile(
+    source: Option<&[u8]>,
+    monitor: Option<&MonitorProfile>,
+    pixels: &mut image::RgbaImage,
+) -> bool {
+    use crate::infrastructure::color::DisplayTransform;
+
+    let monitor = monitor.map(|p| p.data.as_slice());
+    match DisplayTransform::new(source, monitor) {
+        Ok(transform) => {
+            transform.apply(pixels);
+            true
+        }
+        Err(e) => {
+            tracing::warn!("Failed to create display color transform: {e}");
+            false
+        }
+    }
+}
+
+#[cfg(not(feature = "color"))]
+fn to_monitor_profile(
+    _source: Option<&[u8]>,
+    _monitor: Option<&MonitorProfile>,
+    _pixels: &mut image::RgbaImage,
+) -> bool {
+    false
+}
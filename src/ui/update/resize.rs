@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/resize.rs
+//
+// Resizing the document.
+
+use super::UpdateResult;
+use super::render::cache_render;
+use crate::application::commands::resize_document::ResizeDocumentCommand;
+use crate::application::edit_history::EditOperation;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{ResizeUnit, ViewMode};
+
+/// Edit the new size and resize the document.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SetResizeUnit(unit) => {
+            let size = current_size(&app.document_manager);
+            app.model.resize.set_unit(*unit, size);
+        }
+
+        AppMessage::ResizeWidthInput(input) => {
+            let size = current_size(&app.document_manager);
+            app.model.resize.set_width(input, size);
+        }
+
+        AppMessage::ResizeHeightInput(input) => {
+            let size = current_size(&app.document_manager);
+            app.model.resize.set_height(input, size);
+        }
+
+        AppMessage::SetResizeLockAspect(locked) => {
+            app.model.resize.lock_aspect = *locked;
+            if *locked {
+                // Bring the height back in line with the width
+                let size = current_size(&app.document_manager);
+                let width = app.model.resize.width_input.clone();
+                app.model.resize.set_width(&width, size);
+            }
+        }
+
+        AppMessage::SetResizePaper(paper) => {
+            app.model.resize.paper = *paper;
+        }
+
+        AppMessage::SetResizeOrientation(orientation) => {
+            app.model.resize.orientation = *orientation;
+        }
+
+        AppMessage::ResizeDpiInput(input) => {
+            app.model.resize.dpi_input = input
+                .chars()
+                .filter(|c| c.is_ascii_digit() || *c == '.')
+                .collect();
+        }
+
+        AppMessage::SetResampleFilter(filter) => {
+            app.model.resize.filter = *filter;
+        }
+
+        AppMessage::ApplyResize => {
+            let size = current_size(&app.document_manager);
+            let Some(resize) = app
+                .model
+                .resize
+                .resize(size, app.config.max_image_megapixels)
+            else {
+                return UpdateResult::None;
+            };
+            match ResizeDocumentCommand::new(resize).execute(&mut app.document_manager) {
+                Ok(()) => {
+                    app.model.history.record(
+                        EditOperation::Resize(resize),
+                        app.document_manager.current_page(),
+                    );
+                    // Saved with the resolution it was sized for
+                    if app.model.resize.unit == ResizeUnit::Paper
+                        && let Some(dpi) = app.model.resize.dpi()
+                    {
+                        app.model.export_dpi_input = dpi.to_string();
+                    }
+                    let unit = app.model.resize.unit;
+                    app.model
+                        .resize
+                        .set_unit(unit, (resize.width, resize.height));
+                    // Reset view to fit the new image size
+                    app.model.viewport.scale = 1.0;
+                    app.model.viewport.fit_mode = ViewMode::Fit;
+                    app.model.reset_pan();
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+                Err(e) => app.model.set_error(format!("Resize failed: {e}")),
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Pixel size of the current document (0 x 0 without one).
+pub(super) fn current_size(manager: &crate::application::DocumentManager) -> (u32, u32) {
+    manager
+        .current_document()
+        .map(|doc| doc.dimensions())
+        .unwrap_or_default()
+}
@@ -0,0 +1,234 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/save.rs
+//
+// Saving documents, edit sidecars and embedded data.
+
+use std::path::{Path, PathBuf};
+
+use super::{UpdateResult, save_file_dialog};
+use crate::application::commands::save_document::SaveDocumentCommand;
+use crate::application::edit_sidecar::{self, SidecarEdits};
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::operations::alpha::{self, AlphaMode};
+use crate::domain::document::operations::export::ExportFormat;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Save the document, its edits and the data embedded in it.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SaveAs => {
+            if let Some(file_name) = save_file_name(app) {
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-save-as"),
+                    file_name,
+                    AppMessage::SaveAsTo,
+                ));
+            }
+        }
+
+        AppMessage::SaveAsTo(path) => {
+            // The dialog only asked about replacing `path`, not a file whose
+            // extension was changed to the one of the chosen format
+            let target = save_command(app).target(path);
+            if app.model.read_only && app.document_manager.current_path() == Some(target.as_path())
+            {
+                app.model.set_error(fl!("read-only-save-as"));
+            } else if target != *path && target.exists() {
+                app.model.export_profiles.overwrite = Some(path.clone());
+            } else {
+                return save_as(app, path);
+            }
+        }
+
+        AppMessage::ConfirmOverwrite => {
+            if let Some(path) = app.model.export_profiles.overwrite.take() {
+                return save_as(app, &path);
+            }
+        }
+
+        AppMessage::CancelOverwrite => {
+            app.model.export_profiles.overwrite = None;
+        }
+
+        AppMessage::SaveEditSidecar => {
+            if app.model.read_only {
+                app.model.set_error(fl!("read-only-sidecar"));
+            } else if let Some(path) = app.document_manager.current_path() {
+                let history = &app.model.history;
+                let stored = SidecarEdits {
+                    edits: history.entries()[..history.position()].to_vec(),
+                    adjustments: app.model.adjust.values,
+                };
+                match edit_sidecar::save(path, &stored) {
+                    Ok(sidecar) => tracing::info!("Saved edits to {}", sidecar.display()),
+                    Err(e) => app.model.set_error(format!("Saving edits failed: {e}")),
+                }
+            }
+        }
+
+        AppMessage::ExportIccProfile => {
+            if app
+                .document_manager
+                .current_document()
+                .and_then(|d| d.icc_profile())
+                .is_some()
+            {
+                let file_name = app
+                    .document_manager
+                    .current_path()
+                    .and_then(|p| p.file_stem())
+                    .map(|s| format!("{}.icc", s.to_string_lossy()))
+                    .unwrap_or_else(|| "profile.icc".to_string());
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-export-profile"),
+                    file_name,
+                    AppMessage::SaveIccProfile,
+                ));
+            }
+        }
+
+        AppMessage::ExportAlphaMask => {
+            if let Some(path) = app.document_manager.current_path()
+                && app
+                    .document_manager
+                    .current_document()
+                    .is_some_and(|doc| alpha::has_alpha(doc.rendered_image()))
+            {
+                let file_name = path
+                    .file_stem()
+                    .map(|s| format!("{}-alpha.png", s.to_string_lossy()))
+                    .unwrap_or_else(|| "alpha.png".to_string());
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-export-alpha"),
+                    file_name,
+                    AppMessage::SaveAlphaMask,
+                ));
+            }
+        }
+
+        AppMessage::SaveAlphaMask(path) => {
+            if let Some(doc) = app.document_manager.current_document()
+                && let Err(e) = alpha::export_alpha_mask(doc.rendered_image(), path)
+            {
+                app.model
+                    .set_error(format!("Failed to export alpha mask: {e}"));
+            }
+        }
+
+        AppMessage::SaveIccProfile(path) => {
+            if let Some(icc) = app
+                .document_manager
+                .current_document()
+                .and_then(|d| d.icc_profile())
+                && let Err(e) = std::fs::write(path, icc)
+            {
+                app.model
+                    .set_error(format!("Failed to export color profile: {e}"));
+            }
+        }
+
+        AppMessage::SetExportPremultiplied(premultiplied) => {
+            app.model.export_profiles.premultiplied = *premultiplied;
+        }
+
+        AppMessage::SelectSaveFormat(index) => {
+            app.model.export_profiles.format = index
+                .checked_sub(1)
+                .and_then(|index| ExportFormat::SAVE_FORMATS.get(index).copied());
+        }
+
+        AppMessage::SetSaveQuality(quality) => {
+            app.model.export_profiles.quality = *quality;
+        }
+
+        AppMessage::FormatAdviceReady(path, advice) => {
+            // Navigated to another image meanwhile
+            let state = &mut app.model.export_profiles;
+            if state.advice_path.as_ref() == Some(path) {
+                state.advice = Some(advice.clone());
+            }
+        }
+
+        AppMessage::SetStripMetadata(strip) => {
+            app.model.export_profiles.strip_metadata = *strip;
+        }
+
+        AppMessage::UseFormatAdvice => {
+            let state = &mut app.model.export_profiles;
+            if let Some(advice) = &state.advice
+                && let Some(format) = advice.format
+            {
+                state.selected = None;
+                state.format = Some(format);
+                if let Some(quality) = advice.quality {
+                    state.quality = quality;
+                }
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Save command with the format, quality and profile chosen in the Save As panel.
+pub(super) fn save_command(app: &NoctuaApp) -> SaveDocumentCommand {
+    let state = &app.model.export_profiles;
+    let alpha = if state.premultiplied {
+        AlphaMode::Premultiplied
+    } else {
+        AlphaMode::Straight
+    };
+    SaveDocumentCommand::new()
+        .format(state.format)
+        .quality(state.quality)
+        .resolution(app.model.export_resolution())
+        .alpha(alpha)
+        .profile(state.profile(&app.config))
+        .strip_metadata(state.strip_metadata)
+}
+
+/// File name the edited image is saved under by default (None without a
+/// document).
+pub(super) fn save_file_name(app: &NoctuaApp) -> Option<String> {
+    let path = app.document_manager.current_path()?;
+    let doc = app.document_manager.current_document()?;
+    let file_name = match app.model.export_profiles.profile(&app.config) {
+        Some(profile) => profile.file_name(path, 1, profile.output_size(doc.dimensions())),
+        // Keep the name; formats that cannot be written become PNG,
+        // SVGs stay SVG
+        None => {
+            let is_vector = doc.kind() == DocumentKind::Vector;
+            let mut name = PathBuf::from(path.file_name().unwrap_or_default());
+            if let Some(format) = app.model.export_profiles.format {
+                name.set_extension(format.extension());
+            } else if ExportFormat::from_path(&name).is_none_or(|f| match f {
+                ExportFormat::Pdf => true,
+                ExportFormat::Svg => !is_vector,
+                _ => false,
+            }) {
+                name.set_extension(ExportFormat::Png.extension());
+            }
+            name.to_string_lossy().into_owned()
+        }
+    };
+    Some(file_name)
+}
+
+/// Write the edited image; also saves the edit sidecar if configured.
+fn save_as(app: &mut NoctuaApp, path: &Path) -> UpdateResult {
+    match save_command(app).execute(&app.document_manager, path) {
+        Ok(target) => {
+            tracing::info!("Saved as {}", target.display());
+            // The sidecar would go next to the original
+            if app.config.edit_sidecar_on_save && !app.model.read_only {
+                return super::update(app, &AppMessage::SaveEditSidecar);
+            }
+        }
+        Err(e) => app.model.set_error(format!("Save failed: {e}")),
+    }
+    UpdateResult::None
+}
@@ -0,0 +1,45 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/scan_cleanup.rs
+//
+// Scan cleanup.
+
+use super::UpdateResult;
+use super::render::cache_render;
+use crate::application::commands::clean_scan::CleanScanCommand;
+use crate::application::edit_history::EditOperation;
+use crate::domain::document::operations::edit_macro::MacroStep;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Pick and apply the scan cleanup.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SetScanCleanup(cleanup) => {
+            app.model.scan_cleanup = *cleanup;
+        }
+
+        AppMessage::ApplyScanCleanup => {
+            let cleanup = app.model.scan_cleanup;
+            if cleanup.is_none() {
+                return UpdateResult::None;
+            }
+            match CleanScanCommand::new(cleanup).execute(&mut app.document_manager) {
+                Ok(()) => {
+                    app.model.history.record(
+                        EditOperation::CleanScan(cleanup),
+                        app.document_manager.current_page(),
+                    );
+                    if let Some(steps) = &mut app.model.macros.recording {
+                        steps.push(MacroStep::CleanScan(cleanup));
+                    }
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+                Err(e) => app.model.set_error(format!("Scan cleanup failed: {e}")),
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/search.rs
+//
+// Document search, heading outline and the find bar.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use crate::application::services::heading_outline::{self, OutlineEvent};
+use crate::domain::document::core::cancel::CancelToken;
+use crate::domain::document::core::limits::OpenLimits;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::HeadingOutlineState;
+
+/// Search the document and step through the matches.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SearchInput(query) => {
+            app.model.search.query = query.clone();
+        }
+
+        AppMessage::RunSearch => {
+            if let Some(doc) = app.document_manager.current_document() {
+                app.model.search.results = doc.search_text(&app.model.search.query);
+                app.model.search.searched = true;
+            }
+        }
+
+        AppMessage::FindHeadings => {
+            if app.model.heading_outline.is_running() {
+                return UpdateResult::None;
+            }
+            let Some(job) = app.document_manager.current_document().and_then(|doc| {
+                doc.page_render_job((0..doc.page_count()).collect(), heading_outline::OCR_DPI)
+            }) else {
+                return UpdateResult::None;
+            };
+            let token = CancelToken::new();
+            app.model.heading_outline = HeadingOutlineState {
+                path: Some(job.path.clone()),
+                entries: Vec::new(),
+                progress: Some((0, job.pages.len())),
+                cancel: Some(token.clone()),
+                status: None,
+            };
+            return UpdateResult::Task(Task::run(
+                heading_outline::outline_in_background(
+                    job,
+                    app.config.ocr_language.clone(),
+                    OpenLimits::new(app.config.max_image_megapixels, app.config.max_page_size),
+                    token,
+                ),
+                |event| Action::App(AppMessage::HeadingsProgress(event)),
+            ));
+        }
+
+        AppMessage::HeadingsProgress(event) => {
+            let state = &mut app.model.heading_outline;
+            match event {
+                OutlineEvent::Page {
+                    entries,
+                    done,
+                    total,
+                } => {
+                    state.entries.extend(entries.iter().cloned());
+                    state.progress = Some((*done, *total));
+                }
+                OutlineEvent::Finished(result) => {
+                    state.progress = None;
+                    let cancelled = state
+                        .cancel
+                        .take()
+                        .is_some_and(|token| token.is_cancelled());
+                    state.status = match result {
+                        Ok(_) if cancelled => Some(fl!("left-headings-stopped")),
+                        Ok(0) => Some(fl!("left-headings-none")),
+                        Ok(_) => None,
+                        Err(e) => Some(fl!("left-headings-failed", reason: e.clone())),
+                    };
+                }
+            }
+        }
+
+        AppMessage::CancelFindHeadings => {
+            if let Some(token) = &app.model.heading_outline.cancel {
+                token.cancel();
+            }
+        }
+
+        AppMessage::CloseFindBar => {
+            app.model.find = None;
+        }
+
+        AppMessage::FindInput(query) => {
+            if let Some(find) = &mut app.model.find {
+                find.query = query.clone();
+            }
+        }
+
+        AppMessage::FindShift(shift) => {
+            if let Some(find) = &mut app.model.find {
+                find.shift = *shift;
+            }
+        }
+
+        AppMessage::FindSubmit => {
+            let forward = !app.model.find.as_ref().is_some_and(|find| find.shift);
+            return find_step(app, forward);
+        }
+
+        AppMessage::FindNext => return find_step(app, true),
+
+        AppMessage::FindPrevious => return find_step(app, false),
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Jump to the next or previous find hit, searching again if the query changed.
+fn find_step(app: &mut NoctuaApp, forward: bool) -> UpdateResult {
+    let (Some(find), Some(doc)) = (
+        app.model.find.as_mut(),
+        app.document_manager.current_document(),
+    ) else {
+        return UpdateResult::None;
+    };
+    let page = doc.current_page();
+    if find.query.trim().is_empty() {
+        find.hits.clear();
+        find.current = None;
+        find.searched = None;
+        return UpdateResult::None;
+    }
+    if find.searched.as_deref() != Some(find.query.as_str()) {
+        find.hits = doc.find_text(&find.query);
+        find.current = None;
+        find.searched = Some(find.query.clone());
+    }
+
+    match find.step(forward, page).map(|hit| hit.page) {
+        Some(target) if target != page => super::update(app, &AppMessage::GotoPage(target)),
+        _ => UpdateResult::None,
+    }
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/selection.rs
+//
+// Selecting images of the folder.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Select images of the folder, by hand or by rating and tags.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SelectFolderImage(path, selected) => {
+            app.model.selection.set(path, *selected);
+        }
+
+        AppMessage::SelectAllFolderImages(selected) => {
+            let paths = if *selected {
+                app.document_manager.folder_entries()
+            } else {
+                &[]
+            };
+            app.model.selection.set_all(paths);
+        }
+
+        AppMessage::SetSelectionMinRating(stars) => {
+            app.model.selection.min_rating = *stars;
+        }
+
+        AppMessage::SelectionTagsInput(input) => {
+            app.model.selection.tags_input = input.clone();
+        }
+
+        AppMessage::SelectMatchingImages => {
+            if !app.model.selection.matching {
+                app.model.selection.matching = true;
+                let filter = app.model.selection.filter();
+                let entries = app.document_manager.folder_entries().to_vec();
+                return UpdateResult::Task(Task::perform(
+                    tokio::task::spawn_blocking(move || filter.select(&entries)),
+                    |matching| {
+                        Action::App(AppMessage::MatchingImagesFound(
+                            matching.unwrap_or_default(),
+                        ))
+                    },
+                ));
+            }
+        }
+
+        AppMessage::MatchingImagesFound(matching) => {
+            app.model.selection.matching = false;
+            app.model.selection.set_all(matching);
+            // Navigated to another folder meanwhile
+            app.model
+                .selection
+                .retain_existing(app.document_manager.folder_entries());
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/session.rs
+//
+// Saving and restoring sessions.
+
+use cosmic::Task;
+
+use super::compare::load_compare_slot;
+use super::navigation::{load_stepped, sync_folder_entries};
+use super::{UpdateResult, open_file_dialog, save_file_dialog};
+use crate::application::session::{self, Session, SessionCompare, SessionFile};
+use crate::domain::document::core::content::DocumentContent;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, CompareMode, Comparison, ViewLayout};
+
+/// Save and open sessions.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SaveSession => {
+            if app.document_manager.current_path().is_some() {
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-save-session"),
+                    format!("session.{}", session::SESSION_EXTENSION),
+                    AppMessage::SaveSessionTo,
+                ));
+            }
+        }
+
+        AppMessage::SaveSessionTo(path) => match session::save(path, &current_session(app)) {
+            Ok(()) => tracing::info!("Saved session {}", path.display()),
+            Err(e) => app.model.set_error(format!("Failed to save session: {e}")),
+        },
+
+        AppMessage::OpenSessionDialog => {
+            use cosmic::dialog::file_chooser::FileFilter;
+
+            let filter = FileFilter::new(fl!("dialog-filter-session"))
+                .glob(format!("*.{}", session::SESSION_EXTENSION));
+            return UpdateResult::Task(open_file_dialog(
+                fl!("dialog-open-session"),
+                filter,
+                AppMessage::OpenSession,
+            ));
+        }
+
+        AppMessage::OpenSession(path) => match session::load(path) {
+            Ok(session) => return open_session(app, session),
+            Err(e) => app.model.set_error(format!("Failed to open session: {e}")),
+        },
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// The session shown: the navigated files with the views left on them, and
+/// the compare pair.
+fn current_session(app: &NoctuaApp) -> Session {
+    let manager = &app.document_manager;
+    let current = manager.current_path();
+    let page = manager
+        .current_document()
+        .map_or(0, DocumentContent::current_page);
+    let files = manager
+        .folder_entries()
+        .iter()
+        .map(|path| SessionFile {
+            path: path.clone(),
+            view: if current == Some(path.as_path()) {
+                Some(app.model.viewport.document_view(page))
+            } else {
+                app.model.view_states.get(path).cloned()
+            },
+        })
+        .collect();
+    let compare = match &app.model.layout {
+        ViewLayout::Compare(comparison) => Some(SessionCompare {
+            path: comparison.b_path.clone(),
+            swipe: comparison.mode == CompareMode::Swipe,
+            divider: comparison.divider,
+        }),
+        ViewLayout::Single => None,
+    };
+    Session {
+        files,
+        current: manager.current_index().unwrap_or(0),
+        compare,
+    }
+}
+
+/// Navigate the files of a session, showing its current file with the
+/// view kept for it, next to its compare pair.
+fn open_session(app: &mut NoctuaApp, session: Session) -> UpdateResult {
+    // Files deleted or on a disk not mounted are left out; without its
+    // current file, the session goes on with the next one
+    let current = session.files[..session.current]
+        .iter()
+        .filter(|file| file.path.exists())
+        .count();
+    let files: Vec<SessionFile> = session
+        .files
+        .into_iter()
+        .filter(|file| file.path.exists())
+        .collect();
+    if files.is_empty() {
+        app.model.set_error(fl!("session-no-files"));
+        return UpdateResult::None;
+    }
+
+    app.model.view_states = files
+        .iter()
+        .filter_map(|file| Some((file.path.clone(), file.view.clone()?)))
+        .collect();
+    app.model.restore_views = app.model.view_states.clone();
+    app.model.layout = ViewLayout::Single;
+    let current = current.min(files.len() - 1);
+    let paths = files.into_iter().map(|file| file.path).collect();
+    let Some(path) = app.document_manager.set_file_list(paths, current) else {
+        return UpdateResult::None;
+    };
+    sync_folder_entries(app);
+    let load = load_stepped(app, &path);
+
+    let Some(compare) = session.compare.filter(|compare| compare.path.exists()) else {
+        return load;
+    };
+    if app.model.strip.is_some() || !matches!(app.model.mode, AppMode::View) {
+        return load;
+    }
+    let mut comparison =
+        Comparison::new(compare.path.clone(), app.document_manager.folder_entries());
+    comparison.mode = if compare.swipe {
+        CompareMode::Swipe
+    } else {
+        CompareMode::SideBySide
+    };
+    comparison.divider = compare.divider;
+    app.model.layout = ViewLayout::Compare(comparison);
+    let slot = load_compare_slot(app, &compare.path);
+    if matches!(slot, UpdateResult::None) {
+        app.model.layout = ViewLayout::Single;
+    }
+    let tasks = [load, slot].into_iter().filter_map(|result| match result {
+        UpdateResult::Task(task) => Some(task),
+        UpdateResult::None => None,
+    });
+    UpdateResult::Task(Task::batch(tasks))
+}
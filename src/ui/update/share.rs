@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/share.rs
+//
+// Sharing the document through share targets.
+
+use std::path::PathBuf;
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use super::save::{save_command, save_file_name};
+use crate::domain::document::core::document::DocResult;
+use crate::domain::document::core::temp_file;
+use crate::fl;
+use crate::infrastructure::system::share::{SHARE_DIR, ShareTarget};
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Share the document and edit the share targets.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ShareTo(index) => {
+            if app.model.share.running.is_some() {
+                return UpdateResult::None;
+            }
+            let Some((name, target)) = app.config.share_targets.iter().nth(*index) else {
+                return UpdateResult::None;
+            };
+            let Some(file_name) = save_file_name(app) else {
+                return UpdateResult::None;
+            };
+            let file = match export_for_sharing(app, &file_name) {
+                Ok(file) => file,
+                Err(e) => {
+                    app.model.set_error(format!("Share to {name} failed: {e}"));
+                    return UpdateResult::None;
+                }
+            };
+            let target = ShareTarget::parse(target);
+            let state = &mut app.model.share;
+            state.running = Some(name.clone());
+            state.status = None;
+            return UpdateResult::Task(Task::perform(
+                async move {
+                    tokio::task::spawn_blocking(move || {
+                        let link = target.share(&file).map_err(|e| format!("{e:#}"));
+                        if let Err(e) = std::fs::remove_file(&file) {
+                            tracing::warn!("Failed to remove {}: {e}", file.display());
+                        }
+                        link
+                    })
+                    .await
+                    .unwrap_or_else(|e| Err(e.to_string()))
+                },
+                |link| Action::App(AppMessage::ShareFinished(link)),
+            ));
+        }
+
+        AppMessage::ShareFinished(link) => {
+            let name = app.model.share.running.take().unwrap_or_default();
+            match link {
+                Ok(link) => {
+                    tracing::info!("Shared to {name}: {link}");
+                    app.model.share.status = Some(fl!("share-done", name: name));
+                    return UpdateResult::Task(cosmic::iced::clipboard::write(link.clone()));
+                }
+                Err(e) => app.model.set_error(format!("Share to {name} failed: {e}")),
+            }
+        }
+
+        AppMessage::ShareNameInput(input) => {
+            app.model.share.name_input = input.clone();
+        }
+
+        AppMessage::ShareTargetInput(input) => {
+            app.model.share.target_input = input.clone();
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Write the edited image to the share folder in the temp directory, with
+/// the Save As settings, for sharing or uploading (which remove it again).
+pub(super) fn export_for_sharing(app: &NoctuaApp, file_name: &str) -> DocResult<PathBuf> {
+    let dir = temp_file::temp_dir()?.join(SHARE_DIR);
+    std::fs::create_dir_all(&dir)?;
+    save_command(app).execute(&app.document_manager, &dir.join(file_name))
+}
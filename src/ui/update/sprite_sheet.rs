@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/sprite_sheet.rs
+//
+// Sprite sheet slicing and atlas export.
+
+use super::render::cache_render;
+use super::{UpdateResult, open_folder_dialog, save_file_dialog};
+use crate::domain::document::operations::sprite_sheet;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Slice sprite sheets and export their cells.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SpriteGridInput(input) => {
+            app.model.sprite_sheet.input = input.clone();
+            if app.model.sprite_sheet.show_grid {
+                cache_render(&mut app.model, &mut app.document_manager);
+            }
+        }
+
+        AppMessage::SetSpriteGridByCount(by_count) => {
+            app.model.sprite_sheet.by_count = *by_count;
+            if app.model.sprite_sheet.show_grid {
+                cache_render(&mut app.model, &mut app.document_manager);
+            }
+        }
+
+        AppMessage::SetSpriteGridVisible(visible) => {
+            app.model.sprite_sheet.show_grid = *visible;
+            cache_render(&mut app.model, &mut app.document_manager);
+        }
+
+        AppMessage::ExportSpriteCells => {
+            if app.model.sprite_sheet.grid().is_some()
+                && app.document_manager.current_document().is_some()
+            {
+                return UpdateResult::Task(open_folder_dialog(
+                    fl!("dialog-sprite-cells"),
+                    AppMessage::SpriteCellsExportTo,
+                ));
+            }
+        }
+
+        AppMessage::SpriteCellsExportTo(output_dir) => {
+            if let Some(grid) = app.model.sprite_sheet.grid()
+                && let Some(doc) = app.document_manager.current_document()
+                && let Some(path) = app.document_manager.current_path()
+            {
+                match sprite_sheet::export_cells(doc.rendered_image(), grid, path, output_dir) {
+                    Ok(count) => {
+                        app.model.sprite_sheet.status = Some(fl!("sprite-done", count: count));
+                    }
+                    Err(e) => app.model.set_error(format!("Sprite export failed: {e}")),
+                }
+            }
+        }
+
+        AppMessage::ExportSpriteAtlas => {
+            if app.model.sprite_sheet.grid().is_some()
+                && let Some(path) = app.document_manager.current_path()
+            {
+                let file_name = path
+                    .file_stem()
+                    .map(|s| format!("{}.json", s.to_string_lossy()))
+                    .unwrap_or_else(|| "atlas.json".to_string());
+                return UpdateResult::Task(save_file_dialog(
+                    fl!("dialog-sprite-atlas"),
+                    file_name,
+                    AppMessage::SaveSpriteAtlas,
+                ));
+            }
+        }
+
+        AppMessage::SaveSpriteAtlas(target) => {
+            if let Some(grid) = app.model.sprite_sheet.grid()
+                && let Some(doc) = app.document_manager.current_document()
+                && let Some(path) = app.document_manager.current_path()
+            {
+                let json = sprite_sheet::atlas_json(grid, path, doc.dimensions());
+                match std::fs::write(target, json) {
+                    Ok(()) => {
+                        app.model.sprite_sheet.status =
+                            Some(fl!("sprite-atlas-saved", file: target.display().to_string()));
+                    }
+                    Err(e) => app.model.set_error(format!("Failed to save atlas: {e}")),
+                }
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
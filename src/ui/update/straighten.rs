@@ -0,0 +1,177 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/straighten.rs
+//
+// Straightening the document.
+
+use super::UpdateResult;
+use super::render::cache_render;
+use super::transform::record_transform;
+use crate::application::commands::straighten_document::StraightenDocumentCommand;
+use crate::application::commands::transform_document::{TransformDocumentCommand, TransformOperation};
+use crate::application::edit_history::EditOperation;
+use crate::domain::document::operations::straighten::{self, FineRotation, GestureRotation};
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, RotateGesture, StraightenTool, ViewMode};
+
+/// Straighten the document by angle, line or gesture.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::SetStraightenAngle(angle) => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode {
+                straighten.angle = angle.clamp(-straighten::MAX_ANGLE, straighten::MAX_ANGLE);
+            }
+        }
+
+        AppMessage::SetStraightenEdges(edges) => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode {
+                straighten.edges = *edges;
+            }
+        }
+
+        AppMessage::SetStraightenFilter(filter) => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode {
+                straighten.filter = *filter;
+            }
+        }
+
+        AppMessage::ToggleStraightenLine => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode {
+                straighten.drawing = !straighten.drawing;
+                straighten.line = None;
+            }
+        }
+
+        AppMessage::StraightenLineStart { x, y } => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode
+                && straighten.drawing
+            {
+                straighten.line = Some(((*x, *y), (*x, *y)));
+            }
+        }
+
+        AppMessage::StraightenLineMove { x, y } => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode
+                && let Some((_, end)) = &mut straighten.line
+            {
+                *end = (*x, *y);
+            }
+        }
+
+        AppMessage::StraightenLineEnd => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode
+                && let Some((start, end)) = straighten.line.take()
+            {
+                // A click is no line; keep drawing
+                let long_enough =
+                    (end.0 - start.0).hypot(end.1 - start.1) >= StraightenTool::MIN_LINE_LENGTH;
+                if long_enough && let Some(correction) = straighten::line_angle(start, end) {
+                    // The line was drawn over the preview, which is already turned
+                    straighten.angle = (straighten.angle + correction)
+                        .clamp(-straighten::MAX_ANGLE, straighten::MAX_ANGLE);
+                    straighten.drawing = false;
+                }
+            }
+        }
+
+        AppMessage::RotateGesture(turned) => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode
+                && !straighten.drawing
+            {
+                let gesture = straighten.gesture.get_or_insert(RotateGesture {
+                    start_angle: straighten.angle,
+                    turned: 0.0,
+                    snapped: false,
+                });
+                gesture.turned = *turned;
+                gesture.snapped = GestureRotation::split(
+                    gesture.start_angle + gesture.turned,
+                    StraightenTool::GESTURE_SNAP,
+                )
+                .snapped;
+            }
+        }
+
+        AppMessage::RotateGestureEnd => {
+            let AppMode::Transform { straighten, .. } = &mut app.model.mode else {
+                return UpdateResult::None;
+            };
+            let Some(gesture) = straighten.gesture.take() else {
+                return UpdateResult::None;
+            };
+            let rotation = GestureRotation::split(
+                gesture.start_angle + gesture.turned,
+                StraightenTool::GESTURE_SNAP,
+            );
+            // Documents without fine rotation only take the quarter turns
+            if app
+                .document_manager
+                .current_document()
+                .is_some_and(|doc| doc.supports_fine_rotation())
+            {
+                straighten.angle = rotation.degrees;
+            }
+
+            // Whole quarter turns are lossless, so they go to the document
+            let operation = if rotation.quarter_turns < 0 {
+                TransformOperation::RotateCcw
+            } else {
+                TransformOperation::RotateCw
+            };
+            for _ in 0..rotation.quarter_turns.unsigned_abs() {
+                let cmd = TransformDocumentCommand::new(operation);
+                if let Err(e) = cmd.execute(&mut app.document_manager) {
+                    app.model.set_error(format!("Rotate failed: {e}"));
+                    break;
+                }
+                record_transform(&mut app.model, &app.document_manager, operation);
+            }
+            if rotation.quarter_turns != 0 {
+                cache_render(&mut app.model, &mut app.document_manager);
+            }
+        }
+
+        AppMessage::ApplyStraighten => {
+            let AppMode::Transform { straighten, .. } = &mut app.model.mode else {
+                return UpdateResult::None;
+            };
+            let rotation = FineRotation::new(straighten.angle, straighten.edges, straighten.filter);
+            if rotation.is_none() {
+                return UpdateResult::None;
+            }
+            match StraightenDocumentCommand::new(rotation).execute(&mut app.document_manager) {
+                Ok(()) => {
+                    *straighten = StraightenTool {
+                        edges: straighten.edges,
+                        filter: straighten.filter,
+                        ..StraightenTool::default()
+                    };
+                    app.model.history.record(
+                        EditOperation::Straighten(rotation),
+                        app.document_manager.current_page(),
+                    );
+                    // Reset view to fit the new image size
+                    app.model.viewport.scale = 1.0;
+                    app.model.viewport.fit_mode = ViewMode::Fit;
+                    app.model.reset_pan();
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+                Err(e) => app.model.set_error(format!("Straighten failed: {e}")),
+            }
+        }
+
+        AppMessage::ResetStraighten => {
+            if let AppMode::Transform { straighten, .. } = &mut app.model.mode {
+                *straighten = StraightenTool {
+                    edges: straighten.edges,
+                    filter: straighten.filter,
+                    ..StraightenTool::default()
+                };
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
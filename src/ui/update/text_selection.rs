@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/text_selection.rs
+//
+// Selecting and copying text and SVG sources.
+
+use super::UpdateResult;
+use crate::application::commands::crop_document::CropDocumentCommand;
+use crate::domain::document::core::content::{DocumentContent, DocumentKind};
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::clipboard::SvgContents;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, Viewport};
+use crate::ui::widgets::CropSelection;
+
+/// Select text in the document and copy it.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ToggleTextSelectMode => {
+            let has_text = app
+                .document_manager
+                .current_document()
+                .is_some_and(|doc| doc.kind() == DocumentKind::Portable);
+            app.model.mode = match &app.model.mode {
+                AppMode::SelectText { .. } => AppMode::View,
+                _ if has_text => AppMode::SelectText {
+                    selection: CropSelection::default(),
+                    highlights: Vec::new(),
+                },
+                _ => return UpdateResult::None,
+            };
+        }
+
+        AppMessage::TextDragStart { x, y } => {
+            if let AppMode::SelectText {
+                selection,
+                highlights,
+            } = &mut app.model.mode
+            {
+                selection.start_new_selection(*x, *y);
+                highlights.clear();
+            }
+        }
+
+        AppMessage::TextDragMove { x, y, max_x, max_y } => {
+            if let AppMode::SelectText {
+                selection,
+                highlights,
+            } = &mut app.model.mode
+            {
+                selection.update_drag(*x, *y, *max_x, *max_y);
+                *highlights =
+                    text_highlights(&app.model.viewport, selection, &mut app.document_manager);
+            }
+        }
+
+        AppMessage::TextDragEnd => {
+            if let AppMode::SelectText { selection, .. } = &mut app.model.mode {
+                selection.end_drag();
+            }
+        }
+
+        AppMessage::CopySelectedText => {
+            let AppMode::SelectText { selection, .. } = &app.model.mode else {
+                return UpdateResult::None;
+            };
+            let Some(region) = app.model.viewport.image_region(selection) else {
+                return UpdateResult::None;
+            };
+            match app
+                .document_manager
+                .current_document_mut()
+                .and_then(|doc| doc.selected_text(region))
+            {
+                Some(text) if !text.is_empty() => {
+                    return UpdateResult::Task(cosmic::iced::clipboard::write(text));
+                }
+                Some(_) => {}
+                None => app.model.set_error(fl!("text-select-unavailable")),
+            }
+        }
+
+        AppMessage::CopyAsSvg => {
+            if let Some(markup) = app
+                .document_manager
+                .current_document()
+                .and_then(DocumentContent::svg_markup)
+            {
+                return UpdateResult::Task(cosmic::iced::clipboard::write_data(SvgContents(
+                    markup,
+                )));
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Canvas boxes of the characters under a text selection.
+fn text_highlights(
+    viewport: &Viewport,
+    selection: &CropSelection,
+    manager: &mut crate::application::DocumentManager,
+) -> Vec<(f32, f32, f32, f32)> {
+    let Some(region) = viewport.image_region(selection) else {
+        return Vec::new();
+    };
+    let Some(doc) = manager.current_document_mut() else {
+        return Vec::new();
+    };
+    doc.selected_text_boxes(region)
+        .into_iter()
+        .map(|rect| {
+            CropDocumentCommand::new(rect.x, rect.y, rect.width, rect.height).to_canvas_selection(
+                viewport.canvas_size,
+                viewport.image_size,
+                viewport.scale,
+                cosmic::iced::Vector::new(viewport.pan_x, viewport.pan_y),
+            )
+        })
+        .collect()
+}
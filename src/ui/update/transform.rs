@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/transform.rs
+//
+// Rotating and flipping the document.
+
+use super::UpdateResult;
+use super::render::cache_render;
+use crate::application::commands::transform_document::{TransformDocumentCommand, TransformOperation};
+use crate::application::edit_history::EditOperation;
+use crate::domain::document::operations::edit_macro::MacroStep;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, AppModel};
+
+/// Rotate and flip the document.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::FlipHorizontal => {
+            // Ignore transformations in Crop mode (would invalidate selection)
+            if !matches!(app.model.mode, AppMode::Crop { .. }) {
+                let cmd = TransformDocumentCommand::new(TransformOperation::FlipHorizontal);
+                if let Err(e) = cmd.execute(&mut app.document_manager) {
+                    app.model.set_error(format!("Flip horizontal failed: {e}"));
+                } else {
+                    record_transform(
+                        &mut app.model,
+                        &app.document_manager,
+                        TransformOperation::FlipHorizontal,
+                    );
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+            }
+        }
+
+        AppMessage::FlipVertical => {
+            // Ignore transformations in Crop mode (would invalidate selection)
+            if !matches!(app.model.mode, AppMode::Crop { .. }) {
+                let cmd = TransformDocumentCommand::new(TransformOperation::FlipVertical);
+                if let Err(e) = cmd.execute(&mut app.document_manager) {
+                    app.model.set_error(format!("Flip vertical failed: {e}"));
+                } else {
+                    record_transform(
+                        &mut app.model,
+                        &app.document_manager,
+                        TransformOperation::FlipVertical,
+                    );
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+            }
+        }
+
+        AppMessage::RotateCW => {
+            // Ignore transformations in Crop mode (would invalidate selection)
+            if !matches!(app.model.mode, AppMode::Crop { .. }) {
+                let cmd = TransformDocumentCommand::new(TransformOperation::RotateCw);
+                if let Err(e) = cmd.execute(&mut app.document_manager) {
+                    app.model.set_error(format!("Rotate clockwise failed: {e}"));
+                } else {
+                    record_transform(
+                        &mut app.model,
+                        &app.document_manager,
+                        TransformOperation::RotateCw,
+                    );
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+            }
+        }
+
+        AppMessage::RotateCCW => {
+            // Ignore transformations in Crop mode (would invalidate selection)
+            if !matches!(app.model.mode, AppMode::Crop { .. }) {
+                let cmd = TransformDocumentCommand::new(TransformOperation::RotateCcw);
+                if let Err(e) = cmd.execute(&mut app.document_manager) {
+                    app.model.set_error(format!("Rotate CCW failed: {e}"));
+                } else {
+                    record_transform(
+                        &mut app.model,
+                        &app.document_manager,
+                        TransformOperation::RotateCcw,
+                    );
+                    cache_render(&mut app.model, &mut app.document_manager);
+                }
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Remember an applied transform in the edit history, orientation lock and macro recording.
+pub(super) fn record_transform(
+    model: &mut AppModel,
+    manager: &crate::application::DocumentManager,
+    operation: TransformOperation,
+) {
+    model
+        .history
+        .record(EditOperation::Transform(operation), manager.current_page());
+    if let Some(steps) = &mut model.macros.recording {
+        steps.extend(match operation {
+            TransformOperation::RotateCw => Some(MacroStep::RotateCw),
+            TransformOperation::RotateCcw => Some(MacroStep::RotateCcw),
+            TransformOperation::FlipHorizontal => Some(MacroStep::FlipHorizontal),
+            TransformOperation::FlipVertical => Some(MacroStep::FlipVertical),
+            TransformOperation::RotateTo(_) => None,
+        });
+    }
+    // The lock follows the document: one net state, however many turns
+    if let Some(lock) = &mut model.orientation_lock {
+        *lock = operation.apply_to(*lock);
+    }
+}
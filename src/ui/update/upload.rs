@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/upload.rs
+//
+// Uploading the document and the upload history.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use super::save::save_file_name;
+use super::share::export_for_sharing;
+use crate::application::services::upload;
+use crate::domain::document::core::cancel::CancelToken;
+use crate::fl;
+use crate::infrastructure::system::upload::UploadEndpoint;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+
+/// Upload the document and open or copy uploaded links.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::Upload => {
+            if app.model.upload.is_running() {
+                return UpdateResult::None;
+            }
+            let Some(endpoint) = UploadEndpoint::parse(&app.config.upload_endpoint) else {
+                app.model.set_error(fl!("upload-no-endpoint"));
+                return UpdateResult::None;
+            };
+            let Some(file_name) = save_file_name(app) else {
+                return UpdateResult::None;
+            };
+            let file = match export_for_sharing(app, &file_name) {
+                Ok(file) => file,
+                Err(e) => {
+                    app.model.set_error(format!("Upload failed: {e}"));
+                    return UpdateResult::None;
+                }
+            };
+            let token = CancelToken::new();
+            let state = &mut app.model.upload;
+            state.progress = Some(0);
+            state.cancel = Some(token.clone());
+            state.status = None;
+            state.file_name = file
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned());
+            return UpdateResult::Task(Task::run(
+                upload::upload_in_background(endpoint, file, token),
+                |event| Action::App(AppMessage::UploadProgress(event)),
+            ));
+        }
+
+        AppMessage::CancelUpload => {
+            if let Some(token) = &app.model.upload.cancel {
+                token.cancel();
+            }
+        }
+
+        AppMessage::UploadEndpointInput(input) => {
+            app.model.upload.endpoint_input = input.clone();
+        }
+
+        AppMessage::CopyUploadLink(index) => {
+            if let Some((_, link)) = app.config.upload_history.get(*index) {
+                return UpdateResult::Task(cosmic::iced::clipboard::write(link.clone()));
+            }
+        }
+
+        AppMessage::OpenUploadLink(index) => {
+            if let Some((_, link)) = app.config.upload_history.get(*index)
+                && let Err(e) = open::that_detached(link)
+            {
+                app.model.set_error(format!("Failed to open link: {e}"));
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
@@ -0,0 +1,392 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/view.rs
+//
+// Zoom, pan and view options of the canvas.
+
+use std::path::PathBuf;
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use super::render::{cache_render, render_zoom, zoom_cache_key};
+use crate::application::services::thumbnail_worker;
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::operations::{pixel_art, strip};
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{
+    AppMode, AppModel, FindState, HalfPage, LowPowerMode, StraightenTool, StripState, TilePreview,
+    ViewLayout, ViewMode, Viewport,
+};
+
+/// Zoom, pan and change how the document is shown.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::PowerStateChanged(power) => {
+            app.model.power = *power;
+            apply_low_power(&mut app.model, &app.config);
+        }
+
+        AppMessage::ZoomIn => {
+            app.model.viewport.scale = if app.model.viewport.pixel_art {
+                pixel_art::integer_zoom_step(app.model.viewport.scale, true)
+            } else {
+                (app.model.viewport.scale * 1.2).min(10.0)
+            };
+            app.model.viewport.fit_mode = ViewMode::Custom;
+        }
+
+        AppMessage::ZoomOut => {
+            app.model.viewport.scale = if app.model.viewport.pixel_art {
+                pixel_art::integer_zoom_step(app.model.viewport.scale, false).max(0.1)
+            } else {
+                (app.model.viewport.scale / 1.2).max(0.1)
+            };
+            app.model.viewport.fit_mode = ViewMode::Custom;
+        }
+
+        AppMessage::RotateView => {
+            app.model.viewport.view_rotation = (app.model.viewport.view_rotation + 1) % 4;
+            app.model.reset_pan();
+        }
+
+        AppMessage::TogglePixelArt => {
+            app.model.viewport.pixel_art = !app.model.viewport.pixel_art;
+            if app.model.viewport.pixel_art {
+                snap_to_integer_fit(&mut app.model, &app.document_manager);
+            }
+        }
+
+        AppMessage::ToggleAlphaMask => {
+            app.model.viewport.alpha_mask = !app.model.viewport.alpha_mask;
+            cache_render(&mut app.model, &mut app.document_manager);
+        }
+
+        AppMessage::CycleTilePreview => {
+            app.model.viewport.tile_preview = app.model.viewport.tile_preview.next();
+            cache_render(&mut app.model, &mut app.document_manager);
+        }
+
+        AppMessage::ToggleMagnifier => {
+            let viewport = &mut app.model.viewport;
+            viewport.magnifier = !viewport.magnifier;
+            viewport.scale = if viewport.magnifier {
+                app.config.magnifier_zoom
+            } else {
+                1.0
+            };
+            app.model.reset_pan();
+        }
+
+        AppMessage::ToggleStripMode => {
+            if let Some(state) = app.model.strip.take() {
+                // Back to the page view at the image scrolled to
+                if let Some(page) = state.pages.get(state.layout().page_at(state.offset))
+                    && app.document_manager.current_path() != Some(page.path.as_path())
+                {
+                    return super::update(app, &AppMessage::OpenPath(page.path.clone()));
+                }
+            } else if !matches!(app.model.mode, AppMode::Crop { .. }) {
+                let pages = strip::load_pages(app.document_manager.folder_entries());
+                if pages.is_empty() {
+                    app.model.set_error(fl!("strip-no-images"));
+                    return UpdateResult::None;
+                }
+                let start = strip::page_index(&pages, app.document_manager.current_path());
+                let mut state = StripState {
+                    pages,
+                    offset: 0.0,
+                    view_size: app.model.viewport.canvas_size,
+                };
+                state.offset = state.layout().top(start);
+                let task = scroll_strip(&app.model, state.offset);
+                app.model.strip = Some(state);
+                app.model.layout = ViewLayout::Single;
+                return UpdateResult::Task(task);
+            }
+        }
+
+        AppMessage::StripScrolled { offset, view_size } => {
+            if let Some(state) = app.model.strip.as_mut() {
+                state.offset = *offset;
+                state.view_size = *view_size;
+            }
+        }
+
+        AppMessage::ScrollScreen(forward) => {
+            if let Some(state) = &app.model.strip {
+                let offset = state.screen_offset(*forward);
+                return UpdateResult::Task(scroll_strip(&app.model, offset));
+            }
+        }
+
+        AppMessage::ZoomReset => {
+            app.model.viewport.scale = 1.0;
+            app.model.viewport.fit_mode = ViewMode::ActualSize;
+            app.model.reset_pan();
+        }
+
+        AppMessage::ZoomFit => {
+            app.model.viewport.fit_mode = ViewMode::Fit;
+            app.model.reset_pan();
+        }
+
+        AppMessage::ScaleFactorChanged(factor) => {
+            app.model.viewport.scale_factor = *factor;
+        }
+
+        AppMessage::ViewerStateChanged {
+            scale,
+            offset_x,
+            offset_y,
+            canvas_size,
+            image_size,
+        } => {
+            // Detect scale changes (zoom vs just pan)
+            let old_scale = app.model.viewport.scale;
+            let resized = app.model.viewport.canvas_size != *canvas_size;
+
+            // Update model from viewer state
+            app.model.viewport.scale = *scale;
+            app.model.viewport.pan_x = *offset_x;
+            app.model.viewport.pan_y = *offset_y;
+            app.model.viewport.canvas_size = *canvas_size;
+            app.model.viewport.image_size = *image_size;
+
+            // If scale changed, user zoomed -> switch to Custom mode and re-render
+            // (Fit mode is only maintained when explicitly set via ZoomFit button)
+            if (old_scale - *scale).abs() > 0.001 {
+                app.model.viewport.fit_mode = ViewMode::Custom;
+                cache_render(&mut app.model, &mut app.document_manager);
+            } else if resized
+                && app.model.viewport.sharpen
+                && app.model.viewport.fit_mode == ViewMode::Fit
+            {
+                // Sharpen for the new size once the window stops resizing
+                let viewport = &mut app.model.viewport;
+                viewport.sharpen_revision += 1;
+                let revision = viewport.sharpen_revision;
+                return UpdateResult::Task(Task::perform(
+                    tokio::time::sleep(Viewport::SHARPEN_DELAY),
+                    move |()| Action::App(AppMessage::RefreshSharpening(revision)),
+                ));
+            }
+        }
+
+        AppMessage::RefreshSharpening(revision) => {
+            if *revision == app.model.viewport.sharpen_revision {
+                cache_render(&mut app.model, &mut app.document_manager);
+            }
+        }
+
+        AppMessage::RenderZoom(revision) => {
+            let viewport = &app.model.viewport;
+            if *revision != viewport.zoom_revision {
+                return UpdateResult::None;
+            }
+            let Some(job) = app
+                .document_manager
+                .current_document()
+                .and_then(|doc| doc.zoom_render_job(f64::from(viewport.display_scale())))
+            else {
+                return UpdateResult::None;
+            };
+            let monitor = app
+                .model
+                .color
+                .enabled
+                .then(|| app.model.color.monitor.clone());
+            let revision = *revision;
+            return UpdateResult::Task(Task::perform(
+                tokio::task::spawn_blocking(move || render_zoom(&job, monitor)),
+                move |rendered| {
+                    Action::App(AppMessage::ZoomRendered(revision, rendered.ok().flatten()))
+                },
+            ));
+        }
+
+        AppMessage::ZoomRendered(revision, rendered) => {
+            if *revision == app.model.viewport.zoom_revision {
+                if let Some((scale, handle)) = rendered
+                    && let Some(key) = zoom_cache_key(&app.model, &app.document_manager)
+                {
+                    app.model.viewport.cache_zoom(key, *scale, handle.clone());
+                }
+                app.model.viewport.zoom_render = rendered.clone();
+            }
+        }
+
+        AppMessage::PageRefined(refined) => {
+            if app.document_manager.finish_refine(refined) {
+                cache_render(&mut app.model, &mut app.document_manager);
+            }
+        }
+
+        AppMessage::PanLeft => {
+            app.model.viewport.pan_x -= 50.0;
+        }
+
+        AppMessage::PanRight => {
+            app.model.viewport.pan_x += 50.0;
+        }
+
+        AppMessage::PanUp => {
+            app.model.viewport.pan_y -= 50.0;
+        }
+
+        AppMessage::PanDown => {
+            app.model.viewport.pan_y += 50.0;
+        }
+
+        AppMessage::PanReset => {
+            app.model.reset_pan();
+        }
+
+        AppMessage::ZoomBookmarkNameInput(name) => {
+            app.model.zoom_bookmark_name_input = name.clone();
+        }
+
+        AppMessage::GoToZoomBookmark(index) => {
+            let Some((_, page, zoom, (pan_x, pan_y))) = app
+                .document_manager
+                .current_path()
+                .and_then(|path| app.config.zoom_bookmarks_of(path).get(*index))
+                .cloned()
+            else {
+                return UpdateResult::None;
+            };
+            let viewport = &mut app.model.viewport;
+            viewport.fit_mode = ViewMode::Custom;
+            viewport.scale = zoom.clamp(0.1, 10.0);
+            viewport.pan_x = pan_x;
+            viewport.pan_y = pan_y;
+            if page != app.document_manager.current_page() {
+                return super::update(app, &AppMessage::GotoPage(page));
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Reset zoom and pan to the default view mode for the current document kind.
+pub fn reset_view(
+    model: &mut AppModel,
+    manager: &crate::application::DocumentManager,
+    config: &crate::config::AppConfig,
+) {
+    model.viewport.scale = 1.0;
+    model.viewport.view_rotation = 0;
+    // A text selection belongs to the previous document
+    if matches!(model.mode, AppMode::SelectText { .. }) {
+        model.mode = AppMode::View;
+    }
+    // The straighten angle was set up for the previous image
+    if let AppMode::Transform { straighten, .. } = &mut model.mode {
+        *straighten = StraightenTool {
+            edges: straighten.edges,
+            filter: straighten.filter,
+            ..StraightenTool::default()
+        };
+    }
+    // So do find hits; the find bar stays open for other PDFs
+    let has_text = manager
+        .current_document()
+        .is_some_and(|doc| doc.kind() == DocumentKind::Portable);
+    model.find = model
+        .find
+        .take()
+        .filter(|_| has_text)
+        .map(|find| FindState {
+            query: find.query,
+            ..FindState::default()
+        });
+    model.viewport.alpha_mask = false;
+    model.viewport.tile_preview = TilePreview::Off;
+    model.viewport.magnifier = false;
+    model.viewport.fit_mode = manager
+        .current_document()
+        .and_then(|doc| config.view_modes.get(doc.kind().name()))
+        .and_then(|key| ViewMode::from_key(key))
+        .unwrap_or_default();
+    model.reset_pan();
+    if model.viewport.half_page.is_some()
+        && let Some(doc) = manager.current_document()
+    {
+        model
+            .viewport
+            .show_half_page(HalfPage::Upper, doc.dimensions());
+    }
+    model.right_to_left =
+        reading_order_key(manager).is_some_and(|key| config.is_right_to_left(&key));
+
+    // Small images with few colors are shown crisp at an integer zoom.
+    model.viewport.pixel_art = manager.current_document().is_some_and(|doc| {
+        doc.kind() == DocumentKind::Raster && pixel_art::is_pixel_art(doc.rendered_image())
+    });
+    if model.viewport.pixel_art {
+        snap_to_integer_fit(model, manager);
+    }
+}
+
+/// Cut back background work, or stop doing so, as the low-power mode of
+/// the settings and the detected power state ask.
+///
+/// Thumbnails pause after each page and a playing animation preview stops;
+/// folder rescans, zoom renders and touchpad gliding check `low_power`
+/// themselves. Decoding the image shown and the full render of a large PDF
+/// page are left alone, as they are what is on screen.
+pub fn apply_low_power(model: &mut AppModel, config: &crate::config::AppConfig) {
+    let mode = LowPowerMode::from_key(&config.low_power_mode).unwrap_or_default();
+    let low_power = mode.is_active(model.power);
+    if low_power != model.low_power {
+        tracing::info!("Low-power mode {}", if low_power { "on" } else { "off" });
+        if low_power && let Some(player) = model.animation.player.as_mut() {
+            player.pause();
+        }
+    }
+    model.low_power = low_power;
+    thumbnail_worker::set_low_power(low_power);
+}
+
+/// Scroll the webtoon strip to an offset from its top.
+fn scroll_strip(model: &AppModel, offset: f32) -> Task<Action<AppMessage>> {
+    cosmic::iced::widget::scrollable::scroll_to(
+        model.viewport.scroll_id.clone(),
+        cosmic::iced::widget::scrollable::AbsoluteOffset { x: 0.0, y: offset },
+    )
+}
+
+/// Key under which the reading order of the current document is remembered:
+/// the file of a multi-page document, the folder of a single image (a folder
+/// of scanned pages is read as one book).
+pub fn reading_order_key(manager: &crate::application::DocumentManager) -> Option<PathBuf> {
+    let path = manager.current_path()?;
+    if manager
+        .current_document()
+        .is_some_and(|doc| doc.is_multi_page())
+    {
+        Some(path.to_path_buf())
+    } else {
+        path.parent().map(Into::into)
+    }
+}
+
+/// Zoom to the largest integer scale that shows the whole document.
+fn snap_to_integer_fit(model: &mut AppModel, manager: &crate::application::DocumentManager) {
+    let Some(doc) = manager.current_document() else {
+        return;
+    };
+    let canvas = model.viewport.canvas_size;
+    model.viewport.scale =
+        pixel_art::integer_fit_scale(doc.dimensions(), (canvas.width, canvas.height));
+    model.viewport.fit_mode = if model.viewport.scale > 1.0 {
+        ViewMode::Custom
+    } else {
+        ViewMode::ActualSize
+    };
+    model.reset_pan();
+}
@@ -0,0 +1,106 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/update/wallpaper.rs
+//
+// Setting the wallpaper.
+
+use cosmic::{Action, Task};
+
+use super::UpdateResult;
+use crate::application::commands::crop_document::CropDocumentCommand;
+use crate::domain::document::operations::CropRegion;
+use crate::fl;
+use crate::ui::NoctuaApp;
+use crate::ui::message::AppMessage;
+use crate::ui::model::{AppMode, AppModel};
+use crate::ui::widgets::CropSelection;
+
+/// Set the image, or a frame of it, as wallpaper.
+pub(super) fn update(app: &mut NoctuaApp, msg: &AppMessage) -> UpdateResult {
+    match msg {
+        AppMessage::ApplyWallpaper => {
+            let AppMode::Crop { selection } = &app.model.mode else {
+                return UpdateResult::None;
+            };
+            let (Some(region), Some(doc)) = (
+                app.model.viewport.image_region(selection),
+                app.document_manager.current_document(),
+            ) else {
+                app.model.set_error(fl!("wallpaper-no-frame"));
+                return UpdateResult::None;
+            };
+            let image = doc.rendered_image();
+            let image = image.crop_imm(region.x, region.y, region.width, region.height);
+            tracing::info!(
+                "Setting wallpaper to {}x{}+{}+{} of the image",
+                region.width,
+                region.height,
+                region.x,
+                region.y
+            );
+            app.model.mode = AppMode::View;
+            return UpdateResult::Task(Task::perform(
+                tokio::task::spawn_blocking(move || {
+                    crate::infrastructure::system::set_cropped_as_wallpaper(&image)
+                        .map_err(|e| format!("{e:#}"))
+                }),
+                |result| {
+                    Action::App(AppMessage::WallpaperApplied(
+                        result.unwrap_or_else(|e| Err(e.to_string())),
+                    ))
+                },
+            ));
+        }
+
+        AppMessage::SetWholeImageAsWallpaper => {
+            if let Some(path) = app.document_manager.current_path() {
+                tracing::info!("Setting wallpaper to: {}", path.display());
+                if !crate::infrastructure::system::set_as_wallpaper(path) {
+                    app.model.set_error(fl!("wallpaper-unsupported"));
+                }
+                if matches!(app.model.mode, AppMode::Crop { .. }) {
+                    app.model.mode = AppMode::View;
+                }
+            } else {
+                app.model.set_error("No image loaded".to_string());
+            }
+        }
+
+        AppMessage::WallpaperApplied(result) => {
+            if let Err(e) = result {
+                tracing::error!("Failed to set wallpaper: {e}");
+                let message = fl!("wallpaper-failed", error: e.as_str());
+                app.model.set_error(message);
+            }
+        }
+
+        _ => {}
+    }
+
+    UpdateResult::None
+}
+
+/// Crop selection framing the largest part of the current document with
+/// the screen's aspect ratio `(width, height)`, centered and locked to it.
+pub fn wallpaper_frame(
+    model: &AppModel,
+    manager: &crate::application::DocumentManager,
+    ratio: (u32, u32),
+) -> Option<CropSelection> {
+    let (width, height) = manager.current_document()?.dimensions();
+    if width == 0 || height == 0 || ratio.0 == 0 || ratio.1 == 0 {
+        return None;
+    }
+    let region = CropRegion::centered_with_aspect(width, height, ratio);
+    let viewport = &model.viewport;
+    let canvas_rect = CropDocumentCommand::new(region.x, region.y, region.width, region.height)
+        .to_canvas_selection(
+            viewport.canvas_size,
+            viewport.image_size,
+            viewport.scale,
+            cosmic::iced::Vector::new(viewport.pan_x, viewport.pan_y),
+        );
+    Some(CropSelection::with_aspect(
+        canvas_rect,
+        ratio.0 as f32 / ratio.1 as f32,
+    ))
+}
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/adjust_panel.rs
+//
+// Color adjustments panel: sliders shown on top of the image until saving.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, horizontal_space, icon, row, slider, text, toggler};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::domain::document::operations::adjust::Adjustment;
+use crate::domain::document::operations::threshold::Threshold;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the color adjustments panel view.
+pub fn view(model: &AppModel, manager: &DocumentManager) -> Element<'static, AppMessage> {
+    let mut content = column::with_capacity(11)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("adjust-title")));
+
+    if !manager
+        .current_document()
+        .is_some_and(|doc| doc.supports_adjustments())
+    {
+        return content
+            .push(text::caption(fl!("adjust-unsupported")))
+            .into();
+    }
+
+    let values = model.adjust.values;
+    content = content.push(text::caption(fl!("adjust-hint")));
+    for adjustment in Adjustment::ALL {
+        let value = values.get(adjustment);
+        let changed = value != adjustment.neutral();
+        content = content.push(
+            column::with_capacity(2)
+                .spacing(4)
+                .push(
+                    row::with_capacity(3)
+                        .align_y(Alignment::Center)
+                        .push(text::body(format!(
+                            "{}: {}",
+                            label(adjustment),
+                            value_display(adjustment, value)
+                        )))
+                        .push(horizontal_space().width(Length::Fill))
+                        .push(
+                            button::icon(icon::from_name("edit-undo-symbolic"))
+                                .tooltip(fl!("adjust-reset"))
+                                .padding(4)
+                                .on_press_maybe(
+                                    changed.then_some(AppMessage::ResetAdjustment(adjustment)),
+                                ),
+                        ),
+                )
+                .push(
+                    slider(adjustment.range(), value, move |value| {
+                        AppMessage::SetAdjustment(adjustment, value)
+                    })
+                    .step(adjustment.step()),
+                ),
+        );
+    }
+
+    // Black and white for scans, after the tones above
+    let threshold = values.threshold;
+    content = content
+        .push(
+            toggler(threshold.is_some())
+                .label(fl!("adjust-bilevel"))
+                .on_toggle(|on| AppMessage::SetThreshold(on.then(Threshold::default))),
+        )
+        .push_maybe(threshold.map(|threshold| {
+            column::with_capacity(4)
+                .spacing(4)
+                .push(text::body(fl!("adjust-threshold", level: threshold.level)))
+                .push(slider(Threshold::RANGE, threshold.level, move |level| {
+                    AppMessage::SetThreshold(Some(Threshold { level, ..threshold }))
+                }))
+                .push(
+                    toggler(threshold.adaptive)
+                        .label(fl!("adjust-threshold-adaptive"))
+                        .on_toggle(move |adaptive| {
+                            AppMessage::SetThreshold(Some(Threshold {
+                                adaptive,
+                                ..threshold
+                            }))
+                        }),
+                )
+                .push(text::caption(fl!("adjust-bilevel-hint")))
+        }));
+
+    content
+        .push(
+            button::standard(fl!("adjust-reset-all"))
+                .on_press_maybe((!values.is_neutral()).then_some(AppMessage::ResetAdjustments)),
+        )
+        .into()
+}
+
+/// Slider label of `adjustment`.
+fn label(adjustment: Adjustment) -> String {
+    match adjustment {
+        Adjustment::Exposure => fl!("adjust-exposure"),
+        Adjustment::Brightness => fl!("adjust-brightness"),
+        Adjustment::Contrast => fl!("adjust-contrast"),
+        Adjustment::Gamma => fl!("adjust-gamma"),
+        Adjustment::Saturation => fl!("adjust-saturation"),
+    }
+}
+
+/// Value of `adjustment` with its unit.
+fn value_display(adjustment: Adjustment, value: f32) -> String {
+    match adjustment {
+        Adjustment::Exposure => format!("{value:+.2} EV"),
+        Adjustment::Gamma => format!("{value:.2}"),
+        Adjustment::Brightness | Adjustment::Contrast | Adjustment::Saturation => {
+            format!("{value:+.0} %")
+        }
+    }
+}
@@ -0,0 +1,155 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/animation_panel.rs
+//
+// Animation panel: play the animation, extract frames and write animated
+// GIFs and WebPs.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{button, column, divider, dropdown, icon, row, slider, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::domain::document::operations::animation::{self, AnimationFormat};
+use crate::ui::model::AnimationState;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the animation panel view.
+pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let state = &model.animation;
+    let current = manager.current_path();
+    let sources = AnimationState::sources(manager.folder_entries(), current);
+
+    let summary = match sources.as_slice() {
+        [] => fl!("animation-none"),
+        [gif] if animation::is_gif(gif) => fl!("animation-gif"),
+        frames => fl!("animation-sequence", count: frames.len()),
+    };
+    let can_export = !sources.is_empty() && state.settings().is_some();
+
+    let mut content = column::with_capacity(21)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("animation-title")));
+
+    // --- Playback preview ---
+    if state.frame_count.is_some() {
+        content = content.push(text::heading(fl!("animation-playback")));
+        content = match &state.player {
+            Some(player) => {
+                let (play_icon, play_label) = if player.is_playing() {
+                    ("media-playback-pause-symbolic", fl!("animation-pause"))
+                } else {
+                    ("media-playback-start-symbolic", fl!("animation-play"))
+                };
+                let last = player.frames.len().saturating_sub(1) as u32;
+                content
+                    .push(
+                        row::with_capacity(3)
+                            .spacing(8)
+                            .align_y(Alignment::Center)
+                            .push(
+                                button::icon(icon::from_name(play_icon))
+                                    .tooltip(play_label)
+                                    .on_press(AppMessage::ToggleAnimationPlayback),
+                            )
+                            .push(text::body(fl!("animation-speed")))
+                            .push(dropdown(
+                                &state.speed_labels,
+                                Some(player.speed),
+                                AppMessage::SetAnimationSpeed,
+                            )),
+                    )
+                    .push(slider(
+                        0..=last,
+                        player.current as u32,
+                        AppMessage::SeekAnimation,
+                    ))
+                    .push(text::body(fl!("animation-step")))
+                    .push(
+                        row::with_capacity(3)
+                            .spacing(8)
+                            .align_y(Alignment::Center)
+                            .push(
+                                button::icon(icon::from_name("go-previous-symbolic"))
+                                    .tooltip(fl!("animation-step-back"))
+                                    .on_press_maybe(
+                                        state.step().map(|_| AppMessage::StepAnimation(false)),
+                                    ),
+                            )
+                            .push(
+                                text_input("1", state.step_input.as_str())
+                                    .on_input(AppMessage::AnimationStepInput)
+                                    .on_submit(|_| AppMessage::StepAnimation(true)),
+                            )
+                            .push(
+                                button::icon(icon::from_name("go-next-symbolic"))
+                                    .tooltip(fl!("animation-step-forward"))
+                                    .on_press_maybe(
+                                        state.step().map(|_| AppMessage::StepAnimation(true)),
+                                    ),
+                            ),
+                    )
+                    .push(text::caption(fl!("animation-playback-hint")))
+            }
+            None => content.push(text::caption(fl!("animation-playback-too-large"))),
+        };
+        content = content.push(divider::horizontal::light());
+    }
+
+    // --- Frames of the current image ---
+    content = content.push(text::heading(fl!("animation-frames")));
+    content = match state.frame_count {
+        Some(count) => content
+            .push(text::body(fl!("animation-frame-count", count: count)))
+            .push(
+                row::with_capacity(2)
+                    .spacing(8)
+                    .push(
+                        text_input("1", state.frame_input.as_str())
+                            .on_input(AppMessage::AnimationFrameInput)
+                            .on_submit(|_| AppMessage::SaveAnimationFrame),
+                    )
+                    .push(
+                        button::standard(fl!("animation-save-frame")).on_press_maybe(
+                            state.frame_index().map(|_| AppMessage::SaveAnimationFrame),
+                        ),
+                    ),
+            )
+            .push(
+                button::standard(fl!("animation-extract-frames"))
+                    .on_press(AppMessage::ExtractAnimationFrames),
+            )
+            .push(text::caption(fl!("animation-frames-hint"))),
+        None => content.push(text::body(fl!("animation-not-animated"))),
+    };
+
+    // --- Animation export ---
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("animation-gif-title")))
+        .push(text::caption(fl!("animation-hint")))
+        .push(text::body(summary))
+        .push(text::body(fl!("animation-fps")))
+        .push(text_input("12", state.fps_input.as_str()).on_input(AppMessage::AnimationFpsInput))
+        .push(text::body(fl!("animation-loops")))
+        .push(text_input("0", state.loops_input.as_str()).on_input(AppMessage::AnimationLoopsInput))
+        .push(text::body(fl!("animation-format")))
+        .push(dropdown(
+            &state.format_labels,
+            AnimationFormat::ALL
+                .iter()
+                .position(|format| *format == state.format),
+            AppMessage::SetAnimationFormat,
+        ))
+        .push(
+            button::suggested(fl!("animation-export"))
+                .on_press_maybe(can_export.then_some(AppMessage::ExportAnimation)),
+        );
+
+    if let Some(ref status) = state.status {
+        content = content.push(text::caption(status.clone()));
+    }
+
+    content.into()
+}
@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/batch_panel.rs
+//
+// Batch conversion panel: convert the selected (or all) folder images to
+// another format, size and quality, with the progress of the queued jobs
+// and the images that failed.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{
+    button, column, divider, dropdown, icon, progress_bar, row, slider, text, text_input, toggler,
+};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::domain::document::operations::export::ExportFormat;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+use super::{export_profile_picker, selection_list};
+
+/// Build the batch conversion panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let state = &model.batch;
+    let export = &model.export_profiles;
+    let without_profile = export.selected.is_none();
+    let fit_valid = state.fit().is_ok();
+
+    // Without a selection the whole folder is converted
+    let selected = model.selection.len();
+    let convert_label = if selected > 0 {
+        fl!("batch-convert", count: selected)
+    } else {
+        fl!("batch-convert-all", count: manager.folder_entries().len())
+    };
+    let can_convert = !manager.folder_entries().is_empty() && (fit_valid || !without_profile);
+
+    let mut content = column::with_capacity(16)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("batch-title")))
+        .push(text::caption(fl!("batch-hint")))
+        .push(selection_list::view(model, manager))
+        .push(export_profile_picker::view(model, config))
+        .push_maybe(without_profile.then(|| {
+            column::with_capacity(2)
+                .spacing(4)
+                .push(text::body(fl!("batch-format")))
+                .push(dropdown(
+                    &export.format_labels,
+                    Some(export.format_index()),
+                    AppMessage::SelectSaveFormat,
+                ))
+        }))
+        .push_maybe(without_profile.then(|| {
+            column::with_capacity(3)
+                .spacing(4)
+                .push(text::body(fl!("batch-fit")))
+                .push(
+                    text_input("1920x1080", state.fit_input.as_str())
+                        .on_input(AppMessage::BatchFitInput),
+                )
+                .push(text::caption(if fit_valid {
+                    fl!("batch-fit-hint")
+                } else {
+                    fl!("batch-fit-invalid")
+                }))
+        }))
+        // Kept formats may include JPEG originals
+        .push_maybe(
+            (without_profile && export.format.is_none_or(|f| f == ExportFormat::Jpeg)).then(|| {
+                column::with_capacity(2)
+                    .spacing(4)
+                    .push(text::body(fl!("batch-quality", quality: export.quality)))
+                    .push(slider(1..=100, export.quality, AppMessage::SetSaveQuality))
+            }),
+        )
+        .push(
+            toggler(export.strip_metadata)
+                .label(fl!("batch-strip-metadata"))
+                .on_toggle(AppMessage::SetStripMetadata),
+        )
+        .push(
+            button::suggested(convert_label)
+                .on_press_maybe(can_convert.then_some(AppMessage::ConvertImages)),
+        );
+
+    if let Some((done, total)) = state.progress {
+        let label = fl!("batch-progress", done: done, total: total);
+        content = content.push(
+            row::with_capacity(3)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(progress_bar(0.0..=total.max(1) as f32, done as f32))
+                .push(text::caption(label))
+                .push(
+                    button::icon(icon::from_name("process-stop-symbolic"))
+                        .tooltip(fl!("batch-cancel"))
+                        .on_press(AppMessage::CancelBatch)
+                        .padding(4),
+                ),
+        );
+    }
+    if !state.queue.is_empty() {
+        content = content.push(text::caption(fl!(
+            "batch-queued",
+            jobs: state.queue.len(),
+            images: state.queue.image_count()
+        )));
+    }
+    content = content.push_maybe(state.status.clone().map(text::caption));
+
+    // Images that could not be converted, and why
+    if state.failures.is_empty() {
+        return content.into();
+    }
+    let heading = fl!("batch-failures", count: state.failures.len());
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(heading));
+    for (path, reason) in &state.failures {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        content = content.push(
+            column::with_capacity(2)
+                .spacing(2)
+                .width(Length::Fill)
+                .push(text::body(name))
+                .push(text::caption(reason.as_str())),
+        );
+    }
+    content.into()
+}
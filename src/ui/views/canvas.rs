@@ -3,65 +3,230 @@
 //
 // Render the center canvas area with the current document.
 
+use cosmic::iced::widget::container as iced_container;
 use cosmic::iced::widget::image::FilterMethod;
-use cosmic::iced::{ContentFit, Length};
+use cosmic::iced::{mouse, Alignment, Background, Color, ContentFit, Length, Size, Vector};
 use cosmic::iced_widget::stack;
-use cosmic::widget::{container, text};
+use cosmic::widget::image as cosmic_image;
+use cosmic::widget::{
+    button, column, container, horizontal_space, icon, mouse_area, row, scrollable, text,
+    vertical_space,
+};
 use cosmic::Element;
 
-use crate::ui::widgets::{crop_overlay, Viewer};
-use crate::ui::model::{AppMode, ViewMode};
+use crate::ui::widgets::{
+    crop_overlay, hit_overlay, line_overlay, rotate_overlay, text_overlay, Viewer,
+};
+use crate::ui::model::{
+    AnimationPlayer, AppMode, CanvasBackground, CompareMode, Comparison, Presentation,
+    StraightenTool, StripState, ViewLayout, ViewMode,
+};
 use crate::ui::{AppMessage, AppModel};
 use crate::application::DocumentManager;
+use crate::application::commands::crop_document::CropDocumentCommand;
+use crate::domain::document::core::document::{TransformState, Transformable};
+use crate::domain::document::operations::straighten::{self, RotateEdges};
 use crate::config::AppConfig;
 use crate::fl;
 
+use super::{compare_bar, find_bar};
+
 /// Render the center canvas area with the current document.
 pub fn view<'a>(
     model: &'a AppModel,
-    _manager: &'a DocumentManager,
+    manager: &'a DocumentManager,
     config: &'a AppConfig,
 ) -> Element<'a, AppMessage> {
-    // Use cached image handle from viewport
-    if let Some(handle) = &model.viewport.cached_image_handle {
-        // Determine content fit mode
-        let content_fit = match model.viewport.fit_mode {
-            ViewMode::Fit => ContentFit::Contain,
-            ViewMode::ActualSize | ViewMode::Custom => ContentFit::None,
-        };
+    let mut content = document_view(model, manager, config);
+    if let Some(find) = &model.find {
+        content = column::with_capacity(2)
+            .push(find_bar::view(find))
+            .push(content)
+            .into();
+    }
+    if let ViewLayout::Compare(comparison) = &model.layout {
+        content = column::with_capacity(2)
+            .push(compare_bar::view(comparison, manager))
+            .push(content)
+            .into();
+    }
+    match &model.presentation {
+        Some(presentation) => {
+            presentation_view(content, presentation, config.hide_cursor_in_fullscreen)
+        }
+        None if config.canvas_controls => {
+            canvas_controls(with_background(content, manager, config), model, manager)
+        }
+        None => with_background(content, manager, config),
+    }
+}
+
+/// Canvas background of the current document (its own one, or the
+/// configured one) and the color of a solid background.
+fn canvas_background(
+    manager: &DocumentManager,
+    config: &AppConfig,
+) -> (CanvasBackground, Option<[u8; 3]>) {
+    let (key, rgb) = config.canvas_background_of(manager.current_path());
+    (CanvasBackground::from_key(key).unwrap_or_default(), rgb)
+}
+
+/// `content` on the canvas color of the document; other backgrounds leave
+/// the theme's (a checkerboard is drawn by the viewer, under the image only).
+fn with_background<'a>(
+    content: Element<'a, AppMessage>,
+    manager: &DocumentManager,
+    config: &AppConfig,
+) -> Element<'a, AppMessage> {
+    let (background, rgb) = canvas_background(manager, config);
+    let Some([red, green, blue]) = rgb.filter(|_| background == CanvasBackground::Color) else {
+        return content;
+    };
+    container(content)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .class(cosmic::theme::Container::custom(move |_| {
+            iced_container::Style {
+                background: Some(Background::Color(Color::from_rgb8(red, green, blue))),
+                ..Default::default()
+            }
+        }))
+        .into()
+}
+
+/// Canvas boxes of the find bar hits on the shown page, with whether they
+/// belong to the current hit.
+///
+/// Hit positions refer to the upright page, so none are shown while the page
+/// or the view is rotated or flipped.
+fn find_highlights(
+    model: &AppModel,
+    manager: &DocumentManager,
+) -> Vec<((f32, f32, f32, f32), bool)> {
+    let (Some(find), Some(doc)) = (&model.find, manager.current_document()) else {
+        return Vec::new();
+    };
+    let viewport = &model.viewport;
+    if viewport.view_rotation != 0 || doc.transform_state() != TransformState::default() {
+        return Vec::new();
+    }
+    let pan = Vector::new(viewport.pan_x, viewport.pan_y);
+    find.hits_on(doc.current_page())
+        .flat_map(|(hit, current)| hit.boxes.iter().map(move |rect| (rect, current)))
+        .map(|(rect, current)| {
+            let cmd = CropDocumentCommand::new(rect.x, rect.y, rect.width, rect.height);
+            let canvas_rect = cmd.to_canvas_selection(
+                viewport.canvas_size,
+                viewport.image_size,
+                viewport.scale,
+                pan,
+            );
+            (canvas_rect, current)
+        })
+        .collect()
+}
+
+/// The document (or the webtoon strip) with the overlays of the active mode.
+fn document_view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    if let Some(strip) = &model.strip {
+        return strip_view(strip, model);
+    }
+    if let ViewLayout::Compare(comparison) = &model.layout
+        && matches!(model.mode, AppMode::View)
+        && let Some(handle) = &model.viewport.cached_image_handle
+    {
+        return compare_view(comparison, handle, model, manager, config);
+    }
 
-        // Check if we're in crop mode (to disable pan)
-        let disable_pan = matches!(model.mode, AppMode::Crop { .. });
+    // Check if we're in crop or text selection mode (to disable pan)
+    let straighten = match &model.mode {
+        AppMode::Transform { straighten, .. } => Some(straighten),
+        _ => None,
+    };
+    let disable_pan = matches!(
+        model.mode,
+        AppMode::Crop { .. } | AppMode::SelectText { .. }
+    ) || straighten.is_some_and(|tool| tool.drawing);
+
+    // Use cached image handle from viewport; previews do not map to image
+    // coordinates, so crop mode shows the plain image. The animation
+    // preview shows its frame in place of the document.
+    let frame = model
+        .animation
+        .player_of(manager.current_path())
+        .and_then(AnimationPlayer::frame);
+    let preview = frame
+        .or(model.viewport.preview_handle.as_ref())
+        .filter(|_| !disable_pan);
+    let zoomed = model
+        .viewport
+        .zoom_render
+        .as_ref()
+        .filter(|_| preview.is_none() && !disable_pan);
+    let handle = preview
+        .or(zoomed.map(|(_, handle)| handle))
+        .or(model.viewport.cached_image_handle.as_ref());
+    if let Some(handle) = handle {
+        // A sharper render for the zoom is laid out at the document size
+        let source_size = zoomed.and(manager.current_document()).map(|doc| {
+            let (width, height) = doc.dimensions();
+            Size::new(width as f32, height as f32)
+        });
 
         // Create image viewer
-        let img_viewer = Viewer::new(handle.clone())
-            .with_state(
-                model.viewport.scale,
-                model.viewport.pan_x,
-                model.viewport.pan_y,
-            )
-            .on_state_change(|scale, offset_x, offset_y, canvas_size, image_size| {
-                AppMessage::ViewerStateChanged {
-                    scale,
-                    offset_x,
-                    offset_y,
-                    canvas_size,
-                    image_size,
-                }
+        let img_viewer = viewer(handle, model, manager, config)
+            .source_size(source_size)
+            .on_state_change(viewer_state_changed)
+            // Crop selection maps to unrotated image coordinates
+            .rotation(if disable_pan {
+                0
+            } else {
+                model.viewport.view_rotation
             })
-            .width(Length::Fill)
-            .height(Length::Fill)
-            .content_fit(content_fit)
-            .filter_method(FilterMethod::Nearest)
-            .min_scale(config.min_scale)
-            .max_scale(config.max_scale)
-            .scale_step(config.scale_step - 1.0)
-            .disable_pan(disable_pan);
+            .disable_pan(disable_pan)
+            .follow_cursor(model.viewport.magnifier && !disable_pan)
+            .crosshair(model.viewport.magnifier && !disable_pan && config.magnifier_crosshair)
+            // The crop is left out while a gesture turns the preview freely
+            .tilt(
+                straighten.map_or(0.0, StraightenTool::preview_angle),
+                straighten
+                    .filter(|tool| tool.gesture.is_none())
+                    .and_then(|tool| straighten_crop(tool, manager)),
+            );
+
+        // A large PDF page shows a quick preview until its full render is in
+        let refining = matches!(manager.current_document(), Some(doc) if doc.is_refining());
+
+        // Overlays snap to the physical pixels of the window
+        let scale_factor = model.viewport.scale_factor;
 
         // Overlay crop UI when in crop mode
         if let AppMode::Crop { selection } = &model.mode {
-            let overlay = crop_overlay(selection, config.crop_show_grid);
+            let overlay = crop_overlay(selection, config.crop_show_grid, scale_factor);
             stack![img_viewer, overlay].into()
+        } else if let Some(tool) = straighten.filter(|tool| tool.drawing) {
+            stack![img_viewer, line_overlay(tool.line, scale_factor)].into()
+        } else if let Some(tool) = straighten {
+            let gesture = tool.gesture.map(|gesture| gesture.snapped);
+            stack![img_viewer, rotate_overlay(gesture, scale_factor)].into()
+        } else if let AppMode::SelectText {
+            selection,
+            highlights,
+        } = &model.mode
+        {
+            let overlay = text_overlay(selection, highlights, scale_factor);
+            stack![img_viewer, overlay].into()
+        } else if model.viewport.half_page.is_some() {
+            stack![img_viewer, page_turn_zones()].into()
+        } else if model.find.is_some() {
+            let hits = find_highlights(model, manager);
+            stack![img_viewer, hit_overlay(hits, scale_factor)].into()
+        } else if model.viewport.embedded_preview || refining {
+            stack![img_viewer, loading_indicator()].into()
         } else {
             container(img_viewer)
                 .width(Length::Fill)
@@ -77,3 +242,413 @@ pub fn view<'a>(
             .into()
     }
 }
+
+/// Part of the image (fractions of width and height) the straighten
+/// preview keeps, if the empty corners are to be cropped off.
+fn straighten_crop(tool: &StraightenTool, manager: &DocumentManager) -> Option<(f32, f32)> {
+    let (width, height) = manager.current_document()?.dimensions();
+    if tool.edges != RotateEdges::Crop || tool.angle == 0.0 || width == 0 || height == 0 {
+        return None;
+    }
+    let (kept_width, kept_height) = straighten::inscribed_size(width, height, tool.angle);
+    Some((
+        kept_width as f32 / width as f32,
+        kept_height as f32 / height as f32,
+    ))
+}
+
+/// Image viewer zoomed, panned and turned like the viewport.
+fn viewer(
+    handle: &cosmic_image::Handle,
+    model: &AppModel,
+    manager: &DocumentManager,
+    config: &AppConfig,
+) -> Viewer<cosmic_image::Handle, AppMessage> {
+    // Determine content fit mode; tools mapping the canvas to the image
+    // expect all of it in view
+    let mapped = matches!(
+        model.mode,
+        AppMode::Crop { .. } | AppMode::SelectText { .. }
+    );
+    let content_fit = match model.viewport.fit_mode {
+        ViewMode::Fit => ContentFit::Contain,
+        ViewMode::Cover | ViewMode::Fill if mapped => ContentFit::Contain,
+        ViewMode::Cover => ContentFit::Cover,
+        ViewMode::Fill => ContentFit::Fill,
+        ViewMode::FitWidth | ViewMode::ActualSize | ViewMode::Custom => ContentFit::None,
+    };
+
+    Viewer::new(handle.clone())
+        .with_state(
+            model.viewport.scale,
+            model.viewport.pan_x,
+            model.viewport.pan_y,
+        )
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .content_fit(content_fit)
+        .fit_width(model.viewport.fit_mode == ViewMode::FitWidth)
+        .filter_method(if model.viewport.pixel_art {
+            FilterMethod::Nearest
+        } else {
+            FilterMethod::Linear
+        })
+        .min_scale(config.min_scale)
+        .max_scale(config.max_scale)
+        .scale_step(config.scale_step - 1.0)
+        .integer_zoom(config.integer_zoom || model.viewport.pixel_art)
+        .checkerboard(canvas_background(manager, config).0 == CanvasBackground::Checkerboard)
+        .glide(!model.low_power)
+        .scale_factor(model.viewport.scale_factor)
+}
+
+/// Viewer zoom and pan of the current document, kept in the viewport.
+fn viewer_state_changed(
+    scale: f32,
+    offset_x: f32,
+    offset_y: f32,
+    canvas_size: Size,
+    image_size: Size,
+) -> AppMessage {
+    AppMessage::ViewerStateChanged {
+        scale,
+        offset_x,
+        offset_y,
+        canvas_size,
+        image_size,
+    }
+}
+
+/// Compare mode: the current document (slot A) and slot B side by side, or
+/// on top of each other split by the swipe divider. Zooming or panning one
+/// moves both.
+fn compare_view<'a>(
+    comparison: &'a Comparison,
+    handle: &cosmic_image::Handle,
+    model: &AppModel,
+    manager: &DocumentManager,
+    config: &AppConfig,
+) -> Element<'a, AppMessage> {
+    let rotation = model.viewport.view_rotation;
+    let a_name = manager
+        .current_path()
+        .and_then(|path| path.file_name())
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let b_name = comparison
+        .b_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let a_label = fl!("compare-slot-a", file: a_name);
+    let b_label = fl!("compare-slot-b", file: b_name);
+    let a_viewer = viewer(handle, model, manager, config)
+        .rotation(rotation)
+        .on_state_change(viewer_state_changed);
+
+    match (comparison.mode, &comparison.b_handle) {
+        (CompareMode::SideBySide, b_handle) => {
+            let b_pane: Element<'a, AppMessage> = match b_handle {
+                Some(b_handle) => viewer(b_handle, model, manager, config)
+                    .rotation(rotation)
+                    .on_state_change(|scale, offset_x, offset_y, _, _| {
+                        AppMessage::CompareViewChanged {
+                            scale,
+                            offset_x,
+                            offset_y,
+                        }
+                    })
+                    .into(),
+                None => container(text::caption(fl!("compare-loading")))
+                    .width(Length::Fill)
+                    .height(Length::Fill)
+                    .center(Length::Fill)
+                    .into(),
+            };
+            row::with_capacity(2)
+                .spacing(2)
+                .push(stack![a_viewer, slot_label(a_label, Alignment::Start)])
+                .push(stack![b_pane, slot_label(b_label, Alignment::Start)])
+                .into()
+        }
+        (CompareMode::Swipe, Some(b_handle)) => stack![
+            a_viewer
+                .swipe(b_handle.clone(), comparison.divider)
+                .on_swipe(AppMessage::SetCompareDivider),
+            slot_label(a_label, Alignment::Start),
+            slot_label(b_label, Alignment::End),
+        ]
+        .into(),
+        (CompareMode::Swipe, None) => stack![a_viewer, loading_indicator()].into(),
+    }
+}
+
+/// File name of a compare slot in a top corner of its pane.
+fn slot_label<'a>(label: String, align: Alignment) -> Element<'a, AppMessage> {
+    container(
+        container(text::caption(label))
+            .padding([4, 8])
+            .class(cosmic::theme::Container::Card),
+    )
+    .width(Length::Fill)
+    .align_x(align)
+    .padding(12)
+    .into()
+}
+
+/// Fullscreen presentation on black: moving the mouse shows the running
+/// time, the countdown to the next slide and the playback controls for a few
+/// seconds. The pointer hides with them if `hide_cursor` is set.
+fn presentation_view<'a>(
+    content: Element<'a, AppMessage>,
+    presentation: &Presentation,
+    hide_cursor: bool,
+) -> Element<'a, AppMessage> {
+    let content = container(mouse_area(content).on_move(|_| AppMessage::PresentationActivity))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .class(cosmic::theme::Container::custom(|_| {
+            iced_container::Style {
+                background: Some(Background::Color(Color::BLACK)),
+                ..Default::default()
+            }
+        }));
+    // Same widget tree with hidden controls, so the viewer keeps its state
+    if !presentation.controls_visible() {
+        if !hide_cursor {
+            return stack![content, horizontal_space()].into();
+        }
+        // The cover takes the pointer (and its first move) from the viewer
+        let cover = mouse_area(horizontal_space().width(Length::Fill).height(Length::Fill))
+            .interaction(mouse::Interaction::Hidden)
+            .on_move(|_| AppMessage::PresentationActivity);
+        return stack![content, cover].into();
+    }
+
+    let playing = presentation.next_slide.is_some();
+    let control = |icon_name: &'static str, tooltip: String, message: AppMessage| {
+        button::icon(icon::from_name(icon_name))
+            .tooltip(tooltip)
+            .on_press(message)
+            .padding(4)
+    };
+    let controls = row::with_capacity(6)
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .push(text::body(clock(presentation.elapsed().as_secs())))
+        .push_maybe(presentation.countdown().map(|left| {
+            let seconds = left.as_secs_f32().ceil() as u64;
+            text::caption(fl!("presentation-next-in", time: clock(seconds)))
+        }))
+        .push(control(
+            "media-skip-backward-symbolic",
+            fl!("presentation-previous"),
+            AppMessage::TurnPage(false),
+        ))
+        .push(if playing {
+            control(
+                "media-playback-pause-symbolic",
+                fl!("presentation-pause"),
+                AppMessage::ToggleAutoAdvance,
+            )
+        } else {
+            control(
+                "media-playback-start-symbolic",
+                fl!("presentation-play"),
+                AppMessage::ToggleAutoAdvance,
+            )
+        })
+        .push(control(
+            "media-skip-forward-symbolic",
+            fl!("presentation-next"),
+            AppMessage::TurnPage(true),
+        ))
+        .push(control(
+            "view-restore-symbolic",
+            fl!("presentation-exit"),
+            AppMessage::ToggleFullscreen,
+        ));
+
+    let overlay = container(
+        container(controls)
+            .padding([8, 16])
+            .class(cosmic::theme::Container::Card),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .align_x(Alignment::Center)
+    .align_y(Alignment::End)
+    .padding(24);
+
+    stack![content, overlay].into()
+}
+
+/// Large buttons over the canvas for touch screens: previous and next at the
+/// edges, rotation, zoom and fullscreen at the bottom. Moving the pointer or
+/// touching the canvas shows them for a few seconds.
+fn canvas_controls<'a>(
+    content: Element<'a, AppMessage>,
+    model: &AppModel,
+    manager: &DocumentManager,
+) -> Element<'a, AppMessage> {
+    let content = mouse_area(content).on_move(|_| AppMessage::CanvasActivity);
+    // Same widget tree with hidden controls, so the viewer keeps its state;
+    // the tools use the canvas themselves
+    let visible = model.canvas_controls_until.is_some()
+        && manager.current_document().is_some()
+        && matches!(model.mode, AppMode::View);
+    if !visible {
+        return stack![content, horizontal_space()].into();
+    }
+
+    let control = |icon_name: &'static str, tooltip: String, message: AppMessage| {
+        button::icon(icon::from_name(icon_name))
+            .icon_size(32)
+            .tooltip(tooltip)
+            .on_press(message)
+            .padding(12)
+    };
+    let card = |content: Element<'a, AppMessage>| {
+        container(content)
+            .padding(4)
+            .class(cosmic::theme::Container::Card)
+    };
+
+    let edges = row::with_capacity(3)
+        .height(Length::Fill)
+        .align_y(Alignment::Center)
+        .push(card(
+            control(
+                "go-previous-symbolic",
+                fl!("canvas-previous"),
+                AppMessage::NavigateLeft,
+            )
+            .into(),
+        ))
+        .push(horizontal_space())
+        .push(card(
+            control(
+                "go-next-symbolic",
+                fl!("canvas-next"),
+                AppMessage::NavigateRight,
+            )
+            .into(),
+        ));
+    let bar = row::with_capacity(5)
+        .spacing(8)
+        .push(control(
+            "object-rotate-left-symbolic",
+            fl!("canvas-rotate-ccw"),
+            AppMessage::RotateCCW,
+        ))
+        .push(control(
+            "zoom-out-symbolic",
+            fl!("canvas-zoom-out"),
+            AppMessage::ZoomOut,
+        ))
+        .push(control(
+            "zoom-in-symbolic",
+            fl!("canvas-zoom-in"),
+            AppMessage::ZoomIn,
+        ))
+        .push(control(
+            "object-rotate-right-symbolic",
+            fl!("canvas-rotate-cw"),
+            AppMessage::RotateCW,
+        ))
+        .push(control(
+            "view-fullscreen-symbolic",
+            fl!("canvas-fullscreen"),
+            AppMessage::ToggleFullscreen,
+        ));
+
+    let overlay = column::with_capacity(2)
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .align_x(Alignment::Center)
+        .padding(16)
+        .spacing(16)
+        .push(edges)
+        .push(card(bar.into()));
+
+    stack![content, overlay].into()
+}
+
+/// Note in a corner while an embedded thumbnail stands in for the image.
+fn loading_indicator<'a>() -> Element<'a, AppMessage> {
+    container(
+        container(text::caption(fl!("loading-full-resolution")))
+            .padding([4, 8])
+            .class(cosmic::theme::Container::Card),
+    )
+    .width(Length::Fill)
+    .height(Length::Fill)
+    .align_x(Alignment::End)
+    .align_y(Alignment::End)
+    .padding(12)
+    .into()
+}
+
+/// `m:ss`, or `h:mm:ss` from one hour on.
+fn clock(seconds: u64) -> String {
+    match seconds / 3600 {
+        0 => format!("{}:{:02}", seconds / 60, seconds % 60),
+        hours => format!("{hours}:{:02}:{:02}", seconds / 60 % 60, seconds % 60),
+    }
+}
+
+/// Sheet-music mode: clicking the left or right half of the window turns
+/// back or forward, so a page turn never needs precise aiming.
+fn page_turn_zones<'a>() -> Element<'a, AppMessage> {
+    let zone = |forward: bool| {
+        mouse_area(
+            container(vertical_space())
+                .width(Length::Fill)
+                .height(Length::Fill),
+        )
+        .on_press(AppMessage::TurnPage(forward))
+    };
+    row::with_capacity(2)
+        .push(zone(false))
+        .push(zone(true))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
+
+/// Webtoon mode: all folder images in one vertical scroll. Only the pages
+/// near the visible area are loaded; the others are placeholders of the
+/// same size so the scroll position stays stable.
+fn strip_view<'a>(strip: &'a StripState, model: &'a AppModel) -> Element<'a, AppMessage> {
+    let layout = strip.layout();
+    let loaded = strip.loaded();
+
+    let mut pages = column::with_capacity(strip.pages.len())
+        .align_x(Alignment::Center)
+        .width(Length::Fill);
+    for (index, page) in strip.pages.iter().enumerate() {
+        let (width, height) = layout.size(index);
+        pages = pages.push(if loaded.contains(&index) {
+            cosmic_image::Image::new(cosmic_image::Handle::from_path(&page.path))
+                .width(Length::Fixed(width))
+                .height(Length::Fixed(height))
+                .into()
+        } else {
+            Element::from(
+                vertical_space()
+                    .width(Length::Fixed(width))
+                    .height(Length::Fixed(height)),
+            )
+        });
+    }
+
+    scrollable(pages)
+        .id(model.viewport.scroll_id.clone())
+        .on_scroll(|viewport| AppMessage::StripScrolled {
+            offset: viewport.absolute_offset().y,
+            view_size: viewport.bounds().size(),
+        })
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .into()
+}
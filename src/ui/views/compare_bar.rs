@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/compare_bar.rs
+//
+// Compare bar above the canvas: files in the A/B slots and their arrangement.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, dropdown, horizontal_space, icon, row, text};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::ui::model::{CompareMode, CompareSide, Comparison};
+use crate::ui::AppMessage;
+use crate::fl;
+
+/// Build the compare bar.
+pub fn view<'a>(
+    comparison: &'a Comparison,
+    manager: &'a DocumentManager,
+) -> Element<'a, AppMessage> {
+    let b_index = manager
+        .folder_entries()
+        .iter()
+        .position(|path| *path == comparison.b_path);
+    let mode_button = |icon_name: &'static str, tooltip: String, mode: CompareMode| {
+        button::icon(icon::from_name(icon_name))
+            .tooltip(tooltip)
+            .selected(comparison.mode == mode)
+            .on_press(AppMessage::SetCompareMode(mode))
+    };
+
+    row::with_capacity(9)
+        .spacing(4)
+        .padding([4, 12])
+        .align_y(Alignment::Center)
+        .push(text::heading("A"))
+        .push(dropdown(
+            &comparison.names,
+            manager.current_index(),
+            |index| AppMessage::AssignCompareSlot(CompareSide::A, index),
+        ))
+        .push(button::standard(fl!("compare-swap")).on_press(AppMessage::SwapCompareSlots))
+        .push(text::heading("B"))
+        .push(dropdown(&comparison.names, b_index, |index| {
+            AppMessage::AssignCompareSlot(CompareSide::B, index)
+        }))
+        .push(horizontal_space().width(Length::Fill))
+        .push(mode_button(
+            "view-dual-symbolic",
+            fl!("compare-side-by-side"),
+            CompareMode::SideBySide,
+        ))
+        .push(mode_button(
+            "object-flip-horizontal-symbolic",
+            fl!("compare-swipe"),
+            CompareMode::Swipe,
+        ))
+        .push(
+            button::icon(icon::from_name("window-close-symbolic"))
+                .tooltip(fl!("compare-close"))
+                .on_press(AppMessage::ToggleCompare),
+        )
+        .into()
+}
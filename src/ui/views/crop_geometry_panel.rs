@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/crop_geometry_panel.rs
+//
+// Numeric crop entry panel (keyboard alternative to dragging handles).
+
+use cosmic::widget::{button, column, text, text_input, Id};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::domain::document::operations::CropRegion;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Widget ID of the geometry field (focused when the panel opens).
+pub fn input_id() -> Id {
+    Id::new("crop-geometry-input")
+}
+
+/// Build the crop dimensions panel view.
+pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let input = model.crop_geometry_input.as_str();
+    let dimensions = manager.current_document().map(|doc| doc.dimensions());
+    let region = CropRegion::parse_geometry(input);
+
+    let status = match (region, dimensions) {
+        (_, None) => fl!("no-document"),
+        (None, _) if input.trim().is_empty() => String::new(),
+        (None, _) => fl!("crop-geometry-invalid"),
+        (Some(region), Some((width, height))) if !region.fits_within(width, height) => {
+            fl!("crop-geometry-outside", width: width, height: height)
+        }
+        (Some(region), _) => fl!(
+            "crop-geometry-preview",
+            width: region.width,
+            height: region.height,
+            x: region.x,
+            y: region.y
+        ),
+    };
+    let valid = region
+        .zip(dimensions)
+        .is_some_and(|(region, (width, height))| region.fits_within(width, height));
+
+    column::with_capacity(6)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("crop-geometry-title")))
+        .push(
+            text_input(fl!("crop-geometry-placeholder"), input)
+                .id(input_id())
+                .on_input(AppMessage::CropGeometryInput)
+                .on_submit(|_| AppMessage::ApplyCropGeometry),
+        )
+        .push(text::caption(fl!("crop-geometry-hint")))
+        .push(text::body(status))
+        .push(
+            button::suggested(fl!("crop-geometry-apply"))
+                .on_press_maybe(valid.then_some(AppMessage::ApplyCropGeometry)),
+        )
+        .push(text::caption(fl!("crop-geometry-confirm")))
+        .into()
+}
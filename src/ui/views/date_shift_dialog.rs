@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/date_shift_dialog.rs
+//
+// Confirmation before the date shift rewrites the images of a folder.
+
+use cosmic::widget::{button, dialog};
+use cosmic::Element;
+
+use crate::ui::model::DateShiftState;
+use crate::ui::AppMessage;
+use crate::fl;
+
+/// Build the dialog asking to shift the dates of the planned files.
+pub fn view(state: &DateShiftState) -> Element<'_, AppMessage> {
+    let count = state.entries.len();
+    let body = if state.sidecar {
+        fl!("date-shift-confirm-sidecar", count: count)
+    } else {
+        fl!("date-shift-confirm-file", count: count)
+    };
+
+    dialog()
+        .title(fl!("date-shift-confirm-title"))
+        .body(body)
+        .primary_action(
+            button::destructive(fl!("date-shift-confirm")).on_press(AppMessage::ConfirmDateShift),
+        )
+        .secondary_action(
+            button::standard(fl!("date-shift-cancel")).on_press(AppMessage::CancelDateShift),
+        )
+        .into()
+}
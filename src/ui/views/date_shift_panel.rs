@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/date_shift_panel.rs
+//
+// Batch EXIF date shift tool panel.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{
+    button, column, divider, progress_bar, row, scrollable, text, text_input, toggler,
+};
+use cosmic::Element;
+
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the date shift panel view.
+pub fn view(model: &AppModel) -> Element<'_, AppMessage> {
+    let state = &model.date_shift;
+    let offset = state.offset();
+
+    let mut content = column::with_capacity(12).spacing(12).padding(16);
+
+    content = content
+        .push(text::title4(fl!("date-shift-title")))
+        .push(text::body(fl!("date-shift-offset")))
+        .push(
+            text_input(fl!("date-shift-placeholder"), state.offset_input.as_str())
+                .on_input(AppMessage::DateShiftInput)
+                .on_submit(|_| AppMessage::ApplyDateShift),
+        )
+        .push(text::caption(fl!("date-shift-hint")))
+        .push(
+            toggler(state.sidecar)
+                .label(fl!("date-shift-sidecar"))
+                .on_toggle(AppMessage::SetDateShiftSidecar),
+        )
+        .push(
+            button::suggested(fl!("date-shift-apply")).on_press_maybe(
+                (offset.is_some_and(|o| o != 0)
+                    && !state.entries.is_empty()
+                    && !state.is_running())
+                .then_some(AppMessage::ApplyDateShift),
+            ),
+        );
+
+    if let Some((done, total)) = state.progress {
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(progress_bar(0.0..=total.max(1) as f32, done as f32))
+                .push(text::caption(fl!("date-shift-progress", done: done, total: total))),
+        );
+    }
+
+    if let Some(ref status) = state.status {
+        content = content.push(text::caption(status.clone()));
+    }
+
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!(
+            "date-shift-preview",
+            count: state.entries.len()
+        )));
+
+    if state.entries.is_empty() {
+        content = content.push(text::body(fl!("date-shift-empty")));
+        return content.into();
+    }
+
+    let mut list = column::with_capacity(state.entries.len()).spacing(8);
+    for entry in &state.entries {
+        let name = entry
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let dates = match offset {
+            Some(offset) => format!("{} → {}", entry.original, entry.original.shifted(offset)),
+            None => entry.original.to_string(),
+        };
+
+        list = list.push(
+            column::with_capacity(2)
+                .spacing(2)
+                .push(text::caption(name))
+                .push(text::body(dates)),
+        );
+    }
+
+    content.push(scrollable(list).height(Length::Fill)).into()
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/delete_dialog.rs
+//
+// Confirmation before the Delete key moves a file to the trash.
+
+use std::path::Path;
+
+use cosmic::widget::{button, dialog};
+use cosmic::Element;
+
+use crate::ui::AppMessage;
+use crate::fl;
+
+/// Build the dialog asking to move the file at `path` to the trash.
+pub fn view(path: &Path) -> Element<'_, AppMessage> {
+    let name = path.file_name().map_or_else(
+        || path.display().to_string(),
+        |name| name.to_string_lossy().into_owned(),
+    );
+
+    dialog()
+        .title(fl!("delete-title"))
+        .body(fl!("delete-body", name: name))
+        .primary_action(
+            button::destructive(fl!("delete-confirm")).on_press(AppMessage::ConfirmDelete),
+        )
+        .secondary_action(button::standard(fl!("delete-cancel")).on_press(AppMessage::CancelDelete))
+        .into()
+}
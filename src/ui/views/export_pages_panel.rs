@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/export_pages_panel.rs
+//
+// Export pages panel: write the current page, a page range or all pages of
+// a PDF as PNG or JPEG images at a chosen resolution.
+
+use cosmic::widget::{button, column, dropdown, radio, slider, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::operations::export::ExportFormat;
+use crate::ui::model::{PageExportState, PageScope};
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+use super::page_range;
+
+/// Build the export pages panel view.
+pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let mut content = column::with_capacity(14)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("export-pages-title")));
+
+    let Some(doc) = manager
+        .current_document()
+        .filter(|doc| matches!(doc.kind(), DocumentKind::Portable))
+    else {
+        return content
+            .push(text::caption(fl!("export-pages-unsupported")))
+            .into();
+    };
+    let state = &model.page_export;
+    let page_count = doc.page_count();
+
+    for scope in PageScope::ALL {
+        let label = match scope {
+            PageScope::Current => fl!("export-pages-current", page: doc.current_page() + 1),
+            PageScope::Range => fl!("export-pages-range"),
+            PageScope::All => fl!("export-pages-all", count: page_count),
+        };
+        content = content.push(
+            radio(
+                label,
+                scope,
+                Some(state.scope),
+                AppMessage::SetPageExportScope,
+            )
+            .size(16),
+        );
+    }
+    if state.scope == PageScope::Range {
+        content = content.push(page_range::view(
+            &model.export_pages_input,
+            page_count,
+            AppMessage::ExportPagesInput,
+        ));
+    }
+
+    content = content
+        .push(text::body(fl!("export-pages-format")))
+        .push(dropdown(
+            &model.page_format_labels,
+            PageExportState::FORMATS
+                .iter()
+                .position(|&format| format == state.format),
+            |index| AppMessage::SetPageExportFormat(PageExportState::FORMATS[index]),
+        ))
+        .push_maybe((state.format == ExportFormat::Jpeg).then(|| {
+            column::with_capacity(2)
+                .spacing(4)
+                .push(text::body(fl!("save-as-quality", quality: state.quality)))
+                .push(slider(
+                    1..=100,
+                    state.quality,
+                    AppMessage::SetPageExportQuality,
+                ))
+        }))
+        .push(text::body(fl!("export-pages-dpi")))
+        .push(text_input("150", state.dpi_input.as_str()).on_input(AppMessage::PageExportDpiInput));
+
+    let pages = state.pages(&model.export_pages_input, doc.current_page(), page_count);
+    let status = match (&pages, state.dpi()) {
+        (_, None) => Some(fl!("export-pages-invalid-dpi")),
+        (Some(pages), Some(_)) => Some(fl!("export-pages-summary", count: pages.len())),
+        // The page range field explains what is wrong with it
+        (None, Some(_)) => None,
+    };
+    let can_export = pages.is_some() && state.dpi().is_some() && !state.is_running();
+
+    content
+        .push_maybe(status.map(text::caption))
+        .push(
+            button::suggested(fl!("export-pages-button"))
+                .on_press_maybe(can_export.then_some(AppMessage::ExportPages)),
+        )
+        .push_maybe(state.is_running().then(|| {
+            button::standard(fl!("export-pages-cancel")).on_press(AppMessage::CancelPageExport)
+        }))
+        .push_maybe(state.status.clone().map(text::caption))
+        .into()
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/export_profile_picker.rs
+//
+// Export profile dropdown shared by Save As and the batch tools.
+
+use cosmic::widget::{column, dropdown, text};
+use cosmic::Element;
+
+use crate::config::AppConfig;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the export profile picker with a summary of the selected settings.
+pub fn view<'a>(model: &'a AppModel, config: &'a AppConfig) -> Element<'a, AppMessage> {
+    let state = &model.export_profiles;
+    let summary = match state.profile(config) {
+        Some(profile) => profile.to_string(),
+        None => fl!("export-profile-none-hint"),
+    };
+
+    column::with_capacity(3)
+        .spacing(4)
+        .push(text::body(fl!("export-profile")))
+        .push(dropdown(
+            &state.labels,
+            Some(state.selected_index()),
+            AppMessage::SelectExportProfile,
+        ))
+        .push(text::caption(summary))
+        .into()
+}
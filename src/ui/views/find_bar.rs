@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/find_bar.rs
+//
+// Find bar above the canvas (Ctrl+F) for searching the text of PDF pages.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{button, icon, row, text_input, Id};
+use cosmic::Element;
+
+use crate::ui::model::FindState;
+use crate::ui::AppMessage;
+use crate::fl;
+
+/// Widget ID of the search field (focused when the bar opens).
+pub fn input_id() -> Id {
+    Id::new("find-input")
+}
+
+/// Build the find bar.
+pub fn view(find: &FindState) -> Element<'_, AppMessage> {
+    let has_hits = !find.hits.is_empty();
+
+    row()
+        .spacing(4)
+        .padding([4, 12])
+        .align_y(Alignment::Center)
+        .push(
+            text_input(fl!("find-placeholder"), find.query.as_str())
+                .id(input_id())
+                .on_input(AppMessage::FindInput)
+                .on_submit(|_| AppMessage::FindSubmit),
+        )
+        .push(
+            button::icon(icon::from_name("go-up-symbolic"))
+                .tooltip(fl!("find-previous"))
+                .on_press_maybe(has_hits.then_some(AppMessage::FindPrevious)),
+        )
+        .push(
+            button::icon(icon::from_name("go-down-symbolic"))
+                .tooltip(fl!("find-next"))
+                .on_press_maybe(has_hits.then_some(AppMessage::FindNext)),
+        )
+        .push(
+            button::icon(icon::from_name("window-close-symbolic"))
+                .tooltip(fl!("find-close"))
+                .on_press(AppMessage::CloseFindBar),
+        )
+        .into()
+}
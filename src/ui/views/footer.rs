@@ -1,36 +1,114 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // src/ui/views/footer.rs
 //
-// Footer bar with zoom controls and document info.
+// Footer bar with zoom controls and document info, and the slim footer
+// of fullscreen slideshows.
 
 use cosmic::iced::Alignment;
-use cosmic::widget::{button, icon, row, text};
+use cosmic::widget::{button, dropdown, icon, row, text};
 use cosmic::Element;
 
-use crate::ui::model::{AppModel, ViewMode};
+use crate::ui::app::ContextPage;
+use crate::ui::model::{AppModel, Presentation, TilePreview, ViewLayout, ViewMode};
 use crate::ui::AppMessage;
 use crate::application::DocumentManager;
 use crate::domain::document::core::document::Renderable;
+use crate::domain::document::operations::alpha;
 use crate::fl;
 
 /// Build the footer element with zoom controls and document info.
 pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
     // Zoom level display
-    let zoom_text = if model.viewport.fit_mode == ViewMode::Fit {
-        fl!("status-zoom-fit")
-    } else {
-        let percent = (model.viewport.scale * 100.0).round() as i32;
-        fl!("status-zoom-percent", percent: percent)
+    let zoom_text = match model.viewport.fit_mode {
+        ViewMode::Fit => fl!("status-zoom-fit"),
+        ViewMode::FitWidth => fl!("status-zoom-fit-width"),
+        ViewMode::Cover => fl!("status-zoom-cover"),
+        ViewMode::Fill => fl!("status-zoom-fill"),
+        ViewMode::ActualSize | ViewMode::Custom => {
+            let percent = (model.viewport.scale * 100.0).round() as i32;
+            fl!("status-zoom-percent", percent: percent)
+        }
     };
 
     // Document dimensions (from DocumentManager)
     let doc_info = if let Some(doc) = manager.current_document() {
         let info = doc.info();
-        fl!("status-doc-dimensions", width: info.width, height: info.height)
+        let dimensions = fl!("status-doc-dimensions", width: info.width, height: info.height);
+
+        // Physical print size if the file declares a resolution
+        match manager.current_metadata().and_then(|m| m.resolution) {
+            Some(resolution) => {
+                let (width, height) = doc.dimensions();
+                let physical = resolution.physical_size_display(width, height);
+                format!("{dimensions} · {physical}")
+            }
+            None => dimensions,
+        }
     } else {
         String::new()
     };
 
+    // View rotation state
+    let rotation_tooltip = match u32::from(model.viewport.view_rotation) * 90 {
+        0 => fl!("status-rotate-view"),
+        degrees => fl!("status-view-rotated", degrees: degrees),
+    };
+
+    // Find bar hits, once the query was searched
+    let find_info = model
+        .find
+        .as_ref()
+        .filter(|find| find.searched.is_some())
+        .map(|find| match (find.hits.len(), find.current) {
+            (0, _) => fl!("status-find-none"),
+            (total, current) => {
+                fl!("status-find-position", current: current.map_or(0, |i| i + 1), total: total)
+            }
+        });
+
+    // Page being written by a running page export
+    let export_info = model.page_export.progress.map(
+        |(done, total)| fl!("status-export-pages", current: (done + 1).min(total), total: total),
+    );
+
+    // File being read by a running folder catalog export
+    let catalog_info = model
+        .catalog
+        .progress
+        .map(|(done, total)| fl!("status-catalog", current: (done + 1).min(total), total: total));
+
+    // Frame shown by the animation preview and its time in the animation
+    let frame_info = model
+        .animation
+        .player_of(manager.current_path())
+        .map(|player| {
+            let seconds = player.timeline.start_of(player.current).as_secs_f32();
+            fl!(
+                "status-animation-frame",
+                current: player.current + 1,
+                total: player.frames.len(),
+                time: format!("{seconds:.2}")
+            )
+        });
+
+    // Image being converted by a running batch job
+    let batch_info = model
+        .batch
+        .progress
+        .map(|(done, total)| fl!("status-batch", current: (done + 1).min(total), total: total));
+
+    // Upload running in the background
+    let upload_info = model
+        .upload
+        .progress
+        .map(|percent| fl!("status-upload", percent: percent));
+
+    // Share target the image is being sent to, or the result
+    let share_info = match &model.share.running {
+        Some(name) => Some(fl!("status-share", name: name.as_str())),
+        None => model.share.status.clone(),
+    };
+
     // Navigation position (from DocumentManager)
     let folder_count = manager.folder_entries().len();
     let nav_info = if folder_count == 0 {
@@ -71,12 +149,204 @@ pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a
                 .on_press(AppMessage::ZoomFit)
                 .padding(4),
         )
+        // Fit mode, also kept as the default for this kind of document
+        .push_maybe(manager.current_document().map(|_| {
+            dropdown(
+                &model.view_mode_labels,
+                ViewMode::PRESETS
+                    .iter()
+                    .position(|mode| *mode == model.viewport.fit_mode),
+                |index| AppMessage::SetViewMode(ViewMode::PRESETS[index]),
+            )
+        }))
+        // View rotation (display only, not saved)
+        .push_maybe(manager.current_document().map(|_| {
+            button::icon(icon::from_name("object-rotate-right-symbolic"))
+                .tooltip(rotation_tooltip)
+                .selected(model.viewport.view_rotation != 0)
+                .on_press(AppMessage::RotateView)
+                .padding(4)
+        }))
+        // Slideshow (started here, paused in the fullscreen controls)
+        .push_maybe((manager.folder_entries().len() > 1).then(|| {
+            button::icon(icon::from_name("media-playback-start-symbolic"))
+                .tooltip(fl!("status-slideshow"))
+                .on_press(AppMessage::ToggleSlideshow)
+                .padding(4)
+        }))
+        // Fullscreen (the footer is hidden while in it)
+        .push(
+            button::icon(icon::from_name("view-fullscreen-symbolic"))
+                .tooltip(fl!("status-fullscreen"))
+                .on_press(AppMessage::ToggleFullscreen)
+                .padding(4),
+        )
+        // Pixel-art mode (auto-detected, click to override)
+        .push_maybe(manager.current_document().map(|_| {
+            button::icon(icon::from_name("applications-graphics-symbolic"))
+                .tooltip(if model.viewport.pixel_art {
+                    fl!("status-pixel-art-on")
+                } else {
+                    fl!("status-pixel-art-off")
+                })
+                .selected(model.viewport.pixel_art)
+                .on_press(AppMessage::TogglePixelArt)
+                .padding(4)
+        }))
+        // Alpha channel as grayscale mask (images with transparency only)
+        .push_maybe(
+            manager
+                .current_document()
+                .filter(|doc| alpha::has_alpha(doc.rendered_image()))
+                .map(|_| {
+                    button::icon(icon::from_name("image-filter-symbolic"))
+                        .tooltip(fl!("status-alpha-mask"))
+                        .selected(model.viewport.alpha_mask)
+                        .on_press(AppMessage::ToggleAlphaMask)
+                        .padding(4)
+                }),
+        )
+        // Seamless texture preview (cycles grid, half offset, off)
+        .push_maybe(manager.current_document().map(|_| {
+            button::icon(icon::from_name("view-grid-symbolic"))
+                .tooltip(match model.viewport.tile_preview {
+                    TilePreview::Off => fl!("status-tile-off"),
+                    TilePreview::Grid => fl!("status-tile-grid"),
+                    TilePreview::Offset => fl!("status-tile-offset"),
+                })
+                .selected(model.viewport.tile_preview != TilePreview::Off)
+                .on_press(AppMessage::CycleTilePreview)
+                .padding(4)
+        }))
+        // Magnifier (zoomed view follows the mouse pointer)
+        .push_maybe(manager.current_document().map(|_| {
+            button::icon(icon::from_name("find-location-symbolic"))
+                .tooltip(fl!("status-magnifier"))
+                .selected(model.viewport.magnifier)
+                .on_press(AppMessage::ToggleMagnifier)
+                .padding(4)
+        }))
+        // Sheet-music mode (half-page turns, multi-page documents only)
+        .push_maybe(
+            manager
+                .current_document()
+                .filter(|doc| doc.is_multi_page())
+                .map(|_| {
+                    button::icon(icon::from_name("view-paged-symbolic"))
+                        .tooltip(fl!("status-half-page"))
+                        .selected(model.viewport.half_page.is_some())
+                        .on_press(AppMessage::ToggleHalfPageTurn)
+                        .padding(4)
+                }),
+        )
+        // Webtoon mode (folder images as one vertical strip)
+        .push_maybe((folder_count > 1).then(|| {
+            button::icon(icon::from_name("view-continuous-symbolic"))
+                .tooltip(fl!("status-strip-mode"))
+                .selected(model.strip.is_some())
+                .on_press(AppMessage::ToggleStripMode)
+                .padding(4)
+        }))
+        // Compare mode (current image next to another one, or its saved version)
+        .push_maybe(manager.current_document().map(|_| {
+            button::icon(icon::from_name("view-dual-symbolic"))
+                .tooltip(fl!("status-compare"))
+                .selected(matches!(model.layout, ViewLayout::Compare(_)))
+                .on_press(AppMessage::ToggleCompare)
+                .padding(4)
+        }))
+        // Reading order (remembered per document)
+        .push_maybe(manager.current_document().map(|_| {
+            button::icon(icon::from_name("format-text-direction-rtl-symbolic"))
+                .tooltip(if model.right_to_left {
+                    fl!("status-right-to-left-on")
+                } else {
+                    fl!("status-right-to-left-off")
+                })
+                .selected(model.right_to_left)
+                .on_press(AppMessage::ToggleRightToLeft)
+                .padding(4)
+        }))
+        // Edits imported from another tool's XMP sidecar; click to compare with the original
+        .push_maybe(model.xmp_sidecar.as_ref().map(|sidecar| {
+            let history = &model.history;
+            let name = sidecar
+                .file_name()
+                .unwrap_or_default()
+                .to_string_lossy()
+                .into_owned();
+            let applied = history.position() > 0;
+            button::icon(icon::from_name("document-edit-symbolic"))
+                .tooltip(if applied {
+                    fl!("status-xmp-applied", file: name)
+                } else {
+                    fl!("status-xmp-ignored", file: name)
+                })
+                .selected(applied)
+                .on_press(AppMessage::RevertHistory(if applied {
+                    0
+                } else {
+                    history.entries().len()
+                }))
+                .padding(4)
+        }))
+        // Page export running in the background
+        .push_maybe(export_info.map(text))
+        .push_maybe(model.page_export.is_running().then(|| {
+            button::icon(icon::from_name("process-stop-symbolic"))
+                .tooltip(fl!("status-export-pages-cancel"))
+                .on_press(AppMessage::CancelPageExport)
+                .padding(4)
+        }))
+        // Folder catalog running in the background
+        .push_maybe(catalog_info.map(text))
+        .push_maybe(model.catalog.is_running().then(|| {
+            button::icon(icon::from_name("process-stop-symbolic"))
+                .tooltip(fl!("status-catalog-cancel"))
+                .on_press(AppMessage::CancelCatalog)
+                .padding(4)
+        }))
+        // Batch conversion running in the background
+        .push_maybe(batch_info.map(text))
+        .push_maybe(model.batch.is_running().then(|| {
+            button::icon(icon::from_name("process-stop-symbolic"))
+                .tooltip(fl!("batch-cancel"))
+                .on_press(AppMessage::CancelBatch)
+                .padding(4)
+        }))
+        // Upload running in the background
+        .push_maybe(upload_info.map(text))
+        .push_maybe(model.upload.is_running().then(|| {
+            button::icon(icon::from_name("process-stop-symbolic"))
+                .tooltip(fl!("upload-cancel"))
+                .on_press(AppMessage::CancelUpload)
+                .padding(4)
+        }))
+        // Share target running in the background
+        .push_maybe(share_info.map(text))
+        // Animation preview position
+        .push_maybe(frame_info.map(text))
+        // Find bar result count
+        .push_maybe(find_info.map(text))
         // Document dimensions
         .push_maybe(if !doc_info.is_empty() {
             Some(text(doc_info))
         } else {
             None
         })
+        // Folder rescan failed (network mount gone), showing the last known list
+        .push_maybe(model.folder_unavailable.then(|| {
+            button::icon(icon::from_name("folder-remote-symbolic"))
+                .tooltip(fl!("status-folder-unavailable"))
+                .padding(4)
+        }))
+        // File or folder cannot be written; saving goes through Save As
+        .push_maybe(model.read_only.then(|| {
+            button::icon(icon::from_name("changes-prevent-symbolic"))
+                .tooltip(fl!("status-read-only"))
+                .on_press(AppMessage::ToggleContextPage(ContextPage::SaveAs))
+                .padding(4)
+        }))
         // Navigation info
         .push_maybe(if folder_count == 0 {
             None
@@ -85,3 +355,34 @@ pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a
         })
         .into()
 }
+
+/// Build the footer of a running slideshow: a play/pause indicator (click
+/// to pause or resume) and the position in the folder.
+pub fn slideshow_view<'a>(
+    presentation: &'a Presentation,
+    manager: &'a DocumentManager,
+) -> Element<'a, AppMessage> {
+    let (icon_name, tooltip) = if presentation.next_slide.is_some() {
+        ("media-playback-pause-symbolic", fl!("presentation-pause"))
+    } else {
+        ("media-playback-start-symbolic", fl!("presentation-play"))
+    };
+    let folder_count = manager.folder_entries().len();
+    let current = manager.current_index().map_or(0, |i| i + 1);
+
+    row()
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .padding([4, 12])
+        .push(
+            button::icon(icon::from_name(icon_name))
+                .tooltip(tooltip)
+                .on_press(AppMessage::ToggleAutoAdvance)
+                .padding(4),
+        )
+        .push_maybe(
+            (folder_count > 0)
+                .then(|| text(fl!("status-nav-position", current: current, total: folder_count))),
+        )
+        .into()
+}
@@ -1,28 +1,46 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 // src/app/view/format_panel.rs
 //
-// Format panel for paper format and orientation selection.
+// Format panel for straightening, paper format and orientation selection.
 
-use cosmic::widget::{column, radio, text};
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, horizontal_space, icon, radio, row, slider, text, text_input};
 use cosmic::Element;
 
-use crate::ui::model::{AppMode, AppModel, Orientation, PaperFormat};
+use crate::application::DocumentManager;
+use crate::domain::document::core::content::DocumentKind;
+use crate::ui::app::ContextPage;
+use crate::domain::document::operations::straighten::{MAX_ANGLE, RotateEdges, RotateFilter};
+use crate::ui::model::{AppMode, AppModel, Orientation, PaperFormat, StraightenTool};
 use crate::ui::AppMessage;
 use crate::fl;
 
+use super::page_range;
+
 /// Build the format panel view for the navigation bar.
-pub fn view(model: &AppModel) -> Element<'static, AppMessage> {
+pub fn view(model: &AppModel, manager: &DocumentManager) -> Element<'static, AppMessage> {
     // Extract values from Transform mode
-    let (paper_format, orientation) = match &model.mode {
+    let (paper_format, orientation, straighten) = match &model.mode {
         AppMode::Transform {
             paper_format,
             orientation,
-        } => (*paper_format, *orientation),
-        _ => (None, Orientation::default()),
+            straighten,
+        } => (*paper_format, *orientation, straighten.clone()),
+        _ => (None, Orientation::default(), StraightenTool::default()),
     };
 
     let mut content = column::with_capacity(20).spacing(12).padding(16);
 
+    // --- Straighten Section ---
+    if manager
+        .current_document()
+        .is_some_and(|doc| doc.supports_fine_rotation())
+    {
+        content = content
+            .push(straighten_section(&straighten))
+            .push(cosmic::widget::vertical_space().height(16));
+    }
+
     // --- Format Section ---
     content = content
         .push(text::heading(fl!("format-section-title")))
@@ -133,5 +151,142 @@ pub fn view(model: &AppModel) -> Element<'static, AppMessage> {
         .size(16),
     );
 
+    // The paper and orientation above carry over into the resize panel
+    if manager
+        .current_document()
+        .is_some_and(|doc| doc.supports_resize())
+    {
+        content = content.push(
+            button::standard(fl!("format-resize"))
+                .on_press(AppMessage::ToggleContextPage(ContextPage::Resize)),
+        );
+    }
+
+    // --- Print Resolution Section ---
+    // Only the declared DPI changes on export, pixels are not resampled.
+    let file_resolution = manager.current_metadata().and_then(|m| m.resolution);
+    let placeholder =
+        file_resolution.map_or_else(|| "72".to_string(), |r| format!("{:.0}", r.x_dpi));
+
+    content = content
+        .push(cosmic::widget::vertical_space().height(16))
+        .push(text::heading(fl!("resolution-section-title")))
+        .push(text::caption(fl!("resolution-section-subtitle")))
+        .push(
+            text_input(placeholder, model.export_dpi_input.clone())
+                .on_input(AppMessage::ExportDpiInput),
+        );
+
+    if let Some(resolution) = model.export_resolution().or(file_resolution)
+        && let Some(doc) = manager.current_document()
+    {
+        let (width, height) = doc.dimensions();
+        content = content.push(text::caption(
+            resolution.physical_size_display(width, height),
+        ));
+    }
+
+    // --- Pages Section (multi-page documents) ---
+    if let Some(doc) = manager.current_document()
+        && doc.is_multi_page()
+    {
+        content = content
+            .push(cosmic::widget::vertical_space().height(16))
+            .push(text::heading(fl!("pages-section-title")))
+            .push(page_range::view(
+                &model.export_pages_input,
+                doc.page_count(),
+                AppMessage::ExportPagesInput,
+            ))
+            .push_maybe(matches!(doc.kind(), DocumentKind::Portable).then(|| {
+                button::standard(fl!("export-pages-open"))
+                    .on_press(AppMessage::ToggleContextPage(ContextPage::ExportPages))
+            }));
+    }
+
     content.into()
 }
+
+/// Angle slider, line tool, filter and edge options of the fine rotation.
+fn straighten_section(tool: &StraightenTool) -> Element<'static, AppMessage> {
+    let changed = tool.angle != 0.0;
+    let line_hint = if tool.drawing {
+        fl!("straighten-line-active")
+    } else {
+        fl!("straighten-hint")
+    };
+
+    let mut content = column::with_capacity(13)
+        .spacing(12)
+        .push(text::heading(fl!("straighten-section-title")))
+        .push(text::caption(line_hint))
+        .push(
+            row::with_capacity(3)
+                .align_y(Alignment::Center)
+                .push(text::body(
+                    fl!("straighten-angle", degrees: format!("{:+.1}", tool.angle)),
+                ))
+                .push(horizontal_space().width(Length::Fill))
+                .push(
+                    button::icon(icon::from_name("edit-undo-symbolic"))
+                        .tooltip(fl!("straighten-reset"))
+                        .padding(4)
+                        .on_press_maybe(changed.then_some(AppMessage::ResetStraighten)),
+                ),
+        )
+        .push(
+            slider(
+                -MAX_ANGLE..=MAX_ANGLE,
+                tool.angle,
+                AppMessage::SetStraightenAngle,
+            )
+            .step(0.1),
+        )
+        .push(
+            button::standard(fl!("straighten-line"))
+                .selected(tool.drawing)
+                .on_press(AppMessage::ToggleStraightenLine),
+        )
+        .push(text::body(fl!("straighten-filter")));
+    for filter in RotateFilter::ALL {
+        let label = match filter {
+            RotateFilter::Nearest => fl!("straighten-filter-nearest"),
+            RotateFilter::Bilinear => fl!("straighten-filter-bilinear"),
+            RotateFilter::Bicubic => fl!("straighten-filter-bicubic"),
+        };
+        content = content.push(
+            radio(
+                label,
+                filter,
+                Some(tool.filter),
+                AppMessage::SetStraightenFilter,
+            )
+            .size(16),
+        );
+    }
+
+    content = content.push(text::body(fl!("straighten-edges")));
+    for edges in RotateEdges::ALL {
+        let label = match edges {
+            RotateEdges::Transparent => fl!("straighten-edges-transparent"),
+            RotateEdges::Clamp => fl!("straighten-edges-clamp"),
+            RotateEdges::Crop => fl!("straighten-edges-crop"),
+        };
+        content = content.push(
+            radio(
+                label,
+                edges,
+                Some(tool.edges),
+                AppMessage::SetStraightenEdges,
+            )
+            .size(16),
+        );
+    }
+
+    content
+        .push(
+            button::suggested(fl!("straighten-apply"))
+                .on_press_maybe(changed.then_some(AppMessage::ApplyStraighten)),
+        )
+        .into()
+}
@@ -0,0 +1,111 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/geotag_panel.rs
+//
+// GPS location editor panel (current image and batch apply).
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, divider, progress_bar, row, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+use super::selection_list;
+
+/// Build the geotag editor panel view.
+pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let state = &model.geotag;
+    let location = state.location();
+    // Writing GPS tags changes the file in place; one write runs at a time
+    let has_doc = manager.current_document().is_some() && !model.read_only && !state.is_running();
+
+    let mut content = column::with_capacity(16).spacing(12).padding(16);
+
+    // --- Current Image ---
+    content = content
+        .push(text::title4(fl!("geotag-title")))
+        .push(text::body(fl!("geotag-location")))
+        .push(
+            text_input(fl!("geotag-placeholder"), state.input.as_str())
+                .on_input(AppMessage::GeotagInput)
+                .on_submit(|_| AppMessage::ApplyGeotag),
+        )
+        .push(text::caption(match location {
+            Some(location) => location.display(),
+            None if state.input.trim().is_empty() => fl!("geotag-hint"),
+            None => fl!("geotag-invalid"),
+        }))
+        .push_maybe(
+            model
+                .read_only
+                .then(|| text::caption(fl!("geotag-read-only"))),
+        )
+        .push(
+            row()
+                .spacing(8)
+                .push(button::suggested(fl!("geotag-apply")).on_press_maybe(
+                    (has_doc && location.is_some()).then_some(AppMessage::ApplyGeotag),
+                ))
+                .push(
+                    button::destructive(fl!("geotag-clear"))
+                        .on_press_maybe(has_doc.then_some(AppMessage::ClearGeotag)),
+                ),
+        )
+        .push(
+            button::standard(fl!("geotag-show-map"))
+                .on_press_maybe(location.is_some().then_some(AppMessage::ShowGeotagOnMap)),
+        );
+
+    if let Some(ref status) = state.status {
+        content = content.push(text::caption(status.clone()));
+    }
+
+    // --- Batch Apply ---
+    let selected = model.selection.len();
+
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("geotag-batch")))
+        .push(selection_list::view(model, manager))
+        .push(
+            button::suggested(fl!("geotag-apply-selected", count: selected)).on_press_maybe(
+                (location.is_some() && selected > 0 && !state.is_running())
+                    .then_some(AppMessage::ApplyGeotagToSelected),
+            ),
+        );
+
+    if let Some((done, total)) = state.progress {
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(progress_bar(0.0..=total.max(1) as f32, done as f32))
+                .push(text::caption(
+                    fl!("geotag-progress", done: done, total: total),
+                )),
+        );
+    }
+
+    // Files whose location could not be written, and why
+    if state.failures.is_empty() {
+        return content.into();
+    }
+    content = content.push(text::heading(
+        fl!("geotag-failures", count: state.failures.len()),
+    ));
+    for (path, reason) in &state.failures {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| path.display().to_string());
+        content = content.push(
+            column::with_capacity(2)
+                .spacing(2)
+                .width(Length::Fill)
+                .push(text::body(name))
+                .push(text::caption(reason.as_str())),
+        );
+    }
+    content.into()
+}
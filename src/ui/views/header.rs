@@ -4,21 +4,37 @@
 // Header bar content (navigation, rotation, flip).
 
 use cosmic::iced::Length;
-use cosmic::widget::{button, horizontal_space, icon, row};
+use cosmic::widget::{button, dropdown, horizontal_space, icon, row};
 use cosmic::Element;
 
 use crate::ui::message::AppMessage;
-use crate::ui::model::AppModel;
+use crate::ui::model::{AppMode, AppModel};
 use crate::ui::app::ContextPage;
 use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::domain::document::core::content::DocumentKind;
 use crate::fl;
 
+use super::settings_panel;
+
 /// Build the start (left) side of the header bar.
 pub fn start<'a>(
-    _model: &'a AppModel,
+    model: &'a AppModel,
     manager: &'a DocumentManager,
 ) -> Vec<Element<'a, AppMessage>> {
     let has_doc = manager.current_document().is_some();
+    let has_text = manager
+        .current_document()
+        .is_some_and(|doc| doc.kind() == DocumentKind::Portable);
+    let is_vector = manager
+        .current_document()
+        .is_some_and(|doc| doc.kind() == DocumentKind::Vector);
+    let selecting_text = matches!(model.mode, AppMode::SelectText { .. });
+    let lock_icon = if model.orientation_lock.is_some() {
+        "changes-prevent-symbolic"
+    } else {
+        "changes-allow-symbolic"
+    };
 
     // Left section: Panel toggle + Menu + Navigation
     let left_controls = row()
@@ -28,6 +44,11 @@ pub fn start<'a>(
                 .on_press(AppMessage::ToggleNavBar),
             //.tooltip(fl!("tooltip-nav-toggle")),
         )
+        .push(
+            button::icon(icon::from_name("document-open-symbolic"))
+                .on_press(AppMessage::OpenFileDialog),
+            //.tooltip(fl!("menu-file-open")),
+        )
         // .push(
         //     button::icon(icon::from_name("open-menu-symbolic"))
         //         .on_press(AppMessage::ToggleMainMenu),
@@ -35,12 +56,12 @@ pub fn start<'a>(
         // )
         .push(
             button::icon(icon::from_name("go-previous-symbolic"))
-                .on_press_maybe(has_doc.then_some(AppMessage::PrevDocument)),
+                .on_press_maybe(has_doc.then_some(AppMessage::NavigateLeft)),
             //.tooltip(fl!("tooltip-nav-previous")),
         )
         .push(
             button::icon(icon::from_name("go-next-symbolic"))
-                .on_press_maybe(has_doc.then_some(AppMessage::NextDocument)),
+                .on_press_maybe(has_doc.then_some(AppMessage::NavigateRight)),
             //.tooltip(fl!("tooltip-nav-next")),
         );
 
@@ -67,7 +88,46 @@ pub fn start<'a>(
             button::icon(icon::from_name("object-flip-vertical-symbolic"))
                 .on_press_maybe(has_doc.then_some(AppMessage::FlipVertical)),
             //.tooltip(fl!("tooltip-flip-vertical")),
-        );
+        )
+        .push(horizontal_space().width(Length::Fixed(12.0)))
+        .push(
+            button::icon(icon::from_name("edit-undo-symbolic"))
+                .tooltip(fl!("history-undo"))
+                .on_press_maybe(model.history.can_undo().then_some(AppMessage::Undo)),
+        )
+        .push(
+            button::icon(icon::from_name("edit-redo-symbolic"))
+                .tooltip(fl!("history-redo"))
+                .on_press_maybe(model.history.can_redo().then_some(AppMessage::Redo)),
+        )
+        .push(horizontal_space().width(Length::Fixed(12.0)))
+        .push(
+            button::icon(icon::from_name(lock_icon))
+                .selected(model.orientation_lock.is_some())
+                .on_press(AppMessage::ToggleOrientationLock),
+            //.tooltip(fl!("tooltip-orientation-lock")),
+        )
+        .push_maybe(matches!(model.mode, AppMode::Crop { .. }).then(|| {
+            button::icon(icon::from_name("input-keyboard-symbolic"))
+                .tooltip(fl!("action-crop-dimensions"))
+                .on_press(AppMessage::EnterCropGeometry)
+        }))
+        .push_maybe(has_text.then(|| {
+            button::icon(icon::from_name("insert-text-symbolic"))
+                .tooltip(fl!("action-select-text"))
+                .selected(selecting_text)
+                .on_press(AppMessage::ToggleTextSelectMode)
+        }))
+        .push_maybe(selecting_text.then(|| {
+            button::icon(icon::from_name("edit-copy-symbolic"))
+                .tooltip(fl!("action-copy-text"))
+                .on_press(AppMessage::CopySelectedText)
+        }))
+        .push_maybe(is_vector.then(|| {
+            button::icon(icon::from_name("edit-copy-symbolic"))
+                .tooltip(fl!("action-copy-svg"))
+                .on_press(AppMessage::CopyAsSvg)
+        }));
 
     vec![
         left_controls.into(),
@@ -78,14 +138,53 @@ pub fn start<'a>(
 
 /// Build the end (right) side of the header bar.
 pub fn end<'a>(
-    _model: &'a AppModel,
-    _manager: &'a DocumentManager,
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
 ) -> Vec<Element<'a, AppMessage>> {
-    vec![
+    let mut items = Vec::with_capacity(6);
+    // Recent files, ending with the entry that clears them
+    let recent = &config.recent_files;
+    if !recent.is_empty() {
+        let current = manager
+            .current_path()
+            .and_then(|path| recent.iter().position(|recent| recent == path));
+        let count = recent.len();
+        items.push(
+            dropdown(&model.recent_labels, current, move |index| {
+                if index < count {
+                    AppMessage::OpenRecentFile(index)
+                } else {
+                    AppMessage::ClearRecentFiles
+                }
+            })
+            .into(),
+        );
+    }
+    // Share targets, when some are configured
+    if !model.share.labels.is_empty() && manager.current_document().is_some() {
+        items.push(dropdown(&model.share.labels, None, AppMessage::ShareTo).into());
+    }
+    // Order of the files in the folder, when there is more than one
+    if manager.folder_entries().len() > 1 {
+        items.push(settings_panel::sort_order_dropdown(model, config));
+    }
+    items.extend([
+        // Settings panel toggle
+        button::icon(icon::from_name("preferences-system-symbolic"))
+            .on_press(AppMessage::ToggleContextPage(ContextPage::Settings))
+            //.tooltip(fl!("tooltip-settings-panel"))
+            .into(),
+        // Edit history panel toggle
+        button::icon(icon::from_name("document-open-recent-symbolic"))
+            .tooltip(fl!("history-title"))
+            .on_press(AppMessage::ToggleContextPage(ContextPage::History))
+            .into(),
         // Info panel toggle
         button::icon(icon::from_name("dialog-information-symbolic"))
             .on_press(AppMessage::ToggleContextPage(ContextPage::Properties))
             //.tooltip(fl!("tooltip-info-panel"))
             .into(),
-    ]
+    ]);
+    items
 }
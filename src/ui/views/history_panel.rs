@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/history_panel.rs
+//
+// Edit history panel: applied operations, click an entry to revert to it.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, horizontal_space, icon, row, text};
+use cosmic::Element;
+
+use crate::application::commands::transform_document::TransformOperation;
+use crate::application::edit_history::EditOperation;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the history panel view.
+pub fn view(model: &AppModel) -> Element<'_, AppMessage> {
+    let history = &model.history;
+    let position = history.position();
+
+    let mut list = column::with_capacity(history.entries().len() + 1).spacing(2);
+    list = list.push(entry(fl!("history-original"), 0, position));
+    for (index, operation) in history.entries().iter().enumerate() {
+        list = list.push(entry(label(operation), index + 1, position));
+    }
+
+    column::with_capacity(4)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("history-title")))
+        .push(
+            row::with_capacity(2)
+                .spacing(8)
+                .push(
+                    button::icon(icon::from_name("edit-undo-symbolic"))
+                        .tooltip(fl!("history-undo"))
+                        .on_press_maybe(history.can_undo().then_some(AppMessage::Undo)),
+                )
+                .push(
+                    button::icon(icon::from_name("edit-redo-symbolic"))
+                        .tooltip(fl!("history-redo"))
+                        .on_press_maybe(history.can_redo().then_some(AppMessage::Redo)),
+                ),
+        )
+        .push(list)
+        .push(text::caption(fl!("history-hint")))
+        .into()
+}
+
+/// One history state; `count` is the number of operations applied in it.
+///
+/// The current state is marked, undone states are dimmed.
+fn entry(label: String, count: usize, position: usize) -> Element<'static, AppMessage> {
+    let marker = icon::from_name("object-select-symbolic").size(16).icon();
+    let label: Element<'static, AppMessage> = if count > position {
+        text::caption(label).into()
+    } else {
+        text::body(label).into()
+    };
+
+    button::custom(
+        row::with_capacity(3)
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .push(label)
+            .push(horizontal_space().width(Length::Fill))
+            .push_maybe((count == position).then_some(marker)),
+    )
+    .class(cosmic::theme::Button::Text)
+    .width(Length::Fill)
+    .on_press_maybe((count != position).then_some(AppMessage::RevertHistory(count)))
+    .into()
+}
+
+/// Describe an operation with its parameters.
+fn label(operation: &EditOperation) -> String {
+    match operation {
+        EditOperation::Transform(TransformOperation::RotateCw) => fl!("history-rotate-cw"),
+        EditOperation::Transform(TransformOperation::RotateCcw) => fl!("history-rotate-ccw"),
+        EditOperation::Transform(TransformOperation::FlipHorizontal) => {
+            fl!("history-flip-horizontal")
+        }
+        EditOperation::Transform(TransformOperation::FlipVertical) => {
+            fl!("history-flip-vertical")
+        }
+        EditOperation::Transform(TransformOperation::RotateTo(rotation)) => {
+            fl!("history-rotate-to", degrees: rotation.to_degrees())
+        }
+        EditOperation::Crop(region) => fl!(
+            "history-crop",
+            width: region.width,
+            height: region.height,
+            x: region.x,
+            y: region.y
+        ),
+        EditOperation::Straighten(rotation) => {
+            fl!("history-straighten", degrees: format!("{:+.1}", rotation.degrees()))
+        }
+        EditOperation::Resize(resize) => fl!(
+            "history-resize",
+            width: resize.width,
+            height: resize.height
+        ),
+        EditOperation::CleanScan(cleanup) => fl!(
+            "history-clean-scan",
+            size: cleanup.despeckle,
+            percent: cleanup.whiten
+        ),
+    }
+}
@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/left_panel.rs
+//
+// Tabbed left panel for multi-page documents (thumbnails, outline, bookmarks, search).
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, horizontal_space, icon, row, scrollable, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::domain::document::core::content::DocumentKind;
+use crate::ui::model::LeftPanel;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+use super::pages_panel;
+
+/// Build the left panel with tab bar and the active tab's content.
+/// Returns None if the current document doesn't support multiple pages.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    tab: LeftPanel,
+) -> Option<Element<'a, AppMessage>> {
+    let doc = manager.current_document()?;
+    if doc.page_count() <= 1 {
+        return None;
+    }
+
+    let content = match tab {
+        LeftPanel::Thumbnails => pages_panel::view(model, manager)?,
+        LeftPanel::Outline => outline_tab(model, manager),
+        LeftPanel::Bookmarks => bookmarks_tab(model, manager),
+        LeftPanel::Search => search_tab(model),
+    };
+
+    Some(
+        column::with_capacity(2)
+            .spacing(4)
+            .width(Length::Fixed(220.0))
+            .height(Length::Fill)
+            .push(tab_bar(tab))
+            .push(content)
+            .into(),
+    )
+}
+
+// =============================================================================
+// Tabs
+// =============================================================================
+
+/// Row of tab buttons, the active one highlighted.
+fn tab_bar(active: LeftPanel) -> Element<'static, AppMessage> {
+    LeftPanel::ALL
+        .into_iter()
+        .fold(
+            row::with_capacity(LeftPanel::ALL.len())
+                .spacing(4)
+                .padding([8, 8, 0, 8]),
+            |bar, tab| {
+                bar.push(
+                    button::icon(icon::from_name(tab.icon_name()))
+                        .tooltip(tab.label())
+                        .selected(tab == active)
+                        .on_press(AppMessage::SelectLeftPanel(tab)),
+                )
+            },
+        )
+        .into()
+}
+
+/// Document outline entries, indented by level.
+///
+/// Scanned documents rarely have an outline; the headings on their pages
+/// are offered instead.
+fn outline_tab<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let outline = manager
+        .current_document()
+        .map(|doc| doc.outline())
+        .unwrap_or_default();
+
+    if outline.is_empty() {
+        return heading_outline(model, manager);
+    }
+
+    let mut list = column::with_capacity(outline.len()).spacing(2).padding(8);
+    for entry in outline {
+        list = list.push(page_link(
+            entry.title.clone(),
+            entry.page,
+            (entry.level as f32) * 12.0,
+        ));
+    }
+    scrollable(list).height(Length::Fill).into()
+}
+
+/// Headings found on the pages of the current document, the search for
+/// them and its progress.
+fn heading_outline<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+) -> Element<'a, AppMessage> {
+    let state = &model.heading_outline;
+    let is_for_document = state.is_for(manager.current_path());
+    let can_find = !state.is_running()
+        && manager
+            .current_document()
+            .is_some_and(|doc| doc.kind() == DocumentKind::Portable);
+
+    let mut list = column::with_capacity(state.entries.len() + 4)
+        .spacing(2)
+        .padding(8);
+    match state.progress {
+        Some((done, total)) if is_for_document => {
+            list = list.push(
+                row::with_capacity(2)
+                    .spacing(8)
+                    .align_y(Alignment::Center)
+                    .push(
+                        text::caption(fl!("left-headings-progress", done: done, total: total))
+                            .width(Length::Fill),
+                    )
+                    .push(
+                        button::icon(icon::from_name("process-stop-symbolic"))
+                            .tooltip(fl!("left-headings-stop"))
+                            .padding(4)
+                            .on_press(AppMessage::CancelFindHeadings),
+                    ),
+            );
+        }
+        _ if is_for_document && !state.entries.is_empty() => {
+            list = list.push(text::caption(fl!("left-headings-hint")));
+        }
+        _ => {
+            list = list.push(text::caption(fl!("left-outline-empty"))).push(
+                button::standard(fl!("left-headings-find"))
+                    .on_press_maybe(can_find.then_some(AppMessage::FindHeadings)),
+            );
+        }
+    }
+    if !is_for_document {
+        return scrollable(list).height(Length::Fill).into();
+    }
+
+    list = list.push_maybe(state.status.clone().map(text::caption));
+    for entry in &state.entries {
+        list = list.push(page_link(
+            entry.title.clone(),
+            entry.page,
+            (entry.level as f32) * 12.0,
+        ));
+    }
+    scrollable(list).height(Length::Fill).into()
+}
+
+/// Bookmarked pages of the current document plus a toggle for the current page.
+fn bookmarks_tab<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let bookmarks = manager
+        .current_path()
+        .and_then(|path| model.page_bookmarks.get(path))
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let current_page = manager
+        .current_document()
+        .map_or(0, |doc| doc.current_page());
+    let is_bookmarked = bookmarks.contains(&current_page);
+
+    let mut list = column::with_capacity(bookmarks.len() + 1)
+        .spacing(2)
+        .padding(8);
+    list = list.push(
+        button::standard(if is_bookmarked {
+            fl!("left-bookmark-remove")
+        } else {
+            fl!("left-bookmark-add")
+        })
+        .on_press(AppMessage::TogglePageBookmark),
+    );
+
+    if bookmarks.is_empty() {
+        list = list.push(text::caption(fl!("left-bookmarks-empty")));
+    }
+    for &page in bookmarks {
+        list = list.push(page_link(fl!("left-page", page: page + 1), page, 0.0));
+    }
+
+    scrollable(list).height(Length::Fill).into()
+}
+
+/// Search field and result list.
+fn search_tab(model: &AppModel) -> Element<'_, AppMessage> {
+    let search = &model.search;
+
+    let mut list = column::with_capacity(search.results.len() + 2)
+        .spacing(4)
+        .padding(8);
+    list = list.push(
+        text_input(fl!("left-search-placeholder"), search.query.as_str())
+            .on_input(AppMessage::SearchInput)
+            .on_submit(|_| AppMessage::RunSearch),
+    );
+
+    if search.searched {
+        list = list.push(text::caption(fl!(
+            "left-search-count",
+            count: search.results.len()
+        )));
+    }
+
+    for hit in &search.results {
+        list = list.push(
+            button::custom(
+                column::with_capacity(2)
+                    .spacing(2)
+                    .push(text::caption(fl!("left-page", page: hit.page + 1)))
+                    .push(text::body(hit.snippet.clone())),
+            )
+            .class(cosmic::theme::Button::Text)
+            .width(Length::Fill)
+            .on_press(AppMessage::GotoPage(hit.page)),
+        );
+    }
+
+    scrollable(list).height(Length::Fill).into()
+}
+
+// =============================================================================
+// Helper Components
+// =============================================================================
+
+/// Text button navigating to a page, with left indentation.
+fn page_link(label: String, page: usize, indent: f32) -> Element<'static, AppMessage> {
+    button::custom(
+        row::with_capacity(3)
+            .align_y(Alignment::Center)
+            .push(horizontal_space().width(Length::Fixed(indent)))
+            .push(text::body(label))
+            .push(horizontal_space().width(Length::Fill)),
+    )
+    .class(cosmic::theme::Button::Text)
+    .width(Length::Fill)
+    .on_press(AppMessage::GotoPage(page))
+    .into()
+}
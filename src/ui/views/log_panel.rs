@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/log_panel.rs
+//
+// Log viewer (Ctrl+Shift+L): recent warnings and errors, log levels per subsystem.
+
+use cosmic::widget::{button, column, divider, dropdown, icon, row, text};
+use cosmic::Element;
+
+use crate::config::AppConfig;
+use crate::infrastructure::logging::{self, Subsystem};
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the log viewer.
+pub fn view<'a>(model: &'a AppModel, config: &'a AppConfig) -> Element<'a, AppMessage> {
+    let entries = &model.log_entries;
+
+    let mut list = column::with_capacity(entries.len().max(1)).spacing(4);
+    if entries.is_empty() {
+        list = list.push(text::caption(fl!("log-empty")));
+    }
+    for entry in entries {
+        list = list.push(text::monotext(entry.to_string()));
+    }
+
+    let mut content = column::with_capacity(8)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("log-title")))
+        .push(
+            row::with_capacity(2)
+                .spacing(8)
+                .push(
+                    button::icon(icon::from_name("view-refresh-symbolic"))
+                        .tooltip(fl!("log-refresh"))
+                        .on_press(AppMessage::RefreshLog),
+                )
+                .push(
+                    button::icon(icon::from_name("edit-copy-symbolic"))
+                        .tooltip(fl!("log-copy"))
+                        .on_press_maybe((!entries.is_empty()).then_some(AppMessage::CopyLog)),
+                ),
+        )
+        .push(list)
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("log-section-levels")));
+
+    for subsystem in Subsystem::ALL {
+        let level =
+            logging::level_of(&config.log_levels, subsystem).unwrap_or(logging::DEFAULT_LEVEL);
+        content = content
+            .push(text::body(subsystem_label(subsystem)))
+            .push(dropdown(
+                &model.log_level_labels,
+                logging::LEVELS
+                    .iter()
+                    .position(|selectable| *selectable == level),
+                move |index| AppMessage::SetLogLevel(subsystem, logging::LEVELS[index]),
+            ));
+    }
+
+    content.push(text::caption(fl!("log-levels-hint"))).into()
+}
+
+fn subsystem_label(subsystem: Subsystem) -> String {
+    match subsystem {
+        Subsystem::Interface => fl!("log-subsystem-interface"),
+        Subsystem::Documents => fl!("log-subsystem-documents"),
+        Subsystem::Loaders => fl!("log-subsystem-loaders"),
+        Subsystem::Cache => fl!("log-subsystem-cache"),
+        Subsystem::Color => fl!("log-subsystem-color"),
+        Subsystem::System => fl!("log-subsystem-system"),
+        Subsystem::Rendering => fl!("log-subsystem-rendering"),
+    }
+}
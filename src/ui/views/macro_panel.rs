@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/macro_panel.rs
+//
+// Edit macro panel: record operation sequences and replay them on images.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{
+    button, column, divider, horizontal_space, icon, progress_bar, row, text, text_input,
+};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::domain::document::operations::edit_macro::MacroStep;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+use super::export_profile_picker;
+
+/// Build the edit macro panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let state = &model.macros;
+    // One macro runs at a time
+    let can_run = manager.current_document().is_some() && !state.is_running();
+
+    let mut content = column::with_capacity(16).spacing(12).padding(16);
+    content = content
+        .push(text::title4(fl!("macro-title")))
+        .push(text::caption(fl!("macro-hint")));
+
+    // --- Recording ---
+    match &state.recording {
+        None => {
+            content = content.push(
+                button::suggested(fl!("macro-record")).on_press(AppMessage::StartMacroRecording),
+            );
+        }
+        Some(steps) => {
+            content = content.push(text::heading(fl!("macro-recording")));
+            if steps.is_empty() {
+                content = content.push(text::body(fl!("macro-no-steps")));
+            }
+            for (index, step) in steps.iter().enumerate() {
+                content = content.push(
+                    row()
+                        .spacing(8)
+                        .align_y(Alignment::Center)
+                        .push(text::body(step.to_string()))
+                        .push(horizontal_space().width(Length::Fill))
+                        .push(
+                            button::icon(icon::from_name("edit-delete-symbolic"))
+                                .padding(4)
+                                .on_press(AppMessage::RemoveMacroStep(index)),
+                        ),
+                );
+            }
+
+            let step_valid = MacroStep::parse(&state.step_input).is_some();
+            let can_save = !steps.is_empty() && !state.name_input.trim().is_empty();
+            content = content
+                .push(
+                    row()
+                        .spacing(8)
+                        .align_y(Alignment::Center)
+                        .push(
+                            text_input(fl!("macro-step-placeholder"), state.step_input.as_str())
+                                .on_input(AppMessage::MacroStepInput)
+                                .on_submit(|_| AppMessage::AddMacroStep)
+                                .width(Length::Fill),
+                        )
+                        .push(
+                            button::standard(fl!("macro-add-step"))
+                                .on_press_maybe(step_valid.then_some(AppMessage::AddMacroStep)),
+                        ),
+                )
+                .push(text::caption(fl!("macro-step-hint")))
+                .push(
+                    text_input(fl!("macro-name-placeholder"), state.name_input.as_str())
+                        .on_input(AppMessage::MacroNameInput)
+                        .on_submit(|_| AppMessage::SaveMacro),
+                )
+                .push(
+                    row()
+                        .spacing(8)
+                        .push(
+                            button::suggested(fl!("macro-save"))
+                                .on_press_maybe(can_save.then_some(AppMessage::SaveMacro)),
+                        )
+                        .push(
+                            button::standard(fl!("macro-cancel"))
+                                .on_press(AppMessage::CancelMacroRecording),
+                        ),
+                );
+        }
+    }
+
+    // --- Saved macros ---
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("macro-saved")));
+
+    if config.macros.is_empty() {
+        content = content.push(text::body(fl!("macro-none-saved")));
+    } else {
+        content = content.push(export_profile_picker::view(model, config));
+    }
+    for (name, steps) in &config.macros {
+        content =
+            content.push(
+                column::with_capacity(3)
+                    .spacing(4)
+                    .push(
+                        row()
+                            .spacing(8)
+                            .align_y(Alignment::Center)
+                            .push(text::body(name.as_str()))
+                            .push(horizontal_space().width(Length::Fill))
+                            .push(
+                                button::icon(icon::from_name("edit-delete-symbolic"))
+                                    .padding(4)
+                                    .on_press(AppMessage::DeleteMacro(name.clone())),
+                            ),
+                    )
+                    .push(text::caption(steps.as_str()))
+                    .push(
+                        row()
+                            .spacing(8)
+                            .push(button::standard(fl!("macro-run-current")).on_press_maybe(
+                                can_run.then(|| AppMessage::RunMacro(name.clone())),
+                            ))
+                            .push(button::standard(fl!("macro-run-folder")).on_press_maybe(
+                                can_run.then(|| AppMessage::RunMacroOnFolder(name.clone())),
+                            )),
+                    ),
+            );
+    }
+
+    if let Some((done, total)) = state.progress {
+        content = content.push(
+            row::with_capacity(3)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(progress_bar(0.0..=total.max(1) as f32, done as f32))
+                .push(text::caption(
+                    fl!("macro-progress", done: done, total: total),
+                ))
+                .push(
+                    button::icon(icon::from_name("process-stop-symbolic"))
+                        .tooltip(fl!("macro-stop"))
+                        .on_press(AppMessage::CancelMacro)
+                        .padding(4),
+                ),
+        );
+    }
+    if let Some(ref status) = state.status {
+        content = content.push(text::caption(status.clone()));
+    }
+
+    content.into()
+}
@@ -3,17 +3,21 @@
 //
 // Metadata and properties panel for document information.
 
-use cosmic::iced::{Alignment, Length};
-use cosmic::widget::{button, column, divider, horizontal_space, icon, row, text};
+use cosmic::iced::{Alignment, ContentFit, Length};
+use cosmic::widget::{button, column, divider, horizontal_space, icon, image, row, text};
 use cosmic::Element;
 
 use crate::application::DocumentManager;
+use crate::ui::app::ContextPage;
 use crate::domain::document::core::document::Renderable;
+use crate::domain::document::core::metadata::RenderingIntent;
+use crate::domain::document::operations::alpha;
+use crate::ui::model::AppMode;
 use crate::ui::{AppMessage, AppModel};
 use crate::fl;
 
 /// Build the metadata/properties panel view.
-pub fn view(_model: &AppModel, manager: &DocumentManager) -> Element<'static, AppMessage> {
+pub fn view(model: &AppModel, manager: &DocumentManager) -> Element<'static, AppMessage> {
     let mut content = column::with_capacity(16).spacing(8).padding(12);
 
     // Header with action icons
@@ -53,6 +57,20 @@ pub fn view(_model: &AppModel, manager: &DocumentManager) -> Element<'static, Ap
             ));
         }
 
+        if let Some(resolution) = meta.resolution {
+            let (width, height) = manager
+                .current_document()
+                .map_or((meta.basic.width, meta.basic.height), |doc| {
+                    doc.dimensions()
+                });
+            content = content
+                .push(meta_row(fl!("meta-resolution"), resolution.dpi_display()))
+                .push(meta_row(
+                    fl!("meta-print-size"),
+                    resolution.physical_size_display(width, height),
+                ));
+        }
+
         content = content
             .push(meta_row(
                 fl!("meta-filesize"),
@@ -63,6 +81,34 @@ pub fn view(_model: &AppModel, manager: &DocumentManager) -> Element<'static, Ap
                 meta.basic.color_type.clone(),
             ));
 
+        if let Some(orientation) = meta.orientation {
+            let correction = match orientation.correction() {
+                (false, degrees) => fl!("meta-orientation-rotated", degrees: degrees),
+                (true, 0) => fl!("meta-orientation-mirrored"),
+                (true, degrees) => fl!("meta-orientation-mirrored-rotated", degrees: degrees),
+            };
+            let value = if orientation.applied {
+                fl!("meta-orientation-applied", correction: correction)
+            } else {
+                fl!("meta-orientation-ignored", correction: correction)
+            };
+            content = content.push(meta_row(fl!("meta-orientation"), value));
+        }
+
+        if manager
+            .current_document()
+            .is_some_and(|doc| alpha::has_alpha(doc.rendered_image()))
+        {
+            content = content.push(
+                button::standard(fl!("action-export-alpha")).on_press(AppMessage::ExportAlphaMask),
+            );
+        }
+
+        // --- Crop Selection Section (while selecting) ---
+        if let Some(selection) = selection_section(model) {
+            content = content.push(divider::horizontal::light()).push(selection);
+        }
+
         // --- EXIF Section (if available) ---
         if let Some(ref exif) = meta.exif {
             let has_exif_data = exif.camera_display().is_some()
@@ -104,17 +150,95 @@ pub fn view(_model: &AppModel, manager: &DocumentManager) -> Element<'static, Ap
 
                 if let Some(gps) = exif.gps_display() {
                     content = content.push(meta_row(fl!("meta-gps"), gps));
+                    if let Some(altitude) = exif.altitude_display() {
+                        content = content.push(meta_row(fl!("meta-altitude"), altitude));
+                    }
+                    content = content.push(
+                        row::with_capacity(2)
+                            .spacing(8)
+                            .push(
+                                button::standard(fl!("action-copy-coordinates"))
+                                    .on_press(AppMessage::CopyGpsCoordinates),
+                            )
+                            .push(
+                                button::standard(fl!("action-open-in-map"))
+                                    .on_press(AppMessage::OpenGpsInMap),
+                            ),
+                    );
                 }
             }
         }
 
+        // --- Color Profile Section (if embedded) ---
+        if let Some(ref icc) = meta.icc {
+            content = content
+                .push(divider::horizontal::light())
+                .push(section_header(fl!("meta-section-icc")));
+
+            if let Some(ref description) = icc.description {
+                content = content.push(meta_row(fl!("meta-icc-name"), description.clone()));
+            }
+
+            content = content
+                .push(meta_row(
+                    fl!("meta-icc-colorspace"),
+                    format!("{} (ICC v{})", icc.color_space, icc.version),
+                ))
+                .push(meta_row(
+                    fl!("meta-icc-intent"),
+                    rendering_intent_label(icc.rendering_intent),
+                ));
+
+            if let Some(white_point) = icc.white_point_display() {
+                content = content.push(meta_row(fl!("meta-icc-whitepoint"), white_point));
+            }
+
+            content = content.push(
+                button::standard(fl!("action-export-profile"))
+                    .on_press(AppMessage::ExportIccProfile),
+            );
+        }
+
         // --- File Path (at the bottom, less prominent) ---
         content = content
             .push(divider::horizontal::light())
             .push(meta_row_small(
                 fl!("meta-path"),
                 meta.basic.file_path.clone(),
-            ));
+            ))
+            .push(
+                row::with_capacity(2)
+                    .spacing(8)
+                    .push(
+                        button::standard(fl!("action-copy-info"))
+                            .on_press(AppMessage::CopyMetadata(false)),
+                    )
+                    .push(
+                        button::standard(fl!("action-copy-info-json"))
+                            .on_press(AppMessage::CopyMetadata(true)),
+                    ),
+            )
+            .push(
+                row::with_capacity(2)
+                    .spacing(8)
+                    .push(
+                        button::standard(fl!("action-browse-metadata"))
+                            .on_press(AppMessage::ToggleContextPage(ContextPage::MetadataBrowser)),
+                    )
+                    .push(
+                        button::standard(fl!("action-edit-metadata"))
+                            .on_press(AppMessage::ToggleContextPage(ContextPage::EditMetadata)),
+                    ),
+            );
+
+        // --- Catalog of the whole folder ---
+        let can_export = !model.catalog.is_running() && !manager.folder_entries().is_empty();
+        content = content
+            .push(
+                button::standard(fl!("action-export-catalog"))
+                    .on_press_maybe(can_export.then_some(AppMessage::ExportCatalog)),
+            )
+            .push_maybe(model.catalog.status.clone().map(text::caption));
     } else {
         // No document loaded
         content = content
@@ -126,6 +250,68 @@ pub fn view(_model: &AppModel, manager: &DocumentManager) -> Element<'static, Ap
     content.into()
 }
 
+/// Plain text of the metadata `summary` fields, one `Label: value` line
+/// each, with the labels of the panel.
+pub fn summary_text(fields: &[(&str, String)]) -> String {
+    let lines: Vec<String> = fields
+        .iter()
+        .map(|(key, value)| {
+            let value = match *key {
+                "rendering_intent" => intent_name_label(value),
+                _ => value.clone(),
+            };
+            format!("{}: {value}", summary_label(key))
+        })
+        .collect();
+    lines.join("\n")
+}
+
+/// Translated name of a rendering intent.
+fn rendering_intent_label(intent: RenderingIntent) -> String {
+    match intent {
+        RenderingIntent::Unknown(value) => format!("{} ({value})", intent_name_label("unknown")),
+        intent => intent_name_label(intent.as_str()),
+    }
+}
+
+/// Translated name of a rendering intent, by its stable name.
+fn intent_name_label(name: &str) -> String {
+    match name {
+        "perceptual" => fl!("meta-icc-intent-perceptual"),
+        "relative-colorimetric" => fl!("meta-icc-intent-relative"),
+        "saturation" => fl!("meta-icc-intent-saturation"),
+        "absolute-colorimetric" => fl!("meta-icc-intent-absolute"),
+        _ => fl!("meta-icc-intent-unknown"),
+    }
+}
+
+fn summary_label(key: &str) -> String {
+    match key {
+        "file_name" => fl!("meta-filename"),
+        "format" => fl!("meta-format"),
+        "dimensions" => fl!("meta-dimensions"),
+        "resolution" => fl!("meta-resolution"),
+        "print_size" => fl!("meta-print-size"),
+        "file_size" => fl!("meta-filesize"),
+        "color_type" => fl!("meta-colortype"),
+        "orientation" => fl!("meta-orientation"),
+        "camera" => fl!("meta-camera"),
+        "date_time" => fl!("meta-datetime"),
+        "exposure" => fl!("meta-exposure"),
+        "aperture" => fl!("meta-aperture"),
+        "focal_length" => fl!("meta-focal"),
+        "gps" => fl!("meta-gps"),
+        "altitude" => fl!("meta-altitude"),
+        "color_profile" => fl!("meta-icc-name"),
+        "color_space" => fl!("meta-icc-colorspace"),
+        "rendering_intent" => fl!("meta-icc-intent"),
+        "white_point" => fl!("meta-icc-whitepoint"),
+        "path" => fl!("meta-path"),
+        "iso" => "ISO".to_string(),
+        _ => key.to_string(),
+    }
+}
+
 // =============================================================================
 // Helper Components
 // =============================================================================
@@ -134,12 +320,116 @@ pub fn view(_model: &AppModel, manager: &DocumentManager) -> Element<'static, Ap
 fn panel_header(manager: &DocumentManager) -> Element<'static, AppMessage> {
     let has_doc = manager.current_document().is_some();
 
-    row::with_capacity(5)
+    row::with_capacity(16)
         .spacing(4)
         .align_y(Alignment::Center)
         .padding([0, 0, 8, 0])
         .push(text::title4(fl!("panel-properties")))
         .push(horizontal_space().width(Length::Fill))
+        .push(
+            button::icon(icon::from_name("mark-location-symbolic"))
+                .tooltip(fl!("action-edit-location"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::Geotag)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("x-office-calendar-symbolic"))
+                .tooltip(fl!("action-shift-dates"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::DateShift)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("security-high-symbolic"))
+                .tooltip(fl!("action-privacy-export"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::PrivacyExport)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("folder-pictures-symbolic"))
+                .tooltip(fl!("action-batch-convert"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::BatchConvert)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("document-save-as-symbolic"))
+                .tooltip(fl!("action-save-as"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::SaveAs)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("color-select-symbolic"))
+                .tooltip(fl!("action-adjust-colors"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::Adjustments)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("zoom-fit-best-symbolic"))
+                .tooltip(fl!("action-resize"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::Resize)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("edit-clear-all-symbolic"))
+                .tooltip(fl!("action-clean-scan"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::ScanCleanup)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("media-record-symbolic"))
+                .tooltip(fl!("action-edit-macros"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::Macros)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("view-app-grid-symbolic"))
+                .tooltip(fl!("action-sprite-sheet"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::SpriteSheet)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("media-playback-start-symbolic"))
+                .tooltip(fl!("action-export-animation"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::Animation)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("send-to-symbolic"))
+                .tooltip(fl!("action-upload"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::Upload)),
+                ),
+        )
+        .push(
+            button::icon(icon::from_name("bookmark-new-symbolic"))
+                .tooltip(fl!("action-zoom-bookmarks"))
+                .padding(4)
+                .on_press_maybe(
+                    has_doc.then_some(AppMessage::ToggleContextPage(ContextPage::ZoomBookmarks)),
+                ),
+        )
         .push(
             button::icon(icon::from_name("image-x-generic-symbolic"))
                 .tooltip(fl!("action-set-wallpaper"))
@@ -149,6 +439,73 @@ fn panel_header(manager: &DocumentManager) -> Element<'static, AppMessage> {
         .into()
 }
 
+/// Size of the crop selection and, once analyzed, statistics of its pixels.
+///
+/// None outside of crop mode or without a selection.
+fn selection_section(model: &AppModel) -> Option<Element<'static, AppMessage>> {
+    let AppMode::Crop { selection } = &model.mode else {
+        return None;
+    };
+    let region = model.viewport.image_region(selection)?;
+
+    let mut section = column::with_capacity(8)
+        .spacing(8)
+        .push(section_header(fl!("meta-section-selection")))
+        .push(meta_row(
+            fl!("meta-selection-size"),
+            format!("{} × {}", region.width, region.height),
+        ))
+        .push(meta_row(
+            fl!("meta-aspect-ratio"),
+            region.aspect_ratio_display(),
+        ))
+        .push(meta_row(
+            fl!("meta-megapixels"),
+            format!("{:.1} MP", region.megapixels()),
+        ));
+
+    // Statistics of an earlier selection are not shown
+    match model
+        .selection_stats
+        .as_ref()
+        .filter(|analyzed| analyzed.region == region)
+    {
+        Some(analyzed) => {
+            let stats = &analyzed.stats;
+            let [r, g, b] = stats.mean;
+            section = section
+                .push(meta_row(
+                    fl!("meta-mean-color"),
+                    format!("{r:.0}, {g:.0}, {b:.0}"),
+                ))
+                .push(meta_row(
+                    fl!("meta-luminance"),
+                    fl!(
+                        "meta-luminance-value",
+                        mean: format!("{:.1}", stats.luma_mean),
+                        deviation: format!("{:.1}", stats.luma_std_dev),
+                        min: stats.luma_range.0,
+                        max: stats.luma_range.1
+                    ),
+                ))
+                .push(text::caption(format!("{}:", fl!("meta-histogram"))))
+                .push(
+                    image::Image::new(analyzed.histogram.clone())
+                        .width(Length::Fill)
+                        .height(Length::Fixed(64.0))
+                        .content_fit(ContentFit::Fill),
+                );
+        }
+        None => {
+            section = section.push(
+                button::standard(fl!("action-analyze-selection"))
+                    .on_press(AppMessage::AnalyzeSelection),
+            );
+        }
+    }
+    Some(section.into())
+}
+
 /// Section header for grouping metadata.
 fn section_header(label: String) -> Element<'static, AppMessage> {
     text::heading(label).size(14).into()
@@ -0,0 +1,100 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/metadata_browser_panel.rs
+//
+// Metadata browser: every EXIF tag, XMP property and IPTC dataset of the
+// image, grouped, with a search field and copyable values.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, divider, icon, row, text, text_input};
+use cosmic::Element;
+
+use crate::domain::document::operations::metadata_tags::{MetadataTag, TagGroup};
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the metadata browser view.
+pub fn view(model: &AppModel) -> Element<'_, AppMessage> {
+    let state = &model.metadata_browser;
+
+    let mut content = column::with_capacity(4)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("metadata-browser-title")))
+        .push(
+            text_input(fl!("metadata-browser-search"), state.filter_input.as_str())
+                .on_input(AppMessage::MetadataFilterInput),
+        );
+
+    let Some(groups) = &state.groups else {
+        let status = if state.path.is_some() {
+            fl!("metadata-browser-reading")
+        } else {
+            fl!("no-document")
+        };
+        return content.push(text::body(status)).into();
+    };
+    if groups.is_empty() {
+        return content
+            .push(text::body(fl!("metadata-browser-none")))
+            .into();
+    }
+
+    let visible = state.visible();
+    if visible.is_empty() {
+        return content
+            .push(text::body(fl!("metadata-browser-no-match")))
+            .into();
+    }
+    for (index, group) in visible.into_iter().enumerate() {
+        if index > 0 {
+            content = content.push(divider::horizontal::light());
+        }
+        let heading = fl!(
+            "metadata-browser-group",
+            group: group_label(group.group),
+            count: group.tags.len()
+        );
+        let mut section = column::with_capacity(1 + group.tags.len())
+            .spacing(8)
+            .push(text::heading(heading));
+        for tag in group.tags {
+            section = section.push(tag_row(tag));
+        }
+        content = content.push(section);
+    }
+    content.into()
+}
+
+/// Name of a tag over its value, with a button copying the value.
+fn tag_row(tag: MetadataTag) -> Element<'static, AppMessage> {
+    row::with_capacity(2)
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .push(
+            column::with_capacity(2)
+                .spacing(2)
+                .width(Length::Fill)
+                .push(text::caption(tag.name))
+                .push(text::body(tag.value.clone())),
+        )
+        .push(
+            button::icon(icon::from_name("edit-copy-symbolic"))
+                .tooltip(fl!("metadata-browser-copy"))
+                .padding(4)
+                .on_press(AppMessage::CopyMetadataValue(tag.value)),
+        )
+        .into()
+}
+
+fn group_label(group: TagGroup) -> String {
+    match group {
+        TagGroup::Image => fl!("metadata-group-image"),
+        TagGroup::Photo => fl!("metadata-group-photo"),
+        TagGroup::Gps => fl!("metadata-group-gps"),
+        TagGroup::Interoperability => fl!("metadata-group-interoperability"),
+        TagGroup::Thumbnail => fl!("metadata-group-thumbnail"),
+        TagGroup::Xmp => fl!("metadata-group-xmp"),
+        TagGroup::XmpSidecar => fl!("metadata-group-xmp-sidecar"),
+        TagGroup::Iptc => fl!("metadata-group-iptc"),
+    }
+}
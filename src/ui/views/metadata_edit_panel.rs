@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/metadata_edit_panel.rs
+//
+// Metadata editor panel: author, copyright, description, star rating and
+// capture date of the current image, written into the file.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{button, column, icon, row, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::domain::document::operations::labels::MAX_RATING;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the metadata editor panel view.
+pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let state = &model.metadata_edit;
+    let fields = state.fields();
+    // The EXIF block is rewritten in place
+    let can_write = manager.current_document().is_some() && !model.read_only;
+
+    let field = |label: String, value: &'a str, on_input: fn(String) -> AppMessage| {
+        column::with_capacity(2)
+            .spacing(4)
+            .push(text::body(label))
+            .push(text_input("", value).on_input(on_input))
+    };
+
+    let mut stars = row::with_capacity(usize::from(MAX_RATING))
+        .spacing(2)
+        .align_y(Alignment::Center);
+    for star in 1..=MAX_RATING {
+        let name = if star <= state.rating {
+            "starred-symbolic"
+        } else {
+            "non-starred-symbolic"
+        };
+        stars = stars.push(
+            button::icon(icon::from_name(name))
+                .tooltip(fl!("metadata-edit-stars", stars: star))
+                .padding(4)
+                .on_press(AppMessage::SetMetadataRating(star)),
+        );
+    }
+
+    column::with_capacity(12)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("metadata-edit-title")))
+        .push(text::caption(fl!("metadata-edit-hint")))
+        .push(field(
+            fl!("metadata-edit-author"),
+            &state.author,
+            AppMessage::MetadataAuthorInput,
+        ))
+        .push(field(
+            fl!("metadata-edit-copyright"),
+            &state.copyright,
+            AppMessage::MetadataCopyrightInput,
+        ))
+        .push(field(
+            fl!("metadata-edit-description"),
+            &state.description,
+            AppMessage::MetadataDescriptionInput,
+        ))
+        .push(
+            column::with_capacity(2)
+                .spacing(4)
+                .push(text::body(fl!("metadata-edit-rating")))
+                .push(stars),
+        )
+        .push(
+            column::with_capacity(3)
+                .spacing(4)
+                .push(text::body(fl!("metadata-edit-date")))
+                .push(
+                    text_input("YYYY:MM:DD HH:MM:SS", state.date_input.as_str())
+                        .on_input(AppMessage::MetadataDateInput),
+                )
+                .push_maybe(
+                    fields
+                        .is_none()
+                        .then(|| text::caption(fl!("metadata-edit-date-invalid"))),
+                ),
+        )
+        .push_maybe(
+            model
+                .read_only
+                .then(|| text::caption(fl!("metadata-edit-read-only"))),
+        )
+        .push(button::suggested(fl!("metadata-edit-save")).on_press_maybe(
+            (can_write && fields.is_some()).then_some(AppMessage::SaveMetadataEdits),
+        ))
+        .push_maybe(state.status.clone().map(text::caption))
+        .into()
+}
@@ -3,19 +3,48 @@
 //
 // View module exports.
 
+pub mod adjust_panel;
+pub mod animation_panel;
+pub mod batch_panel;
 pub mod canvas;
+pub mod compare_bar;
+pub mod crop_geometry_panel;
+pub mod date_shift_panel;
+pub mod date_shift_dialog;
+pub mod delete_dialog;
+pub mod export_pages_panel;
+pub mod export_profile_picker;
+pub mod find_bar;
 pub mod footer;
 pub mod format_panel;
+pub mod geotag_panel;
 pub mod header;
+pub mod history_panel;
+pub mod left_panel;
+pub mod log_panel;
+pub mod macro_panel;
 pub mod meta_panel;
+pub mod metadata_browser_panel;
+pub mod metadata_edit_panel;
+pub mod page_range;
 pub mod pages_panel;
 pub mod panels;
+pub mod privacy_panel;
+pub mod resize_panel;
+pub mod save_as_panel;
+pub mod scan_cleanup_panel;
+pub mod selection_list;
+pub mod settings_panel;
+pub mod sprite_sheet_panel;
+pub mod start_screen;
+pub mod upload_panel;
+pub mod wallpaper_panel;
+pub mod zoom_bookmarks_panel;
 
 use cosmic::iced::Length;
 use cosmic::widget::container;
 use cosmic::{Action, Element};
 
-use crate::ui::model::LeftPanel;
 use crate::ui::{AppMessage, AppModel};
 use crate::application::DocumentManager;
 use crate::config::AppConfig;
@@ -31,21 +60,19 @@ pub fn view<'a>(
 
 /// Navigation bar content (left panel).
 ///
-/// Shows different panels based on panel state:
-/// - `LeftPanel::Thumbnails`: Page thumbnails (multi-page documents)
+/// Shows the tabbed left panel for multi-page documents:
+/// - `Some(tab)`: Thumbnails, outline, bookmarks or search results
 /// - `None`: Hidden
 pub fn nav_bar<'a>(
     model: &'a AppModel,
     manager: &'a DocumentManager,
 ) -> Option<Element<'a, Action<AppMessage>>> {
-    match model.panels.left {
-        None => None,
-        Some(LeftPanel::Thumbnails) => pages_panel::view(model, manager).map(|panel| {
-            container(panel.map(Action::App))
-                .width(Length::Shrink)
-                .height(Length::Fill)
-                .max_width(250)
-                .into()
-        }),
-    }
+    let tab = model.panels.left?;
+    left_panel::view(model, manager, tab).map(|panel| {
+        container(panel.map(Action::App))
+            .width(Length::Shrink)
+            .height(Length::Fill)
+            .max_width(250)
+            .into()
+    })
 }
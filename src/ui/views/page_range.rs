@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/page_range.rs
+//
+// Reusable page range input with validation and live page count.
+
+use cosmic::widget::{column, text, text_input};
+use cosmic::Element;
+
+use crate::domain::document::core::page::{PageRange, PageRangeError};
+use crate::ui::AppMessage;
+use crate::fl;
+
+/// Page range field for print, export and extract dialogs.
+///
+/// Shows the number of selected pages below the field, or the reason the
+/// expression is invalid.
+pub fn view(
+    input: &str,
+    page_count: usize,
+    on_input: impl Fn(String) -> AppMessage + 'static,
+) -> Element<'static, AppMessage> {
+    let status = match PageRange::parse(input, page_count) {
+        Ok(range) => fl!("page-range-count", count: range.len(), total: page_count),
+        Err(PageRangeError::Invalid(part)) => fl!("page-range-invalid", part: part),
+        Err(PageRangeError::OutOfBounds(page)) => {
+            fl!("page-range-out-of-bounds", page: page, total: page_count)
+        }
+        Err(PageRangeError::NoPages) => fl!("page-range-empty"),
+    };
+
+    column::with_capacity(2)
+        .spacing(4)
+        .push(text_input(fl!("page-range-placeholder"), input.to_string()).on_input(on_input))
+        .push(text::caption(status))
+        .into()
+}
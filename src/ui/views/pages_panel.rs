@@ -6,6 +6,9 @@
 /// Maximum width in pixels for page navigation thumbnails.
 const THUMBNAIL_MAX_WIDTH: f32 = 100.0;
 
+use std::ops::Range;
+
+use cosmic::iced::widget::scrollable::Viewport;
 use cosmic::iced::{Alignment, Length};
 use cosmic::widget::{button, column, container, scrollable, text};
 use cosmic::widget::image as cosmic_image;
@@ -13,6 +16,8 @@ use cosmic::widget::image as cosmic_image;
 use cosmic::Element;
 
 use crate::application::DocumentManager;
+use crate::domain::document::core::content::DocumentKind;
+use crate::ui::app::ContextPage;
 use crate::ui::{AppMessage, AppModel};
 use crate::fl;
 
@@ -33,20 +38,28 @@ pub fn view<'a>(
     let current_page = doc.current_page();
     let loaded = doc.thumbnails_loaded();
 
-    let mut content = column::with_capacity(page_count + 1)
+    let mut content = column::with_capacity(page_count + 2)
         .spacing(12)
         .padding([12, 8])
         .align_x(Alignment::Center)
         .width(Length::Fill);
 
+    // PDF pages can be exported as images.
+    if matches!(doc.kind(), DocumentKind::Portable) {
+        content = content.push(
+            button::standard(fl!("export-pages-open"))
+                .on_press(AppMessage::ToggleContextPage(ContextPage::ExportPages)),
+        );
+    }
+
     // Show loading progress if not all thumbnails are ready.
     if !doc.thumbnails_ready() {
         let loading_msg = fl!("loading-thumbnails", current: loaded, total: page_count);
         content = content.push(text::caption(loading_msg));
     }
 
-    // Build thumbnail list for pages that are already loaded.
-    for page_index in 0..loaded {
+    // Build thumbnail list; pages still waiting for theirs show a placeholder.
+    for page_index in 0..page_count {
         let is_current = page_index == current_page;
 
         // Get cached thumbnail handle (read-only access).
@@ -93,11 +106,28 @@ pub fn view<'a>(
         content = content.push(page_button);
     }
 
-    // Wrap in scrollable container.
+    // Wrap in scrollable container; the pages scrolled into view get their
+    // thumbnails first.
     Some(
         scrollable(content)
+            .on_scroll(move |viewport| {
+                AppMessage::ThumbnailsScrolled(visible_pages(&viewport, page_count))
+            })
             .width(Length::Shrink)
             .height(Length::Fill)
             .into(),
     )
 }
+
+/// Pages in view in the scrolled panel, taking all entries to be about as
+/// tall (placeholders are sized like a portrait page).
+fn visible_pages(viewport: &Viewport, page_count: usize) -> Range<usize> {
+    let content_height = viewport.content_bounds().height;
+    if content_height <= 0.0 {
+        return 0..0;
+    }
+    let top = viewport.absolute_offset().y;
+    let bottom = top + viewport.bounds().height;
+    let page = |y: f32| ((y / content_height * page_count as f32) as usize).min(page_count);
+    page(top)..(page(bottom) + 1).min(page_count)
+}
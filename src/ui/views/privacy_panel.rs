@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/privacy_panel.rs
+//
+// Privacy export panel: blur regions and export copies of selected images.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, divider, horizontal_space, icon, progress_bar, row, text};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::ui::model::AppMode;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+use super::{export_profile_picker, selection_list};
+
+/// Build the privacy export panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let state = &model.privacy;
+    let has_selection = match &model.mode {
+        AppMode::Crop { selection } => selection.region.is_some(),
+        _ => false,
+    };
+    let in_crop_mode = matches!(model.mode, AppMode::Crop { .. });
+
+    let mut content = column::with_capacity(16).spacing(12).padding(16);
+
+    // --- Regions ---
+    content = content
+        .push(text::title4(fl!("privacy-title")))
+        .push(text::caption(fl!("privacy-hint")))
+        .push(
+            row()
+                .spacing(8)
+                .push(
+                    button::standard(fl!("privacy-draw-region")).on_press_maybe(
+                        (!in_crop_mode && manager.current_document().is_some())
+                            .then_some(AppMessage::ToggleCropMode),
+                    ),
+                )
+                .push(
+                    button::suggested(fl!("privacy-add-region"))
+                        .on_press_maybe(has_selection.then_some(AppMessage::AddPrivacyRegion)),
+                ),
+        );
+
+    if state.regions.is_empty() {
+        content = content.push(text::body(fl!("privacy-no-regions")));
+    } else {
+        for (index, region) in state.regions.iter().enumerate() {
+            content = content.push(
+                row()
+                    .spacing(8)
+                    .align_y(Alignment::Center)
+                    .push(text::body(region.display()))
+                    .push(horizontal_space().width(Length::Fill))
+                    .push(
+                        button::icon(icon::from_name("edit-delete-symbolic"))
+                            .padding(4)
+                            .on_press(AppMessage::RemovePrivacyRegion(index)),
+                    ),
+            );
+        }
+        content = content.push(
+            button::destructive(fl!("privacy-clear-regions"))
+                .on_press(AppMessage::ClearPrivacyRegions),
+        );
+    }
+
+    // --- Images ---
+    let selected = model.selection.len();
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("privacy-images")))
+        .push(selection_list::view(model, manager))
+        .push(export_profile_picker::view(model, config))
+        .push(
+            button::suggested(fl!("privacy-export", count: selected)).on_press_maybe(
+                (selected > 0 && !state.regions.is_empty() && !state.is_running())
+                    .then_some(AppMessage::ExportPrivacyCopies),
+            ),
+        );
+
+    if let Some((done, total)) = state.progress {
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(progress_bar(0.0..=total.max(1) as f32, done as f32))
+                .push(text::caption(
+                    fl!("privacy-progress", done: done, total: total),
+                )),
+        );
+    }
+    if let Some(ref status) = state.status {
+        content = content.push(text::caption(status.clone()));
+    }
+
+    content.into()
+}
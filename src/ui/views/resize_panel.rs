@@ -0,0 +1,143 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/resize_panel.rs
+//
+// Resize panel: new size in pixels, percent or paper format, and the
+// resampling filter.
+
+use cosmic::iced::Alignment;
+use cosmic::widget::{button, checkbox, column, dropdown, radio, row, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::domain::document::operations::resize::ResampleFilter;
+use crate::ui::model::{Orientation, PaperFormat, ResizeUnit};
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the resize panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &AppConfig,
+) -> Element<'a, AppMessage> {
+    let mut content = column::with_capacity(12)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("resize-title")));
+
+    let Some(doc) = manager
+        .current_document()
+        .filter(|doc| doc.supports_resize())
+    else {
+        return content
+            .push(text::caption(fl!("resize-unsupported")))
+            .into();
+    };
+    let (width, height) = doc.dimensions();
+    let state = &model.resize;
+
+    content = content.push(text::caption(
+        fl!("resize-current", width: width, height: height),
+    ));
+    for unit in ResizeUnit::ALL {
+        let label = match unit {
+            ResizeUnit::Pixels => fl!("resize-unit-pixels"),
+            ResizeUnit::Percent => fl!("resize-unit-percent"),
+            ResizeUnit::Paper => fl!("resize-unit-paper"),
+        };
+        content =
+            content.push(radio(label, unit, Some(state.unit), AppMessage::SetResizeUnit).size(16));
+    }
+
+    content = match state.unit {
+        ResizeUnit::Pixels | ResizeUnit::Percent => content
+            .push(
+                row::with_capacity(3)
+                    .spacing(8)
+                    .align_y(Alignment::Center)
+                    .push(
+                        text_input(fl!("resize-width"), state.width_input.as_str())
+                            .on_input(AppMessage::ResizeWidthInput)
+                            .on_submit(|_| AppMessage::ApplyResize),
+                    )
+                    .push(text::body("×"))
+                    .push(
+                        text_input(fl!("resize-height"), state.height_input.as_str())
+                            .on_input(AppMessage::ResizeHeightInput)
+                            .on_submit(|_| AppMessage::ApplyResize),
+                    ),
+            )
+            .push(
+                checkbox(fl!("resize-lock-aspect"), state.lock_aspect)
+                    .on_toggle(AppMessage::SetResizeLockAspect),
+            ),
+        ResizeUnit::Paper => content
+            .push(dropdown(
+                &model.paper_format_labels,
+                PaperFormat::ALL
+                    .iter()
+                    .position(|&paper| paper == state.paper),
+                |index| AppMessage::SetResizePaper(PaperFormat::ALL[index]),
+            ))
+            .push(
+                row::with_capacity(2)
+                    .spacing(16)
+                    .push(
+                        radio(
+                            fl!("resize-landscape"),
+                            Orientation::Horizontal,
+                            Some(state.orientation),
+                            AppMessage::SetResizeOrientation,
+                        )
+                        .size(16),
+                    )
+                    .push(
+                        radio(
+                            fl!("resize-portrait"),
+                            Orientation::Vertical,
+                            Some(state.orientation),
+                            AppMessage::SetResizeOrientation,
+                        )
+                        .size(16),
+                    ),
+            )
+            .push(text::body(fl!("resize-dpi")))
+            .push(
+                text_input("300", state.dpi_input.as_str())
+                    .on_input(AppMessage::ResizeDpiInput)
+                    .on_submit(|_| AppMessage::ApplyResize),
+            )
+            .push(
+                checkbox(fl!("resize-fit-paper"), state.lock_aspect)
+                    .on_toggle(AppMessage::SetResizeLockAspect),
+            ),
+    };
+
+    let resize = state.resize((width, height), config.max_image_megapixels);
+    let status = match (resize, state.target((width, height))) {
+        (Some(resize), _) => {
+            fl!("resize-preview", width: resize.width, height: resize.height)
+        }
+        (None, Some(_)) => fl!("resize-too-large", megapixels: config.max_image_megapixels),
+        (None, None) => fl!("resize-invalid"),
+    };
+    let changed = resize.is_some_and(|resize| (resize.width, resize.height) != (width, height));
+
+    content
+        .push(text::body(fl!("resize-filter")))
+        .push(dropdown(
+            &model.resample_filter_labels,
+            ResampleFilter::ALL
+                .iter()
+                .position(|&filter| filter == state.filter),
+            |index| AppMessage::SetResampleFilter(ResampleFilter::ALL[index]),
+        ))
+        .push(text::body(status))
+        .push(
+            button::suggested(fl!("resize-apply"))
+                .on_press_maybe(changed.then_some(AppMessage::ApplyResize)),
+        )
+        .push(text::caption(fl!("resize-hint")))
+        .into()
+}
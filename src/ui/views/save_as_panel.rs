@@ -0,0 +1,181 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/save_as_panel.rs
+//
+// Save As panel: pick an export profile and write the edited image, or
+// keep the edits in a sidecar next to the original.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, divider, dropdown, row, slider, text, toggler};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::core::metadata::format_size;
+use crate::domain::document::operations::export::ExportFormat;
+use crate::domain::document::operations::format_advice::ImageKind;
+use crate::ui::model::ExportProfileState;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+use super::export_profile_picker;
+
+/// Width of the format names in the size comparison.
+const FORMAT_WIDTH: f32 = 56.0;
+
+/// Build the Save As panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let can_save = manager
+        .current_document()
+        .is_some_and(|doc| matches!(doc.kind(), DocumentKind::Raster | DocumentKind::Vector));
+    let state = &model.export_profiles;
+    let without_profile = state.selected.is_none();
+    // JPEG chosen, or kept from a JPEG original
+    let is_jpeg = state
+        .format
+        .or_else(|| manager.current_path().and_then(ExportFormat::from_path))
+        == Some(ExportFormat::Jpeg);
+
+    column::with_capacity(16)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("save-as-title")))
+        .push(text::caption(fl!("save-as-hint")))
+        .push_maybe(
+            model
+                .read_only
+                .then(|| text::body(fl!("save-as-read-only"))),
+        )
+        .push(export_profile_picker::view(model, config))
+        .push_maybe(without_profile.then(|| {
+            column::with_capacity(2)
+                .spacing(4)
+                .push(text::body(fl!("save-as-format")))
+                .push(dropdown(
+                    &state.format_labels,
+                    Some(state.format_index()),
+                    AppMessage::SelectSaveFormat,
+                ))
+        }))
+        .push_maybe((without_profile && is_jpeg).then(|| {
+            column::with_capacity(2)
+                .spacing(4)
+                .push(text::body(fl!("save-as-quality", quality: state.quality)))
+                .push(slider(1..=100, state.quality, AppMessage::SetSaveQuality))
+        }))
+        // Also overrides "metadata keep" of a profile
+        .push(
+            toggler(state.strip_metadata)
+                .label(fl!("save-as-strip-metadata"))
+                .on_toggle(AppMessage::SetStripMetadata),
+        )
+        .push_maybe(without_profile.then(|| {
+            toggler(state.premultiplied)
+                .label(fl!("save-as-premultiplied"))
+                .on_toggle(AppMessage::SetExportPremultiplied)
+        }))
+        .push_maybe(can_save.then(|| advice_section(state)))
+        // No sidecar can be written next to a read-only original
+        .push_maybe((!model.read_only).then(|| {
+            toggler(config.edit_sidecar_on_save)
+                .label(fl!("save-as-sidecar-too"))
+                .on_toggle(AppMessage::SetSidecarOnSave)
+        }))
+        .push(
+            button::suggested(fl!("save-as-button"))
+                .on_press_maybe(can_save.then_some(AppMessage::SaveAs)),
+        )
+        .push_maybe(state.overwrite.as_ref().map(|_| {
+            column::with_capacity(2)
+                .spacing(8)
+                .push(text::body(fl!("save-as-overwrite")))
+                .push(
+                    row::with_capacity(2)
+                        .spacing(8)
+                        .push(
+                            button::destructive(fl!("save-as-replace"))
+                                .on_press(AppMessage::ConfirmOverwrite),
+                        )
+                        .push(
+                            button::standard(fl!("save-as-cancel"))
+                                .on_press(AppMessage::CancelOverwrite),
+                        ),
+                )
+        }))
+        .push(divider::horizontal::light())
+        .push(text::caption(fl!("save-as-sidecar-hint")))
+        .push(
+            button::standard(fl!("save-as-sidecar-button")).on_press_maybe(
+                (can_save && !model.read_only).then_some(AppMessage::SaveEditSidecar),
+            ),
+        )
+        .into()
+}
+
+/// Kind of image, suggested format and the estimated sizes of the formats,
+/// with a button choosing the suggestion.
+fn advice_section(state: &ExportProfileState) -> Element<'_, AppMessage> {
+    let Some(advice) = &state.advice else {
+        return text::caption(fl!("save-as-advice-working")).into();
+    };
+    let kind = match advice.kind {
+        ImageKind::Photo => fl!("save-as-advice-photo"),
+        ImageKind::Graphic => fl!("save-as-advice-graphic"),
+        ImageKind::Transparent => fl!("save-as-advice-transparent"),
+        ImageKind::Animated => fl!("save-as-advice-animated"),
+    };
+    let suggestion = match (advice.format, advice.quality) {
+        (Some(format), Some(quality)) => fl!(
+            "save-as-advice-lossy",
+            format: format.extension().to_uppercase(),
+            quality: quality
+        ),
+        (Some(format), None) => {
+            fl!("save-as-advice-lossless", format: format.extension().to_uppercase())
+        }
+        (None, _) => fl!("save-as-advice-keep"),
+    };
+    // Already chosen, or a profile decides the format
+    let chosen = state.selected.is_none()
+        && state.format == advice.format
+        && advice
+            .quality
+            .is_none_or(|quality| quality == state.quality);
+
+    let mut sizes = column::with_capacity(advice.estimates.len()).spacing(2);
+    for estimate in &advice.estimates {
+        sizes = sizes.push(
+            row::with_capacity(2)
+                .spacing(8)
+                .push(
+                    text::body(estimate.format.extension().to_uppercase())
+                        .width(Length::Fixed(FORMAT_WIDTH)),
+                )
+                .push(text::body(fl!(
+                    "save-as-advice-size",
+                    size: format_size(estimate.bytes)
+                ))),
+        );
+    }
+
+    column::with_capacity(4)
+        .spacing(8)
+        .push(text::heading(kind))
+        .push(
+            row::with_capacity(2)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(text::body(suggestion).width(Length::Fill))
+                .push_maybe(advice.format.map(|_| {
+                    button::standard(fl!("save-as-advice-use"))
+                        .on_press_maybe((!chosen).then_some(AppMessage::UseFormatAdvice))
+                })),
+        )
+        .push(sizes)
+        .push(text::caption(fl!("save-as-advice-hint")))
+        .into()
+}
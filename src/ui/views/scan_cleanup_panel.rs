@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/scan_cleanup_panel.rs
+//
+// Scan cleanup panel: speck size and paper whitening for scanned pages.
+
+use cosmic::widget::{button, column, slider, text};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::domain::document::operations::scan_cleanup::ScanCleanup;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the scan cleanup panel view.
+pub fn view(model: &AppModel, manager: &DocumentManager) -> Element<'static, AppMessage> {
+    let content = column::with_capacity(8)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("scan-cleanup-title")));
+
+    if !manager
+        .current_document()
+        .is_some_and(|doc| doc.supports_scan_cleanup())
+    {
+        return content
+            .push(text::caption(fl!("scan-cleanup-unsupported")))
+            .into();
+    }
+
+    let cleanup = model.scan_cleanup;
+    content
+        .push(
+            column::with_capacity(3)
+                .spacing(4)
+                .push(text::body(
+                    fl!("scan-cleanup-despeckle", size: cleanup.despeckle),
+                ))
+                .push(slider(
+                    ScanCleanup::DESPECKLE_RANGE,
+                    cleanup.despeckle,
+                    move |despeckle| {
+                        AppMessage::SetScanCleanup(ScanCleanup {
+                            despeckle,
+                            ..cleanup
+                        })
+                    },
+                ))
+                .push(text::caption(fl!("scan-cleanup-despeckle-hint"))),
+        )
+        .push(
+            column::with_capacity(3)
+                .spacing(4)
+                .push(text::body(
+                    fl!("scan-cleanup-whiten", percent: cleanup.whiten),
+                ))
+                .push(slider(
+                    ScanCleanup::WHITEN_RANGE,
+                    cleanup.whiten,
+                    move |whiten| AppMessage::SetScanCleanup(ScanCleanup { whiten, ..cleanup }),
+                ))
+                .push(text::caption(fl!("scan-cleanup-whiten-hint"))),
+        )
+        .push(
+            button::suggested(fl!("scan-cleanup-apply"))
+                .on_press_maybe((!cleanup.is_none()).then_some(AppMessage::ApplyScanCleanup)),
+        )
+        .push(text::caption(fl!("scan-cleanup-hint")))
+        .into()
+}
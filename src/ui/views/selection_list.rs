@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/selection_list.rs
+//
+// Folder image checklist shared by batch tools.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, checkbox, column, dropdown, row, scrollable, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build a "select all" toggle and a rating and tag filter, followed by a
+/// scrollable list of folder images.
+pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let selection = &model.selection;
+    let entries = manager.folder_entries();
+    let all_selected = !entries.is_empty() && entries.iter().all(|p| selection.contains(p));
+
+    let mut list = column::with_capacity(entries.len()).spacing(4);
+    for path in entries {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let path = path.clone();
+        list = list.push(
+            checkbox(name, selection.contains(&path))
+                .on_toggle(move |selected| AppMessage::SelectFolderImage(path.clone(), selected)),
+        );
+    }
+
+    let select_matching = button::standard(fl!("selection-select-matching"))
+        .on_press_maybe((!selection.matching).then_some(AppMessage::SelectMatchingImages));
+
+    column::with_capacity(4)
+        .spacing(8)
+        .height(Length::Fill)
+        .push(
+            checkbox(fl!("selection-select-all"), all_selected)
+                .on_toggle(AppMessage::SelectAllFolderImages),
+        )
+        .push(
+            row::with_capacity(2)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(dropdown(
+                    &model.rating_labels,
+                    Some(usize::from(selection.min_rating)),
+                    |stars| AppMessage::SetSelectionMinRating(stars as u8),
+                ))
+                .push(select_matching),
+        )
+        .push(
+            text_input(fl!("selection-tags"), selection.tags_input.as_str())
+                .on_input(AppMessage::SelectionTagsInput)
+                .on_submit(|_| AppMessage::SelectMatchingImages),
+        )
+        .push(scrollable(list).height(Length::Fill))
+        .into()
+}
@@ -0,0 +1,478 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/settings_panel.rs
+//
+// Settings panel for application preferences.
+
+use cosmic::iced::widget::container as iced_container;
+use cosmic::iced::{Alignment, Background, Color, Length};
+use cosmic::widget::{
+    button, column, container, divider, dropdown, horizontal_space, icon, row, text, text_input,
+    toggler,
+};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::{AppConfig, parse_hex_color};
+use crate::domain::document::core::content::DocumentKind;
+use crate::domain::document::operations::export_profile::ExportProfile;
+use crate::infrastructure::filesystem::SortOrder;
+use crate::ui::model::{CanvasBackground, LowPowerMode, ViewMode, Viewport};
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the settings panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let mut content = column::with_capacity(16).spacing(12).padding(16);
+
+    // --- Navigation Section ---
+    content = content
+        .push(text::heading(fl!("settings-section-navigation")))
+        .push(text::body(fl!("settings-exclude-patterns")))
+        .push(
+            text_input(
+                fl!("settings-exclude-placeholder"),
+                model.exclude_patterns_input.as_str(),
+            )
+            .on_input(AppMessage::ExcludePatternsInput)
+            .on_submit(|_| AppMessage::ApplyExcludePatterns),
+        )
+        .push(text::caption(fl!("settings-exclude-hint")))
+        .push(button::standard(fl!("settings-apply")).on_press(AppMessage::ApplyExcludePatterns))
+        .push(text::body(fl!("settings-sort-order")))
+        .push(sort_order_dropdown(model, config))
+        .push(text::caption(fl!("settings-sort-hint")))
+        .push(
+            toggler(config.arrows_turn_pages)
+                .label(fl!("settings-arrows-turn-pages"))
+                .on_toggle(AppMessage::SetArrowsTurnPages),
+        )
+        .push(text::caption(fl!("settings-arrows-turn-pages-hint")))
+        .push(text::body(fl!("settings-rescan")))
+        .push(dropdown(
+            &model.rescan_interval_labels,
+            AppModel::RESCAN_INTERVALS
+                .iter()
+                .position(|&seconds| seconds == config.folder_rescan_interval),
+            |index| AppMessage::SetRescanInterval(AppModel::RESCAN_INTERVALS[index]),
+        ))
+        .push(text::caption(fl!("settings-rescan-hint")))
+        .push(text::body(fl!("settings-low-power")))
+        .push(dropdown(
+            &model.low_power_labels,
+            LowPowerMode::ALL.iter().position(|mode| {
+                *mode == LowPowerMode::from_key(&config.low_power_mode).unwrap_or_default()
+            }),
+            |index| AppMessage::SetLowPowerMode(LowPowerMode::ALL[index]),
+        ))
+        .push(text::caption(if model.low_power {
+            fl!("settings-low-power-active")
+        } else {
+            fl!("settings-low-power-hint")
+        }));
+
+    // --- Default View Section ---
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("settings-section-view")));
+
+    for kind in DocumentKind::ALL {
+        let selected = config
+            .view_modes
+            .get(kind.name())
+            .and_then(|key| ViewMode::from_key(key))
+            .unwrap_or_default();
+        content = content.push(text::body(kind_label(kind))).push(dropdown(
+            &model.view_mode_labels,
+            ViewMode::PRESETS.iter().position(|mode| *mode == selected),
+            move |index| AppMessage::SetDefaultViewMode(kind, ViewMode::PRESETS[index]),
+        ));
+    }
+
+    content = content
+        .push(
+            toggler(config.integer_zoom)
+                .label(fl!("settings-integer-zoom"))
+                .on_toggle(AppMessage::SetIntegerZoom),
+        )
+        .push(text::caption(fl!("settings-integer-zoom-hint")))
+        .push(
+            toggler(config.fit_sharpening)
+                .label(fl!("settings-fit-sharpening"))
+                .on_toggle(AppMessage::SetFitSharpening),
+        )
+        .push(text::caption(fl!("settings-fit-sharpening-hint")))
+        .push(
+            toggler(config.cover_other_screens)
+                .label(fl!("settings-cover-screens"))
+                .on_toggle(AppMessage::SetCoverOtherScreens),
+        )
+        .push(text::caption(fl!("settings-cover-screens-hint")))
+        .push(
+            toggler(config.hide_cursor_in_fullscreen)
+                .label(fl!("settings-hide-cursor"))
+                .on_toggle(AppMessage::SetHideCursorInFullscreen),
+        )
+        .push(
+            toggler(config.canvas_controls)
+                .label(fl!("settings-canvas-controls"))
+                .on_toggle(AppMessage::SetCanvasControls),
+        )
+        .push(text::caption(fl!("settings-canvas-controls-hint")))
+        .push(text::body(fl!("settings-canvas-background")))
+        .push(canvas_background_picker(model, config))
+        .push_maybe(document_background_picker(model, manager, config))
+        .push(text::body(fl!("settings-slide-interval")))
+        .push(dropdown(
+            &model.slide_interval_labels,
+            AppModel::SLIDE_INTERVALS
+                .iter()
+                .position(|&seconds| seconds == config.slide_interval),
+            |index| AppMessage::SetSlideInterval(AppModel::SLIDE_INTERVALS[index]),
+        ))
+        .push(
+            toggler(config.slideshow_loop)
+                .label(fl!("settings-slideshow-loop"))
+                .on_toggle(AppMessage::SetSlideshowLoop),
+        )
+        .push(
+            toggler(config.slideshow_shuffle)
+                .label(fl!("settings-slideshow-shuffle"))
+                .on_toggle(AppMessage::SetSlideshowShuffle),
+        )
+        .push(text::body(fl!("settings-slideshow-filter")))
+        .push(dropdown(
+            &model.rating_labels,
+            Some(usize::from(config.slideshow_min_rating)),
+            |stars| AppMessage::SetSlideshowMinRating(stars as u8),
+        ))
+        .push(
+            text_input(
+                fl!("settings-slideshow-tags"),
+                config.slideshow_tags.as_str(),
+            )
+            .on_input(AppMessage::SetSlideshowTags),
+        )
+        .push(text::caption(fl!("settings-slideshow-filter-hint")))
+        .push(
+            toggler(config.auto_orient)
+                .label(fl!("settings-auto-orient"))
+                .on_toggle(AppMessage::SetAutoOrient),
+        )
+        .push(text::caption(fl!("settings-auto-orient-hint")))
+        .push(
+            toggler(config.import_xmp_edits)
+                .label(fl!("settings-import-xmp"))
+                .on_toggle(AppMessage::SetImportXmpEdits),
+        )
+        .push(text::caption(fl!("settings-import-xmp-hint")))
+        .push(
+            toggler(config.confirm_delete)
+                .label(fl!("settings-confirm-delete"))
+                .on_toggle(AppMessage::SetConfirmDelete),
+        );
+
+    // --- Magnifier Section ---
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("settings-section-magnifier")))
+        .push(text::body(fl!("settings-magnifier-zoom")))
+        .push(dropdown(
+            &model.magnifier_zoom_labels,
+            Viewport::MAGNIFIER_ZOOMS
+                .iter()
+                .position(|zoom| (zoom - config.magnifier_zoom).abs() < f32::EPSILON),
+            |index| AppMessage::SetMagnifierZoom(Viewport::MAGNIFIER_ZOOMS[index]),
+        ))
+        .push(
+            toggler(config.magnifier_crosshair)
+                .label(fl!("settings-magnifier-crosshair"))
+                .on_toggle(AppMessage::SetMagnifierCrosshair),
+        )
+        .push(text::caption(fl!("settings-magnifier-hint")));
+
+    // --- SVG Text Section ---
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("settings-section-svg-text")))
+        .push(text::body(fl!("settings-svg-fonts")))
+        .push(
+            text_input(
+                fl!("settings-svg-fonts-placeholder"),
+                model.svg_fonts_input.as_str(),
+            )
+            .on_input(AppMessage::SvgFontsInput)
+            .on_submit(|_| AppMessage::ApplySvgFonts),
+        )
+        .push(text::caption(fl!("settings-svg-fonts-hint")))
+        .push(button::standard(fl!("settings-apply")).on_press(AppMessage::ApplySvgFonts))
+        .push(text::body(fl!("settings-svg-font-size")))
+        .push(dropdown(
+            &model.svg_font_size_labels,
+            AppModel::SVG_FONT_SIZES
+                .iter()
+                .position(|size| (size - config.svg_font_size).abs() < f32::EPSILON),
+            |index| AppMessage::SetSvgFontSize(AppModel::SVG_FONT_SIZES[index]),
+        ))
+        .push(
+            toggler(config.svg_text_to_path)
+                .label(fl!("settings-svg-text-to-path"))
+                .on_toggle(AppMessage::SetSvgTextToPath),
+        )
+        .push(text::caption(fl!("settings-svg-text-to-path-hint")));
+
+    // --- Export Profiles Section ---
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("settings-section-export")));
+
+    for (name, settings) in &config.export_profiles {
+        content = content.push(
+            column::with_capacity(2)
+                .spacing(4)
+                .push(
+                    row()
+                        .spacing(8)
+                        .align_y(Alignment::Center)
+                        .push(text::body(name.as_str()))
+                        .push(horizontal_space().width(Length::Fill))
+                        .push(
+                            button::icon(icon::from_name("edit-delete-symbolic"))
+                                .padding(4)
+                                .on_press(AppMessage::DeleteExportProfile(name.clone())),
+                        ),
+                )
+                .push(text::caption(settings.as_str())),
+        );
+    }
+
+    let profiles = &model.export_profiles;
+    let parsed = ExportProfile::parse(&profiles.settings_input);
+    let can_add = !profiles.name_input.trim().is_empty() && parsed.is_ok();
+    content = content
+        .push(
+            text_input(fl!("settings-export-name"), profiles.name_input.as_str())
+                .on_input(AppMessage::ExportProfileNameInput),
+        )
+        .push(
+            text_input(
+                fl!("settings-export-placeholder"),
+                profiles.settings_input.as_str(),
+            )
+            .on_input(AppMessage::ExportProfileSettingsInput)
+            .on_submit(|_| AppMessage::AddExportProfile),
+        )
+        .push(text::caption(match parsed {
+            Err(setting) if !profiles.settings_input.trim().is_empty() => {
+                fl!("settings-export-invalid", setting: setting)
+            }
+            _ => fl!("settings-export-hint"),
+        }))
+        .push(
+            button::standard(fl!("settings-export-add"))
+                .on_press_maybe(can_add.then_some(AppMessage::AddExportProfile)),
+        );
+
+    // --- Share Targets Section ---
+    content = content
+        .push(divider::horizontal::light())
+        .push(text::heading(fl!("settings-section-share")));
+
+    for (name, target) in &config.share_targets {
+        content = content.push(
+            column::with_capacity(2)
+                .spacing(4)
+                .push(
+                    row()
+                        .spacing(8)
+                        .align_y(Alignment::Center)
+                        .push(text::body(name.as_str()))
+                        .push(horizontal_space().width(Length::Fill))
+                        .push(
+                            button::icon(icon::from_name("edit-delete-symbolic"))
+                                .padding(4)
+                                .on_press(AppMessage::DeleteShareTarget(name.clone())),
+                        ),
+                )
+                .push(text::caption(target.as_str())),
+        );
+    }
+
+    let share = &model.share;
+    let can_add = !share.name_input.trim().is_empty() && !share.target_input.trim().is_empty();
+    content = content
+        .push(
+            text_input(fl!("settings-share-name"), share.name_input.as_str())
+                .on_input(AppMessage::ShareNameInput),
+        )
+        .push(
+            text_input(
+                fl!("settings-share-placeholder"),
+                share.target_input.as_str(),
+            )
+            .on_input(AppMessage::ShareTargetInput)
+            .on_submit(|_| AppMessage::AddShareTarget),
+        )
+        .push(text::caption(fl!("settings-share-hint")))
+        .push(
+            button::standard(fl!("settings-share-add"))
+                .on_press_maybe(can_add.then_some(AppMessage::AddShareTarget)),
+        );
+
+    // --- Color Management Section ---
+    #[cfg(feature = "color")]
+    {
+        let color = &model.color;
+        let active_profile = color
+            .monitor
+            .as_ref()
+            .map_or_else(|| "sRGB".to_string(), |p| p.name.clone());
+
+        content = content
+            .push(divider::horizontal::light())
+            .push(text::heading(fl!("settings-section-color")))
+            .push(
+                toggler(color.enabled)
+                    .label(fl!("settings-color-management"))
+                    .on_toggle(AppMessage::SetColorManagement),
+            )
+            .push(text::body(fl!("settings-monitor-profile")))
+            .push(dropdown(
+                &color.profile_labels,
+                Some(color.selected_index(config.monitor_profile.as_deref())),
+                AppMessage::SelectMonitorProfile,
+            ))
+            .push(text::caption(fl!(
+                "settings-monitor-profile-active",
+                name: active_profile
+            )));
+    }
+
+    content.into()
+}
+
+/// Picker of the folder navigation order (settings panel and header bar).
+pub fn sort_order_dropdown<'a>(
+    model: &'a AppModel,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let selected = SortOrder::from_key(&config.nav_sort_order).unwrap_or_default();
+    dropdown(
+        &model.sort_order_labels,
+        SortOrder::ALL.iter().position(|order| *order == selected),
+        |index| AppMessage::SetSortOrder(SortOrder::ALL[index]),
+    )
+    .into()
+}
+
+/// Canvas background dropdown, with the color field when a solid color is
+/// chosen.
+fn canvas_background_picker<'a>(
+    model: &'a AppModel,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let selected = CanvasBackground::from_key(&config.canvas_background).unwrap_or_default();
+    let picker = dropdown(
+        &model.canvas_background_labels,
+        CanvasBackground::ALL
+            .iter()
+            .position(|background| *background == selected),
+        |index| AppMessage::SetCanvasBackground(CanvasBackground::ALL[index]),
+    );
+    if selected != CanvasBackground::Color {
+        return picker.into();
+    }
+
+    // Swatch of the typed color, or of the one in use while it is invalid
+    let rgb = parse_hex_color(&model.canvas_color_input)
+        .or_else(|| config.canvas_rgb())
+        .unwrap_or_default();
+    row::with_capacity(3)
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .push(picker)
+        .push(
+            text_input("#808080", model.canvas_color_input.as_str())
+                .on_input(AppMessage::CanvasColorInput),
+        )
+        .push(color_swatch(rgb))
+        .into()
+}
+
+/// Canvas background of the current document alone, with the color field
+/// when it has a solid color of its own; None without a document.
+fn document_background_picker<'a>(
+    model: &'a AppModel,
+    manager: &DocumentManager,
+    config: &'a AppConfig,
+) -> Option<Element<'a, AppMessage>> {
+    let path = manager.current_path()?;
+    let file = path.file_name()?.to_string_lossy().into_owned();
+    let own = config.document_background(path);
+    let (key, rgb) = config.canvas_background_of(Some(path));
+    let selected = own.map(|_| CanvasBackground::from_key(key).unwrap_or_default());
+    let picker = dropdown(
+        &model.document_background_labels,
+        Some(selected.map_or(0, |background| {
+            CanvasBackground::ALL
+                .iter()
+                .position(|option| *option == background)
+                .map_or(0, |index| index + 1)
+        })),
+        |index| {
+            AppMessage::SetDocumentBackground(
+                index.checked_sub(1).map(|i| CanvasBackground::ALL[i]),
+            )
+        },
+    );
+    let mut choice = row::with_capacity(3)
+        .spacing(8)
+        .align_y(Alignment::Center)
+        .push(picker);
+    if selected == Some(CanvasBackground::Color) {
+        // Typed text while it belongs to this document, else the color kept
+        let typed = model
+            .document_color_input
+            .as_ref()
+            .filter(|(typed_for, _)| typed_for == path)
+            .map_or(own.map_or("", String::as_str), |(_, typed)| typed.as_str());
+        choice = choice
+            .push(text_input("#808080", typed).on_input(AppMessage::DocumentColorInput))
+            .push(color_swatch(
+                parse_hex_color(typed).or(rgb).unwrap_or_default(),
+            ));
+    }
+    Some(
+        column::with_capacity(3)
+            .spacing(8)
+            .push(text::body(fl!("settings-document-background", file: file)))
+            .push(choice)
+            .push(text::caption(fl!("settings-document-background-hint")))
+            .into(),
+    )
+}
+
+/// Square filled with a color.
+fn color_swatch([red, green, blue]: [u8; 3]) -> Element<'static, AppMessage> {
+    container(horizontal_space().width(Length::Fixed(24.0)))
+        .height(Length::Fixed(24.0))
+        .class(cosmic::theme::Container::custom(move |_| {
+            iced_container::Style {
+                background: Some(Background::Color(Color::from_rgb8(red, green, blue))),
+                ..Default::default()
+            }
+        }))
+        .into()
+}
+
+/// Settings label for a document kind.
+fn kind_label(kind: DocumentKind) -> String {
+    match kind {
+        DocumentKind::Raster => fl!("settings-view-raster"),
+        DocumentKind::Vector => fl!("settings-view-vector"),
+        DocumentKind::Portable => fl!("settings-view-portable"),
+    }
+}
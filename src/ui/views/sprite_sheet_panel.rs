@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/sprite_sheet_panel.rs
+//
+// Sprite sheet panel: grid overlay, cell export and JSON atlas.
+
+use cosmic::widget::{button, column, row, text, text_input, toggler};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the sprite sheet panel view.
+pub fn view<'a>(model: &'a AppModel, manager: &'a DocumentManager) -> Element<'a, AppMessage> {
+    let state = &model.sprite_sheet;
+    let cells = state
+        .grid()
+        .zip(manager.current_document())
+        .map(|(grid, doc)| grid.cells(doc.dimensions()))
+        .unwrap_or_default();
+
+    let summary = match cells.first() {
+        Some(cell) => fl!(
+            "sprite-cells",
+            count: cells.len(),
+            width: cell.width,
+            height: cell.height
+        ),
+        None if state.input.trim().is_empty() => String::new(),
+        None => fl!("sprite-invalid"),
+    };
+    let placeholder = if state.by_count {
+        fl!("sprite-count-placeholder")
+    } else {
+        fl!("sprite-size-placeholder")
+    };
+    let can_export = !cells.is_empty();
+
+    let mut content = column::with_capacity(8)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("sprite-title")))
+        .push(text::caption(fl!("sprite-hint")))
+        .push(
+            toggler(state.by_count)
+                .label(fl!("sprite-by-count"))
+                .on_toggle(AppMessage::SetSpriteGridByCount),
+        )
+        .push(text_input(placeholder, state.input.as_str()).on_input(AppMessage::SpriteGridInput))
+        .push(text::caption(summary))
+        .push(
+            toggler(state.show_grid)
+                .label(fl!("sprite-show-grid"))
+                .on_toggle(AppMessage::SetSpriteGridVisible),
+        )
+        .push(
+            row::with_capacity(2)
+                .spacing(8)
+                .push(
+                    button::suggested(fl!("sprite-export-cells"))
+                        .on_press_maybe(can_export.then_some(AppMessage::ExportSpriteCells)),
+                )
+                .push(
+                    button::standard(fl!("sprite-export-atlas"))
+                        .on_press_maybe(can_export.then_some(AppMessage::ExportSpriteAtlas)),
+                ),
+        );
+
+    if let Some(ref status) = state.status {
+        content = content.push(text::caption(status.clone()));
+    }
+
+    content.into()
+}
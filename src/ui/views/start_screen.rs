@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/start_screen.rs
+//
+// Start screen shown without a document: the recent files to reopen.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::image as cosmic_image;
+use cosmic::widget::{button, column, container, icon, row, scrollable, text};
+use cosmic::Element;
+
+use crate::ui::{AppMessage, AppModel};
+use crate::config::AppConfig;
+use crate::fl;
+
+/// Side of a recent file tile's picture, in pixels.
+const TILE_SIZE: f32 = 160.0;
+
+/// Recent files per row.
+const TILES_PER_ROW: usize = 4;
+
+/// Build the start screen with a tile for each recent file.
+pub fn view<'a>(model: &'a AppModel, config: &'a AppConfig) -> Element<'a, AppMessage> {
+    let recent = &config.recent_files;
+    let mut tiles = column::with_capacity(recent.len().div_ceil(TILES_PER_ROW))
+        .spacing(12)
+        .align_x(Alignment::Center);
+    for (start, paths) in recent.chunks(TILES_PER_ROW).enumerate() {
+        let mut tile_row = row::with_capacity(paths.len()).spacing(12);
+        for (offset, path) in paths.iter().enumerate() {
+            let index = start * TILES_PER_ROW + offset;
+            // Files without a thumbnail (yet) show a generic icon
+            let picture: Element<'a, AppMessage> = match model.recent_thumbnails.get(path) {
+                Some(handle) => cosmic_image::Image::new(handle.clone())
+                    .width(Length::Fixed(TILE_SIZE))
+                    .height(Length::Fixed(TILE_SIZE))
+                    .into(),
+                None => container(icon::from_name("image-x-generic-symbolic").size(64).icon())
+                    .width(Length::Fixed(TILE_SIZE))
+                    .height(Length::Fixed(TILE_SIZE))
+                    .center(Length::Fixed(TILE_SIZE))
+                    .into(),
+            };
+            let tile = column::with_capacity(2)
+                .spacing(4)
+                .width(Length::Fixed(TILE_SIZE))
+                .align_x(Alignment::Center)
+                .push(picture)
+                .push(text::caption(model.recent_labels[index].clone()));
+            tile_row = tile_row.push(
+                button::custom(tile)
+                    .class(cosmic::theme::Button::Standard)
+                    .padding(4)
+                    .on_press(AppMessage::OpenRecentFile(index)),
+            );
+        }
+        tiles = tiles.push(tile_row);
+    }
+
+    let content = column::with_capacity(3)
+        .spacing(16)
+        .padding(24)
+        .align_x(Alignment::Center)
+        .push(text::title4(fl!("recent-title")))
+        .push(tiles)
+        .push(button::standard(fl!("recent-clear")).on_press(AppMessage::ClearRecentFiles));
+
+    container(scrollable(content).width(Length::Shrink))
+        .width(Length::Fill)
+        .height(Length::Fill)
+        .center(Length::Fill)
+        .into()
+}
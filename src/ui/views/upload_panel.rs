@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/upload_panel.rs
+//
+// Upload panel: the upload endpoint, the running upload and the links of
+// the images uploaded before.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, horizontal_space, icon, progress_bar, row, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::infrastructure::system::upload::UploadEndpoint;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the upload panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let state = &model.upload;
+    let input = state.endpoint_input.trim();
+    let input_valid = input.is_empty() || UploadEndpoint::parse(input).is_some();
+    let can_save = input_valid && input != config.upload_endpoint;
+    let can_upload = !state.is_running()
+        && manager.current_document().is_some()
+        && UploadEndpoint::parse(&config.upload_endpoint).is_some();
+
+    let mut content = column::with_capacity(10)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("upload-title")))
+        .push(
+            column::with_capacity(3)
+                .spacing(4)
+                .push(text::body(fl!("upload-endpoint")))
+                .push(
+                    row::with_capacity(2)
+                        .spacing(8)
+                        .align_y(Alignment::Center)
+                        .push(
+                            text_input(fl!("upload-endpoint-placeholder"), input)
+                                .on_input(AppMessage::UploadEndpointInput)
+                                .on_submit(|_| AppMessage::SaveUploadEndpoint),
+                        )
+                        .push(
+                            button::standard(fl!("upload-endpoint-save"))
+                                .on_press_maybe(can_save.then_some(AppMessage::SaveUploadEndpoint)),
+                        ),
+                )
+                .push(text::caption(if input_valid {
+                    fl!("upload-endpoint-hint")
+                } else {
+                    fl!("upload-endpoint-invalid")
+                })),
+        )
+        .push(
+            button::suggested(fl!("upload-start"))
+                .on_press_maybe(can_upload.then_some(AppMessage::Upload)),
+        );
+
+    if let Some(percent) = state.progress {
+        content = content.push(
+            row::with_capacity(3)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(progress_bar(0.0..=100.0, f32::from(percent)))
+                .push(text::caption(fl!("upload-progress", percent: percent)))
+                .push(
+                    button::icon(icon::from_name("process-stop-symbolic"))
+                        .tooltip(fl!("upload-cancel"))
+                        .on_press(AppMessage::CancelUpload)
+                        .padding(4),
+                ),
+        );
+    }
+    content = content.push_maybe(state.status.clone().map(text::caption));
+
+    // Links of earlier uploads, most recent first
+    if config.upload_history.is_empty() {
+        return content.into();
+    }
+    content = content.push(
+        row::with_capacity(3)
+            .spacing(8)
+            .align_y(Alignment::Center)
+            .push(text::heading(fl!("upload-history")))
+            .push(horizontal_space().width(Length::Fill))
+            .push(
+                button::standard(fl!("upload-history-clear"))
+                    .on_press(AppMessage::ClearUploadHistory),
+            ),
+    );
+    for (index, (file_name, link)) in config.upload_history.iter().enumerate() {
+        content = content.push(
+            row::with_capacity(3)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(
+                    column::with_capacity(2)
+                        .width(Length::Fill)
+                        .push(text::body(file_name.as_str()))
+                        .push(text::caption(link.as_str())),
+                )
+                .push(
+                    button::icon(icon::from_name("edit-copy-symbolic"))
+                        .tooltip(fl!("upload-copy-link"))
+                        .padding(4)
+                        .on_press(AppMessage::CopyUploadLink(index)),
+                )
+                .push(
+                    button::icon(icon::from_name("web-browser-symbolic"))
+                        .tooltip(fl!("upload-open-link"))
+                        .padding(4)
+                        .on_press(AppMessage::OpenUploadLink(index)),
+                ),
+        );
+    }
+    content.into()
+}
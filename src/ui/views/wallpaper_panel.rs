@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/wallpaper_panel.rs
+//
+// Wallpaper assistant: frame the part of the image the screen shows.
+
+use cosmic::widget::{button, column, radio, text};
+use cosmic::Element;
+
+use crate::config::AppConfig;
+use crate::ui::model::WALLPAPER_RATIOS;
+use crate::ui::AppMessage;
+use crate::fl;
+
+/// Build the wallpaper assistant panel view.
+pub fn view(config: &AppConfig) -> Element<'_, AppMessage> {
+    let mut content = column::with_capacity(14)
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("wallpaper-title")))
+        .push(text::caption(fl!("wallpaper-hint")))
+        .push(text::body(fl!("wallpaper-ratio")));
+
+    for ratio in WALLPAPER_RATIOS {
+        let (width, height) = ratio;
+        content = content.push(
+            radio(
+                format!("{width}:{height}"),
+                ratio,
+                Some(config.wallpaper_ratio),
+                AppMessage::SetWallpaperRatio,
+            )
+            .size(16),
+        );
+    }
+
+    content
+        .push(button::suggested(fl!("wallpaper-apply")).on_press(AppMessage::ApplyWallpaper))
+        .push(
+            button::standard(fl!("wallpaper-whole-image"))
+                .on_press(AppMessage::SetWholeImageAsWallpaper),
+        )
+        .into()
+}
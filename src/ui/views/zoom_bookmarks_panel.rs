@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/views/zoom_bookmarks_panel.rs
+//
+// Zoom bookmarks panel: named regions of large maps and plans, shown again
+// with a click or Ctrl and their number.
+
+use cosmic::iced::{Alignment, Length};
+use cosmic::widget::{button, column, icon, row, text, text_input};
+use cosmic::Element;
+
+use crate::application::DocumentManager;
+use crate::config::AppConfig;
+use crate::ui::{AppMessage, AppModel};
+use crate::fl;
+
+/// Build the zoom bookmarks panel view.
+pub fn view<'a>(
+    model: &'a AppModel,
+    manager: &'a DocumentManager,
+    config: &'a AppConfig,
+) -> Element<'a, AppMessage> {
+    let bookmarks = manager
+        .current_path()
+        .map_or(&[][..], |path| config.zoom_bookmarks_of(path));
+    let multi_page = manager
+        .current_document()
+        .is_some_and(|doc| doc.is_multi_page());
+    let name = model.zoom_bookmark_name_input.trim();
+    let can_add = manager.current_document().is_some()
+        && (bookmarks.len() < AppConfig::MAX_ZOOM_BOOKMARKS
+            || bookmarks.iter().any(|bookmark| bookmark.0 == name));
+
+    let mut content = column::with_capacity(5 + bookmarks.len())
+        .spacing(12)
+        .padding(16)
+        .push(text::title4(fl!("zoom-bookmarks-title")))
+        .push(text::caption(fl!(
+            "zoom-bookmarks-hint",
+            count: AppConfig::MAX_ZOOM_BOOKMARKS
+        )))
+        .push(
+            row::with_capacity(2)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(
+                    text_input(
+                        fl!("zoom-bookmark-name-placeholder"),
+                        model.zoom_bookmark_name_input.as_str(),
+                    )
+                    .on_input(AppMessage::ZoomBookmarkNameInput)
+                    .on_submit(|_| AppMessage::AddZoomBookmark),
+                )
+                .push(
+                    button::suggested(fl!("zoom-bookmark-add"))
+                        .on_press_maybe(can_add.then_some(AppMessage::AddZoomBookmark)),
+                ),
+        );
+
+    if bookmarks.is_empty() {
+        return content.push(text::body(fl!("zoom-bookmarks-none"))).into();
+    }
+    for (index, (name, page, zoom, _)) in bookmarks.iter().enumerate() {
+        let percent = (zoom * 100.0).round() as u32;
+        let details = if multi_page {
+            fl!("zoom-bookmark-details-page", number: index + 1, percent: percent, page: page + 1)
+        } else {
+            fl!("zoom-bookmark-details", number: index + 1, percent: percent)
+        };
+        content = content.push(
+            row::with_capacity(2)
+                .spacing(8)
+                .align_y(Alignment::Center)
+                .push(
+                    button::custom(
+                        column::with_capacity(2)
+                            .spacing(2)
+                            .push(text::body(name.as_str()))
+                            .push(text::caption(details)),
+                    )
+                    .class(cosmic::theme::Button::Text)
+                    .width(Length::Fill)
+                    .on_press(AppMessage::GoToZoomBookmark(index)),
+                )
+                .push(
+                    button::icon(icon::from_name("edit-delete-symbolic"))
+                        .tooltip(fl!("zoom-bookmark-delete"))
+                        .padding(4)
+                        .on_press(AppMessage::DeleteZoomBookmark(index)),
+                ),
+        );
+    }
+    content.into()
+}
@@ -3,8 +3,6 @@
 //
 // Crop UI model (drag state and logic).
 
-use crate::domain::document::operations::CropRegion;
-
 /// Drag handle for crop selection.
 /// 
 /// Identifies which part of the selection is being dragged.
@@ -48,6 +46,9 @@ pub struct CropSelection {
     
     /// What was the region when drag started? (for resize calculation)
     drag_start_region: Option<(f32, f32, f32, f32)>,
+
+    /// Locked aspect ratio (width / height), kept while drawing and resizing
+    pub aspect: Option<f32>,
 }
 
 impl CropSelection {
@@ -55,6 +56,23 @@ impl CropSelection {
         Self::default()
     }
 
+    /// Create a selection with a fixed region (e.g. entered as numbers).
+    pub fn from_region(region: (f32, f32, f32, f32)) -> Self {
+        Self {
+            region: Some(region),
+            ..Self::default()
+        }
+    }
+
+    /// Create a selection locked to an aspect ratio (e.g. a wallpaper frame).
+    pub fn with_aspect(region: (f32, f32, f32, f32), aspect: f32) -> Self {
+        Self {
+            region: Some(region),
+            aspect: Some(aspect),
+            ..Self::default()
+        }
+    }
+
     /// Start a new selection (user clicks on empty area).
     pub fn start_new_selection(&mut self, x: f32, y: f32) {
         self.region = Some((x, y, 0.0, 0.0));
@@ -87,7 +105,16 @@ impl CropSelection {
                     let max_x = start_x.max(x).min(img_width);
                     let max_y = start_y.max(y).min(img_height);
 
-                    self.region = Some((min_x, min_y, max_x - min_x, max_y - min_y));
+                    let region = (min_x, min_y, max_x - min_x, max_y - min_y);
+                    self.region = Some(match self.aspect {
+                        Some(aspect) => {
+                            let (_, _, width, height) = region;
+                            // The longer side of the drag decides the size
+                            let anchor = (x < start_x, y < start_y, height * aspect > width);
+                            fit_aspect(region, aspect, anchor, img_width, img_height)
+                        }
+                        None => region,
+                    });
                 }
             }
             DragHandle::Move => {
@@ -105,9 +132,25 @@ impl CropSelection {
             _ => {
                 // Resizing from edge/corner
                 if let Some((rx, ry, rw, rh)) = self.drag_start_region {
-                    let (new_x, new_y, new_w, new_h) =
-                        self.resize_region(rx, ry, rw, rh, x, y, img_width, img_height);
-                    self.region = Some((new_x, new_y, new_w, new_h));
+                    let region = self.resize_region(rx, ry, rw, rh, x, y, img_width, img_height);
+                    self.region = Some(match self.aspect {
+                        Some(aspect) => {
+                            let handle = self.drag_handle;
+                            let anchor = (
+                                matches!(
+                                    handle,
+                                    DragHandle::TopLeft | DragHandle::BottomLeft | DragHandle::Left
+                                ),
+                                matches!(
+                                    handle,
+                                    DragHandle::TopLeft | DragHandle::TopRight | DragHandle::Top
+                                ),
+                                matches!(handle, DragHandle::Top | DragHandle::Bottom),
+                            );
+                            fit_aspect(region, aspect, anchor, img_width, img_height)
+                        }
+                        None => region,
+                    });
                 }
             }
         }
@@ -192,19 +235,43 @@ impl CropSelection {
             .unwrap_or(false)
     }
 
-    /// Convert to domain CropRegion for actual crop operation.
-    pub fn to_crop_region(&self) -> Option<CropRegion> {
-        self.region.and_then(|(x, y, w, h)| {
-            if w > 1.0 && h > 1.0 {
-                Some(CropRegion {
-                    x: x as u32,
-                    y: y as u32,
-                    width: w as u32,
-                    height: h as u32,
-                })
-            } else {
-                None
-            }
-        })
+    /// Selected canvas rectangle for the actual crop operation, if large
+    /// enough. Kept fractional: at 125 % or 150 % a logical pixel covers
+    /// more than one screen pixel, and rounding would shift the crop.
+    pub fn canvas_rect(&self) -> Option<(f32, f32, f32, f32)> {
+        self.region.filter(|_| self.has_selection())
     }
 }
+
+/// Bring a region to the aspect ratio `aspect` (width / height).
+///
+/// `anchor` tells whether the left and top edges were moved (otherwise the
+/// right and bottom ones were) and whether the height sets the size rather
+/// than the width. The edges that were not moved stay in place, and the
+/// region shrinks to stay inside the image.
+fn fit_aspect(
+    (x, y, width, height): (f32, f32, f32, f32),
+    aspect: f32,
+    (moves_left, moves_top, from_height): (bool, bool, bool),
+    img_width: f32,
+    img_height: f32,
+) -> (f32, f32, f32, f32) {
+    let (right, bottom) = (x + width, y + height);
+    let (mut width, mut height) = if from_height {
+        (height * aspect, height)
+    } else {
+        (width, width / aspect)
+    };
+
+    let room_width = if moves_left { right } else { img_width - x };
+    let room_height = if moves_top { bottom } else { img_height - y };
+    if width > 0.0 && height > 0.0 {
+        let fit = (room_width / width).min(room_height / height).min(1.0);
+        width *= fit;
+        height *= fit;
+    }
+
+    let x = if moves_left { right - width } else { x };
+    let y = if moves_top { bottom - height } else { y };
+    (x, y, width, height)
+}
@@ -10,7 +10,6 @@ use cosmic::{
         advanced::{
             Clipboard, Layout, Shell, Widget,
             layout::{Limits, Node},
-            renderer::{Quad, Renderer as QuadRenderer},
             widget::Tree,
         },
         event::{Event, Status},
@@ -19,6 +18,8 @@ use cosmic::{
 };
 
 use crate::ui::widgets::crop_model::{CropSelection, DragHandle};
+use crate::ui::widgets::overlay::{fill_clipped, rectangle, snapped, visible_area};
+use crate::ui::widgets::pixel_grid::{self, Rect};
 use crate::ui::AppMessage;
 
 const HANDLE_SIZE: f32 = 12.0;
@@ -31,192 +32,156 @@ const BORDER_WIDTH: f32 = 2.0;
 pub struct CropOverlay {
     selection: CropSelection,
     show_grid: bool,
+    /// Physical pixels per logical pixel of the window
+    scale_factor: f32,
     last_click: Option<std::time::Instant>,
 }
 
 impl CropOverlay {
-    pub fn new(selection: &CropSelection, show_grid: bool) -> Self {
+    pub fn new(selection: &CropSelection, show_grid: bool, scale_factor: f32) -> Self {
         Self {
             selection: selection.clone(),
             show_grid,
+            scale_factor,
             last_click: None,
         }
     }
 
-    fn hit_test_handle(&self, point: Point) -> DragHandle {
-        let Some((x, y, w, h)) = self.selection.region else {
+    /// Selection in window coordinates, on the physical pixel grid.
+    ///
+    /// Drawing and hit testing both go by it, so at fractional scale
+    /// factors the handles are grabbed exactly where they show.
+    fn selection_rect(&self, bounds: Rectangle) -> Option<Rect> {
+        self.selection
+            .region
+            .map(|region| snapped(bounds, region, self.scale_factor))
+    }
+
+    /// `position` is in window coordinates.
+    fn hit_test_handle(&self, bounds: Rectangle, position: Point) -> DragHandle {
+        let Some(selection) = self.selection_rect(bounds) else {
             return DragHandle::None;
         };
+        let point = (position.x, position.y);
 
-        let handles = [
-            (Point::new(x, y), DragHandle::TopLeft),
-            (Point::new(x + w, y), DragHandle::TopRight),
-            (Point::new(x, y + h), DragHandle::BottomLeft),
-            (Point::new(x + w, y + h), DragHandle::BottomRight),
-            (Point::new(x + w / 2.0, y), DragHandle::Top),
-            (Point::new(x + w / 2.0, y + h), DragHandle::Bottom),
-            (Point::new(x, y + h / 2.0), DragHandle::Left),
-            (Point::new(x + w, y + h / 2.0), DragHandle::Right),
-        ];
-
-        for (pos, handle) in handles {
-            if point_in_handle(point, pos) {
+        for (center, handle) in handle_centers(selection) {
+            let hit_area = pixel_grid::square(center, HANDLE_HIT_SIZE, self.scale_factor);
+            if pixel_grid::contains(hit_area, point) {
                 return handle;
             }
         }
 
-        if point.x >= x && point.x <= x + w && point.y >= y && point.y <= y + h {
+        if pixel_grid::contains(selection, point) {
             return DragHandle::Move;
         }
 
         DragHandle::None
     }
 
-    fn draw_overlay(&self, renderer: &mut Renderer, bounds: Rectangle) {
-        let Some((x, y, w, h)) = self.selection.region else {
-            draw_quad(renderer, bounds, OVERLAY_COLOR);
+    fn draw_overlay(&self, renderer: &mut Renderer, bounds: Rectangle, clip: Rectangle) {
+        let Some((abs_x, abs_y, w, h)) = self.selection_rect(bounds) else {
+            fill_clipped(renderer, bounds, clip, OVERLAY_COLOR);
             return;
         };
-
-        // Convert relative coords to absolute screen coords
-        let abs_x = bounds.x + x;
-        let abs_y = bounds.y + y;
         let abs_right = abs_x + w;
         let abs_bottom = abs_y + h;
 
         // Top
         if abs_y > bounds.y {
-            draw_quad(
+            fill_clipped(
                 renderer,
                 Rectangle::new(
                     Point::new(bounds.x, bounds.y),
                     Size::new(bounds.width, abs_y - bounds.y),
                 ),
+                clip,
                 OVERLAY_COLOR,
             );
         }
 
         // Bottom
         if abs_bottom < bounds.y + bounds.height {
-            draw_quad(
+            fill_clipped(
                 renderer,
                 Rectangle::new(
                     Point::new(bounds.x, abs_bottom),
                     Size::new(bounds.width, bounds.y + bounds.height - abs_bottom),
                 ),
+                clip,
                 OVERLAY_COLOR,
             );
         }
 
         // Left
         if abs_x > bounds.x {
-            draw_quad(
+            fill_clipped(
                 renderer,
                 Rectangle::new(
                     Point::new(bounds.x, abs_y),
                     Size::new(abs_x - bounds.x, h),
                 ),
+                clip,
                 OVERLAY_COLOR,
             );
         }
 
         // Right
         if abs_right < bounds.x + bounds.width {
-            draw_quad(
+            fill_clipped(
                 renderer,
                 Rectangle::new(
                     Point::new(abs_right, abs_y),
                     Size::new(bounds.x + bounds.width - abs_right, h),
                 ),
+                clip,
                 OVERLAY_COLOR,
             );
         }
     }
 
-    fn draw_border(&self, renderer: &mut Renderer, bounds: Rectangle) {
-        let Some((x, y, w, h)) = self.selection.region else {
+    fn draw_border(&self, renderer: &mut Renderer, bounds: Rectangle, clip: Rectangle) {
+        let Some((abs_x, abs_y, w, h)) = self.selection_rect(bounds) else {
             return;
         };
 
-        // Add bounds offset
-        let abs_x = bounds.x + x;
-        let abs_y = bounds.y + y;
-
-        // Top
-        draw_quad(
-            renderer,
-            Rectangle::new(Point::new(abs_x, abs_y), Size::new(w, BORDER_WIDTH)),
-            BORDER_COLOR,
-        );
-
-        // Bottom
-        draw_quad(
-            renderer,
-            Rectangle::new(
-                Point::new(abs_x, abs_y + h - BORDER_WIDTH),
-                Size::new(w, BORDER_WIDTH),
-            ),
-            BORDER_COLOR,
-        );
-
-        // Left
-        draw_quad(
-            renderer,
-            Rectangle::new(Point::new(abs_x, abs_y), Size::new(BORDER_WIDTH, h)),
-            BORDER_COLOR,
-        );
-
-        // Right
-        draw_quad(
-            renderer,
-            Rectangle::new(
-                Point::new(abs_x + w - BORDER_WIDTH, abs_y),
-                Size::new(BORDER_WIDTH, h),
-            ),
-            BORDER_COLOR,
-        );
+        // Whole physical pixels wide, inside the selection
+        let width = pixel_grid::stroke(BORDER_WIDTH, self.scale_factor);
+        let edges = [
+            // Top
+            (abs_x, abs_y, w, width),
+            // Bottom
+            (abs_x, abs_y + h - width, w, width),
+            // Left
+            (abs_x, abs_y, width, h),
+            // Right
+            (abs_x + w - width, abs_y, width, h),
+        ];
+        for edge in edges {
+            fill_clipped(renderer, rectangle(edge), clip, BORDER_COLOR);
+        }
     }
 
-    fn draw_handles(&self, renderer: &mut Renderer, bounds: Rectangle) {
-        let Some((x, y, w, h)) = self.selection.region else {
+    fn draw_handles(&self, renderer: &mut Renderer, bounds: Rectangle, clip: Rectangle) {
+        let Some(selection) = self.selection_rect(bounds) else {
             return;
         };
 
-        let half = HANDLE_SIZE / 2.0;
-
-        // Add bounds offset
-        let abs_x = bounds.x + x;
-        let abs_y = bounds.y + y;
-
-        let handles = [
-            Point::new(abs_x, abs_y),
-            Point::new(abs_x + w, abs_y),
-            Point::new(abs_x, abs_y + h),
-            Point::new(abs_x + w, abs_y + h),
-            Point::new(abs_x + w / 2.0, abs_y),
-            Point::new(abs_x + w / 2.0, abs_y + h),
-            Point::new(abs_x, abs_y + h / 2.0),
-            Point::new(abs_x + w, abs_y + h / 2.0),
-        ];
-
-        for pos in handles {
-            draw_quad(
+        for (center, _) in handle_centers(selection) {
+            fill_clipped(
                 renderer,
-                Rectangle::new(
-                    Point::new(pos.x - half, pos.y - half),
-                    Size::new(HANDLE_SIZE, HANDLE_SIZE),
-                ),
+                rectangle(pixel_grid::square(center, HANDLE_SIZE, self.scale_factor)),
+                clip,
                 HANDLE_COLOR,
             );
         }
     }
 
-    fn draw_grid(&self, renderer: &mut Renderer, bounds: Rectangle) {
+    fn draw_grid(&self, renderer: &mut Renderer, bounds: Rectangle, clip: Rectangle) {
         if !self.show_grid {
             return;
         }
 
-        let Some((x, y, w, h)) = self.selection.region else {
+        let Some((abs_x, abs_y, w, h)) = self.selection_rect(bounds) else {
             return;
         };
 
@@ -224,30 +189,29 @@ impl CropOverlay {
             return;
         }
 
-        // Add bounds offset
-        let abs_x = bounds.x + x;
-        let abs_y = bounds.y + y;
-
         let grid_color = Color::from_rgba(1.0, 1.0, 1.0, 0.3);
+        let line = pixel_grid::stroke(1.0, self.scale_factor);
         let third_w = w / 3.0;
         let third_h = h / 3.0;
 
         // 2 vertical
         for i in 1..3 {
-            let line_x = abs_x + third_w * i as f32;
-            draw_quad(
+            let line_x = pixel_grid::snap(abs_x + third_w * i as f32, self.scale_factor);
+            fill_clipped(
                 renderer,
-                Rectangle::new(Point::new(line_x, abs_y), Size::new(1.0, h)),
+                Rectangle::new(Point::new(line_x, abs_y), Size::new(line, h)),
+                clip,
                 grid_color,
             );
         }
 
         // 2 horizontal
         for i in 1..3 {
-            let line_y = abs_y + third_h * i as f32;
-            draw_quad(
+            let line_y = pixel_grid::snap(abs_y + third_h * i as f32, self.scale_factor);
+            fill_clipped(
                 renderer,
-                Rectangle::new(Point::new(abs_x, line_y), Size::new(w, 1.0)),
+                Rectangle::new(Point::new(abs_x, line_y), Size::new(w, line)),
+                clip,
                 grid_color,
             );
         }
@@ -271,14 +235,17 @@ impl Widget<AppMessage, cosmic::Theme, Renderer> for CropOverlay {
         _style: &cosmic::iced::advanced::renderer::Style,
         layout: Layout<'_>,
         _cursor: Cursor,
-        _viewport: &Rectangle,
+        viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
+        let Some(clip) = visible_area(bounds, viewport) else {
+            return;
+        };
 
-        self.draw_overlay(renderer, bounds);
-        self.draw_border(renderer, bounds);
-        self.draw_handles(renderer, bounds);
-        self.draw_grid(renderer, bounds);
+        self.draw_overlay(renderer, bounds, clip);
+        self.draw_border(renderer, bounds, clip);
+        self.draw_handles(renderer, bounds, clip);
+        self.draw_grid(renderer, bounds, clip);
     }
 
     fn on_event(
@@ -296,8 +263,8 @@ impl Widget<AppMessage, cosmic::Theme, Renderer> for CropOverlay {
 
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(Button::Left)) => {
-                if let Some(pos) = cursor.position_in(bounds) {
-                    let handle = self.hit_test_handle(pos);
+                if let Some(position) = cursor.position_over(bounds) {
+                    let handle = self.hit_test_handle(bounds, position);
 
                     if handle == DragHandle::Move {
                         use std::time::{Duration, Instant};
@@ -313,8 +280,8 @@ impl Widget<AppMessage, cosmic::Theme, Renderer> for CropOverlay {
                     }
 
                     shell.publish(AppMessage::CropDragStart {
-                        x: pos.x,
-                        y: pos.y,
+                        x: position.x - bounds.x,
+                        y: position.y - bounds.y,
                         handle,
                     });
                     return Status::Captured;
@@ -355,8 +322,8 @@ impl Widget<AppMessage, cosmic::Theme, Renderer> for CropOverlay {
     ) -> mouse::Interaction {
         let bounds = layout.bounds();
 
-        if let Some(pos) = cursor.position_in(bounds) {
-            let handle = self.hit_test_handle(pos);
+        if let Some(position) = cursor.position_over(bounds) {
+            let handle = self.hit_test_handle(bounds, position);
             return match handle {
                 DragHandle::TopLeft | DragHandle::BottomRight => {
                     mouse::Interaction::ResizingDiagonallyDown
@@ -381,24 +348,24 @@ impl<'a> From<CropOverlay> for Element<'a, AppMessage> {
     }
 }
 
-fn point_in_handle(point: Point, handle_center: Point) -> bool {
-    let half = HANDLE_HIT_SIZE / 2.0;
-    point.x >= handle_center.x - half
-        && point.x <= handle_center.x + half
-        && point.y >= handle_center.y - half
-        && point.y <= handle_center.y + half
-}
-
-fn draw_quad(renderer: &mut Renderer, bounds: Rectangle, color: Color) {
-    renderer.fill_quad(
-        Quad {
-            bounds,
-            ..Quad::default()
-        },
-        color,
-    );
+/// Centers of the eight handles of `selection`: corners, then edge middles.
+fn handle_centers((x, y, w, h): Rect) -> [((f32, f32), DragHandle); 8] {
+    [
+        ((x, y), DragHandle::TopLeft),
+        ((x + w, y), DragHandle::TopRight),
+        ((x, y + h), DragHandle::BottomLeft),
+        ((x + w, y + h), DragHandle::BottomRight),
+        ((x + w / 2.0, y), DragHandle::Top),
+        ((x + w / 2.0, y + h), DragHandle::Bottom),
+        ((x, y + h / 2.0), DragHandle::Left),
+        ((x + w, y + h / 2.0), DragHandle::Right),
+    ]
 }
 
-pub fn crop_overlay<'a>(selection: &CropSelection, show_grid: bool) -> Element<'a, AppMessage> {
-    CropOverlay::new(selection, show_grid).into()
+pub fn crop_overlay<'a>(
+    selection: &CropSelection,
+    show_grid: bool,
+    scale_factor: f32,
+) -> Element<'a, AppMessage> {
+    CropOverlay::new(selection, show_grid, scale_factor).into()
 }
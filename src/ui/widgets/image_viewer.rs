@@ -10,10 +10,20 @@ use cosmic::iced::advanced::renderer;
 use cosmic::iced::advanced::widget::tree::{self, Tree};
 use cosmic::iced::advanced::widget::Widget;
 use cosmic::iced::advanced::{Clipboard, Layout, Shell};
+use std::time::{Duration, Instant};
+
 use cosmic::iced::event::{self, Event};
+use cosmic::iced::keyboard;
 use cosmic::iced::mouse;
+use cosmic::iced::touch;
+use cosmic::iced::window;
 use cosmic::iced::widget::image::FilterMethod;
-use cosmic::iced::{ContentFit, Element, Length, Pixels, Point, Radians, Rectangle, Size, Vector};
+use cosmic::iced::{
+    Color, ContentFit, Element, Length, Pixels, Point, Radians, Rectangle, Size, Vector,
+};
+
+use crate::domain::document::operations::pixel_art;
+use crate::ui::widgets::pixel_grid;
 
 /// Tolerance for scale comparisons in widget state synchronization.
 const SCALE_EPSILON: f32 = 0.0001;
@@ -21,9 +31,41 @@ const SCALE_EPSILON: f32 = 0.0001;
 /// Tolerance for offset comparisons in widget state synchronization.
 const OFFSET_EPSILON: f32 = 0.01;
 
+/// Crosshair line width in the magnifier (white core).
+const CROSSHAIR_WIDTH: f32 = 2.0;
+
+/// Width of the dark outline around the crosshair lines.
+const CROSSHAIR_OUTLINE: f32 = 1.0;
+
+/// Width of the swipe divider line.
+const DIVIDER_WIDTH: f32 = 2.0;
+
+/// Distance from the swipe divider at which it can be grabbed.
+const DIVIDER_GRAB: f32 = 8.0;
+
+/// Side of a checkerboard square behind transparent images.
+const CHECKER_SIZE: f32 = 12.0;
+
+/// Colors of the light and dark checkerboard squares.
+const CHECKER_LIGHT: Color = Color::from_rgb(0.8, 0.8, 0.8);
+const CHECKER_DARK: Color = Color::from_rgb(0.6, 0.6, 0.6);
+
+/// Gap between touchpad scroll events after which the fingers count as
+/// lifted and the image glides on.
+const GLIDE_DELAY: Duration = Duration::from_millis(50);
+
+/// Time in which the gliding speed drops to about a third, in seconds.
+const GLIDE_TIME_CONSTANT: f32 = 0.3;
+
+/// Speed below which gliding stops, in pixels per second.
+const MIN_GLIDE_SPEED: f32 = 20.0;
+
 /// Callback type for notifying viewer state changes (scale, `offset_x`, `offset_y`, `canvas_size`, `image_size`).
 type StateChangeCallback<Message> = Box<dyn Fn(f32, f32, f32, Size, Size) -> Message>;
 
+/// Callback type for notifying swipe divider moves (fraction of the width).
+type SwipeCallback<Message> = Box<dyn Fn(f32) -> Message>;
+
 /// A frame that displays an image with the ability to zoom in/out and pan.
 #[allow(missing_debug_implementations)]
 pub struct Viewer<Handle, Message> {
@@ -36,12 +78,38 @@ pub struct Viewer<Handle, Message> {
     handle: Handle,
     filter_method: FilterMethod,
     content_fit: ContentFit,
+    /// Scale the image to the viewer width (overrides `content_fit`)
+    fit_width: bool,
+    /// Snap wheel zoom to integer multiples / simple fractions of 100%
+    integer_zoom: bool,
+    /// Display rotation in clockwise quarter turns (view only)
+    quarter_turns: u8,
+    /// Additional clockwise rotation in degrees (straighten preview)
+    tilt: f32,
+    /// Kept fraction of the image width and height around its center
+    tilt_crop: Option<(f32, f32)>,
     /// Optional external state to override internal state (scale, offset)
     external_state: Option<(f32, Vector)>,
     /// Optional callback to notify state changes
     on_state_change: Option<StateChangeCallback<Message>>,
     /// Disable pan interaction (for crop mode)
     disable_pan: bool,
+    /// Pan so the point under the cursor follows it (magnifier mode)
+    follow_cursor: bool,
+    /// Draw a crosshair at the cursor
+    crosshair: bool,
+    /// Draw a checkerboard under the image, showing its transparency
+    checkerboard: bool,
+    /// Let the image glide on after a touchpad scroll
+    glide: bool,
+    /// Physical pixels per logical pixel, for crisp crosshair and divider
+    scale_factor: f32,
+    /// Second image shown right of the divider (fraction of the width)
+    swipe: Option<(Handle, f32)>,
+    /// Optional callback to notify swipe divider moves
+    on_swipe: Option<SwipeCallback<Message>>,
+    /// Size the image is laid out at, if the handle has more pixels
+    source_size: Option<Size>,
 }
 
 impl<Handle, Message> Viewer<Handle, Message> {
@@ -57,9 +125,22 @@ impl<Handle, Message> Viewer<Handle, Message> {
             scale_step: 0.10,
             filter_method: FilterMethod::default(),
             content_fit: ContentFit::default(),
+            fit_width: false,
+            integer_zoom: false,
+            quarter_turns: 0,
+            tilt: 0.0,
+            tilt_crop: None,
             external_state: None,
             on_state_change: None,
             disable_pan: false,
+            follow_cursor: false,
+            crosshair: false,
+            checkerboard: false,
+            glide: true,
+            scale_factor: 1.0,
+            swipe: None,
+            on_swipe: None,
+            source_size: None,
         }
     }
 
@@ -79,12 +160,67 @@ impl<Handle, Message> Viewer<Handle, Message> {
         self
     }
 
+    /// Lay the image out at `size` instead of the pixel size of the handle,
+    /// e.g. for a render sharper than the image it stands for.
+    pub fn source_size(mut self, size: Option<Size>) -> Self {
+        self.source_size = size;
+        self
+    }
+
     /// Disable pan interaction (useful when overlaying crop tools).
     pub fn disable_pan(mut self, disable: bool) -> Self {
         self.disable_pan = disable;
         self
     }
 
+    /// Pans with the cursor like a screen magnifier: the cursor position in
+    /// the viewer maps to the same relative position in the zoomed image.
+    pub fn follow_cursor(mut self, follow: bool) -> Self {
+        self.follow_cursor = follow;
+        self
+    }
+
+    /// Draws a crosshair at the cursor position.
+    pub fn crosshair(mut self, crosshair: bool) -> Self {
+        self.crosshair = crosshair;
+        self
+    }
+
+    /// Draws a checkerboard under the image, so transparent parts show.
+    pub fn checkerboard(mut self, checkerboard: bool) -> Self {
+        self.checkerboard = checkerboard;
+        self
+    }
+
+    /// Lets the image glide on after a quick touchpad scroll, slowing down.
+    pub fn glide(mut self, glide: bool) -> Self {
+        self.glide = glide;
+        self
+    }
+
+    /// Sets the scale factor of the window, which the crosshair and the
+    /// swipe divider are aligned to.
+    pub fn scale_factor(mut self, scale_factor: f32) -> Self {
+        self.scale_factor = scale_factor;
+        self
+    }
+
+    /// Shows `handle` instead of the image right of `divider` (a fraction of
+    /// the width), zoomed and panned along with it.
+    pub fn swipe(mut self, handle: Handle, divider: f32) -> Self {
+        self.swipe = Some((handle, divider.clamp(0.0, 1.0)));
+        self
+    }
+
+    /// Set a callback to be notified when the swipe divider is dragged.
+    pub fn on_swipe<F>(mut self, f: F) -> Self
+    where
+        F: 'static + Fn(f32) -> Message,
+    {
+        self.on_swipe = Some(Box::new(f));
+        self
+    }
+
     /// Sets the [`FilterMethod`] of the [`Viewer`].
     pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
         self.filter_method = filter_method;
@@ -97,6 +233,38 @@ impl<Handle, Message> Viewer<Handle, Message> {
         self
     }
 
+    /// Scales the image to the width of the [`Viewer`] (e.g. for documents).
+    pub fn fit_width(mut self, fit_width: bool) -> Self {
+        self.fit_width = fit_width;
+        self
+    }
+
+    /// Snaps wheel zoom to 100%, 200%, 300%, ... and 50%, 33%, 25%, ...
+    /// of the image pixels (avoids shimmer on screenshots and pixel art).
+    pub fn integer_zoom(mut self, integer_zoom: bool) -> Self {
+        self.integer_zoom = integer_zoom;
+        self
+    }
+
+    /// Rotates the displayed image by clockwise quarter turns.
+    ///
+    /// Only the drawing is rotated; the image data is left untouched.
+    pub fn rotation(mut self, quarter_turns: u8) -> Self {
+        self.quarter_turns = quarter_turns % 4;
+        self
+    }
+
+    /// Rotates the displayed image by `degrees` clockwise on top of the
+    /// quarter turns, showing only the centered `crop` (fractions of the
+    /// image width and height) if given.
+    ///
+    /// Like the quarter turns, this only affects the drawing.
+    pub fn tilt(mut self, degrees: f32, crop: Option<(f32, f32)>) -> Self {
+        self.tilt = degrees;
+        self.tilt_crop = crop;
+        self
+    }
+
     /// Sets the padding of the [`Viewer`].
     pub fn padding(mut self, padding: impl Into<Pixels>) -> Self {
         self.padding = padding.into().0;
@@ -141,6 +309,288 @@ impl<Handle, Message> Viewer<Handle, Message> {
     }
 }
 
+impl<Handle: Clone, Message> Viewer<Handle, Message> {
+    /// Size the image is laid out at: the source size if set, else the
+    /// pixel size of the handle.
+    fn image_size<Renderer>(&self, renderer: &Renderer) -> Size
+    where
+        Renderer: img_renderer::Renderer<Handle = Handle>,
+    {
+        self.source_size
+            .unwrap_or_else(|| measured_size(renderer, &self.handle))
+    }
+
+    /// Displayed size of an image laid out at `image_size`, and how far it is
+    /// moved from the top left corner of `bounds`.
+    fn placement(&self, image_size: Size, state: &State, bounds: Rectangle) -> (Size, Vector) {
+        let scaled_size = scaled_image_size(
+            image_size,
+            state,
+            bounds.size(),
+            self.content_fit,
+            self.fit_width,
+            self.quarter_turns,
+        );
+
+        // Calculate translation to center the image and apply offset
+        let translation = {
+            // How much space is left after placing the scaled image
+            let diff_w = bounds.width - scaled_size.width;
+            let diff_h = bounds.height - scaled_size.height;
+
+            // Base position: center the image in the viewport
+            // For images smaller than viewport: center them (diff > 0)
+            // For images larger than viewport: they extend beyond bounds (diff < 0)
+            let center_offset = Vector::new(diff_w / 2.0, diff_h / 2.0);
+
+            // Apply pan offset (offset moves the "camera", so subtract it)
+            // Positive offset = looking at right/bottom part = image moves left/up
+            center_offset - state.current_offset
+        };
+        (scaled_size, translation)
+    }
+
+    /// Draws `handle`, laid out at `image_size`, zoomed, panned and turned
+    /// like the image.
+    fn draw_handle<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        handle: &Handle,
+        image_size: Size,
+        state: &State,
+        bounds: Rectangle,
+    ) where
+        Renderer: img_renderer::Renderer<Handle = Handle>,
+    {
+        let (scaled_size, translation) = self.placement(image_size, state, bounds);
+        let image = Rectangle::new(bounds.position() + translation, scaled_size);
+
+        // The image is drawn unrotated and turned around its center, so a
+        // quarter turn swaps the drawn size against the displayed size.
+        let drawn_size = if self.quarter_turns % 2 == 1 {
+            Size::new(scaled_size.height, scaled_size.width)
+        } else {
+            scaled_size
+        };
+        let drawing_bounds = Rectangle::new(
+            bounds.position()
+                + Vector::new(
+                    (scaled_size.width - drawn_size.width) / 2.0,
+                    (scaled_size.height - drawn_size.height) / 2.0,
+                ),
+            drawn_size,
+        );
+        let rotation = Radians(
+            f32::from(self.quarter_turns) * std::f32::consts::FRAC_PI_2 + self.tilt.to_radians(),
+        );
+        let draw = |renderer: &mut Renderer| {
+            renderer.with_translation(translation, |renderer| {
+                renderer.draw_image(
+                    handle.clone(),
+                    self.filter_method,
+                    drawing_bounds,
+                    rotation,
+                    1.0,
+                    [0.0; 4],
+                );
+            });
+        };
+
+        // The kept part is given for the unturned image
+        let crop = self.tilt_crop.map(|(width, height)| {
+            if self.quarter_turns % 2 == 1 {
+                (height, width)
+            } else {
+                (width, height)
+            }
+        });
+        match crop {
+            Some((width, height)) => {
+                let kept = Size::new(scaled_size.width * width, scaled_size.height * height);
+                let clip = Rectangle::new(
+                    image.position()
+                        + Vector::new(
+                            (scaled_size.width - kept.width) / 2.0,
+                            (scaled_size.height - kept.height) / 2.0,
+                        ),
+                    kept,
+                );
+                if let Some(clip) = clip.intersection(&bounds) {
+                    if self.checkerboard {
+                        draw_checkerboard(renderer, image, clip);
+                    }
+                    renderer.with_layer(clip, draw);
+                }
+            }
+            None => {
+                if self.checkerboard {
+                    draw_checkerboard(renderer, image, bounds);
+                }
+                draw(renderer);
+            }
+        }
+    }
+
+    /// Report the scale and offset of `state` to the application.
+    fn publish_state<Renderer>(
+        &self,
+        state: &State,
+        bounds: Rectangle,
+        renderer: &Renderer,
+        shell: &mut Shell<'_, Message>,
+    ) where
+        Renderer: img_renderer::Renderer<Handle = Handle>,
+    {
+        if let Some(ref on_change) = self.on_state_change {
+            shell.publish(on_change(
+                state.scale,
+                state.current_offset.x,
+                state.current_offset.y,
+                bounds.size(),
+                self.image_size(renderer),
+            ));
+        }
+    }
+
+    /// Pan by a touchpad scroll of `delta`, measuring the speed the image
+    /// glides on with once the fingers lift.
+    fn scroll_pan<Renderer>(
+        &self,
+        state: &mut State,
+        delta: Vector,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) where
+        Renderer: img_renderer::Renderer<Handle = Handle>,
+    {
+        let now = Instant::now();
+        // The image follows the fingers
+        let step = Vector::new(-delta.x, -delta.y);
+        let elapsed = state
+            .last_scroll
+            .map(|last| now.duration_since(last))
+            .filter(|elapsed| *elapsed < GLIDE_DELAY * 2 && !elapsed.is_zero());
+        state.scroll_velocity = match elapsed {
+            // Smoothed over the last events, touchpads report unevenly
+            Some(elapsed) if self.glide => {
+                let velocity = step * (1.0 / elapsed.as_secs_f32());
+                (state.scroll_velocity + velocity) * 0.5
+            }
+            _ => Vector::ZERO,
+        };
+        state.last_scroll = Some(now);
+        state.last_frame = Some(now);
+
+        let scaled_size = scaled_image_size(
+            self.image_size(renderer),
+            state,
+            bounds.size(),
+            self.content_fit,
+            self.fit_width,
+            self.quarter_turns,
+        );
+        state.current_offset =
+            clamp_offset(state.current_offset + step, bounds.size(), scaled_size);
+    }
+
+    /// Move the image on after a touchpad scroll, slowing down; returns
+    /// whether it moved and keeps moving.
+    fn glide<Renderer>(
+        &self,
+        state: &mut State,
+        now: Instant,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) -> bool
+    where
+        Renderer: img_renderer::Renderer<Handle = Handle>,
+    {
+        let Some(last_scroll) = state.last_scroll else {
+            return false;
+        };
+        if state.scroll_velocity == Vector::ZERO {
+            return false;
+        }
+        let last_frame = state.last_frame.replace(now).unwrap_or(last_scroll);
+        // The fingers may still rest on the touchpad
+        if now.saturating_duration_since(last_scroll) < GLIDE_DELAY {
+            return true;
+        }
+
+        let elapsed = now
+            .saturating_duration_since(last_frame)
+            .as_secs_f32()
+            .min(0.05);
+        let scaled_size = scaled_image_size(
+            self.image_size(renderer),
+            state,
+            bounds.size(),
+            self.content_fit,
+            self.fit_width,
+            self.quarter_turns,
+        );
+        let previous = state.current_offset;
+        state.current_offset = clamp_offset(
+            previous + state.scroll_velocity * elapsed,
+            bounds.size(),
+            scaled_size,
+        );
+        state.scroll_velocity = state.scroll_velocity * (-elapsed / GLIDE_TIME_CONSTANT).exp();
+
+        // Stopped by friction or at the image edge
+        let speed = state.scroll_velocity.x.hypot(state.scroll_velocity.y);
+        if speed < MIN_GLIDE_SPEED || state.current_offset == previous {
+            state.scroll_velocity = Vector::ZERO;
+        }
+        true
+    }
+
+    /// Zoom and pan for the two fingers of a pinch: the image point that was
+    /// under their midpoint follows it, scaled by how far they spread.
+    fn pinch_zoom<Renderer>(
+        &self,
+        state: &mut State,
+        pinch: Pinch,
+        bounds: Rectangle,
+        renderer: &Renderer,
+    ) where
+        Renderer: img_renderer::Renderer<Handle = Handle>,
+    {
+        let [(_, first), (_, second), ..] = state.fingers[..] else {
+            return;
+        };
+        if pinch.distance <= 0.0 {
+            return;
+        }
+        state.scale = (pinch.scale * first.distance(second) / pinch.distance)
+            .clamp(self.min_scale, self.max_scale);
+        let factor = state.scale / pinch.scale;
+        let start = pinch.midpoint - bounds.center();
+        let now = midpoint(first, second) - bounds.center();
+        let offset = Vector::new(
+            (start.x + state.starting_offset.x) * factor - now.x,
+            (start.y + state.starting_offset.y) * factor - now.y,
+        );
+
+        let scaled_size = scaled_image_size(
+            self.image_size(renderer),
+            state,
+            bounds.size(),
+            self.content_fit,
+            self.fit_width,
+            self.quarter_turns,
+        );
+        state.current_offset = clamp_offset(offset, bounds.size(), scaled_size);
+    }
+
+    /// Check if `position` is close enough to the swipe divider to drag it.
+    fn is_over_divider(&self, bounds: Rectangle, position: Point) -> bool {
+        self.swipe.as_ref().is_some_and(|(_, divider)| {
+            (position.x - (bounds.x + bounds.width * divider)).abs() <= DIVIDER_GRAB
+        })
+    }
+}
+
 impl<Message, Theme, Renderer, Handle> Widget<Message, Theme, Renderer> for Viewer<Handle, Message>
 where
     Renderer: img_renderer::Renderer<Handle = Handle>,
@@ -168,7 +618,7 @@ where
             let state = tree.state.downcast_mut::<State>();
 
             // Only apply external state if user is not currently dragging
-            if !state.is_cursor_grabbed() {
+            if !state.is_cursor_grabbed() && state.pinch.is_none() {
                 // Check if external state differs significantly from current state
                 let scale_changed = (state.scale - ext_scale).abs() > SCALE_EPSILON;
                 let offset_changed = (state.current_offset.x - ext_offset.x).abs() > OFFSET_EPSILON
@@ -196,8 +646,7 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let image_size = renderer.measure_image(&self.handle);
-        let image_size = Size::new(image_size.width as f32, image_size.height as f32);
+        let image_size = self.image_size(renderer);
 
         let raw_size = limits.resolve(self.width, self.height, image_size);
         let full_size = self.content_fit.fit(image_size, raw_size);
@@ -230,20 +679,46 @@ where
         let bounds = layout.bounds();
 
         match event {
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                tree.state.downcast_mut::<State>().modifiers = modifiers;
+                event::Status::Ignored
+            }
             Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
                 let Some(cursor_position) = cursor.position_over(bounds) else {
                     return event::Status::Ignored;
                 };
 
+                let state = tree.state.downcast_mut::<State>();
                 match delta {
+                    // Two-finger touchpad scrolling pans (and zooms with Ctrl)
+                    mouse::ScrollDelta::Pixels { x, y }
+                        if !state.modifiers.control()
+                            && !self.disable_pan
+                            && !self.follow_cursor =>
+                    {
+                        self.scroll_pan(state, Vector::new(x, y), bounds, renderer);
+                        self.publish_state(state, bounds, renderer, shell);
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
                     mouse::ScrollDelta::Lines { y, .. } | mouse::ScrollDelta::Pixels { y, .. } => {
-                        let state = tree.state.downcast_mut::<State>();
+                        state.scroll_velocity = Vector::ZERO;
                         let previous_scale = state.scale;
 
                         if y < 0.0 && previous_scale > self.min_scale
                             || y > 0.0 && previous_scale < self.max_scale
                         {
-                            state.scale = (if y > 0.0 {
+                            state.scale = (if self.integer_zoom {
+                                // Snap relative to the image pixels, not the fitted size
+                                let base = pixel_scale(
+                                    self.image_size(renderer),
+                                    state,
+                                    bounds.size(),
+                                    self.content_fit,
+                                    self.fit_width,
+                                    self.quarter_turns,
+                                );
+                                pixel_art::snap_zoom_step(previous_scale * base, y > 0.0) / base
+                            } else if y > 0.0 {
                                 state.scale * (1.0 + self.scale_step)
                             } else {
                                 state.scale / (1.0 + self.scale_step)
@@ -257,21 +732,17 @@ where
                             let cursor_to_center = cursor_position - bounds.center();
 
                             // Transform offset so the point under cursor stays stationary
-                            // Formula: new_offset = old_offset * scale_factor + cursor_to_center * (scale_factor - 1)
-                            let new_offset = Vector::new(
-                                state.current_offset.x * scale_factor
-                                    + cursor_to_center.x * (scale_factor - 1.0),
-                                state.current_offset.y * scale_factor
-                                    + cursor_to_center.y * (scale_factor - 1.0),
-                            );
+                            let new_offset =
+                                zoom_offset(state.current_offset, cursor_to_center, scale_factor);
 
                             // Clamp offset to valid range
                             let scaled_size = scaled_image_size(
-                                renderer,
-                                &self.handle,
+                                self.image_size(renderer),
                                 state,
                                 bounds.size(),
                                 self.content_fit,
+                                self.fit_width,
+                                self.quarter_turns,
                             );
 
                             state.current_offset =
@@ -279,9 +750,7 @@ where
 
                             // Notify state change
                             if let Some(ref on_change) = self.on_state_change {
-                                let image_size = renderer.measure_image(&self.handle);
-                                let image_size =
-                                    Size::new(image_size.width as f32, image_size.height as f32);
+                                let image_size = self.image_size(renderer);
                                 shell.publish(on_change(
                                     state.scale,
                                     state.current_offset.x,
@@ -297,7 +766,14 @@ where
                 event::Status::Captured
             }
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
-                if self.disable_pan {
+                if let Some(position) = cursor.position_over(bounds)
+                    && self.is_over_divider(bounds, position)
+                {
+                    tree.state.downcast_mut::<State>().divider_grabbed = true;
+                    return event::Status::Captured;
+                }
+
+                if self.disable_pan || self.follow_cursor {
                     return event::Status::Ignored;
                 }
 
@@ -306,12 +782,19 @@ where
                 };
 
                 let state = tree.state.downcast_mut::<State>();
+                state.scroll_velocity = Vector::ZERO;
                 state.cursor_grabbed_at = Some(cursor_position);
                 state.starting_offset = state.current_offset;
 
                 event::Status::Captured
             }
             Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)) => {
+                let state = tree.state.downcast_mut::<State>();
+                if state.divider_grabbed {
+                    state.divider_grabbed = false;
+                    return event::Status::Captured;
+                }
+
                 if self.disable_pan {
                     return event::Status::Ignored;
                 }
@@ -323,9 +806,7 @@ where
 
                     // Notify final state after drag ends
                     if let Some(ref on_change) = self.on_state_change {
-                        let image_size = renderer.measure_image(&self.handle);
-                        let image_size =
-                            Size::new(image_size.width as f32, image_size.height as f32);
+                        let image_size = self.image_size(renderer);
                         shell.publish(on_change(
                             state.scale,
                             state.current_offset.x,
@@ -341,19 +822,53 @@ where
                 }
             }
             Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                if tree.state.downcast_ref::<State>().divider_grabbed {
+                    if let Some(ref on_swipe) = self.on_swipe
+                        && bounds.width > 0.0
+                    {
+                        let divider = ((position.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+                        shell.publish(on_swipe(divider));
+                    }
+                    return event::Status::Captured;
+                }
+
                 if self.disable_pan {
                     return event::Status::Ignored;
                 }
 
                 let state = tree.state.downcast_mut::<State>();
 
-                if let Some(origin) = state.cursor_grabbed_at {
+                if self.follow_cursor && bounds.contains(position) {
                     let scaled_size = scaled_image_size(
-                        renderer,
-                        &self.handle,
+                        self.image_size(renderer),
                         state,
                         bounds.size(),
                         self.content_fit,
+                        self.fit_width,
+                        self.quarter_turns,
+                    );
+                    state.current_offset = follow_offset(position, bounds, scaled_size);
+
+                    if let Some(ref on_change) = self.on_state_change {
+                        let image_size = self.image_size(renderer);
+                        shell.publish(on_change(
+                            state.scale,
+                            state.current_offset.x,
+                            state.current_offset.y,
+                            bounds.size(),
+                            image_size,
+                        ));
+                    }
+
+                    event::Status::Captured
+                } else if let Some(origin) = state.cursor_grabbed_at {
+                    let scaled_size = scaled_image_size(
+                        self.image_size(renderer),
+                        state,
+                        bounds.size(),
+                        self.content_fit,
+                        self.fit_width,
+                        self.quarter_turns,
                     );
 
                     let delta = position - origin;
@@ -368,9 +883,7 @@ where
 
                     // Notify state change during pan
                     if let Some(ref on_change) = self.on_state_change {
-                        let image_size = renderer.measure_image(&self.handle);
-                        let image_size =
-                            Size::new(image_size.width as f32, image_size.height as f32);
+                        let image_size = self.image_size(renderer);
                         shell.publish(on_change(
                             state.scale,
                             state.current_offset.x,
@@ -385,6 +898,83 @@ where
                     event::Status::Ignored
                 }
             }
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if self.disable_pan || self.follow_cursor || !bounds.contains(position) {
+                    return event::Status::Ignored;
+                }
+                let state = tree.state.downcast_mut::<State>();
+                state.scroll_velocity = Vector::ZERO;
+                state.fingers.retain(|(finger, _)| *finger != id);
+                state.fingers.push((id, position));
+                state.start_touch_gesture();
+                event::Status::Captured
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                let state = tree.state.downcast_mut::<State>();
+                let Some(finger) = state.fingers.iter_mut().find(|(finger, _)| *finger == id)
+                else {
+                    return event::Status::Ignored;
+                };
+                finger.1 = position;
+
+                if let Some(pinch) = state.pinch {
+                    self.pinch_zoom(state, pinch, bounds, renderer);
+                } else if let Some(origin) = state.cursor_grabbed_at {
+                    let scaled_size = scaled_image_size(
+                        self.image_size(renderer),
+                        state,
+                        bounds.size(),
+                        self.content_fit,
+                        self.fit_width,
+                        self.quarter_turns,
+                    );
+                    state.current_offset = clamp_offset(
+                        state.starting_offset - (position - origin),
+                        bounds.size(),
+                        scaled_size,
+                    );
+                }
+                self.publish_state(state, bounds, renderer, shell);
+                event::Status::Captured
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                let state = tree.state.downcast_mut::<State>();
+                let touching = state.fingers.len();
+                state.fingers.retain(|(finger, _)| *finger != id);
+                if state.fingers.len() == touching {
+                    return event::Status::Ignored;
+                }
+                // A lifted finger of a pinch leaves the other one panning
+                state.start_touch_gesture();
+                event::Status::Captured
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                let state = tree.state.downcast_mut::<State>();
+                if self.glide(state, now, bounds, renderer) {
+                    self.publish_state(state, bounds, renderer, shell);
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+
+                // Report the canvas and image size whenever they change
+                // (window resized, another handle shown), not only on input
+                let image_size = self.image_size(renderer);
+                let sizes = (bounds.size(), image_size);
+                if state.reported_sizes != Some(sizes) {
+                    state.reported_sizes = Some(sizes);
+                    if let Some(ref on_change) = self.on_state_change {
+                        shell.publish(on_change(
+                            state.scale,
+                            state.current_offset.x,
+                            state.current_offset.y,
+                            bounds.size(),
+                            image_size,
+                        ));
+                    }
+                }
+                event::Status::Ignored
+            }
             _ => event::Status::Ignored,
         }
     }
@@ -401,8 +991,16 @@ where
         let bounds = layout.bounds();
         let is_mouse_over = cursor.is_over(bounds);
 
-        if state.is_cursor_grabbed() {
+        if state.divider_grabbed
+            || cursor
+                .position_over(bounds)
+                .is_some_and(|position| self.is_over_divider(bounds, position))
+        {
+            mouse::Interaction::ResizingHorizontally
+        } else if state.is_cursor_grabbed() {
             mouse::Interaction::Grabbing
+        } else if is_mouse_over && self.follow_cursor {
+            mouse::Interaction::Crosshair
         } else if is_mouse_over {
             mouse::Interaction::Grab
         } else {
@@ -417,62 +1015,181 @@ where
         _theme: &Theme,
         _style: &renderer::Style,
         layout: Layout<'_>,
-        _cursor: mouse::Cursor,
+        cursor: mouse::Cursor,
         _viewport: &Rectangle,
     ) {
         let state = tree.state.downcast_ref::<State>();
         let bounds = layout.bounds();
 
-        let scaled_size = scaled_image_size(
-            renderer,
-            &self.handle,
-            state,
-            bounds.size(),
-            self.content_fit,
-        );
-
-        // Calculate translation to center the image and apply offset
-        let translation = {
-            // How much space is left after placing the scaled image
-            let diff_w = bounds.width - scaled_size.width;
-            let diff_h = bounds.height - scaled_size.height;
-
-            // Base position: center the image in the viewport
-            // For images smaller than viewport: center them (diff > 0)
-            // For images larger than viewport: they extend beyond bounds (diff < 0)
-            let center_offset = Vector::new(diff_w / 2.0, diff_h / 2.0);
-
-            // Apply pan offset (offset moves the "camera", so subtract it)
-            // Positive offset = looking at right/bottom part = image moves left/up
-            center_offset - state.current_offset
-        };
-
-        let drawing_bounds = Rectangle::new(bounds.position(), scaled_size);
-
-        let render = |renderer: &mut Renderer| {
-            renderer.with_translation(translation, |renderer| {
-                renderer.draw_image(
-                    self.handle.clone(),
-                    self.filter_method,
-                    drawing_bounds,
-                    Radians(0.0),
-                    1.0,
-                    [0.0; 4],
+        let image_size = self.image_size(renderer);
+        renderer.with_layer(bounds, |renderer| {
+            self.draw_handle(renderer, &self.handle, image_size, state, bounds);
+        });
+
+        // The second image covers the part right of the divider
+        if let Some((handle, divider)) = &self.swipe {
+            let split = bounds.width * divider;
+            let right = Rectangle::new(
+                bounds.position() + Vector::new(split, 0.0),
+                Size::new(bounds.width - split, bounds.height),
+            );
+            let image_size = measured_size(renderer, handle);
+            renderer.with_layer(right, |renderer| {
+                self.draw_handle(renderer, handle, image_size, state, bounds);
+            });
+            let width = pixel_grid::stroke(DIVIDER_WIDTH, self.scale_factor);
+            let left = pixel_grid::snap(bounds.x + split - width / 2.0, self.scale_factor);
+            renderer.with_layer(bounds, |renderer| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle::new(
+                            Point::new(left, bounds.y),
+                            Size::new(width, bounds.height),
+                        ),
+                        ..renderer::Quad::default()
+                    },
+                    Color::WHITE,
                 );
             });
-        };
+        }
 
-        renderer.with_layer(bounds, render);
+        if self.crosshair
+            && let Some(position) = cursor.position_over(bounds)
+        {
+            renderer.with_layer(bounds, |renderer| {
+                draw_crosshair(renderer, bounds, position, self.scale_factor);
+            });
+        }
+    }
+}
+
+/// Offset that shows the image point at the same relative position as the
+/// cursor in the viewer (left edge shows the left edge, and so on).
+fn follow_offset(cursor: Point, bounds: Rectangle, image_size: Size) -> Vector {
+    let relative = |position: f32, start: f32, length: f32| {
+        if length > 0.0 {
+            ((position - start) / length).clamp(0.0, 1.0) * 2.0 - 1.0
+        } else {
+            0.0
+        }
+    };
+    let max_offset_x = ((image_size.width - bounds.width) / 2.0).max(0.0);
+    let max_offset_y = ((image_size.height - bounds.height) / 2.0).max(0.0);
+    Vector::new(
+        relative(cursor.x, bounds.x, bounds.width) * max_offset_x,
+        relative(cursor.y, bounds.y, bounds.height) * max_offset_y,
+    )
+}
+
+/// Checkerboard filling the part of `image` inside `clip`; the squares
+/// start at the image corner, so they move along when panning.
+fn draw_checkerboard<Renderer>(renderer: &mut Renderer, image: Rectangle, clip: Rectangle)
+where
+    Renderer: renderer::Renderer,
+{
+    let Some(visible) = image.intersection(&clip) else {
+        return;
+    };
+    let quad = |bounds| renderer::Quad {
+        bounds,
+        ..renderer::Quad::default()
+    };
+    renderer.fill_quad(quad(visible), CHECKER_LIGHT);
+
+    // Dark squares of the visible rows and columns only
+    let first_column = ((visible.x - image.x) / CHECKER_SIZE).floor() as i64;
+    let first_row = ((visible.y - image.y) / CHECKER_SIZE).floor() as i64;
+    let last_column = ((visible.x + visible.width - image.x) / CHECKER_SIZE).ceil() as i64;
+    let last_row = ((visible.y + visible.height - image.y) / CHECKER_SIZE).ceil() as i64;
+    for row in first_row..last_row {
+        for column in first_column..last_column {
+            if (row + column) % 2 == 0 {
+                continue;
+            }
+            let square = Rectangle::new(
+                Point::new(
+                    image.x + column as f32 * CHECKER_SIZE,
+                    image.y + row as f32 * CHECKER_SIZE,
+                ),
+                Size::new(CHECKER_SIZE, CHECKER_SIZE),
+            );
+            if let Some(square) = square.intersection(&visible) {
+                renderer.fill_quad(quad(square), CHECKER_DARK);
+            }
+        }
     }
 }
 
+/// Full-width and full-height lines through the cursor, white with a dark
+/// outline so they stay visible on any image; on whole physical pixels.
+fn draw_crosshair<Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    position: Point,
+    scale_factor: f32,
+) where
+    Renderer: renderer::Renderer,
+{
+    let line = |renderer: &mut Renderer, width: f32, color: Color| {
+        let width = pixel_grid::stroke(width, scale_factor);
+        let half = width / 2.0;
+        let horizontal = Rectangle::new(
+            Point::new(bounds.x, pixel_grid::snap(position.y - half, scale_factor)),
+            Size::new(bounds.width, width),
+        );
+        let vertical = Rectangle::new(
+            Point::new(pixel_grid::snap(position.x - half, scale_factor), bounds.y),
+            Size::new(width, bounds.height),
+        );
+        for rect in [horizontal, vertical] {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: rect,
+                    ..renderer::Quad::default()
+                },
+                color,
+            );
+        }
+    };
+    let outline = Color::from_rgba(0.0, 0.0, 0.0, 0.7);
+    line(renderer, CROSSHAIR_WIDTH + CROSSHAIR_OUTLINE * 2.0, outline);
+    line(renderer, CROSSHAIR_WIDTH, Color::WHITE);
+}
+
 /// The local state of a [`Viewer`].
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct State {
     scale: f32,
     starting_offset: Vector,
     current_offset: Vector,
     cursor_grabbed_at: Option<Point>,
+    divider_grabbed: bool,
+    /// Canvas and image size last passed to the state change callback
+    reported_sizes: Option<(Size, Size)>,
+    /// Held modifier keys (Ctrl turns touchpad scrolling into zoom)
+    modifiers: keyboard::Modifiers,
+    /// Speed the image glides on with after a touchpad scroll, in pixels
+    /// per second (zero when still)
+    scroll_velocity: Vector,
+    /// Time of the last touchpad scroll event
+    last_scroll: Option<Instant>,
+    /// Time of the last gliding step
+    last_frame: Option<Instant>,
+    /// Fingers on the touch screen and where they are, in touch order
+    fingers: Vec<(touch::Finger, Point)>,
+    /// Two-finger pinch in progress
+    pinch: Option<Pinch>,
+}
+
+/// Start of a two-finger pinch.
+#[derive(Debug, Clone, Copy)]
+struct Pinch {
+    /// Distance between the fingers
+    distance: f32,
+    /// Point between the fingers
+    midpoint: Point,
+    /// Zoom when the pinch started
+    scale: f32,
 }
 
 impl Default for State {
@@ -482,6 +1199,14 @@ impl Default for State {
             starting_offset: Vector::default(),
             current_offset: Vector::default(),
             cursor_grabbed_at: None,
+            divider_grabbed: false,
+            reported_sizes: None,
+            modifiers: keyboard::Modifiers::default(),
+            scroll_velocity: Vector::ZERO,
+            last_scroll: None,
+            last_frame: None,
+            fingers: Vec::new(),
+            pinch: None,
         }
     }
 }
@@ -496,6 +1221,39 @@ impl State {
     pub fn is_cursor_grabbed(&self) -> bool {
         self.cursor_grabbed_at.is_some()
     }
+
+    /// Start panning with one finger or pinching with the first two, from
+    /// where the fingers are now.
+    fn start_touch_gesture(&mut self) {
+        self.starting_offset = self.current_offset;
+        self.cursor_grabbed_at = None;
+        self.pinch = None;
+        match self.fingers[..] {
+            [] => {}
+            [(_, position)] => self.cursor_grabbed_at = Some(position),
+            [(_, first), (_, second), ..] => {
+                self.pinch = Some(Pinch {
+                    distance: first.distance(second),
+                    midpoint: midpoint(first, second),
+                    scale: self.scale,
+                });
+            }
+        }
+    }
+}
+
+/// Offset that keeps the image point at `point` (relative to the viewer
+/// center) in place when zooming by `factor`.
+fn zoom_offset(offset: Vector, point: Vector, factor: f32) -> Vector {
+    Vector::new(
+        offset.x * factor + point.x * (factor - 1.0),
+        offset.y * factor + point.y * (factor - 1.0),
+    )
+}
+
+/// Point halfway between `first` and `second`.
+fn midpoint(first: Point, second: Point) -> Point {
+    Point::new((first.x + second.x) / 2.0, (first.y + second.y) / 2.0)
 }
 
 /// Clamps the offset to keep the image within reasonable bounds.
@@ -529,25 +1287,70 @@ where
     }
 }
 
+/// Pixel size of `handle`.
+fn measured_size<Renderer>(renderer: &Renderer, handle: &Renderer::Handle) -> Size
+where
+    Renderer: img_renderer::Renderer,
+{
+    let Size { width, height } = renderer.measure_image(handle);
+    Size::new(width as f32, height as f32)
+}
+
+/// Displayed pixels per image pixel at `state.scale == 1.0` (after content fit).
+fn pixel_scale(
+    image_size: Size,
+    state: &State,
+    bounds: Size,
+    content_fit: ContentFit,
+    fit_width: bool,
+    quarter_turns: u8,
+) -> f32 {
+    let image_width = if quarter_turns % 2 == 1 {
+        image_size.height
+    } else {
+        image_size.width
+    };
+    let fitted = scaled_image_size(
+        image_size,
+        state,
+        bounds,
+        content_fit,
+        fit_width,
+        quarter_turns,
+    );
+    let fitted_width = fitted.width / state.scale;
+    if image_width > 0.0 && fitted_width > 0.0 {
+        fitted_width / image_width
+    } else {
+        1.0
+    }
+}
+
 /// Returns the scaled size of the image given current state.
 /// Calculate the scaled image size after applying content fit and zoom.
 ///
 /// This is the canonical implementation used by the viewer widget.
 /// A simplified version exists in `document::utils::scaled_image_size`.
-pub fn scaled_image_size<Renderer>(
-    renderer: &Renderer,
-    handle: &<Renderer as img_renderer::Renderer>::Handle,
+pub fn scaled_image_size(
+    image_size: Size,
     state: &State,
     bounds: Size,
     content_fit: ContentFit,
-) -> Size
-where
-    Renderer: img_renderer::Renderer,
-{
-    let Size { width, height } = renderer.measure_image(handle);
-    let image_size = Size::new(width as f32, height as f32);
+    fit_width: bool,
+    quarter_turns: u8,
+) -> Size {
+    // Displayed extent: width and height swap on a quarter turn
+    let image_size = if quarter_turns % 2 == 1 {
+        Size::new(image_size.height, image_size.width)
+    } else {
+        image_size
+    };
 
     let adjusted_fit = match content_fit {
+        _ if fit_width && image_size.width > 0.0 => {
+            let factor = bounds.width / image_size.width;
+            Size::new(bounds.width, image_size.height * factor)
+        }
         ContentFit::None => image_size,
         _ => content_fit.fit(image_size, bounds),
     };
@@ -0,0 +1,140 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/widgets/line_overlay.rs
+//
+// Line drawn over the canvas to straighten the image by.
+
+use cosmic::{
+    Element, Renderer,
+    iced::{
+        Color, Length, Rectangle, Size,
+        advanced::{
+            Clipboard, Layout, Shell, Widget,
+            layout::{Limits, Node},
+            widget::Tree,
+        },
+        event::{Event, Status},
+        mouse::{self, Button, Cursor},
+    },
+};
+
+use crate::ui::widgets::overlay::{fill_clipped, rectangle, visible_area};
+use crate::ui::widgets::pixel_grid;
+use crate::ui::AppMessage;
+
+const LINE_COLOR: Color = Color::from_rgba(1.0, 0.85, 0.0, 0.9);
+const DOT_SIZE: f32 = 2.0;
+/// Distance between the dots the line is drawn with.
+const DOT_SPACING: f32 = 3.0;
+
+pub struct LineOverlay {
+    /// Start and end of the line in canvas coordinates, while drawn.
+    line: Option<((f32, f32), (f32, f32))>,
+    /// Physical pixels per logical pixel of the window
+    scale_factor: f32,
+}
+
+impl Widget<AppMessage, cosmic::Theme, Renderer> for LineOverlay {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.max())
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &cosmic::Theme,
+        _style: &cosmic::iced::advanced::renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let (Some(clip), Some(((x0, y0), (x1, y1)))) = (visible_area(bounds, viewport), self.line)
+        else {
+            return;
+        };
+
+        // Quads are upright, so the line is made of small dots, each on
+        // whole physical pixels so they all look the same
+        let length = (x1 - x0).hypot(y1 - y0);
+        let steps = (length / DOT_SPACING).ceil().max(1.0) as u32;
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let center = (bounds.x + x0 + (x1 - x0) * t, bounds.y + y0 + (y1 - y0) * t);
+            let dot = pixel_grid::square(center, DOT_SIZE, self.scale_factor);
+            fill_clipped(renderer, rectangle(dot), clip, LINE_COLOR);
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, AppMessage>,
+        _viewport: &Rectangle,
+    ) -> Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(Button::Left)) => {
+                if let Some(pos) = cursor.position_in(bounds) {
+                    shell.publish(AppMessage::StraightenLineStart { x: pos.x, y: pos.y });
+                    return Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if self.line.is_some()
+                    && let Some(pos) = cursor.position_in(bounds)
+                {
+                    shell.publish(AppMessage::StraightenLineMove { x: pos.x, y: pos.y });
+                    return Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(Button::Left)) => {
+                if self.line.is_some() {
+                    shell.publish(AppMessage::StraightenLineEnd);
+                    return Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
+
+impl<'a> From<LineOverlay> for Element<'a, AppMessage> {
+    fn from(widget: LineOverlay) -> Self {
+        Element::new(widget)
+    }
+}
+
+pub fn line_overlay<'a>(
+    line: Option<((f32, f32), (f32, f32))>,
+    scale_factor: f32,
+) -> Element<'a, AppMessage> {
+    LineOverlay { line, scale_factor }.into()
+}
@@ -6,8 +6,16 @@
 pub mod crop_model;
 pub mod crop_overlay;
 pub mod image_viewer;
+pub mod line_overlay;
+pub mod overlay;
+pub mod pixel_grid;
+pub mod rotate_overlay;
+pub mod text_overlay;
 
 // Re-exports for convenience
 pub use crop_model::{CropSelection, DragHandle};
 pub use crop_overlay::crop_overlay;
 pub use image_viewer::Viewer;
+pub use line_overlay::line_overlay;
+pub use rotate_overlay::rotate_overlay;
+pub use text_overlay::{hit_overlay, text_overlay};
@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/widgets/overlay.rs
+//
+// Drawing helpers shared by the overlays over the canvas.
+
+use cosmic::{
+    Renderer,
+    iced::{
+        Color, Point, Rectangle, Size,
+        advanced::renderer::{Quad, Renderer as QuadRenderer},
+    },
+};
+
+use crate::ui::widgets::pixel_grid::{self, Rect};
+
+/// Part of the overlay `bounds` that is on screen, None if it is scrolled out.
+///
+/// Quads are clipped to it before they are submitted: at high zoom a
+/// selection can be thousands of pixels larger than the window, and the
+/// renderer would otherwise get the full geometry every frame.
+pub fn visible_area(bounds: Rectangle, viewport: &Rectangle) -> Option<Rectangle> {
+    bounds.intersection(viewport)
+}
+
+/// Fill the part of `rect` inside `clip`; nothing is drawn if it is outside.
+pub fn fill_clipped(renderer: &mut Renderer, rect: Rectangle, clip: Rectangle, color: Color) {
+    if let Some(visible) = rect.intersection(&clip) {
+        renderer.fill_quad(
+            Quad {
+                bounds: visible,
+                ..Quad::default()
+            },
+            color,
+        );
+    }
+}
+
+/// Window rectangle of `rect` (relative to the overlay at `bounds`) with
+/// its edges on the physical pixels of `scale_factor`.
+///
+/// Snapped in window coordinates, since the overlay itself may start
+/// between two physical pixels.
+pub fn snapped(bounds: Rectangle, (x, y, width, height): Rect, scale_factor: f32) -> Rect {
+    pixel_grid::snap_rect((bounds.x + x, bounds.y + y, width, height), scale_factor)
+}
+
+/// Quad bounds of a window rectangle.
+pub fn rectangle((x, y, width, height): Rect) -> Rectangle {
+    Rectangle::new(Point::new(x, y), Size::new(width, height))
+}
@@ -0,0 +1,197 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/widgets/pixel_grid.rs
+//
+// Physical pixel grid of scaled displays: overlay geometry in logical pixels
+// moved onto whole device pixels, so lines stay crisp at 125 % or 150 %.
+
+/// Rectangle in logical pixels: x, y, width and height.
+pub type Rect = (f32, f32, f32, f32);
+
+/// Usable scale factor; zero, negative or NaN factors count as 1.
+fn factor(scale_factor: f32) -> f32 {
+    if scale_factor.is_finite() && scale_factor > 0.0 {
+        scale_factor
+    } else {
+        1.0
+    }
+}
+
+/// Logical position of a physical one (as the compositor reports the
+/// pointer).
+#[must_use]
+pub fn to_logical((x, y): (f32, f32), scale_factor: f32) -> (f32, f32) {
+    let scale = factor(scale_factor);
+    (x / scale, y / scale)
+}
+
+/// Physical position of a logical one.
+#[must_use]
+pub fn to_physical((x, y): (f32, f32), scale_factor: f32) -> (f32, f32) {
+    let scale = factor(scale_factor);
+    (x * scale, y * scale)
+}
+
+/// `value` moved onto the nearest edge between two physical pixels.
+#[must_use]
+pub fn snap(value: f32, scale_factor: f32) -> f32 {
+    let scale = factor(scale_factor);
+    (value * scale).round() / scale
+}
+
+/// `rect` with each edge moved onto the nearest physical pixel edge.
+///
+/// The edges are snapped rather than the size, so rectangles that touch
+/// still touch afterwards, without a gap or an overlapping pixel row.
+#[must_use]
+pub fn snap_rect((x, y, width, height): Rect, scale_factor: f32) -> Rect {
+    let (left, top) = (snap(x, scale_factor), snap(y, scale_factor));
+    (
+        left,
+        top,
+        snap(x + width, scale_factor) - left,
+        snap(y + height, scale_factor) - top,
+    )
+}
+
+/// Line width covering a whole number of physical pixels, at least one.
+///
+/// A 1 px line at 150 % would otherwise be smeared over two pixel rows.
+#[must_use]
+pub fn stroke(width: f32, scale_factor: f32) -> f32 {
+    let scale = factor(scale_factor);
+    (width * scale).round().max(1.0) / scale
+}
+
+/// Square of side `size` around `center`, on the physical pixel grid.
+///
+/// Every square of a size covers the same number of physical pixels,
+/// wherever its center falls; drawing and hit testing a handle with the
+/// squares of the same center keeps them in line.
+#[must_use]
+pub fn square((x, y): (f32, f32), size: f32, scale_factor: f32) -> Rect {
+    let side = stroke(size, scale_factor);
+    (
+        snap(x - side / 2.0, scale_factor),
+        snap(y - side / 2.0, scale_factor),
+        side,
+        side,
+    )
+}
+
+/// Whether `point` is in `rect`; a point on the right or bottom edge
+/// belongs to the neighboring rectangle.
+#[must_use]
+pub fn contains((x, y, width, height): Rect, (px, py): (f32, f32)) -> bool {
+    px >= x && px < x + width && py >= y && py < y + height
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Scale factors offered by desktops, fractional ones included.
+    const SCALE_FACTORS: [f32; 7] = [1.0, 1.25, 1.5, 1.75, 2.0, 2.25, 3.0];
+
+    /// Rounding slack of f32 when converting between the two grids.
+    const EPSILON: f32 = 1e-3;
+
+    /// Whether a logical value is on a physical pixel edge.
+    fn on_grid(value: f32, scale: f32) -> bool {
+        let physical = value * scale;
+        (physical - physical.round()).abs() < EPSILON
+    }
+
+    fn rect_on_grid((x, y, width, height): Rect, scale: f32) -> bool {
+        [x, y, x + width, y + height]
+            .into_iter()
+            .all(|edge| on_grid(edge, scale))
+    }
+
+    #[test]
+    fn test_snap_lands_on_physical_pixels() {
+        for scale in SCALE_FACTORS {
+            for step in 0..400 {
+                let value = step as f32 * 0.137 - 20.0;
+                let snapped = snap(value, scale);
+                assert!(on_grid(snapped, scale), "{value} at {scale}: {snapped}");
+                // Never further than half a physical pixel, and stable
+                assert!((snapped - value).abs() <= 0.5 / scale + EPSILON);
+                assert!((snap(snapped, scale) - snapped).abs() < EPSILON);
+            }
+        }
+    }
+
+    #[test]
+    fn test_snapped_rects_keep_touching() {
+        for scale in SCALE_FACTORS {
+            // Crop selection with the dimmed area around it, at odd positions
+            let selection = (100.3, 40.6, 211.45, 97.8);
+            let (x, y, width, height) = snap_rect(selection, scale);
+            assert!(rect_on_grid((x, y, width, height), scale));
+
+            let left = snap_rect((0.0, 40.6, 100.3, 97.8), scale);
+            let right = snap_rect((311.75, 40.6, 188.25, 97.8), scale);
+            assert!((left.0 + left.2 - x).abs() < EPSILON, "gap left at {scale}");
+            assert!(
+                (x + width - right.0).abs() < EPSILON,
+                "gap right at {scale}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_strokes_cover_whole_pixels() {
+        for scale in SCALE_FACTORS {
+            for width in [0.5, 1.0, 2.0, 3.0, 12.0] {
+                let drawn = stroke(width, scale) * scale;
+                assert!((drawn - drawn.round()).abs() < EPSILON);
+                assert!(drawn >= 1.0 - EPSILON, "{width} at {scale} vanishes");
+            }
+        }
+        // A 1 px border is one device pixel at 125 %, not a blurred 1.25
+        assert!((stroke(1.0, 1.25) * 1.25 - 1.0).abs() < EPSILON);
+        assert!((stroke(2.0, 1.5) * 1.5 - 3.0).abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_handles_are_hit_where_drawn() {
+        for scale in SCALE_FACTORS {
+            for center in [(100.0, 50.0), (100.4, 50.6), (33.3, 66.7)] {
+                let handle = square(center, 12.0, scale);
+                assert!(rect_on_grid(handle, scale));
+                assert!((handle.2 - stroke(12.0, scale)).abs() < EPSILON);
+
+                // Every physical pixel of the handle, and none around it, hits
+                // it once the pointer comes back in logical pixels
+                let (left, top) = to_physical((handle.0, handle.1), scale);
+                let side = (handle.2 * scale).round() as i32;
+                let (left, top) = (left.round() as i32, top.round() as i32);
+                for py in top - 1..=top + side {
+                    for px in left - 1..=left + side {
+                        let pointer = (px as f32 + 0.5, py as f32 + 0.5);
+                        let inside =
+                            (left..left + side).contains(&px) && (top..top + side).contains(&py);
+                        assert_eq!(
+                            contains(handle, to_logical(pointer, scale)),
+                            inside,
+                            "pixel {px},{py} of the handle at {center:?}, {scale}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip_and_bogus_factors() {
+        for scale in SCALE_FACTORS {
+            let (x, y) = to_logical(to_physical((123.4, 56.7), scale), scale);
+            assert!((x - 123.4).abs() < EPSILON && (y - 56.7).abs() < EPSILON);
+        }
+        // Before the compositor tells the scale, or if it reports nonsense
+        for scale in [0.0, -1.5, f32::NAN] {
+            assert_eq!(snap(10.4, scale), 10.0);
+            assert_eq!(stroke(1.0, scale), 1.0);
+        }
+    }
+}
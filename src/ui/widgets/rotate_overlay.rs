@@ -0,0 +1,187 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/widgets/rotate_overlay.rs
+//
+// Two-finger rotate gesture over the canvas for the fine rotation.
+
+use cosmic::{
+    Element, Renderer,
+    iced::{
+        Color, Length, Point, Rectangle, Size,
+        advanced::{
+            Clipboard, Layout, Shell, Widget,
+            layout::{Limits, Node},
+            widget::tree::{self, Tree},
+        },
+        event::{Event, Status},
+        mouse::Cursor,
+        touch::{self, Finger},
+    },
+};
+
+use crate::ui::widgets::overlay::{fill_clipped, visible_area};
+use crate::ui::widgets::pixel_grid;
+use crate::ui::AppMessage;
+
+const GUIDE_COLOR: Color = Color::from_rgba(1.0, 1.0, 1.0, 0.5);
+const SNAP_COLOR: Color = Color::from_rgba(1.0, 0.85, 0.0, 0.9);
+const GUIDE_WIDTH: f32 = 1.0;
+/// Guides thicken when the gesture snaps onto a quarter turn.
+const SNAP_WIDTH: f32 = 3.0;
+
+/// Fingers on the canvas and the angle between the first two.
+#[derive(Debug, Default)]
+struct State {
+    fingers: Vec<(Finger, Point)>,
+    /// Angle of the line between the fingers at the last move, in degrees.
+    last_angle: Option<f32>,
+    /// Clockwise degrees turned since the second finger landed.
+    turned: f32,
+}
+
+impl State {
+    /// Angle of the line from the first to the second finger (y down, so
+    /// it grows clockwise).
+    fn angle(&self) -> Option<f32> {
+        let [(_, a), (_, b), ..] = self.fingers.as_slice() else {
+            return None;
+        };
+        Some((b.y - a.y).atan2(b.x - a.x).to_degrees())
+    }
+}
+
+pub struct RotateOverlay {
+    /// Whether a gesture is under way, and if it snapped onto a quarter turn.
+    gesture: Option<bool>,
+    /// Physical pixels per logical pixel of the window
+    scale_factor: f32,
+}
+
+impl Widget<AppMessage, cosmic::Theme, Renderer> for RotateOverlay {
+    fn tag(&self) -> tree::Tag {
+        tree::Tag::of::<State>()
+    }
+
+    fn state(&self) -> tree::State {
+        tree::State::new(State::default())
+    }
+
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.max())
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &cosmic::Theme,
+        _style: &cosmic::iced::advanced::renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let (Some(clip), Some(snapped)) = (visible_area(bounds, viewport), self.gesture) else {
+            return;
+        };
+
+        // Level and plumb guides through the center to turn the image against
+        let (width, color) = if snapped {
+            (SNAP_WIDTH, SNAP_COLOR)
+        } else {
+            (GUIDE_WIDTH, GUIDE_COLOR)
+        };
+        let width = pixel_grid::stroke(width, self.scale_factor);
+        let center = bounds.center();
+        let level = Rectangle::new(
+            Point::new(
+                bounds.x,
+                pixel_grid::snap(center.y - width / 2.0, self.scale_factor),
+            ),
+            Size::new(bounds.width, width),
+        );
+        let plumb = Rectangle::new(
+            Point::new(
+                pixel_grid::snap(center.x - width / 2.0, self.scale_factor),
+                bounds.y,
+            ),
+            Size::new(width, bounds.height),
+        );
+        fill_clipped(renderer, level, clip, color);
+        fill_clipped(renderer, plumb, clip, color);
+    }
+
+    fn on_event(
+        &mut self,
+        tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, AppMessage>,
+        _viewport: &Rectangle,
+    ) -> Status {
+        let state = tree.state.downcast_mut::<State>();
+
+        // Mouse events pass through to the viewer, so panning still works
+        match event {
+            Event::Touch(touch::Event::FingerPressed { id, position }) => {
+                if !layout.bounds().contains(position) {
+                    return Status::Ignored;
+                }
+                state.fingers.push((id, position));
+                if state.fingers.len() == 2 {
+                    state.last_angle = state.angle();
+                    state.turned = 0.0;
+                    shell.publish(AppMessage::RotateGesture(0.0));
+                    return Status::Captured;
+                }
+            }
+            Event::Touch(touch::Event::FingerMoved { id, position }) => {
+                if let Some(finger) = state.fingers.iter_mut().find(|(finger, _)| *finger == id) {
+                    finger.1 = position;
+                }
+                if let (Some(last), Some(angle)) = (state.last_angle, state.angle()) {
+                    // Step across the ±180° seam without a jump
+                    let step = (angle - last + 180.0).rem_euclid(360.0) - 180.0;
+                    state.turned += step;
+                    state.last_angle = Some(angle);
+                    shell.publish(AppMessage::RotateGesture(state.turned));
+                    return Status::Captured;
+                }
+            }
+            Event::Touch(
+                touch::Event::FingerLifted { id, .. } | touch::Event::FingerLost { id, .. },
+            ) => {
+                state.fingers.retain(|(finger, _)| *finger != id);
+                if state.fingers.len() < 2 && state.last_angle.take().is_some() {
+                    shell.publish(AppMessage::RotateGestureEnd);
+                    return Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        Status::Ignored
+    }
+}
+
+impl<'a> From<RotateOverlay> for Element<'a, AppMessage> {
+    fn from(widget: RotateOverlay) -> Self {
+        Element::new(widget)
+    }
+}
+
+/// Overlay turning the fine rotation with two fingers; `gesture` is
+/// whether one is under way and snapped, for the guides drawn meanwhile.
+pub fn rotate_overlay<'a>(gesture: Option<bool>, scale_factor: f32) -> Element<'a, AppMessage> {
+    RotateOverlay {
+        gesture,
+        scale_factor,
+    }
+    .into()
+}
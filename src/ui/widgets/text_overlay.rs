@@ -0,0 +1,244 @@
+// SPDX-License-Identifier: GPL-3.0-or-later
+// src/ui/widgets/text_overlay.rs
+//
+// Text overlays for PDF pages: the selection and the hits of the find bar.
+
+use cosmic::{
+    Element, Renderer,
+    iced::{
+        Color, Length, Point, Rectangle, Size,
+        advanced::{
+            Clipboard, Layout, Shell, Widget,
+            layout::{Limits, Node},
+            widget::Tree,
+        },
+        event::{Event, Status},
+        mouse::{self, Button, Cursor},
+    },
+};
+
+use crate::ui::widgets::crop_model::CropSelection;
+use crate::ui::widgets::overlay::{fill_clipped, rectangle, snapped, visible_area};
+use crate::ui::widgets::pixel_grid;
+use crate::ui::AppMessage;
+
+const HIGHLIGHT_COLOR: Color = Color::from_rgba(0.2, 0.5, 1.0, 0.35);
+const BORDER_COLOR: Color = Color::from_rgba(0.2, 0.5, 1.0, 0.8);
+const BORDER_WIDTH: f32 = 1.0;
+const HIT_COLOR: Color = Color::from_rgba(1.0, 0.85, 0.0, 0.4);
+const CURRENT_HIT_COLOR: Color = Color::from_rgba(1.0, 0.5, 0.0, 0.55);
+
+pub struct TextOverlay<'a> {
+    selection: &'a CropSelection,
+    highlights: &'a [(f32, f32, f32, f32)],
+    /// Physical pixels per logical pixel of the window
+    scale_factor: f32,
+}
+
+impl<'a> TextOverlay<'a> {
+    pub fn new(
+        selection: &'a CropSelection,
+        highlights: &'a [(f32, f32, f32, f32)],
+        scale_factor: f32,
+    ) -> Self {
+        Self {
+            selection,
+            highlights,
+            scale_factor,
+        }
+    }
+
+    fn draw_highlights(&self, renderer: &mut Renderer, bounds: Rectangle, clip: Rectangle) {
+        for &highlight in self.highlights {
+            let rect = snapped(bounds, highlight, self.scale_factor);
+            fill_clipped(renderer, rectangle(rect), clip, HIGHLIGHT_COLOR);
+        }
+    }
+
+    /// Outline of the dragged rectangle, only while dragging.
+    fn draw_border(&self, renderer: &mut Renderer, bounds: Rectangle, clip: Rectangle) {
+        let Some(region) = self.selection.region else {
+            return;
+        };
+        if !self.selection.is_dragging {
+            return;
+        }
+
+        let (abs_x, abs_y, w, h) = snapped(bounds, region, self.scale_factor);
+        let width = pixel_grid::stroke(BORDER_WIDTH, self.scale_factor);
+        let edges = [
+            (Point::new(abs_x, abs_y), Size::new(w, width)),
+            (Point::new(abs_x, abs_y + h - width), Size::new(w, width)),
+            (Point::new(abs_x, abs_y), Size::new(width, h)),
+            (Point::new(abs_x + w - width, abs_y), Size::new(width, h)),
+        ];
+        for (position, size) in edges {
+            fill_clipped(renderer, Rectangle::new(position, size), clip, BORDER_COLOR);
+        }
+    }
+}
+
+impl Widget<AppMessage, cosmic::Theme, Renderer> for TextOverlay<'_> {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.max())
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &cosmic::Theme,
+        _style: &cosmic::iced::advanced::renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let Some(clip) = visible_area(bounds, viewport) else {
+            return;
+        };
+
+        self.draw_highlights(renderer, bounds, clip);
+        self.draw_border(renderer, bounds, clip);
+    }
+
+    fn on_event(
+        &mut self,
+        _tree: &mut Tree,
+        event: Event,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, AppMessage>,
+        _viewport: &Rectangle,
+    ) -> Status {
+        let bounds = layout.bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(Button::Left)) => {
+                if let Some(pos) = cursor.position_in(bounds) {
+                    shell.publish(AppMessage::TextDragStart { x: pos.x, y: pos.y });
+                    return Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                if self.selection.is_dragging
+                    && let Some(pos) = cursor.position_in(bounds)
+                {
+                    shell.publish(AppMessage::TextDragMove {
+                        x: pos.x,
+                        y: pos.y,
+                        max_x: bounds.width,
+                        max_y: bounds.height,
+                    });
+                    return Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(Button::Left)) => {
+                if self.selection.is_dragging {
+                    shell.publish(AppMessage::TextDragEnd);
+                    return Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        _tree: &Tree,
+        layout: Layout<'_>,
+        cursor: Cursor,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if cursor.is_over(layout.bounds()) {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::None
+        }
+    }
+}
+
+impl<'a> From<TextOverlay<'a>> for Element<'a, AppMessage> {
+    fn from(widget: TextOverlay<'a>) -> Self {
+        Element::new(widget)
+    }
+}
+
+/// Find bar hits drawn over the page; events pass through to the viewer.
+pub struct HitOverlay {
+    /// Canvas boxes, with whether they belong to the current hit.
+    boxes: Vec<((f32, f32, f32, f32), bool)>,
+    /// Physical pixels per logical pixel of the window
+    scale_factor: f32,
+}
+
+impl Widget<AppMessage, cosmic::Theme, Renderer> for HitOverlay {
+    fn size(&self) -> Size<Length> {
+        Size::new(Length::Fill, Length::Fill)
+    }
+
+    fn layout(&self, _tree: &mut Tree, _renderer: &Renderer, limits: &Limits) -> Node {
+        Node::new(limits.max())
+    }
+
+    fn draw(
+        &self,
+        _tree: &Tree,
+        renderer: &mut Renderer,
+        _theme: &cosmic::Theme,
+        _style: &cosmic::iced::advanced::renderer::Style,
+        layout: Layout<'_>,
+        _cursor: Cursor,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let Some(clip) = visible_area(bounds, viewport) else {
+            return;
+        };
+
+        for &(hit, current) in &self.boxes {
+            let rect = rectangle(snapped(bounds, hit, self.scale_factor));
+            let color = if current {
+                CURRENT_HIT_COLOR
+            } else {
+                HIT_COLOR
+            };
+            // Hits scrolled out of the canvas are not drawn over the panels
+            fill_clipped(renderer, rect, clip, color);
+        }
+    }
+}
+
+impl<'a> From<HitOverlay> for Element<'a, AppMessage> {
+    fn from(widget: HitOverlay) -> Self {
+        Element::new(widget)
+    }
+}
+
+pub fn text_overlay<'a>(
+    selection: &'a CropSelection,
+    highlights: &'a [(f32, f32, f32, f32)],
+    scale_factor: f32,
+) -> Element<'a, AppMessage> {
+    TextOverlay::new(selection, highlights, scale_factor).into()
+}
+
+pub fn hit_overlay<'a>(
+    boxes: Vec<((f32, f32, f32, f32), bool)>,
+    scale_factor: f32,
+) -> Element<'a, AppMessage> {
+    HitOverlay {
+        boxes,
+        scale_factor,
+    }
+    .into()
+}